@@ -0,0 +1,205 @@
+//! A write cache over the contract storage.
+use std::cell::{RefCell, RefMut};
+use std::collections::BTreeMap;
+use std::ops::Bound;
+
+use cosmwasm_std::{Binary, Order, Record, Storage};
+
+type Changes = BTreeMap<Vec<u8>, Option<Vec<u8>>>;
+
+/// The values of the keys written by a commit as they were before it, `None`
+/// for the keys that were absent, to revert the commit later on. It can be
+/// saved in the contract storage.
+pub type StorageUndo = Vec<(Binary, Option<Binary>)>;
+
+/// Records the writes of an application to the contract storage until they
+/// are committed, so that they can be discarded when a callback fails
+/// halfway, such as the reception of a packet answered with an error
+/// acknowledgement. The cache is written through a shared reference, as some
+/// ibc-rs execution callbacks only receive one.
+#[derive(Debug, Default)]
+pub struct StorageCache {
+    changes: RefCell<Changes>,
+}
+
+impl StorageCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a view of the given storage with the cached writes applied.
+    /// Only one view may exist at a time.
+    pub fn storage<'a>(&'a self, inner: &'a dyn Storage) -> CachedStorage<'a> {
+        CachedStorage {
+            inner,
+            changes: self.changes.borrow_mut(),
+        }
+    }
+
+    /// Writes the cached changes to the given storage.
+    pub fn commit(&self, storage: &mut dyn Storage) {
+        for (key, value) in self.changes.take() {
+            match value {
+                Some(value) => storage.set(&key, &value),
+                None => storage.remove(&key),
+            }
+        }
+    }
+
+    /// Writes the cached changes to the given storage, and returns the
+    /// values they replaced, to revert them with [`Self::revert`].
+    pub fn commit_revertible(&self, storage: &mut dyn Storage) -> StorageUndo {
+        let undo = self
+            .changes
+            .borrow()
+            .keys()
+            .map(|key| (key.clone().into(), storage.get(key).map(Into::into)))
+            .collect();
+
+        self.commit(storage);
+
+        undo
+    }
+
+    /// Caches the writes restoring the values replaced by a commit.
+    pub fn revert(&self, undo: StorageUndo) {
+        let mut changes = self.changes.borrow_mut();
+
+        for (key, value) in undo {
+            changes.insert(key.into(), value.map(Into::into));
+        }
+    }
+
+    /// Drops the cached changes.
+    pub fn discard(&self) {
+        self.changes.take();
+    }
+}
+
+/// A view of the contract storage with the writes of a [`StorageCache`]
+/// applied, recording any further write into the cache.
+pub struct CachedStorage<'a> {
+    inner: &'a dyn Storage,
+    changes: RefMut<'a, Changes>,
+}
+
+impl Storage for CachedStorage<'_> {
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        match self.changes.get(key) {
+            Some(value) => value.clone(),
+            None => self.inner.get(key),
+        }
+    }
+
+    fn range<'b>(
+        &'b self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = Record> + 'b> {
+        let mut records: BTreeMap<Vec<u8>, Vec<u8>> =
+            self.inner.range(start, end, Order::Ascending).collect();
+
+        let bounds = (
+            start.map_or(Bound::Unbounded, |start| Bound::Included(start.to_vec())),
+            end.map_or(Bound::Unbounded, |end| Bound::Excluded(end.to_vec())),
+        );
+
+        for (key, value) in self.changes.range(bounds) {
+            match value {
+                Some(value) => records.insert(key.clone(), value.clone()),
+                None => records.remove(key),
+            };
+        }
+
+        match order {
+            Order::Ascending => Box::new(records.into_iter()),
+            Order::Descending => Box::new(records.into_iter().rev()),
+        }
+    }
+
+    fn set(&mut self, key: &[u8], value: &[u8]) {
+        self.changes.insert(key.to_vec(), Some(value.to_vec()));
+    }
+
+    fn remove(&mut self, key: &[u8]) {
+        self.changes.insert(key.to_vec(), None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockStorage;
+
+    use super::*;
+
+    #[test]
+    fn test_cached_storage_commit_and_discard() {
+        let mut storage = MockStorage::new();
+
+        storage.set(b"a", b"1");
+        storage.set(b"b", b"2");
+
+        let cache = StorageCache::new();
+
+        {
+            let mut cached = cache.storage(&storage);
+
+            cached.set(b"c", b"3");
+            cached.remove(b"a");
+
+            assert_eq!(cached.get(b"a"), None);
+            assert_eq!(cached.get(b"b"), Some(b"2".to_vec()));
+
+            let keys: Vec<_> = cached
+                .range(None, None, Order::Descending)
+                .map(|(key, _)| key)
+                .collect();
+
+            assert_eq!(keys, vec![b"c".to_vec(), b"b".to_vec()]);
+        }
+
+        // Nothing is written before the changes are committed.
+        assert_eq!(storage.get(b"a"), Some(b"1".to_vec()));
+
+        cache.discard();
+
+        assert_eq!(cache.storage(&storage).get(b"a"), Some(b"1".to_vec()));
+
+        cache.storage(&storage).set(b"b", b"4");
+        cache.commit(&mut storage);
+
+        assert_eq!(storage.get(b"b"), Some(b"4".to_vec()));
+        assert_eq!(cache.storage(&storage).get(b"b"), Some(b"4".to_vec()));
+    }
+
+    #[test]
+    fn test_cached_storage_revert() {
+        let mut storage = MockStorage::new();
+
+        storage.set(b"a", b"1");
+        storage.set(b"b", b"2");
+
+        let cache = StorageCache::new();
+
+        {
+            let mut cached = cache.storage(&storage);
+
+            cached.set(b"a", b"3");
+            cached.remove(b"b");
+            cached.set(b"c", b"4");
+        }
+
+        let undo = cache.commit_revertible(&mut storage);
+
+        assert_eq!(storage.get(b"a"), Some(b"3".to_vec()));
+        assert_eq!(storage.get(b"b"), None);
+
+        cache.revert(undo);
+        cache.commit(&mut storage);
+
+        assert_eq!(storage.get(b"a"), Some(b"1".to_vec()));
+        assert_eq!(storage.get(b"b"), Some(b"2".to_vec()));
+        assert_eq!(storage.get(b"c"), None);
+    }
+}
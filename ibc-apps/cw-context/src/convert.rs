@@ -0,0 +1,92 @@
+//! Conversions between the types of the CosmWasm IBC entry points and the
+//! ibc-rs channel types.
+use std::str::FromStr;
+
+use cosmwasm_std::{Event, IbcChannel, IbcOrder, IbcPacket};
+use ibc_core::channel::types::channel::{Counterparty, Order};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::timeout::{TimeoutHeight, TimeoutTimestamp};
+use ibc_core::client::types::proto::v1::Height as RawHeight;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
+use ibc_core::router::types::module::ModuleExtras;
+
+pub fn order_from_cw(order: &IbcOrder) -> Order {
+    match order {
+        IbcOrder::Unordered => Order::Unordered,
+        IbcOrder::Ordered => Order::Ordered,
+    }
+}
+
+pub fn connection_hops_from_cw(channel: &IbcChannel) -> Result<Vec<ConnectionId>, DecodingError> {
+    Ok(vec![ConnectionId::from_str(&channel.connection_id)?])
+}
+
+/// Returns the identifiers of the local end of a channel.
+pub fn endpoint_from_cw(channel: &IbcChannel) -> Result<(PortId, ChannelId), DecodingError> {
+    Ok((
+        PortId::from_str(&channel.endpoint.port_id)?,
+        ChannelId::from_str(&channel.endpoint.channel_id)?,
+    ))
+}
+
+/// Returns the counterparty of a channel, whose channel identifier is still
+/// unknown on `OpenInit`.
+pub fn counterparty_from_cw(channel: &IbcChannel) -> Result<Counterparty, DecodingError> {
+    let endpoint = &channel.counterparty_endpoint;
+
+    let channel_id = match endpoint.channel_id.as_str() {
+        "" => None,
+        channel_id => Some(ChannelId::from_str(channel_id)?),
+    };
+
+    Ok(Counterparty::new(
+        PortId::from_str(&endpoint.port_id)?,
+        channel_id,
+    ))
+}
+
+/// Converts a packet passed to the IBC entry points into an ibc-rs
+/// [`Packet`]. The source of the packet is always its sender, the chain A of
+/// the ibc-rs callbacks.
+pub fn packet_from_cw(packet: &IbcPacket) -> Result<Packet, DecodingError> {
+    let timeout_height_on_b = match packet.timeout.block() {
+        Some(block) => TimeoutHeight::try_from(RawHeight {
+            revision_number: block.revision,
+            revision_height: block.height,
+        })?,
+        None => TimeoutHeight::Never,
+    };
+
+    let timeout_timestamp_on_b = match packet.timeout.timestamp() {
+        Some(timestamp) => TimeoutTimestamp::from_nanoseconds(timestamp.nanos()),
+        None => TimeoutTimestamp::Never,
+    };
+
+    Ok(Packet {
+        seq_on_a: Sequence::from(packet.sequence),
+        port_id_on_a: PortId::from_str(&packet.src.port_id)?,
+        chan_id_on_a: ChannelId::from_str(&packet.src.channel_id)?,
+        port_id_on_b: PortId::from_str(&packet.dest.port_id)?,
+        chan_id_on_b: ChannelId::from_str(&packet.dest.channel_id)?,
+        data: packet.data.to_vec(),
+        timeout_height_on_b,
+        timeout_timestamp_on_b,
+    })
+}
+
+/// Converts the events emitted by the ibc-rs callbacks into CosmWasm events.
+pub fn events_from_extras(extras: ModuleExtras) -> Vec<Event> {
+    extras
+        .events
+        .into_iter()
+        .map(|event| {
+            Event::new(event.kind).add_attributes(
+                event
+                    .attributes
+                    .into_iter()
+                    .map(|attribute| (attribute.key, attribute.value)),
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,10 @@
+//! Contains the utilities shared by the IBC applications, built using
+//! ibc-rs, that run as CosmWasm contracts owning a `wasm.<contract address>`
+//! port: conversions between the types passed to the CosmWasm IBC entry
+//! points and the ibc-rs channel types, and a write cache over the contract
+//! storage, so that a failed packet reception leaves no state behind.
+
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+
+pub mod cache;
+pub mod convert;
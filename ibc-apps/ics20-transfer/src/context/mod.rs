@@ -0,0 +1,377 @@
+pub mod transfer_ctx;
+
+use std::str::FromStr;
+
+use cosmwasm_std::{
+    coins, to_json_binary, Addr, Api, BankMsg, CosmosMsg, Deps, DepsMut, Env, Storage, Uint128,
+    WasmMsg,
+};
+use cw_storage_plus::{Item, Map};
+use ibc_app_cw::cache::{CachedStorage, StorageCache, StorageUndo};
+use ibc_app_transfer::types::{BaseDenom, PrefixedDenom};
+use ibc_core::host::types::identifiers::PortId;
+
+use crate::types::proto::{any_msg, Coin, MsgBurn, MsgCreateDenom, MsgMint};
+use crate::types::{
+    denom_trace_hash, proto, ChannelInfo, Config, ContractError, Cw20ExecuteMsg, Voucher,
+    VoucherMinter,
+};
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// The ICS-20 channels opened by the contract, by channel identifier.
+pub const CHANNELS: Map<&str, ChannelInfo> = Map::new("channels");
+
+/// The full denom traces of the tokens received from other chains, by hash.
+pub const DENOM_TRACES: Map<&str, String> = Map::new("denom_traces");
+
+/// The vouchers representing the tokens received from other chains, by the
+/// hash of their denom trace.
+pub const VOUCHERS: Map<&str, Voucher> = Map::new("vouchers");
+
+/// The hash of the denom trace represented by each voucher, by the local
+/// denom of the voucher.
+pub const VOUCHER_HASHES: Map<&str, String> = Map::new("voucher_hashes");
+
+/// The amount of tokens escrowed for each channel, by channel identifier and
+/// by denom, as it appears in the packets sent over the channel.
+pub const ESCROWED: Map<(&str, &str), Uint128> = Map::new("escrowed");
+
+/// The values replaced by the reception of the last packet, to revert it if
+/// dispatching its messages fails.
+pub const RECEIVE_UNDO: Item<StorageUndo> = Item::new("receive_undo");
+
+/// The length of the subdenoms of the tokenfactory vouchers, shorter than
+/// the 44 characters most tokenfactory implementations accept.
+const SUBDENOM_LENGTH: usize = 32;
+
+/// TransferContext is a wrapper around the deps and env that provides access
+/// to the methods under the ibc-rs token transfer Validation and Execution
+/// traits, and collects the messages they dispatch.
+///
+/// Its storage writes are cached until committed, so that a failed reception
+/// of a packet leaves no state behind, and so that a reception can be
+/// reverted once its messages fail.
+pub struct TransferContext<'a> {
+    deps: Option<Deps<'a>>,
+    deps_mut: Option<DepsMut<'a>>,
+    env: Env,
+    cache: StorageCache,
+    messages: Vec<CosmosMsg>,
+}
+
+impl<'a> TransferContext<'a> {
+    /// Constructs a new TransferContext object with the given deps and env.
+    pub fn new_ref(deps: Deps<'a>, env: Env) -> Self {
+        Self {
+            deps: Some(deps),
+            deps_mut: None,
+            env,
+            cache: StorageCache::new(),
+            messages: Vec::new(),
+        }
+    }
+
+    /// Constructs a new TransferContext object with the given deps_mut and env.
+    pub fn new_mut(deps_mut: DepsMut<'a>, env: Env) -> Self {
+        Self {
+            deps: None,
+            deps_mut: Some(deps_mut),
+            env,
+            cache: StorageCache::new(),
+            messages: Vec::new(),
+        }
+    }
+
+    /// Returns the env of the context.
+    pub fn env(&self) -> &Env {
+        &self.env
+    }
+
+    pub fn api(&self) -> &dyn Api {
+        match self.deps {
+            Some(ref deps) => deps.api,
+            None => match self.deps_mut {
+                Some(ref deps) => deps.api,
+                None => panic!("Either deps or deps_mut should be available"),
+            },
+        }
+    }
+
+    pub fn storage_ref(&self) -> &dyn Storage {
+        match self.deps {
+            Some(ref deps) => deps.storage,
+            None => match self.deps_mut {
+                Some(ref deps) => deps.storage,
+                None => panic!("Either deps or deps_mut should be available"),
+            },
+        }
+    }
+
+    /// Returns the contract storage with the cached writes applied. Any
+    /// write through it is cached until [`Self::commit`] is called.
+    pub fn store(&self) -> CachedStorage<'_> {
+        self.cache.storage(self.storage_ref())
+    }
+
+    /// Writes the cached changes to the contract storage.
+    pub fn commit(&mut self) {
+        let storage = match self.deps_mut {
+            Some(ref mut deps) => &mut *deps.storage,
+            None => panic!("deps_mut should be available"),
+        };
+
+        self.cache.commit(storage);
+    }
+
+    /// Writes the cached changes to the contract storage, and returns the
+    /// values they replaced, to revert them with [`Self::revert`].
+    pub fn commit_revertible(&mut self) -> StorageUndo {
+        let storage = match self.deps_mut {
+            Some(ref mut deps) => &mut *deps.storage,
+            None => panic!("deps_mut should be available"),
+        };
+
+        self.cache.commit_revertible(storage)
+    }
+
+    /// Caches the writes restoring the values replaced by a commit.
+    pub fn revert(&self, undo: StorageUndo) {
+        self.cache.revert(undo);
+    }
+
+    /// Drops the cached changes and the messages dispatched so far.
+    pub fn discard(&mut self) {
+        self.cache.discard();
+        self.messages.clear();
+    }
+
+    pub fn contract_address(&self) -> &Addr {
+        &self.env.contract.address
+    }
+
+    /// Returns the port bound to the contract by the hosting chain.
+    pub fn port_id(&self) -> Result<PortId, ContractError> {
+        Ok(PortId::from_str(&format!(
+            "wasm.{}",
+            self.contract_address()
+        ))?)
+    }
+
+    pub fn config(&self) -> Result<Config, ContractError> {
+        Ok(CONFIG.load(&self.store())?)
+    }
+
+    /// Returns the messages dispatched by the executed transfer callbacks.
+    pub fn take_messages(&mut self) -> Vec<CosmosMsg> {
+        std::mem::take(&mut self.messages)
+    }
+
+    /// Returns the denom of the tokens sent by a transfer: the denom trace of
+    /// a voucher, or the base denom of any other native token.
+    pub fn prefixed_denom(&self, local_denom: &str) -> Result<PrefixedDenom, ContractError> {
+        // The cached storage is borrowed until the end of the statement.
+        let hash = VOUCHER_HASHES.may_load(&self.store(), local_denom)?;
+
+        match hash {
+            Some(hash) => {
+                let denom_trace = DENOM_TRACES.load(&self.store(), &hash)?;
+
+                Ok(PrefixedDenom::from_str(&denom_trace)?)
+            }
+            None => Ok(BaseDenom::from_str(local_denom)?.into()),
+        }
+    }
+
+    pub fn voucher(&self, denom: &PrefixedDenom) -> Result<Option<Voucher>, ContractError> {
+        Ok(VOUCHERS.may_load(&self.store(), &denom_trace_hash(denom))?)
+    }
+
+    /// Returns the voucher of a denom trace, failing if it was never minted
+    /// nor registered.
+    pub fn existing_voucher(&self, denom: &PrefixedDenom) -> Result<Voucher, ContractError> {
+        self.voucher(denom)?
+            .ok_or_else(|| ContractError::MissingVoucher {
+                denom: denom.to_string(),
+            })
+    }
+
+    pub fn escrowed(
+        &self,
+        channel_id: &str,
+        denom: &PrefixedDenom,
+    ) -> Result<Uint128, ContractError> {
+        Ok(ESCROWED
+            .may_load(&self.store(), (channel_id, &denom.to_string()))?
+            .unwrap_or_default())
+    }
+
+    pub fn set_escrowed(
+        &mut self,
+        channel_id: &str,
+        denom: &PrefixedDenom,
+        amount: Uint128,
+    ) -> Result<(), ContractError> {
+        let key = (channel_id, denom.to_string());
+
+        if amount.is_zero() {
+            ESCROWED.remove(&mut self.store(), (key.0, &key.1));
+        } else {
+            ESCROWED.save(&mut self.store(), (key.0, &key.1), &amount)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the voucher of a denom trace received from another chain,
+    /// creating a tokenfactory denom for it on its first reception if the
+    /// vouchers are minted by the tokenfactory module.
+    pub fn voucher_or_create(&mut self, denom: &PrefixedDenom) -> Result<Voucher, ContractError> {
+        let hash = denom_trace_hash(denom);
+
+        let voucher = VOUCHERS.may_load(&self.store(), &hash)?;
+
+        if let Some(voucher) = voucher {
+            return Ok(voucher);
+        }
+
+        if self.config()?.voucher_minter == VoucherMinter::Cw20 {
+            return Err(ContractError::MissingVoucher {
+                denom: denom.to_string(),
+            });
+        }
+
+        let subdenom = hash[..SUBDENOM_LENGTH].to_lowercase();
+
+        self.messages.push(any_msg(
+            proto::MSG_CREATE_DENOM_TYPE_URL,
+            &MsgCreateDenom {
+                sender: self.contract_address().to_string(),
+                subdenom: subdenom.clone(),
+            },
+        ));
+
+        let voucher = Voucher::Native {
+            denom: format!("factory/{}/{subdenom}", self.contract_address()),
+        };
+
+        DENOM_TRACES.save(&mut self.store(), &hash, &denom.to_string())?;
+        VOUCHERS.save(&mut self.store(), &hash, &voucher)?;
+        VOUCHER_HASHES.save(&mut self.store(), voucher.local_denom(), &hash)?;
+
+        Ok(voucher)
+    }
+
+    /// Returns the local representation of the tokens of a denom: the
+    /// voucher of a denom trace, or the native denom of a base denom.
+    pub fn local_token(&self, denom: &PrefixedDenom) -> Result<Voucher, ContractError> {
+        if denom.trace_path.is_empty() {
+            return Ok(Voucher::Native {
+                denom: denom.base_denom.to_string(),
+            });
+        }
+
+        self.existing_voucher(denom)
+    }
+
+    /// Dispatches the message sending tokens held by the contract, either
+    /// native tokens or cw20 vouchers.
+    pub fn send(
+        &mut self,
+        token: &Voucher,
+        recipient: &Addr,
+        amount: Uint128,
+    ) -> Result<(), ContractError> {
+        let msg = match token {
+            Voucher::Native { denom } => BankMsg::Send {
+                to_address: recipient.to_string(),
+                amount: coins(amount.u128(), denom),
+            }
+            .into(),
+            Voucher::Cw20 { contract } => WasmMsg::Execute {
+                contract_addr: contract.to_string(),
+                msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: recipient.to_string(),
+                    amount,
+                })?,
+                funds: vec![],
+            }
+            .into(),
+        };
+
+        self.messages.push(msg);
+
+        Ok(())
+    }
+
+    /// Dispatches the messages minting vouchers to the given account.
+    pub fn mint(
+        &mut self,
+        voucher: &Voucher,
+        recipient: &Addr,
+        amount: Uint128,
+    ) -> Result<(), ContractError> {
+        match voucher {
+            Voucher::Native { denom } => {
+                // The vouchers are minted to the contract and then sent, as
+                // not every tokenfactory implementation honours the
+                // `mint_to_address` of other accounts.
+                self.messages.push(any_msg(
+                    proto::MSG_MINT_TYPE_URL,
+                    &MsgMint {
+                        sender: self.contract_address().to_string(),
+                        amount: Some(Coin {
+                            denom: denom.clone(),
+                            amount: amount.to_string(),
+                        }),
+                        mint_to_address: self.contract_address().to_string(),
+                    },
+                ));
+
+                self.send(voucher, recipient, amount)
+            }
+            Voucher::Cw20 { contract } => {
+                self.messages.push(
+                    WasmMsg::Execute {
+                        contract_addr: contract.to_string(),
+                        msg: to_json_binary(&Cw20ExecuteMsg::Mint {
+                            recipient: recipient.to_string(),
+                            amount,
+                        })?,
+                        funds: vec![],
+                    }
+                    .into(),
+                );
+
+                Ok(())
+            }
+        }
+    }
+
+    /// Dispatches the messages burning vouchers held by the contract.
+    pub fn burn(&mut self, voucher: &Voucher, amount: Uint128) -> Result<(), ContractError> {
+        let msg = match voucher {
+            Voucher::Native { denom } => any_msg(
+                proto::MSG_BURN_TYPE_URL,
+                &MsgBurn {
+                    sender: self.contract_address().to_string(),
+                    amount: Some(Coin {
+                        denom: denom.clone(),
+                        amount: amount.to_string(),
+                    }),
+                    burn_from_address: self.contract_address().to_string(),
+                },
+            ),
+            Voucher::Cw20 { contract } => WasmMsg::Execute {
+                contract_addr: contract.to_string(),
+                msg: to_json_binary(&Cw20ExecuteMsg::Burn { amount })?,
+                funds: vec![],
+            }
+            .into(),
+        };
+
+        self.messages.push(msg);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,171 @@
+//! Implementation of the token transfer contexts of `ibc-app-transfer`.
+use cosmwasm_std::Addr;
+use ibc_app_transfer::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
+use ibc_app_transfer::types::{Memo, PrefixedCoin, PrefixedDenom};
+use ibc_core::host::types::error::HostError;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::Signer;
+
+use super::TransferContext;
+use crate::types::{amount_to_uint128, denom_trace_hash, ContractError, VoucherMinter};
+
+impl TokenTransferValidationContext for TransferContext<'_> {
+    type AccountId = Addr;
+
+    fn sender_account(&self, sender: &Signer) -> Result<Addr, HostError> {
+        self.api()
+            .addr_validate(sender.as_ref())
+            .map_err(HostError::invalid_state)
+    }
+
+    fn receiver_account(&self, receiver: &Signer) -> Result<Addr, HostError> {
+        self.api()
+            .addr_validate(receiver.as_ref())
+            .map_err(HostError::invalid_state)
+    }
+
+    fn get_port(&self) -> Result<PortId, HostError> {
+        Ok(self.port_id()?)
+    }
+
+    fn can_send_coins(&self) -> Result<(), HostError> {
+        Ok(())
+    }
+
+    fn can_receive_coins(&self) -> Result<(), HostError> {
+        Ok(())
+    }
+
+    /// The tokens are escrowed once sent to the contract along with the
+    /// transfer, so that only their amount is checked here.
+    fn escrow_coins_validate(
+        &self,
+        _from_account: &Addr,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), HostError> {
+        amount_to_uint128(&coin.amount)?;
+
+        Ok(())
+    }
+
+    fn unescrow_coins_validate(
+        &self,
+        _to_account: &Addr,
+        _port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), HostError> {
+        let amount = amount_to_uint128(&coin.amount)?;
+
+        let escrowed = self.escrowed(channel_id.as_str(), &coin.denom)?;
+
+        if escrowed < amount {
+            return Err(ContractError::InsufficientEscrow {
+                denom: coin.denom.to_string(),
+                escrowed,
+                amount,
+            }
+            .into());
+        }
+
+        self.local_token(&coin.denom)?;
+
+        Ok(())
+    }
+
+    /// The vouchers of a denom trace are created on their first reception,
+    /// unless they are minted by cw20 contracts that must be registered.
+    fn mint_coins_validate(&self, _account: &Addr, coin: &PrefixedCoin) -> Result<(), HostError> {
+        amount_to_uint128(&coin.amount)?;
+
+        if self.config()?.voucher_minter == VoucherMinter::Cw20 {
+            self.existing_voucher(&coin.denom)?;
+        }
+
+        Ok(())
+    }
+
+    fn burn_coins_validate(
+        &self,
+        _account: &Addr,
+        coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), HostError> {
+        amount_to_uint128(&coin.amount)?;
+
+        self.existing_voucher(&coin.denom)?;
+
+        Ok(())
+    }
+
+    fn denom_hash_string(&self, denom: &PrefixedDenom) -> Option<String> {
+        Some(format!("ibc/{}", denom_trace_hash(denom)))
+    }
+}
+
+impl TokenTransferExecutionContext for TransferContext<'_> {
+    fn escrow_coins_execute(
+        &mut self,
+        _from_account: &Addr,
+        _port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), HostError> {
+        let amount = amount_to_uint128(&coin.amount)?;
+
+        let escrowed = self.escrowed(channel_id.as_str(), &coin.denom)?;
+
+        let escrowed = escrowed
+            .checked_add(amount)
+            .map_err(HostError::invalid_state)?;
+
+        Ok(self.set_escrowed(channel_id.as_str(), &coin.denom, escrowed)?)
+    }
+
+    fn unescrow_coins_execute(
+        &mut self,
+        to_account: &Addr,
+        _port_id: &PortId,
+        channel_id: &ChannelId,
+        coin: &PrefixedCoin,
+    ) -> Result<(), HostError> {
+        let amount = amount_to_uint128(&coin.amount)?;
+
+        let escrowed = self.escrowed(channel_id.as_str(), &coin.denom)?;
+
+        let escrowed = escrowed
+            .checked_sub(amount)
+            .map_err(HostError::invalid_state)?;
+
+        self.set_escrowed(channel_id.as_str(), &coin.denom, escrowed)?;
+
+        let token = self.local_token(&coin.denom)?;
+
+        Ok(self.send(&token, to_account, amount)?)
+    }
+
+    fn mint_coins_execute(&mut self, account: &Addr, coin: &PrefixedCoin) -> Result<(), HostError> {
+        let amount = amount_to_uint128(&coin.amount)?;
+
+        let voucher = self.voucher_or_create(&coin.denom)?;
+
+        Ok(self.mint(&voucher, account, amount)?)
+    }
+
+    fn burn_coins_execute(
+        &mut self,
+        _account: &Addr,
+        coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), HostError> {
+        let amount = amount_to_uint128(&coin.amount)?;
+
+        let voucher = self.existing_voucher(&coin.denom)?;
+
+        Ok(self.burn(&voucher, amount)?)
+    }
+}
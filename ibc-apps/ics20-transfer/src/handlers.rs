@@ -0,0 +1,561 @@
+use std::str::FromStr;
+
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, Binary, Ibc3ChannelOpenResponse, IbcBasicResponse,
+    IbcChannelCloseMsg, IbcChannelConnectMsg, IbcChannelOpenMsg, IbcChannelOpenResponse, IbcMsg,
+    IbcPacketAckMsg, IbcPacketReceiveMsg, IbcPacketTimeoutMsg, IbcReceiveResponse, IbcTimeout,
+    MessageInfo, Order as StorageOrder, Reply, Response, SubMsg, SubMsgResult, Uint128, WasmMsg,
+};
+use cw_storage_plus::Bound;
+use ibc_app_cw::convert::{
+    connection_hops_from_cw, counterparty_from_cw, endpoint_from_cw, events_from_extras,
+    order_from_cw, packet_from_cw,
+};
+use ibc_app_transfer::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
+use ibc_app_transfer::module::{
+    on_acknowledgement_packet_execute, on_acknowledgement_packet_validate,
+    on_chan_close_confirm_execute, on_chan_close_confirm_validate, on_chan_close_init_execute,
+    on_chan_close_init_validate, on_chan_open_ack_execute, on_chan_open_ack_validate,
+    on_chan_open_confirm_execute, on_chan_open_confirm_validate, on_chan_open_init_execute,
+    on_chan_open_init_validate, on_chan_open_try_execute, on_chan_open_try_validate,
+    on_recv_packet_execute, on_timeout_packet_execute, on_timeout_packet_validate,
+};
+use ibc_app_transfer::types::events::TransferEvent;
+use ibc_app_transfer::types::packet::PacketData;
+use ibc_app_transfer::types::{is_sender_chain_source, Amount, Memo, PrefixedCoin, PrefixedDenom};
+use ibc_core::channel::types::acknowledgement::{
+    Acknowledgement, AcknowledgementStatus, StatusValue,
+};
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::identifiers::ChannelId;
+use ibc_core::primitives::Signer;
+use ibc_core::router::types::event::ModuleEvent;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::context::{
+    TransferContext, CHANNELS, CONFIG, DENOM_TRACES, ESCROWED, RECEIVE_UNDO, VOUCHERS,
+    VOUCHER_HASHES,
+};
+use crate::types::{
+    denom_trace_hash, ChannelInfo, Config, ContractError, Cw20ReceiveMsg, DenomTraceResponse,
+    DenomTracesResponse, DispatchReceivedMsg, EscrowedResponse, ExecuteMsg, InstantiateMsg,
+    QueryMsg, RegisterCw20VoucherMsg, TransferMsg, Voucher, VoucherMinter,
+};
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
+/// The reply to the failed dispatch of the messages of a received packet.
+pub const RECEIVE_REPLY_ID: u64 = 1;
+
+impl TransferContext<'_> {
+    /// Instantiates the contract with the given [`InstantiateMsg`] message.
+    pub fn instantiate(
+        &mut self,
+        info: MessageInfo,
+        msg: InstantiateMsg,
+    ) -> Result<Response, ContractError> {
+        let admin = match msg.admin {
+            Some(admin) => self.api().addr_validate(&admin)?,
+            None => info.sender,
+        };
+
+        let config = Config {
+            admin,
+            default_timeout: msg.default_timeout,
+            voucher_minter: msg.voucher_minter,
+        };
+
+        CONFIG.save(&mut self.store(), &config)?;
+
+        self.commit();
+
+        Ok(Response::default())
+    }
+
+    /// Executes the given [`ExecuteMsg`].
+    pub fn execute(
+        &mut self,
+        info: MessageInfo,
+        msg: ExecuteMsg,
+    ) -> Result<Response, ContractError> {
+        match msg {
+            ExecuteMsg::Transfer(msg) => {
+                let coin = match info.funds.as_slice() {
+                    [coin] => coin,
+                    funds => return Err(ContractError::InvalidFunds { count: funds.len() }),
+                };
+
+                self.transfer(info.sender, &coin.denom, coin.amount, msg)
+            }
+            ExecuteMsg::Receive(msg) => self.receive_cw20(info, msg),
+            ExecuteMsg::RegisterCw20Voucher(msg) => self.register_cw20_voucher(info, msg),
+            ExecuteMsg::DispatchReceived(msg) => self.dispatch_received(info, msg),
+        }
+    }
+
+    /// Transfers the cw20 vouchers sent to the contract by one of the
+    /// registered voucher contracts.
+    fn receive_cw20(
+        &mut self,
+        info: MessageInfo,
+        msg: Cw20ReceiveMsg,
+    ) -> Result<Response, ContractError> {
+        if !VOUCHER_HASHES.has(&self.store(), info.sender.as_str()) {
+            return Err(ContractError::UnknownCw20Voucher {
+                contract: info.sender.to_string(),
+            });
+        }
+
+        let sender = self.api().addr_validate(&msg.sender)?;
+
+        let transfer_msg: TransferMsg = from_json(&msg.msg)?;
+
+        self.transfer(sender, info.sender.as_str(), msg.amount, transfer_msg)
+    }
+
+    /// Sends the tokens held by the contract for the sender to the
+    /// counterparty chain: vouchers returning to their source are burned,
+    /// while any other token is escrowed for the channel.
+    fn transfer(
+        &mut self,
+        sender: Addr,
+        local_denom: &str,
+        amount: Uint128,
+        msg: TransferMsg,
+    ) -> Result<Response, ContractError> {
+        if amount.is_zero() {
+            return Err(ContractError::ZeroAmount);
+        }
+
+        if msg.receiver.is_empty() {
+            return Err(ContractError::EmptyReceiver);
+        }
+
+        if !CHANNELS.has(&self.store(), &msg.channel_id) {
+            return Err(ContractError::UnknownChannel {
+                channel_id: msg.channel_id,
+            });
+        }
+
+        let port_id = self.port_id()?;
+        let channel_id = ChannelId::from_str(&msg.channel_id)?;
+
+        let packet_data = PacketData {
+            token: PrefixedCoin {
+                denom: self.prefixed_denom(local_denom)?,
+                amount: Amount::from_str(&amount.to_string())?,
+            },
+            sender: Signer::from(sender.to_string()),
+            receiver: Signer::from(msg.receiver),
+            memo: Memo::from(msg.memo.unwrap_or_default()),
+        };
+
+        self.can_send_coins()?;
+
+        let sender = self.sender_account(&packet_data.sender)?;
+        let token = &packet_data.token;
+
+        if is_sender_chain_source(port_id.clone(), channel_id.clone(), &token.denom) {
+            self.escrow_coins_validate(&sender, &port_id, &channel_id, token, &packet_data.memo)?;
+            self.escrow_coins_execute(&sender, &port_id, &channel_id, token, &packet_data.memo)?;
+        } else {
+            self.burn_coins_validate(&sender, token, &packet_data.memo)?;
+            self.burn_coins_execute(&sender, token, &packet_data.memo)?;
+        }
+
+        let timeout = msg.timeout.unwrap_or(self.config()?.default_timeout);
+
+        let send_packet = IbcMsg::SendPacket {
+            channel_id: msg.channel_id,
+            data: to_json_binary(&packet_data)?,
+            timeout: IbcTimeout::with_timestamp(self.env().block.time.plus_seconds(timeout)),
+        };
+
+        let transfer_event = TransferEvent {
+            sender: packet_data.sender,
+            receiver: packet_data.receiver,
+            amount: packet_data.token.amount,
+            denom: packet_data.token.denom,
+            memo: packet_data.memo,
+        };
+
+        let extras = ModuleExtras {
+            events: vec![ModuleEvent::from(transfer_event)],
+            log: Vec::new(),
+        };
+
+        self.commit();
+
+        Ok(Response::default()
+            .add_messages(self.take_messages())
+            .add_message(send_packet)
+            .add_events(events_from_extras(extras)))
+    }
+
+    /// Registers the cw20 contract minting the vouchers of a denom trace.
+    fn register_cw20_voucher(
+        &mut self,
+        info: MessageInfo,
+        msg: RegisterCw20VoucherMsg,
+    ) -> Result<Response, ContractError> {
+        let config = self.config()?;
+
+        if info.sender != config.admin {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender.to_string(),
+            });
+        }
+
+        if config.voucher_minter != VoucherMinter::Cw20 {
+            return Err(ContractError::Cw20VouchersDisabled);
+        }
+
+        let denom = PrefixedDenom::from_str(&msg.denom)?;
+        let contract = self.api().addr_validate(&msg.contract)?;
+
+        let hash = denom_trace_hash(&denom);
+
+        if VOUCHERS.has(&self.store(), &hash)
+            || VOUCHER_HASHES.has(&self.store(), contract.as_str())
+        {
+            return Err(ContractError::VoucherAlreadyRegistered { denom: msg.denom });
+        }
+
+        let voucher = Voucher::Cw20 { contract };
+
+        DENOM_TRACES.save(&mut self.store(), &hash, &denom.to_string())?;
+        VOUCHERS.save(&mut self.store(), &hash, &voucher)?;
+        VOUCHER_HASHES.save(&mut self.store(), voucher.local_denom(), &hash)?;
+
+        self.commit();
+
+        Ok(Response::default()
+            .add_attribute("action", "register_cw20_voucher")
+            .add_attribute("denom_hash", hash))
+    }
+
+    /// Dispatches the messages of a received packet on behalf of the
+    /// contract. The reception is no longer to be reverted once they
+    /// succeed, while their failure also reverts the removal of its undo.
+    fn dispatch_received(
+        &mut self,
+        info: MessageInfo,
+        msg: DispatchReceivedMsg,
+    ) -> Result<Response, ContractError> {
+        if info.sender != *self.contract_address() {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender.to_string(),
+            });
+        }
+
+        RECEIVE_UNDO.remove(&mut self.store());
+
+        self.commit();
+
+        Ok(Response::default().add_messages(msg.msgs))
+    }
+
+    /// Handles the failed dispatch of the messages of a received packet:
+    /// the reception is reverted, and acknowledged with an error instead, so
+    /// that the tokens are refunded on the sending chain.
+    pub fn reply(&mut self, msg: Reply) -> Result<Response, ContractError> {
+        if msg.id != RECEIVE_REPLY_ID {
+            return Err(ContractError::UnknownReply { id: msg.id });
+        }
+
+        let SubMsgResult::Err(error) = msg.result else {
+            return Ok(Response::default());
+        };
+
+        let undo = RECEIVE_UNDO.load(&self.store())?;
+
+        self.revert(undo);
+
+        RECEIVE_UNDO.remove(&mut self.store());
+
+        self.commit();
+
+        let status = StatusValue::new(format!("failed to dispatch the received tokens: {error}"))
+            .expect("error message must not be empty");
+
+        let ack = Acknowledgement::from(AcknowledgementStatus::error(status));
+
+        Ok(Response::default().set_data(ack.as_bytes().to_vec()))
+    }
+
+    /// Queries the contract with the given [`QueryMsg`].
+    pub fn query(&self, msg: QueryMsg) -> Result<Binary, ContractError> {
+        let binary = match msg {
+            QueryMsg::Config {} => to_json_binary(&self.config()?)?,
+            QueryMsg::Channel { channel_id } => {
+                let channel = CHANNELS
+                    .may_load(self.storage_ref(), &channel_id)?
+                    .ok_or(ContractError::UnknownChannel { channel_id })?;
+
+                to_json_binary(&channel)?
+            }
+            QueryMsg::DenomTrace { hash } => {
+                let hash = hash.strip_prefix("ibc/").unwrap_or(&hash).to_uppercase();
+
+                let denom_trace = DENOM_TRACES.load(self.storage_ref(), &hash)?;
+
+                to_json_binary(&self.denom_trace_response(hash, &denom_trace)?)?
+            }
+            QueryMsg::DenomTraces { start_after, limit } => {
+                let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+                let start = start_after.as_deref().map(Bound::exclusive);
+
+                let denom_traces = DENOM_TRACES
+                    .range(self.storage_ref(), start, None, StorageOrder::Ascending)
+                    .take(limit)
+                    .map(|item| {
+                        let (hash, denom_trace) = item?;
+                        self.denom_trace_response(hash, &denom_trace)
+                    })
+                    .collect::<Result<_, ContractError>>()?;
+
+                to_json_binary(&DenomTracesResponse { denom_traces })?
+            }
+            QueryMsg::Escrowed { channel_id, denom } => {
+                let amount = ESCROWED
+                    .may_load(self.storage_ref(), (&channel_id, &denom))?
+                    .unwrap_or_default();
+
+                to_json_binary(&EscrowedResponse { amount })?
+            }
+        };
+
+        Ok(binary)
+    }
+
+    fn denom_trace_response(
+        &self,
+        hash: String,
+        denom_trace: &str,
+    ) -> Result<DenomTraceResponse, ContractError> {
+        let denom = PrefixedDenom::from_str(denom_trace)?;
+
+        Ok(DenomTraceResponse {
+            voucher: VOUCHERS.may_load(self.storage_ref(), &hash)?,
+            hash,
+            path: denom.trace_path.to_string(),
+            base_denom: denom.base_denom.to_string(),
+        })
+    }
+
+    /// Runs the `OpenInit` and `OpenTry` callbacks of the channel handshake.
+    pub fn channel_open(
+        &mut self,
+        msg: IbcChannelOpenMsg,
+    ) -> Result<IbcChannelOpenResponse, ContractError> {
+        let channel = msg.channel();
+
+        let order = order_from_cw(&channel.order);
+        let connection_hops = connection_hops_from_cw(channel)?;
+        let (port_id, channel_id) = endpoint_from_cw(channel)?;
+        let counterparty = counterparty_from_cw(channel)?;
+
+        let (_, version) = match msg.counterparty_version() {
+            None => {
+                let version = Version::new(channel.version.clone());
+
+                on_chan_open_init_validate(
+                    self,
+                    order,
+                    &connection_hops,
+                    &port_id,
+                    &channel_id,
+                    &counterparty,
+                    &version,
+                )?;
+
+                on_chan_open_init_execute(
+                    self,
+                    order,
+                    &connection_hops,
+                    &port_id,
+                    &channel_id,
+                    &counterparty,
+                    &version,
+                )?
+            }
+            Some(counterparty_version) => {
+                let counterparty_version = Version::new(counterparty_version.to_string());
+
+                on_chan_open_try_validate(
+                    self,
+                    order,
+                    &connection_hops,
+                    &port_id,
+                    &channel_id,
+                    &counterparty,
+                    &counterparty_version,
+                )?;
+
+                on_chan_open_try_execute(
+                    self,
+                    order,
+                    &connection_hops,
+                    &port_id,
+                    &channel_id,
+                    &counterparty,
+                    &counterparty_version,
+                )?
+            }
+        };
+
+        Ok(Some(Ibc3ChannelOpenResponse {
+            version: version.to_string(),
+        }))
+    }
+
+    /// Runs the `OpenAck` and `OpenConfirm` callbacks of the channel
+    /// handshake, and records the opened channel.
+    pub fn channel_connect(
+        &mut self,
+        msg: IbcChannelConnectMsg,
+    ) -> Result<IbcBasicResponse, ContractError> {
+        let channel = msg.channel();
+
+        let (port_id, channel_id) = endpoint_from_cw(channel)?;
+
+        let extras = match msg.counterparty_version() {
+            Some(counterparty_version) => {
+                let counterparty_version = Version::new(counterparty_version.to_string());
+
+                on_chan_open_ack_validate(self, &port_id, &channel_id, &counterparty_version)?;
+                on_chan_open_ack_execute(self, &port_id, &channel_id, &counterparty_version)?
+            }
+            None => {
+                on_chan_open_confirm_validate(self, &port_id, &channel_id)?;
+                on_chan_open_confirm_execute(self, &port_id, &channel_id)?
+            }
+        };
+
+        let channel_info = ChannelInfo {
+            channel_id: channel.endpoint.channel_id.clone(),
+            counterparty_endpoint: channel.counterparty_endpoint.clone(),
+            connection_id: channel.connection_id.clone(),
+        };
+
+        CHANNELS.save(&mut self.store(), &channel_info.channel_id, &channel_info)?;
+
+        self.commit();
+
+        Ok(IbcBasicResponse::new()
+            .add_attribute("action", "channel_connect")
+            .add_attribute("channel_id", channel_info.channel_id)
+            .add_events(events_from_extras(extras)))
+    }
+
+    /// Runs the `CloseInit` and `CloseConfirm` callbacks. ICS-20 channels
+    /// cannot be closed by the contract, but the counterparty may close them.
+    pub fn channel_close(
+        &mut self,
+        msg: IbcChannelCloseMsg,
+    ) -> Result<IbcBasicResponse, ContractError> {
+        let channel = msg.channel();
+
+        let (port_id, channel_id) = endpoint_from_cw(channel)?;
+
+        let extras = match msg {
+            IbcChannelCloseMsg::CloseInit { .. } => {
+                on_chan_close_init_validate(self, &port_id, &channel_id)?;
+                on_chan_close_init_execute(self, &port_id, &channel_id)?
+            }
+            IbcChannelCloseMsg::CloseConfirm { .. } => {
+                on_chan_close_confirm_validate(self, &port_id, &channel_id)?;
+                on_chan_close_confirm_execute(self, &port_id, &channel_id)?
+            }
+        };
+
+        Ok(IbcBasicResponse::new().add_events(events_from_extras(extras)))
+    }
+
+    /// Receives the tokens of a packet, minting vouchers or releasing
+    /// escrowed tokens. A failed reception is acknowledged with an error, so
+    /// that the tokens are refunded on the sending chain, and leaves no state
+    /// behind nor dispatches any message.
+    ///
+    /// The messages of a successful reception are dispatched together by the
+    /// contract to itself, replying on error: the reception is then reverted
+    /// and acknowledged with an error by [`Self::reply`], as the tokens were
+    /// not received.
+    pub fn packet_receive(
+        &mut self,
+        msg: IbcPacketReceiveMsg,
+    ) -> Result<IbcReceiveResponse, ContractError> {
+        let packet = packet_from_cw(&msg.packet)?;
+
+        let (extras, ack) = on_recv_packet_execute(self, &packet);
+
+        let ack_status: AcknowledgementStatus = from_json(ack.as_bytes())?;
+
+        let resp =
+            IbcReceiveResponse::new(ack.as_bytes().to_vec()).add_events(events_from_extras(extras));
+
+        if !ack_status.is_successful() {
+            self.discard();
+
+            return Ok(resp);
+        }
+
+        let undo = self.commit_revertible();
+
+        RECEIVE_UNDO.save(&mut self.store(), &undo)?;
+
+        self.commit();
+
+        let dispatch_msg = WasmMsg::Execute {
+            contract_addr: self.contract_address().to_string(),
+            msg: to_json_binary(&ExecuteMsg::from(DispatchReceivedMsg {
+                msgs: self.take_messages(),
+            }))?,
+            funds: vec![],
+        };
+
+        Ok(resp.add_submessage(SubMsg::reply_on_error(dispatch_msg, RECEIVE_REPLY_ID)))
+    }
+
+    /// Handles the acknowledgement of a sent packet, refunding its tokens if
+    /// the counterparty failed to receive them.
+    pub fn packet_ack(&mut self, msg: IbcPacketAckMsg) -> Result<IbcBasicResponse, ContractError> {
+        let packet = packet_from_cw(&msg.original_packet)?;
+        let ack = Acknowledgement::try_from(msg.acknowledgement.data.to_vec())?;
+        let relayer = Signer::from(msg.relayer.to_string());
+
+        on_acknowledgement_packet_validate(self, &packet, &ack, &relayer)?;
+
+        let (extras, result) = on_acknowledgement_packet_execute(self, &packet, &ack, &relayer);
+
+        result?;
+
+        self.commit();
+
+        Ok(IbcBasicResponse::new()
+            .add_messages(self.take_messages())
+            .add_events(events_from_extras(extras)))
+    }
+
+    /// Handles the timeout of a sent packet, refunding its tokens.
+    pub fn packet_timeout(
+        &mut self,
+        msg: IbcPacketTimeoutMsg,
+    ) -> Result<IbcBasicResponse, ContractError> {
+        let packet = packet_from_cw(&msg.packet)?;
+        let relayer = Signer::from(msg.relayer.to_string());
+
+        on_timeout_packet_validate(self, &packet, &relayer)?;
+
+        let (extras, result) = on_timeout_packet_execute(self, &packet, &relayer);
+
+        result?;
+
+        self.commit();
+
+        Ok(IbcBasicResponse::new()
+            .add_messages(self.take_messages())
+            .add_events(events_from_extras(extras)))
+    }
+}
@@ -0,0 +1,19 @@
+//! The CosmWasm contract implementation of the ICS-20 fungible token transfer
+//! application. It implements the token transfer contexts of
+//! `ibc-app-transfer` over the contract storage and bank messages, and binds
+//! the application callbacks to the CosmWasm IBC entry points, so that the
+//! contract owns the `wasm.<contract address>` port of the hosting chain.
+//!
+//! Native tokens sent to another chain are escrowed by the contract, and the
+//! tokens received from other chains are represented by vouchers, minted
+//! either through the tokenfactory module or by cw20 contracts registered by
+//! the admin.
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+
+pub mod context;
+pub mod entrypoint;
+pub mod handlers;
+pub mod types;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,362 @@
+use std::str::FromStr;
+
+use cosmwasm_schema::serde::de::DeserializeOwned;
+use cosmwasm_std::testing::{message_info, mock_env, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, Coin, CosmosMsg, Deps, DepsMut, IbcAcknowledgement,
+    IbcBasicResponse, IbcChannel, IbcChannelCloseMsg, IbcChannelConnectMsg, IbcChannelOpenMsg,
+    IbcChannelOpenResponse, IbcEndpoint, IbcMsg, IbcOrder, IbcPacket, IbcPacketAckMsg,
+    IbcPacketReceiveMsg, IbcPacketTimeoutMsg, IbcReceiveResponse, IbcTimeout, OwnedDeps, Reply,
+    ReplyOn, Response, SubMsgResult, Timestamp, WasmMsg,
+};
+use ibc_app_transfer::types::error::TokenTransferError;
+use ibc_app_transfer::types::packet::PacketData;
+use ibc_app_transfer::types::{ack_success_b64, PrefixedCoin, VERSION};
+use ibc_core::channel::types::acknowledgement::AcknowledgementStatus;
+
+use crate::entrypoint::{
+    execute, ibc_channel_close, ibc_channel_connect, ibc_channel_open, ibc_packet_ack,
+    ibc_packet_receive, ibc_packet_timeout, instantiate, query, reply,
+};
+use crate::handlers::RECEIVE_REPLY_ID;
+use crate::types::{
+    ContractError, Cw20ReceiveMsg, DispatchReceivedMsg, EscrowedResponse, ExecuteMsg,
+    InstantiateMsg, QueryMsg, TransferMsg, VoucherMinter,
+};
+
+pub type MockDeps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+/// Test fixture of a transfer contract with an ICS-20 channel to a
+/// counterparty chain.
+#[derive(Clone, Debug)]
+pub struct Fixture {
+    pub channel_id: String,
+    pub counterparty_port_id: String,
+    pub counterparty_channel_id: String,
+    pub connection_id: String,
+    pub default_timeout: u64,
+    pub voucher_minter: VoucherMinter,
+}
+
+impl Default for Fixture {
+    fn default() -> Self {
+        Self {
+            channel_id: "channel-0".to_string(),
+            counterparty_port_id: "transfer".to_string(),
+            counterparty_channel_id: "channel-7".to_string(),
+            connection_id: "connection-2".to_string(),
+            default_timeout: 600,
+            voucher_minter: VoucherMinter::TokenFactory,
+        }
+    }
+}
+
+impl Fixture {
+    pub fn addr(deps: &MockDeps, name: &str) -> Addr {
+        deps.api.addr_make(name)
+    }
+
+    pub fn port_id() -> String {
+        format!("wasm.{}", mock_env().contract.address)
+    }
+
+    pub fn endpoint(&self) -> IbcEndpoint {
+        IbcEndpoint {
+            port_id: Self::port_id(),
+            channel_id: self.channel_id.clone(),
+        }
+    }
+
+    pub fn counterparty_endpoint(&self) -> IbcEndpoint {
+        IbcEndpoint {
+            port_id: self.counterparty_port_id.clone(),
+            channel_id: self.counterparty_channel_id.clone(),
+        }
+    }
+
+    pub fn channel(&self, order: IbcOrder, version: &str) -> IbcChannel {
+        IbcChannel::new(
+            self.endpoint(),
+            self.counterparty_endpoint(),
+            order,
+            version,
+            self.connection_id.clone(),
+        )
+    }
+
+    /// Returns the prefix added to the denoms received over the channel.
+    pub fn trace_prefix(&self) -> String {
+        format!("{}/{}", Self::port_id(), self.channel_id)
+    }
+
+    /// Returns the prefix of the denoms sent back by the counterparty.
+    pub fn counterparty_trace_prefix(&self) -> String {
+        format!(
+            "{}/{}",
+            self.counterparty_port_id, self.counterparty_channel_id
+        )
+    }
+
+    pub fn instantiate(&self, deps: DepsMut<'_>) -> Result<Response, ContractError> {
+        let admin = MockApi::default().addr_make("admin");
+
+        let msg = InstantiateMsg {
+            admin: None,
+            default_timeout: self.default_timeout,
+            voucher_minter: self.voucher_minter.clone(),
+        };
+
+        instantiate(deps, mock_env(), message_info(&admin, &[]), msg)
+    }
+
+    pub fn channel_open(
+        &self,
+        deps: DepsMut<'_>,
+        msg: IbcChannelOpenMsg,
+    ) -> Result<IbcChannelOpenResponse, ContractError> {
+        ibc_channel_open(deps, mock_env(), msg)
+    }
+
+    pub fn channel_connect(
+        &self,
+        deps: DepsMut<'_>,
+        msg: IbcChannelConnectMsg,
+    ) -> Result<IbcBasicResponse, ContractError> {
+        ibc_channel_connect(deps, mock_env(), msg)
+    }
+
+    pub fn channel_close(
+        &self,
+        deps: DepsMut<'_>,
+        msg: IbcChannelCloseMsg,
+    ) -> Result<IbcBasicResponse, ContractError> {
+        ibc_channel_close(deps, mock_env(), msg)
+    }
+
+    /// Instantiates the contract and opens the channel from its side.
+    pub fn setup(&self, deps: &mut MockDeps) {
+        self.instantiate(deps.as_mut()).unwrap();
+
+        let channel = self.channel(IbcOrder::Unordered, VERSION);
+
+        self.channel_open(deps.as_mut(), IbcChannelOpenMsg::new_init(channel.clone()))
+            .unwrap();
+
+        self.channel_connect(
+            deps.as_mut(),
+            IbcChannelConnectMsg::new_ack(channel, VERSION),
+        )
+        .unwrap();
+    }
+
+    pub fn execute(
+        &self,
+        deps: DepsMut<'_>,
+        sender: &Addr,
+        funds: &[Coin],
+        msg: impl Into<ExecuteMsg>,
+    ) -> Result<Response, ContractError> {
+        execute(deps, mock_env(), message_info(sender, funds), msg.into())
+    }
+
+    pub fn transfer_msg(&self, receiver: &str) -> TransferMsg {
+        TransferMsg {
+            channel_id: self.channel_id.clone(),
+            receiver: receiver.to_string(),
+            timeout: None,
+            memo: None,
+        }
+    }
+
+    pub fn transfer(
+        &self,
+        deps: DepsMut<'_>,
+        sender: &Addr,
+        funds: &[Coin],
+    ) -> Result<Response, ContractError> {
+        self.execute(
+            deps,
+            sender,
+            funds,
+            self.transfer_msg("counterparty-receiver"),
+        )
+    }
+
+    /// Transfers cw20 vouchers sent to the contract by the given cw20
+    /// contract.
+    pub fn transfer_cw20(
+        &self,
+        deps: DepsMut<'_>,
+        cw20_contract: &Addr,
+        sender: &Addr,
+        amount: u128,
+    ) -> Result<Response, ContractError> {
+        let msg = Cw20ReceiveMsg {
+            sender: sender.to_string(),
+            amount: amount.into(),
+            msg: to_json_binary(&self.transfer_msg("counterparty-receiver")).unwrap(),
+        };
+
+        self.execute(deps, cw20_contract, &[], msg)
+    }
+
+    pub fn packet_data(denom: &str, amount: u64, sender: &str, receiver: &str) -> PacketData {
+        PacketData {
+            token: PrefixedCoin::from_str(&format!("{amount}{denom}")).unwrap(),
+            sender: sender.to_string().into(),
+            receiver: receiver.to_string().into(),
+            memo: "".into(),
+        }
+    }
+
+    /// Returns a packet sent by the counterparty to the contract.
+    pub fn incoming_packet(&self, data: &PacketData) -> IbcPacket {
+        IbcPacket::new(
+            to_json_binary(data).unwrap(),
+            self.counterparty_endpoint(),
+            self.endpoint(),
+            1,
+            IbcTimeout::with_timestamp(Timestamp::from_seconds(u64::MAX / 1_000_000_000)),
+        )
+    }
+
+    /// Returns the packet dispatched by a transfer of the contract.
+    pub fn sent_packet(&self, resp: &Response) -> IbcPacket {
+        let Some(CosmosMsg::Ibc(IbcMsg::SendPacket {
+            channel_id,
+            data,
+            timeout,
+        })) = resp.messages.last().map(|sub_msg| &sub_msg.msg)
+        else {
+            panic!("the transfer must end with a packet");
+        };
+
+        assert_eq!(channel_id, &self.channel_id);
+
+        IbcPacket::new(
+            data.clone(),
+            self.endpoint(),
+            self.counterparty_endpoint(),
+            1,
+            timeout.clone(),
+        )
+    }
+
+    pub fn receive(
+        &self,
+        deps: DepsMut<'_>,
+        data: &PacketData,
+    ) -> Result<IbcReceiveResponse, ContractError> {
+        let msg = IbcPacketReceiveMsg::new(self.incoming_packet(data), Addr::unchecked("relayer"));
+
+        ibc_packet_receive(deps, mock_env(), msg)
+    }
+
+    /// Dispatches the messages of a successful reception, as the contract
+    /// does to itself.
+    pub fn dispatch_received(
+        &self,
+        deps: DepsMut<'_>,
+        resp: &IbcReceiveResponse,
+    ) -> Result<Response, ContractError> {
+        let msg = DispatchReceivedMsg {
+            msgs: received_messages(resp),
+        };
+
+        self.execute(deps, &mock_env().contract.address, &[], msg)
+    }
+
+    /// Replies to the failed dispatch of the messages of a reception.
+    pub fn reply_error(&self, deps: DepsMut<'_>) -> Result<Response, ContractError> {
+        let msg = Reply {
+            id: RECEIVE_REPLY_ID,
+            payload: Default::default(),
+            gas_used: 0,
+            result: SubMsgResult::Err("mint failed".to_string()),
+        };
+
+        reply(deps, mock_env(), msg)
+    }
+
+    pub fn ack(
+        &self,
+        deps: DepsMut<'_>,
+        packet: IbcPacket,
+        success: bool,
+    ) -> Result<IbcBasicResponse, ContractError> {
+        let ack_status = match success {
+            true => AcknowledgementStatus::success(ack_success_b64()),
+            false => AcknowledgementStatus::error(TokenTransferError::InvalidClosedChannel.into()),
+        };
+
+        let ack: Vec<u8> = ack_status.into();
+
+        let msg = IbcPacketAckMsg::new(
+            IbcAcknowledgement::new(ack),
+            packet,
+            Addr::unchecked("relayer"),
+        );
+
+        ibc_packet_ack(deps, mock_env(), msg)
+    }
+
+    pub fn timeout(
+        &self,
+        deps: DepsMut<'_>,
+        packet: IbcPacket,
+    ) -> Result<IbcBasicResponse, ContractError> {
+        let msg = IbcPacketTimeoutMsg::new(packet, Addr::unchecked("relayer"));
+
+        ibc_packet_timeout(deps, mock_env(), msg)
+    }
+
+    pub fn query<T: DeserializeOwned>(&self, deps: Deps<'_>, msg: QueryMsg) -> T {
+        from_json(query(deps, mock_env(), msg).unwrap()).unwrap()
+    }
+
+    pub fn escrowed(&self, deps: Deps<'_>, denom: &str) -> u128 {
+        let resp: EscrowedResponse = self.query(
+            deps,
+            QueryMsg::Escrowed {
+                channel_id: self.channel_id.clone(),
+                denom: denom.to_string(),
+            },
+        );
+
+        resp.amount.u128()
+    }
+}
+
+/// Returns whether the acknowledgement of a received packet is successful.
+pub fn is_successful(resp: &IbcReceiveResponse) -> bool {
+    let ack_status: AcknowledgementStatus =
+        from_json(resp.acknowledgement.as_ref().unwrap()).unwrap();
+
+    ack_status.is_successful()
+}
+
+/// Returns the messages of a successful reception, dispatched by the
+/// contract to itself.
+pub fn received_messages(resp: &IbcReceiveResponse) -> Vec<CosmosMsg> {
+    let [sub_msg] = resp.messages.as_slice() else {
+        panic!("a successful reception must dispatch a single message");
+    };
+
+    assert_eq!(sub_msg.id, RECEIVE_REPLY_ID);
+    assert_eq!(sub_msg.reply_on, ReplyOn::Error);
+
+    let CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr, msg, ..
+    }) = &sub_msg.msg
+    else {
+        panic!("the received tokens must be dispatched by the contract to itself");
+    };
+
+    assert_eq!(contract_addr, mock_env().contract.address.as_str());
+
+    let ExecuteMsg::DispatchReceived(msg) = from_json(msg).unwrap() else {
+        panic!("the received tokens must be dispatched by the contract to itself");
+    };
+
+    msg.msgs
+}
@@ -0,0 +1,546 @@
+use cosmwasm_std::testing::mock_dependencies;
+use cosmwasm_std::{
+    coins, from_json, to_json_binary, BankMsg, CosmosMsg, IbcChannelCloseMsg, IbcChannelConnectMsg,
+    IbcChannelOpenMsg, IbcOrder, SubMsg, WasmMsg,
+};
+use ibc_app_transfer::types::packet::PacketData;
+use ibc_app_transfer::types::VERSION;
+use ibc_core::channel::types::acknowledgement::AcknowledgementStatus;
+use prost::Message;
+
+use crate::types::proto::{MsgBurn, MsgCreateDenom, MsgMint};
+use crate::types::{
+    proto, ChannelInfo, Cw20ExecuteMsg, DenomTraceResponse, DenomTracesResponse,
+    DispatchReceivedMsg, QueryMsg, RegisterCw20VoucherMsg, Voucher, VoucherMinter,
+};
+
+pub mod fixture;
+
+use fixture::{is_successful, received_messages, Fixture};
+
+/// Returns the messages of a response, without their submessage wrapping.
+fn messages<T>(sub_msgs: &[SubMsg<T>]) -> Vec<&CosmosMsg<T>> {
+    sub_msgs.iter().map(|sub_msg| &sub_msg.msg).collect()
+}
+
+fn decode_any<M: Message + Default>(msg: &CosmosMsg, type_url: &str) -> M {
+    let CosmosMsg::Any(any) = msg else {
+        panic!("expected a `CosmosMsg::Any`, found {msg:?}");
+    };
+
+    assert_eq!(any.type_url, type_url);
+
+    M::decode(any.value.as_slice()).unwrap()
+}
+
+#[test]
+fn test_cw_transfer_channel_handshake() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.instantiate(deps.as_mut()).unwrap();
+
+    let channel = fxt.channel(IbcOrder::Unordered, VERSION);
+
+    let resp = fxt
+        .channel_open(deps.as_mut(), IbcChannelOpenMsg::new_init(channel.clone()))
+        .unwrap();
+
+    assert_eq!(resp.unwrap().version, VERSION);
+
+    // The channel must be unordered, of the ICS-20 version, and bound to the
+    // port of the contract.
+    for channel in [
+        fxt.channel(IbcOrder::Ordered, VERSION),
+        fxt.channel(IbcOrder::Unordered, "ics20-2"),
+    ] {
+        assert!(fxt
+            .channel_open(deps.as_mut(), IbcChannelOpenMsg::new_init(channel))
+            .is_err());
+    }
+
+    let mut other_port = channel.clone();
+    other_port.endpoint.port_id = "transfer".to_string();
+
+    assert!(fxt
+        .channel_open(deps.as_mut(), IbcChannelOpenMsg::new_init(other_port))
+        .is_err());
+
+    // The counterparty version is checked on `OpenTry` and `OpenAck`.
+    assert!(fxt
+        .channel_open(
+            deps.as_mut(),
+            IbcChannelOpenMsg::new_try(channel.clone(), "ics20-2")
+        )
+        .is_err());
+
+    assert!(fxt
+        .channel_connect(
+            deps.as_mut(),
+            IbcChannelConnectMsg::new_ack(channel.clone(), "ics20-2")
+        )
+        .is_err());
+
+    fxt.channel_connect(
+        deps.as_mut(),
+        IbcChannelConnectMsg::new_ack(channel.clone(), VERSION),
+    )
+    .unwrap();
+
+    let channel_info: ChannelInfo = fxt.query(
+        deps.as_ref(),
+        QueryMsg::Channel {
+            channel_id: fxt.channel_id.clone(),
+        },
+    );
+
+    assert_eq!(
+        channel_info.counterparty_endpoint,
+        fxt.counterparty_endpoint()
+    );
+
+    // ICS-20 channels can only be closed by the counterparty.
+    assert!(fxt
+        .channel_close(deps.as_mut(), IbcChannelCloseMsg::new_init(channel.clone()))
+        .is_err());
+
+    fxt.channel_close(deps.as_mut(), IbcChannelCloseMsg::new_confirm(channel))
+        .unwrap();
+}
+
+#[test]
+fn test_cw_transfer_escrow_native_tokens() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.setup(&mut deps);
+
+    let sender = Fixture::addr(&deps, "sender");
+    let receiver = Fixture::addr(&deps, "receiver");
+
+    let resp = fxt
+        .transfer(deps.as_mut(), &sender, &coins(100, "uatom"))
+        .unwrap();
+
+    // The tokens sent along with the transfer are escrowed.
+    assert_eq!(resp.messages.len(), 1);
+
+    let packet = fxt.sent_packet(&resp);
+    let packet_data: PacketData = from_json(&packet.data).unwrap();
+
+    assert_eq!(packet_data.token.to_string(), "100uatom");
+    assert_eq!(packet_data.sender.as_ref(), sender.as_str());
+
+    assert_eq!(fxt.escrowed(deps.as_ref(), "uatom"), 100);
+
+    // The tokens returning from the counterparty are released.
+    let denom = format!("{}/uatom", fxt.counterparty_trace_prefix());
+
+    let resp = fxt
+        .receive(
+            deps.as_mut(),
+            &Fixture::packet_data(&denom, 60, "counterparty-sender", receiver.as_str()),
+        )
+        .unwrap();
+
+    assert!(is_successful(&resp));
+
+    assert_eq!(
+        received_messages(&resp),
+        vec![CosmosMsg::Bank(BankMsg::Send {
+            to_address: receiver.to_string(),
+            amount: coins(60, "uatom"),
+        })]
+    );
+
+    assert_eq!(fxt.escrowed(deps.as_ref(), "uatom"), 40);
+
+    // No more than the escrowed tokens can be released.
+    let resp = fxt
+        .receive(
+            deps.as_mut(),
+            &Fixture::packet_data(&denom, 50, "counterparty-sender", receiver.as_str()),
+        )
+        .unwrap();
+
+    assert!(!is_successful(&resp));
+    assert!(resp.messages.is_empty());
+
+    assert_eq!(fxt.escrowed(deps.as_ref(), "uatom"), 40);
+}
+
+#[test]
+fn test_cw_transfer_invalid_transfer_rejected() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.setup(&mut deps);
+
+    let sender = Fixture::addr(&deps, "sender");
+
+    // Exactly one coin must be sent.
+    for funds in [vec![], [coins(1, "uatom"), coins(1, "uosmo")].concat()] {
+        assert!(fxt.transfer(deps.as_mut(), &sender, &funds).is_err());
+    }
+
+    assert!(fxt
+        .transfer(deps.as_mut(), &sender, &coins(0, "uatom"))
+        .is_err());
+
+    // The channel must be opened by the contract.
+    let mut msg = fxt.transfer_msg("counterparty-receiver");
+    msg.channel_id = "channel-1".to_string();
+
+    assert!(fxt
+        .execute(deps.as_mut(), &sender, &coins(1, "uatom"), msg)
+        .is_err());
+
+    // Only vouchers can be received from cw20 contracts.
+    let cw20_contract = Fixture::addr(&deps, "cw20");
+
+    assert!(fxt
+        .transfer_cw20(deps.as_mut(), &cw20_contract, &sender, 1)
+        .is_err());
+}
+
+#[test]
+fn test_cw_transfer_tokenfactory_vouchers() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.setup(&mut deps);
+
+    let receiver = Fixture::addr(&deps, "receiver");
+
+    let resp = fxt
+        .receive(
+            deps.as_mut(),
+            &Fixture::packet_data("uosmo", 100, "counterparty-sender", receiver.as_str()),
+        )
+        .unwrap();
+
+    assert!(is_successful(&resp));
+
+    // The voucher denom is created on the first reception of a denom trace.
+    let denom_traces: DenomTracesResponse = fxt.query(
+        deps.as_ref(),
+        QueryMsg::DenomTraces {
+            start_after: None,
+            limit: None,
+        },
+    );
+
+    let [denom_trace] = denom_traces.denom_traces.as_slice() else {
+        panic!("a single denom trace must be recorded");
+    };
+
+    assert_eq!(denom_trace.path, fxt.trace_prefix());
+    assert_eq!(denom_trace.base_denom, "uosmo");
+
+    let Some(Voucher::Native { denom: voucher }) = denom_trace.voucher.clone() else {
+        panic!("the voucher must be a tokenfactory denom");
+    };
+
+    let resp_messages = received_messages(&resp);
+
+    assert_eq!(resp_messages.len(), 3);
+
+    let create_denom: MsgCreateDenom =
+        decode_any(&resp_messages[0], proto::MSG_CREATE_DENOM_TYPE_URL);
+
+    assert_eq!(
+        voucher,
+        format!("factory/{}/{}", create_denom.sender, create_denom.subdenom)
+    );
+
+    let mint: MsgMint = decode_any(&resp_messages[1], proto::MSG_MINT_TYPE_URL);
+
+    assert_eq!(mint.amount.unwrap().amount, "100");
+
+    assert_eq!(
+        resp_messages[2],
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: receiver.to_string(),
+            amount: coins(100, &voucher),
+        })
+    );
+
+    // The denom trace is also queried through its `ibc/` denom.
+    let by_ibc_denom: DenomTraceResponse = fxt.query(
+        deps.as_ref(),
+        QueryMsg::DenomTrace {
+            hash: format!("ibc/{}", denom_trace.hash),
+        },
+    );
+
+    assert_eq!(&by_ibc_denom, denom_trace);
+
+    // The following receptions only mint vouchers.
+    let resp = fxt
+        .receive(
+            deps.as_mut(),
+            &Fixture::packet_data("uosmo", 20, "counterparty-sender", receiver.as_str()),
+        )
+        .unwrap();
+
+    assert_eq!(received_messages(&resp).len(), 2);
+
+    // The vouchers returning to their source are burned.
+    let resp = fxt
+        .transfer(deps.as_mut(), &receiver, &coins(70, &voucher))
+        .unwrap();
+
+    let burn: MsgBurn = decode_any(&resp.messages[0].msg, proto::MSG_BURN_TYPE_URL);
+
+    assert_eq!(burn.amount.unwrap().amount, "70");
+
+    let packet_data: PacketData = from_json(&fxt.sent_packet(&resp).data).unwrap();
+
+    assert_eq!(
+        packet_data.token.to_string(),
+        format!("70{}/uosmo", fxt.trace_prefix())
+    );
+
+    assert_eq!(fxt.escrowed(deps.as_ref(), "uosmo"), 0);
+}
+
+#[test]
+fn test_cw_transfer_cw20_vouchers() {
+    let fxt = Fixture {
+        voucher_minter: VoucherMinter::Cw20,
+        ..Default::default()
+    };
+
+    let mut deps = mock_dependencies();
+
+    fxt.setup(&mut deps);
+
+    let admin = Fixture::addr(&deps, "admin");
+    let receiver = Fixture::addr(&deps, "receiver");
+    let cw20_contract = Fixture::addr(&deps, "cw20");
+
+    let packet_data = Fixture::packet_data("uosmo", 100, "counterparty-sender", receiver.as_str());
+
+    // The tokens of an unregistered denom trace are refused.
+    let resp = fxt.receive(deps.as_mut(), &packet_data).unwrap();
+
+    assert!(!is_successful(&resp));
+    assert!(resp.messages.is_empty());
+
+    // Only the admin registers the voucher contracts.
+    let register_msg = RegisterCw20VoucherMsg {
+        denom: format!("{}/uosmo", fxt.trace_prefix()),
+        contract: cw20_contract.to_string(),
+    };
+
+    assert!(fxt
+        .execute(deps.as_mut(), &receiver, &[], register_msg.clone())
+        .is_err());
+
+    fxt.execute(deps.as_mut(), &admin, &[], register_msg.clone())
+        .unwrap();
+
+    assert!(fxt
+        .execute(deps.as_mut(), &admin, &[], register_msg)
+        .is_err());
+
+    let resp = fxt.receive(deps.as_mut(), &packet_data).unwrap();
+
+    assert!(is_successful(&resp));
+
+    assert_eq!(
+        received_messages(&resp),
+        vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: cw20_contract.to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Mint {
+                recipient: receiver.to_string(),
+                amount: 100u128.into(),
+            })
+            .unwrap(),
+            funds: vec![],
+        })]
+    );
+
+    // The vouchers sent to the contract through the cw20 contract are burned.
+    let resp = fxt
+        .transfer_cw20(deps.as_mut(), &cw20_contract, &receiver, 70)
+        .unwrap();
+
+    assert_eq!(
+        resp.messages[0].msg,
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: cw20_contract.to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Burn {
+                amount: 70u128.into()
+            })
+            .unwrap(),
+            funds: vec![],
+        })
+    );
+
+    let packet_data: PacketData = from_json(&fxt.sent_packet(&resp).data).unwrap();
+
+    assert_eq!(packet_data.sender.as_ref(), receiver.as_str());
+    assert_eq!(
+        packet_data.token.to_string(),
+        format!("70{}/uosmo", fxt.trace_prefix())
+    );
+}
+
+#[test]
+fn test_cw_transfer_error_ack_on_failed_dispatch() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.setup(&mut deps);
+
+    let sender = Fixture::addr(&deps, "sender");
+    let receiver = Fixture::addr(&deps, "receiver");
+
+    // A reception whose messages fail, such as a voucher mint refused by the
+    // tokenfactory module, is reverted and acknowledged with an error.
+    let resp = fxt
+        .receive(
+            deps.as_mut(),
+            &Fixture::packet_data("uosmo", 100, "counterparty-sender", receiver.as_str()),
+        )
+        .unwrap();
+
+    assert!(is_successful(&resp));
+
+    let resp = fxt.reply_error(deps.as_mut()).unwrap();
+
+    let ack_status: AcknowledgementStatus = from_json(resp.data.unwrap()).unwrap();
+
+    assert!(!ack_status.is_successful());
+
+    let denom_traces: DenomTracesResponse = fxt.query(
+        deps.as_ref(),
+        QueryMsg::DenomTraces {
+            start_after: None,
+            limit: None,
+        },
+    );
+
+    assert!(denom_traces.denom_traces.is_empty());
+
+    // The escrow of the released tokens is restored as well.
+    fxt.transfer(deps.as_mut(), &sender, &coins(100, "uatom"))
+        .unwrap();
+
+    let denom = format!("{}/uatom", fxt.counterparty_trace_prefix());
+    let packet_data = Fixture::packet_data(&denom, 60, "counterparty-sender", receiver.as_str());
+
+    fxt.receive(deps.as_mut(), &packet_data).unwrap();
+
+    assert_eq!(fxt.escrowed(deps.as_ref(), "uatom"), 40);
+
+    fxt.reply_error(deps.as_mut()).unwrap();
+
+    assert_eq!(fxt.escrowed(deps.as_ref(), "uatom"), 100);
+
+    // Only the contract dispatches the messages of a reception, which is kept
+    // once they succeed.
+    let resp = fxt.receive(deps.as_mut(), &packet_data).unwrap();
+
+    let dispatch_msg = DispatchReceivedMsg {
+        msgs: received_messages(&resp),
+    };
+
+    assert!(fxt
+        .execute(deps.as_mut(), &receiver, &[], dispatch_msg)
+        .is_err());
+
+    fxt.dispatch_received(deps.as_mut(), &resp).unwrap();
+
+    assert_eq!(fxt.escrowed(deps.as_ref(), "uatom"), 40);
+}
+
+#[test]
+fn test_cw_transfer_refund_on_error_ack() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.setup(&mut deps);
+
+    let sender = Fixture::addr(&deps, "sender");
+
+    let resp = fxt
+        .transfer(deps.as_mut(), &sender, &coins(100, "uatom"))
+        .unwrap();
+
+    let packet = fxt.sent_packet(&resp);
+
+    // A successful acknowledgement keeps the tokens escrowed.
+    let resp = fxt.ack(deps.as_mut(), packet.clone(), true).unwrap();
+
+    assert!(resp.messages.is_empty());
+    assert_eq!(fxt.escrowed(deps.as_ref(), "uatom"), 100);
+
+    // An error acknowledgement refunds them.
+    let resp = fxt.ack(deps.as_mut(), packet, false).unwrap();
+
+    assert_eq!(
+        messages(&resp.messages),
+        vec![&CosmosMsg::Bank(BankMsg::Send {
+            to_address: sender.to_string(),
+            amount: coins(100, "uatom"),
+        })]
+    );
+
+    assert_eq!(fxt.escrowed(deps.as_ref(), "uatom"), 0);
+}
+
+#[test]
+fn test_cw_transfer_refund_on_timeout() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.setup(&mut deps);
+
+    let sender = Fixture::addr(&deps, "sender");
+
+    fxt.receive(
+        deps.as_mut(),
+        &Fixture::packet_data("uosmo", 100, "counterparty-sender", sender.as_str()),
+    )
+    .unwrap();
+
+    let denom_traces: DenomTracesResponse = fxt.query(
+        deps.as_ref(),
+        QueryMsg::DenomTraces {
+            start_after: None,
+            limit: None,
+        },
+    );
+
+    let voucher = denom_traces.denom_traces[0].voucher.clone().unwrap();
+
+    let resp = fxt
+        .transfer(deps.as_mut(), &sender, &coins(100, voucher.local_denom()))
+        .unwrap();
+
+    // The burned vouchers are minted back to the sender.
+    let resp = fxt.timeout(deps.as_mut(), fxt.sent_packet(&resp)).unwrap();
+
+    let resp_messages = messages(&resp.messages);
+
+    let mint: MsgMint = decode_any(resp_messages[0], proto::MSG_MINT_TYPE_URL);
+
+    assert_eq!(mint.amount.unwrap().denom, voucher.local_denom());
+
+    assert_eq!(
+        resp_messages[1],
+        &CosmosMsg::Bank(BankMsg::Send {
+            to_address: sender.to_string(),
+            amount: coins(100, voucher.local_denom()),
+        })
+    );
+}
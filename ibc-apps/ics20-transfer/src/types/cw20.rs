@@ -0,0 +1,21 @@
+//! Defines the subset of the cw20 messages exchanged with the voucher
+//! contracts.
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Binary, Uint128};
+
+/// The message sent by a cw20 contract to the contract when tokens are
+/// sent to it with `Send`.
+#[cw_serde]
+pub struct Cw20ReceiveMsg {
+    pub sender: String,
+    pub amount: Uint128,
+    pub msg: Binary,
+}
+
+/// The cw20 messages executed by the contract on the voucher contracts.
+#[cw_serde]
+pub enum Cw20ExecuteMsg {
+    Mint { recipient: String, amount: Uint128 },
+    Burn { amount: Uint128 },
+    Transfer { recipient: String, amount: Uint128 },
+}
@@ -0,0 +1,66 @@
+use cosmwasm_std::{StdError, Uint128};
+use derive_more::{Display, From};
+use ibc_app_transfer::types::error::TokenTransferError;
+use ibc_core::host::types::error::{DecodingError, HostError, IdentifierError};
+
+#[derive(From, Display, Debug)]
+pub enum ContractError {
+    #[from]
+    #[display("CosmWasm standard error: {_0}")]
+    Std(StdError),
+    #[from]
+    #[display("CosmWasm hosting error: {_0}")]
+    Host(HostError),
+    #[from]
+    #[display("IBC token transfer error: {_0}")]
+    Transfer(TokenTransferError),
+    #[from]
+    #[display("IBC identifier error: {_0}")]
+    Identifier(IdentifierError),
+    #[from]
+    #[display("IBC decoding error: {_0}")]
+    Decoding(DecodingError),
+    #[display("unauthorized sender `{sender}`")]
+    Unauthorized { sender: String },
+    #[display("exactly one coin must be sent, found {count}")]
+    InvalidFunds { count: usize },
+    #[display("transfer amount must not be zero")]
+    ZeroAmount,
+    #[display("transfer receiver must not be empty")]
+    EmptyReceiver,
+    #[display("unknown channel `{channel_id}`")]
+    UnknownChannel { channel_id: String },
+    #[display("`{contract}` is not a registered cw20 voucher")]
+    UnknownCw20Voucher { contract: String },
+    #[display("no voucher is registered for `{denom}`")]
+    MissingVoucher { denom: String },
+    #[display("a voucher is already registered for `{denom}`")]
+    VoucherAlreadyRegistered { denom: String },
+    #[display("vouchers are minted by the tokenfactory module, not by cw20 contracts")]
+    Cw20VouchersDisabled,
+    #[display("amount {amount} does not fit in 128 bits")]
+    AmountOverflow { amount: String },
+    #[display("unknown reply `{id}`")]
+    UnknownReply { id: u64 },
+    #[display("insufficient escrow of `{denom}`: {escrowed} < {amount}")]
+    InsufficientEscrow {
+        denom: String,
+        escrowed: Uint128,
+        amount: Uint128,
+    },
+}
+
+impl From<ContractError> for StdError {
+    fn from(err: ContractError) -> Self {
+        Self::generic_err(err.to_string())
+    }
+}
+
+impl From<ContractError> for HostError {
+    fn from(err: ContractError) -> Self {
+        match err {
+            ContractError::Host(err) => err,
+            err => Self::invalid_state(err),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use std::str::FromStr;
+
+use cosmwasm_std::{HexBinary, Uint128};
+use ibc_app_transfer::types::{Amount, PrefixedDenom};
+use sha2::{Digest, Sha256};
+
+use super::error::ContractError;
+
+/// Returns the hash identifying a denom trace: the uppercase hex SHA-256
+/// hash of its full path, as in the `ibc/<hash>` denoms of ibc-go.
+pub fn denom_trace_hash(denom: &PrefixedDenom) -> String {
+    HexBinary::from(Sha256::digest(denom.to_string()).as_slice())
+        .to_hex()
+        .to_uppercase()
+}
+
+/// Converts an ICS-20 amount into a [`Uint128`], the amount type of the
+/// CosmWasm bank and cw20 messages.
+pub fn amount_to_uint128(amount: &Amount) -> Result<Uint128, ContractError> {
+    Uint128::from_str(&amount.to_string()).map_err(|_| ContractError::AmountOverflow {
+        amount: amount.to_string(),
+    })
+}
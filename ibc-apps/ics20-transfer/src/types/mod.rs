@@ -0,0 +1,14 @@
+mod cw20;
+mod error;
+mod helper;
+mod msgs;
+pub mod proto;
+mod response;
+mod state;
+
+pub use cw20::*;
+pub use error::*;
+pub use helper::*;
+pub use msgs::*;
+pub use response::*;
+pub use state::*;
@@ -0,0 +1,86 @@
+//! Defines the messages sent to the CosmWasm contract by its users.
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::CosmosMsg;
+
+use super::cw20::Cw20ReceiveMsg;
+use super::response::{DenomTraceResponse, DenomTracesResponse, EscrowedResponse};
+use super::state::{ChannelInfo, Config, VoucherMinter};
+
+// ------------------------------------------------------------
+// Implementation of the InstantiateMsg struct
+// ------------------------------------------------------------
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The admin of the contract, which defaults to its instantiator.
+    pub admin: Option<String>,
+    /// The timeout of the transfers that do not set one, in seconds.
+    pub default_timeout: u64,
+    pub voucher_minter: VoucherMinter,
+}
+
+// ------------------------------------------------------------
+// Implementation of the ExecuteMsg enum and its variants
+// ------------------------------------------------------------
+
+#[derive(derive_more::From)]
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Transfers the single coin sent along with the message.
+    Transfer(TransferMsg),
+    /// Transfers the cw20 vouchers sent to the contract, with a
+    /// [`TransferMsg`] as the message of the cw20 `Send`.
+    Receive(Cw20ReceiveMsg),
+    /// Registers the cw20 contract minting the vouchers of a denom trace.
+    /// Only the admin may register vouchers.
+    RegisterCw20Voucher(RegisterCw20VoucherMsg),
+    /// Dispatches the messages of a received packet. Only the contract
+    /// itself may send it, so that the messages succeed or fail together.
+    DispatchReceived(DispatchReceivedMsg),
+}
+
+#[cw_serde]
+pub struct TransferMsg {
+    pub channel_id: String,
+    /// The receiver of the tokens on the counterparty chain.
+    pub receiver: String,
+    /// The timeout of the transfer in seconds, which defaults to the one of
+    /// the contract configuration.
+    pub timeout: Option<u64>,
+    pub memo: Option<String>,
+}
+
+#[cw_serde]
+pub struct RegisterCw20VoucherMsg {
+    /// The full denom trace, such as `wasm.<contract>/channel-0/uatom`.
+    pub denom: String,
+    pub contract: String,
+}
+
+#[cw_serde]
+pub struct DispatchReceivedMsg {
+    pub msgs: Vec<CosmosMsg>,
+}
+
+// ------------------------------------------------------------
+// Implementation of the QueryMsg enum and its variants
+// ------------------------------------------------------------
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(Config)]
+    Config {},
+    #[returns(ChannelInfo)]
+    Channel { channel_id: String },
+    /// Returns the denom trace of a hash, with or without its `ibc/` prefix.
+    #[returns(DenomTraceResponse)]
+    DenomTrace { hash: String },
+    #[returns(DenomTracesResponse)]
+    DenomTraces {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(EscrowedResponse)]
+    Escrowed { channel_id: String, denom: String },
+}
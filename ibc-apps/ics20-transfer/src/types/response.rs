@@ -0,0 +1,29 @@
+//! Contains the response types for the CosmWasm contract.
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Uint128;
+
+use super::state::Voucher;
+
+/// The response to [`super::msgs::QueryMsg::DenomTrace`]
+#[cw_serde]
+pub struct DenomTraceResponse {
+    /// The uppercase hex SHA-256 hash of the full denom trace
+    pub hash: String,
+    /// The trace path, such as `wasm.<contract>/channel-0`
+    pub path: String,
+    pub base_denom: String,
+    /// The local representation of the tokens, once minted
+    pub voucher: Option<Voucher>,
+}
+
+/// The response to [`super::msgs::QueryMsg::DenomTraces`]
+#[cw_serde]
+pub struct DenomTracesResponse {
+    pub denom_traces: Vec<DenomTraceResponse>,
+}
+
+/// The response to [`super::msgs::QueryMsg::Escrowed`]
+#[cw_serde]
+pub struct EscrowedResponse {
+    pub amount: Uint128,
+}
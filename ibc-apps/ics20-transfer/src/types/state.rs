@@ -0,0 +1,49 @@
+//! Defines the configuration and records kept in the contract storage.
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, IbcEndpoint};
+
+#[cw_serde]
+pub struct Config {
+    /// The account allowed to register cw20 vouchers.
+    pub admin: Addr,
+    /// The timeout of the transfers that do not set one, in seconds.
+    pub default_timeout: u64,
+    /// How the vouchers of the tokens received from other chains are minted.
+    pub voucher_minter: VoucherMinter,
+}
+
+#[cw_serde]
+pub enum VoucherMinter {
+    /// Vouchers are native tokens created by the contract through the
+    /// tokenfactory module, under the `factory/<contract address>/` prefix.
+    TokenFactory,
+    /// Vouchers are cw20 tokens, minted by contracts registered by the admin
+    /// for each denom trace. The contract must be a minter of these tokens.
+    Cw20,
+}
+
+/// The local representation of the tokens received from other chains.
+#[cw_serde]
+pub enum Voucher {
+    Native { denom: String },
+    Cw20 { contract: Addr },
+}
+
+impl Voucher {
+    /// Returns the key under which the voucher is indexed: its native denom,
+    /// or the address of its cw20 contract.
+    pub fn local_denom(&self) -> &str {
+        match self {
+            Self::Native { denom } => denom,
+            Self::Cw20 { contract } => contract.as_str(),
+        }
+    }
+}
+
+/// An ICS-20 channel opened by the contract.
+#[cw_serde]
+pub struct ChannelInfo {
+    pub channel_id: String,
+    pub counterparty_endpoint: IbcEndpoint,
+    pub connection_id: String,
+}
@@ -0,0 +1,81 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{to_json_binary, Addr, SubMsg, WasmMsg};
+use ibc_app_ica_controller_cw::api::IcaCallbacks;
+use ibc_app_ica_controller_cw::context::IcaContext;
+use ibc_app_ica_controller_cw::types::{AccountInfo, ContractError, PacketOutcome, PendingPacket};
+
+/// The identifier of the replies to the callbacks of the owners.
+pub const CALLBACK_REPLY_ID: u64 = 1;
+
+/// The message executed on the owners that are contracts.
+#[cw_serde]
+pub enum OwnerCallbackMsg {
+    IcaCallback(IcaCallback),
+}
+
+#[cw_serde]
+pub enum IcaCallback {
+    /// The interchain account of the owner is open.
+    AccountOpen { account: AccountInfo },
+    /// A transaction of the owner was acknowledged or timed out.
+    PacketOutcome {
+        packet: PendingPacket,
+        outcome: PacketOutcome,
+    },
+}
+
+/// A unit struct that represents the callbacks of the example contract,
+/// which forward the lifecycle of the accounts and the outcome of their
+/// transactions to their owners, if contracts.
+#[derive(Clone, Debug)]
+pub struct OwnerCallbacks;
+
+impl OwnerCallbacks {
+    /// Returns the callback of an owner, which is not sent to the owners
+    /// that are not contracts. A failing callback is caught by the reply of
+    /// the contract, so that the owner cannot block the acknowledgements and
+    /// timeouts of its transactions.
+    fn callback(
+        ctx: &IcaContext<'_, Self>,
+        owner: &Addr,
+        callback: IcaCallback,
+    ) -> Result<Vec<SubMsg>, ContractError> {
+        if ctx.querier().query_wasm_contract_info(owner).is_err() {
+            return Ok(Vec::new());
+        }
+
+        let msg = WasmMsg::Execute {
+            contract_addr: owner.to_string(),
+            msg: to_json_binary(&OwnerCallbackMsg::IcaCallback(callback))?,
+            funds: vec![],
+        };
+
+        Ok(vec![SubMsg::reply_on_error(msg, CALLBACK_REPLY_ID)])
+    }
+}
+
+impl IcaCallbacks for OwnerCallbacks {
+    fn on_account_open(
+        ctx: &mut IcaContext<'_, Self>,
+        account: &AccountInfo,
+    ) -> Result<Vec<SubMsg>, ContractError> {
+        let callback = IcaCallback::AccountOpen {
+            account: account.clone(),
+        };
+
+        Self::callback(ctx, &account.owner, callback)
+    }
+
+    fn on_packet_outcome(
+        ctx: &mut IcaContext<'_, Self>,
+        packet: &PendingPacket,
+        outcome: &PacketOutcome,
+    ) -> Result<Vec<SubMsg>, ContractError> {
+        let callback = IcaCallback::PacketOutcome {
+            packet: packet.clone(),
+            outcome: outcome.clone(),
+        };
+
+        Self::callback(ctx, &packet.owner, callback)
+    }
+}
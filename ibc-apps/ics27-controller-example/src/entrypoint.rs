@@ -0,0 +1,111 @@
+use cosmwasm_std::{
+    Binary, Deps, DepsMut, Env, IbcBasicResponse, IbcChannelCloseMsg, IbcChannelConnectMsg,
+    IbcChannelOpenMsg, IbcChannelOpenResponse, IbcPacketAckMsg, IbcPacketReceiveMsg,
+    IbcPacketTimeoutMsg, IbcReceiveResponse, MessageInfo, Reply, Response, SubMsgResult,
+};
+use ibc_app_ica_controller_cw::context::IcaContext;
+use ibc_app_ica_controller_cw::types::{ContractError, ExecuteMsg, InstantiateMsg, QueryMsg};
+
+use crate::callbacks::OwnerCallbacks;
+
+pub type ControllerContext<'a> = IcaContext<'a, OwnerCallbacks>;
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+pub fn instantiate(
+    deps: DepsMut<'_>,
+    env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let mut ctx = ControllerContext::new_mut(deps, env);
+    ctx.instantiate(msg)
+}
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+pub fn execute(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    let mut ctx = ControllerContext::new_mut(deps, env);
+    ctx.execute(info, msg)
+}
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+pub fn query(deps: Deps<'_>, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+    let ctx = ControllerContext::new_ref(deps, env);
+    ctx.query(msg)
+}
+
+/// Records the failures of the callbacks of the owners, which are the only
+/// sub-messages replied to.
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+pub fn reply(_deps: DepsMut<'_>, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    let resp = match msg.result {
+        SubMsgResult::Ok(_) => Response::default(),
+        SubMsgResult::Err(error) => Response::default().add_attribute("callback_error", error),
+    };
+
+    Ok(resp)
+}
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+pub fn ibc_channel_open(
+    deps: DepsMut<'_>,
+    env: Env,
+    msg: IbcChannelOpenMsg,
+) -> Result<IbcChannelOpenResponse, ContractError> {
+    let mut ctx = ControllerContext::new_mut(deps, env);
+    ctx.channel_open(msg)
+}
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+pub fn ibc_channel_connect(
+    deps: DepsMut<'_>,
+    env: Env,
+    msg: IbcChannelConnectMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let mut ctx = ControllerContext::new_mut(deps, env);
+    ctx.channel_connect(msg)
+}
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+pub fn ibc_channel_close(
+    deps: DepsMut<'_>,
+    env: Env,
+    msg: IbcChannelCloseMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let mut ctx = ControllerContext::new_mut(deps, env);
+    ctx.channel_close(msg)
+}
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+pub fn ibc_packet_receive(
+    deps: DepsMut<'_>,
+    env: Env,
+    msg: IbcPacketReceiveMsg,
+) -> Result<IbcReceiveResponse, ContractError> {
+    let mut ctx = ControllerContext::new_mut(deps, env);
+    ctx.packet_receive(msg)
+}
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+pub fn ibc_packet_ack(
+    deps: DepsMut<'_>,
+    env: Env,
+    msg: IbcPacketAckMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let mut ctx = ControllerContext::new_mut(deps, env);
+    ctx.packet_ack(msg)
+}
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+pub fn ibc_packet_timeout(
+    deps: DepsMut<'_>,
+    env: Env,
+    msg: IbcPacketTimeoutMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let mut ctx = ControllerContext::new_mut(deps, env);
+    ctx.packet_timeout(msg)
+}
@@ -0,0 +1,12 @@
+//! An example CosmWasm contract of an ICS-27 interchain accounts controller,
+//! built using `ibc-app-ica-controller-cw`. Any address may register an
+//! interchain account on a connection and send transactions to it, and the
+//! owners that are contracts, such as DAOs, are called back with the outcome
+//! of their transactions.
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+
+pub mod callbacks;
+pub mod entrypoint;
+
+#[cfg(test)]
+mod tests;
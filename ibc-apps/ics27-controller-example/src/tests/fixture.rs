@@ -0,0 +1,259 @@
+use cosmwasm_schema::serde::de::DeserializeOwned;
+use cosmwasm_std::testing::{message_info, mock_env, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{
+    from_json, to_json_binary, to_json_string, Addr, AnyMsg, Binary, ContractInfoResponse,
+    ContractResult, CosmosMsg, Deps, DepsMut, IbcAcknowledgement, IbcBasicResponse, IbcChannel,
+    IbcChannelConnectMsg, IbcChannelOpenMsg, IbcChannelOpenResponse, IbcEndpoint, IbcMsg, IbcOrder,
+    IbcPacket, IbcPacketAckMsg, IbcPacketTimeoutMsg, OwnedDeps, Response, SystemError,
+    SystemResult, WasmQuery,
+};
+use ibc_app_ica_controller_cw::types::{
+    ContractError, ExecuteMsg, InstantiateMsg, Metadata, QueryMsg, TxMsgData, HOST_PORT_ID,
+};
+use ibc_core::channel::types::acknowledgement::{AcknowledgementStatus, StatusValue};
+use ibc_core::primitives::proto::Any;
+use prost::Message;
+
+use crate::entrypoint::{
+    execute, ibc_channel_connect, ibc_channel_open, ibc_packet_ack, ibc_packet_timeout,
+    instantiate, query,
+};
+
+pub type MockDeps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+/// Test fixture of a controller contract with a connection to a host chain.
+#[derive(Clone, Debug)]
+pub struct Fixture {
+    pub connection_id: String,
+    pub counterparty_connection_id: String,
+    pub counterparty_channel_id: String,
+    pub account_address: String,
+    pub default_timeout: u64,
+}
+
+impl Default for Fixture {
+    fn default() -> Self {
+        Self {
+            connection_id: "connection-0".to_string(),
+            counterparty_connection_id: "connection-4".to_string(),
+            counterparty_channel_id: "channel-9".to_string(),
+            account_address: "cosmos1interchainaccount".to_string(),
+            default_timeout: 600,
+        }
+    }
+}
+
+impl Fixture {
+    pub fn addr(deps: &MockDeps, name: &str) -> Addr {
+        deps.api.addr_make(name)
+    }
+
+    pub fn port_id() -> String {
+        format!("wasm.{}", mock_env().contract.address)
+    }
+
+    /// Makes the querier report the given addresses as contracts.
+    pub fn set_contracts(deps: &mut MockDeps, contracts: Vec<Addr>) {
+        deps.querier.update_wasm(move |query| match query {
+            WasmQuery::ContractInfo { contract_addr }
+                if contracts.iter().any(|c| c.as_str() == contract_addr) =>
+            {
+                let info =
+                    ContractInfoResponse::new(1, Addr::unchecked("creator"), None, false, None);
+
+                SystemResult::Ok(ContractResult::Ok(to_json_binary(&info).unwrap()))
+            }
+            _ => SystemResult::Err(SystemError::NoSuchContract {
+                addr: "unknown".to_string(),
+            }),
+        });
+    }
+
+    pub fn instantiate(&self, deps: DepsMut<'_>) -> Result<Response, ContractError> {
+        let admin = MockApi::default().addr_make("admin");
+
+        let msg = InstantiateMsg {
+            default_timeout: self.default_timeout,
+        };
+
+        instantiate(deps, mock_env(), message_info(&admin, &[]), msg)
+    }
+
+    pub fn execute(
+        &self,
+        deps: DepsMut<'_>,
+        sender: &Addr,
+        msg: ExecuteMsg,
+    ) -> Result<Response, ContractError> {
+        execute(deps, mock_env(), message_info(sender, &[]), msg)
+    }
+
+    pub fn register(&self, deps: DepsMut<'_>, owner: &Addr) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::RegisterAccount {
+            connection_id: self.connection_id.clone(),
+            counterparty_connection_id: self.counterparty_connection_id.clone(),
+        };
+
+        self.execute(deps, owner, msg)
+    }
+
+    /// Returns the metadata of the channels, with the address set by the
+    /// host chain on `OpenTry`.
+    pub fn metadata(&self, address: &str) -> Metadata {
+        Metadata {
+            address: address.to_string(),
+            ..Metadata::new(
+                self.connection_id.clone(),
+                self.counterparty_connection_id.clone(),
+            )
+        }
+    }
+
+    pub fn endpoint(channel_id: &str) -> IbcEndpoint {
+        IbcEndpoint {
+            port_id: Self::port_id(),
+            channel_id: channel_id.to_string(),
+        }
+    }
+
+    pub fn counterparty_endpoint(&self) -> IbcEndpoint {
+        IbcEndpoint {
+            port_id: HOST_PORT_ID.to_string(),
+            channel_id: self.counterparty_channel_id.clone(),
+        }
+    }
+
+    pub fn channel(&self, channel_id: &str, order: IbcOrder) -> IbcChannel {
+        IbcChannel::new(
+            Self::endpoint(channel_id),
+            self.counterparty_endpoint(),
+            order,
+            to_json_string(&self.metadata("")).unwrap(),
+            self.connection_id.clone(),
+        )
+    }
+
+    pub fn channel_open_init(
+        &self,
+        deps: DepsMut<'_>,
+        channel_id: &str,
+    ) -> Result<IbcChannelOpenResponse, ContractError> {
+        let msg = IbcChannelOpenMsg::new_init(self.channel(channel_id, IbcOrder::Ordered));
+
+        ibc_channel_open(deps, mock_env(), msg)
+    }
+
+    pub fn channel_open_ack(
+        &self,
+        deps: DepsMut<'_>,
+        channel_id: &str,
+        address: &str,
+    ) -> Result<IbcBasicResponse, ContractError> {
+        let msg = IbcChannelConnectMsg::new_ack(
+            self.channel(channel_id, IbcOrder::Ordered),
+            to_json_string(&self.metadata(address)).unwrap(),
+        );
+
+        ibc_channel_connect(deps, mock_env(), msg)
+    }
+
+    /// Registers the account of the owner and opens its channel.
+    pub fn setup(&self, deps: &mut MockDeps, owner: &Addr, channel_id: &str) {
+        self.register(deps.as_mut(), owner).unwrap();
+
+        self.channel_open_init(deps.as_mut(), channel_id).unwrap();
+
+        self.channel_open_ack(deps.as_mut(), channel_id, &self.account_address)
+            .unwrap();
+    }
+
+    pub fn send_tx(
+        &self,
+        deps: DepsMut<'_>,
+        sender: &Addr,
+        messages: Vec<AnyMsg>,
+    ) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::SendTx {
+            connection_id: self.connection_id.clone(),
+            messages,
+            memo: Some("memo".to_string()),
+            timeout: None,
+        };
+
+        self.execute(deps, sender, msg)
+    }
+
+    pub fn bank_send_msg() -> AnyMsg {
+        AnyMsg {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: vec![1, 2, 3].into(),
+        }
+    }
+
+    /// Returns the packet dispatched by a transaction of the contract.
+    pub fn sent_packet(&self, resp: &Response, sequence: u64) -> IbcPacket {
+        let Some(CosmosMsg::Ibc(IbcMsg::SendPacket {
+            channel_id,
+            data,
+            timeout,
+        })) = resp.messages.last().map(|sub_msg| &sub_msg.msg)
+        else {
+            panic!("the transaction must end with a packet");
+        };
+
+        IbcPacket::new(
+            data.clone(),
+            Self::endpoint(channel_id),
+            self.counterparty_endpoint(),
+            sequence,
+            timeout.clone(),
+        )
+    }
+
+    /// Acknowledges a packet with the result of a transaction whose messages
+    /// had the given responses, or with an error.
+    pub fn ack(
+        &self,
+        deps: DepsMut<'_>,
+        packet: IbcPacket,
+        result: Result<Vec<Any>, &str>,
+    ) -> Result<IbcBasicResponse, ContractError> {
+        let ack_status = match result {
+            Ok(msg_responses) => {
+                let result = TxMsgData { msg_responses }.encode_to_vec();
+
+                AcknowledgementStatus::success(
+                    StatusValue::new(Binary::from(result).to_base64()).unwrap(),
+                )
+            }
+            Err(error) => AcknowledgementStatus::error(StatusValue::new(error).unwrap()),
+        };
+
+        let ack: Vec<u8> = ack_status.into();
+
+        ibc_packet_ack(deps, mock_env(), Self::ack_msg(packet, ack))
+    }
+
+    /// Returns the message acknowledging a packet with the given data.
+    pub fn ack_msg(packet: IbcPacket, ack: impl Into<Binary>) -> IbcPacketAckMsg {
+        IbcPacketAckMsg::new(
+            IbcAcknowledgement::new(ack),
+            packet,
+            Addr::unchecked("relayer"),
+        )
+    }
+
+    pub fn timeout(
+        &self,
+        deps: DepsMut<'_>,
+        packet: IbcPacket,
+    ) -> Result<IbcBasicResponse, ContractError> {
+        let msg = IbcPacketTimeoutMsg::new(packet, Addr::unchecked("relayer"));
+
+        ibc_packet_timeout(deps, mock_env(), msg)
+    }
+
+    pub fn query<T: DeserializeOwned>(&self, deps: Deps<'_>, msg: QueryMsg) -> T {
+        from_json(query(deps, mock_env(), msg).unwrap()).unwrap()
+    }
+}
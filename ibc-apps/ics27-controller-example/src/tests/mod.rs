@@ -0,0 +1,564 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env};
+use cosmwasm_std::{
+    from_json, Addr, BankMsg, CosmosMsg, IbcChannelConnectMsg, IbcChannelOpenMsg, IbcOrder,
+    IbcPacketReceiveMsg, SubMsg, WasmMsg,
+};
+use ibc_app_ica_controller_cw::api::IcaCallbacks;
+use ibc_app_ica_controller_cw::context::IcaContext;
+use ibc_app_ica_controller_cw::types::{
+    AccountInfo, AccountsResponse, ChannelState, ContractError, CosmosTx,
+    InterchainAccountPacketData, Metadata, PacketOutcome, PacketType, PendingPacket, QueryMsg,
+    MSG_CHANNEL_OPEN_INIT_TYPE_URL,
+};
+use ibc_core::channel::types::acknowledgement::{AcknowledgementStatus, StatusValue};
+use ibc_core::channel::types::proto::v1::MsgChannelOpenInit;
+use ibc_core::primitives::proto::Any;
+use prost::Message;
+
+use crate::callbacks::{IcaCallback, OwnerCallbackMsg, CALLBACK_REPLY_ID};
+use crate::entrypoint::{
+    ibc_channel_connect, ibc_channel_open, ibc_packet_ack, ibc_packet_receive,
+};
+
+pub mod fixture;
+
+use fixture::Fixture;
+
+/// Returns the callback sent to an owner contract by a response.
+fn owner_callback(sub_msgs: &[SubMsg], owner: &Addr) -> IcaCallback {
+    let [sub_msg] = sub_msgs else {
+        panic!("expected a single callback, found {sub_msgs:?}");
+    };
+
+    assert_eq!(sub_msg.id, CALLBACK_REPLY_ID);
+
+    let CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr, msg, ..
+    }) = &sub_msg.msg
+    else {
+        panic!("expected a contract execution, found {:?}", sub_msg.msg);
+    };
+
+    assert_eq!(contract_addr, owner.as_str());
+
+    let OwnerCallbackMsg::IcaCallback(callback) = from_json(msg).unwrap();
+
+    callback
+}
+
+#[test]
+fn test_cw_ica_register_account() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.instantiate(deps.as_mut()).unwrap();
+
+    let owner = Fixture::addr(&deps, "owner");
+
+    let resp = fxt.register(deps.as_mut(), &owner).unwrap();
+
+    let CosmosMsg::Any(any) = &resp.messages[0].msg else {
+        panic!("expected a `CosmosMsg::Any`");
+    };
+
+    assert_eq!(any.type_url, MSG_CHANNEL_OPEN_INIT_TYPE_URL);
+
+    let open_init = MsgChannelOpenInit::decode(any.value.as_slice()).unwrap();
+    let channel = open_init.channel.unwrap();
+
+    assert_eq!(open_init.port_id, Fixture::port_id());
+    assert_eq!(open_init.signer, mock_env().contract.address.as_str());
+    assert_eq!(channel.ordering, 2);
+    assert_eq!(channel.counterparty.unwrap().port_id, "icahost");
+    assert_eq!(channel.connection_hops, vec![fxt.connection_id.clone()]);
+
+    let metadata: Metadata = from_json(&channel.version).unwrap();
+
+    assert_eq!(metadata, fxt.metadata(""));
+
+    let resp = fxt.channel_open_init(deps.as_mut(), "channel-0").unwrap();
+
+    assert_eq!(resp.unwrap().version, channel.version);
+
+    fxt.channel_open_ack(deps.as_mut(), "channel-0", &fxt.account_address)
+        .unwrap();
+
+    let account: AccountInfo = fxt.query(
+        deps.as_ref(),
+        QueryMsg::Account {
+            connection_id: fxt.connection_id.clone(),
+        },
+    );
+
+    assert_eq!(
+        account,
+        AccountInfo {
+            owner: owner.clone(),
+            connection_id: fxt.connection_id.clone(),
+            counterparty_connection_id: fxt.counterparty_connection_id.clone(),
+            channel_id: "channel-0".to_string(),
+            address: fxt.account_address.clone(),
+            channel_state: ChannelState::Open,
+        }
+    );
+
+    let resp: AccountsResponse = fxt.query(
+        deps.as_ref(),
+        QueryMsg::Accounts {
+            owner: owner.to_string(),
+            start_after: None,
+            limit: None,
+        },
+    );
+
+    assert_eq!(resp.accounts, vec![account]);
+
+    let other = Fixture::addr(&deps, "other");
+
+    let resp: AccountsResponse = fxt.query(
+        deps.as_ref(),
+        QueryMsg::Accounts {
+            owner: other.to_string(),
+            start_after: None,
+            limit: None,
+        },
+    );
+
+    assert!(resp.accounts.is_empty());
+}
+
+#[test]
+fn test_cw_ica_invalid_handshake_rejected() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.instantiate(deps.as_mut()).unwrap();
+
+    // no registration is pending
+    let err = fxt
+        .channel_open_init(deps.as_mut(), "channel-0")
+        .unwrap_err();
+
+    assert!(matches!(err, ContractError::NoPendingRegistration { .. }));
+
+    let owner = Fixture::addr(&deps, "owner");
+
+    fxt.register(deps.as_mut(), &owner).unwrap();
+
+    let msg = IbcChannelOpenMsg::new_try(
+        fxt.channel("channel-0", IbcOrder::Ordered),
+        "ics27-1".to_string(),
+    );
+
+    let err = ibc_channel_open(deps.as_mut(), mock_env(), msg).unwrap_err();
+
+    assert!(matches!(err, ContractError::OpenTryNotSupported));
+
+    let msg = IbcChannelOpenMsg::new_init(fxt.channel("channel-0", IbcOrder::Unordered));
+
+    let err = ibc_channel_open(deps.as_mut(), mock_env(), msg).unwrap_err();
+
+    assert!(matches!(err, ContractError::UnorderedChannel));
+
+    fxt.channel_open_init(deps.as_mut(), "channel-0").unwrap();
+
+    // the host chain must set the address of the account
+    let err = fxt
+        .channel_open_ack(deps.as_mut(), "channel-0", "")
+        .unwrap_err();
+
+    assert!(matches!(err, ContractError::InvalidMetadata { .. }));
+
+    let msg = IbcChannelConnectMsg::new_confirm(fxt.channel("channel-0", IbcOrder::Ordered));
+
+    let err = ibc_channel_connect(deps.as_mut(), mock_env(), msg).unwrap_err();
+
+    assert!(matches!(err, ContractError::OpenTryNotSupported));
+}
+
+#[test]
+fn test_cw_ica_registration_conflicts() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.instantiate(deps.as_mut()).unwrap();
+
+    let owner = Fixture::addr(&deps, "owner");
+    let other = Fixture::addr(&deps, "other");
+
+    fxt.register(deps.as_mut(), &owner).unwrap();
+    fxt.channel_open_init(deps.as_mut(), "channel-0").unwrap();
+
+    let err = fxt.register(deps.as_mut(), &other).unwrap_err();
+
+    assert!(matches!(err, ContractError::RegistrationPending { .. }));
+
+    // the owner replaces its pending registration, whose channel then
+    // cannot open
+    fxt.register(deps.as_mut(), &owner).unwrap();
+    fxt.channel_open_init(deps.as_mut(), "channel-1").unwrap();
+
+    let err = fxt
+        .channel_open_ack(deps.as_mut(), "channel-0", &fxt.account_address)
+        .unwrap_err();
+
+    assert!(matches!(err, ContractError::UnknownChannel { .. }));
+
+    fxt.channel_open_ack(deps.as_mut(), "channel-1", &fxt.account_address)
+        .unwrap();
+
+    let err = fxt.register(deps.as_mut(), &other).unwrap_err();
+
+    assert!(matches!(err, ContractError::AccountTaken { .. }));
+
+    let err = fxt.register(deps.as_mut(), &owner).unwrap_err();
+
+    assert!(matches!(err, ContractError::AccountOpen { .. }));
+}
+
+#[test]
+fn test_cw_ica_send_tx_and_ack() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.instantiate(deps.as_mut()).unwrap();
+
+    let owner = Fixture::addr(&deps, "owner");
+
+    fxt.setup(&mut deps, &owner, "channel-0");
+
+    let resp = fxt
+        .send_tx(deps.as_mut(), &owner, vec![Fixture::bank_send_msg()])
+        .unwrap();
+
+    let packet = fxt.sent_packet(&resp, 1);
+
+    let packet_data: InterchainAccountPacketData = from_json(&packet.data).unwrap();
+
+    assert_eq!(packet_data.packet_type, PacketType::ExecuteTx);
+    assert_eq!(packet_data.memo, "memo");
+
+    let tx = CosmosTx::decode(packet_data.data.as_slice()).unwrap();
+
+    assert_eq!(
+        tx.messages,
+        vec![Any {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: vec![1, 2, 3],
+        }]
+    );
+
+    let pending: Option<PendingPacket> = fxt.query(
+        deps.as_ref(),
+        QueryMsg::PendingPacket {
+            channel_id: "channel-0".to_string(),
+            sequence: 1,
+        },
+    );
+
+    assert_eq!(pending.unwrap().owner, owner);
+
+    // the sequences follow the packets sent over the channel
+    let resp = fxt
+        .send_tx(deps.as_mut(), &owner, vec![Fixture::bank_send_msg()])
+        .unwrap();
+
+    assert!(resp
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "sequence" && attr.value == "2"));
+
+    let msg_response = Any {
+        type_url: "/cosmos.bank.v1beta1.MsgSendResponse".to_string(),
+        value: vec![],
+    };
+
+    let resp = fxt
+        .ack(
+            deps.as_mut(),
+            packet.clone(),
+            Ok(vec![msg_response.clone()]),
+        )
+        .unwrap();
+
+    // the owner is not a contract
+    assert!(resp.messages.is_empty());
+
+    let pending: Option<PendingPacket> = fxt.query(
+        deps.as_ref(),
+        QueryMsg::PendingPacket {
+            channel_id: "channel-0".to_string(),
+            sequence: 1,
+        },
+    );
+
+    assert!(pending.is_none());
+
+    // a packet is acknowledged once
+    let err = fxt
+        .ack(deps.as_mut(), packet, Ok(vec![msg_response]))
+        .unwrap_err();
+
+    assert!(matches!(err, ContractError::UnknownPacket { .. }));
+}
+
+#[test]
+fn test_cw_ica_send_tx_rejected() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.instantiate(deps.as_mut()).unwrap();
+
+    let owner = Fixture::addr(&deps, "owner");
+    let other = Fixture::addr(&deps, "other");
+
+    let err = fxt
+        .send_tx(deps.as_mut(), &owner, vec![Fixture::bank_send_msg()])
+        .unwrap_err();
+
+    assert!(matches!(err, ContractError::UnknownAccount { .. }));
+
+    fxt.setup(&mut deps, &owner, "channel-0");
+
+    let err = fxt
+        .send_tx(deps.as_mut(), &other, vec![Fixture::bank_send_msg()])
+        .unwrap_err();
+
+    assert!(matches!(err, ContractError::Unauthorized { .. }));
+
+    let err = fxt.send_tx(deps.as_mut(), &owner, vec![]).unwrap_err();
+
+    assert!(matches!(err, ContractError::EmptyTx));
+}
+
+#[test]
+fn test_cw_ica_callbacks_to_owner_contract() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.instantiate(deps.as_mut()).unwrap();
+
+    let dao = Fixture::addr(&deps, "dao");
+
+    Fixture::set_contracts(&mut deps, vec![dao.clone()]);
+
+    fxt.register(deps.as_mut(), &dao).unwrap();
+    fxt.channel_open_init(deps.as_mut(), "channel-0").unwrap();
+
+    let resp = fxt
+        .channel_open_ack(deps.as_mut(), "channel-0", &fxt.account_address)
+        .unwrap();
+
+    let IcaCallback::AccountOpen { account } = owner_callback(&resp.messages, &dao) else {
+        panic!("expected the opening of the account");
+    };
+
+    assert_eq!(account.address, fxt.account_address);
+
+    let resp = fxt
+        .send_tx(deps.as_mut(), &dao, vec![Fixture::bank_send_msg()])
+        .unwrap();
+
+    let packet = fxt.sent_packet(&resp, 1);
+
+    let resp = fxt
+        .ack(
+            deps.as_mut(),
+            packet,
+            Err("ABCI code: 5: error handling packet"),
+        )
+        .unwrap();
+
+    let IcaCallback::PacketOutcome { packet, outcome } = owner_callback(&resp.messages, &dao)
+    else {
+        panic!("expected the outcome of the transaction");
+    };
+
+    assert_eq!(packet.sequence, 1);
+    assert_eq!(
+        outcome,
+        PacketOutcome::Error {
+            error: "ABCI code: 5: error handling packet".to_string()
+        }
+    );
+}
+
+/// Callbacks failing on the transactions of the `failing` owner, and
+/// returning a sub-message that does not reply on error for the others.
+struct FaultyCallbacks;
+
+impl IcaCallbacks for FaultyCallbacks {
+    fn on_packet_outcome(
+        _ctx: &mut IcaContext<'_, Self>,
+        packet: &PendingPacket,
+        _outcome: &PacketOutcome,
+    ) -> Result<Vec<SubMsg>, ContractError> {
+        if packet.owner.as_str().ends_with("failing") {
+            return Err(ContractError::Unauthorized {
+                sender: packet.owner.to_string(),
+            });
+        }
+
+        Ok(vec![SubMsg::new(BankMsg::Burn { amount: vec![] })])
+    }
+}
+
+#[test]
+fn test_cw_ica_ack_never_fails() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.instantiate(deps.as_mut()).unwrap();
+
+    let dao = Fixture::addr(&deps, "dao");
+
+    Fixture::set_contracts(&mut deps, vec![dao.clone()]);
+
+    fxt.setup(&mut deps, &dao, "channel-0");
+
+    let resp = fxt
+        .send_tx(deps.as_mut(), &dao, vec![Fixture::bank_send_msg()])
+        .unwrap();
+
+    // an acknowledgement that cannot be parsed fails the transaction
+    let msg = Fixture::ack_msg(
+        fxt.sent_packet(&resp, 1),
+        b"not an acknowledgement".to_vec(),
+    );
+
+    let resp = ibc_packet_ack(deps.as_mut(), mock_env(), msg).unwrap();
+
+    let IcaCallback::PacketOutcome { outcome, .. } = owner_callback(&resp.messages, &dao) else {
+        panic!("expected the outcome of the transaction");
+    };
+
+    let PacketOutcome::Error { error } = outcome else {
+        panic!("expected the failure of the transaction, found {outcome:?}");
+    };
+
+    assert!(error.starts_with("invalid acknowledgement"));
+
+    // a callback failing or returning sub-messages that do not reply on
+    // error is recorded, without dispatching any of its sub-messages
+    for (i, owner) in ["failing", "careless"].into_iter().enumerate() {
+        let owner = Fixture::addr(&deps, owner);
+
+        let fxt = Fixture {
+            connection_id: format!("connection-{}", i + 1),
+            ..fxt.clone()
+        };
+
+        let channel_id = format!("channel-{}", i + 1);
+
+        fxt.setup(&mut deps, &owner, &channel_id);
+
+        let resp = fxt
+            .send_tx(deps.as_mut(), &owner, vec![Fixture::bank_send_msg()])
+            .unwrap();
+
+        let ack: Vec<u8> = AcknowledgementStatus::error(StatusValue::new("error").unwrap()).into();
+
+        let msg = Fixture::ack_msg(fxt.sent_packet(&resp, 1), ack);
+
+        let resp = IcaContext::<FaultyCallbacks>::new_mut(deps.as_mut(), mock_env())
+            .packet_ack(msg)
+            .unwrap();
+
+        assert!(resp.messages.is_empty());
+        assert!(resp
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "callback_error"));
+    }
+}
+
+#[test]
+fn test_cw_ica_timeout_closes_account() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.instantiate(deps.as_mut()).unwrap();
+
+    let dao = Fixture::addr(&deps, "dao");
+
+    Fixture::set_contracts(&mut deps, vec![dao.clone()]);
+
+    fxt.setup(&mut deps, &dao, "channel-0");
+
+    let resp = fxt
+        .send_tx(deps.as_mut(), &dao, vec![Fixture::bank_send_msg()])
+        .unwrap();
+
+    let packet = fxt.sent_packet(&resp, 1);
+
+    let resp = fxt.timeout(deps.as_mut(), packet).unwrap();
+
+    let IcaCallback::PacketOutcome { outcome, .. } = owner_callback(&resp.messages, &dao) else {
+        panic!("expected the outcome of the transaction");
+    };
+
+    assert_eq!(outcome, PacketOutcome::Timeout);
+
+    let err = fxt
+        .send_tx(deps.as_mut(), &dao, vec![Fixture::bank_send_msg()])
+        .unwrap_err();
+
+    assert!(matches!(err, ContractError::AccountClosed { .. }));
+
+    // the owner opens the account again over a new channel
+    fxt.setup(&mut deps, &dao, "channel-1");
+
+    let account: AccountInfo = fxt.query(
+        deps.as_ref(),
+        QueryMsg::Account {
+            connection_id: fxt.connection_id.clone(),
+        },
+    );
+
+    assert_eq!(account.channel_id, "channel-1");
+    assert_eq!(account.channel_state, ChannelState::Open);
+
+    let resp = fxt
+        .send_tx(deps.as_mut(), &dao, vec![Fixture::bank_send_msg()])
+        .unwrap();
+
+    assert_eq!(fxt.sent_packet(&resp, 1).src.channel_id, "channel-1");
+}
+
+#[test]
+fn test_cw_ica_packet_receive_rejected() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.instantiate(deps.as_mut()).unwrap();
+
+    let owner = Fixture::addr(&deps, "owner");
+
+    fxt.setup(&mut deps, &owner, "channel-0");
+
+    let resp = fxt
+        .send_tx(deps.as_mut(), &owner, vec![Fixture::bank_send_msg()])
+        .unwrap();
+
+    let mut packet = fxt.sent_packet(&resp, 1);
+
+    std::mem::swap(&mut packet.src, &mut packet.dest);
+
+    let msg = IbcPacketReceiveMsg::new(packet, Addr::unchecked("relayer"));
+
+    let resp = ibc_packet_receive(deps.as_mut(), mock_env(), msg).unwrap();
+
+    let ack_status: AcknowledgementStatus =
+        from_json(resp.acknowledgement.as_ref().unwrap()).unwrap();
+
+    assert!(!ack_status.is_successful());
+}
@@ -0,0 +1,35 @@
+use cosmwasm_std::SubMsg;
+
+use crate::context::IcaContext;
+use crate::types::{AccountInfo, ContractError, PacketOutcome, PendingPacket};
+
+/// Enables users to react to the lifecycle of the interchain accounts and to
+/// the outcome of the transactions sent to them, by introducing their
+/// callbacks into the generic [`IcaContext`] object.
+///
+/// The sub-messages returned by the callbacks are dispatched along with the
+/// response of the IBC entry point that ran them. A failing
+/// [`Self::on_account_open`] fails the opening of the channel, while the
+/// acknowledgements and timeouts never fail because of
+/// [`Self::on_packet_outcome`]: its error is recorded in the
+/// `callback_error` attribute of the response, and its sub-messages must
+/// reply on error, as they are dropped otherwise.
+pub trait IcaCallbacks: Sized {
+    /// Called once the channel of an interchain account is open, with the
+    /// address of the account on the host chain.
+    fn on_account_open(
+        _ctx: &mut IcaContext<'_, Self>,
+        _account: &AccountInfo,
+    ) -> Result<Vec<SubMsg>, ContractError> {
+        Ok(Vec::new())
+    }
+
+    /// Called once a transaction sent to an interchain account is
+    /// acknowledged by the host chain or timed out. Its sub-messages must
+    /// reply on error, so that the contract catches their failure.
+    fn on_packet_outcome(
+        ctx: &mut IcaContext<'_, Self>,
+        packet: &PendingPacket,
+        outcome: &PacketOutcome,
+    ) -> Result<Vec<SubMsg>, ContractError>;
+}
@@ -0,0 +1,161 @@
+use core::marker::PhantomData;
+
+use cosmwasm_std::{Addr, Api, Deps, DepsMut, Empty, Env, QuerierWrapper, Storage};
+use cw_storage_plus::{Item, Map};
+
+use crate::api::IcaCallbacks;
+use crate::types::{AccountInfo, Config, ContractError, PendingPacket, PendingRegistration};
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// The registrations waiting for their channel to open, by connection
+/// identifier. A single registration may be pending on a connection.
+pub const PENDING_REGISTRATIONS: Map<&str, PendingRegistration> = Map::new("pending_registrations");
+
+/// The interchain accounts controlled by the contract, by connection
+/// identifier.
+pub const ACCOUNTS: Map<&str, AccountInfo> = Map::new("accounts");
+
+/// The connections of the interchain accounts of each owner, as a set.
+pub const OWNER_ACCOUNTS: Map<(&Addr, &str), Empty> = Map::new("owner_accounts");
+
+/// The connection of the interchain account of each channel opened by the
+/// contract, by channel identifier.
+pub const CHANNEL_CONNECTIONS: Map<&str, String> = Map::new("channel_connections");
+
+/// The sequence of the next packet sent over each channel, by channel
+/// identifier. The contract is the only sender over the channels it owns, so
+/// the sequences are tracked without reading the ones assigned by the
+/// hosting chain.
+pub const NEXT_SEQUENCES: Map<&str, u64> = Map::new("next_sequences");
+
+/// The transactions waiting for their acknowledgement or timeout, by channel
+/// identifier and sequence.
+pub const PENDING_PACKETS: Map<(&str, u64), PendingPacket> = Map::new("pending_packets");
+
+/// IcaContext is a wrapper around the deps and env that provides access to
+/// the interchain accounts controlled by the contract, and runs the callbacks
+/// of the controller contract.
+pub struct IcaContext<'a, C>
+where
+    C: IcaCallbacks,
+{
+    deps: Option<Deps<'a>>,
+    deps_mut: Option<DepsMut<'a>>,
+    env: Env,
+    callbacks: PhantomData<C>,
+}
+
+impl<'a, C> IcaContext<'a, C>
+where
+    C: IcaCallbacks,
+{
+    /// Constructs a new IcaContext object with the given deps and env.
+    pub fn new_ref(deps: Deps<'a>, env: Env) -> Self {
+        Self {
+            deps: Some(deps),
+            deps_mut: None,
+            env,
+            callbacks: PhantomData,
+        }
+    }
+
+    /// Constructs a new IcaContext object with the given deps_mut and env.
+    pub fn new_mut(deps_mut: DepsMut<'a>, env: Env) -> Self {
+        Self {
+            deps: None,
+            deps_mut: Some(deps_mut),
+            env,
+            callbacks: PhantomData,
+        }
+    }
+
+    /// Returns the env of the context.
+    pub fn env(&self) -> &Env {
+        &self.env
+    }
+
+    pub fn api(&self) -> &dyn Api {
+        match self.deps {
+            Some(ref deps) => deps.api,
+            None => match self.deps_mut {
+                Some(ref deps) => deps.api,
+                None => panic!("Either deps or deps_mut should be available"),
+            },
+        }
+    }
+
+    pub fn querier(&self) -> &QuerierWrapper<'a> {
+        match self.deps {
+            Some(ref deps) => &deps.querier,
+            None => match self.deps_mut {
+                Some(ref deps) => &deps.querier,
+                None => panic!("Either deps or deps_mut should be available"),
+            },
+        }
+    }
+
+    pub fn storage_ref(&self) -> &dyn Storage {
+        match self.deps {
+            Some(ref deps) => deps.storage,
+            None => match self.deps_mut {
+                Some(ref deps) => deps.storage,
+                None => panic!("Either deps or deps_mut should be available"),
+            },
+        }
+    }
+
+    pub fn storage_mut(&mut self) -> &mut dyn Storage {
+        match self.deps_mut {
+            Some(ref mut deps) => deps.storage,
+            None => panic!("deps_mut should be available"),
+        }
+    }
+
+    pub fn contract_address(&self) -> &Addr {
+        &self.env.contract.address
+    }
+
+    /// Returns the port bound to the contract by the hosting chain.
+    pub fn port_id(&self) -> String {
+        format!("wasm.{}", self.contract_address())
+    }
+
+    pub fn config(&self) -> Result<Config, ContractError> {
+        Ok(CONFIG.load(self.storage_ref())?)
+    }
+
+    /// Returns the interchain account registered on a connection.
+    pub fn account(&self, connection_id: &str) -> Result<AccountInfo, ContractError> {
+        ACCOUNTS
+            .may_load(self.storage_ref(), connection_id)?
+            .ok_or_else(|| ContractError::UnknownAccount {
+                connection_id: connection_id.to_string(),
+            })
+    }
+
+    /// Returns the interchain account whose channel is, or was, the given
+    /// channel.
+    pub fn channel_account(&self, channel_id: &str) -> Result<AccountInfo, ContractError> {
+        let connection_id = CHANNEL_CONNECTIONS
+            .may_load(self.storage_ref(), channel_id)?
+            .ok_or_else(|| ContractError::UnknownChannel {
+                channel_id: channel_id.to_string(),
+            })?;
+
+        self.account(&connection_id)
+    }
+
+    /// Records an interchain account.
+    pub fn save_account(&mut self, account: &AccountInfo) -> Result<(), ContractError> {
+        ACCOUNTS.save(self.storage_mut(), &account.connection_id, account)?;
+
+        OWNER_ACCOUNTS.save(
+            self.storage_mut(),
+            (&account.owner, &account.connection_id),
+            &Empty {},
+        )?;
+
+        Ok(())
+    }
+}
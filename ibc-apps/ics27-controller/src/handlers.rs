@@ -0,0 +1,516 @@
+use cosmwasm_std::{
+    from_json, to_json_binary, to_json_string, AnyMsg, Binary, Ibc3ChannelOpenResponse,
+    IbcBasicResponse, IbcChannelCloseMsg, IbcChannelConnectMsg, IbcChannelOpenMsg,
+    IbcChannelOpenResponse, IbcMsg, IbcOrder, IbcPacketAckMsg, IbcPacketReceiveMsg,
+    IbcPacketTimeoutMsg, IbcReceiveResponse, IbcTimeout, MessageInfo, Order as StorageOrder,
+    ReplyOn, Response, SubMsg,
+};
+use cw_storage_plus::Bound;
+use ibc_core::channel::types::acknowledgement::{AcknowledgementStatus, StatusValue};
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::host::types::identifiers::ConnectionId;
+use ibc_core::primitives::proto::Any;
+use prost::Message;
+
+use crate::api::IcaCallbacks;
+use crate::context::{
+    IcaContext, ACCOUNTS, CHANNEL_CONNECTIONS, CONFIG, NEXT_SEQUENCES, OWNER_ACCOUNTS,
+    PENDING_PACKETS, PENDING_REGISTRATIONS,
+};
+use crate::types::{
+    channel_open_init_msg, AccountInfo, AccountsResponse, ChannelState, Config, ContractError,
+    CosmosTx, ExecuteMsg, InstantiateMsg, InterchainAccountPacketData, Metadata, PacketOutcome,
+    PacketType, PendingPacket, PendingRegistration, QueryMsg, TxMsgData, HOST_PORT_ID,
+};
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
+impl<C> IcaContext<'_, C>
+where
+    C: IcaCallbacks,
+{
+    /// Instantiates the contract with the given [`InstantiateMsg`] message.
+    pub fn instantiate(&mut self, msg: InstantiateMsg) -> Result<Response, ContractError> {
+        let config = Config {
+            default_timeout: msg.default_timeout,
+        };
+
+        CONFIG.save(self.storage_mut(), &config)?;
+
+        Ok(Response::default())
+    }
+
+    /// Executes the given [`ExecuteMsg`].
+    pub fn execute(
+        &mut self,
+        info: MessageInfo,
+        msg: ExecuteMsg,
+    ) -> Result<Response, ContractError> {
+        match msg {
+            ExecuteMsg::RegisterAccount {
+                connection_id,
+                counterparty_connection_id,
+            } => self.register_account(info, connection_id, counterparty_connection_id),
+            ExecuteMsg::SendTx {
+                connection_id,
+                messages,
+                memo,
+                timeout,
+            } => self.send_tx(info, connection_id, messages, memo, timeout),
+        }
+    }
+
+    /// Opens a channel to the interchain accounts host of a connection for
+    /// the account of the sender. A pending registration of the sender on the
+    /// connection is replaced, so that a handshake that never completes does
+    /// not lock the connection.
+    fn register_account(
+        &mut self,
+        info: MessageInfo,
+        connection_id: String,
+        counterparty_connection_id: String,
+    ) -> Result<Response, ContractError> {
+        connection_id.parse::<ConnectionId>()?;
+        counterparty_connection_id.parse::<ConnectionId>()?;
+
+        if let Some(pending) = PENDING_REGISTRATIONS.may_load(self.storage_ref(), &connection_id)? {
+            if pending.owner != info.sender {
+                return Err(ContractError::RegistrationPending { connection_id });
+            }
+        }
+
+        if let Some(account) = ACCOUNTS.may_load(self.storage_ref(), &connection_id)? {
+            if account.owner != info.sender {
+                return Err(ContractError::AccountTaken { connection_id });
+            }
+
+            if account.channel_state == ChannelState::Open {
+                return Err(ContractError::AccountOpen { connection_id });
+            }
+        }
+
+        let pending = PendingRegistration {
+            owner: info.sender.clone(),
+            counterparty_connection_id: counterparty_connection_id.clone(),
+            channel_id: None,
+        };
+
+        PENDING_REGISTRATIONS.save(self.storage_mut(), &connection_id, &pending)?;
+
+        let metadata = Metadata::new(connection_id.clone(), counterparty_connection_id);
+
+        let open_init = channel_open_init_msg(
+            self.port_id(),
+            connection_id.clone(),
+            to_json_string(&metadata)?,
+            self.contract_address().to_string(),
+        );
+
+        Ok(Response::default()
+            .add_message(open_init)
+            .add_attribute("action", "register_account")
+            .add_attribute("owner", info.sender)
+            .add_attribute("connection_id", connection_id))
+    }
+
+    /// Sends a transaction to the interchain account of the sender.
+    fn send_tx(
+        &mut self,
+        info: MessageInfo,
+        connection_id: String,
+        messages: Vec<AnyMsg>,
+        memo: Option<String>,
+        timeout: Option<u64>,
+    ) -> Result<Response, ContractError> {
+        if messages.is_empty() {
+            return Err(ContractError::EmptyTx);
+        }
+
+        let account = self.account(&connection_id)?;
+
+        if account.owner != info.sender {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender.to_string(),
+            });
+        }
+
+        if account.channel_state != ChannelState::Open {
+            return Err(ContractError::AccountClosed { connection_id });
+        }
+
+        let tx = CosmosTx {
+            messages: messages
+                .into_iter()
+                .map(|msg| Any {
+                    type_url: msg.type_url,
+                    value: msg.value.to_vec(),
+                })
+                .collect(),
+        };
+
+        let packet_data = InterchainAccountPacketData {
+            packet_type: PacketType::ExecuteTx,
+            data: tx.encode_to_vec().into(),
+            memo: memo.unwrap_or_default(),
+        };
+
+        let channel_id = account.channel_id;
+
+        let sequence = NEXT_SEQUENCES.load(self.storage_ref(), &channel_id)?;
+
+        NEXT_SEQUENCES.save(self.storage_mut(), &channel_id, &(sequence + 1))?;
+
+        let pending = PendingPacket {
+            owner: info.sender,
+            connection_id,
+            channel_id: channel_id.clone(),
+            sequence,
+        };
+
+        PENDING_PACKETS.save(self.storage_mut(), (&channel_id, sequence), &pending)?;
+
+        let timeout = timeout.unwrap_or(self.config()?.default_timeout);
+
+        let send_packet = IbcMsg::SendPacket {
+            channel_id: channel_id.clone(),
+            data: to_json_binary(&packet_data)?,
+            timeout: IbcTimeout::with_timestamp(self.env().block.time.plus_seconds(timeout)),
+        };
+
+        Ok(Response::default()
+            .add_message(send_packet)
+            .add_attribute("action", "send_tx")
+            .add_attribute("connection_id", pending.connection_id)
+            .add_attribute("channel_id", channel_id)
+            .add_attribute("sequence", sequence.to_string()))
+    }
+
+    /// Queries the contract with the given [`QueryMsg`].
+    pub fn query(&self, msg: QueryMsg) -> Result<Binary, ContractError> {
+        let binary = match msg {
+            QueryMsg::Config {} => to_json_binary(&self.config()?)?,
+            QueryMsg::Account { connection_id } => to_json_binary(&self.account(&connection_id)?)?,
+            QueryMsg::Accounts {
+                owner,
+                start_after,
+                limit,
+            } => {
+                let owner = self.api().addr_validate(&owner)?;
+
+                let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+                let start = start_after.as_deref().map(Bound::exclusive);
+
+                let accounts = OWNER_ACCOUNTS
+                    .prefix(&owner)
+                    .keys(self.storage_ref(), start, None, StorageOrder::Ascending)
+                    .take(limit)
+                    .map(|connection_id| self.account(&connection_id?))
+                    .collect::<Result<_, ContractError>>()?;
+
+                to_json_binary(&AccountsResponse { accounts })?
+            }
+            QueryMsg::PendingPacket {
+                channel_id,
+                sequence,
+            } => to_json_binary(
+                &PENDING_PACKETS.may_load(self.storage_ref(), (&channel_id, sequence))?,
+            )?,
+        };
+
+        Ok(binary)
+    }
+
+    /// Runs the `OpenInit` callback of the channel handshake, checking the
+    /// channel opened by a registration. The controller does not accept the
+    /// channels opened by the counterparty.
+    pub fn channel_open(
+        &mut self,
+        msg: IbcChannelOpenMsg,
+    ) -> Result<IbcChannelOpenResponse, ContractError> {
+        if msg.counterparty_version().is_some() {
+            return Err(ContractError::OpenTryNotSupported);
+        }
+
+        let channel = msg.channel();
+
+        if channel.order != IbcOrder::Ordered {
+            return Err(ContractError::UnorderedChannel);
+        }
+
+        if channel.counterparty_endpoint.port_id != HOST_PORT_ID {
+            return Err(ContractError::InvalidCounterpartyPort {
+                port_id: channel.counterparty_endpoint.port_id.clone(),
+            });
+        }
+
+        let connection_id = &channel.connection_id;
+
+        let mut pending = PENDING_REGISTRATIONS
+            .may_load(self.storage_ref(), connection_id)?
+            .ok_or_else(|| ContractError::NoPendingRegistration {
+                connection_id: connection_id.clone(),
+            })?;
+
+        let metadata = parse_metadata(&channel.version)?;
+
+        metadata.validate(connection_id)?;
+
+        if metadata.host_connection_id != pending.counterparty_connection_id {
+            return Err(ContractError::InvalidMetadata {
+                description: format!(
+                    "expected host connection `{}`, found `{}`",
+                    pending.counterparty_connection_id, metadata.host_connection_id
+                ),
+            });
+        }
+
+        pending.channel_id = Some(channel.endpoint.channel_id.clone());
+
+        PENDING_REGISTRATIONS.save(self.storage_mut(), connection_id, &pending)?;
+
+        Ok(Some(Ibc3ChannelOpenResponse {
+            version: channel.version.clone(),
+        }))
+    }
+
+    /// Runs the `OpenAck` callback of the channel handshake, and records the
+    /// interchain account whose address is set by the host chain in its
+    /// version.
+    pub fn channel_connect(
+        &mut self,
+        msg: IbcChannelConnectMsg,
+    ) -> Result<IbcBasicResponse, ContractError> {
+        // `OpenConfirm` only follows an `OpenTry` on the contract.
+        let Some(counterparty_version) = msg.counterparty_version() else {
+            return Err(ContractError::OpenTryNotSupported);
+        };
+
+        let channel = msg.channel();
+
+        let connection_id = &channel.connection_id;
+        let channel_id = &channel.endpoint.channel_id;
+
+        let pending = PENDING_REGISTRATIONS
+            .may_load(self.storage_ref(), connection_id)?
+            .ok_or_else(|| ContractError::NoPendingRegistration {
+                connection_id: connection_id.clone(),
+            })?;
+
+        // The channels of a replaced registration are never opened.
+        if pending.channel_id.as_ref() != Some(channel_id) {
+            return Err(ContractError::UnknownChannel {
+                channel_id: channel_id.clone(),
+            });
+        }
+
+        let metadata = parse_metadata(counterparty_version)?;
+
+        metadata.validate(connection_id)?;
+
+        if metadata.address.is_empty() {
+            return Err(ContractError::InvalidMetadata {
+                description: "missing interchain account address".to_string(),
+            });
+        }
+
+        PENDING_REGISTRATIONS.remove(self.storage_mut(), connection_id);
+
+        let account = AccountInfo {
+            owner: pending.owner,
+            connection_id: connection_id.clone(),
+            counterparty_connection_id: pending.counterparty_connection_id,
+            channel_id: channel_id.clone(),
+            address: metadata.address,
+            channel_state: ChannelState::Open,
+        };
+
+        self.save_account(&account)?;
+
+        CHANNEL_CONNECTIONS.save(self.storage_mut(), channel_id, connection_id)?;
+        NEXT_SEQUENCES.save(self.storage_mut(), channel_id, &1)?;
+
+        let sub_msgs = C::on_account_open(self, &account)?;
+
+        Ok(IbcBasicResponse::new()
+            .add_submessages(sub_msgs)
+            .add_attribute("action", "channel_connect")
+            .add_attribute("connection_id", account.connection_id)
+            .add_attribute("channel_id", account.channel_id)
+            .add_attribute("address", account.address))
+    }
+
+    /// Runs the `CloseInit` and `CloseConfirm` callbacks. The channels of the
+    /// interchain accounts cannot be closed by the contract, but the host
+    /// chain may close them.
+    pub fn channel_close(
+        &mut self,
+        msg: IbcChannelCloseMsg,
+    ) -> Result<IbcBasicResponse, ContractError> {
+        if let IbcChannelCloseMsg::CloseInit { .. } = msg {
+            return Err(ContractError::CloseInitNotSupported);
+        }
+
+        let channel_id = &msg.channel().endpoint.channel_id;
+
+        self.close_channel(channel_id)?;
+
+        Ok(IbcBasicResponse::new()
+            .add_attribute("action", "channel_close")
+            .add_attribute("channel_id", channel_id))
+    }
+
+    /// Marks the account of a channel as closed, unless it was registered
+    /// again over another channel since.
+    fn close_channel(&mut self, channel_id: &str) -> Result<(), ContractError> {
+        if !CHANNEL_CONNECTIONS.has(self.storage_ref(), channel_id) {
+            return Ok(());
+        }
+
+        let mut account = self.channel_account(channel_id)?;
+
+        if account.channel_id == channel_id {
+            account.channel_state = ChannelState::Closed;
+
+            self.save_account(&account)?;
+        }
+
+        Ok(())
+    }
+
+    /// Rejects the packets sent to the controller with an error
+    /// acknowledgement, as the interchain accounts hosts send none.
+    pub fn packet_receive(
+        &mut self,
+        _msg: IbcPacketReceiveMsg,
+    ) -> Result<IbcReceiveResponse, ContractError> {
+        let ack = AcknowledgementStatus::error(StatusValue::new(
+            "cannot receive packets on the interchain accounts controller",
+        )?);
+
+        Ok(IbcReceiveResponse::new(Vec::<u8>::from(ack)))
+    }
+
+    /// Handles the acknowledgement of a transaction, passing its outcome to
+    /// the callbacks of the contract. An acknowledgement that cannot be
+    /// parsed is passed as the failure of the transaction.
+    pub fn packet_ack(&mut self, msg: IbcPacketAckMsg) -> Result<IbcBasicResponse, ContractError> {
+        let packet = &msg.original_packet;
+
+        let pending = self.take_pending_packet(&packet.src.channel_id, packet.sequence)?;
+
+        let outcome =
+            parse_ack(&msg.acknowledgement.data).unwrap_or_else(|e| PacketOutcome::Error {
+                error: format!("invalid acknowledgement: {e}"),
+            });
+
+        let resp = self.packet_outcome_callback(&pending, &outcome);
+
+        Ok(resp
+            .add_attribute("action", "packet_ack")
+            .add_attribute("channel_id", pending.channel_id)
+            .add_attribute("sequence", pending.sequence.to_string())
+            .add_attribute(
+                "success",
+                matches!(outcome, PacketOutcome::Success { .. }).to_string(),
+            ))
+    }
+
+    /// Handles the timeout of a transaction, which closes the ordered
+    /// channel of the account, passing its outcome to the callbacks of the
+    /// contract.
+    pub fn packet_timeout(
+        &mut self,
+        msg: IbcPacketTimeoutMsg,
+    ) -> Result<IbcBasicResponse, ContractError> {
+        let packet = &msg.packet;
+
+        let pending = self.take_pending_packet(&packet.src.channel_id, packet.sequence)?;
+
+        self.close_channel(&pending.channel_id)?;
+
+        let resp = self.packet_outcome_callback(&pending, &PacketOutcome::Timeout);
+
+        Ok(resp
+            .add_attribute("action", "packet_timeout")
+            .add_attribute("channel_id", pending.channel_id)
+            .add_attribute("sequence", pending.sequence.to_string()))
+    }
+
+    /// Runs the callback of the outcome of a transaction. The acknowledgement
+    /// or timeout does not fail along with the callback, as it could never be
+    /// relayed otherwise: the error of the callback is recorded instead, as
+    /// are its sub-messages not replying on error, which are not dispatched.
+    fn packet_outcome_callback(
+        &mut self,
+        pending: &PendingPacket,
+        outcome: &PacketOutcome,
+    ) -> IbcBasicResponse {
+        let resp = IbcBasicResponse::new();
+
+        match C::on_packet_outcome(self, pending, outcome) {
+            Ok(sub_msgs) if sub_msgs.iter().all(replies_on_error) => resp.add_submessages(sub_msgs),
+            Ok(_) => resp.add_attribute(
+                "callback_error",
+                "the callback sub-messages must reply on error",
+            ),
+            Err(e) => resp.add_attribute("callback_error", e.to_string()),
+        }
+    }
+
+    fn take_pending_packet(
+        &mut self,
+        channel_id: &str,
+        sequence: u64,
+    ) -> Result<PendingPacket, ContractError> {
+        let pending = PENDING_PACKETS
+            .may_load(self.storage_ref(), (channel_id, sequence))?
+            .ok_or_else(|| ContractError::UnknownPacket {
+                channel_id: channel_id.to_string(),
+                sequence,
+            })?;
+
+        PENDING_PACKETS.remove(self.storage_mut(), (channel_id, sequence));
+
+        Ok(pending)
+    }
+}
+
+/// Parses the acknowledgement of a transaction into its outcome.
+fn parse_ack(data: &Binary) -> Result<PacketOutcome, ContractError> {
+    let ack_status: AcknowledgementStatus = from_json(data)?;
+
+    let outcome = match ack_status {
+        AcknowledgementStatus::Success(result) => {
+            let result = Binary::from_base64(&result.to_string())?;
+
+            let tx_msg_data = TxMsgData::decode(result.as_slice()).map_err(DecodingError::from)?;
+
+            PacketOutcome::Success {
+                responses: tx_msg_data
+                    .msg_responses
+                    .into_iter()
+                    .map(|any| AnyMsg {
+                        type_url: any.type_url,
+                        value: any.value.into(),
+                    })
+                    .collect(),
+            }
+        }
+        AcknowledgementStatus::Error(error) => PacketOutcome::Error {
+            error: error.to_string(),
+        },
+    };
+
+    Ok(outcome)
+}
+
+fn replies_on_error(sub_msg: &SubMsg) -> bool {
+    matches!(sub_msg.reply_on, ReplyOn::Error | ReplyOn::Always)
+}
+
+fn parse_metadata(version: &str) -> Result<Metadata, ContractError> {
+    from_json(version).map_err(|e| ContractError::InvalidMetadata {
+        description: e.to_string(),
+    })
+}
@@ -0,0 +1,20 @@
+//! Contains the implementation of the ICS-27 interchain accounts controller
+//! for CosmWasm contracts. This crate functions as a library, allowing users
+//! to import the ready-made `IcaContext` object that is generic across
+//! controller contracts, introduce their callbacks on the outcome of the
+//! transactions sent to the interchain accounts and integrate their assembled
+//! context into the CosmWasm contract's entrypoint.
+//!
+//! The controller owns the `wasm.<contract address>` port of the hosting
+//! chain, and opens an ordered channel to the `icahost` port of the host
+//! chain for each interchain account. As the host chain derives the address
+//! of an interchain account from its connection and the controller port, a
+//! contract controls at most one interchain account per connection, which is
+//! owned by the address that registered it.
+
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+
+pub mod api;
+pub mod context;
+pub mod handlers;
+pub mod types;
@@ -0,0 +1,56 @@
+use cosmwasm_std::StdError;
+use derive_more::{Display, From};
+use ibc_core::channel::types::error::ChannelError;
+use ibc_core::host::types::error::{DecodingError, IdentifierError};
+
+#[derive(From, Display, Debug)]
+pub enum ContractError {
+    #[from]
+    #[display("CosmWasm standard error: {_0}")]
+    Std(StdError),
+    #[from]
+    #[display("IBC channel error: {_0}")]
+    Channel(ChannelError),
+    #[from]
+    #[display("IBC identifier error: {_0}")]
+    Identifier(IdentifierError),
+    #[from]
+    #[display("IBC decoding error: {_0}")]
+    Decoding(DecodingError),
+    #[display("interchain account channels must be ordered")]
+    UnorderedChannel,
+    #[display("expected counterparty port `icahost`, found `{port_id}`")]
+    InvalidCounterpartyPort { port_id: String },
+    #[display("invalid interchain account metadata: {description}")]
+    InvalidMetadata { description: String },
+    #[display("channels to interchain accounts can only be opened by the controller")]
+    OpenTryNotSupported,
+    #[display("channels to interchain accounts cannot be closed by the controller")]
+    CloseInitNotSupported,
+    #[display("no interchain account registration is pending on `{connection_id}`")]
+    NoPendingRegistration { connection_id: String },
+    #[display("an interchain account registration is already pending on `{connection_id}`")]
+    RegistrationPending { connection_id: String },
+    #[display("the interchain account on `{connection_id}` belongs to another owner")]
+    AccountTaken { connection_id: String },
+    #[display("the interchain account on `{connection_id}` is already open")]
+    AccountOpen { connection_id: String },
+    #[display("the interchain account on `{connection_id}` is closed")]
+    AccountClosed { connection_id: String },
+    #[display("no interchain account is registered on `{connection_id}`")]
+    UnknownAccount { connection_id: String },
+    #[display("unknown channel `{channel_id}`")]
+    UnknownChannel { channel_id: String },
+    #[display("`{sender}` is not the owner of the interchain account")]
+    Unauthorized { sender: String },
+    #[display("transactions must contain at least one message")]
+    EmptyTx,
+    #[display("no packet `{sequence}` is pending on `{channel_id}`")]
+    UnknownPacket { channel_id: String, sequence: u64 },
+}
+
+impl From<ContractError> for StdError {
+    fn from(err: ContractError) -> Self {
+        Self::generic_err(err.to_string())
+    }
+}
@@ -0,0 +1,77 @@
+//! Defines the metadata negotiated as the version of the interchain account
+//! channels.
+use cosmwasm_schema::serde::{Deserialize, Serialize};
+
+use super::error::ContractError;
+
+/// The version of the ICS-27 protocol.
+pub const VERSION: &str = "ics27-1";
+
+/// The port bound to the interchain accounts host on the host chains.
+pub const HOST_PORT_ID: &str = "icahost";
+
+/// The encoding of the transactions sent to the interchain accounts.
+pub const ENCODING_PROTO3: &str = "proto3";
+
+/// The type of the transactions sent to the interchain accounts.
+pub const TX_TYPE_SDK_MULTI_MSG: &str = "sdk_multi_msg";
+
+/// The metadata of an interchain account channel, encoded in JSON as its
+/// version. Unknown fields are accepted, as the host chains may add some.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(crate = "cosmwasm_schema::serde")]
+pub struct Metadata {
+    pub version: String,
+    pub controller_connection_id: String,
+    pub host_connection_id: String,
+    /// The address of the interchain account, set by the host chain on
+    /// `OpenTry`.
+    #[serde(default)]
+    pub address: String,
+    pub encoding: String,
+    pub tx_type: String,
+}
+
+impl Metadata {
+    /// Returns the metadata proposed by the controller on `OpenInit`.
+    pub fn new(controller_connection_id: String, host_connection_id: String) -> Self {
+        Self {
+            version: VERSION.to_string(),
+            controller_connection_id,
+            host_connection_id,
+            address: String::new(),
+            encoding: ENCODING_PROTO3.to_string(),
+            tx_type: TX_TYPE_SDK_MULTI_MSG.to_string(),
+        }
+    }
+
+    /// Checks that the metadata follows the protocol supported by the
+    /// controller, over the given connection of the controller chain.
+    pub fn validate(&self, connection_id: &str) -> Result<(), ContractError> {
+        let invalid = |description: String| ContractError::InvalidMetadata { description };
+
+        if self.version != VERSION {
+            return Err(invalid(format!("unsupported version `{}`", self.version)));
+        }
+
+        if self.controller_connection_id != connection_id {
+            return Err(invalid(format!(
+                "expected controller connection `{connection_id}`, found `{}`",
+                self.controller_connection_id
+            )));
+        }
+
+        if self.encoding != ENCODING_PROTO3 {
+            return Err(invalid(format!("unsupported encoding `{}`", self.encoding)));
+        }
+
+        if self.tx_type != TX_TYPE_SDK_MULTI_MSG {
+            return Err(invalid(format!(
+                "unsupported transaction type `{}`",
+                self.tx_type
+            )));
+        }
+
+        Ok(())
+    }
+}
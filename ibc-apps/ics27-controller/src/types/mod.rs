@@ -0,0 +1,15 @@
+mod error;
+mod metadata;
+mod msgs;
+mod packet;
+mod proto;
+mod response;
+mod state;
+
+pub use error::*;
+pub use metadata::*;
+pub use msgs::*;
+pub use packet::*;
+pub use proto::*;
+pub use response::*;
+pub use state::*;
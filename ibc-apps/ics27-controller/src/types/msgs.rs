@@ -0,0 +1,64 @@
+//! Defines the messages sent to the CosmWasm contract by its users.
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::AnyMsg;
+
+use super::response::AccountsResponse;
+use super::state::{AccountInfo, Config, PendingPacket};
+
+// ------------------------------------------------------------
+// Implementation of the InstantiateMsg struct
+// ------------------------------------------------------------
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The timeout of the transactions that do not set one, in seconds.
+    pub default_timeout: u64,
+}
+
+// ------------------------------------------------------------
+// Implementation of the ExecuteMsg enum and its variants
+// ------------------------------------------------------------
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Registers an interchain account of the sender on the host chain of
+    /// the given connection, opening a channel to its interchain accounts
+    /// host. The account is available once the channel is open.
+    RegisterAccount {
+        connection_id: String,
+        counterparty_connection_id: String,
+    },
+    /// Sends a transaction of the given messages to the interchain account
+    /// of the sender on the given connection.
+    SendTx {
+        connection_id: String,
+        messages: Vec<AnyMsg>,
+        memo: Option<String>,
+        /// The timeout of the transaction in seconds, which defaults to the
+        /// one of the contract configuration.
+        timeout: Option<u64>,
+    },
+}
+
+// ------------------------------------------------------------
+// Implementation of the QueryMsg enum and its variants
+// ------------------------------------------------------------
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(Config)]
+    Config {},
+    /// Returns the interchain account registered on a connection.
+    #[returns(AccountInfo)]
+    Account { connection_id: String },
+    /// Returns the interchain accounts of an owner, by connection.
+    #[returns(AccountsResponse)]
+    Accounts {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(Option<PendingPacket>)]
+    PendingPacket { channel_id: String, sequence: u64 },
+}
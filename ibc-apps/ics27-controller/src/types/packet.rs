@@ -0,0 +1,41 @@
+//! Defines the packets sent to the interchain accounts and the outcome of
+//! the transactions they carry.
+use cosmwasm_schema::cw_serde;
+use cosmwasm_schema::serde::{Deserialize, Serialize};
+use cosmwasm_std::{AnyMsg, Binary};
+
+/// The type of an interchain account packet. Transactions are the only type
+/// defined by the protocol.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(crate = "cosmwasm_schema::serde")]
+pub enum PacketType {
+    #[serde(rename = "TYPE_EXECUTE_TX")]
+    ExecuteTx,
+}
+
+/// The data of the packets sent to the interchain accounts, encoded in JSON
+/// as ibc-go does.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(crate = "cosmwasm_schema::serde")]
+pub struct InterchainAccountPacketData {
+    #[serde(rename = "type")]
+    pub packet_type: PacketType,
+    /// The protobuf encoded `CosmosTx` executed by the interchain account.
+    pub data: Binary,
+    #[serde(default)]
+    pub memo: String,
+}
+
+/// The outcome of a transaction sent to an interchain account.
+#[cw_serde]
+pub enum PacketOutcome {
+    /// The transaction succeeded, with the responses of its messages.
+    Success { responses: Vec<AnyMsg> },
+    /// The transaction failed on the host chain. The host chains only
+    /// acknowledge the type of the error, to keep the acknowledgements
+    /// deterministic.
+    Error { error: String },
+    /// The packet timed out, which closes the ordered channel of the
+    /// account.
+    Timeout,
+}
@@ -0,0 +1,59 @@
+//! Protobuf definitions of the transactions sent to the interchain accounts
+//! and of their results, and of the message opening their channels.
+use cosmwasm_std::{AnyMsg, CosmosMsg};
+use ibc_core::channel::types::proto::v1::{
+    Channel as RawChannel, Counterparty as RawCounterparty, MsgChannelOpenInit,
+};
+use ibc_core::primitives::proto::Any;
+use prost::Message;
+
+use super::metadata::HOST_PORT_ID;
+
+pub const MSG_CHANNEL_OPEN_INIT_TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelOpenInit";
+
+/// The `ibc.applications.interchain_accounts.v1.CosmosTx` type.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct CosmosTx {
+    #[prost(message, repeated, tag = "1")]
+    pub messages: Vec<Any>,
+}
+
+/// The `cosmos.base.abci.v1beta1.TxMsgData` type, returned as the result of
+/// the successful transactions. Its deprecated `data` field is skipped.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct TxMsgData {
+    #[prost(message, repeated, tag = "2")]
+    pub msg_responses: Vec<Any>,
+}
+
+/// Returns the message opening an ordered channel from the given port to
+/// the interchain accounts host, signed by the contract owning the port.
+pub fn channel_open_init_msg(
+    port_id: String,
+    connection_id: String,
+    version: String,
+    signer: String,
+) -> CosmosMsg {
+    let msg = MsgChannelOpenInit {
+        port_id,
+        channel: Some(RawChannel {
+            // STATE_INIT
+            state: 1,
+            // ORDER_ORDERED
+            ordering: 2,
+            counterparty: Some(RawCounterparty {
+                port_id: HOST_PORT_ID.to_string(),
+                channel_id: String::new(),
+            }),
+            connection_hops: vec![connection_id],
+            version,
+            upgrade_sequence: 0,
+        }),
+        signer,
+    };
+
+    CosmosMsg::Any(AnyMsg {
+        type_url: MSG_CHANNEL_OPEN_INIT_TYPE_URL.to_string(),
+        value: msg.encode_to_vec().into(),
+    })
+}
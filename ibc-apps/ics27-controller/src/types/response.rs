@@ -0,0 +1,9 @@
+//! Defines the responses to the queries of the contract.
+use cosmwasm_schema::cw_serde;
+
+use super::state::AccountInfo;
+
+#[cw_serde]
+pub struct AccountsResponse {
+    pub accounts: Vec<AccountInfo>,
+}
@@ -0,0 +1,49 @@
+//! Defines the configuration and records kept in the contract storage.
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Addr;
+
+#[cw_serde]
+pub struct Config {
+    /// The timeout of the transactions that do not set one, in seconds.
+    pub default_timeout: u64,
+}
+
+/// The state of the channel of an interchain account.
+#[cw_serde]
+pub enum ChannelState {
+    Open,
+    /// The channel was closed by the counterparty or by a timeout. The owner
+    /// may register the account again, over a new channel.
+    Closed,
+}
+
+/// An interchain account controlled by the contract.
+#[cw_serde]
+pub struct AccountInfo {
+    pub owner: Addr,
+    pub connection_id: String,
+    pub counterparty_connection_id: String,
+    pub channel_id: String,
+    /// The address of the account on the host chain.
+    pub address: String,
+    pub channel_state: ChannelState,
+}
+
+/// A registration waiting for its channel to open.
+#[cw_serde]
+pub struct PendingRegistration {
+    pub owner: Addr,
+    pub counterparty_connection_id: String,
+    /// The channel opened for the registration, once its `OpenInit` ran.
+    pub channel_id: Option<String>,
+}
+
+/// A transaction sent to an interchain account, waiting for its
+/// acknowledgement or timeout.
+#[cw_serde]
+pub struct PendingPacket {
+    pub owner: Addr,
+    pub connection_id: String,
+    pub channel_id: String,
+    pub sequence: u64,
+}
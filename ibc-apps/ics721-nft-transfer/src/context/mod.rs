@@ -0,0 +1,326 @@
+pub mod nft_transfer_ctx;
+
+use std::cell::RefCell;
+use std::str::FromStr;
+
+use cosmwasm_std::{
+    instantiate2_address, to_json_binary, Addr, Api, CosmosMsg, Deps, DepsMut, Env, HexBinary,
+    QuerierWrapper, Storage, WasmMsg,
+};
+use cw_storage_plus::{Item, Map};
+use ibc_app_cw::cache::{CachedStorage, StorageCache};
+use ibc_app_nft_transfer::types::{ClassData, ClassId, ClassUri, PrefixedClassId};
+use ibc_core::host::types::identifiers::PortId;
+
+use crate::types::{
+    class_trace_hash, ChannelInfo, Config, ContractError, Cw721ExecuteMsg, Cw721InstantiateMsg,
+    Cw721QueryMsg, NftInfoResponse, VoucherClass, VoucherToken,
+};
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// The ICS-721 channels opened by the contract, by channel identifier.
+pub const CHANNELS: Map<&str, ChannelInfo> = Map::new("channels");
+
+/// The classes received from other chains, by the hash of their class trace.
+pub const VOUCHER_CLASSES: Map<&str, VoucherClass> = Map::new("voucher_classes");
+
+/// The hash of the class trace minted by each voucher contract, by the
+/// address of the contract.
+pub const CLASS_HASHES: Map<&Addr, String> = Map::new("class_hashes");
+
+/// The metadata of the vouchers minted by the contract, by the hash of their
+/// class trace and by token identifier.
+pub const VOUCHER_TOKENS: Map<(&str, &str), VoucherToken> = Map::new("voucher_tokens");
+
+/// The channel each escrowed NFT was sent over, by class identifier, as it
+/// appears in the packets sent over the channel, and by token identifier.
+pub const ESCROWED: Map<(&str, &str), String> = Map::new("escrowed");
+
+/// NftTransferContext is a wrapper around the deps and env that provides
+/// access to the methods under the ibc-rs NFT transfer Validation and
+/// Execution traits, and collects the messages they dispatch.
+///
+/// Its storage writes are cached until committed, as ibc-rs creates the
+/// voucher classes through a shared reference, and receives the NFTs of a
+/// packet one by one, so that a failed reception must discard the writes of
+/// the NFTs received before.
+pub struct NftTransferContext<'a> {
+    deps: Option<Deps<'a>>,
+    deps_mut: Option<DepsMut<'a>>,
+    env: Env,
+    cache: StorageCache,
+    messages: RefCell<Vec<CosmosMsg>>,
+}
+
+impl<'a> NftTransferContext<'a> {
+    /// Constructs a new NftTransferContext object with the given deps and
+    /// env.
+    pub fn new_ref(deps: Deps<'a>, env: Env) -> Self {
+        Self {
+            deps: Some(deps),
+            deps_mut: None,
+            env,
+            cache: StorageCache::new(),
+            messages: RefCell::default(),
+        }
+    }
+
+    /// Constructs a new NftTransferContext object with the given deps_mut
+    /// and env.
+    pub fn new_mut(deps_mut: DepsMut<'a>, env: Env) -> Self {
+        Self {
+            deps: None,
+            deps_mut: Some(deps_mut),
+            env,
+            cache: StorageCache::new(),
+            messages: RefCell::default(),
+        }
+    }
+
+    /// Returns the env of the context.
+    pub fn env(&self) -> &Env {
+        &self.env
+    }
+
+    pub fn api(&self) -> &dyn Api {
+        match self.deps {
+            Some(ref deps) => deps.api,
+            None => match self.deps_mut {
+                Some(ref deps) => deps.api,
+                None => panic!("Either deps or deps_mut should be available"),
+            },
+        }
+    }
+
+    pub fn querier(&self) -> &QuerierWrapper<'a> {
+        match self.deps {
+            Some(ref deps) => &deps.querier,
+            None => match self.deps_mut {
+                Some(ref deps) => &deps.querier,
+                None => panic!("Either deps or deps_mut should be available"),
+            },
+        }
+    }
+
+    pub fn storage_ref(&self) -> &dyn Storage {
+        match self.deps {
+            Some(ref deps) => deps.storage,
+            None => match self.deps_mut {
+                Some(ref deps) => deps.storage,
+                None => panic!("Either deps or deps_mut should be available"),
+            },
+        }
+    }
+
+    /// Returns the contract storage with the cached writes applied. Any
+    /// write through it is cached until [`Self::commit`] is called.
+    pub fn store(&self) -> CachedStorage<'_> {
+        self.cache.storage(self.storage_ref())
+    }
+
+    /// Writes the cached changes to the contract storage.
+    pub fn commit(&mut self) {
+        let storage = match self.deps_mut {
+            Some(ref mut deps) => &mut *deps.storage,
+            None => panic!("deps_mut should be available"),
+        };
+
+        self.cache.commit(storage);
+    }
+
+    /// Drops the cached changes and the messages dispatched so far.
+    pub fn discard(&mut self) {
+        self.cache.discard();
+        self.messages.take();
+    }
+
+    pub fn contract_address(&self) -> &Addr {
+        &self.env.contract.address
+    }
+
+    /// Returns the port bound to the contract by the hosting chain.
+    pub fn port_id(&self) -> Result<PortId, ContractError> {
+        Ok(PortId::from_str(&format!(
+            "wasm.{}",
+            self.contract_address()
+        ))?)
+    }
+
+    pub fn config(&self) -> Result<Config, ContractError> {
+        Ok(CONFIG.load(&self.store())?)
+    }
+
+    /// Returns the messages dispatched by the executed transfer callbacks.
+    pub fn take_messages(&mut self) -> Vec<CosmosMsg> {
+        self.messages.take()
+    }
+
+    /// Returns the class of the NFTs of a cw721 contract: the class trace of
+    /// a voucher contract, or the address of any other contract.
+    pub fn prefixed_class_id(&self, contract: &Addr) -> Result<PrefixedClassId, ContractError> {
+        // The cached storage is borrowed until the end of the statement.
+        let hash = CLASS_HASHES.may_load(&self.store(), contract)?;
+
+        match hash {
+            Some(hash) => {
+                let class = VOUCHER_CLASSES.load(&self.store(), &hash)?;
+
+                Ok(PrefixedClassId::from_str(&class.class_id)?)
+            }
+            None => Ok(ClassId::from_str(contract.as_str())?.into()),
+        }
+    }
+
+    pub fn voucher_class(
+        &self,
+        class_id: &PrefixedClassId,
+    ) -> Result<Option<VoucherClass>, ContractError> {
+        Ok(VOUCHER_CLASSES.may_load(&self.store(), &class_trace_hash(class_id))?)
+    }
+
+    /// Returns the voucher class of a class trace, failing if none of its
+    /// NFTs was ever received.
+    pub fn existing_voucher_class(
+        &self,
+        class_id: &PrefixedClassId,
+    ) -> Result<VoucherClass, ContractError> {
+        self.voucher_class(class_id)?
+            .ok_or_else(|| ContractError::MissingVoucherClass {
+                class_id: class_id.to_string(),
+            })
+    }
+
+    /// Returns the cw721 contract of a class: the contract of a voucher
+    /// class, or the contract whose address is the base class identifier.
+    pub fn class_contract(&self, class_id: &PrefixedClassId) -> Result<Addr, ContractError> {
+        if class_id.trace_path.is_empty() {
+            return Ok(self.api().addr_validate(class_id.base_class_id.as_ref())?);
+        }
+
+        Ok(self.existing_voucher_class(class_id)?.contract)
+    }
+
+    /// Returns the channel an NFT was sent over, if escrowed.
+    pub fn escrow_channel(
+        &self,
+        class_id: &PrefixedClassId,
+        token_id: &str,
+    ) -> Result<Option<String>, ContractError> {
+        Ok(ESCROWED.may_load(&self.store(), (&class_id.to_string(), token_id))?)
+    }
+
+    /// Records the class of a received NFT, instantiating the cw721 contract
+    /// minting its vouchers on its first reception, or updating its metadata
+    /// otherwise.
+    pub fn create_or_update_voucher_class(
+        &self,
+        class_id: &PrefixedClassId,
+        class_uri: Option<&ClassUri>,
+        class_data: Option<&ClassData>,
+    ) -> Result<(), ContractError> {
+        let hash = class_trace_hash(class_id);
+
+        let contract = match self.voucher_class(class_id)? {
+            Some(class) => class.contract,
+            None => self.instantiate_voucher_contract(class_id, &hash)?,
+        };
+
+        let class = VoucherClass {
+            class_id: class_id.to_string(),
+            class_uri: class_uri.map(ToString::to_string),
+            class_data: class_data.map(ToString::to_string),
+            contract,
+        };
+
+        VOUCHER_CLASSES.save(&mut self.store(), &hash, &class)?;
+
+        Ok(())
+    }
+
+    /// Dispatches the instantiation of the voucher contract of a class, at
+    /// an address derived from the hash of the class trace, and returns this
+    /// address.
+    fn instantiate_voucher_contract(
+        &self,
+        class_id: &PrefixedClassId,
+        hash: &str,
+    ) -> Result<Addr, ContractError> {
+        let code_id = self.config()?.cw721_code_id;
+
+        let salt = HexBinary::from_hex(hash)?;
+
+        let checksum = self.querier().query_wasm_code_info(code_id)?.checksum;
+        let creator = self
+            .api()
+            .addr_canonicalize(self.contract_address().as_str())?;
+        let address = instantiate2_address(checksum.as_slice(), &creator, &salt)?;
+        let contract = self.api().addr_humanize(&address)?;
+
+        let msg = Cw721InstantiateMsg {
+            name: class_id.to_string(),
+            symbol: class_id.base_class_id.to_string(),
+            minter: Some(self.contract_address().to_string()),
+        };
+
+        self.messages.borrow_mut().push(
+            WasmMsg::Instantiate2 {
+                admin: Some(self.contract_address().to_string()),
+                code_id,
+                label: format!("ics721 voucher {hash}"),
+                msg: to_json_binary(&msg)?,
+                funds: vec![],
+                salt: salt.into(),
+            }
+            .into(),
+        );
+
+        CLASS_HASHES.save(&mut self.store(), &contract, &hash.to_string())?;
+
+        Ok(contract)
+    }
+
+    /// Returns the metadata of a voucher minted by the contract.
+    pub fn voucher_token(
+        &self,
+        class_id: &PrefixedClassId,
+        token_id: &str,
+    ) -> Result<Option<VoucherToken>, ContractError> {
+        Ok(VOUCHER_TOKENS.may_load(&self.store(), (&class_trace_hash(class_id), token_id))?)
+    }
+
+    /// Returns the URI of an NFT of the hosting chain, as known by its cw721
+    /// contract.
+    pub fn native_token_uri(
+        &self,
+        contract: &Addr,
+        token_id: &str,
+    ) -> Result<Option<String>, ContractError> {
+        let resp: NftInfoResponse = self.querier().query_wasm_smart(
+            contract,
+            &Cw721QueryMsg::NftInfo {
+                token_id: token_id.to_string(),
+            },
+        )?;
+
+        Ok(resp.token_uri)
+    }
+
+    /// Dispatches a cw721 message to the contract of a class.
+    pub fn execute_cw721(
+        &self,
+        contract: &Addr,
+        msg: &Cw721ExecuteMsg,
+    ) -> Result<(), ContractError> {
+        self.messages.borrow_mut().push(
+            WasmMsg::Execute {
+                contract_addr: contract.to_string(),
+                msg: to_json_binary(msg)?,
+                funds: vec![],
+            }
+            .into(),
+        );
+
+        Ok(())
+    }
+}
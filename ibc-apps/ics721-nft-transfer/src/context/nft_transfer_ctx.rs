@@ -0,0 +1,356 @@
+//! Implementation of the NFT transfer contexts of `ibc-app-nft-transfer`.
+use std::str::FromStr;
+
+use cosmwasm_std::Addr;
+use ibc_app_nft_transfer::context::{
+    NftClassContext, NftContext, NftTransferExecutionContext, NftTransferValidationContext,
+};
+use ibc_app_nft_transfer::types::{
+    ClassData, ClassId, ClassUri, Memo, PrefixedClassId, TokenData, TokenId, TokenUri,
+};
+use ibc_core::host::types::error::HostError;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::Signer;
+
+use super::{NftTransferContext, ESCROWED, VOUCHER_TOKENS};
+use crate::types::{class_trace_hash, ContractError, Cw721ExecuteMsg, VoucherToken};
+
+/// An NFT as sent in the packets of the contract.
+#[derive(Clone, Debug)]
+pub struct Nft {
+    pub class_id: ClassId,
+    pub token_id: TokenId,
+    pub token_uri: Option<TokenUri>,
+    pub token_data: Option<TokenData>,
+}
+
+impl NftContext for Nft {
+    fn get_class_id(&self) -> &ClassId {
+        &self.class_id
+    }
+
+    fn get_id(&self) -> &TokenId {
+        &self.token_id
+    }
+
+    fn get_uri(&self) -> Option<&TokenUri> {
+        self.token_uri.as_ref()
+    }
+
+    fn get_data(&self) -> Option<&TokenData> {
+        self.token_data.as_ref()
+    }
+}
+
+/// A class as sent in the packets of the contract.
+#[derive(Clone, Debug)]
+pub struct NftClass {
+    pub class_id: ClassId,
+    pub class_uri: Option<ClassUri>,
+    pub class_data: Option<ClassData>,
+}
+
+impl NftClassContext for NftClass {
+    fn get_id(&self) -> &ClassId {
+        &self.class_id
+    }
+
+    fn get_uri(&self) -> Option<&ClassUri> {
+        self.class_uri.as_ref()
+    }
+
+    fn get_data(&self) -> Option<&ClassData> {
+        self.class_data.as_ref()
+    }
+}
+
+impl NftTransferValidationContext for NftTransferContext<'_> {
+    type AccountId = Addr;
+    type Nft = Nft;
+    type NftClass = NftClass;
+
+    fn sender_account(&self, sender: &Signer) -> Result<Addr, HostError> {
+        self.api()
+            .addr_validate(sender.as_ref())
+            .map_err(HostError::invalid_state)
+    }
+
+    fn receiver_account(&self, receiver: &Signer) -> Result<Addr, HostError> {
+        self.api()
+            .addr_validate(receiver.as_ref())
+            .map_err(HostError::invalid_state)
+    }
+
+    fn get_port(&self) -> Result<PortId, HostError> {
+        Ok(self.port_id()?)
+    }
+
+    fn can_send_nft(&self) -> Result<(), HostError> {
+        Ok(())
+    }
+
+    fn can_receive_nft(&self) -> Result<(), HostError> {
+        Ok(())
+    }
+
+    fn create_or_update_class_validate(
+        &self,
+        _class_id: &PrefixedClassId,
+        _class_uri: Option<&ClassUri>,
+        _class_data: Option<&ClassData>,
+    ) -> Result<(), HostError> {
+        self.config()?;
+
+        Ok(())
+    }
+
+    /// The NFTs are owned by the contract once sent to it along with the
+    /// transfer, so that only their escrow record is checked here.
+    fn escrow_nft_validate(
+        &self,
+        _from_account: &Addr,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
+        _memo: &Memo,
+    ) -> Result<(), HostError> {
+        if self.escrow_channel(class_id, token_id.as_ref())?.is_some() {
+            return Err(ContractError::AlreadyEscrowed {
+                class_id: class_id.to_string(),
+                token_id: token_id.to_string(),
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
+    fn unescrow_nft_validate(
+        &self,
+        _to_account: &Addr,
+        _port_id: &PortId,
+        channel_id: &ChannelId,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
+    ) -> Result<(), HostError> {
+        if self.escrow_channel(class_id, token_id.as_ref())?.as_deref() != Some(channel_id.as_str())
+        {
+            return Err(ContractError::NotEscrowed {
+                class_id: class_id.to_string(),
+                token_id: token_id.to_string(),
+                channel_id: channel_id.to_string(),
+            }
+            .into());
+        }
+
+        self.class_contract(class_id)?;
+
+        Ok(())
+    }
+
+    /// The voucher contract of a class is instantiated along with the class,
+    /// which ibc-rs creates before minting its vouchers. A voucher minted
+    /// already is rejected here, as its cw721 contract would otherwise fail
+    /// the whole transaction.
+    fn mint_nft_validate(
+        &self,
+        _account: &Addr,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
+        _token_uri: Option<&TokenUri>,
+        _token_data: Option<&TokenData>,
+    ) -> Result<(), HostError> {
+        self.existing_voucher_class(class_id)?;
+
+        if self.voucher_token(class_id, token_id.as_ref())?.is_some() {
+            return Err(ContractError::AlreadyMinted {
+                class_id: class_id.to_string(),
+                token_id: token_id.to_string(),
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
+    fn burn_nft_validate(
+        &self,
+        _account: &Addr,
+        class_id: &PrefixedClassId,
+        _token_id: &TokenId,
+        _memo: &Memo,
+    ) -> Result<(), HostError> {
+        self.existing_voucher_class(class_id)?;
+
+        Ok(())
+    }
+
+    fn token_hash_string(&self, class_id: &PrefixedClassId, token_id: &TokenId) -> Option<String> {
+        Some(format!("ibc/{}/{token_id}", class_trace_hash(class_id)))
+    }
+
+    /// Returns an NFT with the metadata it carries: the URI and data
+    /// received along with a voucher, or the URI known by the cw721 contract
+    /// of a native NFT.
+    fn get_nft(&self, class_id: &PrefixedClassId, token_id: &TokenId) -> Result<Nft, HostError> {
+        let (token_uri, token_data) = match class_id.trace_path.is_empty() {
+            true => {
+                let contract = self.class_contract(class_id)?;
+
+                (self.native_token_uri(&contract, token_id.as_ref())?, None)
+            }
+            false => {
+                let token =
+                    self.voucher_token(class_id, token_id.as_ref())?
+                        .unwrap_or(VoucherToken {
+                            token_uri: None,
+                            token_data: None,
+                        });
+
+                (token.token_uri, token.token_data)
+            }
+        };
+
+        Ok(Nft {
+            class_id: class_id.base_class_id.clone(),
+            token_id: token_id.clone(),
+            token_uri: token_uri
+                .map(|uri| TokenUri::from_str(&uri))
+                .transpose()
+                .map_err(ContractError::from)?,
+            token_data: token_data
+                .map(|data| TokenData::from_str(&data))
+                .transpose()
+                .map_err(ContractError::from)?,
+        })
+    }
+
+    fn get_nft_class(&self, class_id: &PrefixedClassId) -> Result<NftClass, HostError> {
+        let (class_uri, class_data) = match self.voucher_class(class_id)? {
+            Some(class) => (class.class_uri, class.class_data),
+            None => (None, None),
+        };
+
+        Ok(NftClass {
+            class_id: class_id.base_class_id.clone(),
+            class_uri: class_uri
+                .map(|uri| ClassUri::from_str(&uri))
+                .transpose()
+                .map_err(ContractError::from)?,
+            class_data: class_data
+                .map(|data| ClassData::from_str(&data))
+                .transpose()
+                .map_err(ContractError::from)?,
+        })
+    }
+}
+
+impl NftTransferExecutionContext for NftTransferContext<'_> {
+    fn create_or_update_class_execute(
+        &self,
+        class_id: &PrefixedClassId,
+        class_uri: Option<&ClassUri>,
+        class_data: Option<&ClassData>,
+    ) -> Result<(), HostError> {
+        Ok(self.create_or_update_voucher_class(class_id, class_uri, class_data)?)
+    }
+
+    fn escrow_nft_execute(
+        &mut self,
+        _from_account: &Addr,
+        _port_id: &PortId,
+        channel_id: &ChannelId,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
+        _memo: &Memo,
+    ) -> Result<(), HostError> {
+        ESCROWED
+            .save(
+                &mut self.store(),
+                (&class_id.to_string(), token_id.as_ref()),
+                &channel_id.to_string(),
+            )
+            .map_err(HostError::failed_to_store)
+    }
+
+    fn unescrow_nft_execute(
+        &mut self,
+        to_account: &Addr,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
+    ) -> Result<(), HostError> {
+        ESCROWED.remove(
+            &mut self.store(),
+            (&class_id.to_string(), token_id.as_ref()),
+        );
+
+        let contract = self.class_contract(class_id)?;
+
+        Ok(self.execute_cw721(
+            &contract,
+            &Cw721ExecuteMsg::TransferNft {
+                recipient: to_account.to_string(),
+                token_id: token_id.to_string(),
+            },
+        )?)
+    }
+
+    fn mint_nft_execute(
+        &mut self,
+        account: &Addr,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
+        token_uri: Option<&TokenUri>,
+        token_data: Option<&TokenData>,
+    ) -> Result<(), HostError> {
+        let contract = self.existing_voucher_class(class_id)?.contract;
+
+        let token = VoucherToken {
+            token_uri: token_uri.map(ToString::to_string),
+            token_data: token_data.map(ToString::to_string),
+        };
+
+        VOUCHER_TOKENS
+            .save(
+                &mut self.store(),
+                (&class_trace_hash(class_id), token_id.as_ref()),
+                &token,
+            )
+            .map_err(HostError::failed_to_store)?;
+
+        Ok(self.execute_cw721(
+            &contract,
+            &Cw721ExecuteMsg::Mint {
+                token_id: token_id.to_string(),
+                owner: account.to_string(),
+                token_uri: token.token_uri,
+                extension: None,
+            },
+        )?)
+    }
+
+    fn burn_nft_execute(
+        &mut self,
+        _account: &Addr,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
+        _memo: &Memo,
+    ) -> Result<(), HostError> {
+        let contract = self.existing_voucher_class(class_id)?.contract;
+
+        VOUCHER_TOKENS.remove(
+            &mut self.store(),
+            (&class_trace_hash(class_id), token_id.as_ref()),
+        );
+
+        Ok(self.execute_cw721(
+            &contract,
+            &Cw721ExecuteMsg::Burn {
+                token_id: token_id.to_string(),
+            },
+        )?)
+    }
+}
@@ -0,0 +1,434 @@
+use std::str::FromStr;
+
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, Binary, Ibc3ChannelOpenResponse, IbcBasicResponse,
+    IbcChannelCloseMsg, IbcChannelConnectMsg, IbcChannelOpenMsg, IbcChannelOpenResponse, IbcMsg,
+    IbcPacketAckMsg, IbcPacketReceiveMsg, IbcPacketTimeoutMsg, IbcReceiveResponse, IbcTimeout,
+    MessageInfo, Order as StorageOrder, Response,
+};
+use cw_storage_plus::Bound;
+use ibc_app_cw::convert::{
+    connection_hops_from_cw, counterparty_from_cw, endpoint_from_cw, events_from_extras,
+    order_from_cw, packet_from_cw,
+};
+use ibc_app_nft_transfer::context::{
+    NftClassContext, NftContext, NftTransferExecutionContext, NftTransferValidationContext,
+};
+use ibc_app_nft_transfer::module::{
+    on_acknowledgement_packet_execute, on_acknowledgement_packet_validate,
+    on_chan_close_confirm_execute, on_chan_close_confirm_validate, on_chan_close_init_execute,
+    on_chan_close_init_validate, on_chan_open_ack_execute, on_chan_open_ack_validate,
+    on_chan_open_confirm_execute, on_chan_open_confirm_validate, on_chan_open_init_execute,
+    on_chan_open_init_validate, on_chan_open_try_execute, on_chan_open_try_validate,
+    on_recv_packet_execute, on_timeout_packet_execute, on_timeout_packet_validate,
+};
+use ibc_app_nft_transfer::types::events::TransferEvent;
+use ibc_app_nft_transfer::types::packet::PacketData;
+use ibc_app_nft_transfer::types::{
+    is_sender_chain_source, Memo, PrefixedClassId, TokenId, TokenIds,
+};
+use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
+use ibc_core::channel::types::Version;
+use ibc_core::host::types::identifiers::ChannelId;
+use ibc_core::primitives::Signer;
+use ibc_core::router::types::event::ModuleEvent;
+use ibc_core::router::types::module::ModuleExtras;
+
+use crate::context::{
+    NftTransferContext, CHANNELS, CLASS_HASHES, CONFIG, ESCROWED, VOUCHER_CLASSES,
+};
+use crate::types::{
+    ChannelInfo, ClassTraceResponse, ClassTracesResponse, Config, ContractError, Cw721ReceiveMsg,
+    EscrowedResponse, ExecuteMsg, InstantiateMsg, QueryMsg, TransferMsg, VoucherClass,
+};
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
+impl NftTransferContext<'_> {
+    /// Instantiates the contract with the given [`InstantiateMsg`] message.
+    pub fn instantiate(&mut self, msg: InstantiateMsg) -> Result<Response, ContractError> {
+        let config = Config {
+            default_timeout: msg.default_timeout,
+            cw721_code_id: msg.cw721_code_id,
+        };
+
+        CONFIG.save(&mut self.store(), &config)?;
+
+        self.commit();
+
+        Ok(Response::default())
+    }
+
+    /// Executes the given [`ExecuteMsg`].
+    pub fn execute(
+        &mut self,
+        info: MessageInfo,
+        msg: ExecuteMsg,
+    ) -> Result<Response, ContractError> {
+        match msg {
+            ExecuteMsg::ReceiveNft(msg) => self.receive_nft(info, msg),
+        }
+    }
+
+    /// Transfers the NFT sent to the contract by its cw721 contract.
+    fn receive_nft(
+        &mut self,
+        info: MessageInfo,
+        msg: Cw721ReceiveMsg,
+    ) -> Result<Response, ContractError> {
+        let sender = self.api().addr_validate(&msg.sender)?;
+
+        let transfer_msg: TransferMsg = from_json(&msg.msg)?;
+
+        self.transfer(sender, &info.sender, &msg.token_id, transfer_msg)
+    }
+
+    /// Sends an NFT held by the contract for the sender to the counterparty
+    /// chain: vouchers returning to their source are burned, while any other
+    /// NFT is escrowed for the channel.
+    fn transfer(
+        &mut self,
+        sender: Addr,
+        contract: &Addr,
+        token_id: &str,
+        msg: TransferMsg,
+    ) -> Result<Response, ContractError> {
+        if msg.receiver.is_empty() {
+            return Err(ContractError::EmptyReceiver);
+        }
+
+        if !CHANNELS.has(&self.store(), &msg.channel_id) {
+            return Err(ContractError::UnknownChannel {
+                channel_id: msg.channel_id,
+            });
+        }
+
+        let port_id = self.port_id()?;
+        let channel_id = ChannelId::from_str(&msg.channel_id)?;
+
+        let class_id = self.prefixed_class_id(contract)?;
+        let token_id = TokenId::from_str(token_id)?;
+
+        self.can_send_nft()?;
+
+        // The metadata is read before a voucher is burned.
+        let nft_class = self.get_nft_class(&class_id)?;
+        let nft = self.get_nft(&class_id, &token_id)?;
+
+        let packet_data = PacketData::new(
+            class_id,
+            nft_class.get_uri().cloned(),
+            nft_class.get_data().cloned(),
+            TokenIds(vec![token_id]),
+            nft.get_uri().cloned().into_iter().collect(),
+            nft.get_data().cloned().into_iter().collect(),
+            Signer::from(sender.to_string()),
+            Signer::from(msg.receiver),
+            Memo::from(msg.memo.unwrap_or_default()),
+        )?;
+
+        let sender = self.sender_account(&packet_data.sender)?;
+        let class_id = &packet_data.class_id;
+        let token_id = nft.get_id();
+        let memo = packet_data.memo.clone().unwrap_or_else(|| Memo::from(""));
+
+        if is_sender_chain_source(port_id.clone(), channel_id.clone(), class_id) {
+            self.escrow_nft_validate(&sender, &port_id, &channel_id, class_id, token_id, &memo)?;
+            self.escrow_nft_execute(&sender, &port_id, &channel_id, class_id, token_id, &memo)?;
+        } else {
+            self.burn_nft_validate(&sender, class_id, token_id, &memo)?;
+            self.burn_nft_execute(&sender, class_id, token_id, &memo)?;
+        }
+
+        let timeout = msg.timeout.unwrap_or(self.config()?.default_timeout);
+
+        let send_packet = IbcMsg::SendPacket {
+            channel_id: msg.channel_id,
+            data: to_json_binary(&packet_data)?,
+            timeout: IbcTimeout::with_timestamp(self.env().block.time.plus_seconds(timeout)),
+        };
+
+        let transfer_event = TransferEvent {
+            sender: packet_data.sender,
+            receiver: packet_data.receiver,
+            class: packet_data.class_id,
+            tokens: packet_data.token_ids,
+            memo,
+        };
+
+        let extras = ModuleExtras {
+            events: vec![ModuleEvent::from(transfer_event)],
+            log: Vec::new(),
+        };
+
+        self.commit();
+
+        Ok(Response::default()
+            .add_messages(self.take_messages())
+            .add_message(send_packet)
+            .add_events(events_from_extras(extras)))
+    }
+
+    /// Queries the contract with the given [`QueryMsg`].
+    pub fn query(&self, msg: QueryMsg) -> Result<Binary, ContractError> {
+        let binary = match msg {
+            QueryMsg::Config {} => to_json_binary(&self.config()?)?,
+            QueryMsg::Channel { channel_id } => {
+                let channel = CHANNELS
+                    .may_load(self.storage_ref(), &channel_id)?
+                    .ok_or(ContractError::UnknownChannel { channel_id })?;
+
+                to_json_binary(&channel)?
+            }
+            QueryMsg::ClassTrace { hash } => {
+                let hash = hash.strip_prefix("ibc/").unwrap_or(&hash).to_uppercase();
+
+                let class = VOUCHER_CLASSES.load(self.storage_ref(), &hash)?;
+
+                to_json_binary(&class_trace_response(hash, class)?)?
+            }
+            QueryMsg::ClassTraces { start_after, limit } => {
+                let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+                let start = start_after.as_deref().map(Bound::exclusive);
+
+                let class_traces = VOUCHER_CLASSES
+                    .range(self.storage_ref(), start, None, StorageOrder::Ascending)
+                    .take(limit)
+                    .map(|item| {
+                        let (hash, class) = item?;
+                        class_trace_response(hash, class)
+                    })
+                    .collect::<Result<_, ContractError>>()?;
+
+                to_json_binary(&ClassTracesResponse { class_traces })?
+            }
+            QueryMsg::VoucherClass { contract } => {
+                let contract = self.api().addr_validate(&contract)?;
+
+                let hash = CLASS_HASHES.load(self.storage_ref(), &contract)?;
+                let class = VOUCHER_CLASSES.load(self.storage_ref(), &hash)?;
+
+                to_json_binary(&class_trace_response(hash, class)?)?
+            }
+            QueryMsg::Escrowed { class_id, token_id } => {
+                let channel_id = ESCROWED.may_load(self.storage_ref(), (&class_id, &token_id))?;
+
+                to_json_binary(&EscrowedResponse { channel_id })?
+            }
+        };
+
+        Ok(binary)
+    }
+
+    /// Runs the `OpenInit` and `OpenTry` callbacks of the channel handshake.
+    pub fn channel_open(
+        &mut self,
+        msg: IbcChannelOpenMsg,
+    ) -> Result<IbcChannelOpenResponse, ContractError> {
+        let channel = msg.channel();
+
+        let order = order_from_cw(&channel.order);
+        let connection_hops = connection_hops_from_cw(channel)?;
+        let (port_id, channel_id) = endpoint_from_cw(channel)?;
+        let counterparty = counterparty_from_cw(channel)?;
+
+        let (_, version) = match msg.counterparty_version() {
+            None => {
+                let version = Version::new(channel.version.clone());
+
+                on_chan_open_init_validate(
+                    self,
+                    order,
+                    &connection_hops,
+                    &port_id,
+                    &channel_id,
+                    &counterparty,
+                    &version,
+                )?;
+
+                on_chan_open_init_execute(
+                    self,
+                    order,
+                    &connection_hops,
+                    &port_id,
+                    &channel_id,
+                    &counterparty,
+                    &version,
+                )?
+            }
+            Some(counterparty_version) => {
+                let counterparty_version = Version::new(counterparty_version.to_string());
+
+                on_chan_open_try_validate(
+                    self,
+                    order,
+                    &connection_hops,
+                    &port_id,
+                    &channel_id,
+                    &counterparty,
+                    &counterparty_version,
+                )?;
+
+                on_chan_open_try_execute(
+                    self,
+                    order,
+                    &connection_hops,
+                    &port_id,
+                    &channel_id,
+                    &counterparty,
+                    &counterparty_version,
+                )?
+            }
+        };
+
+        Ok(Some(Ibc3ChannelOpenResponse {
+            version: version.to_string(),
+        }))
+    }
+
+    /// Runs the `OpenAck` and `OpenConfirm` callbacks of the channel
+    /// handshake, and records the opened channel.
+    pub fn channel_connect(
+        &mut self,
+        msg: IbcChannelConnectMsg,
+    ) -> Result<IbcBasicResponse, ContractError> {
+        let channel = msg.channel();
+
+        let (port_id, channel_id) = endpoint_from_cw(channel)?;
+
+        let extras = match msg.counterparty_version() {
+            Some(counterparty_version) => {
+                let counterparty_version = Version::new(counterparty_version.to_string());
+
+                on_chan_open_ack_validate(self, &port_id, &channel_id, &counterparty_version)?;
+                on_chan_open_ack_execute(self, &port_id, &channel_id, &counterparty_version)?
+            }
+            None => {
+                on_chan_open_confirm_validate(self, &port_id, &channel_id)?;
+                on_chan_open_confirm_execute(self, &port_id, &channel_id)?
+            }
+        };
+
+        let channel_info = ChannelInfo {
+            channel_id: channel.endpoint.channel_id.clone(),
+            counterparty_endpoint: channel.counterparty_endpoint.clone(),
+            connection_id: channel.connection_id.clone(),
+        };
+
+        CHANNELS.save(&mut self.store(), &channel_info.channel_id, &channel_info)?;
+
+        self.commit();
+
+        Ok(IbcBasicResponse::new()
+            .add_attribute("action", "channel_connect")
+            .add_attribute("channel_id", channel_info.channel_id)
+            .add_events(events_from_extras(extras)))
+    }
+
+    /// Runs the `CloseInit` and `CloseConfirm` callbacks. ICS-721 channels
+    /// cannot be closed by the contract, but the counterparty may close them.
+    pub fn channel_close(
+        &mut self,
+        msg: IbcChannelCloseMsg,
+    ) -> Result<IbcBasicResponse, ContractError> {
+        let channel = msg.channel();
+
+        let (port_id, channel_id) = endpoint_from_cw(channel)?;
+
+        let extras = match msg {
+            IbcChannelCloseMsg::CloseInit { .. } => {
+                on_chan_close_init_validate(self, &port_id, &channel_id)?;
+                on_chan_close_init_execute(self, &port_id, &channel_id)?
+            }
+            IbcChannelCloseMsg::CloseConfirm { .. } => {
+                on_chan_close_confirm_validate(self, &port_id, &channel_id)?;
+                on_chan_close_confirm_execute(self, &port_id, &channel_id)?
+            }
+        };
+
+        Ok(IbcBasicResponse::new().add_events(events_from_extras(extras)))
+    }
+
+    /// Receives the NFTs of a packet, minting vouchers or releasing escrowed
+    /// NFTs. A failed reception is acknowledged with an error, so that the
+    /// NFTs are refunded on the sending chain, and leaves no state behind
+    /// nor dispatches any message, even for the NFTs of the packet received
+    /// before the failure.
+    pub fn packet_receive(
+        &mut self,
+        msg: IbcPacketReceiveMsg,
+    ) -> Result<IbcReceiveResponse, ContractError> {
+        let packet = packet_from_cw(&msg.packet)?;
+
+        let (extras, ack) = on_recv_packet_execute(self, &packet);
+
+        let ack_status: AcknowledgementStatus = from_json(ack.as_bytes())?;
+
+        match ack_status.is_successful() {
+            true => self.commit(),
+            false => self.discard(),
+        }
+
+        Ok(IbcReceiveResponse::new(ack.as_bytes().to_vec())
+            .add_messages(self.take_messages())
+            .add_events(events_from_extras(extras)))
+    }
+
+    /// Handles the acknowledgement of a sent packet, refunding its NFTs if
+    /// the counterparty failed to receive them.
+    pub fn packet_ack(&mut self, msg: IbcPacketAckMsg) -> Result<IbcBasicResponse, ContractError> {
+        let packet = packet_from_cw(&msg.original_packet)?;
+        let ack = Acknowledgement::try_from(msg.acknowledgement.data.to_vec())?;
+        let relayer = Signer::from(msg.relayer.to_string());
+
+        on_acknowledgement_packet_validate(self, &packet, &ack, &relayer)?;
+
+        let (extras, result) = on_acknowledgement_packet_execute(self, &packet, &ack, &relayer);
+
+        result?;
+
+        self.commit();
+
+        Ok(IbcBasicResponse::new()
+            .add_messages(self.take_messages())
+            .add_events(events_from_extras(extras)))
+    }
+
+    /// Handles the timeout of a sent packet, refunding its NFTs.
+    pub fn packet_timeout(
+        &mut self,
+        msg: IbcPacketTimeoutMsg,
+    ) -> Result<IbcBasicResponse, ContractError> {
+        let packet = packet_from_cw(&msg.packet)?;
+        let relayer = Signer::from(msg.relayer.to_string());
+
+        on_timeout_packet_validate(self, &packet, &relayer)?;
+
+        let (extras, result) = on_timeout_packet_execute(self, &packet, &relayer);
+
+        result?;
+
+        self.commit();
+
+        Ok(IbcBasicResponse::new()
+            .add_messages(self.take_messages())
+            .add_events(events_from_extras(extras)))
+    }
+}
+
+fn class_trace_response(
+    hash: String,
+    class: VoucherClass,
+) -> Result<ClassTraceResponse, ContractError> {
+    let class_id = PrefixedClassId::from_str(&class.class_id)?;
+
+    Ok(ClassTraceResponse {
+        hash,
+        path: class_id.trace_path.to_string(),
+        base_class_id: class_id.base_class_id.to_string(),
+        contract: class.contract,
+        class_uri: class.class_uri,
+        class_data: class.class_data,
+    })
+}
@@ -0,0 +1,20 @@
+//! The CosmWasm contract implementation of the ICS-721 non-fungible token
+//! transfer application. It implements the NFT transfer contexts of
+//! `ibc-app-nft-transfer` over the contract storage and cw721 contracts, and
+//! binds the application callbacks to the CosmWasm IBC entry points, so that
+//! the contract owns the `wasm.<contract address>` port of the hosting chain.
+//!
+//! The classes of the hosting chain are cw721 contracts, identified by their
+//! address. Their NFTs are escrowed by the contract when sent to another
+//! chain, and the NFTs received from other chains are represented by
+//! vouchers, minted by a cw721 contract the contract instantiates for each
+//! class trace.
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+
+pub mod context;
+pub mod entrypoint;
+pub mod handlers;
+pub mod types;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,368 @@
+use std::str::FromStr;
+
+use cosmwasm_schema::serde::de::DeserializeOwned;
+use cosmwasm_std::testing::{
+    message_info, mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage,
+};
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, Binary, Checksum, CodeInfoResponse, ContractResult, CosmosMsg,
+    Deps, DepsMut, Env, IbcAcknowledgement, IbcBasicResponse, IbcChannel, IbcChannelCloseMsg,
+    IbcChannelConnectMsg, IbcChannelOpenMsg, IbcChannelOpenResponse, IbcEndpoint, IbcMsg, IbcOrder,
+    IbcPacket, IbcPacketAckMsg, IbcPacketReceiveMsg, IbcPacketTimeoutMsg, IbcReceiveResponse,
+    IbcTimeout, OwnedDeps, Response, SystemResult, Timestamp, WasmQuery,
+};
+use ibc_app_nft_transfer::types::error::NftTransferError;
+use ibc_app_nft_transfer::types::packet::PacketData;
+use ibc_app_nft_transfer::types::{
+    ack_success_b64, ClassData, ClassUri, PrefixedClassId, TokenData, TokenIds, TokenUri, VERSION,
+};
+use ibc_core::channel::types::acknowledgement::AcknowledgementStatus;
+
+use crate::entrypoint::{
+    execute, ibc_channel_close, ibc_channel_connect, ibc_channel_open, ibc_packet_ack,
+    ibc_packet_receive, ibc_packet_timeout, instantiate, query,
+};
+use crate::types::{
+    ContractError, Cw721QueryMsg, Cw721ReceiveMsg, EscrowedResponse, ExecuteMsg, InstantiateMsg,
+    QueryMsg, TransferMsg,
+};
+
+pub type MockDeps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+/// Test fixture of an NFT transfer contract with an ICS-721 channel to a
+/// counterparty chain.
+#[derive(Clone, Debug)]
+pub struct Fixture {
+    pub channel_id: String,
+    pub counterparty_port_id: String,
+    pub counterparty_channel_id: String,
+    pub connection_id: String,
+    pub default_timeout: u64,
+    pub cw721_code_id: u64,
+}
+
+impl Default for Fixture {
+    fn default() -> Self {
+        Self {
+            channel_id: "channel-0".to_string(),
+            counterparty_port_id: "nft-transfer".to_string(),
+            counterparty_channel_id: "channel-7".to_string(),
+            connection_id: "connection-2".to_string(),
+            default_timeout: 600,
+            cw721_code_id: 7,
+        }
+    }
+}
+
+impl Fixture {
+    pub fn addr(name: &str) -> Addr {
+        MockApi::default().addr_make(name)
+    }
+
+    /// Returns the env of the contract, whose address must be a valid one
+    /// to derive the addresses of the voucher contracts.
+    pub fn env() -> Env {
+        let mut env = mock_env();
+        env.contract.address = Self::addr("ics721");
+        env
+    }
+
+    /// Returns mock dependencies whose querier knows the code of the voucher
+    /// contracts, and answers the `NftInfo` queries of any cw721 contract.
+    pub fn dependencies(&self) -> MockDeps {
+        let mut deps = mock_dependencies();
+
+        let cw721_code_id = self.cw721_code_id;
+
+        deps.querier.update_wasm(move |query| match query {
+            WasmQuery::CodeInfo { code_id } if *code_id == cw721_code_id => {
+                let resp = CodeInfoResponse::new(
+                    cw721_code_id,
+                    Self::addr("creator"),
+                    Checksum::generate(b"cw721"),
+                );
+
+                SystemResult::Ok(ContractResult::Ok(to_json_binary(&resp).unwrap()))
+            }
+            WasmQuery::Smart { contract_addr, msg } => {
+                let Cw721QueryMsg::NftInfo { token_id } = from_json(msg).unwrap();
+
+                // The extension of the response must be ignored.
+                let resp = format!(
+                    r#"{{"token_uri":"{}","extension":{{"rarity":"rare"}}}}"#,
+                    Self::native_token_uri(contract_addr, &token_id)
+                );
+
+                SystemResult::Ok(ContractResult::Ok(Binary::from(resp.into_bytes())))
+            }
+            _ => panic!("unexpected wasm query {query:?}"),
+        });
+
+        deps
+    }
+
+    /// Returns the URI of a native NFT, as known by its cw721 contract.
+    pub fn native_token_uri(contract: &str, token_id: &str) -> String {
+        format!("https://nft.example/{}/{token_id}", &contract[..12])
+    }
+
+    pub fn port_id() -> String {
+        format!("wasm.{}", Self::env().contract.address)
+    }
+
+    pub fn endpoint(&self) -> IbcEndpoint {
+        IbcEndpoint {
+            port_id: Self::port_id(),
+            channel_id: self.channel_id.clone(),
+        }
+    }
+
+    pub fn counterparty_endpoint(&self) -> IbcEndpoint {
+        IbcEndpoint {
+            port_id: self.counterparty_port_id.clone(),
+            channel_id: self.counterparty_channel_id.clone(),
+        }
+    }
+
+    pub fn channel(&self, order: IbcOrder, version: &str) -> IbcChannel {
+        IbcChannel::new(
+            self.endpoint(),
+            self.counterparty_endpoint(),
+            order,
+            version,
+            self.connection_id.clone(),
+        )
+    }
+
+    /// Returns the prefix added to the classes received over the channel.
+    pub fn trace_prefix(&self) -> String {
+        format!("{}/{}", Self::port_id(), self.channel_id)
+    }
+
+    /// Returns the prefix of the classes sent back by the counterparty.
+    pub fn counterparty_trace_prefix(&self) -> String {
+        format!(
+            "{}/{}",
+            self.counterparty_port_id, self.counterparty_channel_id
+        )
+    }
+
+    pub fn instantiate(&self, deps: DepsMut<'_>) -> Result<Response, ContractError> {
+        let msg = InstantiateMsg {
+            default_timeout: self.default_timeout,
+            cw721_code_id: self.cw721_code_id,
+        };
+
+        instantiate(
+            deps,
+            Self::env(),
+            message_info(&Self::addr("admin"), &[]),
+            msg,
+        )
+    }
+
+    pub fn channel_open(
+        &self,
+        deps: DepsMut<'_>,
+        msg: IbcChannelOpenMsg,
+    ) -> Result<IbcChannelOpenResponse, ContractError> {
+        ibc_channel_open(deps, Self::env(), msg)
+    }
+
+    pub fn channel_connect(
+        &self,
+        deps: DepsMut<'_>,
+        msg: IbcChannelConnectMsg,
+    ) -> Result<IbcBasicResponse, ContractError> {
+        ibc_channel_connect(deps, Self::env(), msg)
+    }
+
+    pub fn channel_close(
+        &self,
+        deps: DepsMut<'_>,
+        msg: IbcChannelCloseMsg,
+    ) -> Result<IbcBasicResponse, ContractError> {
+        ibc_channel_close(deps, Self::env(), msg)
+    }
+
+    /// Instantiates the contract and opens the channel from its side.
+    pub fn setup(&self) -> MockDeps {
+        let mut deps = self.dependencies();
+
+        self.instantiate(deps.as_mut()).unwrap();
+
+        let channel = self.channel(IbcOrder::Unordered, VERSION);
+
+        self.channel_open(deps.as_mut(), IbcChannelOpenMsg::new_init(channel.clone()))
+            .unwrap();
+
+        self.channel_connect(
+            deps.as_mut(),
+            IbcChannelConnectMsg::new_ack(channel, VERSION),
+        )
+        .unwrap();
+
+        deps
+    }
+
+    pub fn transfer_msg(&self, receiver: &str) -> TransferMsg {
+        TransferMsg {
+            channel_id: self.channel_id.clone(),
+            receiver: receiver.to_string(),
+            timeout: None,
+            memo: None,
+        }
+    }
+
+    /// Transfers an NFT sent to the contract by the given cw721 contract.
+    pub fn send_nft(
+        &self,
+        deps: DepsMut<'_>,
+        cw721_contract: &Addr,
+        sender: &Addr,
+        token_id: &str,
+    ) -> Result<Response, ContractError> {
+        let msg = Cw721ReceiveMsg {
+            sender: sender.to_string(),
+            token_id: token_id.to_string(),
+            msg: to_json_binary(&self.transfer_msg("counterparty-receiver")).unwrap(),
+        };
+
+        execute(
+            deps,
+            Self::env(),
+            message_info(cw721_contract, &[]),
+            ExecuteMsg::from(msg),
+        )
+    }
+
+    /// Returns the data of a packet sending NFTs of a class, along with
+    /// their metadata.
+    pub fn packet_data(
+        class_id: &str,
+        token_ids: &[&str],
+        sender: &str,
+        receiver: &str,
+    ) -> PacketData {
+        let token_ids: Vec<String> = token_ids.iter().map(ToString::to_string).collect();
+
+        PacketData::new(
+            PrefixedClassId::from_str(class_id).unwrap(),
+            Some(ClassUri::from_str("https://class.example").unwrap()),
+            Some(ClassData::from_str(r#"{"name":"class"}"#).unwrap()),
+            TokenIds::try_from(token_ids.clone()).unwrap(),
+            token_ids
+                .iter()
+                .map(|id| TokenUri::from_str(&format!("https://token.example/{id}")).unwrap())
+                .collect(),
+            token_ids
+                .iter()
+                .map(|id| TokenData::from_str(&format!(r#"{{"id":"{id}"}}"#)).unwrap())
+                .collect(),
+            sender.to_string().into(),
+            receiver.to_string().into(),
+            "".into(),
+        )
+        .unwrap()
+    }
+
+    /// Returns a packet sent by the counterparty to the contract.
+    pub fn incoming_packet(&self, data: &PacketData) -> IbcPacket {
+        IbcPacket::new(
+            to_json_binary(data).unwrap(),
+            self.counterparty_endpoint(),
+            self.endpoint(),
+            1,
+            IbcTimeout::with_timestamp(Timestamp::from_seconds(u64::MAX / 1_000_000_000)),
+        )
+    }
+
+    /// Returns the packet dispatched by a transfer of the contract.
+    pub fn sent_packet(&self, resp: &Response) -> IbcPacket {
+        let Some(CosmosMsg::Ibc(IbcMsg::SendPacket {
+            channel_id,
+            data,
+            timeout,
+        })) = resp.messages.last().map(|sub_msg| &sub_msg.msg)
+        else {
+            panic!("the transfer must end with a packet");
+        };
+
+        assert_eq!(channel_id, &self.channel_id);
+
+        IbcPacket::new(
+            data.clone(),
+            self.endpoint(),
+            self.counterparty_endpoint(),
+            1,
+            timeout.clone(),
+        )
+    }
+
+    pub fn receive(
+        &self,
+        deps: DepsMut<'_>,
+        data: &PacketData,
+    ) -> Result<IbcReceiveResponse, ContractError> {
+        let msg = IbcPacketReceiveMsg::new(self.incoming_packet(data), Addr::unchecked("relayer"));
+
+        ibc_packet_receive(deps, Self::env(), msg)
+    }
+
+    pub fn ack(
+        &self,
+        deps: DepsMut<'_>,
+        packet: IbcPacket,
+        success: bool,
+    ) -> Result<IbcBasicResponse, ContractError> {
+        let ack_status = match success {
+            true => AcknowledgementStatus::success(ack_success_b64()),
+            false => AcknowledgementStatus::error(NftTransferError::InvalidClosedChannel.into()),
+        };
+
+        let ack: Vec<u8> = ack_status.into();
+
+        let msg = IbcPacketAckMsg::new(
+            IbcAcknowledgement::new(ack),
+            packet,
+            Addr::unchecked("relayer"),
+        );
+
+        ibc_packet_ack(deps, Self::env(), msg)
+    }
+
+    pub fn timeout(
+        &self,
+        deps: DepsMut<'_>,
+        packet: IbcPacket,
+    ) -> Result<IbcBasicResponse, ContractError> {
+        let msg = IbcPacketTimeoutMsg::new(packet, Addr::unchecked("relayer"));
+
+        ibc_packet_timeout(deps, Self::env(), msg)
+    }
+
+    pub fn query<T: DeserializeOwned>(&self, deps: Deps<'_>, msg: QueryMsg) -> T {
+        from_json(query(deps, Self::env(), msg).unwrap()).unwrap()
+    }
+
+    /// Returns the channel an NFT is escrowed for.
+    pub fn escrowed(&self, deps: Deps<'_>, class_id: &str, token_id: &str) -> Option<String> {
+        let resp: EscrowedResponse = self.query(
+            deps,
+            QueryMsg::Escrowed {
+                class_id: class_id.to_string(),
+                token_id: token_id.to_string(),
+            },
+        );
+
+        resp.channel_id
+    }
+}
+
+/// Returns whether the acknowledgement of a received packet is successful.
+pub fn is_successful(resp: &IbcReceiveResponse) -> bool {
+    let ack_status: AcknowledgementStatus =
+        from_json(resp.acknowledgement.as_ref().unwrap()).unwrap();
+
+    ack_status.is_successful()
+}
@@ -0,0 +1,389 @@
+use cosmwasm_std::{
+    from_json, Addr, CosmosMsg, HexBinary, IbcChannelCloseMsg, IbcChannelConnectMsg,
+    IbcChannelOpenMsg, IbcOrder, SubMsg, WasmMsg,
+};
+use ibc_app_nft_transfer::types::packet::PacketData;
+use ibc_app_nft_transfer::types::VERSION;
+
+use crate::types::{ClassTraceResponse, ClassTracesResponse, Cw721ExecuteMsg, QueryMsg};
+
+pub mod fixture;
+
+use fixture::{is_successful, Fixture};
+
+/// Returns the messages of a response, without their submessage wrapping.
+fn messages<T>(sub_msgs: &[SubMsg<T>]) -> Vec<&CosmosMsg<T>> {
+    sub_msgs.iter().map(|sub_msg| &sub_msg.msg).collect()
+}
+
+/// Decodes a message executed on a cw721 contract.
+fn decode_cw721(msg: &CosmosMsg) -> (Addr, Cw721ExecuteMsg) {
+    let CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr, msg, ..
+    }) = msg
+    else {
+        panic!("expected a `WasmMsg::Execute`, found {msg:?}");
+    };
+
+    (Addr::unchecked(contract_addr), from_json(msg).unwrap())
+}
+
+fn class_traces(fxt: &Fixture, deps: &fixture::MockDeps) -> Vec<ClassTraceResponse> {
+    let resp: ClassTracesResponse = fxt.query(
+        deps.as_ref(),
+        QueryMsg::ClassTraces {
+            start_after: None,
+            limit: None,
+        },
+    );
+
+    resp.class_traces
+}
+
+#[test]
+fn test_cw_nft_transfer_channel_handshake() {
+    let fxt = Fixture::default();
+
+    let mut deps = fxt.dependencies();
+
+    fxt.instantiate(deps.as_mut()).unwrap();
+
+    let channel = fxt.channel(IbcOrder::Unordered, VERSION);
+
+    let resp = fxt
+        .channel_open(deps.as_mut(), IbcChannelOpenMsg::new_init(channel.clone()))
+        .unwrap();
+
+    assert_eq!(resp.unwrap().version, VERSION);
+
+    // The channel must be unordered and of the ICS-721 version.
+    for channel in [
+        fxt.channel(IbcOrder::Ordered, VERSION),
+        fxt.channel(IbcOrder::Unordered, "ics20-1"),
+    ] {
+        assert!(fxt
+            .channel_open(deps.as_mut(), IbcChannelOpenMsg::new_init(channel))
+            .is_err());
+    }
+
+    assert!(fxt
+        .channel_connect(
+            deps.as_mut(),
+            IbcChannelConnectMsg::new_ack(channel.clone(), "ics20-1")
+        )
+        .is_err());
+
+    fxt.channel_connect(
+        deps.as_mut(),
+        IbcChannelConnectMsg::new_ack(channel.clone(), VERSION),
+    )
+    .unwrap();
+
+    // The channel cannot be closed by the contract, but by the counterparty.
+    assert!(fxt
+        .channel_close(deps.as_mut(), IbcChannelCloseMsg::new_init(channel.clone()))
+        .is_err());
+
+    fxt.channel_close(deps.as_mut(), IbcChannelCloseMsg::new_confirm(channel))
+        .unwrap();
+}
+
+#[test]
+fn test_cw_nft_transfer_native_round_trip() {
+    let fxt = Fixture::default();
+
+    let mut deps = fxt.setup();
+
+    let cw721 = Fixture::addr("cw721");
+    let alice = Fixture::addr("alice");
+    let bob = Fixture::addr("bob");
+
+    // ------------------- Send a native NFT -------------------
+
+    let resp = fxt
+        .send_nft(deps.as_mut(), &cw721, &alice, "token-1")
+        .unwrap();
+
+    // The NFT is owned by the contract already, so that only the packet is
+    // dispatched.
+    assert_eq!(resp.messages.len(), 1);
+
+    let packet = fxt.sent_packet(&resp);
+    let data: PacketData = from_json(&packet.data).unwrap();
+
+    assert_eq!(data.class_id.to_string(), cw721.to_string());
+    assert_eq!(data.sender.as_ref(), alice.as_str());
+    assert_eq!(
+        data.token_uris.unwrap()[0].to_string(),
+        Fixture::native_token_uri(cw721.as_str(), "token-1")
+    );
+    assert!(data.token_data.is_none());
+
+    assert_eq!(
+        fxt.escrowed(deps.as_ref(), cw721.as_str(), "token-1"),
+        Some(fxt.channel_id.clone())
+    );
+
+    // An escrowed NFT cannot be sent again.
+    assert!(fxt
+        .send_nft(deps.as_mut(), &cw721, &alice, "token-1")
+        .is_err());
+
+    let resp = fxt.ack(deps.as_mut(), packet, true).unwrap();
+
+    assert!(resp.messages.is_empty());
+
+    // ------------------- Receive it back -------------------
+
+    let class_id = format!("{}/{cw721}", fxt.counterparty_trace_prefix());
+
+    let data = Fixture::packet_data(&class_id, &["token-1"], "counterparty-sender", bob.as_str());
+
+    let resp = fxt.receive(deps.as_mut(), &data).unwrap();
+
+    assert!(is_successful(&resp));
+
+    assert_eq!(
+        messages(&resp.messages)
+            .into_iter()
+            .map(decode_cw721)
+            .collect::<Vec<_>>(),
+        vec![(
+            cw721.clone(),
+            Cw721ExecuteMsg::TransferNft {
+                recipient: bob.to_string(),
+                token_id: "token-1".to_string(),
+            }
+        )]
+    );
+
+    assert_eq!(fxt.escrowed(deps.as_ref(), cw721.as_str(), "token-1"), None);
+
+    // No voucher class is created for a returning class.
+    assert!(class_traces(&fxt, &deps).is_empty());
+}
+
+#[test]
+fn test_cw_nft_transfer_voucher_round_trip() {
+    let fxt = Fixture::default();
+
+    let mut deps = fxt.setup();
+
+    let bob = Fixture::addr("bob");
+
+    // ------------------- Receive NFTs of another chain -------------------
+
+    let data = Fixture::packet_data("class-a", &["a", "b"], "counterparty-sender", bob.as_str());
+
+    let resp = fxt.receive(deps.as_mut(), &data).unwrap();
+
+    assert!(is_successful(&resp));
+
+    let [class_trace] = class_traces(&fxt, &deps).try_into().unwrap();
+
+    assert_eq!(class_trace.path, fxt.trace_prefix());
+    assert_eq!(class_trace.base_class_id, "class-a");
+    assert!(class_trace.class_uri.is_some());
+    assert!(class_trace.class_data.is_some());
+
+    let voucher_contract = class_trace.contract;
+
+    let resp_messages = messages(&resp.messages);
+
+    let CosmosMsg::Wasm(WasmMsg::Instantiate2 {
+        admin,
+        code_id,
+        salt,
+        ..
+    }) = resp_messages[0]
+    else {
+        panic!("the voucher contract must be instantiated first");
+    };
+
+    assert_eq!(
+        admin.as_deref(),
+        Some(Fixture::env().contract.address.as_str())
+    );
+    assert_eq!(*code_id, fxt.cw721_code_id);
+    assert_eq!(
+        HexBinary::from(salt.as_slice()).to_hex().to_uppercase(),
+        class_trace.hash
+    );
+
+    for (msg, token_id) in resp_messages[1..].iter().zip(["a", "b"]) {
+        assert_eq!(
+            decode_cw721(msg),
+            (
+                voucher_contract.clone(),
+                Cw721ExecuteMsg::Mint {
+                    token_id: token_id.to_string(),
+                    owner: bob.to_string(),
+                    token_uri: Some(format!("https://token.example/{token_id}")),
+                    extension: None,
+                }
+            )
+        );
+    }
+
+    // The voucher contract is instantiated once per class trace.
+    let data = Fixture::packet_data("class-a", &["c"], "counterparty-sender", bob.as_str());
+
+    let resp = fxt.receive(deps.as_mut(), &data).unwrap();
+
+    assert!(is_successful(&resp));
+    assert_eq!(resp.messages.len(), 1);
+
+    let voucher_class: ClassTraceResponse = fxt.query(
+        deps.as_ref(),
+        QueryMsg::VoucherClass {
+            contract: voucher_contract.to_string(),
+        },
+    );
+
+    assert_eq!(voucher_class.hash, class_trace.hash);
+
+    // ------------------- Send a voucher back -------------------
+
+    let resp = fxt
+        .send_nft(deps.as_mut(), &voucher_contract, &bob, "a")
+        .unwrap();
+
+    assert_eq!(
+        decode_cw721(&resp.messages[0].msg),
+        (
+            voucher_contract.clone(),
+            Cw721ExecuteMsg::Burn {
+                token_id: "a".to_string()
+            }
+        )
+    );
+
+    let packet = fxt.sent_packet(&resp);
+    let sent: PacketData = from_json(&packet.data).unwrap();
+
+    assert_eq!(
+        sent.class_id.to_string(),
+        format!("{}/class-a", fxt.trace_prefix())
+    );
+    assert_eq!(sent.class_uri, data.class_uri);
+    assert_eq!(sent.class_data, data.class_data);
+    assert_eq!(
+        sent.token_uris.unwrap()[0].to_string(),
+        "https://token.example/a"
+    );
+    assert_eq!(sent.token_data.unwrap()[0].to_string(), r#"{"id":"a"}"#);
+
+    // ------------------- Refund it on timeout -------------------
+
+    let resp = fxt.timeout(deps.as_mut(), packet).unwrap();
+
+    assert_eq!(
+        messages(&resp.messages)
+            .into_iter()
+            .map(decode_cw721)
+            .collect::<Vec<_>>(),
+        vec![(
+            voucher_contract,
+            Cw721ExecuteMsg::Mint {
+                token_id: "a".to_string(),
+                owner: bob.to_string(),
+                token_uri: Some("https://token.example/a".to_string()),
+                extension: None,
+            }
+        )]
+    );
+}
+
+#[test]
+fn test_cw_nft_transfer_refund_on_error_ack() {
+    let fxt = Fixture::default();
+
+    let mut deps = fxt.setup();
+
+    let cw721 = Fixture::addr("cw721");
+    let alice = Fixture::addr("alice");
+
+    let resp = fxt
+        .send_nft(deps.as_mut(), &cw721, &alice, "token-1")
+        .unwrap();
+
+    let packet = fxt.sent_packet(&resp);
+
+    let resp = fxt.ack(deps.as_mut(), packet.clone(), false).unwrap();
+
+    assert_eq!(
+        messages(&resp.messages)
+            .into_iter()
+            .map(decode_cw721)
+            .collect::<Vec<_>>(),
+        vec![(
+            cw721.clone(),
+            Cw721ExecuteMsg::TransferNft {
+                recipient: alice.to_string(),
+                token_id: "token-1".to_string(),
+            }
+        )]
+    );
+
+    assert_eq!(fxt.escrowed(deps.as_ref(), cw721.as_str(), "token-1"), None);
+
+    // The NFT cannot be refunded twice.
+    assert!(fxt.timeout(deps.as_mut(), packet).is_err());
+}
+
+#[test]
+fn test_cw_nft_transfer_failed_receive_discards_state() {
+    let fxt = Fixture::default();
+
+    let mut deps = fxt.setup();
+
+    let cw721 = Fixture::addr("cw721");
+    let alice = Fixture::addr("alice");
+    let bob = Fixture::addr("bob");
+
+    fxt.send_nft(deps.as_mut(), &cw721, &alice, "token-1")
+        .unwrap();
+
+    // The second NFT of the packet was never escrowed, so that the release
+    // of the first one is discarded.
+    let class_id = format!("{}/{cw721}", fxt.counterparty_trace_prefix());
+
+    let data = Fixture::packet_data(
+        &class_id,
+        &["token-1", "token-2"],
+        "counterparty-sender",
+        bob.as_str(),
+    );
+
+    let resp = fxt.receive(deps.as_mut(), &data).unwrap();
+
+    assert!(!is_successful(&resp));
+    assert!(resp.messages.is_empty());
+
+    assert_eq!(
+        fxt.escrowed(deps.as_ref(), cw721.as_str(), "token-1"),
+        Some(fxt.channel_id.clone())
+    );
+
+    // The second voucher of the packet is minted already, so that the first
+    // one is not recorded.
+    let data = Fixture::packet_data("class-a", &["a"], "counterparty-sender", bob.as_str());
+
+    assert!(is_successful(&fxt.receive(deps.as_mut(), &data).unwrap()));
+
+    let data = Fixture::packet_data("class-a", &["b", "a"], "counterparty-sender", bob.as_str());
+
+    let resp = fxt.receive(deps.as_mut(), &data).unwrap();
+
+    assert!(!is_successful(&resp));
+    assert!(resp.messages.is_empty());
+
+    let data = Fixture::packet_data("class-a", &["b"], "counterparty-sender", bob.as_str());
+
+    assert!(is_successful(&fxt.receive(deps.as_mut(), &data).unwrap()));
+
+    // The receiver must be a valid address.
+    let data = Fixture::packet_data("class-a", &["a"], "counterparty-sender", "receiver");
+
+    assert!(!is_successful(&fxt.receive(deps.as_mut(), &data).unwrap()));
+}
@@ -0,0 +1,53 @@
+//! Defines the subset of the cw721 messages exchanged with the class
+//! contracts.
+use cosmwasm_schema::{cw_serde, serde};
+use cosmwasm_std::{Binary, Empty};
+
+/// The message sent by a cw721 contract to the contract when an NFT is sent
+/// to it with `SendNft`.
+#[cw_serde]
+pub struct Cw721ReceiveMsg {
+    pub sender: String,
+    pub token_id: String,
+    pub msg: Binary,
+}
+
+/// The instantiation message of the voucher contracts, compatible with the
+/// `cw721-base` contract.
+#[cw_serde]
+pub struct Cw721InstantiateMsg {
+    pub name: String,
+    pub symbol: String,
+    pub minter: Option<String>,
+}
+
+/// The cw721 messages executed by the contract on the class contracts.
+#[cw_serde]
+pub enum Cw721ExecuteMsg {
+    Mint {
+        token_id: String,
+        owner: String,
+        token_uri: Option<String>,
+        extension: Option<Empty>,
+    },
+    Burn {
+        token_id: String,
+    },
+    TransferNft {
+        recipient: String,
+        token_id: String,
+    },
+}
+
+#[cw_serde]
+pub enum Cw721QueryMsg {
+    NftInfo { token_id: String },
+}
+
+/// The response to a [`Cw721QueryMsg::NftInfo`] query, whose extension
+/// differs between cw721 contracts and is ignored.
+#[derive(serde::Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "cosmwasm_schema::serde")]
+pub struct NftInfoResponse {
+    pub token_uri: Option<String>,
+}
@@ -0,0 +1,57 @@
+use cosmwasm_std::{Instantiate2AddressError, StdError};
+use derive_more::{Display, From};
+use ibc_app_nft_transfer::types::error::NftTransferError;
+use ibc_core::host::types::error::{DecodingError, HostError, IdentifierError};
+
+#[derive(From, Display, Debug)]
+pub enum ContractError {
+    #[from]
+    #[display("CosmWasm standard error: {_0}")]
+    Std(StdError),
+    #[from]
+    #[display("CosmWasm hosting error: {_0}")]
+    Host(HostError),
+    #[from]
+    #[display("IBC NFT transfer error: {_0}")]
+    NftTransfer(NftTransferError),
+    #[from]
+    #[display("IBC identifier error: {_0}")]
+    Identifier(IdentifierError),
+    #[from]
+    #[display("IBC decoding error: {_0}")]
+    Decoding(DecodingError),
+    #[from]
+    #[display("voucher contract address error: {_0}")]
+    Instantiate2Address(Instantiate2AddressError),
+    #[display("transfer receiver must not be empty")]
+    EmptyReceiver,
+    #[display("unknown channel `{channel_id}`")]
+    UnknownChannel { channel_id: String },
+    #[display("no voucher contract exists for class `{class_id}`")]
+    MissingVoucherClass { class_id: String },
+    #[display("voucher `{token_id}` of class `{class_id}` is already minted")]
+    AlreadyMinted { class_id: String, token_id: String },
+    #[display("NFT `{token_id}` of class `{class_id}` is already escrowed")]
+    AlreadyEscrowed { class_id: String, token_id: String },
+    #[display("NFT `{token_id}` of class `{class_id}` is not escrowed for `{channel_id}`")]
+    NotEscrowed {
+        class_id: String,
+        token_id: String,
+        channel_id: String,
+    },
+}
+
+impl From<ContractError> for StdError {
+    fn from(err: ContractError) -> Self {
+        Self::generic_err(err.to_string())
+    }
+}
+
+impl From<ContractError> for HostError {
+    fn from(err: ContractError) -> Self {
+        match err {
+            ContractError::Host(err) => err,
+            err => Self::invalid_state(err),
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use cosmwasm_std::HexBinary;
+use ibc_app_nft_transfer::types::PrefixedClassId;
+use sha2::{Digest, Sha256};
+
+/// Returns the hash identifying a class trace: the uppercase hex SHA-256
+/// hash of its full path, as in the `ibc/<hash>` classes of ibc-go.
+pub fn class_trace_hash(class_id: &PrefixedClassId) -> String {
+    HexBinary::from(Sha256::digest(class_id.to_string()).as_slice())
+        .to_hex()
+        .to_uppercase()
+}
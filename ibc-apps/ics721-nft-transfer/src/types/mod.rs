@@ -0,0 +1,13 @@
+mod cw721;
+mod error;
+mod helper;
+mod msgs;
+mod response;
+mod state;
+
+pub use cw721::*;
+pub use error::*;
+pub use helper::*;
+pub use msgs::*;
+pub use response::*;
+pub use state::*;
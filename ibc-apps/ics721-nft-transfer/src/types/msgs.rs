@@ -0,0 +1,66 @@
+//! Defines the messages sent to the CosmWasm contract by its users.
+use cosmwasm_schema::{cw_serde, QueryResponses};
+
+use super::cw721::Cw721ReceiveMsg;
+use super::response::{ClassTraceResponse, ClassTracesResponse, EscrowedResponse};
+use super::state::{ChannelInfo, Config};
+
+// ------------------------------------------------------------
+// Implementation of the InstantiateMsg struct
+// ------------------------------------------------------------
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The timeout of the transfers that do not set one, in seconds.
+    pub default_timeout: u64,
+    pub cw721_code_id: u64,
+}
+
+// ------------------------------------------------------------
+// Implementation of the ExecuteMsg enum and its variants
+// ------------------------------------------------------------
+
+#[derive(derive_more::From)]
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Transfers the NFT sent to the contract, with a [`TransferMsg`] as the
+    /// message of the cw721 `SendNft`.
+    ReceiveNft(Cw721ReceiveMsg),
+}
+
+#[cw_serde]
+pub struct TransferMsg {
+    pub channel_id: String,
+    /// The receiver of the NFT on the counterparty chain.
+    pub receiver: String,
+    /// The timeout of the transfer in seconds, which defaults to the one of
+    /// the contract configuration.
+    pub timeout: Option<u64>,
+    pub memo: Option<String>,
+}
+
+// ------------------------------------------------------------
+// Implementation of the QueryMsg enum and its variants
+// ------------------------------------------------------------
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(Config)]
+    Config {},
+    #[returns(ChannelInfo)]
+    Channel { channel_id: String },
+    /// Returns the class trace of a hash, with or without its `ibc/` prefix.
+    #[returns(ClassTraceResponse)]
+    ClassTrace { hash: String },
+    #[returns(ClassTracesResponse)]
+    ClassTraces {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns the class trace minted by a voucher contract.
+    #[returns(ClassTraceResponse)]
+    VoucherClass { contract: String },
+    #[returns(EscrowedResponse)]
+    Escrowed { class_id: String, token_id: String },
+}
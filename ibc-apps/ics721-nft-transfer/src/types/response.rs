@@ -0,0 +1,24 @@
+//! Defines the responses to the queries of the contract.
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Addr;
+
+#[cw_serde]
+pub struct ClassTraceResponse {
+    pub hash: String,
+    pub path: String,
+    pub base_class_id: String,
+    pub contract: Addr,
+    pub class_uri: Option<String>,
+    pub class_data: Option<String>,
+}
+
+#[cw_serde]
+pub struct ClassTracesResponse {
+    pub class_traces: Vec<ClassTraceResponse>,
+}
+
+#[cw_serde]
+pub struct EscrowedResponse {
+    /// The channel the NFT was sent over, if escrowed.
+    pub channel_id: Option<String>,
+}
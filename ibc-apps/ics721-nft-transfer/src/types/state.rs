@@ -0,0 +1,39 @@
+//! Defines the configuration and records kept in the contract storage.
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, IbcEndpoint};
+
+#[cw_serde]
+pub struct Config {
+    /// The timeout of the transfers that do not set one, in seconds.
+    pub default_timeout: u64,
+    /// The code of the cw721 contracts instantiated to mint the vouchers of
+    /// the classes received from other chains. It must accept a
+    /// `cw721-base` instantiation message.
+    pub cw721_code_id: u64,
+}
+
+/// A class received from another chain, and the cw721 contract minting its
+/// vouchers.
+#[cw_serde]
+pub struct VoucherClass {
+    /// The full class trace, such as `wasm.<contract>/channel-0/class`.
+    pub class_id: String,
+    pub class_uri: Option<String>,
+    pub class_data: Option<String>,
+    pub contract: Addr,
+}
+
+/// The metadata of a voucher, kept to be sent along with it.
+#[cw_serde]
+pub struct VoucherToken {
+    pub token_uri: Option<String>,
+    pub token_data: Option<String>,
+}
+
+/// An ICS-721 channel opened by the contract.
+#[cw_serde]
+pub struct ChannelInfo {
+    pub channel_id: String,
+    pub counterparty_endpoint: IbcEndpoint,
+    pub connection_id: String,
+}
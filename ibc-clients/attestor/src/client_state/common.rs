@@ -0,0 +1,119 @@
+use core::time::Duration;
+
+use ibc_client_tendermint::client_state::{verify_membership, verify_non_membership};
+use ibc_core::client::context::client_state::ClientStateCommon;
+use ibc_core::client::context::consensus_state::ConsensusState as _;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::{Height, Status};
+use ibc_core::commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core::commitment_types::proto::ics23::HostFunctionsManager;
+use ibc_core::host::types::identifiers::ClientType;
+use ibc_core::host::types::path::{Path, PathBytes};
+use ibc_core::primitives::proto::Any;
+use ibc_core::primitives::Timestamp;
+
+use super::ClientState;
+use crate::consensus_state::ConsensusState;
+
+pub const ATTESTOR_CLIENT_TYPE: &str = "attestor";
+
+impl ClientStateCommon for ClientState {
+    fn verify_consensus_state(
+        &self,
+        consensus_state: Any,
+        host_timestamp: &Timestamp,
+    ) -> Result<(), ClientError> {
+        let consensus_state = ConsensusState::try_from(consensus_state)?;
+
+        if consensus_status(&consensus_state, host_timestamp, self.trusting_period)?.is_expired() {
+            return Err(ClientError::InvalidStatus(Status::Expired));
+        }
+
+        Ok(())
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::new(ATTESTOR_CLIENT_TYPE).expect("valid client type")
+    }
+
+    fn latest_height(&self) -> Height {
+        self.latest_height
+    }
+
+    fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError> {
+        if self.latest_height < proof_height {
+            return Err(ClientError::InsufficientProofHeight {
+                actual: self.latest_height,
+                expected: proof_height,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn serialize_path(&self, path: Path) -> Result<PathBytes, ClientError> {
+        Ok(path.to_string().into_bytes().into())
+    }
+
+    /// Upgrades are not supported by this client: a change of the
+    /// counterparty chain is followed by attesting its new states, and a
+    /// change of the attestors by a committee rotation.
+    fn verify_upgrade_client(
+        &self,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+        _proof_upgrade_client: CommitmentProofBytes,
+        _proof_upgrade_consensus_state: CommitmentProofBytes,
+        _root: &CommitmentRoot,
+    ) -> Result<(), ClientError> {
+        Err(ClientError::ClientSpecific {
+            description: "upgrades are not supported by the attestor client".to_string(),
+        })
+    }
+
+    fn verify_membership_raw(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: PathBytes,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        verify_membership::<HostFunctionsManager>(
+            &self.proof_specs,
+            prefix,
+            proof,
+            root,
+            path,
+            value,
+        )
+    }
+
+    fn verify_non_membership_raw(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: PathBytes,
+    ) -> Result<(), ClientError> {
+        verify_non_membership::<HostFunctionsManager>(&self.proof_specs, prefix, proof, root, path)
+    }
+}
+
+/// Determines whether a consensus state is `Active` or `Expired`, given the
+/// host's timestamp and the trusting period of the client.
+pub fn consensus_status(
+    consensus_state: &ConsensusState,
+    host_timestamp: &Timestamp,
+    trusting_period: Duration,
+) -> Result<Status, ClientError> {
+    if let Some(elapsed) = host_timestamp.duration_since(&consensus_state.timestamp()?) {
+        if elapsed >= trusting_period {
+            return Ok(Status::Expired);
+        }
+    }
+
+    Ok(Status::Active)
+}
@@ -0,0 +1,222 @@
+use ibc_core::client::context::client_state::{ClientStateCommon, ClientStateExecution};
+use ibc_core::client::context::prelude::*;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Height;
+use ibc_core::host::types::identifiers::ClientId;
+use ibc_core::host::types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc_core::primitives::proto::Any;
+
+use super::{consensus_state_at, ClientState};
+use crate::consensus_state::ConsensusState;
+use crate::secp256k1::Secp256k1Verifier;
+use crate::types::{
+    CommitteeRotation, StateAttestation, ATTESTOR_COMMITTEE_ROTATION_TYPE_URL,
+    ATTESTOR_STATE_ATTESTATION_TYPE_URL,
+};
+
+impl<E> ClientStateExecution<E> for ClientState
+where
+    E: ExtClientExecutionContext + Secp256k1Verifier,
+    E::ClientStateRef: From<Self>,
+    ConsensusState: Convertible<E::ConsensusStateRef>,
+    <ConsensusState as TryFrom<E::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    fn initialise(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        let consensus_state: ConsensusState = consensus_state.try_into()?;
+
+        ctx.store_client_state(ClientStatePath::new(client_id.clone()), self.clone().into())?;
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                client_id.clone(),
+                self.latest_height.revision_number(),
+                self.latest_height.revision_height(),
+            ),
+            consensus_state.into(),
+        )?;
+        ctx.store_update_meta(
+            client_id.clone(),
+            self.latest_height,
+            ctx.host_timestamp()?,
+            ctx.host_height()?,
+        )?;
+
+        Ok(())
+    }
+
+    fn update_state(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        match client_message.type_url.as_str() {
+            ATTESTOR_STATE_ATTESTATION_TYPE_URL => {
+                let attestation = StateAttestation::try_from(client_message)?;
+                self.update_on_attestation(ctx, client_id, &attestation)
+            }
+            ATTESTOR_COMMITTEE_ROTATION_TYPE_URL => {
+                let rotation = CommitteeRotation::try_from(client_message)?;
+                self.update_on_rotation(ctx, client_id, rotation)
+            }
+            header_type => Err(ClientError::InvalidHeaderType(header_type.to_owned())),
+        }
+    }
+
+    fn update_state_on_misbehaviour(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        _client_message: Any,
+    ) -> Result<(), ClientError> {
+        let frozen_client_state = Self {
+            frozen_height: Some(Height::min(0)),
+            ..self.clone()
+        };
+
+        ctx.store_client_state(
+            ClientStatePath::new(client_id.clone()),
+            frozen_client_state.into(),
+        )?;
+
+        Ok(())
+    }
+
+    fn update_state_on_upgrade(
+        &self,
+        _ctx: &mut E,
+        _client_id: &ClientId,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        Err(ClientError::ClientSpecific {
+            description: "upgrades are not supported by the attestor client".to_string(),
+        })
+    }
+
+    fn update_on_recovery(
+        &self,
+        ctx: &mut E,
+        subject_client_id: &ClientId,
+        substitute_client_state: Any,
+        substitute_consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        let substitute_client_state = Self::try_from(substitute_client_state)?;
+        let new_consensus_state: ConsensusState = substitute_consensus_state.try_into()?;
+
+        let new_client_state = Self {
+            committee: substitute_client_state.committee,
+            latest_height: substitute_client_state.latest_height,
+            frozen_height: None,
+            trusting_period: substitute_client_state.trusting_period,
+            ..self.clone()
+        };
+
+        let latest_height = new_client_state.latest_height();
+
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                subject_client_id.clone(),
+                latest_height.revision_number(),
+                latest_height.revision_height(),
+            ),
+            new_consensus_state.into(),
+        )?;
+        ctx.store_client_state(
+            ClientStatePath::new(subject_client_id.clone()),
+            new_client_state.into(),
+        )?;
+        ctx.store_update_meta(
+            subject_client_id.clone(),
+            latest_height,
+            ctx.host_timestamp()?,
+            ctx.host_height()?,
+        )?;
+
+        Ok(())
+    }
+}
+
+impl ClientState {
+    fn update_on_attestation<E>(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        attestation: &StateAttestation,
+    ) -> Result<Vec<Height>, ClientError>
+    where
+        E: ExtClientExecutionContext,
+        E::ClientStateRef: From<Self>,
+        ConsensusState: Convertible<E::ConsensusStateRef>,
+        <ConsensusState as TryFrom<E::ConsensusStateRef>>::Error: Into<ClientError>,
+    {
+        let height = attestation.height;
+
+        if consensus_state_at(ctx, client_id, height).is_ok() {
+            // if we already had the attestation installed by a previous
+            // relayer then this is a no-op.
+            return Ok(vec![height]);
+        }
+
+        let new_consensus_state: ConsensusState = attestation.into();
+
+        let new_client_state = if height > self.latest_height {
+            Self {
+                latest_height: height,
+                ..self.clone()
+            }
+        } else {
+            self.clone()
+        };
+
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                client_id.clone(),
+                height.revision_number(),
+                height.revision_height(),
+            ),
+            new_consensus_state.into(),
+        )?;
+        ctx.store_client_state(
+            ClientStatePath::new(client_id.clone()),
+            new_client_state.into(),
+        )?;
+        ctx.store_update_meta(
+            client_id.clone(),
+            height,
+            ctx.host_timestamp()?,
+            ctx.host_height()?,
+        )?;
+
+        Ok(vec![height])
+    }
+
+    /// Hands the client over to the next committee. No consensus state is
+    /// installed, so the latest height is reported as the updated height.
+    fn update_on_rotation<E>(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        rotation: CommitteeRotation,
+    ) -> Result<Vec<Height>, ClientError>
+    where
+        E: ExtClientExecutionContext,
+        E::ClientStateRef: From<Self>,
+    {
+        let new_client_state = Self {
+            committee: rotation.next_committee,
+            ..self.clone()
+        };
+
+        ctx.store_client_state(
+            ClientStatePath::new(client_id.clone()),
+            new_client_state.into(),
+        )?;
+
+        Ok(vec![self.latest_height])
+    }
+}
@@ -0,0 +1,244 @@
+use ibc_core::client::context::client_state::ClientStateValidation;
+use ibc_core::client::context::prelude::*;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::{Height, Status};
+use ibc_core::host::types::identifiers::ClientId;
+use ibc_core::host::types::path::ClientConsensusStatePath;
+use ibc_core::primitives::proto::Any;
+
+use super::{consensus_status, ClientState};
+use crate::consensus_state::ConsensusState;
+use crate::error::AttestorError;
+use crate::secp256k1::Secp256k1Verifier;
+use crate::types::{
+    CommitteeRotation, Misbehaviour, StateAttestation, ATTESTOR_COMMITTEE_ROTATION_TYPE_URL,
+    ATTESTOR_MISBEHAVIOUR_TYPE_URL, ATTESTOR_STATE_ATTESTATION_TYPE_URL,
+};
+
+impl<V> ClientStateValidation<V> for ClientState
+where
+    V: ExtClientValidationContext + Secp256k1Verifier,
+    ConsensusState: Convertible<V::ConsensusStateRef>,
+    <ConsensusState as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    fn verify_client_message(
+        &self,
+        ctx: &V,
+        _client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        match client_message.type_url.as_str() {
+            ATTESTOR_STATE_ATTESTATION_TYPE_URL => {
+                let attestation = StateAttestation::try_from(client_message)?;
+                self.verify_attestation(ctx, &attestation)
+            }
+            ATTESTOR_COMMITTEE_ROTATION_TYPE_URL => {
+                let rotation = CommitteeRotation::try_from(client_message)?;
+                self.verify_rotation(ctx, &rotation)
+            }
+            ATTESTOR_MISBEHAVIOUR_TYPE_URL => {
+                let misbehaviour = Misbehaviour::try_from(client_message)?;
+                self.verify_misbehaviour(ctx, &misbehaviour)
+            }
+            header_type => Err(ClientError::InvalidHeaderType(header_type.to_owned())),
+        }
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        match client_message.type_url.as_str() {
+            ATTESTOR_STATE_ATTESTATION_TYPE_URL => {
+                let attestation = StateAttestation::try_from(client_message)?;
+                self.check_for_misbehaviour_on_attestation(ctx, client_id, &attestation)
+            }
+            ATTESTOR_COMMITTEE_ROTATION_TYPE_URL => Ok(false),
+            ATTESTOR_MISBEHAVIOUR_TYPE_URL => {
+                let misbehaviour = Misbehaviour::try_from(client_message)?;
+
+                Ok(!misbehaviour
+                    .attestation_1
+                    .attests_same_state(&misbehaviour.attestation_2))
+            }
+            header_type => Err(ClientError::InvalidHeaderType(header_type.to_owned())),
+        }
+    }
+
+    fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError> {
+        if self.is_frozen() {
+            return Ok(Status::Frozen);
+        }
+
+        // if the client state does not have an associated consensus state for
+        // its latest height then it must be expired
+        let Ok(latest_consensus_state) = consensus_state_at(ctx, client_id, self.latest_height)
+        else {
+            return Ok(Status::Expired);
+        };
+
+        consensus_status(
+            &latest_consensus_state,
+            &ctx.host_timestamp()?,
+            self.trusting_period,
+        )
+    }
+
+    /// The subject and substitute client states match if they track the same
+    /// chain with the same proof specs. The committee may differ, as a
+    /// frozen client is typically recovered because its committee has been
+    /// compromised.
+    fn check_substitute(&self, _ctx: &V, substitute_client_state: Any) -> Result<(), ClientError> {
+        let substitute_client_state = Self::try_from(substitute_client_state)?;
+
+        let normalized = Self {
+            committee: self.committee.clone(),
+            latest_height: self.latest_height,
+            frozen_height: self.frozen_height,
+            trusting_period: self.trusting_period,
+            ..substitute_client_state
+        };
+
+        (&normalized == self)
+            .then_some(())
+            .ok_or(ClientError::FailedToVerifyClientRecoveryStates)
+    }
+}
+
+impl ClientState {
+    /// Verifies that the attested state is signed by the current committee.
+    pub fn verify_attestation<V>(
+        &self,
+        ctx: &V,
+        attestation: &StateAttestation,
+    ) -> Result<(), ClientError>
+    where
+        V: Secp256k1Verifier,
+    {
+        self.committee.verify_signatures(
+            ctx,
+            &attestation.sign_doc(&self.chain_id, self.committee.epoch),
+            &attestation.signatures,
+        )?;
+
+        Ok(())
+    }
+
+    /// Verifies that the next committee follows the current one and is
+    /// attested by it.
+    pub fn verify_rotation<V>(
+        &self,
+        ctx: &V,
+        rotation: &CommitteeRotation,
+    ) -> Result<(), ClientError>
+    where
+        V: Secp256k1Verifier,
+    {
+        let expected = self.committee.epoch + 1;
+
+        if rotation.next_committee.epoch != expected {
+            return Err(AttestorError::InvalidEpoch {
+                epoch: rotation.next_committee.epoch,
+                expected,
+            }
+            .into());
+        }
+
+        self.committee.verify_signatures(
+            ctx,
+            &rotation.sign_doc(&self.chain_id, self.committee.epoch),
+            &rotation.signatures,
+        )?;
+
+        Ok(())
+    }
+
+    /// Verifies that both attestations of a misbehaviour are at the same
+    /// height and signed by the current committee. Equivocations of past
+    /// committees are not considered, as their signatures no longer update
+    /// the client.
+    pub fn verify_misbehaviour<V>(
+        &self,
+        ctx: &V,
+        misbehaviour: &Misbehaviour,
+    ) -> Result<(), ClientError>
+    where
+        V: Secp256k1Verifier,
+    {
+        let height_1 = misbehaviour.attestation_1.height;
+        let height_2 = misbehaviour.attestation_2.height;
+
+        if height_1 != height_2 {
+            return Err(AttestorError::MismatchedHeights { height_1, height_2 }.into());
+        }
+
+        self.verify_attestation(ctx, &misbehaviour.attestation_1)?;
+        self.verify_attestation(ctx, &misbehaviour.attestation_2)
+    }
+
+    /// Checks whether an attestation conflicts with the consensus state
+    /// already installed at its height, or breaks the monotonicity of the
+    /// consensus state timestamps.
+    pub fn check_for_misbehaviour_on_attestation<V>(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        attestation: &StateAttestation,
+    ) -> Result<bool, ClientError>
+    where
+        V: ExtClientValidationContext,
+        ConsensusState: Convertible<V::ConsensusStateRef>,
+        <ConsensusState as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+    {
+        let height = attestation.height;
+        let attested_consensus_state: ConsensusState = attestation.into();
+
+        if let Ok(existing_consensus_state) = consensus_state_at(ctx, client_id, height) {
+            return Ok(existing_consensus_state != attested_consensus_state);
+        }
+
+        if let Some(prev_consensus_state) = ctx.prev_consensus_state(client_id, &height)? {
+            let prev_consensus_state: ConsensusState =
+                prev_consensus_state.try_into().map_err(Into::into)?;
+
+            if attested_consensus_state.timestamp <= prev_consensus_state.timestamp {
+                return Ok(true);
+            }
+        }
+
+        if height < self.latest_height {
+            if let Some(next_consensus_state) = ctx.next_consensus_state(client_id, &height)? {
+                let next_consensus_state: ConsensusState =
+                    next_consensus_state.try_into().map_err(Into::into)?;
+
+                if attested_consensus_state.timestamp >= next_consensus_state.timestamp {
+                    return Ok(true);
+                }
+            }
+        }
+
+        Ok(false)
+    }
+}
+
+/// Fetches the consensus state stored for the client at the given height.
+pub fn consensus_state_at<V>(
+    ctx: &V,
+    client_id: &ClientId,
+    height: Height,
+) -> Result<ConsensusState, ClientError>
+where
+    V: ExtClientValidationContext,
+    ConsensusState: Convertible<V::ConsensusStateRef>,
+    <ConsensusState as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    ctx.consensus_state(&ClientConsensusStatePath::new(
+        client_id.clone(),
+        height.revision_number(),
+        height.revision_height(),
+    ))?
+    .try_into()
+    .map_err(Into::into)
+}
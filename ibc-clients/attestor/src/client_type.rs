@@ -0,0 +1,13 @@
+use ibc_client_cw::api::ClientType;
+
+use crate::client_state::ClientState;
+use crate::consensus_state::ConsensusState;
+
+/// A unit struct that represents the attestor client type.
+#[derive(Clone, Debug)]
+pub struct AttestorClient;
+
+impl ClientType<'_> for AttestorClient {
+    type ClientState = ClientState;
+    type ConsensusState = ConsensusState;
+}
@@ -0,0 +1,93 @@
+//! Implements the attestor `ConsensusState`. Each consensus state is keyed by
+//! an attested height and records the attested state root and block time.
+use ibc_core::client::context::consensus_state::ConsensusState as ConsensusStateTrait;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::commitment_types::commitment::CommitmentRoot;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::proto::{Any, Protobuf};
+use ibc_core::primitives::Timestamp;
+
+use crate::types::proto::ConsensusState as RawConsensusState;
+use crate::types::StateAttestation;
+
+pub const ATTESTOR_CONSENSUS_STATE_TYPE_URL: &str = "/ibc.lightclients.attestor.v1.ConsensusState";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusState {
+    /// The attested state root, used as the commitment root.
+    pub root: CommitmentRoot,
+    /// The attested block time.
+    pub timestamp: Timestamp,
+}
+
+impl ConsensusStateTrait for ConsensusState {
+    fn root(&self) -> &CommitmentRoot {
+        &self.root
+    }
+
+    fn timestamp(&self) -> Result<Timestamp, ClientError> {
+        Ok(self.timestamp)
+    }
+}
+
+/// Derives the consensus state installed by a state attestation.
+impl From<&StateAttestation> for ConsensusState {
+    fn from(attestation: &StateAttestation) -> Self {
+        Self {
+            root: attestation.root.clone().into(),
+            timestamp: attestation.timestamp,
+        }
+    }
+}
+
+impl Protobuf<RawConsensusState> for ConsensusState {}
+
+impl TryFrom<RawConsensusState> for ConsensusState {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawConsensusState) -> Result<Self, Self::Error> {
+        if raw.root.is_empty() {
+            return Err(DecodingError::missing_raw_data("consensus state root"));
+        }
+
+        Ok(Self {
+            root: raw.root.into(),
+            timestamp: Timestamp::from_nanoseconds(raw.timestamp),
+        })
+    }
+}
+
+impl From<ConsensusState> for RawConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        Self {
+            root: value.root.into_vec(),
+            timestamp: value.timestamp.nanoseconds(),
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let ATTESTOR_CONSENSUS_STATE_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawConsensusState>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: ATTESTOR_CONSENSUS_STATE_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Self {
+            type_url: ATTESTOR_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawConsensusState>::encode_vec(consensus_state),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+//! Defines the error type of the attestor light client.
+use derive_more::{Display, From};
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Height;
+use ibc_core::host::types::error::DecodingError;
+
+#[derive(From, Display, Debug)]
+pub enum AttestorError {
+    #[from]
+    #[display("decoding error: {_0}")]
+    Decoding(DecodingError),
+    #[display("unknown attestor index `{index}` in a committee of {size} attestors")]
+    UnknownAttestor { index: u32, size: usize },
+    #[display("duplicate signature of attestor `{index}`")]
+    DuplicateSignature { index: u32 },
+    #[display("invalid signature of attestor `{index}`")]
+    InvalidSignature { index: u32 },
+    #[display("insufficient signatures: `{signed}` attestors signed, `{threshold}` required")]
+    InsufficientSignatures { signed: usize, threshold: u32 },
+    #[display("invalid committee epoch `{epoch}`, expected `{expected}`")]
+    InvalidEpoch { epoch: u64, expected: u64 },
+    #[display("attestations at different heights `{height_1}` and `{height_2}`")]
+    MismatchedHeights { height_1: Height, height_2: Height },
+}
+
+impl From<AttestorError> for ClientError {
+    fn from(e: AttestorError) -> Self {
+        Self::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+//! The CosmWasm contract implementation of an attestor committee light
+//! client. It is meant for chains without an on-chain light client protocol,
+//! such as closed or permissioned chains: instead of verifying the consensus
+//! of the counterparty, the client trusts the state roots attested by at
+//! least M of the N secp256k1 keys of a committee. IBC commitments are
+//! verified against the attested roots with ICS-23 proofs, and the committee
+//! itself is rotated through client messages attested by the current
+//! committee.
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+
+pub mod client_state;
+pub mod client_type;
+pub mod consensus_state;
+pub mod entrypoint;
+pub mod error;
+pub mod secp256k1;
+pub mod types;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,34 @@
+//! secp256k1 signature verification of attestations, delegated to the
+//! primitives exposed by the CosmWasm host.
+use ibc_client_cw::context::Context;
+
+use crate::client_type::AttestorClient;
+use crate::types::{Bytes33, Bytes64};
+
+/// Provides secp256k1 signature verification to the light client.
+pub trait Secp256k1Verifier {
+    /// Returns whether `signature` is a valid ECDSA signature of the SHA-256
+    /// `message_hash` by `public_key`. Malformed signatures and public keys
+    /// are invalid.
+    fn secp256k1_verify(
+        &self,
+        message_hash: &[u8; 32],
+        signature: &Bytes64,
+        public_key: &Bytes33,
+    ) -> bool;
+}
+
+impl Secp256k1Verifier for Context<'_, AttestorClient> {
+    fn secp256k1_verify(
+        &self,
+        message_hash: &[u8; 32],
+        signature: &Bytes64,
+        public_key: &Bytes33,
+    ) -> bool {
+        matches!(
+            self.api()
+                .secp256k1_verify(message_hash, signature, public_key),
+            Ok(true)
+        )
+    }
+}
@@ -0,0 +1,329 @@
+use std::time::Duration;
+
+use ibc_client_cw::types::{MerklePath, VerifyMembershipMsgRaw, VerifyNonMembershipMsgRaw};
+use ibc_client_cw::utils::AnyCodec;
+use ibc_client_cw_testkit::api::TestClient;
+use ibc_client_cw_testkit::clock::Clock;
+use ibc_client_cw_testkit::store::{MultiStore, IBC_STORE};
+use ibc_core::client::types::Height;
+use ibc_core::commitment_types::specs::ProofSpecs;
+use ibc_core::primitives::Timestamp;
+use k256::ecdsa::signature::Signer;
+use k256::ecdsa::{Signature, SigningKey};
+
+use crate::client_state::ClientState;
+use crate::client_type::AttestorClient;
+use crate::consensus_state::ConsensusState;
+use crate::types::{
+    AttestorSignature, Committee, CommitteeRotation, Misbehaviour, StateAttestation,
+};
+
+/// The block time of the synthetic chain.
+const BLOCK_TIME: u64 = 5;
+
+/// Test fixture of the attestor client.
+pub type Fixture = ibc_client_cw_testkit::fixture::Fixture<AttestorClient>;
+
+/// A permissioned chain whose state is a Cosmos SDK multistore, attested by
+/// a committee of secp256k1 keys.
+#[derive(Clone, Debug)]
+pub struct AttestorConfig {
+    pub chain_id: String,
+    /// The time of the block at height zero, in seconds.
+    pub genesis_time: u64,
+    pub trusting_period: Duration,
+    /// The keys of the current committee.
+    pub attestor_keys: Vec<SigningKey>,
+    pub threshold: u32,
+    pub epoch: u64,
+    /// The (path, value) commitments stored in the IBC store.
+    pub commitments: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+impl Default for AttestorConfig {
+    fn default() -> Self {
+        let now = Clock::default().now().nanoseconds() / 1_000_000_000;
+
+        Self {
+            chain_id: "permissioned-chain-1".to_string(),
+            genesis_time: now - 3600,
+            trusting_period: Duration::from_secs(64000),
+            attestor_keys: AttestorClient::signing_keys(1..=4),
+            threshold: 3,
+            epoch: 0,
+            commitments: vec![
+                (
+                    b"commitments/ports/transfer/channels/channel-0/sequences/1".to_vec(),
+                    b"packet-commitment-1".to_vec(),
+                ),
+                (
+                    b"commitments/ports/transfer/channels/channel-0/sequences/2".to_vec(),
+                    b"packet-commitment-2".to_vec(),
+                ),
+                (
+                    b"connections/connection-0".to_vec(),
+                    b"connection-end".to_vec(),
+                ),
+            ],
+        }
+    }
+}
+
+impl AttestorConfig {
+    // ------------------- Attestors -------------------
+
+    pub fn committee(&self) -> Committee {
+        Committee {
+            epoch: self.epoch,
+            attestors: self
+                .attestor_keys
+                .iter()
+                .map(|key| {
+                    key.verifying_key()
+                        .to_encoded_point(true)
+                        .as_bytes()
+                        .try_into()
+                        .expect("never fails")
+                })
+                .collect(),
+            threshold: self.threshold,
+        }
+    }
+
+    /// Signs `sign_doc` with the keys of the current committee at the given
+    /// indices.
+    pub fn sign(&self, sign_doc: &[u8], signers: &[u32]) -> Vec<AttestorSignature> {
+        signers
+            .iter()
+            .map(|&index| {
+                let signature: Signature = self.attestor_keys[index as usize].sign(sign_doc);
+
+                AttestorSignature {
+                    attestor_index: index,
+                    signature: signature.to_bytes().into(),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the indices of the first `threshold` attestors.
+    pub fn quorum(&self) -> Vec<u32> {
+        (0..self.threshold).collect()
+    }
+
+    // ------------------- Synthetic chain -------------------
+
+    pub fn block_time(&self, height: u64) -> Timestamp {
+        Timestamp::from_nanoseconds((self.genesis_time + height * BLOCK_TIME) * 1_000_000_000)
+    }
+
+    /// Returns the multistore committed at the given height. Besides the IBC
+    /// commitments, the IBC store records the height so that the app hash
+    /// changes on every block.
+    pub fn store(&self, height: u64) -> MultiStore {
+        let mut store = MultiStore::default();
+
+        store.set(b"bank", b"supply", b"bank-store");
+        store.set(b"staking", b"validators", b"staking-store");
+
+        for (path, value) in &self.commitments {
+            store.set(IBC_STORE, path, value);
+        }
+
+        store.set(IBC_STORE, b"height", &height.to_be_bytes());
+
+        store.commit();
+
+        store
+    }
+
+    pub fn state_root(&self, height: u64) -> Vec<u8> {
+        self.store(height).app_hash()
+    }
+
+    /// Signs the attestation with the keys of the current committee at the
+    /// given indices, replacing its signatures.
+    pub fn signed_attestation(
+        &self,
+        mut attestation: StateAttestation,
+        signers: &[u32],
+    ) -> StateAttestation {
+        let sign_doc = attestation.sign_doc(&self.chain_id, self.epoch);
+
+        attestation.signatures = self.sign(&sign_doc, signers);
+
+        attestation
+    }
+
+    /// Builds the attestation of the block at `height`, signed by a quorum
+    /// of the current committee.
+    pub fn dummy_attestation(&self, height: u64) -> StateAttestation {
+        self.signed_attestation(
+            StateAttestation {
+                height: AttestorClient::height(height),
+                timestamp: self.block_time(height),
+                root: self.state_root(height),
+                signatures: vec![],
+            },
+            &self.quorum(),
+        )
+    }
+
+    /// Builds the rotation to the committee of `next_keys`, signed by a
+    /// quorum of the current committee.
+    pub fn dummy_rotation(
+        &self,
+        next_keys: &[SigningKey],
+        next_threshold: u32,
+    ) -> CommitteeRotation {
+        let next_committee = Self {
+            attestor_keys: next_keys.to_vec(),
+            threshold: next_threshold,
+            epoch: self.epoch + 1,
+            ..self.clone()
+        }
+        .committee();
+
+        let mut rotation = CommitteeRotation {
+            next_committee,
+            signatures: vec![],
+        };
+
+        rotation.signatures = self.sign(
+            &rotation.sign_doc(&self.chain_id, self.epoch),
+            &self.quorum(),
+        );
+
+        rotation
+    }
+
+    /// Builds a misbehaviour made of two attestations at the same height,
+    /// committing to different state roots, both signed by a quorum of the
+    /// current committee.
+    pub fn dummy_misbehaviour(&self, height: u64) -> Misbehaviour {
+        let attestation_1 = self.dummy_attestation(height);
+
+        let attestation_2 = self.signed_attestation(
+            StateAttestation {
+                root: self.state_root(height + 1),
+                ..attestation_1.clone()
+            },
+            &self.quorum(),
+        );
+
+        Misbehaviour {
+            attestation_1,
+            attestation_2,
+        }
+    }
+
+    pub fn dummy_verify_membership_msg(
+        &self,
+        height: Height,
+        path: &[u8],
+        value: &[u8],
+    ) -> VerifyMembershipMsgRaw {
+        VerifyMembershipMsgRaw {
+            proof: self
+                .store(height.revision_height())
+                .membership_proof(IBC_STORE, path),
+            merkle_path: MerklePath {
+                key_path: vec![IBC_STORE.to_vec().into(), path.to_vec().into()],
+            },
+            value: value.to_vec().into(),
+            height: height.into(),
+            delay_block_period: 0,
+            delay_time_period: 0,
+        }
+    }
+
+    pub fn dummy_verify_non_membership_msg(
+        &self,
+        height: Height,
+        path: &[u8],
+    ) -> VerifyNonMembershipMsgRaw {
+        VerifyNonMembershipMsgRaw {
+            proof: self
+                .store(height.revision_height())
+                .non_membership_proof(IBC_STORE, path),
+            merkle_path: MerklePath {
+                key_path: vec![IBC_STORE.to_vec().into(), path.to_vec().into()],
+            },
+            height: height.into(),
+            delay_block_period: 0,
+            delay_time_period: 0,
+        }
+    }
+}
+
+impl AttestorClient {
+    /// Derives deterministic signing keys from the given seeds.
+    pub fn signing_keys(seeds: impl IntoIterator<Item = u8>) -> Vec<SigningKey> {
+        seeds
+            .into_iter()
+            .map(|seed| SigningKey::from_bytes(&[seed; 32].into()).expect("never fails"))
+            .collect()
+    }
+
+    pub fn height(height: u64) -> Height {
+        Height::new(1, height).expect("never fails")
+    }
+
+    pub fn dummy_client_state(fxt: &Fixture) -> ClientState {
+        ClientState {
+            chain_id: fxt.config.chain_id.clone(),
+            committee: fxt.config.committee(),
+            latest_height: fxt.trusted_height,
+            frozen_height: None,
+            trusting_period: fxt.config.trusting_period,
+            proof_specs: ProofSpecs::cosmos(),
+        }
+    }
+
+    pub fn dummy_consensus_state(fxt: &Fixture) -> ConsensusState {
+        let trusted_height = fxt.trusted_height.revision_height();
+
+        ConsensusState {
+            root: fxt.config.state_root(trusted_height).into(),
+            timestamp: fxt.config.block_time(trusted_height),
+        }
+    }
+}
+
+impl TestClient for AttestorClient {
+    type Config = AttestorConfig;
+
+    fn trusting_period(config: &AttestorConfig) -> Duration {
+        config.trusting_period
+    }
+
+    fn trusted_height(_config: &AttestorConfig) -> Height {
+        Self::height(10)
+    }
+
+    fn client_state(fxt: &Fixture) -> Vec<u8> {
+        ClientState::encode_to_any_vec(Self::dummy_client_state(fxt))
+    }
+
+    fn consensus_state(fxt: &Fixture) -> Vec<u8> {
+        ConsensusState::encode_to_any_vec(Self::dummy_consensus_state(fxt))
+    }
+
+    /// Constructs the attestation of the block at the target height, signed
+    /// by a quorum of the current committee.
+    fn header(fxt: &Fixture, target_height: Height) -> Vec<u8> {
+        StateAttestation::encode_to_any_vec(
+            fxt.config
+                .dummy_attestation(target_height.revision_height()),
+        )
+    }
+
+    /// Constructs two conflicting attestations of the block right after the
+    /// trusted height.
+    fn misbehaviour(fxt: &Fixture) -> Vec<u8> {
+        Misbehaviour::encode_to_any_vec(
+            fxt.config
+                .dummy_misbehaviour(fxt.trusted_height.revision_height() + 1),
+        )
+    }
+}
@@ -0,0 +1,399 @@
+use std::time::Duration;
+
+use cosmwasm_std::from_json;
+use cosmwasm_std::testing::mock_dependencies;
+use ibc_client_cw::types::{ContractResult, MigrationPrefix};
+use ibc_client_cw::utils::AnyCodec;
+use ibc_client_cw_testkit::scenarios;
+use ibc_core::client::types::Status;
+
+use crate::client_type::AttestorClient;
+use crate::types::{CommitteeRotation, Misbehaviour, StateAttestation};
+
+pub mod fixture;
+
+use fixture::{AttestorConfig, Fixture};
+
+#[test]
+fn test_cw_attestor_testkit_scenarios() {
+    scenarios::run_all(&Fixture::default());
+}
+
+#[test]
+fn test_cw_attestor_update_client_ok() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let trusted_height = fxt.trusted_height.revision_height();
+
+    for height in [trusted_height + 1, trusted_height + 20] {
+        let resp = fxt
+            .update_client_with(
+                deps.as_mut(),
+                StateAttestation::encode_to_any_vec(fxt.config.dummy_attestation(height)),
+            )
+            .unwrap();
+
+        let contract_result: ContractResult = from_json(resp.data.unwrap()).unwrap();
+
+        assert_eq!(
+            contract_result.heights,
+            Some(vec![AttestorClient::height(height)])
+        );
+    }
+
+    // Attestations signed by every attestor are accepted as well.
+    let attestation = fxt.config.signed_attestation(
+        fxt.config.dummy_attestation(trusted_height + 21),
+        &[3, 0, 2, 1],
+    );
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        StateAttestation::encode_to_any_vec(attestation),
+    )
+    .unwrap();
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+}
+
+#[test]
+fn test_cw_attestor_invalid_attestation_rejected() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let attestation = fxt
+        .config
+        .dummy_attestation(fxt.trusted_height.revision_height() + 5);
+
+    let below_threshold = fxt.config.signed_attestation(attestation.clone(), &[0, 1]);
+
+    let duplicate_signer = fxt
+        .config
+        .signed_attestation(attestation.clone(), &[0, 1, 1]);
+
+    let mut unknown_signer = attestation.clone();
+    unknown_signer.signatures[0].attestor_index = 4;
+
+    let mut tampered_root = attestation.clone();
+    tampered_root.root = vec![0xff; 32];
+
+    let mut tampered_height = attestation.clone();
+    tampered_height.height = tampered_height.height.increment();
+
+    let mut outsider_signature = attestation.clone();
+    outsider_signature.signatures[0].signature = AttestorConfig {
+        attestor_keys: AttestorClient::signing_keys([9]),
+        ..fxt.config.clone()
+    }
+    .sign(
+        &attestation.sign_doc(&fxt.config.chain_id, fxt.config.epoch),
+        &[0],
+    )[0]
+    .signature;
+
+    let other_chain = AttestorConfig {
+        chain_id: "other-chain-1".to_string(),
+        ..fxt.config.clone()
+    }
+    .signed_attestation(attestation.clone(), &fxt.config.quorum());
+
+    let other_epoch = AttestorConfig {
+        epoch: fxt.config.epoch + 1,
+        ..fxt.config.clone()
+    }
+    .signed_attestation(attestation, &fxt.config.quorum());
+
+    for attestation in [
+        below_threshold,
+        duplicate_signer,
+        unknown_signer,
+        tampered_root,
+        tampered_height,
+        outsider_signature,
+        other_chain,
+        other_epoch,
+    ] {
+        assert!(!fxt.is_valid_client_message(
+            deps.as_ref(),
+            StateAttestation::encode_to_any_vec(attestation)
+        ));
+    }
+}
+
+#[test]
+fn test_cw_attestor_verify_membership() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let trusted_height = fxt.trusted_height.revision_height();
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        StateAttestation::encode_to_any_vec(fxt.config.dummy_attestation(trusted_height + 5)),
+    )
+    .unwrap();
+
+    for height in [trusted_height, trusted_height + 5] {
+        let height = AttestorClient::height(height);
+
+        for (path, value) in &fxt.config.commitments {
+            fxt.verify_membership(
+                deps.as_mut(),
+                fxt.config.dummy_verify_membership_msg(height, path, value),
+            )
+            .unwrap();
+
+            assert!(fxt
+                .verify_membership(
+                    deps.as_mut(),
+                    fxt.config
+                        .dummy_verify_membership_msg(height, path, b"other-value"),
+                )
+                .is_err());
+
+            assert!(fxt
+                .verify_non_membership(
+                    deps.as_mut(),
+                    fxt.config.dummy_verify_non_membership_msg(height, path),
+                )
+                .is_err());
+        }
+
+        for absent_path in [
+            b"commitments/ports/transfer/channels/channel-0/sequences/3".as_slice(),
+            b"acks/ports/transfer/channels/channel-0/sequences/1",
+            b"receipts/ports/transfer/channels/channel-0/sequences/1",
+        ] {
+            fxt.verify_non_membership(
+                deps.as_mut(),
+                fxt.config
+                    .dummy_verify_non_membership_msg(height, absent_path),
+            )
+            .unwrap();
+        }
+    }
+
+    // A proof against the state root of another height is rejected.
+    let (path, value) = &fxt.config.commitments[0];
+
+    let mut msg =
+        fxt.config
+            .dummy_verify_membership_msg(AttestorClient::height(trusted_height), path, value);
+    msg.height = AttestorClient::height(trusted_height + 5).into();
+
+    assert!(fxt.verify_membership(deps.as_mut(), msg).is_err());
+}
+
+#[test]
+fn test_cw_attestor_committee_rotation() {
+    let mut fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let trusted_height = fxt.trusted_height.revision_height();
+
+    let next_keys = AttestorClient::signing_keys(5..=7);
+
+    let rotation = fxt.config.dummy_rotation(&next_keys, 2);
+
+    // A rotation skipping an epoch is rejected.
+    let mut skipping_epoch = rotation.clone();
+    skipping_epoch.next_committee.epoch += 1;
+    skipping_epoch.signatures = fxt.config.sign(
+        &skipping_epoch.sign_doc(&fxt.config.chain_id, fxt.config.epoch),
+        &fxt.config.quorum(),
+    );
+
+    assert!(!fxt.is_valid_client_message(
+        deps.as_ref(),
+        CommitteeRotation::encode_to_any_vec(skipping_epoch)
+    ));
+
+    // A rotation below the threshold of the current committee is rejected.
+    let mut below_threshold = rotation.clone();
+    below_threshold.signatures.pop();
+
+    assert!(!fxt.is_valid_client_message(
+        deps.as_ref(),
+        CommitteeRotation::encode_to_any_vec(below_threshold)
+    ));
+
+    let resp = fxt
+        .update_client_with(
+            deps.as_mut(),
+            CommitteeRotation::encode_to_any_vec(rotation.clone()),
+        )
+        .unwrap();
+
+    let contract_result: ContractResult = from_json(resp.data.unwrap()).unwrap();
+
+    assert_eq!(contract_result.heights, Some(vec![fxt.trusted_height]));
+
+    // The previous committee can neither attest states nor replay the
+    // rotation anymore.
+    assert!(!fxt.is_valid_client_message(
+        deps.as_ref(),
+        StateAttestation::encode_to_any_vec(fxt.config.dummy_attestation(trusted_height + 5))
+    ));
+
+    assert!(!fxt.is_valid_client_message(
+        deps.as_ref(),
+        CommitteeRotation::encode_to_any_vec(rotation)
+    ));
+
+    // The next committee takes over.
+    fxt.config.attestor_keys = next_keys;
+    fxt.config.threshold = 2;
+    fxt.config.epoch += 1;
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        StateAttestation::encode_to_any_vec(fxt.config.dummy_attestation(trusted_height + 5)),
+    )
+    .unwrap();
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+}
+
+#[test]
+fn test_cw_attestor_freeze_on_misbehaviour() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let misbehaviour = fxt
+        .config
+        .dummy_misbehaviour(fxt.trusted_height.revision_height() + 5);
+
+    // Attestations of the same state are no evidence of misbehaviour.
+    let same_state = Misbehaviour {
+        attestation_2: fxt
+            .config
+            .signed_attestation(misbehaviour.attestation_1.clone(), &[1, 2, 3]),
+        ..misbehaviour.clone()
+    };
+
+    assert!(!fxt.found_misbehaviour(deps.as_ref(), Misbehaviour::encode_to_any_vec(same_state)));
+
+    fxt.update_client_on_misbehaviour_with(
+        deps.as_mut(),
+        Misbehaviour::encode_to_any_vec(misbehaviour),
+    );
+
+    fxt.check_client_status(deps.as_ref(), Status::Frozen);
+}
+
+#[test]
+fn test_cw_attestor_freeze_on_conflicting_attestation() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let trusted_height = fxt.trusted_height.revision_height();
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        StateAttestation::encode_to_any_vec(fxt.config.dummy_attestation(trusted_height + 5)),
+    )
+    .unwrap();
+
+    let conflicting_attestation = fxt
+        .config
+        .dummy_misbehaviour(trusted_height + 5)
+        .attestation_2;
+
+    fxt.update_client_on_misbehaviour_with(
+        deps.as_mut(),
+        StateAttestation::encode_to_any_vec(conflicting_attestation),
+    );
+
+    fxt.check_client_status(deps.as_ref(), Status::Frozen);
+}
+
+#[test]
+fn test_cw_attestor_recovery_client_ok() {
+    let mut fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    // ------------------- Create subject client -------------------
+
+    fxt.set_migration_prefix(MigrationPrefix::Subject);
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    // ------------------- Freeze subject client -------------------
+
+    let misbehaviour = fxt
+        .config
+        .dummy_misbehaviour(fxt.trusted_height.revision_height() + 5);
+
+    fxt.update_client_on_misbehaviour_with(
+        deps.as_mut(),
+        Misbehaviour::encode_to_any_vec(misbehaviour),
+    );
+
+    fxt.check_client_status(deps.as_ref(), Status::Frozen);
+
+    // ------------------- Create substitute client -------------------
+
+    // The substitute client is trusting a new committee.
+    fxt.set_migration_prefix(MigrationPrefix::Substitute);
+
+    fxt.trusted_height = AttestorClient::height(fxt.trusted_height.revision_height() + 20);
+    fxt.config.attestor_keys = AttestorClient::signing_keys(5..=7);
+    fxt.config.threshold = 2;
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    // ------------------- Recover subject client -------------------
+
+    let resp = fxt.recover_client(deps.as_mut()).unwrap();
+
+    assert_eq!(0, resp.messages.len());
+
+    fxt.set_migration_prefix(MigrationPrefix::Subject);
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+
+    // ------------------- Update with the new committee -------------------
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        StateAttestation::encode_to_any_vec(
+            fxt.config
+                .dummy_attestation(fxt.trusted_height.revision_height() + 1),
+        ),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_cw_attestor_client_expiry() {
+    let fxt = Fixture::new(AttestorConfig {
+        trusting_period: Duration::from_secs(60),
+        ..Default::default()
+    });
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    fxt.check_client_status(deps.as_ref(), Status::Expired);
+}
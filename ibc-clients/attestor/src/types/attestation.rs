@@ -0,0 +1,183 @@
+//! State attestations, the client messages updating the client to a new
+//! counterparty state root, and the misbehaviour made of two conflicting
+//! attestations.
+use ibc_core::client::types::Height;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::proto::{Any, Protobuf};
+use ibc_core::primitives::Timestamp;
+use prost::Message;
+
+use super::committee::decode_signatures;
+use super::proto::{
+    Misbehaviour as RawMisbehaviour, StateAttestation as RawStateAttestation,
+    StateAttestationSignDoc,
+};
+use super::AttestorSignature;
+
+pub const ATTESTOR_STATE_ATTESTATION_TYPE_URL: &str =
+    "/ibc.lightclients.attestor.v1.StateAttestation";
+pub const ATTESTOR_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.lightclients.attestor.v1.Misbehaviour";
+
+/// The attestation by the committee that the counterparty state at `height`
+/// is committed by `root`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StateAttestation {
+    pub height: Height,
+    /// The time of the counterparty block at `height`.
+    pub timestamp: Timestamp,
+    /// The root of the counterparty state, against which ICS-23 proofs are
+    /// verified.
+    pub root: Vec<u8>,
+    pub signatures: Vec<AttestorSignature>,
+}
+
+impl StateAttestation {
+    /// Returns the encoded document signed by the attestors, binding the
+    /// attested state to the counterparty chain and to the committee epoch.
+    pub fn sign_doc(&self, chain_id: &str, epoch: u64) -> Vec<u8> {
+        StateAttestationSignDoc {
+            type_url: ATTESTOR_STATE_ATTESTATION_TYPE_URL.to_string(),
+            chain_id: chain_id.to_string(),
+            epoch,
+            height: Some(self.height.into()),
+            timestamp: self.timestamp.nanoseconds(),
+            root: self.root.clone(),
+        }
+        .encode_to_vec()
+    }
+
+    /// Returns whether both attestations attest the same state, regardless
+    /// of their signatures.
+    pub fn attests_same_state(&self, other: &Self) -> bool {
+        self.height == other.height && self.timestamp == other.timestamp && self.root == other.root
+    }
+}
+
+impl Protobuf<RawStateAttestation> for StateAttestation {}
+
+impl TryFrom<RawStateAttestation> for StateAttestation {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawStateAttestation) -> Result<Self, Self::Error> {
+        if raw.root.is_empty() {
+            return Err(DecodingError::missing_raw_data("attested state root"));
+        }
+
+        if raw.timestamp == 0 {
+            return Err(DecodingError::missing_raw_data("attested timestamp"));
+        }
+
+        Ok(Self {
+            height: raw
+                .height
+                .ok_or(DecodingError::missing_raw_data("attested height"))?
+                .try_into()?,
+            timestamp: Timestamp::from_nanoseconds(raw.timestamp),
+            root: raw.root,
+            signatures: decode_signatures(raw.signatures)?,
+        })
+    }
+}
+
+impl From<StateAttestation> for RawStateAttestation {
+    fn from(value: StateAttestation) -> Self {
+        Self {
+            height: Some(value.height.into()),
+            timestamp: value.timestamp.nanoseconds(),
+            root: value.root,
+            signatures: value.signatures.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl Protobuf<Any> for StateAttestation {}
+
+impl TryFrom<Any> for StateAttestation {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let ATTESTOR_STATE_ATTESTATION_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawStateAttestation>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: ATTESTOR_STATE_ATTESTATION_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<StateAttestation> for Any {
+    fn from(attestation: StateAttestation) -> Self {
+        Self {
+            type_url: ATTESTOR_STATE_ATTESTATION_TYPE_URL.to_string(),
+            value: Protobuf::<RawStateAttestation>::encode_vec(attestation),
+        }
+    }
+}
+
+/// Two attestations of different states at the same height, both signed by
+/// the current committee.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Misbehaviour {
+    pub attestation_1: StateAttestation,
+    pub attestation_2: StateAttestation,
+}
+
+impl Protobuf<RawMisbehaviour> for Misbehaviour {}
+
+impl TryFrom<RawMisbehaviour> for Misbehaviour {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawMisbehaviour) -> Result<Self, Self::Error> {
+        Ok(Self {
+            attestation_1: raw
+                .attestation_1
+                .ok_or(DecodingError::missing_raw_data(
+                    "misbehaviour attestation 1",
+                ))?
+                .try_into()?,
+            attestation_2: raw
+                .attestation_2
+                .ok_or(DecodingError::missing_raw_data(
+                    "misbehaviour attestation 2",
+                ))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<Misbehaviour> for RawMisbehaviour {
+    fn from(value: Misbehaviour) -> Self {
+        Self {
+            attestation_1: Some(value.attestation_1.into()),
+            attestation_2: Some(value.attestation_2.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for Misbehaviour {}
+
+impl TryFrom<Any> for Misbehaviour {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let ATTESTOR_MISBEHAVIOUR_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawMisbehaviour>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: ATTESTOR_MISBEHAVIOUR_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<Misbehaviour> for Any {
+    fn from(misbehaviour: Misbehaviour) -> Self {
+        Self {
+            type_url: ATTESTOR_MISBEHAVIOUR_TYPE_URL.to_string(),
+            value: Protobuf::<RawMisbehaviour>::encode_vec(misbehaviour),
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//! The committee of attestors trusted by the client, and the verification of
+//! the signatures they produce.
+use std::collections::BTreeSet;
+
+use ibc_core::host::types::error::DecodingError;
+use sha2::{Digest, Sha256};
+
+use super::proto::{AttestorSignature as RawAttestorSignature, Committee as RawCommittee};
+use super::{fixed_bytes, Bytes33, Bytes64};
+use crate::error::AttestorError;
+use crate::secp256k1::Secp256k1Verifier;
+
+/// A committee of N attestors, any M of which are trusted to attest the
+/// counterparty state, M being the threshold.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Committee {
+    /// The sequence number of the committee, incremented on every rotation.
+    /// It is part of every signed document, so that the signatures of a
+    /// committee are not valid for the following ones.
+    pub epoch: u64,
+    /// The compressed secp256k1 public keys of the attestors.
+    pub attestors: Vec<Bytes33>,
+    /// The number of distinct attestors required to sign a document.
+    pub threshold: u32,
+}
+
+impl Committee {
+    /// Verifies that at least `threshold` distinct attestors of the committee
+    /// signed `sign_doc`. Every signature must be valid, even beyond the
+    /// threshold.
+    pub fn verify_signatures<V>(
+        &self,
+        verifier: &V,
+        sign_doc: &[u8],
+        signatures: &[AttestorSignature],
+    ) -> Result<(), AttestorError>
+    where
+        V: Secp256k1Verifier + ?Sized,
+    {
+        let message_hash: [u8; 32] = Sha256::digest(sign_doc).into();
+
+        let mut signers = BTreeSet::new();
+
+        for signature in signatures {
+            let index = signature.attestor_index;
+
+            let attestor =
+                self.attestors
+                    .get(index as usize)
+                    .ok_or(AttestorError::UnknownAttestor {
+                        index,
+                        size: self.attestors.len(),
+                    })?;
+
+            if !signers.insert(index) {
+                return Err(AttestorError::DuplicateSignature { index });
+            }
+
+            if !verifier.secp256k1_verify(&message_hash, &signature.signature, attestor) {
+                return Err(AttestorError::InvalidSignature { index });
+            }
+        }
+
+        if signers.len() < self.threshold as usize {
+            return Err(AttestorError::InsufficientSignatures {
+                signed: signers.len(),
+                threshold: self.threshold,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), DecodingError> {
+        if self.threshold == 0 || self.threshold as usize > self.attestors.len() {
+            return Err(DecodingError::invalid_raw_data(format!(
+                "committee threshold `{}` must be between 1 and the number of attestors `{}`",
+                self.threshold,
+                self.attestors.len()
+            )));
+        }
+
+        let distinct_attestors: BTreeSet<_> = self.attestors.iter().collect();
+
+        if distinct_attestors.len() != self.attestors.len() {
+            return Err(DecodingError::invalid_raw_data(
+                "committee attestors must be distinct",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<RawCommittee> for Committee {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawCommittee) -> Result<Self, Self::Error> {
+        let committee = Self {
+            epoch: raw.epoch,
+            attestors: raw
+                .attestors
+                .into_iter()
+                .map(|attestor| fixed_bytes("attestor public key", attestor))
+                .collect::<Result<_, _>>()?,
+            threshold: raw.threshold,
+        };
+
+        committee.validate()?;
+
+        Ok(committee)
+    }
+}
+
+impl From<Committee> for RawCommittee {
+    fn from(value: Committee) -> Self {
+        Self {
+            epoch: value.epoch,
+            attestors: value.attestors.into_iter().map(Vec::from).collect(),
+            threshold: value.threshold,
+        }
+    }
+}
+
+/// The signature of a document by an attestor, referred to by its index in
+/// the committee.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AttestorSignature {
+    pub attestor_index: u32,
+    pub signature: Bytes64,
+}
+
+impl TryFrom<RawAttestorSignature> for AttestorSignature {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawAttestorSignature) -> Result<Self, Self::Error> {
+        Ok(Self {
+            attestor_index: raw.attestor_index,
+            signature: fixed_bytes("attestor signature", raw.signature)?,
+        })
+    }
+}
+
+impl From<AttestorSignature> for RawAttestorSignature {
+    fn from(value: AttestorSignature) -> Self {
+        Self {
+            attestor_index: value.attestor_index,
+            signature: value.signature.to_vec(),
+        }
+    }
+}
+
+/// Decodes a list of raw attestor signatures.
+pub(crate) fn decode_signatures(
+    raw: Vec<RawAttestorSignature>,
+) -> Result<Vec<AttestorSignature>, DecodingError> {
+    raw.into_iter().map(TryInto::try_into).collect()
+}
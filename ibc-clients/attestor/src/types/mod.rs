@@ -0,0 +1,30 @@
+mod attestation;
+mod committee;
+pub mod proto;
+mod rotation;
+
+pub use attestation::*;
+pub use committee::*;
+use ibc_core::host::types::error::DecodingError;
+pub use rotation::*;
+
+/// The size of a compressed secp256k1 public key.
+pub type Bytes33 = [u8; 33];
+
+/// The size of a secp256k1 signature, serialized as `r || s`.
+pub type Bytes64 = [u8; 64];
+
+/// Converts raw bytes into a fixed-size array, failing if the length does not
+/// match.
+pub(crate) fn fixed_bytes<const N: usize>(
+    name: &str,
+    bytes: Vec<u8>,
+) -> Result<[u8; N], DecodingError> {
+    let actual = bytes.len();
+
+    bytes.try_into().map_err(|_| {
+        DecodingError::invalid_raw_data(format!(
+            "{name} must be {N} bytes long, found {actual} bytes"
+        ))
+    })
+}
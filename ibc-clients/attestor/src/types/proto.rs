@@ -0,0 +1,110 @@
+//! Protobuf definitions of the attestor light client types, under the
+//! `ibc.lightclients.attestor.v1` package.
+use ibc_core::client::types::proto::v1::Height as RawHeight;
+use ibc_core::commitment_types::proto::ics23::ProofSpec;
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Committee {
+    #[prost(uint64, tag = "1")]
+    pub epoch: u64,
+    /// The compressed secp256k1 public keys of the attestors.
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub attestors: Vec<Vec<u8>>,
+    #[prost(uint32, tag = "3")]
+    pub threshold: u32,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ClientState {
+    #[prost(string, tag = "1")]
+    pub chain_id: String,
+    #[prost(message, optional, tag = "2")]
+    pub committee: Option<Committee>,
+    #[prost(message, optional, tag = "3")]
+    pub latest_height: Option<RawHeight>,
+    #[prost(message, optional, tag = "4")]
+    pub frozen_height: Option<RawHeight>,
+    #[prost(uint64, tag = "5")]
+    pub trusting_period: u64,
+    #[prost(message, repeated, tag = "6")]
+    pub proof_specs: Vec<ProofSpec>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ConsensusState {
+    #[prost(bytes = "vec", tag = "1")]
+    pub root: Vec<u8>,
+    #[prost(uint64, tag = "2")]
+    pub timestamp: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct AttestorSignature {
+    /// The index of the attestor in the committee.
+    #[prost(uint32, tag = "1")]
+    pub attestor_index: u32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub signature: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct StateAttestation {
+    #[prost(message, optional, tag = "1")]
+    pub height: Option<RawHeight>,
+    #[prost(uint64, tag = "2")]
+    pub timestamp: u64,
+    #[prost(bytes = "vec", tag = "3")]
+    pub root: Vec<u8>,
+    #[prost(message, repeated, tag = "4")]
+    pub signatures: Vec<AttestorSignature>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct CommitteeRotation {
+    #[prost(message, optional, tag = "1")]
+    pub next_committee: Option<Committee>,
+    #[prost(message, repeated, tag = "2")]
+    pub signatures: Vec<AttestorSignature>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Misbehaviour {
+    #[prost(message, optional, tag = "1")]
+    pub attestation_1: Option<StateAttestation>,
+    #[prost(message, optional, tag = "2")]
+    pub attestation_2: Option<StateAttestation>,
+}
+
+/// The document signed by the attestors of a state attestation.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct StateAttestationSignDoc {
+    /// The type URL of the attested message, separating the domains of the
+    /// signed documents.
+    #[prost(string, tag = "1")]
+    pub type_url: String,
+    #[prost(string, tag = "2")]
+    pub chain_id: String,
+    #[prost(uint64, tag = "3")]
+    pub epoch: u64,
+    #[prost(message, optional, tag = "4")]
+    pub height: Option<RawHeight>,
+    #[prost(uint64, tag = "5")]
+    pub timestamp: u64,
+    #[prost(bytes = "vec", tag = "6")]
+    pub root: Vec<u8>,
+}
+
+/// The document signed by the attestors of a committee rotation.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct CommitteeRotationSignDoc {
+    /// The type URL of the attested message, separating the domains of the
+    /// signed documents.
+    #[prost(string, tag = "1")]
+    pub type_url: String,
+    #[prost(string, tag = "2")]
+    pub chain_id: String,
+    #[prost(uint64, tag = "3")]
+    pub epoch: u64,
+    #[prost(message, optional, tag = "4")]
+    pub next_committee: Option<Committee>,
+}
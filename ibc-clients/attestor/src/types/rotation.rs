@@ -0,0 +1,85 @@
+//! Committee rotations, the client messages handing the trust of the client
+//! over to a new committee.
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::proto::{Any, Protobuf};
+use prost::Message;
+
+use super::committee::decode_signatures;
+use super::proto::{CommitteeRotation as RawCommitteeRotation, CommitteeRotationSignDoc};
+use super::{AttestorSignature, Committee};
+
+pub const ATTESTOR_COMMITTEE_ROTATION_TYPE_URL: &str =
+    "/ibc.lightclients.attestor.v1.CommitteeRotation";
+
+/// The attestation by the current committee that `next_committee` succeeds
+/// it. The epoch of the next committee must follow the current one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommitteeRotation {
+    pub next_committee: Committee,
+    pub signatures: Vec<AttestorSignature>,
+}
+
+impl CommitteeRotation {
+    /// Returns the encoded document signed by the attestors of the current
+    /// committee, whose epoch is `epoch`.
+    pub fn sign_doc(&self, chain_id: &str, epoch: u64) -> Vec<u8> {
+        CommitteeRotationSignDoc {
+            type_url: ATTESTOR_COMMITTEE_ROTATION_TYPE_URL.to_string(),
+            chain_id: chain_id.to_string(),
+            epoch,
+            next_committee: Some(self.next_committee.clone().into()),
+        }
+        .encode_to_vec()
+    }
+}
+
+impl Protobuf<RawCommitteeRotation> for CommitteeRotation {}
+
+impl TryFrom<RawCommitteeRotation> for CommitteeRotation {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawCommitteeRotation) -> Result<Self, Self::Error> {
+        Ok(Self {
+            next_committee: raw
+                .next_committee
+                .ok_or(DecodingError::missing_raw_data("next committee"))?
+                .try_into()?,
+            signatures: decode_signatures(raw.signatures)?,
+        })
+    }
+}
+
+impl From<CommitteeRotation> for RawCommitteeRotation {
+    fn from(value: CommitteeRotation) -> Self {
+        Self {
+            next_committee: Some(value.next_committee.into()),
+            signatures: value.signatures.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl Protobuf<Any> for CommitteeRotation {}
+
+impl TryFrom<Any> for CommitteeRotation {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let ATTESTOR_COMMITTEE_ROTATION_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawCommitteeRotation>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: ATTESTOR_COMMITTEE_ROTATION_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<CommitteeRotation> for Any {
+    fn from(rotation: CommitteeRotation) -> Self {
+        Self {
+            type_url: ATTESTOR_COMMITTEE_ROTATION_TYPE_URL.to_string(),
+            value: Protobuf::<RawCommitteeRotation>::encode_vec(rotation),
+        }
+    }
+}
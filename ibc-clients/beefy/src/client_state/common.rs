@@ -0,0 +1,151 @@
+use core::time::Duration;
+
+use ibc_core::client::context::client_state::ClientStateCommon;
+use ibc_core::client::context::consensus_state::ConsensusState as _;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::{Height, Status};
+use ibc_core::commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::host::types::identifiers::ClientType;
+use ibc_core::host::types::path::{Path, PathBytes};
+use ibc_core::primitives::proto::Any;
+use ibc_core::primitives::Timestamp;
+use prost::Message;
+
+use super::ClientState;
+use crate::consensus_state::ConsensusState;
+use crate::error::BeefyError;
+use crate::trie::verify_proof;
+use crate::types::proto::StorageProof as RawStorageProof;
+use crate::types::{fixed_bytes, Bytes32};
+
+pub const BEEFY_CLIENT_TYPE: &str = "beefy";
+
+impl ClientStateCommon for ClientState {
+    fn verify_consensus_state(
+        &self,
+        consensus_state: Any,
+        host_timestamp: &Timestamp,
+    ) -> Result<(), ClientError> {
+        let consensus_state = ConsensusState::try_from(consensus_state)?;
+
+        if consensus_status(&consensus_state, host_timestamp, self.trusting_period)?.is_expired() {
+            return Err(ClientError::InvalidStatus(Status::Expired));
+        }
+
+        Ok(())
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::new(BEEFY_CLIENT_TYPE).expect("valid client type")
+    }
+
+    fn latest_height(&self) -> Height {
+        self.latest_height
+    }
+
+    fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError> {
+        if self.latest_height < proof_height {
+            return Err(ClientError::InsufficientProofHeight {
+                actual: self.latest_height,
+                expected: proof_height,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn serialize_path(&self, path: Path) -> Result<PathBytes, ClientError> {
+        Ok(path.to_string().into_bytes().into())
+    }
+
+    /// Upgrades are not supported by this client: runtime upgrades keep the
+    /// parachain and its relay chain valid, and a change of the client
+    /// parameters goes through a client recovery.
+    fn verify_upgrade_client(
+        &self,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+        _proof_upgrade_client: CommitmentProofBytes,
+        _proof_upgrade_consensus_state: CommitmentProofBytes,
+        _root: &CommitmentRoot,
+    ) -> Result<(), ClientError> {
+        Err(ClientError::ClientSpecific {
+            description: "upgrades are not supported by the BEEFY client".to_string(),
+        })
+    }
+
+    /// Verifies that `value` is stored in the parachain state under the key
+    /// `prefix || path`, as written by IBC pallets storing the commitments
+    /// as raw bytes under their prefix.
+    fn verify_membership_raw(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: PathBytes,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        let stored = verify_storage_proof(proof, root, &storage_key(prefix, &path))?;
+
+        if stored.as_ref() != Some(&value) {
+            return Err(BeefyError::MismatchedStorageValue)?;
+        }
+
+        Ok(())
+    }
+
+    fn verify_non_membership_raw(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: PathBytes,
+    ) -> Result<(), ClientError> {
+        if verify_storage_proof(proof, root, &storage_key(prefix, &path))?.is_some() {
+            return Err(BeefyError::MismatchedStorageValue)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the key of the parachain storage holding the commitment at
+/// `path`.
+pub fn storage_key(prefix: &CommitmentPrefix, path: &PathBytes) -> Vec<u8> {
+    [prefix.as_bytes(), path.as_ref()].concat()
+}
+
+/// Verifies the storage proof of `key` against the state root, returning the
+/// stored value if present.
+fn verify_storage_proof(
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    key: &[u8],
+) -> Result<Option<Vec<u8>>, ClientError> {
+    let storage_proof = RawStorageProof::decode(proof.as_ref())
+        .map_err(|e| BeefyError::from(DecodingError::from(e)))?;
+
+    let state_root: Bytes32 =
+        fixed_bytes("state root", root.as_bytes().to_vec()).map_err(BeefyError::from)?;
+
+    Ok(verify_proof(&state_root, key, &storage_proof.proof)?)
+}
+
+/// Determines whether a consensus state is `Active` or `Expired`, given the
+/// host's timestamp and the trusting period.
+pub fn consensus_status(
+    consensus_state: &ConsensusState,
+    host_timestamp: &Timestamp,
+    trusting_period: Duration,
+) -> Result<Status, ClientError> {
+    if let Some(elapsed) = host_timestamp.duration_since(&consensus_state.timestamp()?) {
+        if elapsed >= trusting_period {
+            return Ok(Status::Expired);
+        }
+    }
+
+    Ok(Status::Active)
+}
@@ -0,0 +1,182 @@
+use ibc_core::client::context::client_state::{ClientStateCommon, ClientStateExecution};
+use ibc_core::client::context::prelude::*;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Height;
+use ibc_core::host::types::identifiers::ClientId;
+use ibc_core::host::types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc_core::primitives::proto::Any;
+
+use super::{consensus_state_at, parachain_consensus_state, ClientState};
+use crate::consensus_state::ConsensusState;
+use crate::secp256k1::Secp256k1Recoverer;
+use crate::types::Header;
+
+impl<E> ClientStateExecution<E> for ClientState
+where
+    E: ExtClientExecutionContext + Secp256k1Recoverer,
+    E::ClientStateRef: From<Self>,
+    ConsensusState: Convertible<E::ConsensusStateRef>,
+    <ConsensusState as TryFrom<E::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    fn initialise(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        let consensus_state: ConsensusState = consensus_state.try_into()?;
+
+        ctx.store_client_state(ClientStatePath::new(client_id.clone()), self.clone().into())?;
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                client_id.clone(),
+                self.latest_height.revision_number(),
+                self.latest_height.revision_height(),
+            ),
+            consensus_state.into(),
+        )?;
+        ctx.store_update_meta(
+            client_id.clone(),
+            self.latest_height,
+            ctx.host_timestamp()?,
+            ctx.host_height()?,
+        )?;
+
+        Ok(())
+    }
+
+    /// Installs the consensus state of the parachain header and follows the
+    /// relay chain to the block of the commitment. A commitment signed by
+    /// the next authority set hands the client over to it, and the next
+    /// authority set is always taken from the verified MMR leaf, which
+    /// belongs to the session of the signing set.
+    fn update_state(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        let header = Header::try_from(client_message)?;
+        let height = header.height()?;
+        let commitment = &header.signed_commitment.commitment;
+
+        let current_authorities = if commitment.validator_set_id == self.next_authorities.id {
+            self.next_authorities.clone()
+        } else {
+            self.current_authorities.clone()
+        };
+
+        let new_client_state = Self {
+            latest_beefy_height: commitment.block_number,
+            current_authorities,
+            next_authorities: header.mmr_leaf.beefy_next_authority_set.clone(),
+            latest_height: self.latest_height.max(height),
+            ..self.clone()
+        };
+
+        ctx.store_client_state(
+            ClientStatePath::new(client_id.clone()),
+            new_client_state.into(),
+        )?;
+
+        if consensus_state_at(ctx, client_id, height).is_ok() {
+            // if we already had the parachain header installed by a previous
+            // relayer then only the relay chain is followed.
+            return Ok(vec![height]);
+        }
+
+        let new_consensus_state = parachain_consensus_state(&header.parachain_header)?;
+
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                client_id.clone(),
+                height.revision_number(),
+                height.revision_height(),
+            ),
+            new_consensus_state.into(),
+        )?;
+        ctx.store_update_meta(
+            client_id.clone(),
+            height,
+            ctx.host_timestamp()?,
+            ctx.host_height()?,
+        )?;
+
+        Ok(vec![height])
+    }
+
+    fn update_state_on_misbehaviour(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        _client_message: Any,
+    ) -> Result<(), ClientError> {
+        let frozen_client_state = Self {
+            frozen_height: Some(Height::min(0)),
+            ..self.clone()
+        };
+
+        ctx.store_client_state(
+            ClientStatePath::new(client_id.clone()),
+            frozen_client_state.into(),
+        )?;
+
+        Ok(())
+    }
+
+    fn update_state_on_upgrade(
+        &self,
+        _ctx: &mut E,
+        _client_id: &ClientId,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        Err(ClientError::ClientSpecific {
+            description: "upgrades are not supported by the BEEFY client".to_string(),
+        })
+    }
+
+    fn update_on_recovery(
+        &self,
+        ctx: &mut E,
+        subject_client_id: &ClientId,
+        substitute_client_state: Any,
+        substitute_consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        let substitute_client_state = Self::try_from(substitute_client_state)?;
+        let new_consensus_state: ConsensusState = substitute_consensus_state.try_into()?;
+
+        let new_client_state = Self {
+            latest_beefy_height: substitute_client_state.latest_beefy_height,
+            current_authorities: substitute_client_state.current_authorities,
+            next_authorities: substitute_client_state.next_authorities,
+            latest_height: substitute_client_state.latest_height,
+            frozen_height: None,
+            trusting_period: substitute_client_state.trusting_period,
+            ..self.clone()
+        };
+
+        let latest_height = new_client_state.latest_height();
+
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                subject_client_id.clone(),
+                latest_height.revision_number(),
+                latest_height.revision_height(),
+            ),
+            new_consensus_state.into(),
+        )?;
+        ctx.store_client_state(
+            ClientStatePath::new(subject_client_id.clone()),
+            new_client_state.into(),
+        )?;
+        ctx.store_update_meta(
+            subject_client_id.clone(),
+            latest_height,
+            ctx.host_timestamp()?,
+            ctx.host_height()?,
+        )?;
+
+        Ok(())
+    }
+}
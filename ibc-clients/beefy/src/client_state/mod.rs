@@ -0,0 +1,155 @@
+//! Implements the BEEFY `ClientState` along with the `ClientStateCommon`,
+//! `ClientStateValidation` and `ClientStateExecution` traits.
+use core::time::Duration;
+
+use ibc_core::client::types::Height;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::proto::{Any, Protobuf};
+
+use crate::types::proto::ClientState as RawClientState;
+use crate::types::AuthoritySet;
+
+mod common;
+mod execution;
+mod validation;
+
+pub use common::*;
+pub use validation::*;
+
+pub const BEEFY_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.beefy.v1.ClientState";
+
+/// The revision number of the client heights, whose revision heights are
+/// parachain block numbers.
+pub const BEEFY_REVISION_NUMBER: u64 = 0;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientState {
+    /// The ID of the tracked parachain.
+    pub para_id: u32,
+    /// The relay chain block whose MMR leaf has index zero.
+    pub beefy_activation_block: u32,
+    /// The relay chain block of the latest verified commitment.
+    pub latest_beefy_height: u32,
+    /// The authority set signing the commitments of the current session.
+    pub current_authorities: AuthoritySet,
+    /// The authority set signing the commitments from the first block of
+    /// the next session.
+    pub next_authorities: AuthoritySet,
+    pub latest_height: Height,
+    pub frozen_height: Option<Height>,
+    /// The duration after which a parachain state is no longer trusted.
+    pub trusting_period: Duration,
+}
+
+impl ClientState {
+    pub fn is_frozen(&self) -> bool {
+        self.frozen_height.is_some()
+    }
+
+    fn validate(&self) -> Result<(), DecodingError> {
+        if self.latest_height.revision_number() != BEEFY_REVISION_NUMBER {
+            return Err(DecodingError::invalid_raw_data(format!(
+                "latest height must have revision number {BEEFY_REVISION_NUMBER}"
+            )));
+        }
+
+        if self.latest_beefy_height < self.beefy_activation_block {
+            return Err(DecodingError::invalid_raw_data(format!(
+                "latest BEEFY height `{}` precedes the activation block `{}`",
+                self.latest_beefy_height, self.beefy_activation_block
+            )));
+        }
+
+        if self.current_authorities.id.checked_add(1) != Some(self.next_authorities.id) {
+            return Err(DecodingError::invalid_raw_data(format!(
+                "next authority set `{}` must follow the current set `{}`",
+                self.next_authorities.id, self.current_authorities.id
+            )));
+        }
+
+        if self.trusting_period.is_zero() {
+            return Err(DecodingError::invalid_raw_data(
+                "trusting period must be non-zero",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        let client_state = Self {
+            para_id: raw.para_id,
+            beefy_activation_block: raw.beefy_activation_block,
+            latest_beefy_height: raw.latest_beefy_height,
+            current_authorities: raw
+                .current_authorities
+                .ok_or(DecodingError::missing_raw_data("current authority set"))?
+                .try_into()?,
+            next_authorities: raw
+                .next_authorities
+                .ok_or(DecodingError::missing_raw_data("next authority set"))?
+                .try_into()?,
+            latest_height: raw
+                .latest_height
+                .ok_or(DecodingError::missing_raw_data("latest height"))?
+                .try_into()?,
+            frozen_height: raw.frozen_height.map(TryInto::try_into).transpose()?,
+            trusting_period: Duration::from_nanos(raw.trusting_period),
+        };
+
+        client_state.validate()?;
+
+        Ok(client_state)
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            para_id: value.para_id,
+            beefy_activation_block: value.beefy_activation_block,
+            latest_beefy_height: value.latest_beefy_height,
+            current_authorities: Some(value.current_authorities.into()),
+            next_authorities: Some(value.next_authorities.into()),
+            latest_height: Some(value.latest_height.into()),
+            frozen_height: value.frozen_height.map(Into::into),
+            trusting_period: value
+                .trusting_period
+                .as_nanos()
+                .try_into()
+                .expect("no overflow"),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let BEEFY_CLIENT_STATE_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawClientState>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: BEEFY_CLIENT_STATE_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Self {
+            type_url: BEEFY_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientState>::encode_vec(client_state),
+        }
+    }
+}
@@ -0,0 +1,327 @@
+use ibc_core::client::context::client_state::ClientStateValidation;
+use ibc_core::client::context::prelude::*;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::{Height, Status};
+use ibc_core::host::types::identifiers::ClientId;
+use ibc_core::host::types::path::ClientConsensusStatePath;
+use ibc_core::primitives::proto::Any;
+use ibc_core::primitives::Timestamp;
+
+use super::{consensus_status, ClientState};
+use crate::consensus_state::ConsensusState;
+use crate::error::BeefyError;
+use crate::merkle::{keccak256, verify_binary_merkle_proof, verify_mmr_proof};
+use crate::secp256k1::Secp256k1Recoverer;
+use crate::trie::{verify_proof, TIMESTAMP_NOW_KEY};
+use crate::types::{
+    AuthoritySet, Header, Misbehaviour, ParachainHeader, SignedCommitment, BEEFY_HEADER_TYPE_URL,
+    BEEFY_MISBEHAVIOUR_TYPE_URL,
+};
+
+impl<V> ClientStateValidation<V> for ClientState
+where
+    V: ExtClientValidationContext + Secp256k1Recoverer,
+    ConsensusState: Convertible<V::ConsensusStateRef>,
+    <ConsensusState as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    fn verify_client_message(
+        &self,
+        ctx: &V,
+        _client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        match client_message.type_url.as_str() {
+            BEEFY_HEADER_TYPE_URL => {
+                let header = Header::try_from(client_message)?;
+                self.verify_header(ctx, &header)?;
+                Ok(())
+            }
+            BEEFY_MISBEHAVIOUR_TYPE_URL => {
+                let misbehaviour = Misbehaviour::try_from(client_message)?;
+                self.verify_misbehaviour(ctx, &misbehaviour)
+            }
+            header_type => Err(ClientError::InvalidHeaderType(header_type.to_owned())),
+        }
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        match client_message.type_url.as_str() {
+            BEEFY_HEADER_TYPE_URL => {
+                let header = Header::try_from(client_message)?;
+                self.check_for_misbehaviour_on_header(ctx, client_id, &header)
+            }
+            BEEFY_MISBEHAVIOUR_TYPE_URL => {
+                let misbehaviour = Misbehaviour::try_from(client_message)?;
+
+                Ok(misbehaviour.commitment_1.commitment != misbehaviour.commitment_2.commitment)
+            }
+            header_type => Err(ClientError::InvalidHeaderType(header_type.to_owned())),
+        }
+    }
+
+    fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError> {
+        if self.is_frozen() {
+            return Ok(Status::Frozen);
+        }
+
+        // if the client state does not have an associated consensus state for
+        // its latest height then it must be expired
+        let Ok(latest_consensus_state) = consensus_state_at(ctx, client_id, self.latest_height)
+        else {
+            return Ok(Status::Expired);
+        };
+
+        consensus_status(
+            &latest_consensus_state,
+            &ctx.host_timestamp()?,
+            self.trusting_period,
+        )
+    }
+
+    /// The subject and substitute client states match if they track the same
+    /// parachain of the same relay chain. The authority sets may differ, as
+    /// a client left behind by more than a session can only be recovered
+    /// with the authority sets of a later session.
+    fn check_substitute(&self, _ctx: &V, substitute_client_state: Any) -> Result<(), ClientError> {
+        let substitute_client_state = Self::try_from(substitute_client_state)?;
+
+        let normalized = Self {
+            latest_beefy_height: self.latest_beefy_height,
+            current_authorities: self.current_authorities.clone(),
+            next_authorities: self.next_authorities.clone(),
+            latest_height: self.latest_height,
+            frozen_height: self.frozen_height,
+            trusting_period: self.trusting_period,
+            ..substitute_client_state
+        };
+
+        (&normalized == self)
+            .then_some(())
+            .ok_or(ClientError::FailedToVerifyClientRecoveryStates)
+    }
+}
+
+impl ClientState {
+    /// Returns the trusted authority set with the given ID, which must either
+    /// be the current or the next set.
+    pub fn authority_set(&self, id: u64) -> Result<&AuthoritySet, BeefyError> {
+        [&self.current_authorities, &self.next_authorities]
+            .into_iter()
+            .find(|set| set.id == id)
+            .ok_or(BeefyError::UnknownAuthoritySet {
+                id,
+                current: self.current_authorities.id,
+            })
+    }
+
+    /// Verifies that a commitment of a block covered by the MMR is signed by
+    /// more than two thirds of the current or of the next authority set.
+    pub fn verify_signed_commitment<V>(
+        &self,
+        ctx: &V,
+        signed_commitment: &SignedCommitment,
+    ) -> Result<(), ClientError>
+    where
+        V: Secp256k1Recoverer,
+    {
+        let commitment = &signed_commitment.commitment;
+
+        if commitment.block_number < self.beefy_activation_block {
+            return Err(BeefyError::CommitmentBeforeActivation {
+                block_number: commitment.block_number,
+                activation_block: self.beefy_activation_block,
+            }
+            .into());
+        }
+
+        self.authority_set(commitment.validator_set_id)?
+            .verify_signatures(ctx, &commitment.hash(), &signed_commitment.signatures)?;
+
+        Ok(())
+    }
+
+    /// Verifies a header, returning the consensus state it installs.
+    ///
+    /// The commitment must not be older than the latest verified one, and
+    /// its MMR root must prove the MMR leaf of one of the blocks it covers.
+    /// The leaf must belong to the session of the signing authority set, so
+    /// that its next authority set is the one following the signing set,
+    /// and its parachain heads root must prove the head of the tracked
+    /// parachain.
+    pub fn verify_header<V>(&self, ctx: &V, header: &Header) -> Result<ConsensusState, ClientError>
+    where
+        V: Secp256k1Recoverer,
+    {
+        let commitment = &header.signed_commitment.commitment;
+
+        if commitment.block_number < self.latest_beefy_height {
+            return Err(BeefyError::StaleCommitment {
+                block_number: commitment.block_number,
+                latest: self.latest_beefy_height,
+            }
+            .into());
+        }
+
+        self.verify_signed_commitment(ctx, &header.signed_commitment)?;
+
+        let leaf_block = header.mmr_leaf.block_number();
+
+        if leaf_block < self.beefy_activation_block || leaf_block > commitment.block_number {
+            return Err(BeefyError::UncommittedMmrLeaf {
+                block_number: leaf_block,
+                commitment_block: commitment.block_number,
+            }
+            .into());
+        }
+
+        if !verify_mmr_proof(
+            &commitment.mmr_root()?,
+            header.mmr_leaf.hash(),
+            (leaf_block - self.beefy_activation_block).into(),
+            u64::from(commitment.block_number - self.beefy_activation_block) + 1,
+            &header.mmr_proof,
+        ) {
+            return Err(BeefyError::InvalidMmrProof.into());
+        }
+
+        let next_authority_set_id = header.mmr_leaf.beefy_next_authority_set.id;
+        let expected = commitment.validator_set_id.saturating_add(1);
+
+        if next_authority_set_id != expected {
+            return Err(BeefyError::InvalidNextAuthoritySet {
+                id: next_authority_set_id,
+                expected,
+            }
+            .into());
+        }
+
+        let parachain_header = &header.parachain_header;
+
+        if !verify_binary_merkle_proof(
+            &header.mmr_leaf.parachain_heads_root,
+            keccak256(parachain_header.heads_leaf(self.para_id)),
+            parachain_header.heads_leaf_index,
+            parachain_header.heads_leaf_count,
+            &parachain_header.heads_proof,
+        ) {
+            return Err(BeefyError::InvalidParachainHeadProof.into());
+        }
+
+        parachain_consensus_state(parachain_header)
+    }
+
+    /// Verifies that both commitments of a misbehaviour are of the same
+    /// block and signed by a trusted authority set.
+    pub fn verify_misbehaviour<V>(
+        &self,
+        ctx: &V,
+        misbehaviour: &Misbehaviour,
+    ) -> Result<(), ClientError>
+    where
+        V: Secp256k1Recoverer,
+    {
+        let block_1 = misbehaviour.commitment_1.commitment.block_number;
+        let block_2 = misbehaviour.commitment_2.commitment.block_number;
+
+        if block_1 != block_2 {
+            return Err(BeefyError::MisbehaviourBlockMismatch { block_1, block_2 }.into());
+        }
+
+        self.verify_signed_commitment(ctx, &misbehaviour.commitment_1)?;
+        self.verify_signed_commitment(ctx, &misbehaviour.commitment_2)
+    }
+
+    /// Checks whether the parachain header conflicts with the consensus
+    /// state already installed at its height, or breaks the monotonicity of
+    /// the consensus state timestamps.
+    pub fn check_for_misbehaviour_on_header<V>(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        header: &Header,
+    ) -> Result<bool, ClientError>
+    where
+        V: ExtClientValidationContext,
+        ConsensusState: Convertible<V::ConsensusStateRef>,
+        <ConsensusState as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+    {
+        let height = header.height()?;
+        let header_consensus_state = parachain_consensus_state(&header.parachain_header)?;
+
+        if let Ok(existing_consensus_state) = consensus_state_at(ctx, client_id, height) {
+            return Ok(existing_consensus_state != header_consensus_state);
+        }
+
+        if let Some(prev_consensus_state) = ctx.prev_consensus_state(client_id, &height)? {
+            let prev_consensus_state: ConsensusState =
+                prev_consensus_state.try_into().map_err(Into::into)?;
+
+            if header_consensus_state.timestamp <= prev_consensus_state.timestamp {
+                return Ok(true);
+            }
+        }
+
+        if height < self.latest_height {
+            if let Some(next_consensus_state) = ctx.next_consensus_state(client_id, &height)? {
+                let next_consensus_state: ConsensusState =
+                    next_consensus_state.try_into().map_err(Into::into)?;
+
+                if header_consensus_state.timestamp >= next_consensus_state.timestamp {
+                    return Ok(true);
+                }
+            }
+        }
+
+        Ok(false)
+    }
+}
+
+/// Derives the consensus state of a parachain header, reading its timestamp
+/// from the state with the timestamp proof.
+pub fn parachain_consensus_state(
+    parachain_header: &ParachainHeader,
+) -> Result<ConsensusState, ClientError> {
+    let timestamp_millis = verify_proof(
+        &parachain_header.state_root,
+        &TIMESTAMP_NOW_KEY,
+        &parachain_header.timestamp_proof,
+    )?
+    .and_then(|value| <[u8; 8]>::try_from(value).ok())
+    .map(u64::from_le_bytes)
+    .filter(|millis| *millis != 0)
+    .ok_or(BeefyError::MissingTimestamp)?;
+
+    let timestamp_nanos = timestamp_millis
+        .checked_mul(1_000_000)
+        .ok_or(BeefyError::MissingTimestamp)?;
+
+    Ok(ConsensusState {
+        root: parachain_header.state_root.to_vec().into(),
+        timestamp: Timestamp::from_nanoseconds(timestamp_nanos),
+    })
+}
+
+/// Fetches the consensus state stored for the client at the given height.
+pub fn consensus_state_at<V>(
+    ctx: &V,
+    client_id: &ClientId,
+    height: Height,
+) -> Result<ConsensusState, ClientError>
+where
+    V: ExtClientValidationContext,
+    ConsensusState: Convertible<V::ConsensusStateRef>,
+    <ConsensusState as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    ctx.consensus_state(&ClientConsensusStatePath::new(
+        client_id.clone(),
+        height.revision_number(),
+        height.revision_height(),
+    ))?
+    .try_into()
+    .map_err(Into::into)
+}
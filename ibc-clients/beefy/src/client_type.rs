@@ -0,0 +1,13 @@
+use ibc_client_cw::api::ClientType;
+
+use crate::client_state::ClientState;
+use crate::consensus_state::ConsensusState;
+
+/// A unit struct that represents the BEEFY client type.
+#[derive(Clone, Debug)]
+pub struct BeefyClient;
+
+impl ClientType<'_> for BeefyClient {
+    type ClientState = ClientState;
+    type ConsensusState = ConsensusState;
+}
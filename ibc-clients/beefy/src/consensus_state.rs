@@ -0,0 +1,82 @@
+//! Implements the BEEFY `ConsensusState`. Each consensus state is keyed by a
+//! parachain block number and records the state root and the timestamp of
+//! the block.
+use ibc_core::client::context::consensus_state::ConsensusState as ConsensusStateTrait;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::commitment_types::commitment::CommitmentRoot;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::proto::{Any, Protobuf};
+use ibc_core::primitives::Timestamp;
+
+use crate::types::proto::ConsensusState as RawConsensusState;
+use crate::types::{fixed_bytes, Bytes32};
+
+pub const BEEFY_CONSENSUS_STATE_TYPE_URL: &str = "/ibc.lightclients.beefy.v1.ConsensusState";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusState {
+    /// The state root of the parachain block, used as the commitment root.
+    pub root: CommitmentRoot,
+    /// The time of the parachain block, as set by its timestamp inherent.
+    pub timestamp: Timestamp,
+}
+
+impl ConsensusStateTrait for ConsensusState {
+    fn root(&self) -> &CommitmentRoot {
+        &self.root
+    }
+
+    fn timestamp(&self) -> Result<Timestamp, ClientError> {
+        Ok(self.timestamp)
+    }
+}
+
+impl Protobuf<RawConsensusState> for ConsensusState {}
+
+impl TryFrom<RawConsensusState> for ConsensusState {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawConsensusState) -> Result<Self, Self::Error> {
+        let root: Bytes32 = fixed_bytes("consensus state root", raw.root)?;
+
+        Ok(Self {
+            root: root.to_vec().into(),
+            timestamp: Timestamp::from_nanoseconds(raw.timestamp),
+        })
+    }
+}
+
+impl From<ConsensusState> for RawConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        Self {
+            root: value.root.into_vec(),
+            timestamp: value.timestamp.nanoseconds(),
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let BEEFY_CONSENSUS_STATE_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawConsensusState>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: BEEFY_CONSENSUS_STATE_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Self {
+            type_url: BEEFY_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawConsensusState>::encode_vec(consensus_state),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! Defines the error type of the BEEFY light client.
+use derive_more::{Display, From};
+use ibc_core::client::types::error::ClientError;
+use ibc_core::host::types::error::DecodingError;
+
+#[derive(From, Display, Debug)]
+pub enum BeefyError {
+    #[from]
+    #[display("decoding error: {_0}")]
+    Decoding(DecodingError),
+    #[display(
+        "commitment of block `{block_number}` is older than the latest BEEFY block `{latest}`"
+    )]
+    StaleCommitment { block_number: u32, latest: u32 },
+    #[display("commitment of block `{block_number}` precedes the BEEFY activation block `{activation_block}`")]
+    CommitmentBeforeActivation {
+        block_number: u32,
+        activation_block: u32,
+    },
+    #[display(
+        "unknown authority set `{id}`, expected the current set `{current}` or the next one"
+    )]
+    UnknownAuthoritySet { id: u64, current: u64 },
+    #[display("unknown authority index `{index}` in a set of {len} authorities")]
+    UnknownAuthority { index: u32, len: u32 },
+    #[display("duplicate signature of authority `{index}`")]
+    DuplicateSignature { index: u32 },
+    #[display("invalid signature of authority `{index}`")]
+    InvalidSignature { index: u32 },
+    #[display("authority `{index}` is not proven to be part of the authority set")]
+    InvalidAuthorityProof { index: u32 },
+    #[display("insufficient signatures: `{signed}` authorities signed, `{threshold}` required")]
+    InsufficientSignatures { signed: usize, threshold: u32 },
+    #[display("commitment payload has no MMR root")]
+    MissingMmrRoot,
+    #[display("MMR leaf of block `{block_number}` is not covered by the commitment of block `{commitment_block}`")]
+    UncommittedMmrLeaf {
+        block_number: u32,
+        commitment_block: u32,
+    },
+    #[display("MMR leaf is not proven against the committed MMR root")]
+    InvalidMmrProof,
+    #[display("invalid next authority set `{id}`, expected `{expected}`")]
+    InvalidNextAuthoritySet { id: u64, expected: u64 },
+    #[display("parachain head is not proven against the parachain heads root")]
+    InvalidParachainHeadProof,
+    #[display("invalid Substrate trie proof: {description}")]
+    InvalidTrieProof { description: String },
+    #[display("parachain timestamp is not proven by the timestamp proof")]
+    MissingTimestamp,
+    #[display("storage proof value does not match the expected commitment")]
+    MismatchedStorageValue,
+    #[display("misbehaviour commitments of different blocks `{block_1}` and `{block_2}`")]
+    MisbehaviourBlockMismatch { block_1: u32, block_2: u32 },
+}
+
+impl From<BeefyError> for ClientError {
+    fn from(e: BeefyError) -> Self {
+        Self::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
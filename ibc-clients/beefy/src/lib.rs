@@ -0,0 +1,25 @@
+//! The CosmWasm contract implementation of a BEEFY light client for
+//! Substrate parachains. The client follows the relay chain through the
+//! BEEFY commitments signed by more than two thirds of its current authority
+//! set, whose members are proven against the keyset commitment of the set.
+//! The MMR root of a commitment proves the MMR leaf of a relay chain block,
+//! which in turn commits to the next authority set, used to hand over the
+//! client to the following session, and to the heads of the parachains. The
+//! head of the tracked parachain installs a consensus state made of its state
+//! root and of its timestamp, read from the state with a Substrate trie
+//! proof, and IBC commitments are verified against the state root with trie
+//! proofs as well.
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+
+pub mod client_state;
+pub mod client_type;
+pub mod consensus_state;
+pub mod entrypoint;
+pub mod error;
+pub mod merkle;
+pub mod secp256k1;
+pub mod trie;
+pub mod types;
+
+#[cfg(test)]
+mod tests;
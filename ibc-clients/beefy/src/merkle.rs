@@ -0,0 +1,149 @@
+//! Keccak-256 Merkle trees committed to by BEEFY: the binary Merkle trees of
+//! the authority sets and of the parachain heads, and the Merkle Mountain
+//! Range (MMR) of the relay chain blocks.
+use sha3::{Digest, Keccak256};
+
+use crate::types::Bytes32;
+
+/// Returns the Keccak-256 digest of the given bytes.
+pub fn keccak256(data: impl AsRef<[u8]>) -> Bytes32 {
+    Keccak256::digest(data.as_ref()).into()
+}
+
+/// Returns the Keccak-256 digest of the concatenation of two nodes.
+fn merge(left: &Bytes32, right: &Bytes32) -> Bytes32 {
+    let mut hasher = Keccak256::new();
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Verifies the proof of the leaf at `leaf_index` in a binary Merkle tree of
+/// `leaf_count` leaves, as built by Substrate's `binary-merkle-tree`: leaves
+/// are the Keccak-256 digests of the leaf data, and the last node of a level
+/// with an odd number of nodes is promoted to the next level, consuming no
+/// proof item. The proof holds the siblings of the leaf from the bottom.
+pub fn verify_binary_merkle_proof(
+    root: &Bytes32,
+    leaf_hash: Bytes32,
+    leaf_index: u32,
+    leaf_count: u32,
+    proof: &[Bytes32],
+) -> bool {
+    if leaf_index >= leaf_count {
+        return false;
+    }
+
+    let mut items = proof.iter();
+    let mut node = leaf_hash;
+    let mut position = leaf_index;
+    let mut width = leaf_count;
+
+    while width > 1 {
+        let is_promoted = position + 1 == width && width % 2 == 1;
+
+        if !is_promoted {
+            let Some(sibling) = items.next() else {
+                return false;
+            };
+
+            node = if position % 2 == 0 {
+                merge(&node, sibling)
+            } else {
+                merge(sibling, &node)
+            };
+        }
+
+        position /= 2;
+        width = width.div_ceil(2);
+    }
+
+    items.next().is_none() && &node == root
+}
+
+/// Verifies the proof of the leaf at `leaf_index` in a Merkle Mountain Range
+/// of `leaf_count` leaves, against its root.
+///
+/// The leaves of the range are split into perfect binary trees of
+/// decreasing sizes, the peaks, following the binary decomposition of
+/// `leaf_count`. As in the proofs generated by Substrate's MMR pallet, the
+/// proof holds the peaks on the left of the peak of the leaf, the siblings of
+/// the leaf within its peak from the bottom, and the bagged peaks on its
+/// right, if any. The root is obtained by bagging all the peaks from the
+/// right, each step hashing the bag so far with the peak on its left.
+pub fn verify_mmr_proof(
+    root: &Bytes32,
+    leaf_hash: Bytes32,
+    leaf_index: u64,
+    leaf_count: u64,
+    proof: &[Bytes32],
+) -> bool {
+    if leaf_index >= leaf_count {
+        return false;
+    }
+
+    let mut items = proof.iter();
+    let mut peaks = Vec::new();
+    let mut peak_start = 0;
+
+    // the peaks on the left of the leaf's peak
+    let peak_height = loop {
+        let peak_height = (leaf_count - peak_start).ilog2();
+        let peak_size = 1 << peak_height;
+
+        if leaf_index < peak_start + peak_size {
+            break peak_height;
+        }
+
+        let Some(peak) = items.next() else {
+            return false;
+        };
+
+        peaks.push(*peak);
+        peak_start += peak_size;
+    };
+
+    // the peak of the leaf
+    let mut node = leaf_hash;
+    let mut position = leaf_index - peak_start;
+
+    for _ in 0..peak_height {
+        let Some(sibling) = items.next() else {
+            return false;
+        };
+
+        node = if position % 2 == 0 {
+            merge(&node, sibling)
+        } else {
+            merge(sibling, &node)
+        };
+
+        position /= 2;
+    }
+
+    peaks.push(node);
+
+    // the bagged peaks on the right of the leaf's peak
+    if peak_start + (1 << peak_height) < leaf_count {
+        let Some(rhs_peaks) = items.next() else {
+            return false;
+        };
+
+        peaks.push(*rhs_peaks);
+    }
+
+    if items.next().is_some() {
+        return false;
+    }
+
+    &bag_peaks(&peaks) == root
+}
+
+/// Bags the given peaks, from left to right, from the rightmost one.
+pub fn bag_peaks(peaks: &[Bytes32]) -> Bytes32 {
+    let Some((last, rest)) = peaks.split_last() else {
+        return Bytes32::default();
+    };
+
+    rest.iter().rev().fold(*last, |bag, peak| merge(&bag, peak))
+}
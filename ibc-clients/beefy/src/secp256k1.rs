@@ -0,0 +1,38 @@
+//! secp256k1 public key recovery from the signatures of BEEFY commitments,
+//! delegated to the primitives exposed by the CosmWasm host.
+use ibc_client_cw::context::Context;
+
+use crate::client_type::BeefyClient;
+use crate::types::Bytes65;
+
+/// Provides secp256k1 public key recovery to the light client.
+pub trait Secp256k1Recoverer {
+    /// Recovers the uncompressed public key which produced the recoverable
+    /// ECDSA `signature`, serialized as `r || s || v`, of `message_hash`.
+    /// Returns `None` for malformed signatures.
+    fn secp256k1_recover_pubkey(
+        &self,
+        message_hash: &[u8; 32],
+        signature: &Bytes65,
+    ) -> Option<Vec<u8>>;
+}
+
+impl Secp256k1Recoverer for Context<'_, BeefyClient> {
+    fn secp256k1_recover_pubkey(
+        &self,
+        message_hash: &[u8; 32],
+        signature: &Bytes65,
+    ) -> Option<Vec<u8>> {
+        // Substrate signatures carry a recovery ID of 0 or 1, while
+        // Ethereum-style ones are offset by 27.
+        let recovery_id = match signature[64] {
+            id @ 0..=1 => id,
+            id @ 27..=28 => id - 27,
+            _ => return None,
+        };
+
+        self.api()
+            .secp256k1_recover_pubkey(message_hash, &signature[..64], recovery_id)
+            .ok()
+    }
+}
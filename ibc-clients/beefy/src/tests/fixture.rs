@@ -0,0 +1,442 @@
+use std::time::Duration;
+
+use cosmwasm_std::Binary;
+use ibc_client_cw::types::{MerklePath, VerifyMembershipMsgRaw, VerifyNonMembershipMsgRaw};
+use ibc_client_cw::utils::AnyCodec;
+use ibc_client_cw_testkit::api::TestClient;
+use ibc_client_cw_testkit::clock::Clock;
+use ibc_core::client::types::Height;
+use ibc_core::primitives::Timestamp;
+use k256::ecdsa::SigningKey;
+use parity_scale_codec::Encode;
+
+use crate::client_state::{ClientState, BEEFY_REVISION_NUMBER};
+use crate::client_type::BeefyClient;
+use crate::consensus_state::ConsensusState;
+use crate::merkle::keccak256;
+use crate::tests::helper::{
+    address, binary_merkle_tree, mmr, sign_recoverable, substrate_header, TestTrie,
+};
+use crate::trie::{blake2_256, TIMESTAMP_NOW_KEY};
+use crate::types::proto::StorageProof as RawStorageProof;
+use crate::types::{
+    AuthoritySet, Bytes32, Commitment, CommitmentSignature, Header, Misbehaviour, MmrLeaf,
+    ParachainHeader, PayloadItem, SignedCommitment, MMR_ROOT_ID,
+};
+
+/// The ID of the tracked parachain.
+pub const PARA_ID: u32 = 2000;
+
+/// The storage prefix of the IBC commitments in the parachain state.
+pub const IBC_PREFIX: &[u8] = b"ibc/";
+
+/// The parachain blocks are included every other relay chain block.
+const RELAY_BLOCKS_PER_PARA_BLOCK: u32 = 2;
+
+/// The block time of the parachain, in milliseconds.
+const PARA_BLOCK_TIME_MS: u64 = 12_000;
+
+/// Test fixture of the BEEFY client.
+pub type Fixture = ibc_client_cw_testkit::fixture::Fixture<BeefyClient>;
+
+/// A relay chain finalized by BEEFY authority sets of secp256k1 keys,
+/// rotated every session, and the tracked parachain, whose state is a
+/// Substrate trie.
+#[derive(Clone, Debug)]
+pub struct BeefyConfig {
+    /// The time of the parachain block at height zero, in milliseconds.
+    pub genesis_time_ms: u64,
+    pub trusting_period: Duration,
+    pub beefy_activation_block: u32,
+    /// The number of relay chain blocks of a session.
+    pub session_length: u32,
+    /// The number of authorities of every set.
+    pub authority_count: u8,
+    /// The (path, value) commitments stored under the IBC prefix.
+    pub commitments: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+impl Default for BeefyConfig {
+    fn default() -> Self {
+        let now_ms = Clock::default().now().nanoseconds() / 1_000_000;
+
+        Self {
+            genesis_time_ms: now_ms - 3_600_000,
+            trusting_period: Duration::from_secs(64000),
+            beefy_activation_block: 3,
+            session_length: 1000,
+            authority_count: 4,
+            commitments: vec![
+                (
+                    b"commitments/ports/transfer/channels/channel-0/sequences/1".to_vec(),
+                    keccak256(b"packet-1").to_vec(),
+                ),
+                (
+                    b"commitments/ports/transfer/channels/channel-0/sequences/2".to_vec(),
+                    keccak256(b"packet-2").to_vec(),
+                ),
+                (
+                    // stored in a separate value node
+                    b"connections/connection-0".to_vec(),
+                    b"connection-end-of-a-counterparty-chain-encoded-as-protobuf".to_vec(),
+                ),
+            ],
+        }
+    }
+}
+
+impl BeefyConfig {
+    // ------------------- Parachain -------------------
+
+    pub fn timestamp_ms(&self, para_height: u32) -> u64 {
+        self.genesis_time_ms + u64::from(para_height) * PARA_BLOCK_TIME_MS
+    }
+
+    /// Returns the parachain state at the given height, holding the IBC
+    /// commitments next to the storage of other pallets.
+    pub fn para_state(&self, para_height: u32) -> TestTrie {
+        let mut state = TestTrie::default();
+
+        state.insert(&TIMESTAMP_NOW_KEY, &self.timestamp_ms(para_height).encode());
+        state.insert(b":code", b"parachain-runtime-wasm-blob");
+        state.insert(b":heappages", &64_u64.encode());
+
+        for (path, value) in &self.commitments {
+            state.insert(&[IBC_PREFIX, path].concat(), value);
+        }
+
+        state
+    }
+
+    pub fn state_root(&self, para_height: u32) -> Bytes32 {
+        self.para_state(para_height).root()
+    }
+
+    /// Returns the SCALE-encoded parachain header at the given height.
+    pub fn para_header(&self, para_height: u32) -> Vec<u8> {
+        substrate_header(
+            blake2_256(para_height.saturating_sub(1).encode()),
+            para_height,
+            self.state_root(para_height),
+        )
+    }
+
+    // ------------------- Relay chain -------------------
+
+    /// Returns the ID of the authority set of the session of the block.
+    pub fn authority_set_id(&self, relay_block: u32) -> u64 {
+        (relay_block / self.session_length).into()
+    }
+
+    /// Derives deterministic authority keys for the given set.
+    pub fn authority_keys(&self, set_id: u64) -> Vec<SigningKey> {
+        (0..self.authority_count)
+            .map(|index| {
+                SigningKey::from_bytes(&keccak256((set_id, index).encode()).into())
+                    .expect("never fails")
+            })
+            .collect()
+    }
+
+    fn authority_leaves(&self, set_id: u64) -> Vec<Bytes32> {
+        self.authority_keys(set_id)
+            .iter()
+            .map(|key| keccak256(address(key)))
+            .collect()
+    }
+
+    pub fn authority_set(&self, set_id: u64) -> AuthoritySet {
+        let leaves = self.authority_leaves(set_id);
+
+        AuthoritySet {
+            id: set_id,
+            len: leaves.len() as u32,
+            keyset_commitment: binary_merkle_tree(&leaves, 0).0,
+        }
+    }
+
+    /// Returns the parachain heads at the given relay chain block, sorted by
+    /// parachain ID, along with the index of the tracked parachain.
+    pub fn parachain_heads(&self, relay_block: u32) -> (Vec<(u32, Vec<u8>)>, usize) {
+        let heads = vec![
+            (
+                1000,
+                [b"asset-hub-head".as_slice(), &relay_block.encode()].concat(),
+            ),
+            (
+                1002,
+                [b"bridge-hub-head".as_slice(), &relay_block.encode()].concat(),
+            ),
+            (
+                PARA_ID,
+                self.para_header(relay_block / RELAY_BLOCKS_PER_PARA_BLOCK),
+            ),
+            (
+                3000,
+                [b"other-parachain-head".as_slice(), &relay_block.encode()].concat(),
+            ),
+            (3001, b"stalled-parachain-head".to_vec()),
+        ];
+
+        (heads, 2)
+    }
+
+    fn parachain_heads_leaves(&self, relay_block: u32) -> Vec<Bytes32> {
+        self.parachain_heads(relay_block)
+            .0
+            .iter()
+            .map(|head| keccak256(head.encode()))
+            .collect()
+    }
+
+    pub fn mmr_leaf(&self, relay_block: u32) -> MmrLeaf {
+        let parent_number = relay_block - 1;
+
+        MmrLeaf {
+            version: 0,
+            parent_number,
+            parent_hash: keccak256(parent_number.encode()),
+            beefy_next_authority_set: self.authority_set(self.authority_set_id(relay_block) + 1),
+            parachain_heads_root: binary_merkle_tree(&self.parachain_heads_leaves(relay_block), 0)
+                .0,
+        }
+    }
+
+    /// Returns the MMR root committed by the given block, along with the
+    /// proof of the leaf of `leaf_block`.
+    pub fn mmr(&self, commitment_block: u32, leaf_block: u32) -> (Bytes32, Vec<Bytes32>) {
+        let leaves: Vec<_> = (self.beefy_activation_block..=commitment_block)
+            .map(|block| self.mmr_leaf(block).hash())
+            .collect();
+
+        mmr(&leaves, (leaf_block - self.beefy_activation_block) as usize)
+    }
+
+    pub fn commitment(&self, relay_block: u32) -> Commitment {
+        Commitment {
+            payload: vec![PayloadItem {
+                id: MMR_ROOT_ID,
+                data: self.mmr(relay_block, relay_block).0.to_vec(),
+            }],
+            block_number: relay_block,
+            validator_set_id: self.authority_set_id(relay_block),
+        }
+    }
+
+    /// Signs the commitment with the keys of its authority set at the given
+    /// indices.
+    pub fn sign(&self, commitment: Commitment, signers: &[u32]) -> SignedCommitment {
+        let set_id = commitment.validator_set_id;
+        let keys = self.authority_keys(set_id);
+        let leaves = self.authority_leaves(set_id);
+        let hash = commitment.hash();
+
+        let signatures = signers
+            .iter()
+            .map(|&index| CommitmentSignature {
+                authority_index: index,
+                signature: sign_recoverable(&keys[index as usize], &hash),
+                authority_proof: binary_merkle_tree(&leaves, index as usize).1,
+            })
+            .collect();
+
+        SignedCommitment {
+            commitment,
+            signatures,
+        }
+    }
+
+    /// Returns the indices of the first authorities reaching the threshold.
+    pub fn quorum(&self) -> Vec<u32> {
+        let len = u32::from(self.authority_count);
+
+        (0..len - (len - 1) / 3).collect()
+    }
+
+    pub fn parachain_header(&self, para_height: u32) -> ParachainHeader {
+        let relay_block = BeefyClient::relay_block(para_height);
+        let (heads, index) = self.parachain_heads(relay_block);
+        let head_data = heads[index].1.clone();
+
+        ParachainHeader {
+            head_data,
+            number: para_height,
+            state_root: self.state_root(para_height),
+            heads_leaf_index: index as u32,
+            heads_leaf_count: heads.len() as u32,
+            heads_proof: binary_merkle_tree(&self.parachain_heads_leaves(relay_block), index).1,
+            timestamp_proof: self.para_state(para_height).proof(&TIMESTAMP_NOW_KEY),
+        }
+    }
+
+    /// Builds the header of the parachain block at `para_height`, proven
+    /// against the commitment of the relay chain block `commitment_block`,
+    /// signed by a quorum of its authority set.
+    pub fn header_at(&self, para_height: u32, commitment_block: u32) -> Header {
+        let leaf_block = BeefyClient::relay_block(para_height);
+
+        Header {
+            signed_commitment: self.sign(self.commitment(commitment_block), &self.quorum()),
+            mmr_leaf: self.mmr_leaf(leaf_block),
+            mmr_proof: self.mmr(commitment_block, leaf_block).1,
+            parachain_header: self.parachain_header(para_height),
+        }
+    }
+
+    /// Builds the header of the parachain block at `para_height`, proven
+    /// against the commitment of the relay chain block including it.
+    pub fn dummy_header(&self, para_height: u32) -> Header {
+        self.header_at(para_height, BeefyClient::relay_block(para_height))
+    }
+
+    /// Builds a misbehaviour made of two commitments of the given relay
+    /// chain block, committing to different MMR roots, both signed by a
+    /// quorum of its authority set.
+    pub fn dummy_misbehaviour(&self, relay_block: u32) -> Misbehaviour {
+        let commitment = self.commitment(relay_block);
+
+        let forked_commitment = Commitment {
+            payload: vec![PayloadItem {
+                id: MMR_ROOT_ID,
+                data: vec![0xff; 32],
+            }],
+            ..commitment.clone()
+        };
+
+        Misbehaviour {
+            commitment_1: self.sign(commitment, &self.quorum()),
+            commitment_2: self.sign(forked_commitment, &self.quorum()),
+        }
+    }
+
+    fn storage_proof(&self, height: Height, path: &[u8]) -> Binary {
+        let para_height = height.revision_height().try_into().expect("never fails");
+
+        let storage_proof = RawStorageProof {
+            proof: self
+                .para_state(para_height)
+                .proof(&[IBC_PREFIX, path].concat()),
+        };
+
+        prost::Message::encode_to_vec(&storage_proof).into()
+    }
+
+    pub fn dummy_verify_membership_msg(
+        &self,
+        height: Height,
+        path: &[u8],
+        value: &[u8],
+    ) -> VerifyMembershipMsgRaw {
+        VerifyMembershipMsgRaw {
+            proof: self.storage_proof(height, path),
+            merkle_path: MerklePath {
+                key_path: vec![IBC_PREFIX.to_vec().into(), path.to_vec().into()],
+            },
+            value: value.to_vec().into(),
+            height: height.into(),
+            delay_block_period: 0,
+            delay_time_period: 0,
+        }
+    }
+
+    pub fn dummy_verify_non_membership_msg(
+        &self,
+        height: Height,
+        path: &[u8],
+    ) -> VerifyNonMembershipMsgRaw {
+        VerifyNonMembershipMsgRaw {
+            proof: self.storage_proof(height, path),
+            merkle_path: MerklePath {
+                key_path: vec![IBC_PREFIX.to_vec().into(), path.to_vec().into()],
+            },
+            height: height.into(),
+            delay_block_period: 0,
+            delay_time_period: 0,
+        }
+    }
+}
+
+impl BeefyClient {
+    pub fn height(height: u64) -> Height {
+        Height::new(BEEFY_REVISION_NUMBER, height).expect("never fails")
+    }
+
+    /// Returns the relay chain block including the parachain block at the
+    /// given height.
+    pub fn relay_block(para_height: u32) -> u32 {
+        para_height * RELAY_BLOCKS_PER_PARA_BLOCK
+    }
+
+    pub fn dummy_client_state(fxt: &Fixture) -> ClientState {
+        let relay_block = Self::relay_block(Self::trusted_para_height(fxt));
+        let set_id = fxt.config.authority_set_id(relay_block);
+
+        ClientState {
+            para_id: PARA_ID,
+            beefy_activation_block: fxt.config.beefy_activation_block,
+            latest_beefy_height: relay_block,
+            current_authorities: fxt.config.authority_set(set_id),
+            next_authorities: fxt.config.authority_set(set_id + 1),
+            latest_height: fxt.trusted_height,
+            frozen_height: None,
+            trusting_period: fxt.config.trusting_period,
+        }
+    }
+
+    pub fn trusted_para_height(fxt: &Fixture) -> u32 {
+        fxt.trusted_height
+            .revision_height()
+            .try_into()
+            .expect("never fails")
+    }
+
+    pub fn dummy_consensus_state(fxt: &Fixture) -> ConsensusState {
+        let para_height = Self::trusted_para_height(fxt);
+
+        ConsensusState {
+            root: fxt.config.state_root(para_height).to_vec().into(),
+            timestamp: Timestamp::from_nanoseconds(
+                fxt.config.timestamp_ms(para_height) * 1_000_000,
+            ),
+        }
+    }
+}
+
+impl TestClient for BeefyClient {
+    type Config = BeefyConfig;
+
+    fn trusting_period(config: &BeefyConfig) -> Duration {
+        config.trusting_period
+    }
+
+    fn trusted_height(_config: &BeefyConfig) -> Height {
+        Self::height(10)
+    }
+
+    fn client_state(fxt: &Fixture) -> Vec<u8> {
+        ClientState::encode_to_any_vec(Self::dummy_client_state(fxt))
+    }
+
+    fn consensus_state(fxt: &Fixture) -> Vec<u8> {
+        ConsensusState::encode_to_any_vec(Self::dummy_consensus_state(fxt))
+    }
+
+    /// Constructs the header of the parachain block at the target height,
+    /// proven against the commitment of the relay chain block including it.
+    fn header(fxt: &Fixture, target_height: Height) -> Vec<u8> {
+        let para_height = target_height
+            .revision_height()
+            .try_into()
+            .expect("never fails");
+
+        Header::encode_to_any_vec(fxt.config.dummy_header(para_height))
+    }
+
+    /// Constructs two conflicting commitments of the relay chain block
+    /// including the parachain block right after the trusted height.
+    fn misbehaviour(fxt: &Fixture) -> Vec<u8> {
+        let relay_block = Self::relay_block(Self::trusted_para_height(fxt) + 1);
+
+        Misbehaviour::encode_to_any_vec(fxt.config.dummy_misbehaviour(relay_block))
+    }
+}
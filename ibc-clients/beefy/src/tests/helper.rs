@@ -0,0 +1,321 @@
+use std::collections::BTreeMap;
+
+use k256::ecdsa::SigningKey;
+use parity_scale_codec::{Compact, Encode};
+
+use crate::merkle::{bag_peaks, keccak256};
+use crate::trie::blake2_256;
+use crate::types::{authority_address, Bytes20, Bytes32, Bytes65};
+
+fn merge(left: &Bytes32, right: &Bytes32) -> Bytes32 {
+    keccak256([left.as_slice(), right.as_slice()].concat())
+}
+
+// ------------------- Signatures -------------------
+
+/// Returns the Ethereum-style address of the authority key.
+pub fn address(key: &SigningKey) -> Bytes20 {
+    authority_address(key.verifying_key().to_encoded_point(false).as_bytes())
+        .expect("uncompressed public key")
+}
+
+/// Signs the message hash with a recoverable signature, serialized as
+/// `r || s || v`.
+pub fn sign_recoverable(key: &SigningKey, message_hash: &Bytes32) -> Bytes65 {
+    let (signature, recovery_id) = key
+        .sign_prehash_recoverable(message_hash)
+        .expect("never fails");
+
+    let mut bytes = [0; 65];
+    bytes[..64].copy_from_slice(&signature.to_bytes());
+    bytes[64] = recovery_id.to_byte();
+    bytes
+}
+
+// ------------------- Binary Merkle Tree -------------------
+
+/// Returns the root of the binary Merkle tree of the given leaf hashes, as
+/// built by Substrate's `binary-merkle-tree`, along with the proof of the
+/// leaf at `index`.
+pub fn binary_merkle_tree(leaves: &[Bytes32], mut index: usize) -> (Bytes32, Vec<Bytes32>) {
+    let mut level = leaves.to_vec();
+    let mut proof = vec![];
+
+    while level.len() > 1 {
+        let sibling = index ^ 1;
+
+        if sibling < level.len() {
+            proof.push(level[sibling]);
+        }
+
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => merge(left, right),
+                [promoted] => *promoted,
+                _ => unreachable!(),
+            })
+            .collect();
+
+        index /= 2;
+    }
+
+    (level[0], proof)
+}
+
+// ------------------- Merkle Mountain Range -------------------
+
+/// Returns the root of the MMR of the given leaf hashes, along with the
+/// proof of the leaf at `index`.
+pub fn mmr(leaves: &[Bytes32], index: usize) -> (Bytes32, Vec<Bytes32>) {
+    let mut peaks = vec![];
+    let mut left_peaks = vec![];
+    let mut siblings = vec![];
+    let mut right_peaks = vec![];
+    let mut start = 0;
+
+    while start < leaves.len() {
+        let size = 1 << (leaves.len() - start).ilog2();
+        let peak_leaves = &leaves[start..start + size];
+
+        if (start..start + size).contains(&index) {
+            // a perfect tree, so that no node is promoted
+            let (peak, proof) = binary_merkle_tree(peak_leaves, index - start);
+            siblings = proof;
+            peaks.push(peak);
+        } else {
+            let peak = binary_merkle_tree(peak_leaves, 0).0;
+
+            if start < index {
+                left_peaks.push(peak);
+            } else {
+                right_peaks.push(peak);
+            }
+
+            peaks.push(peak);
+        }
+
+        start += size;
+    }
+
+    let mut proof = [left_peaks, siblings].concat();
+
+    if !right_peaks.is_empty() {
+        proof.push(bag_peaks(&right_peaks));
+    }
+
+    (bag_peaks(&peaks), proof)
+}
+
+// ------------------- Substrate Trie -------------------
+
+/// Values of at least this size are stored in separate value nodes, in state
+/// version 1.
+const VALUE_NODE_THRESHOLD: usize = 33;
+
+fn to_nibbles(bytes: &[u8]) -> Vec<u8> {
+    bytes.iter().flat_map(|b| [b >> 4, b & 0x0f]).collect()
+}
+
+/// Encodes a node header: the type bits `prefix`, spanning `prefix_bits`
+/// bits, followed by the number of nibbles of the partial key.
+fn node_header(prefix: u8, prefix_bits: u32, nibble_count: usize) -> Vec<u8> {
+    let max_value = usize::from(0xff_u8 >> prefix_bits);
+
+    if nibble_count < max_value {
+        return vec![prefix | nibble_count as u8];
+    }
+
+    let mut header = vec![prefix | max_value as u8];
+    let mut remainder = nibble_count - max_value;
+
+    while remainder >= 0xff {
+        header.push(0xff);
+        remainder -= 0xff;
+    }
+
+    header.push(remainder as u8);
+    header
+}
+
+/// Encodes a partial key, left-padding odd numbers of nibbles.
+fn partial_key(nibbles: &[u8]) -> Vec<u8> {
+    let mut encoded = vec![];
+
+    if nibbles.len() % 2 == 1 {
+        encoded.push(nibbles[0]);
+    }
+
+    for pair in nibbles[nibbles.len() % 2..].chunks(2) {
+        encoded.push(pair[0] << 4 | pair[1]);
+    }
+
+    encoded
+}
+
+/// An in-memory Substrate trie in state version 1, whose proofs are the sets
+/// of nodes visited by lookups.
+#[derive(Clone, Debug, Default)]
+pub struct TestTrie {
+    entries: BTreeMap<Vec<u8>, Vec<u8>>,
+}
+
+impl TestTrie {
+    pub fn insert(&mut self, key: &[u8], value: &[u8]) {
+        self.entries.insert(key.to_vec(), value.to_vec());
+    }
+
+    /// Encodes the value of a node, recording its value node in the proof
+    /// if it is hashed and looked up.
+    fn encode_value(value: &[u8], is_looked_up: bool, proof: &mut Vec<Vec<u8>>) -> Vec<u8> {
+        if value.len() < VALUE_NODE_THRESHOLD {
+            return value.encode();
+        }
+
+        if is_looked_up {
+            proof.push(value.to_vec());
+        }
+
+        blake2_256(value).to_vec()
+    }
+
+    /// Encodes the node of the given entries, sorted by key, whose keys share
+    /// their first `depth` nibbles. Visited nodes are recorded in the proof
+    /// when the node is on the lookup path of `key`.
+    fn encode_node(
+        entries: &[(Vec<u8>, &Vec<u8>)],
+        depth: usize,
+        key: Option<&[u8]>,
+        proof: &mut Vec<Vec<u8>>,
+    ) -> Vec<u8> {
+        if let [(nibbles, value)] = entries {
+            let is_looked_up = key == Some(nibbles.as_slice());
+            let hashed = value.len() >= VALUE_NODE_THRESHOLD;
+
+            let (prefix, prefix_bits) = if hashed {
+                (0b001 << 5, 3)
+            } else {
+                (0b01 << 6, 2)
+            };
+
+            return [
+                node_header(prefix, prefix_bits, nibbles.len() - depth),
+                partial_key(&nibbles[depth..]),
+                Self::encode_value(value, is_looked_up, proof),
+            ]
+            .concat();
+        }
+
+        let first = &entries[0].0;
+        let last = &entries[entries.len() - 1].0;
+        let common = first[depth..]
+            .iter()
+            .zip(&last[depth..])
+            .take_while(|(a, b)| a == b)
+            .count();
+        let branch_depth = depth + common;
+
+        let key = key.filter(|key| key.get(..branch_depth) == Some(&first[..branch_depth]));
+
+        let (value, children) = match entries.split_first() {
+            Some(((nibbles, value), rest)) if nibbles.len() == branch_depth => (Some(*value), rest),
+            _ => (None, entries),
+        };
+
+        let (prefix, prefix_bits) = match value {
+            None => (0b10 << 6, 2),
+            Some(value) if value.len() >= VALUE_NODE_THRESHOLD => (0b0001 << 4, 4),
+            Some(_) => (0b11 << 6, 2),
+        };
+
+        let mut encoded = [
+            node_header(prefix, prefix_bits, common),
+            partial_key(&first[depth..branch_depth]),
+        ]
+        .concat();
+
+        let mut bitmap = 0_u16;
+        let mut encoded_children = vec![];
+
+        for nibble in 0..16 {
+            let child_entries: Vec<_> = children
+                .iter()
+                .filter(|(nibbles, _)| nibbles[branch_depth] == nibble)
+                .cloned()
+                .collect();
+
+            if child_entries.is_empty() {
+                continue;
+            }
+
+            bitmap |= 1 << nibble;
+
+            let child_key =
+                key.filter(|key| key.len() > branch_depth && key[branch_depth] == nibble);
+            let child = Self::encode_node(&child_entries, branch_depth + 1, child_key, proof);
+
+            if child.len() >= 32 {
+                if child_key.is_some() {
+                    proof.push(child.clone());
+                }
+
+                encoded_children.extend(blake2_256(&child).as_slice().encode());
+            } else {
+                encoded_children.extend(child.encode());
+            }
+        }
+
+        encoded.extend(bitmap.to_le_bytes());
+
+        if let Some(value) = value {
+            let is_looked_up = key.map(<[u8]>::len) == Some(branch_depth);
+            encoded.extend(Self::encode_value(value, is_looked_up, proof));
+        }
+
+        encoded.extend(encoded_children);
+        encoded
+    }
+
+    /// Returns the encoded root node, along with the proof of `key`.
+    fn encode_root(&self, key: &[u8]) -> (Vec<u8>, Vec<Vec<u8>>) {
+        let entries: Vec<_> = self
+            .entries
+            .iter()
+            .map(|(key, value)| (to_nibbles(key), value))
+            .collect();
+
+        let mut proof = vec![];
+
+        let root = if entries.is_empty() {
+            vec![0]
+        } else {
+            Self::encode_node(&entries, 0, Some(&to_nibbles(key)), &mut proof)
+        };
+
+        proof.push(root.clone());
+
+        (root, proof)
+    }
+
+    pub fn root(&self) -> Bytes32 {
+        blake2_256(self.encode_root(&[]).0)
+    }
+
+    /// Returns the storage proof of `key`, proving either its value or its
+    /// absence.
+    pub fn proof(&self, key: &[u8]) -> Vec<Vec<u8>> {
+        self.encode_root(key).1
+    }
+}
+
+/// Encodes a Substrate header with an empty digest.
+pub fn substrate_header(parent_hash: Bytes32, number: u32, state_root: Bytes32) -> Vec<u8> {
+    (
+        parent_hash,
+        Compact(number),
+        state_root,
+        [0_u8; 32],
+        Vec::<u8>::new(),
+    )
+        .encode()
+}
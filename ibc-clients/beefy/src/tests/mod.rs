@@ -0,0 +1,442 @@
+use std::time::Duration;
+
+use cosmwasm_std::from_json;
+use cosmwasm_std::testing::mock_dependencies;
+use ibc_client_cw::types::{ContractResult, MigrationPrefix};
+use ibc_client_cw::utils::AnyCodec;
+use ibc_client_cw_testkit::scenarios;
+use ibc_core::client::types::Status;
+
+use crate::client_type::BeefyClient;
+use crate::types::{Commitment, Header, Misbehaviour};
+
+pub mod fixture;
+pub mod helper;
+
+use fixture::{BeefyConfig, Fixture};
+
+#[test]
+fn test_cw_beefy_testkit_scenarios() {
+    scenarios::run_all(&Fixture::default());
+}
+
+#[test]
+fn test_cw_beefy_update_client_ok() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let trusted_height = BeefyClient::trusted_para_height(&fxt);
+
+    for height in [trusted_height + 1, trusted_height + 20] {
+        let resp = fxt
+            .update_client_with(
+                deps.as_mut(),
+                Header::encode_to_any_vec(fxt.config.dummy_header(height)),
+            )
+            .unwrap();
+
+        let contract_result: ContractResult = from_json(resp.data.unwrap()).unwrap();
+
+        assert_eq!(
+            contract_result.heights,
+            Some(vec![BeefyClient::height(height.into())])
+        );
+    }
+
+    // A parachain header included before the commitment is proven with the
+    // MMR root of the later commitment.
+    let latest_commitment_block = BeefyClient::relay_block(trusted_height + 30);
+
+    for height in [trusted_height + 21, trusted_height + 25] {
+        fxt.update_client_with(
+            deps.as_mut(),
+            Header::encode_to_any_vec(fxt.config.header_at(height, latest_commitment_block)),
+        )
+        .unwrap();
+    }
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+}
+
+#[test]
+fn test_cw_beefy_invalid_header_rejected() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let trusted_height = BeefyClient::trusted_para_height(&fxt);
+
+    let header = fxt.config.dummy_header(trusted_height + 5);
+    let commitment = header.signed_commitment.commitment.clone();
+
+    let below_threshold = Header {
+        signed_commitment: fxt.config.sign(commitment.clone(), &[0, 1]),
+        ..header.clone()
+    };
+
+    let duplicate_signer = Header {
+        signed_commitment: fxt.config.sign(commitment.clone(), &[0, 1, 1]),
+        ..header.clone()
+    };
+
+    let mut unknown_signer = header.clone();
+    unknown_signer.signed_commitment.signatures[0].authority_index = 4;
+
+    let mut outsider_signature = header.clone();
+    outsider_signature.signed_commitment.signatures[0].signature = BeefyConfig {
+        authority_count: 5,
+        ..fxt.config.clone()
+    }
+    .sign(commitment.clone(), &[4])
+    .signatures[0]
+        .signature;
+
+    let mut tampered_authority_proof = header.clone();
+    tampered_authority_proof.signed_commitment.signatures[0].authority_proof[0] = [0xff; 32];
+
+    let mut unknown_authority_set = commitment.clone();
+    unknown_authority_set.validator_set_id += 2;
+
+    let unknown_authority_set = Header {
+        signed_commitment: fxt.config.sign(unknown_authority_set, &fxt.config.quorum()),
+        ..header.clone()
+    };
+
+    let mut tampered_mmr_proof = header.clone();
+    tampered_mmr_proof.mmr_proof[0] = [0xff; 32];
+
+    let mut tampered_mmr_leaf = header.clone();
+    tampered_mmr_leaf.mmr_leaf.parachain_heads_root = [0xff; 32];
+
+    let mut tampered_next_authority_set = header.clone();
+    tampered_next_authority_set
+        .mmr_leaf
+        .beefy_next_authority_set
+        .id += 1;
+
+    let mut other_parachain = header.clone();
+    other_parachain.parachain_header.heads_leaf_index -= 1;
+
+    let mut tampered_heads_proof = header.clone();
+    tampered_heads_proof.parachain_header.heads_proof[0] = [0xff; 32];
+
+    // the timestamp proof of another block does not prove the timestamp
+    let mut stale_timestamp_proof = header.clone();
+    stale_timestamp_proof.parachain_header.timestamp_proof =
+        fxt.config.parachain_header(trusted_height).timestamp_proof;
+
+    let stale_commitment = fxt.config.header_at(
+        trusted_height - 2,
+        BeefyClient::relay_block(trusted_height - 1),
+    );
+
+    for header in [
+        below_threshold,
+        duplicate_signer,
+        unknown_signer,
+        outsider_signature,
+        tampered_authority_proof,
+        unknown_authority_set,
+        tampered_mmr_proof,
+        tampered_mmr_leaf,
+        tampered_next_authority_set,
+        other_parachain,
+        tampered_heads_proof,
+        stale_timestamp_proof,
+        stale_commitment,
+    ] {
+        assert!(!fxt.is_valid_client_message(deps.as_ref(), Header::encode_to_any_vec(header)));
+    }
+}
+
+#[test]
+fn test_cw_beefy_verify_membership() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let trusted_height = BeefyClient::trusted_para_height(&fxt);
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(fxt.config.dummy_header(trusted_height + 5)),
+    )
+    .unwrap();
+
+    for height in [trusted_height, trusted_height + 5] {
+        let height = BeefyClient::height(height.into());
+
+        for (path, value) in &fxt.config.commitments {
+            fxt.verify_membership(
+                deps.as_mut(),
+                fxt.config.dummy_verify_membership_msg(height, path, value),
+            )
+            .unwrap();
+
+            assert!(fxt
+                .verify_membership(
+                    deps.as_mut(),
+                    fxt.config
+                        .dummy_verify_membership_msg(height, path, b"other-value"),
+                )
+                .is_err());
+
+            assert!(fxt
+                .verify_non_membership(
+                    deps.as_mut(),
+                    fxt.config.dummy_verify_non_membership_msg(height, path),
+                )
+                .is_err());
+        }
+
+        for absent_path in [
+            b"commitments/ports/transfer/channels/channel-0/sequences/3".as_slice(),
+            b"acks/ports/transfer/channels/channel-0/sequences/1",
+            b"connections",
+        ] {
+            fxt.verify_non_membership(
+                deps.as_mut(),
+                fxt.config
+                    .dummy_verify_non_membership_msg(height, absent_path),
+            )
+            .unwrap();
+        }
+    }
+
+    // A proof against the state root of another height is rejected.
+    let (path, value) = &fxt.config.commitments[0];
+
+    let mut msg = fxt.config.dummy_verify_membership_msg(
+        BeefyClient::height(trusted_height.into()),
+        path,
+        value,
+    );
+    msg.height = BeefyClient::height((trusted_height + 5).into()).into();
+
+    assert!(fxt.verify_membership(deps.as_mut(), msg).is_err());
+}
+
+#[test]
+fn test_cw_beefy_authority_set_rotation() {
+    // The parachain block at the trusted height is included in the first
+    // session, the one following it starts at relay chain block 30.
+    let fxt = Fixture::new(BeefyConfig {
+        session_length: 30,
+        ..Default::default()
+    });
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let trusted_height = BeefyClient::trusted_para_height(&fxt);
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(fxt.config.dummy_header(trusted_height + 4)),
+    )
+    .unwrap();
+
+    // A commitment of the session after the next one is signed by an
+    // unknown authority set.
+    assert!(!fxt.is_valid_client_message(
+        deps.as_ref(),
+        Header::encode_to_any_vec(fxt.config.dummy_header(trusted_height + 21))
+    ));
+
+    // A leaf of the first session does not hand over the session of the
+    // signing authority set.
+    assert!(!fxt.is_valid_client_message(
+        deps.as_ref(),
+        Header::encode_to_any_vec(fxt.config.header_at(trusted_height + 4, 32))
+    ));
+
+    // The next authority set signs the first commitment of its session.
+    fxt.update_client_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(fxt.config.dummy_header(trusted_height + 6)),
+    )
+    .unwrap();
+
+    // The previous authority set cannot sign commitments anymore.
+    let mut header = fxt.config.dummy_header(trusted_height + 7);
+    header.signed_commitment = fxt.config.sign(
+        Commitment {
+            validator_set_id: 0,
+            ..header.signed_commitment.commitment
+        },
+        &fxt.config.quorum(),
+    );
+
+    assert!(!fxt.is_valid_client_message(deps.as_ref(), Header::encode_to_any_vec(header)));
+
+    // The session after the next one is reachable once the client follows
+    // the second session.
+    for height in [trusted_height + 7, trusted_height + 21] {
+        fxt.update_client_with(
+            deps.as_mut(),
+            Header::encode_to_any_vec(fxt.config.dummy_header(height)),
+        )
+        .unwrap();
+    }
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+}
+
+#[test]
+fn test_cw_beefy_freeze_on_misbehaviour() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let relay_block = BeefyClient::relay_block(BeefyClient::trusted_para_height(&fxt) + 5);
+
+    let misbehaviour = fxt.config.dummy_misbehaviour(relay_block);
+
+    // Commitments of the same payload are no evidence of misbehaviour.
+    let same_commitment = Misbehaviour {
+        commitment_2: fxt
+            .config
+            .sign(misbehaviour.commitment_1.commitment.clone(), &[1, 2, 3]),
+        ..misbehaviour.clone()
+    };
+
+    assert!(!fxt.found_misbehaviour(
+        deps.as_ref(),
+        Misbehaviour::encode_to_any_vec(same_commitment)
+    ));
+
+    // Commitments of different blocks are no evidence of misbehaviour.
+    let different_blocks = Misbehaviour {
+        commitment_2: fxt
+            .config
+            .sign(fxt.config.commitment(relay_block + 1), &fxt.config.quorum()),
+        ..misbehaviour.clone()
+    };
+
+    assert!(!fxt.is_valid_client_message(
+        deps.as_ref(),
+        Misbehaviour::encode_to_any_vec(different_blocks)
+    ));
+
+    fxt.update_client_on_misbehaviour_with(
+        deps.as_mut(),
+        Misbehaviour::encode_to_any_vec(misbehaviour),
+    );
+
+    fxt.check_client_status(deps.as_ref(), Status::Frozen);
+}
+
+#[test]
+fn test_cw_beefy_freeze_on_conflicting_header() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let height = BeefyClient::trusted_para_height(&fxt) + 5;
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(fxt.config.dummy_header(height)),
+    )
+    .unwrap();
+
+    // The authorities finalize a fork of the parachain with another state.
+    let conflicting_header = BeefyConfig {
+        commitments: vec![(b"connections/connection-0".to_vec(), b"forged".to_vec())],
+        ..fxt.config.clone()
+    }
+    .dummy_header(height);
+
+    fxt.update_client_on_misbehaviour_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(conflicting_header),
+    );
+
+    fxt.check_client_status(deps.as_ref(), Status::Frozen);
+}
+
+#[test]
+fn test_cw_beefy_recovery_client_ok() {
+    let mut fxt = Fixture::new(BeefyConfig {
+        session_length: 30,
+        ..Default::default()
+    });
+
+    let mut deps = mock_dependencies();
+
+    // ------------------- Create subject client -------------------
+
+    fxt.set_migration_prefix(MigrationPrefix::Subject);
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    // ------------------- Freeze subject client -------------------
+
+    let relay_block = BeefyClient::relay_block(BeefyClient::trusted_para_height(&fxt) + 2);
+
+    let misbehaviour = fxt.config.dummy_misbehaviour(relay_block);
+
+    fxt.update_client_on_misbehaviour_with(
+        deps.as_mut(),
+        Misbehaviour::encode_to_any_vec(misbehaviour),
+    );
+
+    fxt.check_client_status(deps.as_ref(), Status::Frozen);
+
+    // ------------------- Create substitute client -------------------
+
+    // The substitute client is trusting the authority sets of a later
+    // session.
+    fxt.set_migration_prefix(MigrationPrefix::Substitute);
+
+    fxt.trusted_height = BeefyClient::height(fxt.trusted_height.revision_height() + 30);
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    // ------------------- Recover subject client -------------------
+
+    let resp = fxt.recover_client(deps.as_mut()).unwrap();
+
+    assert_eq!(0, resp.messages.len());
+
+    fxt.set_migration_prefix(MigrationPrefix::Subject);
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+
+    // ------------------- Update with the later session -------------------
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(
+            fxt.config
+                .dummy_header(BeefyClient::trusted_para_height(&fxt) + 1),
+        ),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_cw_beefy_client_expiry() {
+    let fxt = Fixture::new(BeefyConfig {
+        trusting_period: Duration::from_secs(60),
+        ..Default::default()
+    });
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    fxt.check_client_status(deps.as_ref(), Status::Expired);
+}
@@ -0,0 +1,302 @@
+//! Substrate base-16 Patricia trie proof verification, used to verify the
+//! storage of parachains against their state roots. Nodes follow the codec
+//! of `sp-trie`, hashed with Blake2-256, and both state versions are
+//! supported: values of at least 33 bytes are stored as separate value nodes
+//! referred to by their hash in state version 1.
+use std::collections::BTreeMap;
+
+use blake2::digest::consts::U32;
+use blake2::{Blake2b, Digest};
+use parity_scale_codec::{Compact, Decode};
+
+use crate::error::BeefyError;
+use crate::types::Bytes32;
+
+/// Returns the Blake2-256 digest of the given bytes.
+pub fn blake2_256(data: impl AsRef<[u8]>) -> Bytes32 {
+    Blake2b::<U32>::digest(data.as_ref()).into()
+}
+
+/// The storage key of the `Now` value of the timestamp pallet, i.e.
+/// `twox128("Timestamp") || twox128("Now")`. Its value is the time of the
+/// block, in milliseconds since the Unix epoch, as a SCALE-encoded `u64`.
+pub const TIMESTAMP_NOW_KEY: [u8; 32] = [
+    0xf0, 0xc3, 0x65, 0xc3, 0xcf, 0x59, 0xd6, 0x71, 0xeb, 0x72, 0xda, 0x0e, 0x7a, 0x41, 0x13, 0xc4,
+    0x9f, 0x1f, 0x05, 0x15, 0xf4, 0x62, 0xcd, 0xcf, 0x84, 0xe0, 0xf1, 0xd6, 0x04, 0x5d, 0xfc, 0xbb,
+];
+
+/// The encoding of the empty node, the root of an empty trie.
+const EMPTY_TRIE: u8 = 0b0000_0000;
+const LEAF_PREFIX_MASK: u8 = 0b01 << 6;
+const BRANCH_WITHOUT_VALUE_MASK: u8 = 0b10 << 6;
+const BRANCH_WITH_VALUE_MASK: u8 = 0b11 << 6;
+const HASHED_VALUE_LEAF_PREFIX_MASK: u8 = 0b001 << 5;
+const HASHED_VALUE_BRANCH_PREFIX_MASK: u8 = 0b0001 << 4;
+
+fn trie_error(description: &str) -> BeefyError {
+    BeefyError::InvalidTrieProof {
+        description: description.to_string(),
+    }
+}
+
+/// Splits the given bytes into nibbles.
+fn to_nibbles(bytes: &[u8]) -> Vec<u8> {
+    bytes.iter().flat_map(|b| [b >> 4, b & 0x0f]).collect()
+}
+
+/// A cursor over an encoded node.
+struct NodeInput<'a>(&'a [u8]);
+
+impl<'a> NodeInput<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], BeefyError> {
+        if self.0.len() < len {
+            return Err(trie_error("unexpected end of node"));
+        }
+
+        let (taken, rest) = self.0.split_at(len);
+        self.0 = rest;
+
+        Ok(taken)
+    }
+
+    fn byte(&mut self) -> Result<u8, BeefyError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn compact_len(&mut self) -> Result<usize, BeefyError> {
+        let len = Compact::<u32>::decode(&mut self.0)
+            .map_err(|_| trie_error("invalid compact length"))?
+            .0;
+
+        Ok(len as usize)
+    }
+
+    fn hash(&mut self) -> Result<Bytes32, BeefyError> {
+        Ok(self.take(32)?.try_into().expect("32 bytes"))
+    }
+
+    /// Decodes the size of the partial key stored in the header byte `first`
+    /// after its `prefix_bits` type bits, continued on the following bytes
+    /// when the header bits are saturated.
+    fn partial_key_len(&mut self, first: u8, prefix_bits: u32) -> Result<usize, BeefyError> {
+        let max_value = 0xff_u8 >> prefix_bits;
+        let mut len = usize::from(first & max_value);
+
+        if len < usize::from(max_value) {
+            return Ok(len);
+        }
+
+        loop {
+            let next = self.byte()?;
+            len += usize::from(next);
+
+            if next < 0xff {
+                return Ok(len);
+            }
+        }
+    }
+
+    /// Reads a partial key of `len` nibbles, left-padded to whole bytes.
+    fn partial_key(&mut self, len: usize) -> Result<Vec<u8>, BeefyError> {
+        let bytes = self.take(len.div_ceil(2))?;
+        let nibbles = to_nibbles(bytes);
+
+        if len % 2 == 1 && nibbles[0] != 0 {
+            return Err(trie_error("non-zero partial key padding"));
+        }
+
+        Ok(nibbles[nibbles.len() - len..].to_vec())
+    }
+
+    fn value(&mut self, is_hashed: bool) -> Result<Value<'a>, BeefyError> {
+        if is_hashed {
+            return Ok(Value::Hashed(self.hash()?));
+        }
+
+        let len = self.compact_len()?;
+
+        Ok(Value::Inline(self.take(len)?))
+    }
+
+    fn child(&mut self) -> Result<NodeRef<'a>, BeefyError> {
+        let len = self.compact_len()?;
+        let child = self.take(len)?;
+
+        Ok(match child.try_into() {
+            Ok(hash) => NodeRef::Hash(hash),
+            Err(_) => NodeRef::Inline(child),
+        })
+    }
+}
+
+enum NodeRef<'a> {
+    Hash(Bytes32),
+    /// A node whose encoding is shorter than a hash, stored in its parent.
+    Inline(&'a [u8]),
+}
+
+enum Value<'a> {
+    Inline(&'a [u8]),
+    /// A value stored in a separate node, in state version 1.
+    Hashed(Bytes32),
+}
+
+enum Node<'a> {
+    Empty,
+    Leaf {
+        partial_key: Vec<u8>,
+        value: Value<'a>,
+    },
+    Branch {
+        partial_key: Vec<u8>,
+        value: Option<Value<'a>>,
+        children: Box<[Option<NodeRef<'a>>; 16]>,
+    },
+}
+
+impl<'a> Node<'a> {
+    fn decode(encoded: &'a [u8]) -> Result<Self, BeefyError> {
+        let mut input = NodeInput(encoded);
+        let first = input.byte()?;
+
+        let node = match first & (0b11 << 6) {
+            _ if first == EMPTY_TRIE => Self::Empty,
+            LEAF_PREFIX_MASK => Self::decode_leaf(&mut input, first, 2, false)?,
+            BRANCH_WITHOUT_VALUE_MASK => Self::decode_branch(&mut input, first, 2, None)?,
+            BRANCH_WITH_VALUE_MASK => Self::decode_branch(&mut input, first, 2, Some(false))?,
+            _ if first & (0b111 << 5) == HASHED_VALUE_LEAF_PREFIX_MASK => {
+                Self::decode_leaf(&mut input, first, 3, true)?
+            }
+            _ if first & (0b1111 << 4) == HASHED_VALUE_BRANCH_PREFIX_MASK => {
+                Self::decode_branch(&mut input, first, 4, Some(true))?
+            }
+            _ => return Err(trie_error("unknown node header")),
+        };
+
+        if !input.0.is_empty() {
+            return Err(trie_error("trailing bytes after node"));
+        }
+
+        Ok(node)
+    }
+
+    fn decode_leaf(
+        input: &mut NodeInput<'a>,
+        first: u8,
+        prefix_bits: u32,
+        is_hashed: bool,
+    ) -> Result<Self, BeefyError> {
+        let partial_key_len = input.partial_key_len(first, prefix_bits)?;
+
+        Ok(Self::Leaf {
+            partial_key: input.partial_key(partial_key_len)?,
+            value: input.value(is_hashed)?,
+        })
+    }
+
+    /// Decodes a branch, whose value is hashed if `value_is_hashed` is
+    /// `Some(true)`, and absent if it is `None`.
+    fn decode_branch(
+        input: &mut NodeInput<'a>,
+        first: u8,
+        prefix_bits: u32,
+        value_is_hashed: Option<bool>,
+    ) -> Result<Self, BeefyError> {
+        let partial_key_len = input.partial_key_len(first, prefix_bits)?;
+        let partial_key = input.partial_key(partial_key_len)?;
+
+        let bitmap = u16::from_le_bytes(input.take(2)?.try_into().expect("2 bytes"));
+
+        if bitmap == 0 {
+            return Err(trie_error("branch without children"));
+        }
+
+        let value = value_is_hashed
+            .map(|is_hashed| input.value(is_hashed))
+            .transpose()?;
+
+        let mut children: Box<[Option<NodeRef<'a>>; 16]> = Box::default();
+
+        for (i, child) in children.iter_mut().enumerate() {
+            if bitmap & (1 << i) != 0 {
+                *child = Some(input.child()?);
+            }
+        }
+
+        Ok(Self::Branch {
+            partial_key,
+            value,
+            children,
+        })
+    }
+}
+
+/// Verifies a Substrate storage proof, the set of trie nodes visited to
+/// look up `key`, against `root`. Returns the value stored at the key, or
+/// `None` if the proof shows the key is absent.
+pub fn verify_proof(
+    root: &Bytes32,
+    key: &[u8],
+    proof: &[Vec<u8>],
+) -> Result<Option<Vec<u8>>, BeefyError> {
+    let nodes: BTreeMap<Bytes32, &[u8]> = proof
+        .iter()
+        .map(|node| (blake2_256(node), node.as_slice()))
+        .collect();
+
+    let lookup = |hash: &Bytes32| -> Result<&[u8], BeefyError> {
+        nodes
+            .get(hash)
+            .copied()
+            .ok_or(trie_error("missing proof node"))
+    };
+
+    let nibbles = to_nibbles(key);
+    let mut position = 0;
+    let mut next = NodeRef::Hash(*root);
+
+    let value = loop {
+        let encoded = match next {
+            NodeRef::Hash(hash) => lookup(&hash)?,
+            NodeRef::Inline(node) => node,
+        };
+
+        let remaining = &nibbles[position..];
+
+        match Node::decode(encoded)? {
+            Node::Empty => break None,
+            Node::Leaf { partial_key, value } => {
+                break (remaining == partial_key.as_slice()).then_some(value);
+            }
+            Node::Branch {
+                partial_key,
+                value,
+                mut children,
+            } => {
+                if !remaining.starts_with(&partial_key) {
+                    break None;
+                }
+
+                position += partial_key.len();
+
+                let Some(nibble) = nibbles.get(position) else {
+                    break value;
+                };
+
+                position += 1;
+
+                match children[usize::from(*nibble)].take() {
+                    Some(child) => next = child,
+                    None => break None,
+                }
+            }
+        }
+    };
+
+    value
+        .map(|value| match value {
+            Value::Inline(value) => Ok(value.to_vec()),
+            Value::Hashed(hash) => lookup(&hash).map(<[u8]>::to_vec),
+        })
+        .transpose()
+}
@@ -0,0 +1,162 @@
+//! The BEEFY authority sets trusted by the client, and the verification of
+//! the commitment signatures of their members.
+use std::collections::BTreeSet;
+
+use ibc_core::host::types::error::DecodingError;
+use parity_scale_codec::Encode;
+
+use super::proto::{
+    AuthoritySet as RawAuthoritySet, CommitmentSignature as RawCommitmentSignature,
+};
+use super::{branch, branch_to_raw, fixed_bytes, Bytes20, Bytes32, Bytes65};
+use crate::error::BeefyError;
+use crate::merkle::{keccak256, verify_binary_merkle_proof};
+use crate::secp256k1::Secp256k1Recoverer;
+
+/// A BEEFY authority set, as committed to by the MMR leaves: the authorities
+/// are not listed, but committed to by the root of the binary Merkle tree of
+/// their Ethereum-style addresses.
+#[derive(Clone, Debug, PartialEq, Eq, Encode)]
+pub struct AuthoritySet {
+    /// The ID of the set, incremented on every session.
+    pub id: u64,
+    /// The number of authorities in the set.
+    pub len: u32,
+    pub keyset_commitment: Bytes32,
+}
+
+impl AuthoritySet {
+    /// Returns the number of signatures required to finalize a commitment,
+    /// i.e. more than two thirds of the set, tolerating `(len - 1) / 3`
+    /// faulty authorities.
+    pub fn threshold(&self) -> u32 {
+        self.len - (self.len - 1) / 3
+    }
+
+    /// Verifies that more than two thirds of the set signed the commitment
+    /// hash. Every signature must be valid and come from an authority proven
+    /// to be part of the set, even beyond the threshold.
+    pub fn verify_signatures<V>(
+        &self,
+        verifier: &V,
+        commitment_hash: &Bytes32,
+        signatures: &[CommitmentSignature],
+    ) -> Result<(), BeefyError>
+    where
+        V: Secp256k1Recoverer + ?Sized,
+    {
+        let mut signers = BTreeSet::new();
+
+        for signature in signatures {
+            let index = signature.authority_index;
+
+            if index >= self.len {
+                return Err(BeefyError::UnknownAuthority {
+                    index,
+                    len: self.len,
+                });
+            }
+
+            if !signers.insert(index) {
+                return Err(BeefyError::DuplicateSignature { index });
+            }
+
+            let address = verifier
+                .secp256k1_recover_pubkey(commitment_hash, &signature.signature)
+                .as_deref()
+                .and_then(authority_address)
+                .ok_or(BeefyError::InvalidSignature { index })?;
+
+            if !verify_binary_merkle_proof(
+                &self.keyset_commitment,
+                keccak256(address),
+                index,
+                self.len,
+                &signature.authority_proof,
+            ) {
+                return Err(BeefyError::InvalidAuthorityProof { index });
+            }
+        }
+
+        if signers.len() < self.threshold() as usize {
+            return Err(BeefyError::InsufficientSignatures {
+                signed: signers.len(),
+                threshold: self.threshold(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<RawAuthoritySet> for AuthoritySet {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawAuthoritySet) -> Result<Self, Self::Error> {
+        if raw.len == 0 {
+            return Err(DecodingError::invalid_raw_data(
+                "authority set must be non-empty",
+            ));
+        }
+
+        Ok(Self {
+            id: raw.id,
+            len: raw.len,
+            keyset_commitment: fixed_bytes("keyset commitment", raw.keyset_commitment)?,
+        })
+    }
+}
+
+impl From<AuthoritySet> for RawAuthoritySet {
+    fn from(value: AuthoritySet) -> Self {
+        Self {
+            id: value.id,
+            len: value.len,
+            keyset_commitment: value.keyset_commitment.to_vec(),
+        }
+    }
+}
+
+/// Derives the Ethereum-style address of an authority, i.e. the last 20
+/// bytes of the Keccak-256 digest of its uncompressed public key, the leaves
+/// of the keyset commitment.
+pub fn authority_address(uncompressed_pubkey: &[u8]) -> Option<Bytes20> {
+    match uncompressed_pubkey {
+        [0x04, pubkey @ ..] if pubkey.len() == 64 => {
+            Some(keccak256(pubkey)[12..].try_into().expect("20 bytes"))
+        }
+        _ => None,
+    }
+}
+
+/// The signature of a commitment by an authority, referred to by its index
+/// in the set, along with the proof of its address against the keyset
+/// commitment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommitmentSignature {
+    pub authority_index: u32,
+    pub signature: Bytes65,
+    pub authority_proof: Vec<Bytes32>,
+}
+
+impl TryFrom<RawCommitmentSignature> for CommitmentSignature {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawCommitmentSignature) -> Result<Self, Self::Error> {
+        Ok(Self {
+            authority_index: raw.authority_index,
+            signature: fixed_bytes("commitment signature", raw.signature)?,
+            authority_proof: branch("authority proof node", raw.authority_proof)?,
+        })
+    }
+}
+
+impl From<CommitmentSignature> for RawCommitmentSignature {
+    fn from(value: CommitmentSignature) -> Self {
+        Self {
+            authority_index: value.authority_index,
+            signature: value.signature.to_vec(),
+            authority_proof: branch_to_raw(value.authority_proof),
+        }
+    }
+}
@@ -0,0 +1,131 @@
+//! BEEFY commitments, the statements about finalized relay chain blocks
+//! signed by the authorities.
+use ibc_core::host::types::error::DecodingError;
+use parity_scale_codec::Encode;
+
+use super::proto::{
+    Commitment as RawCommitment, PayloadItem as RawPayloadItem,
+    SignedCommitment as RawSignedCommitment,
+};
+use super::{fixed_bytes, Bytes32, CommitmentSignature};
+use crate::error::BeefyError;
+use crate::merkle::keccak256;
+
+/// The payload identifier of the MMR root.
+pub const MMR_ROOT_ID: [u8; 2] = *b"mh";
+
+/// An item of the commitment payload.
+#[derive(Clone, Debug, PartialEq, Eq, Encode)]
+pub struct PayloadItem {
+    pub id: [u8; 2],
+    pub data: Vec<u8>,
+}
+
+/// The commitment signed by the authorities of the set `validator_set_id`
+/// upon the finalization of the relay chain block `block_number`. Its
+/// payload items are sorted by identifier, as in their SCALE encoding.
+#[derive(Clone, Debug, PartialEq, Eq, Encode)]
+pub struct Commitment {
+    pub payload: Vec<PayloadItem>,
+    pub block_number: u32,
+    pub validator_set_id: u64,
+}
+
+impl Commitment {
+    /// Returns the message signed by the authorities, i.e. the Keccak-256
+    /// digest of the SCALE-encoded commitment.
+    pub fn hash(&self) -> Bytes32 {
+        keccak256(self.encode())
+    }
+
+    /// Returns the MMR root of the relay chain committed to by the payload.
+    pub fn mmr_root(&self) -> Result<Bytes32, BeefyError> {
+        let item = self
+            .payload
+            .iter()
+            .find(|item| item.id == MMR_ROOT_ID)
+            .ok_or(BeefyError::MissingMmrRoot)?;
+
+        Ok(fixed_bytes("MMR root", item.data.clone())?)
+    }
+}
+
+impl TryFrom<RawCommitment> for Commitment {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawCommitment) -> Result<Self, Self::Error> {
+        let payload: Vec<PayloadItem> = raw
+            .payload
+            .into_iter()
+            .map(|item: RawPayloadItem| {
+                Ok(PayloadItem {
+                    id: fixed_bytes("payload identifier", item.id)?,
+                    data: item.data,
+                })
+            })
+            .collect::<Result<_, DecodingError>>()?;
+
+        if !payload.windows(2).all(|pair| pair[0].id < pair[1].id) {
+            return Err(DecodingError::invalid_raw_data(
+                "commitment payload must be sorted by distinct identifiers",
+            ));
+        }
+
+        Ok(Self {
+            payload,
+            block_number: raw.block_number,
+            validator_set_id: raw.validator_set_id,
+        })
+    }
+}
+
+impl From<Commitment> for RawCommitment {
+    fn from(value: Commitment) -> Self {
+        Self {
+            payload: value
+                .payload
+                .into_iter()
+                .map(|item| RawPayloadItem {
+                    id: item.id.to_vec(),
+                    data: item.data,
+                })
+                .collect(),
+            block_number: value.block_number,
+            validator_set_id: value.validator_set_id,
+        }
+    }
+}
+
+/// A commitment along with the signatures of its authorities.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignedCommitment {
+    pub commitment: Commitment,
+    pub signatures: Vec<CommitmentSignature>,
+}
+
+impl TryFrom<RawSignedCommitment> for SignedCommitment {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawSignedCommitment) -> Result<Self, Self::Error> {
+        Ok(Self {
+            commitment: raw
+                .commitment
+                .ok_or(DecodingError::missing_raw_data("commitment"))?
+                .try_into()?,
+            signatures: raw
+                .signatures
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<SignedCommitment> for RawSignedCommitment {
+    fn from(value: SignedCommitment) -> Self {
+        Self {
+            commitment: Some(value.commitment.into()),
+            signatures: value.signatures.into_iter().map(Into::into).collect(),
+        }
+    }
+}
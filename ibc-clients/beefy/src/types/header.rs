@@ -0,0 +1,155 @@
+//! Client messages accepted by the BEEFY light client: headers installing a
+//! parachain consensus state, and the misbehaviour made of two conflicting
+//! commitments.
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Height;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::proto::{Any, Protobuf};
+
+use super::proto::{Header as RawHeader, Misbehaviour as RawMisbehaviour};
+use super::{branch, branch_to_raw, Bytes32, MmrLeaf, ParachainHeader, SignedCommitment};
+use crate::client_state::BEEFY_REVISION_NUMBER;
+
+pub const BEEFY_HEADER_TYPE_URL: &str = "/ibc.lightclients.beefy.v1.Header";
+pub const BEEFY_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.lightclients.beefy.v1.Misbehaviour";
+
+/// A parachain header included in the relay chain block of `mmr_leaf`,
+/// which is itself proven against the MMR root of a signed commitment of the
+/// same or of a later block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Header {
+    pub signed_commitment: SignedCommitment,
+    pub mmr_leaf: MmrLeaf,
+    pub mmr_proof: Vec<Bytes32>,
+    pub parachain_header: ParachainHeader,
+}
+
+impl Header {
+    /// Returns the client height of the parachain header.
+    pub fn height(&self) -> Result<Height, ClientError> {
+        Height::new(BEEFY_REVISION_NUMBER, self.parachain_header.number.into())
+    }
+}
+
+impl Protobuf<RawHeader> for Header {}
+
+impl TryFrom<RawHeader> for Header {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawHeader) -> Result<Self, Self::Error> {
+        Ok(Self {
+            signed_commitment: raw
+                .signed_commitment
+                .ok_or(DecodingError::missing_raw_data("signed commitment"))?
+                .try_into()?,
+            mmr_leaf: raw
+                .mmr_leaf
+                .ok_or(DecodingError::missing_raw_data("MMR leaf"))?
+                .try_into()?,
+            mmr_proof: branch("MMR proof node", raw.mmr_proof)?,
+            parachain_header: raw
+                .parachain_header
+                .ok_or(DecodingError::missing_raw_data("parachain header"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<Header> for RawHeader {
+    fn from(value: Header) -> Self {
+        Self {
+            signed_commitment: Some(value.signed_commitment.into()),
+            mmr_leaf: Some(value.mmr_leaf.into()),
+            mmr_proof: branch_to_raw(value.mmr_proof),
+            parachain_header: Some(value.parachain_header.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let BEEFY_HEADER_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawHeader>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: BEEFY_HEADER_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        Self {
+            type_url: BEEFY_HEADER_TYPE_URL.to_string(),
+            value: Protobuf::<RawHeader>::encode_vec(header),
+        }
+    }
+}
+
+/// Two different commitments of the same relay chain block, both signed by
+/// an authority set trusted by the client.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Misbehaviour {
+    pub commitment_1: SignedCommitment,
+    pub commitment_2: SignedCommitment,
+}
+
+impl Protobuf<RawMisbehaviour> for Misbehaviour {}
+
+impl TryFrom<RawMisbehaviour> for Misbehaviour {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawMisbehaviour) -> Result<Self, Self::Error> {
+        Ok(Self {
+            commitment_1: raw
+                .commitment_1
+                .ok_or(DecodingError::missing_raw_data("misbehaviour commitment 1"))?
+                .try_into()?,
+            commitment_2: raw
+                .commitment_2
+                .ok_or(DecodingError::missing_raw_data("misbehaviour commitment 2"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<Misbehaviour> for RawMisbehaviour {
+    fn from(value: Misbehaviour) -> Self {
+        Self {
+            commitment_1: Some(value.commitment_1.into()),
+            commitment_2: Some(value.commitment_2.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for Misbehaviour {}
+
+impl TryFrom<Any> for Misbehaviour {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let BEEFY_MISBEHAVIOUR_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawMisbehaviour>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: BEEFY_MISBEHAVIOUR_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<Misbehaviour> for Any {
+    fn from(misbehaviour: Misbehaviour) -> Self {
+        Self {
+            type_url: BEEFY_MISBEHAVIOUR_TYPE_URL.to_string(),
+            value: Protobuf::<RawMisbehaviour>::encode_vec(misbehaviour),
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! The leaves of the relay chain MMR, one per block, committing to the next
+//! authority set and to the parachain heads.
+use ibc_core::host::types::error::DecodingError;
+use parity_scale_codec::Encode;
+
+use super::proto::MmrLeaf as RawMmrLeaf;
+use super::{fixed_bytes, AuthoritySet, Bytes32};
+use crate::merkle::keccak256;
+
+/// The MMR leaf appended by the relay chain block following the block
+/// `parent_number`, as defined by the BEEFY MMR pallet.
+#[derive(Clone, Debug, PartialEq, Eq, Encode)]
+pub struct MmrLeaf {
+    pub version: u8,
+    pub parent_number: u32,
+    pub parent_hash: Bytes32,
+    /// The authority set of the next session, which signs the commitments
+    /// from the first block of that session.
+    pub beefy_next_authority_set: AuthoritySet,
+    /// The root of the binary Merkle tree of the SCALE-encoded
+    /// `(para_id, head)` pairs, sorted by parachain ID.
+    pub parachain_heads_root: Bytes32,
+}
+
+impl MmrLeaf {
+    /// Returns the number of the relay chain block which appended the leaf.
+    pub fn block_number(&self) -> u32 {
+        self.parent_number.saturating_add(1)
+    }
+
+    /// Returns the hash of the leaf in the MMR, i.e. the Keccak-256 digest of
+    /// its SCALE encoding.
+    pub fn hash(&self) -> Bytes32 {
+        keccak256(self.encode())
+    }
+}
+
+impl TryFrom<RawMmrLeaf> for MmrLeaf {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawMmrLeaf) -> Result<Self, Self::Error> {
+        Ok(Self {
+            version: raw.version.try_into().map_err(|_| {
+                DecodingError::invalid_raw_data(format!(
+                    "MMR leaf version `{}` must fit in a byte",
+                    raw.version
+                ))
+            })?,
+            parent_number: raw.parent_number,
+            parent_hash: fixed_bytes("parent hash", raw.parent_hash)?,
+            beefy_next_authority_set: raw
+                .beefy_next_authority_set
+                .ok_or(DecodingError::missing_raw_data("next authority set"))?
+                .try_into()?,
+            parachain_heads_root: fixed_bytes("parachain heads root", raw.parachain_heads_root)?,
+        })
+    }
+}
+
+impl From<MmrLeaf> for RawMmrLeaf {
+    fn from(value: MmrLeaf) -> Self {
+        Self {
+            version: value.version.into(),
+            parent_number: value.parent_number,
+            parent_hash: value.parent_hash.to_vec(),
+            beefy_next_authority_set: Some(value.beefy_next_authority_set.into()),
+            parachain_heads_root: value.parachain_heads_root.to_vec(),
+        }
+    }
+}
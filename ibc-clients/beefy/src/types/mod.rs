@@ -0,0 +1,44 @@
+mod authority;
+mod commitment;
+mod header;
+mod mmr;
+mod parachain;
+pub mod proto;
+
+pub use authority::*;
+pub use commitment::*;
+pub use header::*;
+use ibc_core::host::types::error::DecodingError;
+pub use mmr::*;
+pub use parachain::*;
+
+pub type Bytes20 = [u8; 20];
+pub type Bytes32 = [u8; 32];
+
+/// The size of a recoverable secp256k1 signature, serialized as
+/// `r || s || v`.
+pub type Bytes65 = [u8; 65];
+
+/// Converts raw bytes into a fixed-size array, failing if the length does not
+/// match.
+pub(crate) fn fixed_bytes<const N: usize>(
+    name: &str,
+    bytes: Vec<u8>,
+) -> Result<[u8; N], DecodingError> {
+    let actual = bytes.len();
+
+    bytes.try_into().map_err(|_| {
+        DecodingError::invalid_raw_data(format!(
+            "{name} must be {N} bytes long, found {actual} bytes"
+        ))
+    })
+}
+
+/// Converts a list of raw Merkle proof nodes into 32-byte hashes.
+pub(crate) fn branch(name: &str, nodes: Vec<Vec<u8>>) -> Result<Vec<Bytes32>, DecodingError> {
+    nodes.into_iter().map(|n| fixed_bytes(name, n)).collect()
+}
+
+pub(crate) fn branch_to_raw(branch: Vec<Bytes32>) -> Vec<Vec<u8>> {
+    branch.into_iter().map(Vec::from).collect()
+}
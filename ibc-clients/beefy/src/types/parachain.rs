@@ -0,0 +1,82 @@
+//! The headers of the tracked parachain, proven to be included in the relay
+//! chain through the parachain heads root of an MMR leaf.
+use ibc_core::host::types::error::DecodingError;
+use parity_scale_codec::{Decode, Encode};
+
+use super::proto::ParachainHeader as RawParachainHeader;
+use super::{branch, branch_to_raw, Bytes32};
+
+/// The leading fields of a SCALE-encoded Substrate header, up to the roots
+/// it commits to. The digest which follows is not needed by the client.
+#[derive(Decode)]
+struct SubstrateHeaderPrefix {
+    _parent_hash: Bytes32,
+    #[codec(compact)]
+    number: u32,
+    state_root: Bytes32,
+    _extrinsics_root: Bytes32,
+}
+
+/// A parachain header, along with the proofs of its inclusion in the relay
+/// chain and of its timestamp.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParachainHeader {
+    /// The SCALE-encoded header, i.e. the head data of the parachain.
+    pub head_data: Vec<u8>,
+    /// The block number, decoded from the head data.
+    pub number: u32,
+    /// The state root, decoded from the head data.
+    pub state_root: Bytes32,
+    pub heads_leaf_index: u32,
+    pub heads_leaf_count: u32,
+    pub heads_proof: Vec<Bytes32>,
+    /// The trie nodes proving the `Timestamp::Now` value against the state
+    /// root.
+    pub timestamp_proof: Vec<Vec<u8>>,
+}
+
+impl ParachainHeader {
+    /// Returns the leaf of the parachain heads tree committing to this
+    /// header as the head of `para_id`.
+    pub fn heads_leaf(&self, para_id: u32) -> Vec<u8> {
+        (para_id, &self.head_data).encode()
+    }
+}
+
+impl TryFrom<RawParachainHeader> for ParachainHeader {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawParachainHeader) -> Result<Self, Self::Error> {
+        let prefix = SubstrateHeaderPrefix::decode(&mut raw.header.as_slice()).map_err(|e| {
+            DecodingError::invalid_raw_data(format!("SCALE-encoded parachain header: {e}"))
+        })?;
+
+        if prefix.number == 0 {
+            return Err(DecodingError::invalid_raw_data(
+                "parachain header number must be non-zero",
+            ));
+        }
+
+        Ok(Self {
+            head_data: raw.header,
+            number: prefix.number,
+            state_root: prefix.state_root,
+            heads_leaf_index: raw.heads_leaf_index,
+            heads_leaf_count: raw.heads_leaf_count,
+            heads_proof: branch("parachain heads proof node", raw.heads_proof)?,
+            timestamp_proof: raw.timestamp_proof,
+        })
+    }
+}
+
+impl From<ParachainHeader> for RawParachainHeader {
+    fn from(value: ParachainHeader) -> Self {
+        Self {
+            header: value.head_data,
+            heads_leaf_index: value.heads_leaf_index,
+            heads_leaf_count: value.heads_leaf_count,
+            heads_proof: branch_to_raw(value.heads_proof),
+            timestamp_proof: value.timestamp_proof,
+        }
+    }
+}
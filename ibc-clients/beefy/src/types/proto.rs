@@ -0,0 +1,145 @@
+//! Protobuf definitions of the BEEFY light client types, under the
+//! `ibc.lightclients.beefy.v1` package.
+use ibc_core::client::types::proto::v1::Height as RawHeight;
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct AuthoritySet {
+    #[prost(uint64, tag = "1")]
+    pub id: u64,
+    #[prost(uint32, tag = "2")]
+    pub len: u32,
+    /// The root of the binary Merkle tree of the authority addresses.
+    #[prost(bytes = "vec", tag = "3")]
+    pub keyset_commitment: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ClientState {
+    #[prost(uint32, tag = "1")]
+    pub para_id: u32,
+    #[prost(uint32, tag = "2")]
+    pub beefy_activation_block: u32,
+    #[prost(uint32, tag = "3")]
+    pub latest_beefy_height: u32,
+    #[prost(message, optional, tag = "4")]
+    pub current_authorities: Option<AuthoritySet>,
+    #[prost(message, optional, tag = "5")]
+    pub next_authorities: Option<AuthoritySet>,
+    #[prost(message, optional, tag = "6")]
+    pub latest_height: Option<RawHeight>,
+    #[prost(message, optional, tag = "7")]
+    pub frozen_height: Option<RawHeight>,
+    #[prost(uint64, tag = "8")]
+    pub trusting_period: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ConsensusState {
+    #[prost(bytes = "vec", tag = "1")]
+    pub root: Vec<u8>,
+    #[prost(uint64, tag = "2")]
+    pub timestamp: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct PayloadItem {
+    /// The two-byte identifier of the payload item, e.g. `mh` for the MMR
+    /// root.
+    #[prost(bytes = "vec", tag = "1")]
+    pub id: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub data: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Commitment {
+    #[prost(message, repeated, tag = "1")]
+    pub payload: Vec<PayloadItem>,
+    #[prost(uint32, tag = "2")]
+    pub block_number: u32,
+    #[prost(uint64, tag = "3")]
+    pub validator_set_id: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct CommitmentSignature {
+    /// The index of the authority in its set.
+    #[prost(uint32, tag = "1")]
+    pub authority_index: u32,
+    /// The recoverable secp256k1 signature, serialized as `r || s || v`.
+    #[prost(bytes = "vec", tag = "2")]
+    pub signature: Vec<u8>,
+    /// The proof of the authority address against the keyset commitment.
+    #[prost(bytes = "vec", repeated, tag = "3")]
+    pub authority_proof: Vec<Vec<u8>>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SignedCommitment {
+    #[prost(message, optional, tag = "1")]
+    pub commitment: Option<Commitment>,
+    #[prost(message, repeated, tag = "2")]
+    pub signatures: Vec<CommitmentSignature>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MmrLeaf {
+    #[prost(uint32, tag = "1")]
+    pub version: u32,
+    #[prost(uint32, tag = "2")]
+    pub parent_number: u32,
+    #[prost(bytes = "vec", tag = "3")]
+    pub parent_hash: Vec<u8>,
+    #[prost(message, optional, tag = "4")]
+    pub beefy_next_authority_set: Option<AuthoritySet>,
+    /// The root of the binary Merkle tree of the parachain heads.
+    #[prost(bytes = "vec", tag = "5")]
+    pub parachain_heads_root: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ParachainHeader {
+    /// The SCALE-encoded parachain header, i.e. the head data of the
+    /// parachain.
+    #[prost(bytes = "vec", tag = "1")]
+    pub header: Vec<u8>,
+    #[prost(uint32, tag = "2")]
+    pub heads_leaf_index: u32,
+    #[prost(uint32, tag = "3")]
+    pub heads_leaf_count: u32,
+    /// The proof of the head against the parachain heads root.
+    #[prost(bytes = "vec", repeated, tag = "4")]
+    pub heads_proof: Vec<Vec<u8>>,
+    /// The storage proof of the timestamp against the parachain state root.
+    #[prost(bytes = "vec", repeated, tag = "5")]
+    pub timestamp_proof: Vec<Vec<u8>>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Header {
+    #[prost(message, optional, tag = "1")]
+    pub signed_commitment: Option<SignedCommitment>,
+    #[prost(message, optional, tag = "2")]
+    pub mmr_leaf: Option<MmrLeaf>,
+    /// The proof of the MMR leaf against the committed MMR root.
+    #[prost(bytes = "vec", repeated, tag = "3")]
+    pub mmr_proof: Vec<Vec<u8>>,
+    #[prost(message, optional, tag = "4")]
+    pub parachain_header: Option<ParachainHeader>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Misbehaviour {
+    #[prost(message, optional, tag = "1")]
+    pub commitment_1: Option<SignedCommitment>,
+    #[prost(message, optional, tag = "2")]
+    pub commitment_2: Option<SignedCommitment>,
+}
+
+/// The proof of a parachain storage entry: the trie nodes visited to look
+/// up its key.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct StorageProof {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub proof: Vec<Vec<u8>>,
+}
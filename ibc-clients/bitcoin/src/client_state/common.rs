@@ -0,0 +1,154 @@
+use cosmwasm_std::HexBinary;
+use ibc_core::client::context::client_state::ClientStateCommon;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Height;
+use ibc_core::commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::host::types::identifiers::ClientType;
+use ibc_core::host::types::path::{Path, PathBytes};
+use ibc_core::primitives::proto::{Any, Protobuf};
+use ibc_core::primitives::Timestamp;
+
+use super::ClientState;
+use crate::consensus_state::ConsensusState;
+use crate::error::BitcoinError;
+use crate::types::proto::TransactionProof as RawTransactionProof;
+use crate::types::{double_sha256, Bytes32, TransactionProof};
+
+pub const BITCOIN_CLIENT_TYPE: &str = "bitcoin";
+
+/// The size of a transaction that can be mistaken for an inner node of a
+/// transaction Merkle tree.
+const AMBIGUOUS_TRANSACTION_SIZE: usize = 64;
+
+impl ClientStateCommon for ClientState {
+    /// Bitcoin consensus states do not expire: the client follows the chain
+    /// with the most work, regardless of the age of its blocks.
+    fn verify_consensus_state(
+        &self,
+        consensus_state: Any,
+        _host_timestamp: &Timestamp,
+    ) -> Result<(), ClientError> {
+        ConsensusState::try_from(consensus_state)?;
+
+        Ok(())
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::new(BITCOIN_CLIENT_TYPE).expect("valid client type")
+    }
+
+    fn latest_height(&self) -> Height {
+        self.latest_height
+    }
+
+    fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError> {
+        if self.latest_height < proof_height {
+            return Err(ClientError::InsufficientProofHeight {
+                actual: self.latest_height,
+                expected: proof_height,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn serialize_path(&self, path: Path) -> Result<PathBytes, ClientError> {
+        Ok(path.to_string().into_bytes().into())
+    }
+
+    /// Upgrades are not supported by this client: Bitcoin soft forks keep
+    /// the header chain valid, and a change of the client parameters goes
+    /// through a client recovery.
+    fn verify_upgrade_client(
+        &self,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+        _proof_upgrade_client: CommitmentProofBytes,
+        _proof_upgrade_consensus_state: CommitmentProofBytes,
+        _root: &CommitmentRoot,
+    ) -> Result<(), ClientError> {
+        Err(ClientError::ClientSpecific {
+            description: "upgrades are not supported by the Bitcoin client".to_string(),
+        })
+    }
+
+    /// Verifies the inclusion of a transaction in the block committed by
+    /// `root`. The path is the transaction ID, hex-encoded in the usual
+    /// reversed byte order, the value is the transaction serialized without
+    /// its witness data, and the proof is an encoded `TransactionProof`. The
+    /// prefix is ignored, as Bitcoin has no IBC store.
+    fn verify_membership_raw(
+        &self,
+        _prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: PathBytes,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        let (merkle_root, height) = ConsensusState::decode_commitment_root(root)?;
+
+        let confirmations = self.confirmations_of(height);
+
+        if confirmations < self.confirmations {
+            return Err(BitcoinError::InsufficientConfirmations {
+                height,
+                confirmations,
+                required: self.confirmations,
+            }
+            .into());
+        }
+
+        let txid = decode_txid(&path)?;
+
+        // 64-byte transactions could be passed off as inner nodes of the
+        // Merkle tree, see CVE-2017-12842.
+        if value.len() == AMBIGUOUS_TRANSACTION_SIZE || double_sha256(&value) != txid {
+            return Err(BitcoinError::MismatchedTransactionId {
+                txid: String::from_utf8_lossy(path.as_ref()).into_owned(),
+            }
+            .into());
+        }
+
+        let proof: TransactionProof =
+            Protobuf::<RawTransactionProof>::decode(proof.as_ref()).map_err(DecodingError::from)?;
+
+        if proof.merkle_root(&txid)? != merkle_root {
+            return Err(BitcoinError::InvalidMerkleBranch.into());
+        }
+
+        Ok(())
+    }
+
+    /// The absence of a transaction cannot be proven with SPV proofs.
+    fn verify_non_membership_raw(
+        &self,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        _path: PathBytes,
+    ) -> Result<(), ClientError> {
+        Err(ClientError::ClientSpecific {
+            description: "non-membership proofs are not supported by the Bitcoin client"
+                .to_string(),
+        })
+    }
+}
+
+/// Decodes a transaction ID from its hex encoding, in which the bytes are
+/// reversed with respect to the internal byte order.
+pub fn decode_txid(path: &PathBytes) -> Result<Bytes32, DecodingError> {
+    let hex = core::str::from_utf8(path.as_ref())
+        .map_err(|e| DecodingError::invalid_raw_data(format!("transaction ID: {e}")))?;
+
+    let mut txid: Bytes32 = HexBinary::from_hex(hex)
+        .map_err(|e| DecodingError::invalid_raw_data(format!("transaction ID: {e}")))?
+        .to_array()
+        .map_err(|e| DecodingError::invalid_raw_data(format!("transaction ID: {e}")))?;
+
+    txid.reverse();
+
+    Ok(txid)
+}
@@ -0,0 +1,225 @@
+use ibc_core::client::context::client_state::{ClientStateCommon, ClientStateExecution};
+use ibc_core::client::context::prelude::*;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Height;
+use ibc_core::host::types::identifiers::ClientId;
+use ibc_core::host::types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc_core::primitives::proto::Any;
+
+use super::{fork_height, ClientState, BITCOIN_REVISION_NUMBER};
+use crate::consensus_state::ConsensusState;
+use crate::types::{Header, BITCOIN_HEADER_TYPE_URL};
+
+impl<E> ClientStateExecution<E> for ClientState
+where
+    E: ExtClientExecutionContext,
+    E::ClientStateRef: From<Self>,
+    ConsensusState: Convertible<E::ConsensusStateRef>,
+    <ConsensusState as TryFrom<E::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    fn initialise(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        let consensus_state: ConsensusState = consensus_state.try_into()?;
+
+        if consensus_state.height != self.latest_height.revision_height() {
+            return Err(ClientError::ClientSpecific {
+                description: format!(
+                    "consensus state height `{}` does not match the latest height `{}`",
+                    consensus_state.height, self.latest_height
+                ),
+            });
+        }
+
+        ctx.store_client_state(ClientStatePath::new(client_id.clone()), self.clone().into())?;
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                client_id.clone(),
+                self.latest_height.revision_number(),
+                self.latest_height.revision_height(),
+            ),
+            consensus_state.into(),
+        )?;
+        ctx.store_update_meta(
+            client_id.clone(),
+            self.latest_height,
+            ctx.host_timestamp()?,
+            ctx.host_height()?,
+        )?;
+
+        Ok(())
+    }
+
+    /// Installs the consensus states of the blocks above the fork point of
+    /// the headers, after removing those of the blocks they replace. The
+    /// tip of the headers becomes the latest height of the client.
+    fn update_state(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        let header = match client_message.type_url.as_str() {
+            BITCOIN_HEADER_TYPE_URL => Header::try_from(client_message)?,
+            header_type => return Err(ClientError::InvalidHeaderType(header_type.to_owned())),
+        };
+
+        let chain = self.header_chain(ctx, client_id, &header)?;
+        let fork_height = fork_height(ctx, client_id, &header)?;
+
+        let new_latest_height = header.height();
+
+        if fork_height == new_latest_height.revision_height() {
+            // if we already had the headers installed by a previous relayer
+            // then this is a no-op.
+            return Ok(vec![new_latest_height]);
+        }
+
+        // remove the blocks of the replaced branch, if any
+        prune_consensus_states(ctx, client_id, |height| {
+            height.revision_height() > fork_height
+        })?;
+
+        let mut heights = vec![];
+
+        for consensus_state in chain {
+            if consensus_state.height <= fork_height {
+                continue;
+            }
+
+            let height = Height::new(BITCOIN_REVISION_NUMBER, consensus_state.height)?;
+
+            ctx.store_consensus_state(
+                ClientConsensusStatePath::new(
+                    client_id.clone(),
+                    height.revision_number(),
+                    height.revision_height(),
+                ),
+                consensus_state.into(),
+            )?;
+            ctx.store_update_meta(
+                client_id.clone(),
+                height,
+                ctx.host_timestamp()?,
+                ctx.host_height()?,
+            )?;
+
+            heights.push(height);
+        }
+
+        let new_client_state = Self {
+            latest_height: new_latest_height,
+            ..self.clone()
+        };
+
+        ctx.store_client_state(
+            ClientStatePath::new(client_id.clone()),
+            new_client_state.into(),
+        )?;
+
+        Ok(heights)
+    }
+
+    fn update_state_on_misbehaviour(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        _client_message: Any,
+    ) -> Result<(), ClientError> {
+        let frozen_client_state = Self {
+            frozen_height: Some(Height::min(0)),
+            ..self.clone()
+        };
+
+        ctx.store_client_state(
+            ClientStatePath::new(client_id.clone()),
+            frozen_client_state.into(),
+        )?;
+
+        Ok(())
+    }
+
+    fn update_state_on_upgrade(
+        &self,
+        _ctx: &mut E,
+        _client_id: &ClientId,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        Err(ClientError::ClientSpecific {
+            description: "upgrades are not supported by the Bitcoin client".to_string(),
+        })
+    }
+
+    /// Restarts the subject client from the tip of the substitute client. The
+    /// consensus states of the subject are removed, as they may belong to a
+    /// branch replaced by the chain of the substitute.
+    fn update_on_recovery(
+        &self,
+        ctx: &mut E,
+        subject_client_id: &ClientId,
+        substitute_client_state: Any,
+        substitute_consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        let substitute_client_state = Self::try_from(substitute_client_state)?;
+        let new_consensus_state: ConsensusState = substitute_consensus_state.try_into()?;
+
+        let new_client_state = Self {
+            latest_height: substitute_client_state.latest_height,
+            frozen_height: None,
+            ..self.clone()
+        };
+
+        let latest_height = new_client_state.latest_height();
+
+        prune_consensus_states(ctx, subject_client_id, |height| height != latest_height)?;
+
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                subject_client_id.clone(),
+                latest_height.revision_number(),
+                latest_height.revision_height(),
+            ),
+            new_consensus_state.into(),
+        )?;
+        ctx.store_client_state(
+            ClientStatePath::new(subject_client_id.clone()),
+            new_client_state.into(),
+        )?;
+        ctx.store_update_meta(
+            subject_client_id.clone(),
+            latest_height,
+            ctx.host_timestamp()?,
+            ctx.host_height()?,
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Removes the consensus states, along with their update metadata, stored at
+/// the heights matching the predicate.
+fn prune_consensus_states<E>(
+    ctx: &mut E,
+    client_id: &ClientId,
+    predicate: impl Fn(Height) -> bool,
+) -> Result<(), ClientError>
+where
+    E: ExtClientExecutionContext,
+{
+    for height in ctx.consensus_state_heights(client_id)? {
+        if predicate(height) {
+            ctx.delete_consensus_state(ClientConsensusStatePath::new(
+                client_id.clone(),
+                height.revision_number(),
+                height.revision_height(),
+            ))?;
+            ctx.delete_update_meta(client_id.clone(), height)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+//! Implements the Bitcoin `ClientState` along with the `ClientStateCommon`,
+//! `ClientStateValidation` and `ClientStateExecution` traits.
+use ibc_core::client::types::Height;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::proto::{Any, Protobuf};
+
+use crate::types::proto::ClientState as RawClientState;
+use crate::types::ChainParams;
+
+mod common;
+mod execution;
+mod validation;
+
+pub use common::*;
+pub use validation::*;
+
+pub const BITCOIN_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.bitcoin.v1.ClientState";
+
+/// Bitcoin has no revisions: all the heights of the client are block heights
+/// under revision zero.
+pub const BITCOIN_REVISION_NUMBER: u64 = 0;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientState {
+    pub chain_params: ChainParams,
+    /// The number of blocks, including its own, a block needs on top of the
+    /// chain before transactions can be proven against it.
+    pub confirmations: u64,
+    /// The height of the tip of the tracked chain.
+    pub latest_height: Height,
+    pub frozen_height: Option<Height>,
+}
+
+impl ClientState {
+    pub fn is_frozen(&self) -> bool {
+        self.frozen_height.is_some()
+    }
+
+    /// Returns the number of confirmations of the block at `height`, i.e.
+    /// zero if it is above the tip and one if it is the tip.
+    pub fn confirmations_of(&self, height: u64) -> u64 {
+        (self.latest_height.revision_height() + 1).saturating_sub(height)
+    }
+
+    fn validate(&self) -> Result<(), DecodingError> {
+        if self.confirmations == 0 {
+            return Err(DecodingError::invalid_raw_data(
+                "confirmations must be non-zero",
+            ));
+        }
+
+        if self.latest_height.revision_number() != BITCOIN_REVISION_NUMBER {
+            return Err(DecodingError::invalid_raw_data(format!(
+                "latest height must have revision number {BITCOIN_REVISION_NUMBER}"
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        let client_state = Self {
+            chain_params: raw
+                .chain_params
+                .ok_or(DecodingError::missing_raw_data("chain params"))?
+                .try_into()?,
+            confirmations: raw.confirmations,
+            latest_height: raw
+                .latest_height
+                .ok_or(DecodingError::missing_raw_data("latest height"))?
+                .try_into()?,
+            frozen_height: raw.frozen_height.map(TryInto::try_into).transpose()?,
+        };
+
+        client_state.validate()?;
+
+        Ok(client_state)
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            chain_params: Some(value.chain_params.into()),
+            confirmations: value.confirmations,
+            latest_height: Some(value.latest_height.into()),
+            frozen_height: value.frozen_height.map(Into::into),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let BITCOIN_CLIENT_STATE_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawClientState>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: BITCOIN_CLIENT_STATE_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Self {
+            type_url: BITCOIN_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientState>::encode_vec(client_state),
+        }
+    }
+}
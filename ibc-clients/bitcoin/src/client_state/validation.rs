@@ -0,0 +1,312 @@
+use ibc_core::client::context::client_state::ClientStateValidation;
+use ibc_core::client::context::prelude::*;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::{Height, Status};
+use ibc_core::host::types::identifiers::ClientId;
+use ibc_core::host::types::path::ClientConsensusStatePath;
+use ibc_core::primitives::proto::Any;
+
+use super::{ClientState, BITCOIN_REVISION_NUMBER};
+use crate::consensus_state::ConsensusState;
+use crate::error::BitcoinError;
+use crate::types::{block_work, Header, BITCOIN_HEADER_TYPE_URL};
+
+/// The number of blocks whose median time a new block must exceed.
+pub const MEDIAN_TIME_SPAN: usize = 11;
+
+/// How far in the future of the host a block time may be, in seconds.
+pub const MAX_FUTURE_BLOCK_TIME: u64 = 2 * 60 * 60;
+
+impl<V> ClientStateValidation<V> for ClientState
+where
+    V: ExtClientValidationContext,
+    ConsensusState: Convertible<V::ConsensusStateRef>,
+    <ConsensusState as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    fn verify_client_message(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        match client_message.type_url.as_str() {
+            BITCOIN_HEADER_TYPE_URL => {
+                let header = Header::try_from(client_message)?;
+                self.verify_header(ctx, client_id, &header)
+            }
+            header_type => Err(ClientError::InvalidHeaderType(header_type.to_owned())),
+        }
+    }
+
+    /// A valid batch of headers is a misbehaviour if it reorganizes blocks
+    /// with the required number of confirmations, against which proofs may
+    /// already have been verified.
+    fn check_for_misbehaviour(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        match client_message.type_url.as_str() {
+            BITCOIN_HEADER_TYPE_URL => {
+                let header = Header::try_from(client_message)?;
+                let fork_height = fork_height(ctx, client_id, &header)?;
+
+                Ok(fork_height < header.height().revision_height()
+                    && self.confirmations_of(fork_height + 1) >= self.confirmations)
+            }
+            header_type => Err(ClientError::InvalidHeaderType(header_type.to_owned())),
+        }
+    }
+
+    fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError> {
+        if self.is_frozen() {
+            return Ok(Status::Frozen);
+        }
+
+        // if the client state does not have an associated consensus state for
+        // its latest height then it must be expired
+        if consensus_state_at(ctx, client_id, self.latest_height).is_err() {
+            return Ok(Status::Expired);
+        }
+
+        Ok(Status::Active)
+    }
+
+    /// The subject and substitute client states match if they track the same
+    /// network with the same confirmation depth.
+    fn check_substitute(&self, _ctx: &V, substitute_client_state: Any) -> Result<(), ClientError> {
+        let substitute_client_state = Self::try_from(substitute_client_state)?;
+
+        let normalized = Self {
+            latest_height: self.latest_height,
+            frozen_height: self.frozen_height,
+            ..substitute_client_state
+        };
+
+        (&normalized == self)
+            .then_some(())
+            .ok_or(ClientError::FailedToVerifyClientRecoveryStates)
+    }
+}
+
+impl ClientState {
+    /// Verifies that the headers are valid and lead to a chain with more work
+    /// than the tracked one, unless they are already stored.
+    pub fn verify_header<V>(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        header: &Header,
+    ) -> Result<(), ClientError>
+    where
+        V: ExtClientValidationContext,
+        ConsensusState: Convertible<V::ConsensusStateRef>,
+        <ConsensusState as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+    {
+        let chain = self.header_chain(ctx, client_id, header)?;
+
+        let Some(new_tip) = chain.last() else {
+            return Err(BitcoinError::EmptyHeaders.into());
+        };
+
+        if fork_height(ctx, client_id, header)? == new_tip.height {
+            // the headers were already submitted by a previous relayer.
+            return Ok(());
+        }
+
+        let tip = consensus_state_at(ctx, client_id, self.latest_height)?;
+
+        if new_tip.chain_work <= tip.chain_work {
+            return Err(BitcoinError::InsufficientChainWork {
+                height: new_tip.height,
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
+    /// Validates the headers on top of the stored block at the trusted
+    /// height, and returns the consensus states of the resulting chain.
+    ///
+    /// Each header must extend the previous block, carry the target required
+    /// by the difficulty adjustment rules and meet it, and have a time after
+    /// the median time of the previous blocks and not too far in the future
+    /// of the host.
+    pub fn header_chain<V>(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        header: &Header,
+    ) -> Result<Vec<ConsensusState>, ClientError>
+    where
+        V: ExtClientValidationContext,
+        ConsensusState: Convertible<V::ConsensusStateRef>,
+        <ConsensusState as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+    {
+        let mut parent = consensus_state_at(ctx, client_id, header.trusted_height)?;
+        let mut recent_times = recent_times(ctx, client_id, &parent)?;
+
+        let pow_limit = self.chain_params.pow_limit_target()?;
+        let host_time = ctx.host_timestamp()?.nanoseconds() / 1_000_000_000;
+
+        let mut chain = Vec::with_capacity(header.headers.len());
+
+        for block in &header.headers {
+            let height = parent.height + 1;
+
+            if block.prev_block_hash != parent.block_hash() {
+                return Err(BitcoinError::DisconnectedHeader { height }.into());
+            }
+
+            let expected = self.chain_params.next_bits(
+                height,
+                parent.header.bits,
+                parent.header.time,
+                parent.epoch_start_time,
+            )?;
+
+            if block.bits != expected {
+                return Err(BitcoinError::InvalidDifficulty {
+                    height,
+                    bits: block.bits,
+                    expected,
+                }
+                .into());
+            }
+
+            let target = block.target()?;
+
+            if target > pow_limit || !block.meets_target()? {
+                return Err(BitcoinError::InsufficientProofOfWork { height }.into());
+            }
+
+            let median_time_past = median(&recent_times);
+
+            if block.time <= median_time_past {
+                return Err(BitcoinError::BlockTimeTooOld {
+                    height,
+                    time: block.time,
+                    median_time_past,
+                }
+                .into());
+            }
+
+            if u64::from(block.time) > host_time + MAX_FUTURE_BLOCK_TIME {
+                return Err(BitcoinError::BlockTimeTooNew {
+                    height,
+                    time: block.time,
+                    host_time,
+                }
+                .into());
+            }
+
+            let epoch_start_time = if self.chain_params.is_retarget_height(height) {
+                block.time
+            } else {
+                parent.epoch_start_time
+            };
+
+            let consensus_state = ConsensusState::new(
+                *block,
+                height,
+                parent.chain_work.saturating_add(block_work(target)),
+                epoch_start_time,
+            );
+
+            if recent_times.len() == MEDIAN_TIME_SPAN {
+                recent_times.remove(0);
+            }
+            recent_times.push(block.time);
+
+            chain.push(consensus_state.clone());
+            parent = consensus_state;
+        }
+
+        Ok(chain)
+    }
+}
+
+/// Returns the height of the last block of the headers, or of the trusted
+/// block, that is already stored: the headers above it fork from the tracked
+/// chain.
+pub fn fork_height<V>(ctx: &V, client_id: &ClientId, header: &Header) -> Result<u64, ClientError>
+where
+    V: ExtClientValidationContext,
+    ConsensusState: Convertible<V::ConsensusStateRef>,
+    <ConsensusState as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    let mut fork_height = header.trusted_height.revision_height();
+
+    for block in &header.headers {
+        let height = Height::new(BITCOIN_REVISION_NUMBER, fork_height + 1)?;
+
+        match consensus_state_at(ctx, client_id, height) {
+            Ok(stored) if stored.header == *block => fork_height += 1,
+            _ => break,
+        }
+    }
+
+    Ok(fork_height)
+}
+
+/// Returns the times of the stored ancestors of a block, up to the block
+/// itself, following the links between their hashes. Fewer than
+/// [`MEDIAN_TIME_SPAN`] times are returned close to the first stored block.
+fn recent_times<V>(
+    ctx: &V,
+    client_id: &ClientId,
+    block: &ConsensusState,
+) -> Result<Vec<u32>, ClientError>
+where
+    V: ExtClientValidationContext,
+    ConsensusState: Convertible<V::ConsensusStateRef>,
+    <ConsensusState as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    let mut times = vec![block.header.time];
+    let mut child = block.clone();
+
+    while times.len() < MEDIAN_TIME_SPAN && child.height > 1 {
+        let height = Height::new(BITCOIN_REVISION_NUMBER, child.height - 1)?;
+
+        match consensus_state_at(ctx, client_id, height) {
+            Ok(parent) if parent.block_hash() == child.header.prev_block_hash => {
+                times.push(parent.header.time);
+                child = parent;
+            }
+            _ => break,
+        }
+    }
+
+    times.reverse();
+
+    Ok(times)
+}
+
+fn median(times: &[u32]) -> u32 {
+    let mut sorted = times.to_vec();
+    sorted.sort_unstable();
+
+    sorted[sorted.len() / 2]
+}
+
+/// Fetches the consensus state stored for the client at the given height.
+pub fn consensus_state_at<V>(
+    ctx: &V,
+    client_id: &ClientId,
+    height: Height,
+) -> Result<ConsensusState, ClientError>
+where
+    V: ExtClientValidationContext,
+    ConsensusState: Convertible<V::ConsensusStateRef>,
+    <ConsensusState as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    ctx.consensus_state(&ClientConsensusStatePath::new(
+        client_id.clone(),
+        height.revision_number(),
+        height.revision_height(),
+    ))?
+    .try_into()
+    .map_err(Into::into)
+}
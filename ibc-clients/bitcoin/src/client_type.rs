@@ -0,0 +1,13 @@
+use ibc_client_cw::api::ClientType;
+
+use crate::client_state::ClientState;
+use crate::consensus_state::ConsensusState;
+
+/// A unit struct that represents the Bitcoin SPV client type.
+#[derive(Clone, Debug)]
+pub struct BitcoinClient;
+
+impl ClientType<'_> for BitcoinClient {
+    type ClientState = ClientState;
+    type ConsensusState = ConsensusState;
+}
@@ -0,0 +1,136 @@
+//! Implements the Bitcoin `ConsensusState`. Each consensus state is keyed by
+//! a block height and records the header of the block at that height, along
+//! with the chain data needed to validate the blocks built upon it.
+use cosmwasm_std::Uint256;
+use ibc_core::client::context::consensus_state::ConsensusState as ConsensusStateTrait;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::commitment_types::commitment::CommitmentRoot;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::proto::{Any, Protobuf};
+use ibc_core::primitives::Timestamp;
+
+use crate::types::proto::ConsensusState as RawConsensusState;
+use crate::types::{fixed_bytes, BlockHeader, Bytes32};
+
+pub const BITCOIN_CONSENSUS_STATE_TYPE_URL: &str = "/ibc.lightclients.bitcoin.v1.ConsensusState";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusState {
+    pub header: BlockHeader,
+    pub height: u64,
+    /// The total work of the chain up to the block.
+    pub chain_work: Uint256,
+    /// The time of the first block of the difficulty adjustment interval of
+    /// the block.
+    pub epoch_start_time: u32,
+    /// The transaction Merkle root of the block followed by its height, see
+    /// [`ConsensusState::commitment_root`].
+    root: CommitmentRoot,
+}
+
+impl ConsensusState {
+    pub fn new(
+        header: BlockHeader,
+        height: u64,
+        chain_work: Uint256,
+        epoch_start_time: u32,
+    ) -> Self {
+        Self {
+            root: Self::commitment_root(&header.merkle_root, height),
+            header,
+            height,
+            chain_work,
+            epoch_start_time,
+        }
+    }
+
+    /// Returns the commitment root of the block at `height`. The height is
+    /// committed along with the transaction Merkle root so that proofs can be
+    /// checked against the confirmation depth of the block.
+    pub fn commitment_root(merkle_root: &Bytes32, height: u64) -> CommitmentRoot {
+        [merkle_root.as_slice(), &height.to_be_bytes()]
+            .concat()
+            .into()
+    }
+
+    /// Splits a commitment root into the transaction Merkle root and the
+    /// height of its block.
+    pub fn decode_commitment_root(root: &CommitmentRoot) -> Result<(Bytes32, u64), DecodingError> {
+        let root: [u8; 40] = fixed_bytes("commitment root", root.as_bytes().to_vec())?;
+
+        let (merkle_root, height) = root.split_at(32);
+
+        Ok((
+            merkle_root.try_into().expect("32 bytes"),
+            u64::from_be_bytes(height.try_into().expect("8 bytes")),
+        ))
+    }
+
+    pub fn block_hash(&self) -> Bytes32 {
+        self.header.block_hash()
+    }
+}
+
+impl ConsensusStateTrait for ConsensusState {
+    fn root(&self) -> &CommitmentRoot {
+        &self.root
+    }
+
+    fn timestamp(&self) -> Result<Timestamp, ClientError> {
+        Ok(Timestamp::from_nanoseconds(
+            u64::from(self.header.time) * 1_000_000_000,
+        ))
+    }
+}
+
+impl Protobuf<RawConsensusState> for ConsensusState {}
+
+impl TryFrom<RawConsensusState> for ConsensusState {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawConsensusState) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            BlockHeader::from_bytes(&raw.header)?,
+            raw.height,
+            Uint256::from_be_bytes(fixed_bytes("chain work", raw.chain_work)?),
+            raw.epoch_start_time,
+        ))
+    }
+}
+
+impl From<ConsensusState> for RawConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        Self {
+            header: value.header.to_bytes().to_vec(),
+            height: value.height,
+            chain_work: value.chain_work.to_be_bytes().to_vec(),
+            epoch_start_time: value.epoch_start_time,
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let BITCOIN_CONSENSUS_STATE_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawConsensusState>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: BITCOIN_CONSENSUS_STATE_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Self {
+            type_url: BITCOIN_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawConsensusState>::encode_vec(consensus_state),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//! Defines the error type of the Bitcoin SPV light client.
+use derive_more::{Display, From};
+use ibc_core::client::types::error::ClientError;
+use ibc_core::host::types::error::DecodingError;
+
+#[derive(From, Display, Debug)]
+pub enum BitcoinError {
+    #[from]
+    #[display("decoding error: {_0}")]
+    Decoding(DecodingError),
+    #[display("empty batch of block headers")]
+    EmptyHeaders,
+    #[display("block header at height `{height}` does not extend the previous block")]
+    DisconnectedHeader { height: u64 },
+    #[display("invalid compact target `{bits:#010x}`")]
+    InvalidCompactTarget { bits: u32 },
+    #[display(
+        "invalid difficulty `{bits:#010x}` at height `{height}`, expected `{expected:#010x}`"
+    )]
+    InvalidDifficulty {
+        height: u64,
+        bits: u32,
+        expected: u32,
+    },
+    #[display("insufficient proof of work for the block at height `{height}`")]
+    InsufficientProofOfWork { height: u64 },
+    #[display(
+        "block time `{time}` at height `{height}` is not after the median time past `{median_time_past}`"
+    )]
+    BlockTimeTooOld {
+        height: u64,
+        time: u32,
+        median_time_past: u32,
+    },
+    #[display(
+        "block time `{time}` at height `{height}` is too far after the host time `{host_time}`"
+    )]
+    BlockTimeTooNew {
+        height: u64,
+        time: u32,
+        host_time: u64,
+    },
+    #[display("block at height `{height}` does not have more chain work than the current tip")]
+    InsufficientChainWork { height: u64 },
+    #[display(
+        "block at height `{height}` has `{confirmations}` confirmations, `{required}` required"
+    )]
+    InsufficientConfirmations {
+        height: u64,
+        confirmations: u64,
+        required: u64,
+    },
+    #[display("transaction does not match the transaction ID `{txid}`")]
+    MismatchedTransactionId { txid: String },
+    #[display("Merkle branch does not lead to the transaction Merkle root of the block")]
+    InvalidMerkleBranch,
+}
+
+impl From<BitcoinError> for ClientError {
+    fn from(e: BitcoinError) -> Self {
+        Self::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+//! The CosmWasm contract implementation of a Bitcoin SPV light client. The
+//! client follows the Bitcoin header chain with the most accumulated work:
+//! batches of block headers are checked for proof of work, difficulty
+//! adjustments and timestamps, and every accepted header installs a
+//! consensus state at its block height, so that a heavier fork can replace
+//! the most recent blocks. The inclusion of transactions is verified with
+//! Merkle branches against the transaction Merkle root of blocks having the
+//! required number of confirmations, and a fork replacing confirmed blocks
+//! freezes the client.
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+
+pub mod client_state;
+pub mod client_type;
+pub mod consensus_state;
+pub mod entrypoint;
+pub mod error;
+pub mod types;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,331 @@
+use std::time::Duration;
+
+use ibc_client_cw::types::{MerklePath, VerifyMembershipMsgRaw, VerifyNonMembershipMsgRaw};
+use ibc_client_cw::utils::AnyCodec;
+use ibc_client_cw_testkit::api::TestClient;
+use ibc_core::client::types::Height;
+use ibc_core::primitives::proto::Protobuf;
+
+use crate::client_state::{ClientState, BITCOIN_REVISION_NUMBER};
+use crate::client_type::BitcoinClient;
+use crate::consensus_state::ConsensusState;
+use crate::tests::helper::{
+    decode_header, display_hash, merkle_branch, merkle_root, mine, MAINNET_HEADERS,
+    REGTEST_GENESIS_HEADER,
+};
+use crate::types::proto::TransactionProof as RawTransactionProof;
+use crate::types::{
+    block_work, double_sha256, BlockHeader, Bytes32, ChainParams, Header, TransactionProof,
+};
+
+/// The number of transactions of the mined blocks.
+const TRANSACTIONS_PER_BLOCK: usize = 3;
+
+/// A block of the test chain, along with its transactions.
+#[derive(Clone, Debug)]
+pub struct TestBlock {
+    pub consensus_state: ConsensusState,
+    pub transactions: Vec<Vec<u8>>,
+}
+
+impl TestBlock {
+    /// Returns the block at height zero.
+    pub fn genesis(header: BlockHeader) -> Self {
+        Self {
+            consensus_state: ConsensusState::new(
+                header,
+                0,
+                block_work(header.target().expect("valid target")),
+                header.time,
+            ),
+            transactions: vec![],
+        }
+    }
+
+    /// Returns the child of the block with the given header, deriving its
+    /// chain data.
+    pub fn child(
+        &self,
+        params: &ChainParams,
+        header: BlockHeader,
+        transactions: Vec<Vec<u8>>,
+    ) -> Self {
+        let parent = &self.consensus_state;
+        let height = parent.height + 1;
+
+        let epoch_start_time = if params.is_retarget_height(height) {
+            header.time
+        } else {
+            parent.epoch_start_time
+        };
+
+        Self {
+            consensus_state: ConsensusState::new(
+                header,
+                height,
+                parent.chain_work + block_work(header.target().expect("valid target")),
+                epoch_start_time,
+            ),
+            transactions,
+        }
+    }
+
+    pub fn header(&self) -> BlockHeader {
+        self.consensus_state.header
+    }
+
+    pub fn height(&self) -> u64 {
+        self.consensus_state.height
+    }
+
+    pub fn txids(&self) -> Vec<Bytes32> {
+        self.transactions.iter().map(double_sha256).collect()
+    }
+}
+
+/// Test fixture of the Bitcoin client.
+pub type Fixture = ibc_client_cw_testkit::fixture::Fixture<BitcoinClient>;
+
+/// A Bitcoin network, whose blocks are mined at the regtest difficulty on
+/// top of the regtest genesis block.
+#[derive(Clone, Debug)]
+pub struct BitcoinConfig {
+    pub chain_params: ChainParams,
+    pub confirmations: u64,
+    /// The time between two mined blocks, in seconds.
+    pub block_time: u32,
+    /// The blocks of the tracked chain, indexed by height.
+    pub chain: Vec<TestBlock>,
+}
+
+impl Default for BitcoinConfig {
+    fn default() -> Self {
+        Self::new(ChainParams::regtest(), 600, 40)
+    }
+}
+
+impl BitcoinConfig {
+    /// Creates a chain of `length` blocks mined on top of the regtest
+    /// genesis block.
+    pub fn new(chain_params: ChainParams, block_time: u32, length: usize) -> Self {
+        let mut config = Self {
+            chain_params,
+            confirmations: 3,
+            block_time,
+            chain: vec![TestBlock::genesis(decode_header(REGTEST_GENESIS_HEADER))],
+        };
+
+        config.chain = [
+            config.chain.clone(),
+            config.mine(&config.chain[0], length, "main"),
+        ]
+        .concat();
+
+        config
+    }
+
+    /// Creates a chain made of the recorded first blocks of the Bitcoin main
+    /// network.
+    pub fn mainnet() -> Self {
+        let chain_params = ChainParams::mainnet();
+
+        let mut chain = vec![TestBlock::genesis(decode_header(MAINNET_HEADERS[0]))];
+
+        for header in &MAINNET_HEADERS[1..] {
+            let block = chain[chain.len() - 1].child(&chain_params, decode_header(header), vec![]);
+            chain.push(block);
+        }
+
+        Self {
+            chain_params,
+            confirmations: 1,
+            block_time: 600,
+            chain,
+        }
+    }
+
+    // ------------------- Mining -------------------
+
+    /// Mines a block on top of `parent` with the given time, whose
+    /// transactions are labelled by `branch`.
+    pub fn mine_block(&self, parent: &TestBlock, branch: &str, time: u32) -> TestBlock {
+        let height = parent.height() + 1;
+
+        let transactions: Vec<Vec<u8>> = (0..TRANSACTIONS_PER_BLOCK)
+            .map(|i| format!("{branch}-transaction-{height}-{i}").into_bytes())
+            .collect();
+
+        let bits = self
+            .chain_params
+            .next_bits(
+                height,
+                parent.header().bits,
+                parent.header().time,
+                parent.consensus_state.epoch_start_time,
+            )
+            .expect("valid target");
+
+        let header = mine(BlockHeader {
+            version: 0x2000_0000,
+            prev_block_hash: parent.consensus_state.block_hash(),
+            merkle_root: merkle_root(&transactions.iter().map(double_sha256).collect::<Vec<_>>()),
+            time,
+            bits,
+            nonce: 0,
+        });
+
+        parent.child(&self.chain_params, header, transactions)
+    }
+
+    /// Mines `count` consecutive blocks on top of `parent`.
+    pub fn mine(&self, parent: &TestBlock, count: usize, branch: &str) -> Vec<TestBlock> {
+        let mut blocks: Vec<TestBlock> = vec![];
+
+        for _ in 0..count {
+            let parent = blocks.last().unwrap_or(parent);
+            let block = self.mine_block(parent, branch, parent.header().time + self.block_time);
+            blocks.push(block);
+        }
+
+        blocks
+    }
+
+    /// Mines `count` blocks forking from the tracked chain at `height`.
+    pub fn fork(&self, height: u64, count: usize, branch: &str) -> Vec<TestBlock> {
+        self.mine(&self.chain[height as usize], count, branch)
+    }
+
+    // ------------------- Client messages -------------------
+
+    /// Returns the header carrying the given blocks on top of the block at
+    /// `trusted_height`.
+    pub fn header(&self, trusted_height: u64, blocks: &[TestBlock]) -> Header {
+        Header {
+            trusted_height: BitcoinClient::height(trusted_height),
+            headers: blocks.iter().map(TestBlock::header).collect(),
+        }
+    }
+
+    /// Returns the header carrying the blocks of the tracked chain from
+    /// `trusted_height` (exclusive) to `height`.
+    pub fn dummy_header(&self, trusted_height: u64, height: u64) -> Header {
+        self.header(
+            trusted_height,
+            &self.chain[trusted_height as usize + 1..=height as usize],
+        )
+    }
+
+    /// Returns the message proving the inclusion of the transaction at
+    /// `index` in the given block.
+    pub fn dummy_verify_membership_msg(
+        &self,
+        block: &TestBlock,
+        index: usize,
+    ) -> VerifyMembershipMsgRaw {
+        let txid = block.txids()[index];
+
+        let proof = TransactionProof {
+            index: index as u32,
+            merkle_branch: merkle_branch(&block.txids(), index),
+        };
+
+        VerifyMembershipMsgRaw {
+            proof: Protobuf::<RawTransactionProof>::encode_vec(proof).into(),
+            merkle_path: MerklePath {
+                key_path: vec![vec![].into(), display_hash(&txid).into_bytes().into()],
+            },
+            value: block.transactions[index].clone().into(),
+            height: BitcoinClient::height(block.height()).into(),
+            delay_block_period: 0,
+            delay_time_period: 0,
+        }
+    }
+
+    pub fn dummy_verify_non_membership_msg(
+        &self,
+        block: &TestBlock,
+        txid: &Bytes32,
+    ) -> VerifyNonMembershipMsgRaw {
+        VerifyNonMembershipMsgRaw {
+            proof: Protobuf::<RawTransactionProof>::encode_vec(TransactionProof {
+                index: 1,
+                merkle_branch: vec![[0; 32]],
+            })
+            .into(),
+            merkle_path: MerklePath {
+                key_path: vec![vec![].into(), display_hash(txid).into_bytes().into()],
+            },
+            height: BitcoinClient::height(block.height()).into(),
+            delay_block_period: 0,
+            delay_time_period: 0,
+        }
+    }
+}
+
+impl BitcoinClient {
+    pub fn height(height: u64) -> Height {
+        Height::new(BITCOIN_REVISION_NUMBER, height).expect("never fails")
+    }
+
+    pub fn dummy_client_state(fxt: &Fixture) -> ClientState {
+        ClientState {
+            chain_params: fxt.config.chain_params.clone(),
+            confirmations: fxt.config.confirmations,
+            latest_height: fxt.trusted_height,
+            frozen_height: None,
+        }
+    }
+
+    pub fn dummy_consensus_state(fxt: &Fixture) -> ConsensusState {
+        fxt.config.chain[fxt.trusted_height.revision_height() as usize]
+            .consensus_state
+            .clone()
+    }
+}
+
+impl TestClient for BitcoinClient {
+    type Config = BitcoinConfig;
+
+    /// Bitcoin clients never expire, as their consensus states are secured
+    /// by the work of the blocks rather than by a trusted validator set.
+    fn trusting_period(_config: &BitcoinConfig) -> Duration {
+        Duration::MAX
+    }
+
+    fn trusted_height(_config: &BitcoinConfig) -> Height {
+        Self::height(10)
+    }
+
+    fn client_state(fxt: &Fixture) -> Vec<u8> {
+        ClientState::encode_to_any_vec(Self::dummy_client_state(fxt))
+    }
+
+    fn consensus_state(fxt: &Fixture) -> Vec<u8> {
+        ConsensusState::encode_to_any_vec(Self::dummy_consensus_state(fxt))
+    }
+
+    /// Constructs the header carrying the blocks of the tracked chain up to
+    /// the target height.
+    fn header(fxt: &Fixture, target_height: Height) -> Vec<u8> {
+        Header::encode_to_any_vec(fxt.config.dummy_header(
+            fxt.trusted_height.revision_height(),
+            target_height.revision_height(),
+        ))
+    }
+
+    /// Constructs a heavier fork of the blocks following the trusted height.
+    /// A client only detects it as misbehaviour once it has confirmed these
+    /// blocks, as the fork then replaces them: right after its creation, the
+    /// client takes it for an update.
+    fn misbehaviour(fxt: &Fixture) -> Vec<u8> {
+        let trusted_height = fxt.trusted_height.revision_height();
+
+        let fork = fxt.config.fork(
+            trusted_height,
+            fxt.config.confirmations as usize + 5,
+            "fork",
+        );
+
+        Header::encode_to_any_vec(fxt.config.header(trusted_height, &fork))
+    }
+}
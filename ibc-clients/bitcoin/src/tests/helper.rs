@@ -0,0 +1,81 @@
+use cosmwasm_std::HexBinary;
+
+use crate::types::{double_sha256, BlockHeader, Bytes32};
+
+/// The header of the genesis block of Bitcoin Core's regtest network.
+pub const REGTEST_GENESIS_HEADER: &str = "0100000000000000000000000000000000000000000000000000000000000000000000003ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4adae5494dffff7f2002000000";
+
+/// The headers of the first three blocks of the Bitcoin main network.
+pub const MAINNET_HEADERS: [&str; 3] = [
+    "0100000000000000000000000000000000000000000000000000000000000000000000003ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4a29ab5f49ffff001d1dac2b7c",
+    "010000006fe28c0ab6f1b372c1a6a246ae63f74f931e8365e15a089c68d6190000000000982051fd1e4ba744bbbe680e1fee14677ba1a3c3540bf7b1cdb606e857233e0e61bc6649ffff001d01e36299",
+    "010000004860eb18bf1b1620e37e9490fc8a427514416fd75159ab86688e9a8300000000d5fdcc541e25de1c7a5addedf24858b8bb665c9f36ef744ee42c316022c90f9bb0bc6649ffff001d08d2bd61",
+];
+
+/// Decodes a hex-encoded block header.
+pub fn decode_header(hex: &str) -> BlockHeader {
+    BlockHeader::from_bytes(&HexBinary::from_hex(hex).expect("valid hex")).expect("valid header")
+}
+
+/// Returns the hex encoding of a hash in the usual reversed byte order.
+pub fn display_hash(hash: &Bytes32) -> String {
+    let mut hash = *hash;
+    hash.reverse();
+
+    HexBinary::from(hash).to_hex()
+}
+
+/// Replaces the target of a block header, leaving it to be mined again.
+pub fn with_bits(header: BlockHeader, bits: u32) -> BlockHeader {
+    BlockHeader { bits, ..header }
+}
+
+/// Increments the nonce of the header until its hash meets its target.
+pub fn mine(mut header: BlockHeader) -> BlockHeader {
+    while !header.meets_target().expect("valid target") {
+        header.nonce += 1;
+    }
+
+    header
+}
+
+/// Computes the transaction Merkle root of a block, duplicating the last
+/// node of levels with an odd number of nodes.
+pub fn merkle_root(txids: &[Bytes32]) -> Bytes32 {
+    let mut level = txids.to_vec();
+
+    while level.len() > 1 {
+        level = next_level(&level);
+    }
+
+    level[0]
+}
+
+/// Returns the sibling hashes along the path of the transaction at `index`
+/// in the transaction Merkle tree of a block.
+pub fn merkle_branch(txids: &[Bytes32], mut index: usize) -> Vec<Bytes32> {
+    let mut level = txids.to_vec();
+    let mut branch = vec![];
+
+    while level.len() > 1 {
+        let sibling = (index ^ 1).min(level.len() - 1);
+
+        branch.push(level[sibling]);
+
+        level = next_level(&level);
+        index >>= 1;
+    }
+
+    branch
+}
+
+fn next_level(level: &[Bytes32]) -> Vec<Bytes32> {
+    level
+        .chunks(2)
+        .map(|pair| {
+            let right = pair.get(1).unwrap_or(&pair[0]);
+
+            double_sha256([pair[0].as_slice(), right].concat())
+        })
+        .collect()
+}
@@ -0,0 +1,527 @@
+use cosmwasm_std::testing::mock_dependencies;
+use cosmwasm_std::{from_json, Uint256};
+use ibc_client_cw::types::{ContractResult, MigrationPrefix};
+use ibc_client_cw::utils::AnyCodec;
+use ibc_client_cw_testkit::scenarios;
+use ibc_core::client::types::Status;
+
+use crate::client_state::consensus_state_at;
+use crate::client_type::BitcoinClient;
+use crate::types::{block_work, decode_compact, encode_compact, ChainParams, Header};
+
+pub mod fixture;
+pub mod helper;
+
+use fixture::{BitcoinConfig, Fixture};
+use helper::{decode_header, display_hash, mine, with_bits, REGTEST_GENESIS_HEADER};
+
+#[test]
+fn test_bitcoin_recorded_genesis_headers() {
+    let regtest_genesis = decode_header(REGTEST_GENESIS_HEADER);
+
+    assert_eq!(
+        display_hash(&regtest_genesis.block_hash()),
+        "0f9188f13cb7b2c71f2a335e3a4fc328bf5beb436012afca590b1a11466e2206"
+    );
+    assert!(regtest_genesis.meets_target().unwrap());
+
+    let mainnet = BitcoinConfig::mainnet();
+
+    assert_eq!(
+        display_hash(&mainnet.chain[0].consensus_state.block_hash()),
+        "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f"
+    );
+
+    // The chain work of the second mainnet block is `0x200020002`.
+    assert_eq!(
+        mainnet.chain[1].consensus_state.chain_work,
+        Uint256::from(0x2_0002_0002u64)
+    );
+}
+
+#[test]
+fn test_bitcoin_compact_targets() {
+    for bits in [0x1d00_ffff, 0x207f_ffff, 0x1b04_04cb, 0x1703_4219] {
+        assert_eq!(encode_compact(decode_compact(bits).unwrap()), bits);
+    }
+
+    assert_eq!(
+        block_work(decode_compact(0x1d00_ffff).unwrap()),
+        Uint256::from(0x1_0001_0001u64)
+    );
+
+    // negative, overflowing and zero targets
+    for bits in [0x0480_0001, 0x2301_0000, 0x0100_0000] {
+        assert!(decode_compact(bits).is_err());
+    }
+}
+
+#[test]
+fn test_cw_bitcoin_testkit_scenarios() {
+    // The freeze and recovery scenarios need a reorg of blocks the client
+    // confirmed after its creation, and Bitcoin clients do not expire, so
+    // only the creation and update scenarios apply.
+    let fxt = Fixture::default();
+
+    scenarios::create_client_ok(&fxt);
+    scenarios::update_client_ok(&fxt);
+}
+
+#[test]
+fn test_cw_bitcoin_update_client_ok() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let trusted_height = fxt.trusted_height.revision_height();
+
+    let resp = fxt
+        .update_client_with(
+            deps.as_mut(),
+            Header::encode_to_any_vec(fxt.config.dummy_header(trusted_height, trusted_height + 3)),
+        )
+        .unwrap();
+
+    let contract_result: ContractResult = from_json(resp.data.unwrap()).unwrap();
+
+    assert_eq!(
+        contract_result.heights,
+        Some(
+            (trusted_height + 1..=trusted_height + 3)
+                .map(BitcoinClient::height)
+                .collect()
+        )
+    );
+
+    // ------------------- Resubmitted headers -------------------
+
+    let resp = fxt
+        .update_client_with(
+            deps.as_mut(),
+            Header::encode_to_any_vec(fxt.config.dummy_header(trusted_height, trusted_height + 3)),
+        )
+        .unwrap();
+
+    let contract_result: ContractResult = from_json(resp.data.unwrap()).unwrap();
+
+    assert_eq!(
+        contract_result.heights,
+        Some(vec![BitcoinClient::height(trusted_height + 3)])
+    );
+
+    // ------------------- Headers overlapping the tracked chain -------------------
+
+    let resp = fxt
+        .update_client_with(
+            deps.as_mut(),
+            Header::encode_to_any_vec(
+                fxt.config
+                    .dummy_header(trusted_height + 1, trusted_height + 5),
+            ),
+        )
+        .unwrap();
+
+    let contract_result: ContractResult = from_json(resp.data.unwrap()).unwrap();
+
+    assert_eq!(
+        contract_result.heights,
+        Some(vec![
+            BitcoinClient::height(trusted_height + 4),
+            BitcoinClient::height(trusted_height + 5)
+        ])
+    );
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+}
+
+#[test]
+fn test_cw_bitcoin_invalid_headers_rejected() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let trusted_height = fxt.trusted_height.revision_height();
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(fxt.config.dummy_header(trusted_height, trusted_height + 5)),
+    )
+    .unwrap();
+
+    let tip = trusted_height + 5;
+    let parent = &fxt.config.chain[tip as usize];
+
+    let mut skipped_block = fxt.config.dummy_header(tip, tip + 3);
+    skipped_block.headers.remove(1);
+
+    let unknown_trusted_height = fxt.config.dummy_header(tip + 1, tip + 3);
+
+    let mut insufficient_work = fxt.config.dummy_header(tip, tip + 1);
+    while insufficient_work.headers[0].meets_target().unwrap() {
+        insufficient_work.headers[0].nonce += 1;
+    }
+
+    let mut other_difficulty = fxt.config.dummy_header(tip, tip + 1);
+    other_difficulty.headers[0] = mine(with_bits(other_difficulty.headers[0], 0x2000_ffff));
+
+    let old_block = fxt.config.header(
+        tip,
+        &[fxt.config.mine_block(
+            parent,
+            "old",
+            fxt.config.chain[tip as usize - 5].header().time,
+        )],
+    );
+
+    let now = fxt.clock.now().nanoseconds() / 1_000_000_000;
+
+    let future_block = fxt.config.header(
+        tip,
+        &[fxt
+            .config
+            .mine_block(parent, "future", (now + 3 * 60 * 60) as u32)],
+    );
+
+    // A fork with less work than the tracked chain.
+    let lighter_fork = fxt
+        .config
+        .header(tip - 2, &fxt.config.fork(tip - 2, 1, "lighter"));
+
+    let equal_fork = fxt
+        .config
+        .header(tip - 2, &fxt.config.fork(tip - 2, 2, "equal"));
+
+    for header in [
+        skipped_block,
+        unknown_trusted_height,
+        insufficient_work,
+        other_difficulty,
+        old_block,
+        future_block,
+        lighter_fork,
+        equal_fork,
+    ] {
+        assert!(!fxt.is_valid_client_message(deps.as_ref(), Header::encode_to_any_vec(header)));
+    }
+}
+
+#[test]
+fn test_cw_bitcoin_reorg_ok() {
+    let mut fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let trusted_height = fxt.trusted_height.revision_height();
+    let tip = trusted_height + 5;
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(fxt.config.dummy_header(trusted_height, tip)),
+    )
+    .unwrap();
+
+    // ------------------- Heavier fork replacing the last two blocks -------------------
+
+    let fork_height = tip - 2;
+    let fork = fxt.config.fork(fork_height, 3, "fork");
+
+    let resp = fxt
+        .update_client_with(
+            deps.as_mut(),
+            Header::encode_to_any_vec(fxt.config.header(fork_height, &fork)),
+        )
+        .unwrap();
+
+    let contract_result: ContractResult = from_json(resp.data.unwrap()).unwrap();
+
+    assert_eq!(
+        contract_result.heights,
+        Some(
+            (fork_height + 1..=fork_height + 3)
+                .map(BitcoinClient::height)
+                .collect()
+        )
+    );
+
+    let ctx = fxt.ctx_ref(deps.as_ref());
+
+    for block in &fork {
+        let stored = consensus_state_at(
+            &ctx,
+            &ctx.client_id(),
+            BitcoinClient::height(block.height()),
+        )
+        .unwrap();
+
+        assert_eq!(stored, block.consensus_state);
+    }
+
+    // ------------------- Transactions of the replaced blocks -------------------
+
+    let replaced_block = fxt.config.chain[fork_height as usize + 1].clone();
+
+    fxt.config.chain.truncate(fork_height as usize + 1);
+    fxt.config.chain.extend(fork);
+
+    assert!(fxt
+        .verify_membership(
+            deps.as_mut(),
+            fxt.config.dummy_verify_membership_msg(&replaced_block, 0),
+        )
+        .is_err());
+
+    fxt.verify_membership(
+        deps.as_mut(),
+        fxt.config
+            .dummy_verify_membership_msg(&fxt.config.chain[fork_height as usize + 1], 0),
+    )
+    .unwrap();
+
+    // ------------------- Extension of the new branch -------------------
+
+    let new_tip = fxt.config.chain.len() as u64 - 1;
+    let extension = fxt
+        .config
+        .mine(&fxt.config.chain[new_tip as usize], 2, "fork");
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(fxt.config.header(new_tip, &extension)),
+    )
+    .unwrap();
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+}
+
+#[test]
+fn test_cw_bitcoin_verify_membership() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let trusted_height = fxt.trusted_height.revision_height();
+    let tip = trusted_height + 5;
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(fxt.config.dummy_header(trusted_height, tip)),
+    )
+    .unwrap();
+
+    // Blocks with at least three confirmations, including their own.
+    for height in trusted_height..=tip + 1 - fxt.config.confirmations {
+        let block = &fxt.config.chain[height as usize];
+
+        for index in 0..block.transactions.len() {
+            fxt.verify_membership(
+                deps.as_mut(),
+                fxt.config.dummy_verify_membership_msg(block, index),
+            )
+            .unwrap();
+        }
+
+        assert!(fxt
+            .verify_non_membership(
+                deps.as_mut(),
+                fxt.config.dummy_verify_non_membership_msg(block, &[0; 32]),
+            )
+            .is_err());
+    }
+
+    // Blocks lacking confirmations.
+    for height in tip + 2 - fxt.config.confirmations..=tip {
+        assert!(fxt
+            .verify_membership(
+                deps.as_mut(),
+                fxt.config
+                    .dummy_verify_membership_msg(&fxt.config.chain[height as usize], 0),
+            )
+            .is_err());
+    }
+
+    let block = &fxt.config.chain[trusted_height as usize + 1];
+
+    let mut other_transaction = fxt.config.dummy_verify_membership_msg(block, 0);
+    other_transaction.value = b"other-transaction".to_vec().into();
+
+    let mut other_index = fxt.config.dummy_verify_membership_msg(block, 0);
+    other_index.proof = fxt.config.dummy_verify_membership_msg(block, 1).proof;
+
+    let mut other_block = fxt.config.dummy_verify_membership_msg(block, 0);
+    other_block.height = BitcoinClient::height(block.height() + 1).into();
+
+    // The proof of another transaction at the same position.
+    let mut other_path = fxt.config.dummy_verify_membership_msg(block, 0);
+    other_path.merkle_path = fxt.config.dummy_verify_membership_msg(block, 1).merkle_path;
+
+    for msg in [other_transaction, other_index, other_block, other_path] {
+        assert!(fxt.verify_membership(deps.as_mut(), msg).is_err());
+    }
+}
+
+#[test]
+fn test_cw_bitcoin_difficulty_adjustment() {
+    // Blocks mined four times faster than expected, over intervals of eight
+    // blocks.
+    let chain_params = ChainParams {
+        difficulty_adjustment_interval: 8,
+        target_timespan: 8 * 600,
+        no_retargeting: false,
+        ..ChainParams::regtest()
+    };
+
+    let fxt = Fixture::new(BitcoinConfig::new(chain_params, 150, 30));
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let trusted_height = fxt.trusted_height.revision_height();
+
+    assert!(fxt.config.chain[16].header().bits < fxt.config.chain[15].header().bits);
+    assert!(fxt.config.chain[24].header().bits < fxt.config.chain[23].header().bits);
+
+    // A block keeping the previous difficulty at an adjustment height.
+    let mut unadjusted = fxt.config.dummy_header(trusted_height, 16);
+    let last = unadjusted.headers.len() - 1;
+    unadjusted.headers[last] = mine(with_bits(
+        unadjusted.headers[last],
+        fxt.config.chain[15].header().bits,
+    ));
+
+    assert!(!fxt.is_valid_client_message(deps.as_ref(), Header::encode_to_any_vec(unadjusted)));
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(fxt.config.dummy_header(trusted_height, 26)),
+    )
+    .unwrap();
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+}
+
+#[test]
+fn test_cw_bitcoin_recorded_mainnet_update() {
+    // The client is created at the first block following the genesis block.
+    let mut fxt = Fixture::new(BitcoinConfig::mainnet());
+    fxt.trusted_height = BitcoinClient::height(1);
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let mut tampered = fxt.config.dummy_header(1, 2);
+    tampered.headers[0].nonce += 1;
+
+    assert!(!fxt.is_valid_client_message(deps.as_ref(), Header::encode_to_any_vec(tampered)));
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(fxt.config.dummy_header(1, 2)),
+    )
+    .unwrap();
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+}
+
+#[test]
+fn test_cw_bitcoin_freeze_on_deep_reorg() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let trusted_height = fxt.trusted_height.revision_height();
+    let tip = trusted_height + 5;
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(fxt.config.dummy_header(trusted_height, tip)),
+    )
+    .unwrap();
+
+    // A heavier fork replacing a block with the required confirmations.
+    let fork_height = tip - fxt.config.confirmations;
+    let fork = fxt.config.fork(fork_height, 5, "fork");
+
+    fxt.update_client_on_misbehaviour_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(fxt.config.header(fork_height, &fork)),
+    );
+
+    fxt.check_client_status(deps.as_ref(), Status::Frozen);
+}
+
+#[test]
+fn test_cw_bitcoin_recovery_client_ok() {
+    let mut fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    // ------------------- Create subject client -------------------
+
+    fxt.set_migration_prefix(MigrationPrefix::Subject);
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let trusted_height = fxt.trusted_height.revision_height();
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(fxt.config.dummy_header(trusted_height, trusted_height + 5)),
+    )
+    .unwrap();
+
+    // ------------------- Freeze subject client -------------------
+
+    let fork = fxt.config.fork(trusted_height, 8, "fork");
+
+    fxt.update_client_on_misbehaviour_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(fxt.config.header(trusted_height, &fork)),
+    );
+
+    fxt.check_client_status(deps.as_ref(), Status::Frozen);
+
+    // ------------------- Create substitute client -------------------
+
+    fxt.set_migration_prefix(MigrationPrefix::Substitute);
+
+    fxt.config.chain.truncate(trusted_height as usize + 1);
+    fxt.config.chain.extend(fork);
+
+    fxt.trusted_height = BitcoinClient::height(trusted_height + 8);
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    // ------------------- Recover subject client -------------------
+
+    let resp = fxt.recover_client(deps.as_mut()).unwrap();
+
+    assert_eq!(0, resp.messages.len());
+
+    fxt.set_migration_prefix(MigrationPrefix::Subject);
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+
+    // ------------------- Update the recovered client -------------------
+
+    let extension = fxt
+        .config
+        .mine(&fxt.config.chain[trusted_height as usize + 8], 2, "fork");
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(fxt.config.header(trusted_height + 8, &extension)),
+    )
+    .unwrap();
+}
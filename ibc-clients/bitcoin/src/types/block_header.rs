@@ -0,0 +1,86 @@
+//! The 80-byte Bitcoin block header.
+use cosmwasm_std::Uint256;
+use ibc_core::host::types::error::DecodingError;
+
+use super::{decode_compact, double_sha256, Bytes32};
+use crate::error::BitcoinError;
+
+/// The size of a serialized block header.
+pub const BLOCK_HEADER_SIZE: usize = 80;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BlockHeader {
+    pub version: i32,
+    /// The hash of the previous block, in internal byte order.
+    pub prev_block_hash: Bytes32,
+    /// The root of the transaction Merkle tree, in internal byte order.
+    pub merkle_root: Bytes32,
+    /// The block time, in seconds since the Unix epoch.
+    pub time: u32,
+    /// The target of the block, in its compact form.
+    pub bits: u32,
+    pub nonce: u32,
+}
+
+impl BlockHeader {
+    /// Decodes a serialized block header.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodingError> {
+        let bytes: &[u8; BLOCK_HEADER_SIZE] = bytes.try_into().map_err(|_| {
+            DecodingError::invalid_raw_data(format!(
+                "block header must be {BLOCK_HEADER_SIZE} bytes long, found {} bytes",
+                bytes.len()
+            ))
+        })?;
+
+        let word = |offset: usize| {
+            let mut word = [0; 4];
+            word.copy_from_slice(&bytes[offset..offset + 4]);
+            word
+        };
+
+        let hash = |offset: usize| {
+            let mut hash = [0; 32];
+            hash.copy_from_slice(&bytes[offset..offset + 32]);
+            hash
+        };
+
+        Ok(Self {
+            version: i32::from_le_bytes(word(0)),
+            prev_block_hash: hash(4),
+            merkle_root: hash(36),
+            time: u32::from_le_bytes(word(68)),
+            bits: u32::from_le_bytes(word(72)),
+            nonce: u32::from_le_bytes(word(76)),
+        })
+    }
+
+    /// Serializes the block header.
+    pub fn to_bytes(&self) -> [u8; BLOCK_HEADER_SIZE] {
+        let mut bytes = [0; BLOCK_HEADER_SIZE];
+
+        bytes[0..4].copy_from_slice(&self.version.to_le_bytes());
+        bytes[4..36].copy_from_slice(&self.prev_block_hash);
+        bytes[36..68].copy_from_slice(&self.merkle_root);
+        bytes[68..72].copy_from_slice(&self.time.to_le_bytes());
+        bytes[72..76].copy_from_slice(&self.bits.to_le_bytes());
+        bytes[76..80].copy_from_slice(&self.nonce.to_le_bytes());
+
+        bytes
+    }
+
+    /// Returns the hash of the block, in internal byte order.
+    pub fn block_hash(&self) -> Bytes32 {
+        double_sha256(self.to_bytes())
+    }
+
+    /// Returns the target of the block.
+    pub fn target(&self) -> Result<Uint256, BitcoinError> {
+        decode_compact(self.bits)
+    }
+
+    /// Returns whether the block hash, read as a little-endian integer,
+    /// meets the target of the block.
+    pub fn meets_target(&self) -> Result<bool, BitcoinError> {
+        Ok(Uint256::from_le_bytes(self.block_hash()) <= self.target()?)
+    }
+}
@@ -0,0 +1,130 @@
+//! The consensus parameters of the tracked Bitcoin network.
+use cosmwasm_std::{Uint256, Uint512};
+use ibc_core::host::types::error::DecodingError;
+
+use super::proto::ChainParams as RawChainParams;
+use super::{decode_compact, encode_compact};
+use crate::error::BitcoinError;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChainParams {
+    /// The easiest target allowed, in its compact form.
+    pub pow_limit: u32,
+    /// The number of blocks between two difficulty adjustments.
+    pub difficulty_adjustment_interval: u64,
+    /// The expected duration of a difficulty adjustment interval, in seconds.
+    pub target_timespan: u64,
+    /// Whether the difficulty never changes, as on regtest.
+    pub no_retargeting: bool,
+}
+
+impl ChainParams {
+    /// The parameters of the Bitcoin main network.
+    pub fn mainnet() -> Self {
+        Self {
+            pow_limit: 0x1d00_ffff,
+            difficulty_adjustment_interval: 2016,
+            target_timespan: 14 * 24 * 60 * 60,
+            no_retargeting: false,
+        }
+    }
+
+    /// The parameters of a Bitcoin regression test network.
+    pub fn regtest() -> Self {
+        Self {
+            pow_limit: 0x207f_ffff,
+            no_retargeting: true,
+            ..Self::mainnet()
+        }
+    }
+
+    /// Returns the easiest target allowed.
+    pub fn pow_limit_target(&self) -> Result<Uint256, BitcoinError> {
+        decode_compact(self.pow_limit)
+    }
+
+    /// Returns whether the block at `height` starts a new difficulty
+    /// adjustment interval.
+    pub fn is_retarget_height(&self, height: u64) -> bool {
+        height % self.difficulty_adjustment_interval == 0
+    }
+
+    /// Returns the compact target required for the block at `height`, given
+    /// the target and time of its parent and the time of the first block of
+    /// the interval of its parent.
+    ///
+    /// The target is adjusted by the ratio between the actual and expected
+    /// duration of the last interval, bounded to a factor of four, as in
+    /// Bitcoin Core. The minimum difficulty blocks of testnet are not
+    /// supported.
+    pub fn next_bits(
+        &self,
+        height: u64,
+        parent_bits: u32,
+        parent_time: u32,
+        epoch_start_time: u32,
+    ) -> Result<u32, BitcoinError> {
+        if self.no_retargeting || !self.is_retarget_height(height) {
+            return Ok(parent_bits);
+        }
+
+        let target_timespan = i64::try_from(self.target_timespan).unwrap_or(i64::MAX);
+
+        let actual_timespan = (i64::from(parent_time) - i64::from(epoch_start_time))
+            .clamp(target_timespan / 4, target_timespan.saturating_mul(4));
+
+        let target = decode_compact(parent_bits)?
+            .full_mul(actual_timespan.unsigned_abs())
+            .checked_div(Uint512::from(self.target_timespan))
+            .map_err(|_| BitcoinError::InvalidCompactTarget { bits: parent_bits })?;
+
+        let pow_limit = self.pow_limit_target()?;
+
+        let target = Uint256::try_from(target)
+            .unwrap_or(pow_limit)
+            .min(pow_limit);
+
+        Ok(encode_compact(target))
+    }
+
+    fn validate(&self) -> Result<(), DecodingError> {
+        decode_compact(self.pow_limit)
+            .map_err(|e| DecodingError::invalid_raw_data(format!("proof-of-work limit: {e}")))?;
+
+        if self.difficulty_adjustment_interval == 0 || self.target_timespan == 0 {
+            return Err(DecodingError::invalid_raw_data(
+                "difficulty adjustment interval and target timespan must be non-zero",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<RawChainParams> for ChainParams {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawChainParams) -> Result<Self, Self::Error> {
+        let params = Self {
+            pow_limit: raw.pow_limit,
+            difficulty_adjustment_interval: raw.difficulty_adjustment_interval,
+            target_timespan: raw.target_timespan,
+            no_retargeting: raw.no_retargeting,
+        };
+
+        params.validate()?;
+
+        Ok(params)
+    }
+}
+
+impl From<ChainParams> for RawChainParams {
+    fn from(value: ChainParams) -> Self {
+        Self {
+            pow_limit: value.pow_limit,
+            difficulty_adjustment_interval: value.difficulty_adjustment_interval,
+            target_timespan: value.target_timespan,
+            no_retargeting: value.no_retargeting,
+        }
+    }
+}
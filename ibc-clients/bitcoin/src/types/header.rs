@@ -0,0 +1,88 @@
+//! The client message carrying a batch of block headers.
+use ibc_core::client::types::Height;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::proto::{Any, Protobuf};
+
+use super::proto::Header as RawHeader;
+use super::BlockHeader;
+
+pub const BITCOIN_HEADER_TYPE_URL: &str = "/ibc.lightclients.bitcoin.v1.Header";
+
+/// A batch of consecutive block headers extending the stored block at
+/// `trusted_height`, either at the tip of the tracked chain or on a fork of
+/// it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Header {
+    pub trusted_height: Height,
+    pub headers: Vec<BlockHeader>,
+}
+
+impl Header {
+    /// Returns the height of the last block of the batch.
+    pub fn height(&self) -> Height {
+        self.trusted_height.add(self.headers.len() as u64)
+    }
+}
+
+impl Protobuf<RawHeader> for Header {}
+
+impl TryFrom<RawHeader> for Header {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawHeader) -> Result<Self, Self::Error> {
+        if raw.headers.is_empty() {
+            return Err(DecodingError::missing_raw_data("block headers"));
+        }
+
+        Ok(Self {
+            trusted_height: raw
+                .trusted_height
+                .ok_or(DecodingError::missing_raw_data("trusted height"))?
+                .try_into()?,
+            headers: raw
+                .headers
+                .iter()
+                .map(|header| BlockHeader::from_bytes(header))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<Header> for RawHeader {
+    fn from(value: Header) -> Self {
+        Self {
+            trusted_height: Some(value.trusted_height.into()),
+            headers: value
+                .headers
+                .iter()
+                .map(|header| header.to_bytes().to_vec())
+                .collect(),
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let BITCOIN_HEADER_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawHeader>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: BITCOIN_HEADER_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        Self {
+            type_url: BITCOIN_HEADER_TYPE_URL.to_string(),
+            value: Protobuf::<RawHeader>::encode_vec(header),
+        }
+    }
+}
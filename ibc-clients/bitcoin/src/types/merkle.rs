@@ -0,0 +1,70 @@
+//! Merkle branches proving the inclusion of a transaction in a block.
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::proto::Protobuf;
+
+use super::proto::TransactionProof as RawTransactionProof;
+use super::{double_sha256, fixed_bytes, Bytes32};
+use crate::error::BitcoinError;
+
+/// The proof that a transaction is included in a block: its position and the
+/// sibling hashes along its path in the transaction Merkle tree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionProof {
+    pub index: u32,
+    pub merkle_branch: Vec<Bytes32>,
+}
+
+impl TransactionProof {
+    /// Computes the Merkle root the branch leads to from the transaction ID,
+    /// failing if the position does not fit in the depth of the branch.
+    pub fn merkle_root(&self, txid: &Bytes32) -> Result<Bytes32, BitcoinError> {
+        let mut index = self.index;
+        let mut hash = *txid;
+
+        for sibling in &self.merkle_branch {
+            hash = if index & 1 == 1 {
+                double_sha256([sibling.as_slice(), &hash].concat())
+            } else {
+                double_sha256([hash.as_slice(), sibling].concat())
+            };
+
+            index >>= 1;
+        }
+
+        if index != 0 {
+            return Err(BitcoinError::InvalidMerkleBranch);
+        }
+
+        Ok(hash)
+    }
+}
+
+impl Protobuf<RawTransactionProof> for TransactionProof {}
+
+impl TryFrom<RawTransactionProof> for TransactionProof {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawTransactionProof) -> Result<Self, Self::Error> {
+        Ok(Self {
+            index: raw.index,
+            merkle_branch: raw
+                .merkle_branch
+                .into_iter()
+                .map(|hash| fixed_bytes("Merkle branch hash", hash))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<TransactionProof> for RawTransactionProof {
+    fn from(value: TransactionProof) -> Self {
+        Self {
+            index: value.index,
+            merkle_branch: value
+                .merkle_branch
+                .into_iter()
+                .map(|hash| hash.to_vec())
+                .collect(),
+        }
+    }
+}
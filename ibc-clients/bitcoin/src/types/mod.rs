@@ -0,0 +1,37 @@
+mod block_header;
+mod chain_params;
+mod header;
+mod merkle;
+mod pow;
+pub mod proto;
+
+pub use block_header::*;
+pub use chain_params::*;
+pub use header::*;
+use ibc_core::host::types::error::DecodingError;
+pub use merkle::*;
+pub use pow::*;
+use sha2::{Digest, Sha256};
+
+pub type Bytes32 = [u8; 32];
+
+/// Computes the double SHA-256 digest used by Bitcoin for block hashes,
+/// transaction IDs and the nodes of transaction Merkle trees.
+pub fn double_sha256(data: impl AsRef<[u8]>) -> Bytes32 {
+    Sha256::digest(Sha256::digest(data)).into()
+}
+
+/// Converts raw bytes into a fixed-size array, failing if the length does not
+/// match.
+pub(crate) fn fixed_bytes<const N: usize>(
+    name: &str,
+    bytes: Vec<u8>,
+) -> Result<[u8; N], DecodingError> {
+    let actual = bytes.len();
+
+    bytes.try_into().map_err(|_| {
+        DecodingError::invalid_raw_data(format!(
+            "{name} must be {N} bytes long, found {actual} bytes"
+        ))
+    })
+}
@@ -0,0 +1,64 @@
+//! Proof-of-work arithmetic: the compact encoding of targets and the work
+//! they represent.
+use cosmwasm_std::Uint256;
+
+use crate::error::BitcoinError;
+
+/// Decodes a compact target, as found in the `bits` field of block headers,
+/// rejecting negative, overflowing and zero targets.
+pub fn decode_compact(bits: u32) -> Result<Uint256, BitcoinError> {
+    let size = bits >> 24;
+    let word = bits & 0x007f_ffff;
+
+    let is_negative = word != 0 && bits & 0x0080_0000 != 0;
+    let overflows =
+        word != 0 && (size > 34 || (word > 0xff && size > 33) || (word > 0xffff && size > 32));
+
+    if is_negative || overflows {
+        return Err(BitcoinError::InvalidCompactTarget { bits });
+    }
+
+    let target = if size <= 3 {
+        Uint256::from(word >> (8 * (3 - size)))
+    } else {
+        Uint256::from(word)
+            .checked_shl(8 * (size - 3))
+            .map_err(|_| BitcoinError::InvalidCompactTarget { bits })?
+    };
+
+    if target.is_zero() {
+        return Err(BitcoinError::InvalidCompactTarget { bits });
+    }
+
+    Ok(target)
+}
+
+/// Encodes a target into its compact form, truncating it to its three most
+/// significant bytes.
+pub fn encode_compact(target: Uint256) -> u32 {
+    let bytes = target.to_be_bytes();
+
+    let mut size = bytes.iter().skip_while(|byte| **byte == 0).count();
+
+    let mut word = bytes[32 - size..]
+        .iter()
+        .chain([0u8; 3].iter())
+        .take(3)
+        .fold(0u32, |word, byte| word << 8 | u32::from(*byte));
+
+    // The sign bit of the mantissa must not be set.
+    if word & 0x0080_0000 != 0 {
+        word >>= 8;
+        size += 1;
+    }
+
+    word | (size as u32) << 24
+}
+
+/// Returns the expected number of hashes needed to meet the target, i.e.
+/// `2^256 / (target + 1)`.
+pub fn block_work(target: Uint256) -> Uint256 {
+    // 2^256 does not fit in 256 bits, but `2^256 / (target + 1)` is
+    // `(2^256 - target - 1) / (target + 1) + 1`.
+    (!target) / (target + Uint256::one()) + Uint256::one()
+}
@@ -0,0 +1,67 @@
+//! Protobuf definitions of the Bitcoin SPV light client types, under the
+//! `ibc.lightclients.bitcoin.v1` package.
+use ibc_core::client::types::proto::v1::Height as RawHeight;
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ChainParams {
+    /// The easiest target allowed, in its compact form.
+    #[prost(uint32, tag = "1")]
+    pub pow_limit: u32,
+    /// The number of blocks between two difficulty adjustments.
+    #[prost(uint64, tag = "2")]
+    pub difficulty_adjustment_interval: u64,
+    /// The expected duration of a difficulty adjustment interval, in seconds.
+    #[prost(uint64, tag = "3")]
+    pub target_timespan: u64,
+    #[prost(bool, tag = "4")]
+    pub no_retargeting: bool,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ClientState {
+    #[prost(message, optional, tag = "1")]
+    pub chain_params: Option<ChainParams>,
+    #[prost(uint64, tag = "2")]
+    pub confirmations: u64,
+    #[prost(message, optional, tag = "3")]
+    pub latest_height: Option<RawHeight>,
+    #[prost(message, optional, tag = "4")]
+    pub frozen_height: Option<RawHeight>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ConsensusState {
+    /// The 80-byte serialized block header.
+    #[prost(bytes = "vec", tag = "1")]
+    pub header: Vec<u8>,
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+    /// The total work of the chain up to the block, as a big-endian 256-bit
+    /// integer.
+    #[prost(bytes = "vec", tag = "3")]
+    pub chain_work: Vec<u8>,
+    /// The time of the first block of the difficulty adjustment interval of
+    /// the block.
+    #[prost(uint32, tag = "4")]
+    pub epoch_start_time: u32,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Header {
+    /// The height of the stored block the headers build upon.
+    #[prost(message, optional, tag = "1")]
+    pub trusted_height: Option<RawHeight>,
+    /// The consecutive 80-byte serialized block headers.
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub headers: Vec<Vec<u8>>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct TransactionProof {
+    /// The position of the transaction in the block.
+    #[prost(uint32, tag = "1")]
+    pub index: u32,
+    /// The sibling hashes from the transaction up to the Merkle root.
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub merkle_branch: Vec<Vec<u8>>,
+}
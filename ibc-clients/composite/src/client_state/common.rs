@@ -0,0 +1,175 @@
+use std::collections::BTreeSet;
+
+use ibc_core::client::context::client_state::ClientStateCommon;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Height;
+use ibc_core::commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::host::types::identifiers::ClientType;
+use ibc_core::host::types::path::{Path, PathBytes};
+use ibc_core::primitives::proto::Any;
+use ibc_core::primitives::Timestamp;
+use prost::Message;
+
+use super::ClientState;
+use crate::consensus_state::ConsensusState;
+use crate::error::CompositeError;
+use crate::member::MemberClientState;
+use crate::types::proto::{MemberRoots as RawMemberRoots, MembershipProof as RawMembershipProof};
+
+pub const COMPOSITE_CLIENT_TYPE: &str = "composite";
+
+impl ClientStateCommon for ClientState {
+    /// Verifies the consensus state of every member with the member.
+    fn verify_consensus_state(
+        &self,
+        consensus_state: Any,
+        host_timestamp: &Timestamp,
+    ) -> Result<(), ClientError> {
+        let consensus_state = ConsensusState::try_from(consensus_state)?;
+
+        for (index, member_consensus_state) in consensus_state.members() {
+            self.member(*index)?
+                .verify_consensus_state(member_consensus_state.clone().into(), host_timestamp)?;
+        }
+
+        Ok(())
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::new(COMPOSITE_CLIENT_TYPE).expect("valid client type")
+    }
+
+    fn latest_height(&self) -> Height {
+        self.latest_height
+    }
+
+    fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError> {
+        if self.latest_height < proof_height {
+            return Err(ClientError::InsufficientProofHeight {
+                actual: self.latest_height,
+                expected: proof_height,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn serialize_path(&self, path: Path) -> Result<PathBytes, ClientError> {
+        Ok(path.to_string().into_bytes().into())
+    }
+
+    /// Upgrades are not supported by this client: the members are upgraded
+    /// one by one through a client recovery.
+    fn verify_upgrade_client(
+        &self,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+        _proof_upgrade_client: CommitmentProofBytes,
+        _proof_upgrade_consensus_state: CommitmentProofBytes,
+        _root: &CommitmentRoot,
+    ) -> Result<(), ClientError> {
+        Err(ClientError::ClientSpecific {
+            description: "upgrades are not supported by the composite client".to_string(),
+        })
+    }
+
+    fn verify_membership_raw(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: PathBytes,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        self.verify_member_proofs(proof, root, |member, member_proof, member_root| {
+            member.verify_membership_raw(
+                prefix,
+                member_proof,
+                member_root,
+                path.clone(),
+                value.clone(),
+            )
+        })
+    }
+
+    fn verify_non_membership_raw(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: PathBytes,
+    ) -> Result<(), ClientError> {
+        self.verify_member_proofs(proof, root, |member, member_proof, member_root| {
+            member.verify_non_membership_raw(prefix, member_proof, member_root, path.clone())
+        })
+    }
+}
+
+impl ClientState {
+    /// Checks that `count` members accepted a client message or a proof.
+    pub fn check_threshold(&self, count: usize) -> Result<(), CompositeError> {
+        if count < self.threshold as usize {
+            return Err(CompositeError::InsufficientMembers {
+                count,
+                threshold: self.threshold,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Verifies the proofs of distinct members that are not frozen, each
+    /// against the root of its member at the proof height, and checks that
+    /// enough members verified their proof.
+    fn verify_member_proofs<F>(
+        &self,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        verify: F,
+    ) -> Result<(), ClientError>
+    where
+        F: Fn(
+            &MemberClientState,
+            &CommitmentProofBytes,
+            &CommitmentRoot,
+        ) -> Result<(), ClientError>,
+    {
+        let membership_proof = RawMembershipProof::decode(proof.as_ref())
+            .map_err(|e| CompositeError::from(DecodingError::from(e)))?;
+
+        let member_roots = RawMemberRoots::decode(root.as_bytes())
+            .map_err(|e| CompositeError::from(DecodingError::from(e)))?;
+
+        let mut verified = BTreeSet::new();
+
+        for member_proof in membership_proof.proofs {
+            let index = member_proof.index as usize;
+            let member = self.member(index)?;
+
+            if member.is_frozen() {
+                return Err(CompositeError::FrozenMember { index }.into());
+            }
+
+            if !verified.insert(index) {
+                return Err(CompositeError::DuplicateMember { index }.into());
+            }
+
+            let member_root = member_roots
+                .roots
+                .iter()
+                .find(|member_root| member_root.index as usize == index)
+                .ok_or(CompositeError::MissingMemberRoot { index })?;
+
+            verify(
+                member,
+                &member_proof.proof.try_into()?,
+                &member_root.root.clone().into(),
+            )?;
+        }
+
+        Ok(self.check_threshold(verified.len())?)
+    }
+}
@@ -0,0 +1,266 @@
+use ibc_core::client::context::client_state::ClientStateExecution;
+use ibc_core::client::context::prelude::*;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Height;
+use ibc_core::host::types::identifiers::ClientId;
+use ibc_core::host::types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc_core::primitives::proto::Any;
+
+use super::ClientState;
+use crate::consensus_state::ConsensusState;
+use crate::entrypoint::CompositeContext;
+use crate::error::CompositeError;
+use crate::member::MemberClientState;
+use crate::types::{
+    Header, Misbehaviour, COMPOSITE_HEADER_TYPE_URL, COMPOSITE_MISBEHAVIOUR_TYPE_URL,
+};
+
+impl<'a> ClientStateExecution<CompositeContext<'a>> for ClientState {
+    /// Initialises every member with its consensus state, which must be
+    /// installed at the latest height of the composite client.
+    fn initialise(
+        &self,
+        ctx: &mut CompositeContext<'a>,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        let consensus_state = ConsensusState::try_from(consensus_state)?;
+
+        for (index, member) in self.members.iter().enumerate() {
+            let (_, member_consensus_state) = consensus_state
+                .members()
+                .iter()
+                .find(|(member_index, _)| *member_index == index)
+                .ok_or(CompositeError::MissingConsensusState { index })?;
+
+            let height = member.latest_height();
+
+            if height != self.latest_height {
+                return Err(CompositeError::MismatchedHeight {
+                    index,
+                    height,
+                    expected: self.latest_height,
+                }
+                .into());
+            }
+
+            member.initialise(ctx, index, client_id, member_consensus_state.clone().into())?;
+        }
+
+        ctx.store_client_state(ClientStatePath::new(client_id.clone()), self.clone())?;
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                client_id.clone(),
+                self.latest_height.revision_number(),
+                self.latest_height.revision_height(),
+            ),
+            consensus_state,
+        )?;
+        ctx.store_update_meta(
+            client_id.clone(),
+            self.latest_height,
+            ctx.host_timestamp()?,
+            ctx.host_height()?,
+        )?;
+
+        Ok(())
+    }
+
+    /// Updates the members of the header, then installs the composite
+    /// consensus state made of the consensus states of all the members that
+    /// are not frozen at the height of the header.
+    fn update_state(
+        &self,
+        ctx: &mut CompositeContext<'a>,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        let header = Header::try_from(client_message)?;
+        let height = header.height;
+
+        for message in header.messages {
+            self.member(message.index)?.update_state(
+                ctx,
+                message.index,
+                client_id,
+                message.client_message,
+            )?;
+        }
+
+        let members = self.stored_members(ctx, client_id)?;
+
+        let mut member_consensus_states = Vec::new();
+
+        for (index, member) in members.iter().enumerate() {
+            if member.is_frozen() {
+                continue;
+            }
+
+            if let Ok(consensus_state) = member.consensus_state(ctx, index, client_id, height) {
+                member_consensus_states.push((index, consensus_state));
+            }
+        }
+
+        self.check_threshold(member_consensus_states.len())?;
+
+        let new_consensus_state =
+            ConsensusState::new(member_consensus_states).map_err(CompositeError::from)?;
+
+        let new_client_state = Self {
+            members,
+            latest_height: self.latest_height.max(height),
+            ..self.clone()
+        };
+
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                client_id.clone(),
+                height.revision_number(),
+                height.revision_height(),
+            ),
+            new_consensus_state,
+        )?;
+        ctx.store_client_state(ClientStatePath::new(client_id.clone()), new_client_state)?;
+        ctx.store_update_meta(
+            client_id.clone(),
+            height,
+            ctx.host_timestamp()?,
+            ctx.host_height()?,
+        )?;
+
+        Ok(vec![height])
+    }
+
+    /// Freezes the misbehaving members only. The composite client itself is
+    /// frozen once K members can no longer be gathered.
+    fn update_state_on_misbehaviour(
+        &self,
+        ctx: &mut CompositeContext<'a>,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        match client_message.type_url.as_str() {
+            COMPOSITE_HEADER_TYPE_URL => {
+                let header = Header::try_from(client_message)?;
+
+                for message in header.messages {
+                    let member = self.member(message.index)?;
+
+                    if member.check_for_misbehaviour(
+                        ctx,
+                        message.index,
+                        client_id,
+                        message.client_message.clone(),
+                    )? {
+                        member.update_state_on_misbehaviour(
+                            ctx,
+                            message.index,
+                            client_id,
+                            message.client_message,
+                        )?;
+                    }
+                }
+            }
+            COMPOSITE_MISBEHAVIOUR_TYPE_URL => {
+                let misbehaviour = Misbehaviour::try_from(client_message)?;
+                let index = misbehaviour.evidence.index;
+
+                self.member(index)?.update_state_on_misbehaviour(
+                    ctx,
+                    index,
+                    client_id,
+                    misbehaviour.evidence.client_message,
+                )?;
+            }
+            header_type => return Err(ClientError::InvalidHeaderType(header_type.to_owned())),
+        }
+
+        let new_client_state = Self {
+            members: self.stored_members(ctx, client_id)?,
+            ..self.clone()
+        };
+
+        ctx.store_client_state(ClientStatePath::new(client_id.clone()), new_client_state)?;
+
+        Ok(())
+    }
+
+    fn update_state_on_upgrade(
+        &self,
+        _ctx: &mut CompositeContext<'a>,
+        _client_id: &ClientId,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        Err(ClientError::ClientSpecific {
+            description: "upgrades are not supported by the composite client".to_string(),
+        })
+    }
+
+    /// Recovers every member with the substitute member at the same index,
+    /// then installs the latest consensus state of the substitute.
+    fn update_on_recovery(
+        &self,
+        ctx: &mut CompositeContext<'a>,
+        subject_client_id: &ClientId,
+        substitute_client_state: Any,
+        substitute_consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        let substitute_client_state = Self::try_from(substitute_client_state)?;
+        let new_consensus_state = ConsensusState::try_from(substitute_consensus_state)?;
+
+        for (index, (member, substitute)) in self
+            .members
+            .iter()
+            .zip(&substitute_client_state.members)
+            .enumerate()
+        {
+            member.update_on_recovery(ctx, index, subject_client_id, substitute)?;
+        }
+
+        let new_client_state = Self {
+            members: self.stored_members(ctx, subject_client_id)?,
+            latest_height: substitute_client_state.latest_height,
+            ..self.clone()
+        };
+
+        let latest_height = new_client_state.latest_height;
+
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                subject_client_id.clone(),
+                latest_height.revision_number(),
+                latest_height.revision_height(),
+            ),
+            new_consensus_state,
+        )?;
+        ctx.store_client_state(
+            ClientStatePath::new(subject_client_id.clone()),
+            new_client_state,
+        )?;
+        ctx.store_update_meta(
+            subject_client_id.clone(),
+            latest_height,
+            ctx.host_timestamp()?,
+            ctx.host_height()?,
+        )?;
+
+        Ok(())
+    }
+}
+
+impl ClientState {
+    /// Fetches the client states of all the members from their stores, as
+    /// updated by the members themselves.
+    fn stored_members(
+        &self,
+        ctx: &CompositeContext<'_>,
+        client_id: &ClientId,
+    ) -> Result<Vec<MemberClientState>, ClientError> {
+        self.members
+            .iter()
+            .enumerate()
+            .map(|(index, member)| member.stored(ctx, index, client_id))
+            .collect()
+    }
+}
@@ -0,0 +1,115 @@
+//! Implements the composite `ClientState` along with the `ClientStateCommon`,
+//! `ClientStateValidation` and `ClientStateExecution` traits.
+use ibc_core::client::types::Height;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::proto::{Any, Protobuf};
+
+use crate::error::CompositeError;
+use crate::member::MemberClientState;
+use crate::types::proto::ClientState as RawClientState;
+
+mod common;
+mod execution;
+mod validation;
+
+pub use common::*;
+
+pub const COMPOSITE_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.composite.v1.ClientState";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClientState {
+    /// The number K of members required to accept an update or a proof.
+    pub threshold: u32,
+    /// The client states of the N members, as last stored by the members.
+    pub members: Vec<MemberClientState>,
+    pub latest_height: Height,
+}
+
+impl ClientState {
+    /// Returns the member at `index`.
+    pub fn member(&self, index: usize) -> Result<&MemberClientState, CompositeError> {
+        self.members
+            .get(index)
+            .ok_or(CompositeError::UnknownMember {
+                index,
+                len: self.members.len(),
+            })
+    }
+
+    fn validate(&self) -> Result<(), DecodingError> {
+        if self.members.is_empty() {
+            return Err(DecodingError::missing_raw_data("members"));
+        }
+
+        if self.threshold == 0 || self.threshold as usize > self.members.len() {
+            return Err(DecodingError::invalid_raw_data(format!(
+                "threshold must be between 1 and the {} members, found {}",
+                self.members.len(),
+                self.threshold
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        let client_state = Self {
+            threshold: raw.threshold,
+            members: raw
+                .members
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            latest_height: raw
+                .latest_height
+                .ok_or(DecodingError::missing_raw_data("latest height"))?
+                .try_into()?,
+        };
+
+        client_state.validate()?;
+
+        Ok(client_state)
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            threshold: value.threshold,
+            members: value.members.into_iter().map(Into::into).collect(),
+            latest_height: Some(value.latest_height.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let COMPOSITE_CLIENT_STATE_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawClientState>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: COMPOSITE_CLIENT_STATE_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Self {
+            type_url: COMPOSITE_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientState>::encode_vec(client_state),
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::BTreeSet;
+
+use ibc_core::client::context::client_state::ClientStateValidation;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Status;
+use ibc_core::host::types::identifiers::ClientId;
+use ibc_core::primitives::proto::Any;
+
+use super::ClientState;
+use crate::entrypoint::CompositeContext;
+use crate::error::CompositeError;
+use crate::types::{
+    Header, Misbehaviour, COMPOSITE_HEADER_TYPE_URL, COMPOSITE_MISBEHAVIOUR_TYPE_URL,
+};
+
+impl<'a> ClientStateValidation<CompositeContext<'a>> for ClientState {
+    fn verify_client_message(
+        &self,
+        ctx: &CompositeContext<'a>,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        match client_message.type_url.as_str() {
+            COMPOSITE_HEADER_TYPE_URL => {
+                let header = Header::try_from(client_message)?;
+                self.verify_header(ctx, client_id, &header)
+            }
+            COMPOSITE_MISBEHAVIOUR_TYPE_URL => {
+                let misbehaviour = Misbehaviour::try_from(client_message)?;
+                let index = misbehaviour.evidence.index;
+
+                self.member(index)?.verify_client_message(
+                    ctx,
+                    index,
+                    client_id,
+                    misbehaviour.evidence.client_message,
+                )
+            }
+            header_type => Err(ClientError::InvalidHeaderType(header_type.to_owned())),
+        }
+    }
+
+    /// A header is a misbehaviour as soon as the client message of one of
+    /// its members is.
+    fn check_for_misbehaviour(
+        &self,
+        ctx: &CompositeContext<'a>,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        match client_message.type_url.as_str() {
+            COMPOSITE_HEADER_TYPE_URL => {
+                let header = Header::try_from(client_message)?;
+
+                for message in header.messages {
+                    if self.member(message.index)?.check_for_misbehaviour(
+                        ctx,
+                        message.index,
+                        client_id,
+                        message.client_message,
+                    )? {
+                        return Ok(true);
+                    }
+                }
+
+                Ok(false)
+            }
+            COMPOSITE_MISBEHAVIOUR_TYPE_URL => {
+                let misbehaviour = Misbehaviour::try_from(client_message)?;
+                let index = misbehaviour.evidence.index;
+
+                self.member(index)?.check_for_misbehaviour(
+                    ctx,
+                    index,
+                    client_id,
+                    misbehaviour.evidence.client_message,
+                )
+            }
+            header_type => Err(ClientError::InvalidHeaderType(header_type.to_owned())),
+        }
+    }
+
+    /// The client is active as long as K members are active, and frozen
+    /// once more than N - K members are frozen, as K members can then no
+    /// longer be gathered. Otherwise, too many members have expired.
+    fn status(
+        &self,
+        ctx: &CompositeContext<'a>,
+        client_id: &ClientId,
+    ) -> Result<Status, ClientError> {
+        let mut active = 0;
+        let mut frozen = 0;
+
+        for (index, member) in self.members.iter().enumerate() {
+            if member.is_frozen() {
+                frozen += 1;
+                continue;
+            }
+
+            match member.status(ctx, index, client_id)? {
+                Status::Active => active += 1,
+                Status::Frozen => frozen += 1,
+                _ => {}
+            }
+        }
+
+        let threshold = self.threshold as usize;
+
+        if active >= threshold {
+            Ok(Status::Active)
+        } else if frozen > self.members.len() - threshold {
+            Ok(Status::Frozen)
+        } else {
+            Ok(Status::Expired)
+        }
+    }
+
+    /// The subject and substitute client states match if they have the same
+    /// threshold and every member matches the substitute member at the same
+    /// index.
+    fn check_substitute(
+        &self,
+        ctx: &CompositeContext<'a>,
+        substitute_client_state: Any,
+    ) -> Result<(), ClientError> {
+        let substitute_client_state = Self::try_from(substitute_client_state)?;
+
+        if self.threshold != substitute_client_state.threshold
+            || self.members.len() != substitute_client_state.members.len()
+        {
+            return Err(ClientError::FailedToVerifyClientRecoveryStates);
+        }
+
+        for (index, (member, substitute)) in self
+            .members
+            .iter()
+            .zip(&substitute_client_state.members)
+            .enumerate()
+        {
+            member.check_substitute(ctx, index, substitute)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ClientState {
+    /// Verifies that the header gathers the client messages of at least K
+    /// distinct members that are not frozen, all at the height of the header
+    /// and each verified by its member.
+    pub fn verify_header(
+        &self,
+        ctx: &CompositeContext<'_>,
+        client_id: &ClientId,
+        header: &Header,
+    ) -> Result<(), ClientError> {
+        let mut verified = BTreeSet::new();
+
+        for message in &header.messages {
+            let index = message.index;
+            let member = self.member(index)?;
+
+            if member.is_frozen() {
+                return Err(CompositeError::FrozenMember { index }.into());
+            }
+
+            if !verified.insert(index) {
+                return Err(CompositeError::DuplicateMember { index }.into());
+            }
+
+            let height = member.client_message_height(&message.client_message)?;
+
+            if height != header.height {
+                return Err(CompositeError::MismatchedHeight {
+                    index,
+                    height,
+                    expected: header.height,
+                }
+                .into());
+            }
+
+            member.verify_client_message(ctx, index, client_id, message.client_message.clone())?;
+        }
+
+        Ok(self.check_threshold(verified.len())?)
+    }
+}
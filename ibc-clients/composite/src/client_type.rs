@@ -0,0 +1,13 @@
+use ibc_client_cw::api::ClientType;
+
+use crate::client_state::ClientState;
+use crate::consensus_state::ConsensusState;
+
+/// A unit struct that represents the composite client type.
+#[derive(Clone, Debug)]
+pub struct CompositeClient;
+
+impl ClientType<'_> for CompositeClient {
+    type ClientState = ClientState;
+    type ConsensusState = ConsensusState;
+}
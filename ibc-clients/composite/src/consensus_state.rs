@@ -0,0 +1,144 @@
+//! Implements the composite `ConsensusState`, made of the consensus states
+//! installed at the same height by the members accepting an update.
+use ibc_core::client::context::consensus_state::ConsensusState as ConsensusStateTrait;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::commitment_types::commitment::CommitmentRoot;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::proto::{Any, Protobuf};
+use ibc_core::primitives::Timestamp;
+use prost::Message;
+
+use crate::member::MemberConsensusState;
+use crate::types::proto::{
+    ConsensusState as RawConsensusState, MemberConsensusState as RawMemberConsensusState,
+    MemberRoot as RawMemberRoot, MemberRoots as RawMemberRoots,
+};
+
+pub const COMPOSITE_CONSENSUS_STATE_TYPE_URL: &str =
+    "/ibc.lightclients.composite.v1.ConsensusState";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConsensusState {
+    /// The consensus states of the members, sorted by member index.
+    members: Vec<(usize, MemberConsensusState)>,
+    /// The encoded roots of the members, see [`RawMemberRoots`].
+    root: CommitmentRoot,
+}
+
+impl ConsensusState {
+    pub fn new(members: Vec<(usize, MemberConsensusState)>) -> Result<Self, DecodingError> {
+        if members.is_empty() {
+            return Err(DecodingError::missing_raw_data("member consensus states"));
+        }
+
+        if !members.windows(2).all(|pair| pair[0].0 < pair[1].0) {
+            return Err(DecodingError::invalid_raw_data(
+                "member consensus states must be sorted by strictly increasing member index",
+            ));
+        }
+
+        let roots = RawMemberRoots {
+            roots: members
+                .iter()
+                .map(|(index, consensus_state)| RawMemberRoot {
+                    index: *index as u32,
+                    root: consensus_state.root().as_bytes().to_vec(),
+                })
+                .collect(),
+        };
+
+        Ok(Self {
+            members,
+            root: roots.encode_to_vec().into(),
+        })
+    }
+
+    pub fn members(&self) -> &[(usize, MemberConsensusState)] {
+        &self.members
+    }
+}
+
+impl ConsensusStateTrait for ConsensusState {
+    fn root(&self) -> &CommitmentRoot {
+        &self.root
+    }
+
+    /// Returns the earliest timestamp of the members, so that a single member
+    /// can neither delay the expiry of the client nor let a packet time out
+    /// early.
+    fn timestamp(&self) -> Result<Timestamp, ClientError> {
+        self.members
+            .iter()
+            .map(|(_, consensus_state)| consensus_state.timestamp())
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .min()
+            .ok_or(ClientError::ClientSpecific {
+                description: "composite consensus state without members".to_string(),
+            })
+    }
+}
+
+impl Protobuf<RawConsensusState> for ConsensusState {}
+
+impl TryFrom<RawConsensusState> for ConsensusState {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawConsensusState) -> Result<Self, Self::Error> {
+        let members = raw
+            .members
+            .into_iter()
+            .map(|member| {
+                let consensus_state = member
+                    .consensus_state
+                    .ok_or(DecodingError::missing_raw_data("member consensus state"))?
+                    .try_into()?;
+
+                Ok((member.index as usize, consensus_state))
+            })
+            .collect::<Result<_, DecodingError>>()?;
+
+        Self::new(members)
+    }
+}
+
+impl From<ConsensusState> for RawConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        Self {
+            members: value
+                .members
+                .into_iter()
+                .map(|(index, consensus_state)| RawMemberConsensusState {
+                    index: index as u32,
+                    consensus_state: Some(consensus_state.into()),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let COMPOSITE_CONSENSUS_STATE_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawConsensusState>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: COMPOSITE_CONSENSUS_STATE_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Self {
+            type_url: COMPOSITE_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawConsensusState>::encode_vec(consensus_state),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+//! Defines the error type of the composite light client.
+use derive_more::{Display, From};
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Height;
+use ibc_core::host::types::error::DecodingError;
+
+#[derive(From, Display, Debug)]
+pub enum CompositeError {
+    #[from]
+    #[display("decoding error: {_0}")]
+    Decoding(DecodingError),
+    #[display("unknown member `{index}` in a composite client of {len} members")]
+    UnknownMember { index: usize, len: usize },
+    #[display("duplicate entry of member `{index}`")]
+    DuplicateMember { index: usize },
+    #[display("member `{index}` is frozen")]
+    FrozenMember { index: usize },
+    #[display("insufficient members: `{count}` members accepted, `{threshold}` required")]
+    InsufficientMembers { count: usize, threshold: u32 },
+    #[display("client message of member `{index}` is at height `{height}`, expected `{expected}`")]
+    MismatchedHeight {
+        index: usize,
+        height: Height,
+        expected: Height,
+    },
+    #[display("member `{index}` has no consensus state at the proof height")]
+    MissingMemberRoot { index: usize },
+    #[display("missing consensus state of member `{index}`")]
+    MissingConsensusState { index: usize },
+}
+
+impl From<CompositeError> for ClientError {
+    fn from(e: CompositeError) -> Self {
+        Self::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+//! The CosmWasm contract implementation of a composite light client, for
+//! defence in depth on high-value connections. Its client state aggregates
+//! several inner light clients of the same counterparty chain, such as two
+//! independent Tendermint clients or a Tendermint client and an attestor
+//! committee. An update installs a consensus state only when at least K of
+//! the N member clients accept it, and an IBC commitment proof is accepted
+//! only when at least K members verify it. Each member keeps its own store
+//! within the contract store, under a namespace of its own.
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+
+pub mod client_state;
+pub mod client_type;
+pub mod consensus_state;
+pub mod entrypoint;
+pub mod error;
+pub mod member;
+pub mod types;
+
+#[cfg(test)]
+mod tests;
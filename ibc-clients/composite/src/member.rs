@@ -0,0 +1,440 @@
+//! The member clients supported by the composite client. Each member runs
+//! the light client of its type against a context over its own namespace of
+//! the contract store, so that the consensus states and the update metadata
+//! of the members never mix.
+use ibc_client_attestor_cw::client_state::{
+    ClientState as AttestorClientState, ATTESTOR_CLIENT_STATE_TYPE_URL,
+};
+use ibc_client_attestor_cw::client_type::AttestorClient;
+use ibc_client_attestor_cw::consensus_state::{
+    ConsensusState as AttestorConsensusState, ATTESTOR_CONSENSUS_STATE_TYPE_URL,
+};
+use ibc_client_attestor_cw::types::StateAttestation;
+use ibc_client_tendermint::client_state::ClientState as TmClientState;
+use ibc_client_tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc_client_tendermint::types::{
+    Header as TmHeader, TENDERMINT_CLIENT_STATE_TYPE_URL, TENDERMINT_CONSENSUS_STATE_TYPE_URL,
+};
+use ibc_client_tendermint_cw::client_type::TendermintClient;
+use ibc_core::client::context::client_state::{
+    ClientStateCommon, ClientStateExecution, ClientStateValidation,
+};
+use ibc_core::client::context::consensus_state::ConsensusState as ConsensusStateTrait;
+use ibc_core::client::context::prelude::*;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::{Height, Status};
+use ibc_core::commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::host::types::identifiers::ClientId;
+use ibc_core::host::types::path::{ClientConsensusStatePath, PathBytes};
+use ibc_core::primitives::proto::Any;
+use ibc_core::primitives::Timestamp;
+
+use crate::entrypoint::CompositeContext;
+
+/// Returns the namespace of the store of the member at `index`.
+pub fn member_namespace(index: usize) -> Vec<u8> {
+    format!("members/{index}/").into_bytes()
+}
+
+fn consensus_state_path(client_id: &ClientId, height: Height) -> ClientConsensusStatePath {
+    ClientConsensusStatePath::new(
+        client_id.clone(),
+        height.revision_number(),
+        height.revision_height(),
+    )
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum MemberClientState {
+    Tendermint(TmClientState),
+    Attestor(AttestorClientState),
+}
+
+impl MemberClientState {
+    pub fn is_frozen(&self) -> bool {
+        match self {
+            Self::Tendermint(cs) => cs.inner().is_frozen(),
+            Self::Attestor(cs) => cs.is_frozen(),
+        }
+    }
+
+    pub fn latest_height(&self) -> Height {
+        match self {
+            Self::Tendermint(cs) => cs.latest_height(),
+            Self::Attestor(cs) => cs.latest_height(),
+        }
+    }
+
+    /// Returns the height of the consensus state that a client message of
+    /// this member installs. Only the client messages installing a consensus
+    /// state are accepted in a composite header.
+    pub fn client_message_height(&self, client_message: &Any) -> Result<Height, ClientError> {
+        match self {
+            Self::Tendermint(_) => Ok(TmHeader::try_from(client_message.clone())?.height()),
+            Self::Attestor(_) => Ok(StateAttestation::try_from(client_message.clone())?.height),
+        }
+    }
+
+    pub fn verify_consensus_state(
+        &self,
+        consensus_state: Any,
+        host_timestamp: &Timestamp,
+    ) -> Result<(), ClientError> {
+        match self {
+            Self::Tendermint(cs) => cs.verify_consensus_state(consensus_state, host_timestamp),
+            Self::Attestor(cs) => cs.verify_consensus_state(consensus_state, host_timestamp),
+        }
+    }
+
+    pub fn verify_membership_raw(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: PathBytes,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        match self {
+            Self::Tendermint(cs) => cs.verify_membership_raw(prefix, proof, root, path, value),
+            Self::Attestor(cs) => cs.verify_membership_raw(prefix, proof, root, path, value),
+        }
+    }
+
+    pub fn verify_non_membership_raw(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: PathBytes,
+    ) -> Result<(), ClientError> {
+        match self {
+            Self::Tendermint(cs) => cs.verify_non_membership_raw(prefix, proof, root, path),
+            Self::Attestor(cs) => cs.verify_non_membership_raw(prefix, proof, root, path),
+        }
+    }
+
+    pub fn verify_client_message(
+        &self,
+        ctx: &CompositeContext<'_>,
+        index: usize,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        let namespace = member_namespace(index);
+
+        match self {
+            Self::Tendermint(cs) => cs.verify_client_message(
+                &ctx.namespaced_ref::<TendermintClient>(namespace),
+                client_id,
+                client_message,
+            ),
+            Self::Attestor(cs) => cs.verify_client_message(
+                &ctx.namespaced_ref::<AttestorClient>(namespace),
+                client_id,
+                client_message,
+            ),
+        }
+    }
+
+    pub fn check_for_misbehaviour(
+        &self,
+        ctx: &CompositeContext<'_>,
+        index: usize,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        let namespace = member_namespace(index);
+
+        match self {
+            Self::Tendermint(cs) => cs.check_for_misbehaviour(
+                &ctx.namespaced_ref::<TendermintClient>(namespace),
+                client_id,
+                client_message,
+            ),
+            Self::Attestor(cs) => cs.check_for_misbehaviour(
+                &ctx.namespaced_ref::<AttestorClient>(namespace),
+                client_id,
+                client_message,
+            ),
+        }
+    }
+
+    pub fn status(
+        &self,
+        ctx: &CompositeContext<'_>,
+        index: usize,
+        client_id: &ClientId,
+    ) -> Result<Status, ClientError> {
+        let namespace = member_namespace(index);
+
+        match self {
+            Self::Tendermint(cs) => cs.status(
+                &ctx.namespaced_ref::<TendermintClient>(namespace),
+                client_id,
+            ),
+            Self::Attestor(cs) => {
+                cs.status(&ctx.namespaced_ref::<AttestorClient>(namespace), client_id)
+            }
+        }
+    }
+
+    /// Checks the substitute of this member, which must be a client of the
+    /// same type.
+    pub fn check_substitute(
+        &self,
+        ctx: &CompositeContext<'_>,
+        index: usize,
+        substitute: &Self,
+    ) -> Result<(), ClientError> {
+        let namespace = member_namespace(index);
+
+        match (self, substitute) {
+            (Self::Tendermint(cs), Self::Tendermint(_)) => cs.check_substitute(
+                &ctx.namespaced_ref::<TendermintClient>(namespace),
+                substitute.clone().into(),
+            ),
+            (Self::Attestor(cs), Self::Attestor(_)) => cs.check_substitute(
+                &ctx.namespaced_ref::<AttestorClient>(namespace),
+                substitute.clone().into(),
+            ),
+            _ => Err(ClientError::FailedToVerifyClientRecoveryStates),
+        }
+    }
+
+    /// Fetches the consensus state stored by the member at the given height.
+    pub fn consensus_state(
+        &self,
+        ctx: &CompositeContext<'_>,
+        index: usize,
+        client_id: &ClientId,
+        height: Height,
+    ) -> Result<MemberConsensusState, ClientError> {
+        let namespace = member_namespace(index);
+        let path = consensus_state_path(client_id, height);
+
+        let consensus_state = match self {
+            Self::Tendermint(_) => MemberConsensusState::Tendermint(
+                ctx.namespaced_ref::<TendermintClient>(namespace)
+                    .consensus_state(&path)?,
+            ),
+            Self::Attestor(_) => MemberConsensusState::Attestor(
+                ctx.namespaced_ref::<AttestorClient>(namespace)
+                    .consensus_state(&path)?,
+            ),
+        };
+
+        Ok(consensus_state)
+    }
+
+    /// Fetches the client state of the member from its store, where the
+    /// member keeps it up to date.
+    pub fn stored(
+        &self,
+        ctx: &CompositeContext<'_>,
+        index: usize,
+        client_id: &ClientId,
+    ) -> Result<Self, ClientError> {
+        let namespace = member_namespace(index);
+
+        let client_state = match self {
+            Self::Tendermint(_) => Self::Tendermint(
+                ctx.namespaced_ref::<TendermintClient>(namespace)
+                    .client_state(client_id)?,
+            ),
+            Self::Attestor(_) => Self::Attestor(
+                ctx.namespaced_ref::<AttestorClient>(namespace)
+                    .client_state(client_id)?,
+            ),
+        };
+
+        Ok(client_state)
+    }
+
+    pub fn initialise(
+        &self,
+        ctx: &mut CompositeContext<'_>,
+        index: usize,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        let namespace = member_namespace(index);
+
+        match self {
+            Self::Tendermint(cs) => cs.initialise(
+                &mut ctx.namespaced_mut::<TendermintClient>(namespace),
+                client_id,
+                consensus_state,
+            ),
+            Self::Attestor(cs) => cs.initialise(
+                &mut ctx.namespaced_mut::<AttestorClient>(namespace),
+                client_id,
+                consensus_state,
+            ),
+        }
+    }
+
+    pub fn update_state(
+        &self,
+        ctx: &mut CompositeContext<'_>,
+        index: usize,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        let namespace = member_namespace(index);
+
+        match self {
+            Self::Tendermint(cs) => cs.update_state(
+                &mut ctx.namespaced_mut::<TendermintClient>(namespace),
+                client_id,
+                client_message,
+            ),
+            Self::Attestor(cs) => cs.update_state(
+                &mut ctx.namespaced_mut::<AttestorClient>(namespace),
+                client_id,
+                client_message,
+            ),
+        }
+    }
+
+    pub fn update_state_on_misbehaviour(
+        &self,
+        ctx: &mut CompositeContext<'_>,
+        index: usize,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        let namespace = member_namespace(index);
+
+        match self {
+            Self::Tendermint(cs) => cs.update_state_on_misbehaviour(
+                &mut ctx.namespaced_mut::<TendermintClient>(namespace),
+                client_id,
+                client_message,
+            ),
+            Self::Attestor(cs) => cs.update_state_on_misbehaviour(
+                &mut ctx.namespaced_mut::<AttestorClient>(namespace),
+                client_id,
+                client_message,
+            ),
+        }
+    }
+
+    /// Recovers this member with its substitute, whose latest consensus
+    /// state is read from the store of the substitute member.
+    pub fn update_on_recovery(
+        &self,
+        ctx: &mut CompositeContext<'_>,
+        index: usize,
+        client_id: &ClientId,
+        substitute: &Self,
+    ) -> Result<(), ClientError> {
+        let namespace = member_namespace(index);
+        let path = consensus_state_path(client_id, substitute.latest_height());
+
+        match (self, substitute) {
+            (Self::Tendermint(cs), Self::Tendermint(_)) => {
+                let substitute_consensus_state = {
+                    let mut substitute_ctx =
+                        ctx.namespaced_ref::<TendermintClient>(namespace.as_slice());
+                    substitute_ctx.set_substitute_prefix();
+                    substitute_ctx.consensus_state(&path)?
+                };
+
+                cs.update_on_recovery(
+                    &mut ctx.namespaced_mut::<TendermintClient>(namespace),
+                    client_id,
+                    substitute.clone().into(),
+                    substitute_consensus_state.into(),
+                )
+            }
+            (Self::Attestor(cs), Self::Attestor(_)) => {
+                let substitute_consensus_state = {
+                    let mut substitute_ctx =
+                        ctx.namespaced_ref::<AttestorClient>(namespace.as_slice());
+                    substitute_ctx.set_substitute_prefix();
+                    substitute_ctx.consensus_state(&path)?
+                };
+
+                cs.update_on_recovery(
+                    &mut ctx.namespaced_mut::<AttestorClient>(namespace),
+                    client_id,
+                    substitute.clone().into(),
+                    substitute_consensus_state.into(),
+                )
+            }
+            _ => Err(ClientError::FailedToVerifyClientRecoveryStates),
+        }
+    }
+}
+
+impl TryFrom<Any> for MemberClientState {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            TENDERMINT_CLIENT_STATE_TYPE_URL => Ok(Self::Tendermint(raw.try_into()?)),
+            ATTESTOR_CLIENT_STATE_TYPE_URL => Ok(Self::Attestor(raw.try_into()?)),
+            type_url => Err(DecodingError::invalid_raw_data(format!(
+                "unsupported member client state `{type_url}`"
+            ))),
+        }
+    }
+}
+
+impl From<MemberClientState> for Any {
+    fn from(client_state: MemberClientState) -> Self {
+        match client_state {
+            MemberClientState::Tendermint(cs) => cs.into(),
+            MemberClientState::Attestor(cs) => cs.into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum MemberConsensusState {
+    Tendermint(TmConsensusState),
+    Attestor(AttestorConsensusState),
+}
+
+impl MemberConsensusState {
+    pub fn root(&self) -> &CommitmentRoot {
+        match self {
+            Self::Tendermint(cs) => cs.root(),
+            Self::Attestor(cs) => cs.root(),
+        }
+    }
+
+    pub fn timestamp(&self) -> Result<Timestamp, ClientError> {
+        match self {
+            // the inherent method returns the time of the Tendermint header
+            Self::Tendermint(cs) => ConsensusStateTrait::timestamp(cs),
+            Self::Attestor(cs) => Ok(cs.timestamp),
+        }
+    }
+}
+
+impl TryFrom<Any> for MemberConsensusState {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            TENDERMINT_CONSENSUS_STATE_TYPE_URL => Ok(Self::Tendermint(raw.try_into()?)),
+            ATTESTOR_CONSENSUS_STATE_TYPE_URL => Ok(Self::Attestor(raw.try_into()?)),
+            type_url => Err(DecodingError::invalid_raw_data(format!(
+                "unsupported member consensus state `{type_url}`"
+            ))),
+        }
+    }
+}
+
+impl From<MemberConsensusState> for Any {
+    fn from(consensus_state: MemberConsensusState) -> Self {
+        match consensus_state {
+            MemberConsensusState::Tendermint(cs) => cs.into(),
+            MemberConsensusState::Attestor(cs) => cs.into(),
+        }
+    }
+}
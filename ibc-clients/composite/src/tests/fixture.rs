@@ -0,0 +1,463 @@
+use std::time::Duration;
+
+use cosmwasm_std::Binary;
+use ibc_client_attestor_cw::client_state::ClientState as AttestorClientState;
+use ibc_client_attestor_cw::consensus_state::ConsensusState as AttestorConsensusState;
+use ibc_client_attestor_cw::types::{
+    AttestorSignature, Committee, Misbehaviour as AttestorMisbehaviour, StateAttestation,
+};
+use ibc_client_cw::types::{MerklePath, VerifyMembershipMsgRaw, VerifyNonMembershipMsgRaw};
+use ibc_client_cw::utils::AnyCodec;
+use ibc_client_cw_testkit::api::TestClient;
+use ibc_client_cw_testkit::clock::Clock;
+use ibc_client_cw_testkit::store::{MultiStore, IBC_STORE};
+use ibc_client_tendermint::client_state::ClientState as TmClientState;
+use ibc_client_tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc_client_tendermint::types::Header as TmHeader;
+use ibc_core::client::types::Height;
+use ibc_core::commitment_types::specs::ProofSpecs;
+use ibc_core::host::types::identifiers::ChainId;
+use ibc_core::primitives::proto::Any;
+use ibc_core::primitives::Timestamp;
+use ibc_testkit::fixtures::clients::tendermint::ClientStateConfig;
+use k256::ecdsa::signature::Signer;
+use k256::ecdsa::{Signature, SigningKey};
+use prost::Message;
+use tendermint::Time;
+use tendermint_testgen::light_block::TmLightBlock;
+use tendermint_testgen::{Generator, Validator};
+
+use crate::client_state::ClientState;
+use crate::client_type::CompositeClient;
+use crate::consensus_state::ConsensusState;
+use crate::member::{MemberClientState, MemberConsensusState};
+use crate::types::proto::{MemberProof as RawMemberProof, MembershipProof as RawMembershipProof};
+use crate::types::{Header, MemberMessage, Misbehaviour};
+
+/// The index of the Tendermint member.
+pub const TENDERMINT_MEMBER: usize = 0;
+
+/// The indices of the attestor members.
+pub const ATTESTOR_MEMBERS: [usize; 2] = [1, 2];
+
+/// The block time of the synthetic chain.
+const BLOCK_TIME: u64 = 5;
+
+/// Test fixture of the composite client.
+pub type Fixture = ibc_client_cw_testkit::fixture::Fixture<CompositeClient>;
+
+/// A chain whose state is a Cosmos SDK multistore, tracked by a composite
+/// client of three members: a Tendermint client of the validators of the
+/// chain, and two attestor clients of distinct committees.
+#[derive(Clone, Debug)]
+pub struct CompositeConfig {
+    pub chain_id: ChainId,
+    /// The time of the block at height zero, in seconds.
+    pub genesis_time: u64,
+    pub trusting_period: Duration,
+    pub validators: Vec<Validator>,
+    /// The keys of the committees of the attestor members, in the order of
+    /// [`ATTESTOR_MEMBERS`].
+    pub committee_keys: Vec<Vec<SigningKey>>,
+    /// The number of signatures required by the committees.
+    pub committee_threshold: u32,
+    /// The number K of members required by the composite client.
+    pub threshold: u32,
+    /// The (path, value) commitments stored in the IBC store.
+    pub commitments: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+impl Default for CompositeConfig {
+    fn default() -> Self {
+        let now = Clock::default().now().nanoseconds() / 1_000_000_000;
+
+        Self {
+            chain_id: ChainId::new("test-chain-1").expect("never fails"),
+            genesis_time: now - 3600,
+            trusting_period: Duration::from_secs(64000),
+            validators: vec![
+                Validator::new("1").voting_power(40),
+                Validator::new("2").voting_power(30),
+                Validator::new("3").voting_power(30),
+            ],
+            committee_keys: vec![
+                CompositeClient::signing_keys(1..=3),
+                CompositeClient::signing_keys(4..=6),
+            ],
+            committee_threshold: 2,
+            threshold: 2,
+            commitments: vec![
+                (
+                    b"commitments/ports/transfer/channels/channel-0/sequences/1".to_vec(),
+                    b"packet-commitment-1".to_vec(),
+                ),
+                (
+                    b"connections/connection-0".to_vec(),
+                    b"connection-end".to_vec(),
+                ),
+            ],
+        }
+    }
+}
+
+impl CompositeClient {
+    // ------------------- Synthetic chain -------------------
+
+    pub fn height(height: u64) -> Height {
+        Height::new(1, height).expect("never fails")
+    }
+
+    fn block_time(fxt: &Fixture, height: u64) -> Timestamp {
+        Timestamp::from_nanoseconds((fxt.config.genesis_time + height * BLOCK_TIME) * 1_000_000_000)
+    }
+
+    /// Returns the multistore committed at the given height. Besides the IBC
+    /// commitments, the IBC store records the height so that the app hash
+    /// changes on every block.
+    pub fn store(fxt: &Fixture, height: u64) -> MultiStore {
+        let mut store = MultiStore::default();
+
+        store.set(b"bank", b"supply", b"bank-store");
+
+        for (path, value) in &fxt.config.commitments {
+            store.set(IBC_STORE, path, value);
+        }
+
+        store.set(IBC_STORE, b"height", &height.to_be_bytes());
+
+        store.commit();
+
+        store
+    }
+
+    pub fn state_root(fxt: &Fixture, height: u64) -> Vec<u8> {
+        Self::store(fxt, height).app_hash()
+    }
+
+    // ------------------- Tendermint member -------------------
+
+    /// Generates the light block at `height`, committing to the state root
+    /// of the synthetic chain.
+    fn light_block(fxt: &Fixture, height: u64) -> TmLightBlock {
+        let block_time = Self::block_time(fxt, height).nanoseconds() / 1_000_000_000;
+
+        let header = tendermint_testgen::Header::new(&fxt.config.validators)
+            .chain_id(fxt.config.chain_id.as_str())
+            .height(height)
+            .time(Time::from_unix_timestamp(block_time as i64, 0).expect("never fails"))
+            .next_validators(&fxt.config.validators)
+            .app_hash(
+                Self::state_root(fxt, height)
+                    .try_into()
+                    .expect("never fails"),
+            );
+
+        tendermint_testgen::LightBlock::new_default_with_header(header)
+            .generate()
+            .expect("failed to generate light block")
+    }
+
+    pub fn tendermint_client_state(fxt: &Fixture) -> TmClientState {
+        ClientStateConfig::builder()
+            .trusting_period(fxt.config.trusting_period)
+            .proof_specs(ProofSpecs::cosmos())
+            .build()
+            .into_client_state(fxt.config.chain_id.clone(), fxt.trusted_height)
+            .expect("never fails")
+    }
+
+    pub fn tendermint_consensus_state(fxt: &Fixture) -> TmConsensusState {
+        Self::light_block(fxt, fxt.trusted_height.revision_height())
+            .signed_header
+            .header
+            .into()
+    }
+
+    /// Builds the Tendermint header of the block at `height`, trusting the
+    /// consensus state at the trusted height.
+    pub fn tendermint_header(fxt: &Fixture, height: u64) -> TmHeader {
+        let light_block = Self::light_block(fxt, height);
+
+        TmHeader {
+            signed_header: light_block.signed_header,
+            validator_set: light_block.validators,
+            trusted_height: fxt.trusted_height,
+            trusted_next_validator_set: light_block.next_validators,
+        }
+    }
+
+    // ------------------- Attestor members -------------------
+
+    /// Derives deterministic signing keys from the given seeds.
+    pub fn signing_keys(seeds: impl IntoIterator<Item = u8>) -> Vec<SigningKey> {
+        seeds
+            .into_iter()
+            .map(|seed| SigningKey::from_bytes(&[seed; 32].into()).expect("never fails"))
+            .collect()
+    }
+
+    fn keys_of(fxt: &Fixture, member: usize) -> &[SigningKey] {
+        let position = ATTESTOR_MEMBERS
+            .iter()
+            .position(|&index| index == member)
+            .expect("member is an attestor");
+
+        &fxt.config.committee_keys[position]
+    }
+
+    pub fn attestor_client_state(fxt: &Fixture, member: usize) -> AttestorClientState {
+        AttestorClientState {
+            chain_id: fxt.config.chain_id.to_string(),
+            committee: Committee {
+                epoch: 0,
+                attestors: Self::keys_of(fxt, member)
+                    .iter()
+                    .map(|key| {
+                        key.verifying_key()
+                            .to_encoded_point(true)
+                            .as_bytes()
+                            .try_into()
+                            .expect("never fails")
+                    })
+                    .collect(),
+                threshold: fxt.config.committee_threshold,
+            },
+            latest_height: fxt.trusted_height,
+            frozen_height: None,
+            trusting_period: fxt.config.trusting_period,
+            proof_specs: ProofSpecs::cosmos(),
+        }
+    }
+
+    pub fn attestor_consensus_state(fxt: &Fixture) -> AttestorConsensusState {
+        let trusted_height = fxt.trusted_height.revision_height();
+
+        AttestorConsensusState {
+            root: Self::state_root(fxt, trusted_height).into(),
+            timestamp: Self::block_time(fxt, trusted_height),
+        }
+    }
+
+    /// Signs the attestation with the first keys of the committee of the
+    /// given member, replacing its signatures.
+    pub fn signed_attestation(
+        fxt: &Fixture,
+        member: usize,
+        mut attestation: StateAttestation,
+    ) -> StateAttestation {
+        let sign_doc = attestation.sign_doc(fxt.config.chain_id.as_str(), 0);
+
+        attestation.signatures = (0..fxt.config.committee_threshold)
+            .map(|index| {
+                let signature: Signature =
+                    Self::keys_of(fxt, member)[index as usize].sign(&sign_doc);
+
+                AttestorSignature {
+                    attestor_index: index,
+                    signature: signature.to_bytes().into(),
+                }
+            })
+            .collect();
+
+        attestation
+    }
+
+    /// Builds the attestation of the block at `height` by the committee of
+    /// the given member.
+    pub fn attestation(fxt: &Fixture, member: usize, height: u64) -> StateAttestation {
+        Self::signed_attestation(
+            fxt,
+            member,
+            StateAttestation {
+                height: Self::height(height),
+                timestamp: Self::block_time(fxt, height),
+                root: Self::state_root(fxt, height),
+                signatures: vec![],
+            },
+        )
+    }
+
+    /// Builds an attestation of the block at `height` committing to a state
+    /// root that conflicts with the one of the synthetic chain.
+    pub fn conflicting_attestation(fxt: &Fixture, member: usize, height: u64) -> StateAttestation {
+        Self::signed_attestation(
+            fxt,
+            member,
+            StateAttestation {
+                root: Self::state_root(fxt, height + 1),
+                ..Self::attestation(fxt, member, height)
+            },
+        )
+    }
+
+    // ------------------- Composite client -------------------
+
+    pub fn dummy_client_state(fxt: &Fixture) -> ClientState {
+        ClientState {
+            threshold: fxt.config.threshold,
+            members: vec![
+                MemberClientState::Tendermint(Self::tendermint_client_state(fxt)),
+                MemberClientState::Attestor(Self::attestor_client_state(fxt, ATTESTOR_MEMBERS[0])),
+                MemberClientState::Attestor(Self::attestor_client_state(fxt, ATTESTOR_MEMBERS[1])),
+            ],
+            latest_height: fxt.trusted_height,
+        }
+    }
+
+    pub fn dummy_consensus_state(fxt: &Fixture) -> ConsensusState {
+        ConsensusState::new(vec![
+            (
+                TENDERMINT_MEMBER,
+                MemberConsensusState::Tendermint(Self::tendermint_consensus_state(fxt)),
+            ),
+            (
+                ATTESTOR_MEMBERS[0],
+                MemberConsensusState::Attestor(Self::attestor_consensus_state(fxt)),
+            ),
+            (
+                ATTESTOR_MEMBERS[1],
+                MemberConsensusState::Attestor(Self::attestor_consensus_state(fxt)),
+            ),
+        ])
+        .expect("never fails")
+    }
+
+    /// Builds the client message of the given member updating it to
+    /// `height`.
+    pub fn member_message(fxt: &Fixture, member: usize, height: u64) -> MemberMessage {
+        let client_message: Any = if member == TENDERMINT_MEMBER {
+            Self::tendermint_header(fxt, height).into()
+        } else {
+            Self::attestation(fxt, member, height).into()
+        };
+
+        MemberMessage {
+            index: member,
+            client_message,
+        }
+    }
+
+    /// Builds the header updating the given members to `height`.
+    pub fn dummy_header(fxt: &Fixture, height: u64, members: &[usize]) -> Header {
+        Header {
+            height: Self::height(height),
+            messages: members
+                .iter()
+                .map(|&member| Self::member_message(fxt, member, height))
+                .collect(),
+        }
+    }
+
+    /// Builds the misbehaviour of the given attestor member, made of two
+    /// conflicting attestations at `height`.
+    pub fn dummy_misbehaviour(fxt: &Fixture, member: usize, height: u64) -> Misbehaviour {
+        let misbehaviour = AttestorMisbehaviour {
+            attestation_1: Self::attestation(fxt, member, height),
+            attestation_2: Self::conflicting_attestation(fxt, member, height),
+        };
+
+        Misbehaviour {
+            evidence: MemberMessage {
+                index: member,
+                client_message: misbehaviour.into(),
+            },
+        }
+    }
+
+    /// Encodes the proofs of the given members, all proving the same entry
+    /// of the synthetic chain.
+    fn membership_proof(members: &[usize], proof: Binary) -> Binary {
+        RawMembershipProof {
+            proofs: members
+                .iter()
+                .map(|&member| RawMemberProof {
+                    index: member as u32,
+                    proof: proof.to_vec(),
+                })
+                .collect(),
+        }
+        .encode_to_vec()
+        .into()
+    }
+
+    pub fn dummy_verify_membership_msg(
+        fxt: &Fixture,
+        height: Height,
+        path: &[u8],
+        value: &[u8],
+        members: &[usize],
+    ) -> VerifyMembershipMsgRaw {
+        VerifyMembershipMsgRaw {
+            proof: Self::membership_proof(
+                members,
+                Self::store(fxt, height.revision_height()).membership_proof(IBC_STORE, path),
+            ),
+            merkle_path: MerklePath {
+                key_path: vec![IBC_STORE.to_vec().into(), path.to_vec().into()],
+            },
+            value: value.to_vec().into(),
+            height: height.into(),
+            delay_block_period: 0,
+            delay_time_period: 0,
+        }
+    }
+
+    pub fn dummy_verify_non_membership_msg(
+        fxt: &Fixture,
+        height: Height,
+        path: &[u8],
+        members: &[usize],
+    ) -> VerifyNonMembershipMsgRaw {
+        VerifyNonMembershipMsgRaw {
+            proof: Self::membership_proof(
+                members,
+                Self::store(fxt, height.revision_height()).non_membership_proof(IBC_STORE, path),
+            ),
+            merkle_path: MerklePath {
+                key_path: vec![IBC_STORE.to_vec().into(), path.to_vec().into()],
+            },
+            height: height.into(),
+            delay_block_period: 0,
+            delay_time_period: 0,
+        }
+    }
+}
+
+impl TestClient for CompositeClient {
+    type Config = CompositeConfig;
+
+    fn trusting_period(config: &CompositeConfig) -> Duration {
+        config.trusting_period
+    }
+
+    fn trusted_height(_config: &CompositeConfig) -> Height {
+        Self::height(10)
+    }
+
+    fn client_state(fxt: &Fixture) -> Vec<u8> {
+        ClientState::encode_to_any_vec(Self::dummy_client_state(fxt))
+    }
+
+    fn consensus_state(fxt: &Fixture) -> Vec<u8> {
+        ConsensusState::encode_to_any_vec(Self::dummy_consensus_state(fxt))
+    }
+
+    /// Constructs the header updating every member to the target height.
+    fn header(fxt: &Fixture, target_height: Height) -> Vec<u8> {
+        Header::encode_to_any_vec(Self::dummy_header(
+            fxt,
+            target_height.revision_height(),
+            &[TENDERMINT_MEMBER, ATTESTOR_MEMBERS[0], ATTESTOR_MEMBERS[1]],
+        ))
+    }
+
+    /// Constructs the misbehaviour of the first attestor member at the block
+    /// right after the trusted height.
+    fn misbehaviour(fxt: &Fixture) -> Vec<u8> {
+        Misbehaviour::encode_to_any_vec(Self::dummy_misbehaviour(
+            fxt,
+            ATTESTOR_MEMBERS[0],
+            fxt.trusted_height.revision_height() + 1,
+        ))
+    }
+}
@@ -0,0 +1,415 @@
+use std::time::Duration;
+
+use cosmwasm_std::from_json;
+use cosmwasm_std::testing::mock_dependencies;
+use ibc_client_cw::types::{ContractResult, MigrationPrefix};
+use ibc_client_cw::utils::AnyCodec;
+use ibc_client_cw_testkit::scenarios;
+use ibc_core::client::types::Status;
+
+use crate::client_type::CompositeClient;
+use crate::types::{Header, MemberMessage, Misbehaviour};
+
+pub mod fixture;
+
+use fixture::{CompositeConfig, Fixture, ATTESTOR_MEMBERS, TENDERMINT_MEMBER};
+
+const ALL_MEMBERS: [usize; 3] = [TENDERMINT_MEMBER, ATTESTOR_MEMBERS[0], ATTESTOR_MEMBERS[1]];
+
+#[test]
+fn test_cw_composite_testkit_scenarios() {
+    // The scenarios freeze the client with the misbehaviour of a single
+    // member, which is enough only when all the members are required.
+    scenarios::run_all(&Fixture::new(CompositeConfig {
+        threshold: 3,
+        ..Default::default()
+    }));
+}
+
+#[test]
+fn test_cw_composite_update_client_ok() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let trusted_height = fxt.trusted_height.revision_height();
+
+    for (height, members) in [
+        (trusted_height + 1, &ALL_MEMBERS[..2]),
+        (trusted_height + 5, &ALL_MEMBERS[1..]),
+        (trusted_height + 10, &ALL_MEMBERS[..]),
+    ] {
+        let resp = fxt
+            .update_client_with(
+                deps.as_mut(),
+                Header::encode_to_any_vec(CompositeClient::dummy_header(&fxt, height, members)),
+            )
+            .unwrap();
+
+        let contract_result: ContractResult = from_json(resp.data.unwrap()).unwrap();
+
+        assert_eq!(
+            contract_result.heights,
+            Some(vec![CompositeClient::height(height)])
+        );
+    }
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+}
+
+#[test]
+fn test_cw_composite_invalid_header_rejected() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let height = fxt.trusted_height.revision_height() + 5;
+
+    // A header below the threshold is rejected.
+    assert!(!fxt.is_valid_client_message(
+        deps.as_ref(),
+        Header::encode_to_any_vec(CompositeClient::dummy_header(
+            &fxt,
+            height,
+            &[ATTESTOR_MEMBERS[0]]
+        ))
+    ));
+
+    // The message of a member cannot be counted twice.
+    assert!(!fxt.is_valid_client_message(
+        deps.as_ref(),
+        Header::encode_to_any_vec(CompositeClient::dummy_header(
+            &fxt,
+            height,
+            &[ATTESTOR_MEMBERS[0], ATTESTOR_MEMBERS[0]]
+        ))
+    ));
+
+    // The members must exist.
+    let mut header = CompositeClient::dummy_header(&fxt, height, &ALL_MEMBERS[..2]);
+
+    header.messages[1].index = ALL_MEMBERS.len();
+
+    assert!(!fxt.is_valid_client_message(deps.as_ref(), Header::encode_to_any_vec(header)));
+
+    // Every member message must be valid, even if K valid ones remain.
+    let mut header = CompositeClient::dummy_header(&fxt, height, &ALL_MEMBERS);
+
+    let mut attestation = CompositeClient::attestation(&fxt, ATTESTOR_MEMBERS[1], height);
+    attestation.signatures.pop();
+
+    header.messages[2] = MemberMessage {
+        index: ATTESTOR_MEMBERS[1],
+        client_message: attestation.into(),
+    };
+
+    assert!(!fxt.is_valid_client_message(deps.as_ref(), Header::encode_to_any_vec(header)));
+
+    // Every member message must be at the height of the header.
+    let mut header = CompositeClient::dummy_header(&fxt, height, &ALL_MEMBERS[..2]);
+
+    header.messages[1] = CompositeClient::member_message(&fxt, ATTESTOR_MEMBERS[0], height + 1);
+
+    assert!(!fxt.is_valid_client_message(deps.as_ref(), Header::encode_to_any_vec(header)));
+}
+
+#[test]
+fn test_cw_composite_verify_membership() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let trusted_height = fxt.trusted_height.revision_height();
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(CompositeClient::dummy_header(
+            &fxt,
+            trusted_height + 5,
+            &ALL_MEMBERS,
+        )),
+    )
+    .unwrap();
+
+    let height = CompositeClient::height(trusted_height + 5);
+
+    for (path, value) in &fxt.config.commitments {
+        for members in [&ALL_MEMBERS[..2], &ALL_MEMBERS[1..], &ALL_MEMBERS[..]] {
+            fxt.verify_membership(
+                deps.as_mut(),
+                CompositeClient::dummy_verify_membership_msg(&fxt, height, path, value, members),
+            )
+            .unwrap();
+        }
+
+        // The proof of a single member is not enough, nor is the same proof
+        // counted twice.
+        for members in [
+            &[TENDERMINT_MEMBER][..],
+            &[TENDERMINT_MEMBER, TENDERMINT_MEMBER][..],
+        ] {
+            assert!(fxt
+                .verify_membership(
+                    deps.as_mut(),
+                    CompositeClient::dummy_verify_membership_msg(
+                        &fxt, height, path, value, members
+                    ),
+                )
+                .is_err());
+        }
+
+        assert!(fxt
+            .verify_membership(
+                deps.as_mut(),
+                CompositeClient::dummy_verify_membership_msg(
+                    &fxt,
+                    height,
+                    path,
+                    b"other-value",
+                    &ALL_MEMBERS
+                ),
+            )
+            .is_err());
+    }
+
+    let absent_path = b"receipts/ports/transfer/channels/channel-0/sequences/1";
+
+    fxt.verify_non_membership(
+        deps.as_mut(),
+        CompositeClient::dummy_verify_non_membership_msg(
+            &fxt,
+            height,
+            absent_path,
+            &ALL_MEMBERS[1..],
+        ),
+    )
+    .unwrap();
+
+    assert!(fxt
+        .verify_non_membership(
+            deps.as_mut(),
+            CompositeClient::dummy_verify_non_membership_msg(
+                &fxt,
+                height,
+                absent_path,
+                &ALL_MEMBERS[2..]
+            ),
+        )
+        .is_err());
+
+    // A member that did not take part in an update has no root to verify
+    // proofs against at that height.
+    fxt.update_client_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(CompositeClient::dummy_header(
+            &fxt,
+            trusted_height + 7,
+            &ALL_MEMBERS[..2],
+        )),
+    )
+    .unwrap();
+
+    let (path, value) = &fxt.config.commitments[0];
+    let height = CompositeClient::height(trusted_height + 7);
+
+    fxt.verify_membership(
+        deps.as_mut(),
+        CompositeClient::dummy_verify_membership_msg(&fxt, height, path, value, &ALL_MEMBERS[..2]),
+    )
+    .unwrap();
+
+    assert!(fxt
+        .verify_membership(
+            deps.as_mut(),
+            CompositeClient::dummy_verify_membership_msg(
+                &fxt,
+                height,
+                path,
+                value,
+                &ALL_MEMBERS[1..]
+            ),
+        )
+        .is_err());
+}
+
+#[test]
+fn test_cw_composite_freeze_member_on_misbehaviour() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let height = fxt.trusted_height.revision_height() + 5;
+
+    fxt.update_client_on_misbehaviour_with(
+        deps.as_mut(),
+        Misbehaviour::encode_to_any_vec(CompositeClient::dummy_misbehaviour(
+            &fxt,
+            ATTESTOR_MEMBERS[0],
+            height,
+        )),
+    );
+
+    // The client keeps working with the remaining K members.
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+
+    assert!(!fxt.is_valid_client_message(
+        deps.as_ref(),
+        Header::encode_to_any_vec(CompositeClient::dummy_header(&fxt, height, &ALL_MEMBERS))
+    ));
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(CompositeClient::dummy_header(
+            &fxt,
+            height,
+            &[TENDERMINT_MEMBER, ATTESTOR_MEMBERS[1]],
+        )),
+    )
+    .unwrap();
+
+    // K members can no longer be gathered once a second member is frozen.
+    fxt.update_client_on_misbehaviour_with(
+        deps.as_mut(),
+        Misbehaviour::encode_to_any_vec(CompositeClient::dummy_misbehaviour(
+            &fxt,
+            ATTESTOR_MEMBERS[1],
+            height,
+        )),
+    );
+
+    fxt.check_client_status(deps.as_ref(), Status::Frozen);
+}
+
+#[test]
+fn test_cw_composite_freeze_member_on_conflicting_header() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let height = fxt.trusted_height.revision_height() + 5;
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(CompositeClient::dummy_header(&fxt, height, &ALL_MEMBERS)),
+    )
+    .unwrap();
+
+    let mut header = CompositeClient::dummy_header(&fxt, height, &ALL_MEMBERS[..2]);
+
+    header.messages[1] = MemberMessage {
+        index: ATTESTOR_MEMBERS[0],
+        client_message: CompositeClient::conflicting_attestation(&fxt, ATTESTOR_MEMBERS[0], height)
+            .into(),
+    };
+
+    fxt.update_client_on_misbehaviour_with(deps.as_mut(), Header::encode_to_any_vec(header));
+
+    // Only the conflicting member is frozen.
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(CompositeClient::dummy_header(
+            &fxt,
+            height + 1,
+            &[TENDERMINT_MEMBER, ATTESTOR_MEMBERS[1]],
+        )),
+    )
+    .unwrap();
+
+    assert!(!fxt.is_valid_client_message(
+        deps.as_ref(),
+        Header::encode_to_any_vec(CompositeClient::dummy_header(
+            &fxt,
+            height + 2,
+            &ATTESTOR_MEMBERS
+        ))
+    ));
+}
+
+#[test]
+fn test_cw_composite_recovery_client_ok() {
+    let mut fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    // ------------------- Create subject client -------------------
+
+    fxt.set_migration_prefix(MigrationPrefix::Subject);
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    // ------------------- Freeze subject client -------------------
+
+    let height = fxt.trusted_height.revision_height() + 5;
+
+    for member in ATTESTOR_MEMBERS {
+        fxt.update_client_on_misbehaviour_with(
+            deps.as_mut(),
+            Misbehaviour::encode_to_any_vec(CompositeClient::dummy_misbehaviour(
+                &fxt, member, height,
+            )),
+        );
+    }
+
+    fxt.check_client_status(deps.as_ref(), Status::Frozen);
+
+    // ------------------- Create substitute client -------------------
+
+    // The substitute client is trusting new committees.
+    fxt.set_migration_prefix(MigrationPrefix::Substitute);
+
+    fxt.trusted_height = CompositeClient::height(fxt.trusted_height.revision_height() + 20);
+    fxt.config.committee_keys = vec![
+        CompositeClient::signing_keys(7..=9),
+        CompositeClient::signing_keys(10..=12),
+    ];
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    // ------------------- Recover subject client -------------------
+
+    let resp = fxt.recover_client(deps.as_mut()).unwrap();
+
+    assert_eq!(0, resp.messages.len());
+
+    fxt.set_migration_prefix(MigrationPrefix::Subject);
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+
+    // ------------------- Update with the new committees -------------------
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(CompositeClient::dummy_header(
+            &fxt,
+            fxt.trusted_height.revision_height() + 1,
+            &ALL_MEMBERS,
+        )),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_cw_composite_client_expiry() {
+    let fxt = Fixture::new(CompositeConfig {
+        trusting_period: Duration::from_secs(60),
+        ..Default::default()
+    });
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    fxt.check_client_status(deps.as_ref(), Status::Expired);
+}
@@ -0,0 +1,167 @@
+//! Client messages accepted by the composite light client: headers made of
+//! the client messages of several members, and the misbehaviour of a single
+//! member.
+use ibc_core::client::types::Height;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::proto::{Any, Protobuf};
+
+use super::proto::{
+    Header as RawHeader, MemberMessage as RawMemberMessage, Misbehaviour as RawMisbehaviour,
+};
+
+pub const COMPOSITE_HEADER_TYPE_URL: &str = "/ibc.lightclients.composite.v1.Header";
+pub const COMPOSITE_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.lightclients.composite.v1.Misbehaviour";
+
+/// A client message addressed to the member at `index`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MemberMessage {
+    pub index: usize,
+    pub client_message: Any,
+}
+
+impl TryFrom<RawMemberMessage> for MemberMessage {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawMemberMessage) -> Result<Self, Self::Error> {
+        Ok(Self {
+            index: raw.index as usize,
+            client_message: raw
+                .client_message
+                .ok_or(DecodingError::missing_raw_data("member client message"))?,
+        })
+    }
+}
+
+impl From<MemberMessage> for RawMemberMessage {
+    fn from(value: MemberMessage) -> Self {
+        Self {
+            index: value.index as u32,
+            client_message: Some(value.client_message),
+        }
+    }
+}
+
+/// Updates the composite client to `height` with the client messages of
+/// several members, each installing the consensus state of its member at
+/// that height.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Header {
+    pub height: Height,
+    pub messages: Vec<MemberMessage>,
+}
+
+impl Protobuf<RawHeader> for Header {}
+
+impl TryFrom<RawHeader> for Header {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawHeader) -> Result<Self, Self::Error> {
+        if raw.messages.is_empty() {
+            return Err(DecodingError::missing_raw_data("member client messages"));
+        }
+
+        Ok(Self {
+            height: raw
+                .height
+                .ok_or(DecodingError::missing_raw_data("header height"))?
+                .try_into()?,
+            messages: raw
+                .messages
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<Header> for RawHeader {
+    fn from(value: Header) -> Self {
+        Self {
+            height: Some(value.height.into()),
+            messages: value.messages.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let COMPOSITE_HEADER_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawHeader>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: COMPOSITE_HEADER_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        Self {
+            type_url: COMPOSITE_HEADER_TYPE_URL.to_string(),
+            value: Protobuf::<RawHeader>::encode_vec(header),
+        }
+    }
+}
+
+/// The evidence of misbehaviour of a single member, which freezes that
+/// member only: the composite client keeps working as long as K members are
+/// not frozen.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Misbehaviour {
+    pub evidence: MemberMessage,
+}
+
+impl Protobuf<RawMisbehaviour> for Misbehaviour {}
+
+impl TryFrom<RawMisbehaviour> for Misbehaviour {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawMisbehaviour) -> Result<Self, Self::Error> {
+        Ok(Self {
+            evidence: raw
+                .evidence
+                .ok_or(DecodingError::missing_raw_data("misbehaviour evidence"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<Misbehaviour> for RawMisbehaviour {
+    fn from(value: Misbehaviour) -> Self {
+        Self {
+            evidence: Some(value.evidence.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for Misbehaviour {}
+
+impl TryFrom<Any> for Misbehaviour {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let COMPOSITE_MISBEHAVIOUR_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawMisbehaviour>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: COMPOSITE_MISBEHAVIOUR_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<Misbehaviour> for Any {
+    fn from(misbehaviour: Misbehaviour) -> Self {
+        Self {
+            type_url: COMPOSITE_MISBEHAVIOUR_TYPE_URL.to_string(),
+            value: Protobuf::<RawMisbehaviour>::encode_vec(misbehaviour),
+        }
+    }
+}
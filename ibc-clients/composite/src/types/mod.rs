@@ -0,0 +1,4 @@
+mod header;
+pub mod proto;
+
+pub use header::*;
@@ -0,0 +1,89 @@
+//! Protobuf definitions of the composite light client types, under the
+//! `ibc.lightclients.composite.v1` package.
+use ibc_core::client::types::proto::v1::Height as RawHeight;
+use ibc_core::primitives::proto::Any;
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ClientState {
+    /// The number of members required to accept an update or a proof.
+    #[prost(uint32, tag = "1")]
+    pub threshold: u32,
+    /// The client states of the members, in member index order.
+    #[prost(message, repeated, tag = "2")]
+    pub members: Vec<Any>,
+    #[prost(message, optional, tag = "3")]
+    pub latest_height: Option<RawHeight>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MemberConsensusState {
+    #[prost(uint32, tag = "1")]
+    pub index: u32,
+    #[prost(message, optional, tag = "2")]
+    pub consensus_state: Option<Any>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ConsensusState {
+    /// The consensus states of the members at the same height, sorted by
+    /// member index.
+    #[prost(message, repeated, tag = "1")]
+    pub members: Vec<MemberConsensusState>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MemberRoot {
+    #[prost(uint32, tag = "1")]
+    pub index: u32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub root: Vec<u8>,
+}
+
+/// The commitment root of a composite consensus state, made of the roots of
+/// its members.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MemberRoots {
+    #[prost(message, repeated, tag = "1")]
+    pub roots: Vec<MemberRoot>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MemberMessage {
+    #[prost(uint32, tag = "1")]
+    pub index: u32,
+    #[prost(message, optional, tag = "2")]
+    pub client_message: Option<Any>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Header {
+    #[prost(message, optional, tag = "1")]
+    pub height: Option<RawHeight>,
+    /// The client messages of the members, each installing a consensus
+    /// state at `height`.
+    #[prost(message, repeated, tag = "2")]
+    pub messages: Vec<MemberMessage>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Misbehaviour {
+    /// The evidence of misbehaviour of a single member.
+    #[prost(message, optional, tag = "1")]
+    pub evidence: Option<MemberMessage>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MemberProof {
+    #[prost(uint32, tag = "1")]
+    pub index: u32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub proof: Vec<u8>,
+}
+
+/// The commitment proof accepted by the composite client, made of the proofs
+/// of the members.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MembershipProof {
+    #[prost(message, repeated, tag = "1")]
+    pub proofs: Vec<MemberProof>,
+}
@@ -0,0 +1,398 @@
+//! Parses the command line of the tool and runs its commands.
+use ibc_client_tendermint::types::{Header, Misbehaviour};
+use ibc_client_tendermint_cw::client_type::TendermintClient;
+
+use crate::decode::{decode, Encoding};
+use crate::encode::{
+    header_client_message, instantiate_msg, misbehaviour_client_message, InstantiateDescription,
+};
+use crate::error::CliError;
+use crate::input::{parse, read_input, Format};
+use crate::inspect::inspect;
+use crate::replay::{parse_log, replay, Snapshot};
+
+pub const USAGE: &str = "\
+Usage: ibc-client-cw-cli <COMMAND> [OPTIONS] <FILE>
+
+Reads <FILE>, or the standard input for `-`, and prints the result.
+
+Commands:
+  instantiate   Builds the `InstantiateMsg` of a Tendermint client from its description
+  header        Encodes a Tendermint header into a base64 `client_message`
+  misbehaviour  Encodes a Tendermint misbehaviour into a base64 `client_message`
+  decode        Decodes a stored value or a response of a contract to JSON
+  replay        Replays a JSON-lines log of messages against a Tendermint client contract
+  inspect       Classifies and decodes the JSON storage export of a Tendermint client contract
+
+Options:
+  --toml                  Reads the description as TOML, the default for `.toml` files
+  --json                  Reads the description as JSON, the default otherwise
+  --encoding <ENCODING>   The encoding of the value to decode: `base64` (default), `hex` or `raw`
+  --snapshot <FILE>       The JSON storage export the replay starts from, empty by default
+  --client-id <ID>        The client the replayed contract stands for, `08-wasm-0` by default
+  -h, --help              Prints this message";
+
+/// A command of the tool.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Command {
+    Instantiate {
+        path: String,
+        format: Format,
+    },
+    Header {
+        path: String,
+        format: Format,
+    },
+    Misbehaviour {
+        path: String,
+        format: Format,
+    },
+    Decode {
+        path: String,
+        encoding: Encoding,
+    },
+    Replay {
+        path: String,
+        snapshot: Option<String>,
+        client_id: String,
+    },
+    Inspect {
+        path: String,
+    },
+    Help,
+}
+
+impl Command {
+    /// Parses the arguments following the name of the binary.
+    pub fn parse<I, S>(args: I) -> Result<Self, CliError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut args = args.into_iter().map(Into::into);
+
+        let command = match args.next() {
+            Some(command) => command,
+            None => return Err(CliError::Usage("missing command".to_string())),
+        };
+
+        if matches!(command.as_str(), "-h" | "--help" | "help") {
+            return Ok(Self::Help);
+        }
+
+        let mut path = None;
+        let mut format = None;
+        let mut encoding = Encoding::default();
+        let mut snapshot = None;
+        let mut client_id = "08-wasm-0".to_string();
+
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| {
+                args.next()
+                    .ok_or_else(|| CliError::Usage(format!("missing value of `{name}`")))
+            };
+
+            match arg.as_str() {
+                "-h" | "--help" => return Ok(Self::Help),
+                "--toml" => format = Some(Format::Toml),
+                "--json" => format = Some(Format::Json),
+                "--encoding" => encoding = value(&arg)?.parse()?,
+                "--snapshot" => snapshot = Some(value(&arg)?),
+                "--client-id" => client_id = value(&arg)?,
+                _ if arg.starts_with("--") => {
+                    return Err(CliError::Usage(format!("unknown option `{arg}`")))
+                }
+                _ if path.is_none() => path = Some(arg),
+                _ => return Err(CliError::Usage(format!("unexpected argument `{arg}`"))),
+            }
+        }
+
+        let path = path.ok_or_else(|| CliError::Usage("missing input file".to_string()))?;
+        let format = format.unwrap_or_else(|| Format::from_path(&path));
+
+        match command.as_str() {
+            "instantiate" => Ok(Self::Instantiate { path, format }),
+            "header" => Ok(Self::Header { path, format }),
+            "misbehaviour" => Ok(Self::Misbehaviour { path, format }),
+            "decode" => Ok(Self::Decode { path, encoding }),
+            "replay" => Ok(Self::Replay {
+                path,
+                snapshot,
+                client_id,
+            }),
+            "inspect" => Ok(Self::Inspect { path }),
+            _ => Err(CliError::Usage(format!("unknown command `{command}`"))),
+        }
+    }
+
+    /// Runs the command on its input, returning what to print.
+    pub fn run(&self) -> Result<String, CliError> {
+        match self {
+            Self::Instantiate { path, format } => instantiate(&read_input(path)?, *format),
+            Self::Header { path, format } => header(&read_input(path)?, *format),
+            Self::Misbehaviour { path, format } => misbehaviour(&read_input(path)?, *format),
+            Self::Decode { path, encoding } => decode_value(&read_input(path)?, *encoding),
+            Self::Replay {
+                path,
+                snapshot,
+                client_id,
+            } => {
+                let snapshot = match snapshot {
+                    Some(snapshot) => serde_json::from_str(&read_input(snapshot)?)?,
+                    None => Snapshot::default(),
+                };
+
+                replay_log(&read_input(path)?, snapshot, client_id)
+            }
+            Self::Inspect { path } => inspect_storage(&read_input(path)?),
+            Self::Help => Ok(USAGE.to_string()),
+        }
+    }
+}
+
+/// Builds the JSON `InstantiateMsg` of the described client.
+pub fn instantiate(input: &str, format: Format) -> Result<String, CliError> {
+    let description: InstantiateDescription = parse(input, format)?;
+
+    Ok(serde_json::to_string_pretty(&instantiate_msg(
+        &description,
+    )?)?)
+}
+
+/// Encodes the given header to the base64 `client_message`.
+pub fn header(input: &str, format: Format) -> Result<String, CliError> {
+    let header: Header = parse(input, format)?;
+
+    Ok(header_client_message(header).to_base64())
+}
+
+/// Encodes the given misbehaviour to the base64 `client_message`.
+pub fn misbehaviour(input: &str, format: Format) -> Result<String, CliError> {
+    let misbehaviour: Misbehaviour = parse(input, format)?;
+
+    Ok(misbehaviour_client_message(misbehaviour).to_base64())
+}
+
+/// Replays the given log against the snapshot of a Tendermint client
+/// contract, printing the report of every step.
+pub fn replay_log(input: &str, snapshot: Snapshot, client_id: &str) -> Result<String, CliError> {
+    let reports = replay::<TendermintClient>(client_id.parse()?, snapshot, parse_log(input)?);
+
+    reports
+        .iter()
+        .map(serde_json::to_string_pretty)
+        .collect::<Result<Vec<_>, _>>()
+        .map(|reports| reports.join("\n"))
+        .map_err(Into::into)
+}
+
+/// Inspects the given storage export of a Tendermint client contract.
+pub fn inspect_storage(input: &str) -> Result<String, CliError> {
+    let inspection = inspect::<TendermintClient>(serde_json::from_str(input)?);
+
+    Ok(serde_json::to_string_pretty(&inspection)?)
+}
+
+/// Decodes the given value to pretty-printed JSON.
+pub fn decode_value(input: &str, encoding: Encoding) -> Result<String, CliError> {
+    let bytes = encoding.bytes(input)?;
+
+    Ok(serde_json::to_string_pretty(&decode(&bytes)?)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Binary;
+    use ibc_client_cw::types::InstantiateMsg;
+    use ibc_client_cw::utils::AnyCodec;
+    use ibc_client_tendermint::types::{
+        ClientState, ConsensusState, TENDERMINT_CLIENT_STATE_TYPE_URL, TENDERMINT_HEADER_TYPE_URL,
+    };
+    use ibc_core::client::types::Height;
+    use ibc_core::host::types::identifiers::ClientId;
+    use serde_json::Value;
+    use tendermint_testgen::{Generator, LightBlock};
+
+    use super::*;
+
+    const DESCRIPTION: &str = r#"{
+        "checksum": "2469f43c3ca20d476442bd3d98cbd97a180776ab37332aa7b02cae5a620acfc6",
+        "client_state": {
+            "chain_id": "cosmoshub-4",
+            "trusting_period_secs": 1209600,
+            "unbonding_period_secs": 1814400,
+            "max_clock_drift_secs": 10,
+            "latest_height": { "revision_number": 4, "revision_height": 100 }
+        },
+        "consensus_state": {
+            "timestamp": "2024-01-01T00:00:00Z",
+            "root": "0a0b0c",
+            "next_validators_hash": "b5d5e0e4a4e8cbd5b3a9a3d3c1a6cc5f6b6ab8f71a0d4c0f1fc2b4b2c2d7e5c4"
+        }
+    }"#;
+
+    const DESCRIPTION_TOML: &str = r#"
+        checksum = "2469f43c3ca20d476442bd3d98cbd97a180776ab37332aa7b02cae5a620acfc6"
+
+        [client_state]
+        chain_id = "cosmoshub-4"
+        trusting_period_secs = 1209600
+        unbonding_period_secs = 1814400
+        max_clock_drift_secs = 10
+        latest_height = { revision_number = 4, revision_height = 100 }
+
+        [consensus_state]
+        timestamp = 2024-01-01T00:00:00Z
+        root = "0a0b0c"
+        next_validators_hash = "b5d5e0e4a4e8cbd5b3a9a3d3c1a6cc5f6b6ab8f71a0d4c0f1fc2b4b2c2d7e5c4"
+    "#;
+
+    fn dummy_header(height: u64) -> Header {
+        let light_block = LightBlock::new_default(height).generate().unwrap();
+
+        Header {
+            signed_header: light_block.signed_header,
+            validator_set: light_block.validators,
+            trusted_height: Height::new(0, 1).unwrap(),
+            trusted_next_validator_set: light_block.next_validators,
+        }
+    }
+
+    #[test]
+    fn instantiate_msg_from_json() {
+        let msg: InstantiateMsg =
+            serde_json::from_str(&instantiate(DESCRIPTION, Format::Json).unwrap()).unwrap();
+
+        let client_state: ClientState =
+            ClientState::decode_any_vec(msg.client_state.to_vec()).unwrap();
+        let consensus_state: ConsensusState =
+            ConsensusState::decode_any_vec(msg.consensus_state.to_vec()).unwrap();
+
+        assert_eq!(client_state.chain_id.as_str(), "cosmoshub-4");
+        assert_eq!(client_state.latest_height, Height::new(4, 100).unwrap());
+        assert_eq!(client_state.upgrade_path, ["upgrade", "upgradedIBCState"]);
+        assert_eq!(consensus_state.root.as_bytes(), [0x0a, 0x0b, 0x0c]);
+        assert_eq!(msg.checksum.len(), 32);
+    }
+
+    #[test]
+    fn instantiate_msg_from_toml_matches_json() {
+        assert_eq!(
+            instantiate(DESCRIPTION_TOML, Format::Toml).unwrap(),
+            instantiate(DESCRIPTION, Format::Json).unwrap()
+        );
+    }
+
+    #[test]
+    fn instantiate_msg_decodes_back() {
+        let msg: InstantiateMsg =
+            serde_json::from_str(&instantiate(DESCRIPTION, Format::Json).unwrap()).unwrap();
+
+        let decoded: Value = serde_json::from_str(
+            &decode_value(&msg.client_state.to_base64(), Encoding::Base64).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(decoded["type_url"], TENDERMINT_CLIENT_STATE_TYPE_URL);
+        assert_eq!(decoded["value"]["chain_id"]["id"], "cosmoshub-4");
+    }
+
+    #[test]
+    fn header_encodes_to_client_message() {
+        let header = dummy_header(5);
+
+        let client_message =
+            super::header(&serde_json::to_string(&header).unwrap(), Format::Json).unwrap();
+
+        assert_eq!(
+            Binary::from_base64(&client_message).unwrap().to_vec(),
+            Header::encode_to_any_vec(header)
+        );
+
+        let decoded: Value =
+            serde_json::from_str(&decode_value(&client_message, Encoding::Base64).unwrap())
+                .unwrap();
+
+        assert_eq!(decoded["type_url"], TENDERMINT_HEADER_TYPE_URL);
+    }
+
+    #[test]
+    fn misbehaviour_encodes_to_client_message() {
+        let misbehaviour = Misbehaviour::new(
+            ClientId::new("08-wasm", 0).unwrap(),
+            dummy_header(5),
+            dummy_header(5),
+        );
+
+        let client_message =
+            super::misbehaviour(&serde_json::to_string(&misbehaviour).unwrap(), Format::Json)
+                .unwrap();
+
+        assert_eq!(
+            Binary::from_base64(&client_message).unwrap().to_vec(),
+            Misbehaviour::encode_to_any_vec(misbehaviour)
+        );
+    }
+
+    #[test]
+    fn decode_json_response() {
+        let response = r#"{"is_valid":true}"#;
+
+        assert_eq!(
+            decode_value(response, Encoding::Raw).unwrap(),
+            "{\n  \"is_valid\": true\n}"
+        );
+    }
+
+    #[test]
+    fn parse_commands() {
+        assert_eq!(
+            Command::parse(["instantiate", "client.toml"]).unwrap(),
+            Command::Instantiate {
+                path: "client.toml".to_string(),
+                format: Format::Toml,
+            }
+        );
+        assert_eq!(
+            Command::parse(["header", "--toml", "-"]).unwrap(),
+            Command::Header {
+                path: "-".to_string(),
+                format: Format::Toml,
+            }
+        );
+        assert_eq!(
+            Command::parse(["decode", "--encoding", "hex", "value.txt"]).unwrap(),
+            Command::Decode {
+                path: "value.txt".to_string(),
+                encoding: Encoding::Hex,
+            }
+        );
+        assert_eq!(
+            Command::parse(["replay", "log.jsonl", "--snapshot", "state.json"]).unwrap(),
+            Command::Replay {
+                path: "log.jsonl".to_string(),
+                snapshot: Some("state.json".to_string()),
+                client_id: "08-wasm-0".to_string(),
+            }
+        );
+        assert_eq!(
+            Command::parse(["inspect", "-"]).unwrap(),
+            Command::Inspect {
+                path: "-".to_string(),
+            }
+        );
+        assert_eq!(Command::parse(["--help"]).unwrap(), Command::Help);
+        assert!(matches!(
+            Command::parse(["decode"]),
+            Err(CliError::Usage(_))
+        ));
+        assert!(matches!(
+            Command::parse(["replay", "log.jsonl", "--client-id"]),
+            Err(CliError::Usage(_))
+        ));
+        assert!(matches!(
+            Command::parse(["encode", "value.txt"]),
+            Err(CliError::Usage(_))
+        ));
+    }
+}
@@ -0,0 +1,121 @@
+//! Decodes the values stored by the contracts and their responses to
+//! readable JSON.
+use core::fmt::Display;
+use core::str::FromStr;
+
+use cosmwasm_std::{Binary, HexBinary};
+use ibc_client_tendermint::types::{
+    ClientState as TmClientState, ConsensusState as TmConsensusState, Header, Misbehaviour,
+    TENDERMINT_CLIENT_STATE_TYPE_URL, TENDERMINT_CONSENSUS_STATE_TYPE_URL,
+    TENDERMINT_HEADER_TYPE_URL, TENDERMINT_MISBEHAVIOUR_TYPE_URL,
+};
+use ibc_client_wasm_types::client_message::WASM_CLIENT_MESSAGE_TYPE_URL;
+use ibc_client_wasm_types::client_state::{
+    ClientState as WasmClientState, WASM_CLIENT_STATE_TYPE_URL,
+};
+use ibc_client_wasm_types::consensus_state::{
+    ConsensusState as WasmConsensusState, WASM_CONSENSUS_STATE_TYPE_URL,
+};
+use ibc_client_wasm_types::proto::v1::ClientMessage as RawWasmClientMessage;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::proto::Any;
+use prost::Message;
+use serde_json::{json, Value};
+
+use crate::error::CliError;
+
+/// The text encoding of the bytes to decode.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Encoding {
+    /// Base64, as the `Binary` fields of the contract messages.
+    #[default]
+    Base64,
+    Hex,
+    /// The bytes themselves, as the JSON responses of the contract.
+    Raw,
+}
+
+impl FromStr for Encoding {
+    type Err = CliError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "base64" => Ok(Self::Base64),
+            "hex" => Ok(Self::Hex),
+            "raw" => Ok(Self::Raw),
+            _ => Err(CliError::Usage(format!(
+                "unknown encoding `{s}`, expected one of `base64`, `hex` or `raw`"
+            ))),
+        }
+    }
+}
+
+impl Encoding {
+    /// Reads the bytes from their text encoding, ignoring the surrounding
+    /// whitespace.
+    pub fn bytes(self, input: &str) -> Result<Vec<u8>, CliError> {
+        let bytes = match self {
+            Self::Base64 => Binary::from_base64(input.trim())?.to_vec(),
+            Self::Hex => HexBinary::from_hex(input.trim())?.to_vec(),
+            Self::Raw => input.as_bytes().to_vec(),
+        };
+
+        Ok(bytes)
+    }
+}
+
+/// Decodes a stored value or a response of a contract. JSON is returned as
+/// is; anything else is decoded as an `Any`.
+pub fn decode(bytes: &[u8]) -> Result<Value, CliError> {
+    if let Ok(json) = serde_json::from_slice::<Value>(bytes) {
+        return Ok(json);
+    }
+
+    decode_any(Any::decode(bytes).map_err(DecodingError::from)?)
+}
+
+/// Decodes an `Any` to its type URL and value. The `data` of the 08-wasm
+/// types is decoded in turn, and values of unknown types are kept in base64.
+pub fn decode_any(any: Any) -> Result<Value, CliError> {
+    let type_url = any.type_url.clone();
+
+    let value = match type_url.as_str() {
+        WASM_CLIENT_STATE_TYPE_URL => {
+            let client_state: WasmClientState = domain(any)?;
+
+            json!({
+                "data": decode(&client_state.data)?,
+                "checksum": HexBinary::from(client_state.checksum),
+                "latest_height": client_state.latest_height,
+            })
+        }
+        WASM_CONSENSUS_STATE_TYPE_URL => {
+            let consensus_state: WasmConsensusState = domain(any)?;
+
+            json!({ "data": decode(&consensus_state.data)? })
+        }
+        WASM_CLIENT_MESSAGE_TYPE_URL => {
+            let client_message =
+                RawWasmClientMessage::decode(any.value.as_slice()).map_err(DecodingError::from)?;
+
+            json!({ "data": decode(&client_message.data)? })
+        }
+        TENDERMINT_CLIENT_STATE_TYPE_URL => serde_json::to_value(domain::<TmClientState>(any)?)?,
+        TENDERMINT_CONSENSUS_STATE_TYPE_URL => {
+            serde_json::to_value(domain::<TmConsensusState>(any)?)?
+        }
+        TENDERMINT_HEADER_TYPE_URL => serde_json::to_value(domain::<Header>(any)?)?,
+        TENDERMINT_MISBEHAVIOUR_TYPE_URL => serde_json::to_value(domain::<Misbehaviour>(any)?)?,
+        _ => json!(Binary::from(any.value)),
+    };
+
+    Ok(json!({ "type_url": type_url, "value": value }))
+}
+
+fn domain<T>(any: Any) -> Result<T, CliError>
+where
+    T: TryFrom<Any>,
+    <T as TryFrom<Any>>::Error: Display,
+{
+    Ok(T::try_from(any).map_err(DecodingError::invalid_raw_data)?)
+}
@@ -0,0 +1,121 @@
+//! Builds the payloads of the Tendermint client contract from their
+//! descriptions.
+use std::time::Duration;
+
+use cosmwasm_std::{Binary, HexBinary};
+use ibc_client_cw::types::InstantiateMsg;
+use ibc_client_cw::utils::AnyCodec;
+use ibc_client_tendermint::types::{
+    AllowUpdate, ClientState, ConsensusState, Header, Misbehaviour, TrustThreshold,
+};
+use ibc_core::client::types::Height;
+use ibc_core::commitment_types::commitment::CommitmentRoot;
+use ibc_core::commitment_types::specs::ProofSpecs;
+use ibc_core::host::types::identifiers::ChainId;
+use serde::{Deserialize, Serialize};
+use tendermint::hash::Algorithm;
+use tendermint::{Hash, Time};
+
+use crate::error::CliError;
+
+/// Describes the instantiation of a Tendermint client contract.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct InstantiateDescription {
+    /// The checksum of the stored contract code, in hex.
+    pub checksum: HexBinary,
+    pub client_state: ClientStateDescription,
+    pub consensus_state: ConsensusStateDescription,
+}
+
+/// Describes the initial Tendermint client state. The proof specs are the
+/// Cosmos SDK ones.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ClientStateDescription {
+    pub chain_id: String,
+    #[serde(default = "default_trust_level")]
+    pub trust_level: TrustThreshold,
+    pub trusting_period_secs: u64,
+    pub unbonding_period_secs: u64,
+    pub max_clock_drift_secs: u64,
+    pub latest_height: Height,
+    #[serde(default = "default_upgrade_path")]
+    pub upgrade_path: Vec<String>,
+    #[serde(default)]
+    pub allow_update_after_expiry: bool,
+    #[serde(default)]
+    pub allow_update_after_misbehaviour: bool,
+}
+
+/// Describes the Tendermint consensus state at the latest height of the
+/// client.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ConsensusStateDescription {
+    /// The block time, in RFC 3339.
+    pub timestamp: Time,
+    /// The app hash of the block, in hex.
+    pub root: HexBinary,
+    /// The hash of the next validator set of the block, in hex.
+    pub next_validators_hash: HexBinary,
+}
+
+fn default_trust_level() -> TrustThreshold {
+    TrustThreshold::ONE_THIRD
+}
+
+fn default_upgrade_path() -> Vec<String> {
+    vec!["upgrade".to_string(), "upgradedIBCState".to_string()]
+}
+
+impl ClientStateDescription {
+    pub fn build(&self) -> Result<ClientState, CliError> {
+        let client_state = ClientState::new(
+            ChainId::new(&self.chain_id)?,
+            self.trust_level,
+            Duration::from_secs(self.trusting_period_secs),
+            Duration::from_secs(self.unbonding_period_secs),
+            Duration::from_secs(self.max_clock_drift_secs),
+            self.latest_height,
+            ProofSpecs::cosmos(),
+            self.upgrade_path.clone(),
+            AllowUpdate {
+                after_expiry: self.allow_update_after_expiry,
+                after_misbehaviour: self.allow_update_after_misbehaviour,
+            },
+        )?;
+
+        Ok(client_state)
+    }
+}
+
+impl ConsensusStateDescription {
+    pub fn build(&self) -> Result<ConsensusState, CliError> {
+        Ok(ConsensusState::new(
+            CommitmentRoot::from_bytes(self.root.as_slice()),
+            self.timestamp,
+            Hash::from_bytes(Algorithm::Sha256, self.next_validators_hash.as_slice())?,
+        ))
+    }
+}
+
+/// Builds the `InstantiateMsg` of the described client, with the client and
+/// consensus states encoded as `Any`.
+pub fn instantiate_msg(description: &InstantiateDescription) -> Result<InstantiateMsg, CliError> {
+    let client_state = description.client_state.build()?;
+    let consensus_state = description.consensus_state.build()?;
+
+    Ok(InstantiateMsg {
+        client_state: ClientState::encode_to_any_vec(client_state).into(),
+        consensus_state: ConsensusState::encode_to_any_vec(consensus_state).into(),
+        checksum: description.checksum.to_vec().into(),
+    })
+}
+
+/// Encodes a header into the `client_message` of the contract messages.
+pub fn header_client_message(header: Header) -> Binary {
+    Header::encode_to_any_vec(header).into()
+}
+
+/// Encodes a misbehaviour into the `client_message` of the contract messages.
+pub fn misbehaviour_client_message(misbehaviour: Misbehaviour) -> Binary {
+    Misbehaviour::encode_to_any_vec(misbehaviour).into()
+}
@@ -0,0 +1,40 @@
+use cosmwasm_std::StdError;
+use derive_more::{Display, From};
+use ibc_client_tendermint::types::error::TendermintClientError;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::host::types::error::{DecodingError, IdentifierError};
+
+#[derive(From, Display, Debug)]
+pub enum CliError {
+    #[from]
+    #[display("I/O error: {_0}")]
+    Io(std::io::Error),
+    #[from]
+    #[display("JSON error: {_0}")]
+    Json(serde_json::Error),
+    #[from]
+    #[display("TOML error: {_0}")]
+    Toml(toml_edit::TomlError),
+    #[from]
+    #[display("CosmWasm standard error: {_0}")]
+    Std(StdError),
+    #[from]
+    #[display("IBC decoding error: {_0}")]
+    Decoding(DecodingError),
+    #[from]
+    #[display("IBC identifier error: {_0}")]
+    Identifier(IdentifierError),
+    #[from]
+    #[display("IBC client error: {_0}")]
+    Client(ClientError),
+    #[from]
+    #[display("Tendermint client error: {_0}")]
+    TendermintClient(TendermintClientError),
+    #[from]
+    #[display("Tendermint error: {_0}")]
+    Tendermint(tendermint::Error),
+    #[display("TOML value `{value}` has no JSON equivalent")]
+    UnsupportedToml { value: String },
+    #[display("{_0}")]
+    Usage(String),
+}
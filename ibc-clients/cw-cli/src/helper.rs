@@ -0,0 +1,88 @@
+use cosmwasm_std::HexBinary;
+use ibc_client_cw::types::{QueryMsg, StatusMsg, SudoMsg, UpdateStateMsgRaw};
+use ibc_client_tendermint::types::{Header, TrustThreshold};
+use ibc_core::client::types::Height;
+use ibc_core::host::types::identifiers::ClientId;
+use tendermint::Time;
+use tendermint_testgen::{Generator, LightBlock};
+
+use crate::encode::{
+    header_client_message, instantiate_msg, ClientStateDescription, ConsensusStateDescription,
+    InstantiateDescription,
+};
+use crate::replay::{Step, StepMsg};
+
+pub fn client_id() -> ClientId {
+    "08-wasm-0".parse().unwrap()
+}
+
+pub fn block_time(secs: i64) -> Time {
+    Time::from_unix_timestamp(secs, 0).unwrap()
+}
+
+pub fn step(block_height: u64, msg: StepMsg) -> Step {
+    Step {
+        block_height,
+        block_time: block_time(100),
+        msg,
+    }
+}
+
+/// Instantiates a client trusting the generated light block at height 5.
+pub fn instantiate_step() -> Step {
+    let trusted = LightBlock::new_default(5).generate().unwrap();
+
+    let description = InstantiateDescription {
+        checksum: HexBinary::from(vec![1; 32]),
+        client_state: ClientStateDescription {
+            chain_id: "test-chain".to_string(),
+            trust_level: TrustThreshold::ONE_THIRD,
+            trusting_period_secs: 3600,
+            unbonding_period_secs: 7200,
+            max_clock_drift_secs: 10,
+            latest_height: Height::new(0, 5).unwrap(),
+            upgrade_path: vec![],
+            allow_update_after_expiry: false,
+            allow_update_after_misbehaviour: false,
+        },
+        consensus_state: ConsensusStateDescription {
+            timestamp: trusted.signed_header.header.time,
+            root: trusted.signed_header.header.app_hash.as_bytes().into(),
+            next_validators_hash: trusted
+                .signed_header
+                .header
+                .next_validators_hash
+                .as_bytes()
+                .into(),
+        },
+    };
+
+    step(
+        10,
+        StepMsg::Instantiate(instantiate_msg(&description).unwrap()),
+    )
+}
+
+/// Updates the client to height 6.
+pub fn update_step() -> Step {
+    let trusted = LightBlock::new_default(5).generate().unwrap();
+    let target = LightBlock::new_default(6).generate().unwrap();
+
+    let header = Header {
+        signed_header: target.signed_header,
+        validator_set: target.validators,
+        trusted_height: Height::new(0, 5).unwrap(),
+        trusted_next_validator_set: trusted.next_validators,
+    };
+
+    step(
+        11,
+        StepMsg::Sudo(SudoMsg::UpdateState(UpdateStateMsgRaw {
+            client_message: header_client_message(header),
+        })),
+    )
+}
+
+pub fn status_step() -> Step {
+    step(12, StepMsg::Query(QueryMsg::Status(StatusMsg {})))
+}
@@ -0,0 +1,159 @@
+//! Reads the descriptions given to the tool, in JSON or TOML.
+use std::io::Read;
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Number, Value};
+use toml_edit::{DocumentMut, Item, Table};
+
+use crate::error::CliError;
+
+/// The format of a description.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Toml,
+}
+
+impl Format {
+    /// Picks the format from the extension of the given path, defaulting to
+    /// JSON.
+    pub fn from_path(path: &str) -> Self {
+        match Path::new(path).extension() {
+            Some(ext) if ext.eq_ignore_ascii_case("toml") => Self::Toml,
+            _ => Self::Json,
+        }
+    }
+}
+
+/// Reads the content of the given path, or of the standard input for `-`.
+pub fn read_input(path: &str) -> Result<String, CliError> {
+    if path == "-" {
+        let mut input = String::new();
+
+        std::io::stdin().read_to_string(&mut input)?;
+
+        return Ok(input);
+    }
+
+    Ok(std::fs::read_to_string(path)?)
+}
+
+/// Parses a description of the given format.
+pub fn parse<T: DeserializeOwned>(input: &str, format: Format) -> Result<T, CliError> {
+    let value = match format {
+        Format::Json => serde_json::from_str(input)?,
+        Format::Toml => toml_to_json(input)?,
+    };
+
+    Ok(serde_json::from_value(value)?)
+}
+
+/// Converts a TOML document to the equivalent JSON value, so that both
+/// formats are deserialized the same way. Datetimes become their RFC 3339
+/// string.
+pub fn toml_to_json(input: &str) -> Result<Value, CliError> {
+    let document = input.parse::<DocumentMut>()?;
+
+    table_to_json(document.as_table())
+}
+
+fn table_to_json(table: &Table) -> Result<Value, CliError> {
+    table
+        .iter()
+        .map(|(key, item)| Ok((key.to_string(), item_to_json(item)?)))
+        .collect::<Result<Map<_, _>, CliError>>()
+        .map(Value::Object)
+}
+
+fn item_to_json(item: &Item) -> Result<Value, CliError> {
+    match item {
+        Item::None => Ok(Value::Null),
+        Item::Value(value) => value_to_json(value),
+        Item::Table(table) => table_to_json(table),
+        Item::ArrayOfTables(tables) => tables
+            .iter()
+            .map(table_to_json)
+            .collect::<Result<_, _>>()
+            .map(Value::Array),
+    }
+}
+
+fn value_to_json(value: &toml_edit::Value) -> Result<Value, CliError> {
+    use toml_edit::Value as Toml;
+
+    let json = match value {
+        Toml::String(s) => Value::String(s.value().clone()),
+        Toml::Integer(i) => Value::Number((*i.value()).into()),
+        Toml::Float(f) => Number::from_f64(*f.value())
+            .map(Value::Number)
+            .ok_or_else(|| CliError::UnsupportedToml {
+                value: f.value().to_string(),
+            })?,
+        Toml::Boolean(b) => Value::Bool(*b.value()),
+        Toml::Datetime(d) => Value::String(d.value().to_string()),
+        Toml::Array(array) => Value::Array(
+            array
+                .iter()
+                .map(value_to_json)
+                .collect::<Result<_, CliError>>()?,
+        ),
+        Toml::InlineTable(table) => Value::Object(
+            table
+                .iter()
+                .map(|(key, value)| Ok((key.to_string(), value_to_json(value)?)))
+                .collect::<Result<_, CliError>>()?,
+        ),
+    };
+
+    Ok(json)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn toml_converts_to_json() {
+        let toml = r#"
+            name = "test-chain"
+            ratio = 0.5
+            enabled = true
+            timestamp = 2024-01-01T00:00:00Z
+            path = ["upgrade", "upgradedIBCState"]
+            height = { revision_number = 0, revision_height = 10 }
+
+            [nested]
+            count = -3
+
+            [[items]]
+            id = 1
+
+            [[items]]
+            id = 2
+        "#;
+
+        let expected = json!({
+            "name": "test-chain",
+            "ratio": 0.5,
+            "enabled": true,
+            "timestamp": "2024-01-01T00:00:00Z",
+            "path": ["upgrade", "upgradedIBCState"],
+            "height": { "revision_number": 0, "revision_height": 10 },
+            "nested": { "count": -3 },
+            "items": [{ "id": 1 }, { "id": 2 }],
+        });
+
+        assert_eq!(toml_to_json(toml).unwrap(), expected);
+    }
+
+    #[test]
+    fn format_from_path() {
+        assert_eq!(Format::from_path("client.toml"), Format::Toml);
+        assert_eq!(Format::from_path("client.TOML"), Format::Toml);
+        assert_eq!(Format::from_path("client.json"), Format::Json);
+        assert_eq!(Format::from_path("-"), Format::Json);
+    }
+}
@@ -0,0 +1,710 @@
+//! Inspects a key/value dump of the storage of an 08-wasm light client
+//! contract, classifying every key by the layout the `ibc-client-cw`
+//! context stores them with:
+//!
+//! - `clientState`, the 08-wasm `ClientState` wrapping the client state;
+//! - `consensusStates/{height}`, the 08-wasm `ConsensusState` wrapping the
+//!   consensus state at the height;
+//! - `consensusStates/{height}/processedTime` and `processedHeight`, the
+//!   host time and height the consensus state was stored at;
+//! - the `iterateConsensusStates` map, indexing the heights of the
+//!   consensus states;
+//!
+//! all of them possibly under the namespace of a client nested in the
+//! contract, such as `members/{index}/` for a member of a composite client
+//! or `clients/{client_id}/` for a client hosted by an IBC core contract,
+//! and under the `subject/` or `substitute/` prefix of a client recovery.
+//! The entries that cannot be decoded, or whose related entries are missing,
+//! are flagged.
+use std::collections::{BTreeMap, BTreeSet};
+
+use cosmwasm_std::{from_json, Binary, Empty};
+use ibc_client_cw::api::ClientType;
+use ibc_client_wasm_types::client_state::ClientState as WasmClientState;
+use ibc_client_wasm_types::consensus_state::ConsensusState as WasmConsensusState;
+use ibc_client_wasm_types::{SUBJECT_PREFIX, SUBSTITUTE_PREFIX};
+use ibc_core::client::context::client_state::ClientStateCommon;
+use ibc_core::client::types::Height;
+use ibc_core::host::types::path::{
+    CLIENT_STATE, CONSENSUS_STATE_PREFIX, ITERATE_CONSENSUS_STATE_PREFIX, PROCESSED_HEIGHT,
+    PROCESSED_TIME,
+};
+use ibc_core::primitives::proto::Any;
+use prost::Message;
+use serde::Serialize;
+use serde_json::{json, Value};
+use tendermint::Time;
+
+use crate::decode::decode_any;
+use crate::replay::{display_key, Snapshot};
+
+/// The migration prefix a key is stored under.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Prefix {
+    None,
+    Subject,
+    Substitute,
+}
+
+/// What a key of the contract storage holds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum KeyKind {
+    ClientState,
+    ConsensusState { height: Height },
+    UpdateTime { height: Height },
+    UpdateHeight { height: Height },
+    HeightIndex { height: Height },
+    Unknown,
+}
+
+impl KeyKind {
+    fn height(&self) -> Option<Height> {
+        match self {
+            Self::ConsensusState { height }
+            | Self::UpdateTime { height }
+            | Self::UpdateHeight { height }
+            | Self::HeightIndex { height } => Some(*height),
+            Self::ClientState | Self::Unknown => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::ClientState => "client state",
+            Self::ConsensusState { .. } => "consensus state",
+            Self::UpdateTime { .. } => "update time",
+            Self::UpdateHeight { .. } => "update height",
+            Self::HeightIndex { .. } => "height index",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+/// A problem found with an entry.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "issue", rename_all = "snake_case")]
+pub enum Issue {
+    /// The key matches none of the layout of the contract storage.
+    UnknownKey,
+    /// The value cannot be decoded as the key says it should.
+    Undecodable { error: String },
+    /// The entry belongs to a client or consensus state that is not stored.
+    Orphaned { missing: &'static str },
+    /// The consensus state lacks some of the entries stored along with it.
+    Incomplete { missing: Vec<&'static str> },
+    /// The latest height of the 08-wasm client state differs from the one of
+    /// the client state it wraps.
+    LatestHeightMismatch { wasm: Height, client: Height },
+}
+
+/// The classification and decoded value of an entry of the dump.
+#[derive(Clone, Debug, Serialize)]
+pub struct InspectedEntry {
+    pub key: String,
+    pub prefix: Prefix,
+    /// The namespace of the nested client store the key belongs to.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub namespace: String,
+    #[serde(flatten)]
+    pub kind: KeyKind,
+    pub value: Value,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub issues: Vec<Issue>,
+}
+
+/// The inspection of a whole dump.
+#[derive(Clone, Debug, Serialize)]
+pub struct Inspection {
+    pub entries: Vec<InspectedEntry>,
+    /// The number of entries with issues.
+    pub flagged: usize,
+}
+
+/// Classifies a key of the contract storage, returning its migration prefix
+/// and the namespace of the client store it belongs to along with its kind.
+/// The namespace of an unknown key is empty.
+pub fn classify(key: &[u8]) -> (Prefix, Vec<u8>, KeyKind) {
+    let (prefix, key) = if let Some(key) = key.strip_prefix(SUBJECT_PREFIX) {
+        (Prefix::Subject, key)
+    } else if let Some(key) = key.strip_prefix(SUBSTITUTE_PREFIX) {
+        (Prefix::Substitute, key)
+    } else {
+        (Prefix::None, key)
+    };
+
+    let (namespace, kind) = classify_unprefixed(key).unwrap_or((&[], KeyKind::Unknown));
+
+    (prefix, namespace.to_vec(), kind)
+}
+
+fn classify_unprefixed(key: &[u8]) -> Option<(&[u8], KeyKind)> {
+    if let Some(namespace) = key.strip_suffix(CLIENT_STATE.as_bytes()) {
+        return Some((namespace, KeyKind::ClientState));
+    }
+
+    if let Some(parsed) = parse_height_index(key) {
+        return Some(parsed);
+    }
+
+    // The namespace is arbitrary bytes, so the path is looked for from the
+    // end of the key.
+    let path_prefix = format!("{CONSENSUS_STATE_PREFIX}/");
+    let position = key
+        .windows(path_prefix.len())
+        .rposition(|window| window == path_prefix.as_bytes())?;
+
+    let (namespace, path) = key.split_at(position);
+    let path = core::str::from_utf8(&path[path_prefix.len()..]).ok()?;
+
+    let (height, kind): (_, fn(Height) -> KeyKind) = match path.split_once('/') {
+        None => (path, |height| KeyKind::ConsensusState { height }),
+        Some((height, PROCESSED_TIME)) => (height, |height| KeyKind::UpdateTime { height }),
+        Some((height, PROCESSED_HEIGHT)) => (height, |height| KeyKind::UpdateHeight { height }),
+        Some(_) => return None,
+    };
+
+    height.parse().ok().map(|height| (namespace, kind(height)))
+}
+
+/// Parses a key of the height index map, made of the length-prefixed
+/// namespace of the map, the length-prefixed revision number and the
+/// revision height. The namespace of the map is the one of the client store
+/// followed by `iterateConsensusStates`.
+fn parse_height_index(key: &[u8]) -> Option<(&[u8], KeyKind)> {
+    let (length, rest) = key.split_first_chunk::<2>()?;
+    let length = u16::from_be_bytes(*length).into();
+    let (map_namespace, rest) = (rest.get(..length)?, rest.get(length..)?);
+
+    let namespace = map_namespace.strip_suffix(ITERATE_CONSENSUS_STATE_PREFIX.as_bytes())?;
+
+    let rest = rest.strip_prefix(&8u16.to_be_bytes())?;
+    let (revision_number, revision_height) = rest.split_first_chunk::<8>()?;
+
+    let height = Height::new(
+        u64::from_be_bytes(*revision_number),
+        u64::from_be_bytes(revision_height.try_into().ok()?),
+    )
+    .ok()?;
+
+    Some((namespace, KeyKind::HeightIndex { height }))
+}
+
+/// Inspects the dump of the storage of a contract of the client type `C`.
+pub fn inspect<C>(snapshot: Snapshot) -> Inspection
+where
+    C: for<'a> ClientType<'a>,
+{
+    let mut entries = snapshot
+        .entries()
+        .into_iter()
+        .map(|entry| {
+            let (prefix, namespace, kind) = classify(entry.key.as_slice());
+
+            let (value, issues) = match decode_value::<C>(&kind, entry.value.as_slice()) {
+                Ok(decoded) => decoded,
+                Err(error) => (json!(entry.value), vec![Issue::Undecodable { error }]),
+            };
+
+            InspectedEntry {
+                key: display_key(entry.key.as_slice()),
+                prefix,
+                namespace: display_key(&namespace),
+                kind,
+                value,
+                issues,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    flag_missing_entries(&mut entries);
+
+    let flagged = entries
+        .iter()
+        .filter(|entry| !entry.issues.is_empty())
+        .count();
+
+    Inspection { entries, flagged }
+}
+
+/// Decodes a value as the kind of its key says, returning its JSON along
+/// with the issues found in its content.
+fn decode_value<C>(kind: &KeyKind, value: &[u8]) -> Result<(Value, Vec<Issue>), String>
+where
+    C: for<'a> ClientType<'a>,
+{
+    match kind {
+        KeyKind::ClientState => {
+            let any = Any::decode(value).map_err(|e| e.to_string())?;
+            let wasm_client_state =
+                WasmClientState::try_from(any.clone()).map_err(|e| e.to_string())?;
+
+            let client_state = Any::decode(wasm_client_state.data.as_slice())
+                .map_err(|e| e.to_string())
+                .and_then(|any| {
+                    <C as ClientType<'_>>::ClientState::try_from(any).map_err(|e| e.to_string())
+                })?;
+
+            let mut issues = Vec::new();
+
+            if client_state.latest_height() != wasm_client_state.latest_height {
+                issues.push(Issue::LatestHeightMismatch {
+                    wasm: wasm_client_state.latest_height,
+                    client: client_state.latest_height(),
+                });
+            }
+
+            Ok((decode_any(any).map_err(|e| e.to_string())?, issues))
+        }
+        KeyKind::ConsensusState { .. } => {
+            let any = Any::decode(value).map_err(|e| e.to_string())?;
+            let wasm_consensus_state =
+                WasmConsensusState::try_from(any.clone()).map_err(|e| e.to_string())?;
+
+            Any::decode(wasm_consensus_state.data.as_slice())
+                .map_err(|e| e.to_string())
+                .and_then(|any| {
+                    <C as ClientType<'_>>::ConsensusState::try_from(any).map_err(|e| e.to_string())
+                })?;
+
+            Ok((decode_any(any).map_err(|e| e.to_string())?, Vec::new()))
+        }
+        KeyKind::UpdateTime { .. } => {
+            let nanos = decode_u64(value)?;
+
+            let time = Time::from_unix_timestamp(
+                (nanos / 1_000_000_000) as i64,
+                (nanos % 1_000_000_000) as u32,
+            )
+            .map_err(|e| e.to_string())?;
+
+            Ok((json!({ "nanoseconds": nanos, "time": time }), Vec::new()))
+        }
+        KeyKind::UpdateHeight { .. } => {
+            Ok((json!({ "revision_height": decode_u64(value)? }), Vec::new()))
+        }
+        KeyKind::HeightIndex { .. } => {
+            from_json::<Empty>(value).map_err(|e| e.to_string())?;
+
+            Ok((json!({}), Vec::new()))
+        }
+        KeyKind::Unknown => Ok((json!(Binary::from(value)), vec![Issue::UnknownKey])),
+    }
+}
+
+fn decode_u64(value: &[u8]) -> Result<u64, String> {
+    value
+        .try_into()
+        .map(u64::from_be_bytes)
+        .map_err(|_| format!("expected 8 bytes, got {}", value.len()))
+}
+
+/// Flags the entries stored along with a consensus state that is missing,
+/// the consensus states missing some of them, and the entries of a store
+/// without client state. Every namespace under every prefix is a store of
+/// its own.
+fn flag_missing_entries(entries: &mut [InspectedEntry]) {
+    let mut client_states = BTreeSet::new();
+    let mut heights: BTreeMap<(Prefix, String, Height), BTreeSet<&'static str>> = BTreeMap::new();
+
+    for entry in entries.iter() {
+        match entry.kind.height() {
+            Some(height) => {
+                heights
+                    .entry((entry.prefix, entry.namespace.clone(), height))
+                    .or_default()
+                    .insert(entry.kind.name());
+            }
+            None if entry.kind == KeyKind::ClientState => {
+                client_states.insert((entry.prefix, entry.namespace.clone()));
+            }
+            None => {}
+        }
+    }
+
+    for entry in entries.iter_mut() {
+        let Some(height) = entry.kind.height() else {
+            continue;
+        };
+
+        let store = (entry.prefix, entry.namespace.clone());
+
+        if !client_states.contains(&store) {
+            entry.issues.push(Issue::Orphaned {
+                missing: KeyKind::ClientState.name(),
+            });
+        }
+
+        let stored = &heights[&(store.0, store.1, height)];
+
+        if let KeyKind::ConsensusState { .. } = entry.kind {
+            let missing = [
+                KeyKind::UpdateTime { height },
+                KeyKind::UpdateHeight { height },
+                KeyKind::HeightIndex { height },
+            ]
+            .iter()
+            .map(KeyKind::name)
+            .filter(|name| !stored.contains(name))
+            .collect::<Vec<_>>();
+
+            if !missing.is_empty() {
+                entry.issues.push(Issue::Incomplete { missing });
+            }
+        } else if !stored.contains(KeyKind::ConsensusState { height }.name()) {
+            entry.issues.push(Issue::Orphaned {
+                missing: KeyKind::ConsensusState { height }.name(),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::{Addr, Env, HexBinary, Order, Storage};
+    use ibc_client_cw::context::{Context, CONSENSUS_STATE_HEIGHT_MAP};
+    use ibc_client_tendermint_cw::client_type::TendermintClient;
+
+    use super::*;
+    use crate::helper::{client_id, instantiate_step, update_step};
+    use crate::replay::{Entry, Replayer};
+
+    fn height(revision_height: u64) -> Height {
+        Height::new(0, revision_height).unwrap()
+    }
+
+    fn env() -> Env {
+        let mut env = mock_env();
+        env.contract.address = Addr::unchecked(client_id().as_str());
+        env
+    }
+
+    /// Returns the key of the height index entry at height 5 written by a
+    /// context over the store under `namespace`, with the migration prefix
+    /// `prefix`.
+    fn height_index_key(prefix: Prefix, namespace: &[u8]) -> Vec<u8> {
+        let mut deps = mock_dependencies();
+
+        {
+            let mut ctx = Context::<TendermintClient>::new_mut(deps.as_mut(), env()).unwrap();
+
+            match prefix {
+                Prefix::None => {}
+                Prefix::Subject => ctx.set_subject_prefix(),
+                Prefix::Substitute => ctx.set_substitute_prefix(),
+            }
+
+            let mut ctx = ctx.namespaced_mut::<TendermintClient>(namespace);
+            let height_map = ctx.consensus_state_height_map();
+
+            height_map
+                .save(&mut ctx.height_map_storage_mut(), (0, 5), &Empty {})
+                .unwrap();
+        }
+
+        let mut keys = deps
+            .storage
+            .range(None, None, Order::Ascending)
+            .map(|(key, _)| key);
+        let key = keys.next().unwrap();
+        assert!(keys.next().is_none());
+
+        key
+    }
+
+    /// Moves the entries of `entries` under `namespace`, as a client nested
+    /// in the contract stores them.
+    fn namespaced(entries: Vec<Entry>, namespace: &[u8]) -> Vec<Entry> {
+        let deps = mock_dependencies();
+        let ctx = Context::<TendermintClient>::new_ref(deps.as_ref(), env()).unwrap();
+        let ctx = ctx.namespaced_ref::<TendermintClient>(namespace);
+
+        entries
+            .into_iter()
+            .map(|entry| {
+                let key = match classify(entry.key.as_slice()).2 {
+                    KeyKind::HeightIndex { height } => ctx
+                        .consensus_state_height_map()
+                        .key((height.revision_number(), height.revision_height()))
+                        .to_vec(),
+                    _ => ctx.prefixed_key(entry.key.as_slice()),
+                };
+
+                Entry {
+                    key: key.into(),
+                    value: entry.value,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the storage of a client instantiated at height 5 and updated
+    /// to height 6.
+    fn dump() -> Vec<Entry> {
+        let mut replayer = Replayer::<TendermintClient>::new(client_id(), Snapshot::default());
+
+        replayer.step(instantiate_step());
+        replayer.step(update_step());
+
+        replayer
+            .storage()
+            .into_iter()
+            .map(|(key, value)| Entry {
+                key: key.into(),
+                value: value.into(),
+            })
+            .collect()
+    }
+
+    fn entry<'a>(inspection: &'a Inspection, kind: &KeyKind) -> &'a InspectedEntry {
+        inspection
+            .entries
+            .iter()
+            .find(|entry| entry.kind == *kind)
+            .unwrap()
+    }
+
+    #[test]
+    fn classify_keys() {
+        assert_eq!(
+            classify(b"clientState"),
+            (Prefix::None, vec![], KeyKind::ClientState)
+        );
+        assert_eq!(
+            classify(b"consensusStates/1-10"),
+            (
+                Prefix::None,
+                vec![],
+                KeyKind::ConsensusState {
+                    height: Height::new(1, 10).unwrap()
+                }
+            )
+        );
+        assert_eq!(
+            classify(b"subject/consensusStates/0-5/processedTime"),
+            (
+                Prefix::Subject,
+                vec![],
+                KeyKind::UpdateTime { height: height(5) }
+            )
+        );
+        assert_eq!(
+            classify(b"substitute/consensusStates/0-5/processedHeight"),
+            (
+                Prefix::Substitute,
+                vec![],
+                KeyKind::UpdateHeight { height: height(5) }
+            )
+        );
+        assert_eq!(
+            classify(&CONSENSUS_STATE_HEIGHT_MAP.key((0, 5))),
+            (
+                Prefix::None,
+                vec![],
+                KeyKind::HeightIndex { height: height(5) }
+            )
+        );
+        assert_eq!(
+            classify(b"consensusStates/0-5/other"),
+            (Prefix::None, vec![], KeyKind::Unknown)
+        );
+        assert_eq!(
+            classify(b"consensusStates/latest"),
+            (Prefix::None, vec![], KeyKind::Unknown)
+        );
+    }
+
+    #[test]
+    fn classify_namespaced_keys() {
+        let namespace = b"members/1/".to_vec();
+
+        assert_eq!(
+            classify(b"members/1/clientState"),
+            (Prefix::None, namespace.clone(), KeyKind::ClientState)
+        );
+        assert_eq!(
+            classify(b"subject/members/1/consensusStates/0-5/processedTime"),
+            (
+                Prefix::Subject,
+                namespace.clone(),
+                KeyKind::UpdateTime { height: height(5) }
+            )
+        );
+        assert_eq!(
+            classify(&height_index_key(Prefix::None, &namespace)),
+            (
+                Prefix::None,
+                namespace,
+                KeyKind::HeightIndex { height: height(5) }
+            )
+        );
+    }
+
+    #[test]
+    fn classify_hosted_keys() {
+        let namespace = b"clients/07-tendermint-0/".to_vec();
+
+        assert_eq!(
+            classify(b"clients/07-tendermint-0/clientState"),
+            (Prefix::None, namespace.clone(), KeyKind::ClientState)
+        );
+        assert_eq!(
+            classify(b"clients/07-tendermint-0/consensusStates/0-5"),
+            (
+                Prefix::None,
+                namespace.clone(),
+                KeyKind::ConsensusState { height: height(5) }
+            )
+        );
+        assert_eq!(
+            classify(&height_index_key(Prefix::None, &namespace)),
+            (
+                Prefix::None,
+                namespace,
+                KeyKind::HeightIndex { height: height(5) }
+            )
+        );
+    }
+
+    #[test]
+    fn classify_migration_prefixed_height_index() {
+        assert_eq!(
+            classify(&height_index_key(Prefix::Subject, &[])),
+            (
+                Prefix::Subject,
+                vec![],
+                KeyKind::HeightIndex { height: height(5) }
+            )
+        );
+        assert_eq!(
+            classify(&height_index_key(Prefix::Substitute, &[])),
+            (
+                Prefix::Substitute,
+                vec![],
+                KeyKind::HeightIndex { height: height(5) }
+            )
+        );
+        assert_eq!(
+            classify(&height_index_key(Prefix::Substitute, b"l1/")),
+            (
+                Prefix::Substitute,
+                b"l1/".to_vec(),
+                KeyKind::HeightIndex { height: height(5) }
+            )
+        );
+    }
+
+    #[test]
+    fn inspect_client_storage() {
+        let inspection = inspect::<TendermintClient>(Snapshot::Entries(dump()));
+
+        assert_eq!(inspection.flagged, 0);
+        // The client state, and four entries for each of the two consensus
+        // states.
+        assert_eq!(inspection.entries.len(), 9);
+
+        let client_state = entry(&inspection, &KeyKind::ClientState);
+        assert_eq!(
+            client_state.value["type_url"],
+            "/ibc.lightclients.wasm.v1.ClientState"
+        );
+
+        let update_height = entry(&inspection, &KeyKind::UpdateHeight { height: height(6) });
+        assert_eq!(update_height.value["revision_height"], 11);
+    }
+
+    #[test]
+    fn inspect_flags_broken_entries() {
+        let mut entries = dump()
+            .into_iter()
+            .filter(|entry| entry.key.as_slice() != b"consensusStates/0-6/processedTime")
+            .collect::<Vec<_>>();
+
+        for entry in entries.iter_mut() {
+            if entry.key.as_slice() == b"consensusStates/0-5" {
+                entry.value = b"not a consensus state".to_vec().into();
+            }
+        }
+
+        entries.push(Entry {
+            key: HexBinary::from(b"consensusStates/0-7/processedHeight".to_vec()),
+            value: 12u64.to_be_bytes().to_vec().into(),
+        });
+        entries.push(Entry {
+            key: HexBinary::from(b"leftover".to_vec()),
+            value: b"value".to_vec().into(),
+        });
+
+        let inspection = inspect::<TendermintClient>(Snapshot::Entries(entries));
+
+        assert_eq!(inspection.flagged, 4);
+
+        assert!(matches!(
+            entry(&inspection, &KeyKind::ConsensusState { height: height(5) }).issues[..],
+            [Issue::Undecodable { .. }]
+        ));
+        assert_eq!(
+            entry(&inspection, &KeyKind::ConsensusState { height: height(6) }).issues,
+            [Issue::Incomplete {
+                missing: vec!["update time"]
+            }]
+        );
+        assert_eq!(
+            entry(&inspection, &KeyKind::UpdateHeight { height: height(7) }).issues,
+            [Issue::Orphaned {
+                missing: "consensus state"
+            }]
+        );
+        assert_eq!(
+            entry(&inspection, &KeyKind::Unknown).issues,
+            [Issue::UnknownKey]
+        );
+    }
+
+    #[test]
+    fn inspect_namespaced_storage() {
+        let mut entries = namespaced(dump(), b"members/0/");
+        entries.extend(
+            namespaced(dump(), b"members/1/")
+                .into_iter()
+                .filter(|entry| entry.key.as_slice() != b"members/1/clientState"),
+        );
+
+        let inspection = inspect::<TendermintClient>(Snapshot::Entries(entries));
+
+        assert_eq!(inspection.entries.len(), 17);
+        assert!(inspection
+            .entries
+            .iter()
+            .all(|entry| entry.kind != KeyKind::Unknown));
+
+        // Only the entries of the member without client state are flagged.
+        assert_eq!(inspection.flagged, 8);
+        assert!(inspection
+            .entries
+            .iter()
+            .filter(|entry| !entry.issues.is_empty())
+            .all(|entry| entry.namespace == "members/1/"));
+    }
+
+    #[test]
+    fn inspect_flags_missing_client_state() {
+        let entries = dump()
+            .into_iter()
+            .filter(|entry| entry.key.as_slice() != b"clientState")
+            .collect();
+
+        let inspection = inspect::<TendermintClient>(Snapshot::Entries(entries));
+
+        assert_eq!(inspection.flagged, 8);
+        assert!(
+            entry(&inspection, &KeyKind::HeightIndex { height: height(5) })
+                .issues
+                .contains(&Issue::Orphaned {
+                    missing: "client state"
+                })
+        );
+    }
+}
@@ -0,0 +1,29 @@
+//! Contains a command line tool building the payloads expected by light
+//! client contracts built with the `ibc-client-cw` crate, as operators
+//! submit them through governance proposals:
+//!
+//! - `instantiate` builds the `InstantiateMsg` of a Tendermint client, with
+//!   its `Any`-encoded client and consensus states, from a JSON or TOML
+//!   description;
+//! - `header` and `misbehaviour` encode the Tendermint client messages into
+//!   the `client_message` of the sudo and query messages;
+//! - `decode` turns a value stored by a contract, or one of its responses,
+//!   back into readable JSON;
+//! - `replay` reproduces a recorded log of sudo and query messages against
+//!   a snapshot of the storage of a Tendermint client contract. The
+//!   [`replay`](crate::replay) module runs them for any `ClientType`;
+//! - `inspect` classifies the keys of a storage dump of a Tendermint client
+//!   contract and decodes their values, flagging the orphaned and
+//!   undecodable entries. The [`inspect`](crate::inspect) module does so for
+//!   any `ClientType`.
+
+pub mod cli;
+pub mod decode;
+pub mod encode;
+pub mod error;
+pub mod input;
+pub mod inspect;
+pub mod replay;
+
+#[cfg(test)]
+mod helper;
@@ -0,0 +1,24 @@
+use std::process::ExitCode;
+
+use ibc_client_cw_cli::cli::{Command, USAGE};
+use ibc_client_cw_cli::error::CliError;
+
+fn main() -> ExitCode {
+    match Command::parse(std::env::args().skip(1)).and_then(|command| command.run()) {
+        Ok(output) => {
+            println!("{output}");
+
+            ExitCode::SUCCESS
+        }
+        Err(CliError::Usage(message)) => {
+            eprintln!("error: {message}\n\n{USAGE}");
+
+            ExitCode::from(2)
+        }
+        Err(e) => {
+            eprintln!("error: {e}");
+
+            ExitCode::FAILURE
+        }
+    }
+}
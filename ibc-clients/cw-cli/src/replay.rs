@@ -0,0 +1,497 @@
+//! Replays recorded sudo and query messages against a snapshot of the
+//! storage of a light client contract, to reproduce the behaviour of a
+//! client offline.
+//!
+//! The messages run through the [`Context`] of the [`ClientType`] of the
+//! contract over an in-memory store, and every step reports the response of
+//! the contract, the events `ibc-go` emits for it and the storage it changed.
+//! As on chain, a failed step leaves the storage untouched.
+use core::marker::PhantomData;
+use std::collections::BTreeMap;
+
+use cosmwasm_std::testing::{mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{from_json, Addr, Binary, Env, Event, HexBinary, Order, OwnedDeps, Storage};
+use ibc_client_cw::api::ClientType;
+use ibc_client_cw::context::Context;
+use ibc_client_cw::types::{ContractError, ContractResult, InstantiateMsg, QueryMsg, SudoMsg};
+use ibc_core::host::types::identifiers::ClientId;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tendermint::Time;
+
+use crate::decode::decode;
+use crate::error::CliError;
+
+/// The client type of the 08-wasm clients, as reported in the `ibc-go`
+/// events.
+pub const WASM_CLIENT_TYPE: &str = "08-wasm";
+
+/// A key/value export of the storage of a contract, either as the `models`
+/// printed by `wasmd query wasm contract-state all` or as their bare list.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Snapshot {
+    Models { models: Vec<Entry> },
+    Entries(Vec<Entry>),
+}
+
+impl Default for Snapshot {
+    /// The storage of a contract yet to be instantiated.
+    fn default() -> Self {
+        Self::Entries(Vec::new())
+    }
+}
+
+/// An entry of a storage export, with the key in hex and the value in
+/// base64.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub key: HexBinary,
+    pub value: Binary,
+}
+
+impl Snapshot {
+    pub fn entries(self) -> Vec<Entry> {
+        match self {
+            Self::Models { models } => models,
+            Self::Entries(entries) => entries,
+        }
+    }
+}
+
+/// A recorded message along with the block it was executed at, read from a
+/// line of the replayed log, such as
+/// `{"block_height":12,"block_time":"2024-01-01T00:00:00Z","sudo":{..}}`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "RawStep", into = "RawStep")]
+pub struct Step {
+    pub block_height: u64,
+    /// The block time, in RFC 3339.
+    pub block_time: Time,
+    pub msg: StepMsg,
+}
+
+#[derive(Clone, Debug)]
+pub enum StepMsg {
+    Instantiate(InstantiateMsg),
+    Sudo(SudoMsg),
+    Query(QueryMsg),
+}
+
+/// The JSON layout of a [`Step`], with its message under the `instantiate`,
+/// `sudo` or `query` key.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct RawStep {
+    block_height: u64,
+    block_time: Time,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    instantiate: Option<InstantiateMsg>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sudo: Option<SudoMsg>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query: Option<QueryMsg>,
+}
+
+impl TryFrom<RawStep> for Step {
+    type Error = String;
+
+    fn try_from(raw: RawStep) -> Result<Self, Self::Error> {
+        let msg = match (raw.instantiate, raw.sudo, raw.query) {
+            (Some(msg), None, None) => StepMsg::Instantiate(msg),
+            (None, Some(msg), None) => StepMsg::Sudo(msg),
+            (None, None, Some(msg)) => StepMsg::Query(msg),
+            _ => {
+                return Err(
+                    "a step must have exactly one of `instantiate`, `sudo` or `query`".to_string(),
+                )
+            }
+        };
+
+        Ok(Self {
+            block_height: raw.block_height,
+            block_time: raw.block_time,
+            msg,
+        })
+    }
+}
+
+impl From<Step> for RawStep {
+    fn from(step: Step) -> Self {
+        let (instantiate, sudo, query) = match step.msg {
+            StepMsg::Instantiate(msg) => (Some(msg), None, None),
+            StepMsg::Sudo(msg) => (None, Some(msg), None),
+            StepMsg::Query(msg) => (None, None, Some(msg)),
+        };
+
+        Self {
+            block_height: step.block_height,
+            block_time: step.block_time,
+            instantiate,
+            sudo,
+            query,
+        }
+    }
+}
+
+impl StepMsg {
+    /// Returns the name of the message, as in its JSON encoding.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Instantiate(_) => "instantiate",
+            Self::Sudo(SudoMsg::UpdateState(_)) => "update_state",
+            Self::Sudo(SudoMsg::UpdateStateOnMisbehaviour(_)) => "update_state_on_misbehaviour",
+            Self::Sudo(SudoMsg::VerifyUpgradeAndUpdateState(_)) => {
+                "verify_upgrade_and_update_state"
+            }
+            Self::Sudo(SudoMsg::VerifyMembership(_)) => "verify_membership",
+            Self::Sudo(SudoMsg::VerifyNonMembership(_)) => "verify_non_membership",
+            Self::Sudo(SudoMsg::MigrateClientStore(_)) => "migrate_client_store",
+            Self::Query(QueryMsg::Status(_)) => "status",
+            Self::Query(QueryMsg::TimestampAtHeight(_)) => "timestamp_at_height",
+            Self::Query(QueryMsg::VerifyClientMessage(_)) => "verify_client_message",
+            Self::Query(QueryMsg::CheckForMisbehaviour(_)) => "check_for_misbehaviour",
+        }
+    }
+}
+
+/// Parses a log of steps, one JSON object per line. Blank lines are skipped.
+pub fn parse_log(input: &str) -> Result<Vec<Step>, CliError> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| Ok(serde_json::from_str(line)?))
+        .collect()
+}
+
+/// What a step did, as printed by the tool.
+#[derive(Clone, Debug, Serialize)]
+pub struct StepReport {
+    pub step: usize,
+    pub msg: &'static str,
+    pub block_height: u64,
+    pub block_time: Time,
+    pub result: StepResult,
+    pub events: Vec<Event>,
+    pub storage_diff: Vec<StorageChange>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepResult {
+    /// The response of the contract, decoded to JSON.
+    Ok(Value),
+    Error(String),
+}
+
+/// A key of the storage written or removed by a step. The values are
+/// decoded when they are `Any` or JSON.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct StorageChange {
+    pub key: String,
+    pub before: Option<Binary>,
+    pub after: Option<Binary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decoded: Option<Value>,
+}
+
+/// Renders a storage key with its printable ASCII bytes as is and the others
+/// escaped, as the keys mix path segments with big-endian integers.
+pub fn display_key(key: &[u8]) -> String {
+    key.iter()
+        .map(|&byte| match byte {
+            b' '..=b'~' if byte != b'\\' => char::from(byte).to_string(),
+            _ => format!("\\x{byte:02x}"),
+        })
+        .collect()
+}
+
+/// Replays steps against the storage of a contract of the client type `C`.
+pub struct Replayer<C> {
+    deps: OwnedDeps<MockStorage, MockApi, MockQuerier>,
+    client_id: ClientId,
+    steps: usize,
+    client_type: PhantomData<C>,
+}
+
+impl<C> Replayer<C>
+where
+    C: for<'a> ClientType<'a>,
+{
+    /// Loads the snapshot of the storage of the contract of the given
+    /// client.
+    pub fn new(client_id: ClientId, snapshot: Snapshot) -> Self {
+        let mut deps = mock_dependencies();
+
+        for entry in snapshot.entries() {
+            deps.storage
+                .set(entry.key.as_slice(), entry.value.as_slice());
+        }
+
+        Self {
+            deps,
+            client_id,
+            steps: 0,
+            client_type: PhantomData,
+        }
+    }
+
+    /// Returns the current content of the storage.
+    pub fn storage(&self) -> BTreeMap<Vec<u8>, Vec<u8>> {
+        self.deps
+            .storage
+            .range(None, None, Order::Ascending)
+            .collect()
+    }
+
+    /// Runs a step, reverting its writes if it fails.
+    pub fn step(&mut self, step: Step) -> StepReport {
+        let before = self.storage();
+
+        let env = self.env(&step);
+        let name = step.msg.name();
+
+        let result = match step.msg.clone() {
+            StepMsg::Instantiate(msg) => Context::<C>::new_mut(self.deps.as_mut(), env)
+                .and_then(|mut ctx| ctx.instantiate(msg)),
+            StepMsg::Sudo(msg) => {
+                Context::<C>::new_mut(self.deps.as_mut(), env).and_then(|mut ctx| ctx.sudo(msg))
+            }
+            StepMsg::Query(msg) => {
+                Context::<C>::new_ref(self.deps.as_ref(), env).and_then(|ctx| ctx.query(msg))
+            }
+        };
+
+        if result.is_err() {
+            self.restore(&before);
+        }
+
+        self.steps += 1;
+
+        StepReport {
+            step: self.steps,
+            msg: name,
+            block_height: step.block_height,
+            block_time: step.block_time,
+            events: self.events(&step.msg, &result),
+            result: match result {
+                Ok(response) => match decode(&response) {
+                    Ok(json) => StepResult::Ok(json),
+                    Err(e) => StepResult::Error(format!("undecodable response: {e}")),
+                },
+                Err(e) => StepResult::Error(e.to_string()),
+            },
+            storage_diff: storage_diff(&before, &self.storage()),
+        }
+    }
+
+    fn env(&self, step: &Step) -> Env {
+        let mut env = mock_env();
+
+        env.block.height = step.block_height;
+        env.block.time = cosmwasm_std::Timestamp::from_nanos(
+            step.block_time
+                .unix_timestamp_nanos()
+                .try_into()
+                .unwrap_or_default(),
+        );
+        env.contract.address = Addr::unchecked(self.client_id.as_str());
+
+        env
+    }
+
+    fn restore(&mut self, storage: &BTreeMap<Vec<u8>, Vec<u8>>) {
+        let mut restored = MockStorage::new();
+
+        for (key, value) in storage {
+            restored.set(key, value);
+        }
+
+        self.deps.storage = restored;
+    }
+
+    /// Returns the events the 02-client module of `ibc-go` emits after the
+    /// successful sudo calls it makes to the contract.
+    fn events(&self, msg: &StepMsg, result: &Result<Binary, ContractError>) -> Vec<Event> {
+        let Ok(response) = result else {
+            return Vec::new();
+        };
+
+        let client_id = self.client_id.to_string();
+
+        let event = match msg {
+            StepMsg::Sudo(SudoMsg::UpdateState(_)) => {
+                let heights = from_json::<ContractResult>(response)
+                    .ok()
+                    .and_then(|result| result.heights)
+                    .unwrap_or_default();
+
+                let consensus_heights = heights
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(",");
+
+                Event::new("update_client")
+                    .add_attribute("client_id", client_id)
+                    .add_attribute("client_type", WASM_CLIENT_TYPE)
+                    .add_attribute("consensus_heights", consensus_heights)
+            }
+            StepMsg::Sudo(SudoMsg::UpdateStateOnMisbehaviour(_)) => {
+                Event::new("client_misbehaviour")
+                    .add_attribute("client_id", client_id)
+                    .add_attribute("client_type", WASM_CLIENT_TYPE)
+            }
+            StepMsg::Sudo(SudoMsg::VerifyUpgradeAndUpdateState(_)) => Event::new("upgrade_client")
+                .add_attribute("client_id", client_id)
+                .add_attribute("client_type", WASM_CLIENT_TYPE),
+            StepMsg::Sudo(SudoMsg::MigrateClientStore(_)) => Event::new("recover_client")
+                .add_attribute("subject_client_id", client_id)
+                .add_attribute("client_type", WASM_CLIENT_TYPE),
+            _ => return Vec::new(),
+        };
+
+        vec![event]
+    }
+}
+
+/// Replays all the steps of a log, one after the other.
+pub fn replay<C>(client_id: ClientId, snapshot: Snapshot, steps: Vec<Step>) -> Vec<StepReport>
+where
+    C: for<'a> ClientType<'a>,
+{
+    let mut replayer = Replayer::<C>::new(client_id, snapshot);
+
+    steps.into_iter().map(|step| replayer.step(step)).collect()
+}
+
+/// Lists the keys whose value differs between the two storages.
+pub fn storage_diff(
+    before: &BTreeMap<Vec<u8>, Vec<u8>>,
+    after: &BTreeMap<Vec<u8>, Vec<u8>>,
+) -> Vec<StorageChange> {
+    let mut keys = before.keys().chain(after.keys()).collect::<Vec<_>>();
+    keys.sort();
+    keys.dedup();
+
+    keys.into_iter()
+        .filter(|key| before.get(*key) != after.get(*key))
+        .map(|key| {
+            let after = after.get(key);
+
+            StorageChange {
+                key: display_key(key),
+                before: before.get(key).cloned().map(Binary::from),
+                after: after.cloned().map(Binary::from),
+                decoded: after.and_then(|value| decode(value).ok()),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_client_cw::types::UpdateStateMsgRaw;
+    use ibc_client_tendermint_cw::client_type::TendermintClient;
+    use serde_json::json;
+
+    use super::*;
+    use crate::helper::{client_id, instantiate_step, status_step, step, update_step};
+
+    #[test]
+    fn replay_tendermint_update() {
+        let reports = replay::<TendermintClient>(
+            client_id(),
+            Snapshot::default(),
+            vec![instantiate_step(), update_step(), status_step()],
+        );
+
+        assert!(matches!(reports[0].result, StepResult::Ok(_)));
+        assert!(reports[0]
+            .storage_diff
+            .iter()
+            .any(|change| change.key == "clientState" && change.before.is_none()));
+
+        assert!(matches!(reports[1].result, StepResult::Ok(_)));
+        assert_eq!(reports[1].events.len(), 1);
+        assert_eq!(reports[1].events[0].ty, "update_client");
+        assert_eq!(reports[1].events[0].attributes[2].value, "0-6");
+        assert!(reports[1]
+            .storage_diff
+            .iter()
+            .any(|change| change.key == "consensusStates/0-6"));
+
+        match &reports[2].result {
+            StepResult::Ok(json) => assert_eq!(json, &json!({ "status": "Active" })),
+            StepResult::Error(e) => panic!("status query failed: {e}"),
+        }
+        assert!(reports[2].storage_diff.is_empty());
+    }
+
+    #[test]
+    fn failed_step_reverts_storage() {
+        let mut replayer = Replayer::<TendermintClient>::new(client_id(), Snapshot::default());
+
+        replayer.step(instantiate_step());
+
+        let before = replayer.storage();
+
+        let report = replayer.step(step(
+            11,
+            StepMsg::Sudo(SudoMsg::UpdateState(UpdateStateMsgRaw {
+                client_message: Binary::from(b"not a header".to_vec()),
+            })),
+        ));
+
+        assert!(matches!(report.result, StepResult::Error(_)));
+        assert!(report.events.is_empty());
+        assert!(report.storage_diff.is_empty());
+        assert_eq!(replayer.storage(), before);
+    }
+
+    #[test]
+    fn replay_from_snapshot() {
+        let mut replayer = Replayer::<TendermintClient>::new(client_id(), Snapshot::default());
+
+        replayer.step(instantiate_step());
+
+        let models = replayer
+            .storage()
+            .into_iter()
+            .map(
+                |(key, value)| json!({ "key": HexBinary::from(key), "value": Binary::from(value) }),
+            )
+            .collect::<Vec<_>>();
+
+        let snapshot: Snapshot =
+            serde_json::from_value(json!({ "models": models, "pagination": {} })).unwrap();
+
+        let log = [update_step(), status_step()]
+            .iter()
+            .map(|step| serde_json::to_string(step).unwrap())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let reports = replay::<TendermintClient>(client_id(), snapshot, parse_log(&log).unwrap());
+
+        assert_eq!(reports[0].msg, "update_state");
+        assert!(matches!(reports[0].result, StepResult::Ok(_)));
+        assert_eq!(reports[1].msg, "status");
+        assert!(matches!(reports[1].result, StepResult::Ok(_)));
+    }
+
+    #[test]
+    fn step_with_several_messages_is_rejected() {
+        let line = r#"{"block_height":1,"block_time":"2024-01-01T00:00:00Z","sudo":{},"query":{}}"#;
+
+        assert!(parse_log(line).is_err());
+    }
+
+    #[test]
+    fn display_storage_keys() {
+        assert_eq!(display_key(b"clientState"), "clientState");
+        assert_eq!(
+            display_key(&[0, 5, b'a', b'\\', 0xff]),
+            "\\x00\\x05a\\x5c\\xff"
+        );
+    }
+}
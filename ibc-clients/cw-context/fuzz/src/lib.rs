@@ -0,0 +1,166 @@
+//! Fuzzing harnesses of the message decoding and handlers of `ibc-client-cw`,
+//! run by the targets under `fuzz_targets/` with `cargo fuzz`. The handlers
+//! are driven through the mock client, over mock storage. Any input must be
+//! rejected with a `ContractError` rather than a panic.
+use cosmwasm_std::testing::{mock_dependencies, mock_env};
+use cosmwasm_std::{from_json, DepsMut, Order, Storage};
+use ibc_client_cw::types::{
+    CheckForMisbehaviourMsg, InstantiateMsg, QueryMsg, StatusMsg, SudoMsg, TimestampAtHeightMsg,
+    UpdateStateMsg, UpdateStateMsgRaw, UpdateStateOnMisbehaviourMsg, VerifyClientMessageMsg,
+    VerifyMembershipMsg, VerifyNonMembershipMsg, VerifyUpgradeAndUpdateStateMsg,
+};
+use ibc_client_cw::utils::AnyCodec;
+use ibc_client_mock_cw::client_state::ClientState;
+use ibc_client_mock_cw::entrypoint::MockContext;
+use ibc_client_wasm_types::client_state::ClientState as WasmClientState;
+use ibc_client_wasm_types::consensus_state::ConsensusState as WasmConsensusState;
+use ibc_core::client::types::Height;
+use ibc_core::primitives::proto::{Any, Protobuf};
+use ibc_core::primitives::Timestamp;
+use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+
+/// Decodes the input as a [`SudoMsg`] and converts it into its domain type.
+pub fn sudo_msg(data: &[u8]) {
+    let Ok(msg) = from_json::<SudoMsg>(data) else {
+        return;
+    };
+
+    let _ = match msg {
+        SudoMsg::UpdateState(msg) => UpdateStateMsg::try_from(msg).map(drop),
+        SudoMsg::UpdateStateOnMisbehaviour(msg) => {
+            UpdateStateOnMisbehaviourMsg::try_from(msg).map(drop)
+        }
+        SudoMsg::VerifyUpgradeAndUpdateState(msg) => {
+            VerifyUpgradeAndUpdateStateMsg::try_from(msg).map(drop)
+        }
+        SudoMsg::VerifyMembership(msg) => VerifyMembershipMsg::try_from(msg).map(drop),
+        SudoMsg::VerifyNonMembership(msg) => VerifyNonMembershipMsg::try_from(msg).map(drop),
+        SudoMsg::MigrateClientStore(_) => Ok(()),
+    };
+}
+
+/// Decodes the input as a [`QueryMsg`] and converts it into its domain type.
+pub fn query_msg(data: &[u8]) {
+    let Ok(msg) = from_json::<QueryMsg>(data) else {
+        return;
+    };
+
+    let _ = match msg {
+        QueryMsg::Status(_) | QueryMsg::TimestampAtHeight(_) => Ok(()),
+        QueryMsg::VerifyClientMessage(msg) => VerifyClientMessageMsg::try_from(msg).map(drop),
+        QueryMsg::CheckForMisbehaviour(msg) => CheckForMisbehaviourMsg::try_from(msg).map(drop),
+    };
+}
+
+/// Decodes the input as the 08-wasm client and consensus states, and their
+/// data as the states of the mock client, as the context does on reads.
+pub fn any_codec(data: &[u8]) {
+    if let Ok(wasm_client_state) = <WasmClientState as Protobuf<Any>>::decode(data) {
+        let _ = ClientState::decode_any_vec::<ClientState>(wasm_client_state.data);
+    }
+
+    if let Ok(wasm_consensus_state) =
+        WasmConsensusState::decode_any_vec::<WasmConsensusState>(data.to_vec())
+    {
+        let _ = MockConsensusState::decode_any_vec::<MockConsensusState>(wasm_consensus_state.data);
+    }
+}
+
+/// Handles the input, after a selector byte, as a [`SudoMsg`] or a
+/// [`QueryMsg`] sent to a created mock client.
+pub fn context(data: &[u8]) {
+    let Some((selector, msg)) = data.split_first() else {
+        return;
+    };
+
+    let mut deps = mock_dependencies();
+
+    create_client(deps.as_mut());
+
+    if selector % 2 == 0 {
+        if let Ok(msg) = from_json::<SudoMsg>(msg) {
+            let _ = MockContext::new_mut(deps.as_mut(), mock_env())
+                .expect("never fails")
+                .sudo(msg);
+        }
+    } else if let Ok(msg) = from_json::<QueryMsg>(msg) {
+        let _ = MockContext::new_ref(deps.as_ref(), mock_env())
+            .expect("never fails")
+            .query(msg);
+    }
+}
+
+/// Overwrites the entry of the store of a created mock client, picked by a
+/// selector byte, with the rest of the input, before querying and updating
+/// the client.
+pub fn client_store(data: &[u8]) {
+    let Some((selector, value)) = data.split_first() else {
+        return;
+    };
+
+    // CosmWasm does not store empty values.
+    if value.is_empty() {
+        return;
+    }
+
+    let mut deps = mock_dependencies();
+
+    create_client(deps.as_mut());
+
+    let keys: Vec<Vec<u8>> = deps
+        .storage
+        .range(None, None, Order::Ascending)
+        .map(|(key, _)| key)
+        .collect();
+
+    deps.storage
+        .set(&keys[usize::from(*selector) % keys.len()], value);
+
+    let ctx = MockContext::new_ref(deps.as_ref(), mock_env()).expect("never fails");
+
+    let _ = ctx.query(StatusMsg {}.into());
+    let _ = ctx.query(
+        TimestampAtHeightMsg {
+            height: trusted_height(),
+        }
+        .into(),
+    );
+
+    let _ = MockContext::new_mut(deps.as_mut(), mock_env())
+        .expect("never fails")
+        .sudo(
+            UpdateStateMsgRaw {
+                client_message: MockHeader::encode_to_any_vec(dummy_header(
+                    trusted_height().increment(),
+                ))
+                .into(),
+            }
+            .into(),
+        );
+}
+
+fn trusted_height() -> Height {
+    Height::new(0, 1).expect("never fails")
+}
+
+fn dummy_header(height: Height) -> MockHeader {
+    MockHeader::new(height)
+        .with_timestamp(Timestamp::from_nanoseconds(mock_env().block.time.nanos()))
+}
+
+fn create_client(deps: DepsMut<'_>) {
+    let header = dummy_header(trusted_height());
+
+    let msg = InstantiateMsg {
+        client_state: ClientState::encode_to_any_vec(ClientState::new(header)).into(),
+        consensus_state: MockConsensusState::encode_to_any_vec(MockConsensusState::new(header))
+            .into(),
+        checksum: vec![0; 32].into(),
+    };
+
+    MockContext::new_mut(deps, mock_env())
+        .expect("never fails")
+        .instantiate(msg)
+        .expect("the mock client is created");
+}
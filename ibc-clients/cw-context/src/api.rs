@@ -2,6 +2,8 @@ use core::fmt::Display;
 
 use ibc_core::client::context::client_state::ClientStateExecution;
 use ibc_core::client::context::consensus_state::ConsensusState as ConsensusStateTrait;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Height;
 use ibc_core::primitives::proto::Any;
 
 use crate::context::Context;
@@ -16,4 +18,16 @@ where
 {
     type ClientState: ClientStateExecution<Context<'a, Self>>;
     type ConsensusState: ConsensusStateTrait;
+
+    /// Checks that the consensus state at `height` can be used to verify
+    /// membership and non-membership proofs. Any stored consensus state can
+    /// be used by default, while clients whose consensus states are first
+    /// pending, such as optimistic clients, reject the pending ones.
+    fn verify_proof_height(
+        _ctx: &Context<'a, Self>,
+        _client_state: &Self::ClientState,
+        _height: &Height,
+    ) -> Result<(), ClientError> {
+        Ok(())
+    }
 }
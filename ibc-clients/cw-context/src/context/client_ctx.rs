@@ -12,9 +12,8 @@ use ibc_core::host::types::path::{ClientConsensusStatePath, ClientStatePath};
 use ibc_core::primitives::proto::{Any, Protobuf};
 use ibc_core::primitives::Timestamp;
 
-use super::{Context, StorageMut};
+use super::Context;
 use crate::api::ClientType;
-use crate::context::CONSENSUS_STATE_HEIGHT_MAP;
 use crate::utils::AnyCodec;
 
 impl<'a, C: ClientType<'a>> ClientValidationContext for Context<'a, C>
@@ -159,9 +158,9 @@ where
 
         self.insert(prefixed_height_key, revision_height_vec);
 
-        CONSENSUS_STATE_HEIGHT_MAP
+        self.consensus_state_height_map()
             .save(
-                self.storage_mut(),
+                &mut self.height_map_storage_mut(),
                 (height.revision_number(), height.revision_height()),
                 &Default::default(),
             )
@@ -187,8 +186,8 @@ where
 
         self.remove(prefixed_height_key);
 
-        CONSENSUS_STATE_HEIGHT_MAP.remove(
-            self.storage_mut(),
+        self.consensus_state_height_map().remove(
+            &mut self.height_map_storage_mut(),
             (height.revision_number(), height.revision_height()),
         );
 
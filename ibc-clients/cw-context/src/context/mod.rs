@@ -2,24 +2,26 @@ pub mod client_ctx;
 pub mod custom_ctx;
 
 use core::fmt::Display;
+use std::borrow::Cow;
 use std::str::FromStr;
 
-use cosmwasm_std::{Binary, CustomQuery, Deps, DepsMut, Empty, Env, Order, Storage};
-use cw_storage_plus::{Bound, Map};
+use cosmwasm_std::{Api, Binary, CustomQuery, Deps, DepsMut, Empty, Env, Order, Storage};
+use cw_storage_plus::{range_with_prefix, Bound, KeyDeserialize, Map, Prefix};
 use ibc_client_wasm_types::client_state::ClientState as WasmClientState;
 use ibc_core::client::context::client_state::ClientStateCommon;
 use ibc_core::client::types::Height;
 use ibc_core::host::types::error::HostError;
 use ibc_core::host::types::identifiers::ClientId;
 use ibc_core::host::types::path::{
-    ClientStatePath, ClientUpdateHeightPath, ClientUpdateTimePath, ITERATE_CONSENSUS_STATE_PREFIX,
+    ClientStatePath, ClientUpdateHeightPath, ClientUpdateTimePath, CLIENT_PREFIX,
+    ITERATE_CONSENSUS_STATE_PREFIX,
 };
 use ibc_core::primitives::proto::{Any, Protobuf};
 use prost::Message;
 
 use crate::api::ClientType;
 use crate::types::{ContractError, HeightTravel, MigrationPrefix};
-use crate::utils::AnyCodec;
+use crate::utils::{AnyCodec, PrefixedStorage};
 
 /// - [`Height`] cannot be used directly as keys in the map,
 ///   as it doesn't implement some cw_storage specific traits.
@@ -44,6 +46,7 @@ where
     client_id: ClientId,
     checksum: Option<Binary>,
     migration_prefix: MigrationPrefix,
+    namespace: Vec<u8>,
     client_type: std::marker::PhantomData<C>,
 }
 
@@ -65,6 +68,7 @@ where
             client_id,
             checksum: None,
             migration_prefix: MigrationPrefix::None,
+            namespace: Vec::new(),
             client_type: std::marker::PhantomData::<C>,
         })
     }
@@ -80,15 +84,64 @@ where
             client_id,
             checksum: None,
             migration_prefix: MigrationPrefix::None,
+            namespace: Vec::new(),
             client_type: std::marker::PhantomData::<C>,
         })
     }
 
+    /// Constructs a new Context object over the store of the given client,
+    /// hosted along with other clients by an IBC core contract rather than
+    /// instantiated as its own contract. The keys of the client are prefixed
+    /// with `clients/{client_id}/`, so that they match its ICS-24 paths.
+    pub fn new_hosted_ref(deps: Deps<'a, Q>, env: Env, client_id: ClientId) -> Self {
+        Self {
+            deps: Some(deps),
+            deps_mut: None,
+            env,
+            namespace: Self::hosted_namespace(&client_id),
+            client_id,
+            checksum: None,
+            migration_prefix: MigrationPrefix::None,
+            client_type: std::marker::PhantomData::<C>,
+        }
+    }
+
+    /// Constructs a new mutable Context object over the store of the given
+    /// hosted client. See [`Context::new_hosted_ref`].
+    pub fn new_hosted_mut(deps_mut: DepsMut<'a, Q>, env: Env, client_id: ClientId) -> Self {
+        Self {
+            deps: None,
+            deps_mut: Some(deps_mut),
+            env,
+            namespace: Self::hosted_namespace(&client_id),
+            client_id,
+            checksum: None,
+            migration_prefix: MigrationPrefix::None,
+            client_type: std::marker::PhantomData::<C>,
+        }
+    }
+
+    fn hosted_namespace(client_id: &ClientId) -> Vec<u8> {
+        format!("{CLIENT_PREFIX}/{client_id}/").into_bytes()
+    }
+
     /// Returns the env of the context.
     pub fn env(&self) -> &Env {
         &self.env
     }
 
+    /// Returns the API of the context, which exposes the host-provided
+    /// cryptographic primitives to the light client.
+    pub fn api(&self) -> &dyn Api {
+        match self.deps {
+            Some(ref deps) => deps.api,
+            None => match self.deps_mut {
+                Some(ref deps) => deps.api,
+                None => panic!("Either deps or deps_mut should be available"),
+            },
+        }
+    }
+
     /// Logs the given message.
     pub fn log(&self, msg: &str) -> Option<()> {
         self.deps.map(|deps| deps.api.debug(msg))
@@ -114,15 +167,117 @@ where
         self.migration_prefix = MigrationPrefix::Substitute;
     }
 
-    /// Prefixes the given key with the migration prefix.
+    /// Returns a context over the store of a client nested in this one, such
+    /// as a member of a composite client, whose keys are prefixed with the
+    /// given namespace. The namespace comes after the migration prefix, so
+    /// that the nested store is migrated along with the store of this client.
+    pub fn namespaced_ref<'b, D>(&'b self, namespace: impl AsRef<[u8]>) -> Context<'b, D, Q>
+    where
+        D: ClientType<'b>,
+        <D::ClientState as TryFrom<Any>>::Error: Display,
+        <D::ConsensusState as TryFrom<Any>>::Error: Display,
+    {
+        Context {
+            deps: self
+                .deps
+                .or_else(|| self.deps_mut.as_ref().map(|deps| deps.as_ref())),
+            deps_mut: None,
+            env: self.env.clone(),
+            client_id: self.client_id.clone(),
+            checksum: self.checksum.clone(),
+            migration_prefix: self.migration_prefix.clone(),
+            namespace: [self.namespace.as_slice(), namespace.as_ref()].concat(),
+            client_type: std::marker::PhantomData::<D>,
+        }
+    }
+
+    /// Returns a mutable context over the store of a client nested in this
+    /// one. See [`Context::namespaced_ref`].
+    pub fn namespaced_mut<'b, D>(&'b mut self, namespace: impl AsRef<[u8]>) -> Context<'b, D, Q>
+    where
+        D: ClientType<'b>,
+        <D::ClientState as TryFrom<Any>>::Error: Display,
+        <D::ConsensusState as TryFrom<Any>>::Error: Display,
+    {
+        Context {
+            deps: self.deps,
+            deps_mut: self.deps_mut.as_mut().map(|deps| deps.branch()),
+            env: self.env.clone(),
+            client_id: self.client_id.clone(),
+            checksum: self.checksum.clone(),
+            migration_prefix: self.migration_prefix.clone(),
+            namespace: [self.namespace.as_slice(), namespace.as_ref()].concat(),
+            client_type: std::marker::PhantomData::<D>,
+        }
+    }
+
+    /// Prefixes the given key with the migration prefix and the namespace.
     pub fn prefixed_key(&self, key: impl AsRef<[u8]>) -> Vec<u8> {
         let mut prefixed_key = Vec::new();
         prefixed_key.extend_from_slice(self.migration_prefix.key());
+        prefixed_key.extend_from_slice(&self.namespace);
         prefixed_key.extend_from_slice(key.as_ref());
 
         prefixed_key
     }
 
+    /// Returns the sorted set of the heights of the stored consensus states,
+    /// kept apart for every namespace. Its keys are not migration prefixed,
+    /// and must be written through [`Context::height_map_storage_mut`].
+    pub fn consensus_state_height_map(&self) -> Map<(u64, u64), Empty> {
+        if self.namespace.is_empty() {
+            return CONSENSUS_STATE_HEIGHT_MAP;
+        }
+
+        let namespace = [
+            self.namespace.as_slice(),
+            ITERATE_CONSENSUS_STATE_PREFIX.as_bytes(),
+        ]
+        .concat();
+
+        Map::new_dyn(Cow::Owned(namespace))
+    }
+
+    /// Returns the mutable storage of the consensus state heights, under the
+    /// migration prefix. The prefix must come before the length-prefixed
+    /// namespace of the height map, as the 08-wasm migration store splits
+    /// the keys between the subject and substitute stores by their leading
+    /// bytes.
+    pub fn height_map_storage_mut(&mut self) -> PrefixedStorage<'_> {
+        let prefix = self.migration_prefix.key().to_vec();
+
+        PrefixedStorage::new(self.storage_mut(), prefix)
+    }
+
+    /// Iterates over the heights of the stored consensus states within the
+    /// given bounds, reading the height map under the migration prefix. See
+    /// [`Context::height_map_storage_mut`].
+    fn height_range(
+        &self,
+        min: Option<Bound<'_, (u64, u64)>>,
+        max: Option<Bound<'_, (u64, u64)>>,
+        order: Order,
+    ) -> impl Iterator<Item = Result<Height, HostError>> + '_ {
+        let height_map = self.consensus_state_height_map();
+
+        let namespace = Prefix::<(u64, u64), Empty>::new(height_map.namespace_bytes(), &[]);
+
+        let height_map_prefix = [self.migration_prefix.key(), &namespace].concat();
+
+        range_with_prefix(
+            self.storage_ref(),
+            &height_map_prefix,
+            min.map(|bound| bound.to_raw_bound()),
+            max.map(|bound| bound.to_raw_bound()),
+            order,
+        )
+        .map(|(key, _)| {
+            let (rev_number, rev_height) =
+                <(u64, u64)>::from_vec(key).map_err(HostError::failed_to_retrieve)?;
+            Height::new(rev_number, rev_height).map_err(HostError::invalid_state)
+        })
+    }
+
     /// Retrieves the value of the given key.
     pub fn retrieve(&self, key: impl AsRef<[u8]>) -> Result<Vec<u8>, HostError> {
         let prefixed_key = self.prefixed_key(key);
@@ -149,14 +304,7 @@ where
 
     /// Returns the storage of the context.
     pub fn get_heights(&self) -> Result<Vec<Height>, HostError> {
-        CONSENSUS_STATE_HEIGHT_MAP
-            .keys(self.storage_ref(), None, None, Order::Ascending)
-            .map(|deserialized_result| {
-                let (rev_number, rev_height) =
-                    deserialized_result.map_err(HostError::failed_to_retrieve)?;
-                Height::new(rev_number, rev_height).map_err(HostError::invalid_state)
-            })
-            .collect()
+        self.height_range(None, None, Order::Ascending).collect()
     }
 
     /// Searches for either the earliest next or latest previous height based on
@@ -166,35 +314,14 @@ where
         height: &Height,
         travel: HeightTravel,
     ) -> Result<Option<Height>, HostError> {
-        let iterator = match travel {
-            HeightTravel::Prev => CONSENSUS_STATE_HEIGHT_MAP.range(
-                self.storage_ref(),
-                None,
-                Some(Bound::exclusive((
-                    height.revision_number(),
-                    height.revision_height(),
-                ))),
-                Order::Descending,
-            ),
-            HeightTravel::Next => CONSENSUS_STATE_HEIGHT_MAP.range(
-                self.storage_ref(),
-                Some(Bound::exclusive((
-                    height.revision_number(),
-                    height.revision_height(),
-                ))),
-                None,
-                Order::Ascending,
-            ),
+        let bound = Bound::exclusive((height.revision_number(), height.revision_height()));
+
+        let mut iterator = match travel {
+            HeightTravel::Prev => self.height_range(None, Some(bound), Order::Descending),
+            HeightTravel::Next => self.height_range(Some(bound), None, Order::Ascending),
         };
 
-        iterator
-            .map(|deserialized_result| {
-                let ((rev_number, rev_height), _) =
-                    deserialized_result.map_err(HostError::failed_to_retrieve)?;
-                Height::new(rev_number, rev_height).map_err(HostError::invalid_state)
-            })
-            .next()
-            .transpose()
+        iterator.next().transpose()
     }
 
     /// Returns the key for the client update time.
@@ -287,3 +414,146 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, MockStorage};
+    use cosmwasm_std::Addr;
+    use ibc_core::client::context::ExtClientValidationContext;
+    use ibc_core::primitives::Timestamp;
+    use ibc_testkit::testapp::ibc::clients::mock::client_state::{
+        MockClientContext, MockClientState,
+    };
+    use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+    use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+
+    use super::*;
+    use crate::types::{InstantiateMsg, MigrateClientStoreMsg, SudoMsg, UpdateStateMsgRaw};
+
+    struct MockClient;
+
+    impl ClientType<'_> for MockClient {
+        type ClientState = MockClientState;
+        type ConsensusState = MockConsensusState;
+    }
+
+    impl MockClientContext for Context<'_, MockClient> {
+        fn host_timestamp(&self) -> Result<Timestamp, HostError> {
+            ExtClientValidationContext::host_timestamp(self)
+        }
+
+        fn host_height(&self) -> Result<Height, HostError> {
+            ExtClientValidationContext::host_height(self)
+        }
+    }
+
+    fn env() -> Env {
+        let mut env = mock_env();
+        env.contract.address = Addr::unchecked("08-wasm-0");
+        env
+    }
+
+    fn header(revision_height: u64) -> MockHeader {
+        MockHeader::new(Height::new(0, revision_height).unwrap())
+    }
+
+    /// Creates a client with consensus states at the given heights, and
+    /// returns its store under the given migration prefix, as the 08-wasm
+    /// migration store exposes it.
+    fn client_store(prefix: MigrationPrefix, heights: &[u64]) -> Vec<(Vec<u8>, Vec<u8>)> {
+        let mut deps = mock_dependencies();
+
+        let mut ctx = Context::<MockClient>::new_mut(deps.as_mut(), env()).unwrap();
+
+        ctx.instantiate(InstantiateMsg {
+            client_state: MockClientState::encode_to_any_vec(MockClientState::new(header(
+                heights[0],
+            )))
+            .into(),
+            consensus_state: MockConsensusState::encode_to_any_vec(MockConsensusState::new(
+                header(heights[0]),
+            ))
+            .into(),
+            checksum: Binary::from(vec![0; 32]),
+        })
+        .unwrap();
+
+        for height in &heights[1..] {
+            ctx.sudo(SudoMsg::UpdateState(UpdateStateMsgRaw {
+                client_message: MockHeader::encode_to_any_vec(header(*height)).into(),
+            }))
+            .unwrap();
+        }
+
+        deps.storage
+            .range(None, None, Order::Ascending)
+            .map(|(key, value)| ([prefix.key(), key.as_slice()].concat(), value))
+            .collect()
+    }
+
+    fn heights(revision_heights: &[u64]) -> Vec<Height> {
+        revision_heights
+            .iter()
+            .map(|revision_height| Height::new(0, *revision_height).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_migrate_client_store_height_map() {
+        let mut deps = mock_dependencies();
+
+        client_store(MigrationPrefix::Subject, &[1, 2, 3])
+            .into_iter()
+            .chain(client_store(MigrationPrefix::Substitute, &[5, 7]))
+            .for_each(|(key, value)| deps.storage.set(&key, &value));
+
+        Context::<MockClient>::new_mut(deps.as_mut(), env())
+            .unwrap()
+            .sudo(SudoMsg::MigrateClientStore(MigrateClientStoreMsg {}))
+            .unwrap();
+
+        // The 08-wasm migration store only holds the keys of the subject and
+        // substitute stores.
+        let storage: &MockStorage = &deps.storage;
+
+        assert!(storage.range(None, None, Order::Ascending).all(|(key, _)| {
+            key.starts_with(MigrationPrefix::Subject.key())
+                || key.starts_with(MigrationPrefix::Substitute.key())
+        }));
+
+        let mut ctx = Context::<MockClient>::new_ref(deps.as_ref(), env()).unwrap();
+
+        ctx.set_substitute_prefix();
+
+        assert_eq!(ctx.get_heights().unwrap(), heights(&[5, 7]));
+
+        ctx.set_subject_prefix();
+
+        assert_eq!(ctx.get_heights().unwrap(), heights(&[1, 2, 3, 7]));
+
+        let [three, seven] = [heights(&[3])[0], heights(&[7])[0]];
+
+        assert_eq!(
+            ctx.get_adjacent_height(&three, HeightTravel::Next).unwrap(),
+            Some(seven)
+        );
+        assert_eq!(
+            ctx.get_adjacent_height(&seven, HeightTravel::Prev).unwrap(),
+            Some(three)
+        );
+        assert_eq!(
+            ctx.get_adjacent_height(&heights(&[5])[0], HeightTravel::Prev)
+                .unwrap(),
+            Some(three)
+        );
+        assert_eq!(
+            ctx.get_adjacent_height(&seven, HeightTravel::Next).unwrap(),
+            None
+        );
+        assert_eq!(
+            ctx.get_adjacent_height(&heights(&[1])[0], HeightTravel::Prev)
+                .unwrap(),
+            None
+        );
+    }
+}
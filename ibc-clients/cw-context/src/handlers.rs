@@ -74,6 +74,8 @@ where
 
                 let consensus_state = self.consensus_state(&client_cons_state_path)?;
 
+                C::verify_proof_height(self, &client_state, &msg.height)?;
+
                 client_state.verify_membership_raw(
                     &msg.prefix,
                     &msg.proof,
@@ -95,6 +97,8 @@ where
 
                 let consensus_state = self.consensus_state(&client_cons_state_path)?;
 
+                C::verify_proof_height(self, &client_state, &msg.height)?;
+
                 client_state.verify_non_membership_raw(
                     &msg.prefix,
                     &msg.proof,
@@ -5,6 +5,7 @@ use cosmwasm_std::Binary;
 use ibc_core::client::types::proto::v1::Height as RawHeight;
 use ibc_core::client::types::Height;
 use ibc_core::commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc_core::host::types::error::DecodingError;
 use ibc_core::host::types::path::PathBytes;
 use ibc_core::primitives::proto::Any;
 use prost::Message;
@@ -119,6 +120,23 @@ pub struct MerklePath {
     pub key_path: Vec<Binary>,
 }
 
+impl MerklePath {
+    /// Splits the path into the commitment prefix, given by its first key,
+    /// and the path made of the remaining keys.
+    pub fn into_prefix_and_path(self) -> Result<(CommitmentPrefix, PathBytes), ContractError> {
+        let mut keys = self.key_path.into_iter();
+
+        let prefix = keys
+            .next()
+            .ok_or_else(|| DecodingError::missing_raw_data("merkle path prefix"))?;
+
+        Ok((
+            CommitmentPrefix::from_bytes(prefix),
+            PathBytes::flatten(keys.collect()),
+        ))
+    }
+}
+
 #[cw_serde]
 pub struct VerifyMembershipMsgRaw {
     pub proof: Binary,
@@ -142,10 +160,9 @@ pub struct VerifyMembershipMsg {
 impl TryFrom<VerifyMembershipMsgRaw> for VerifyMembershipMsg {
     type Error = ContractError;
 
-    fn try_from(mut raw: VerifyMembershipMsgRaw) -> Result<Self, Self::Error> {
+    fn try_from(raw: VerifyMembershipMsgRaw) -> Result<Self, Self::Error> {
         let proof = CommitmentProofBytes::try_from(raw.proof.to_vec())?;
-        let prefix = CommitmentPrefix::from_bytes(raw.merkle_path.key_path.remove(0));
-        let path = PathBytes::flatten(raw.merkle_path.key_path);
+        let (prefix, path) = raw.merkle_path.into_prefix_and_path()?;
         let height = Height::try_from(raw.height)?;
 
         Ok(Self {
@@ -181,10 +198,9 @@ pub struct VerifyNonMembershipMsg {
 impl TryFrom<VerifyNonMembershipMsgRaw> for VerifyNonMembershipMsg {
     type Error = ContractError;
 
-    fn try_from(mut raw: VerifyNonMembershipMsgRaw) -> Result<Self, Self::Error> {
+    fn try_from(raw: VerifyNonMembershipMsgRaw) -> Result<Self, Self::Error> {
         let proof = CommitmentProofBytes::try_from(raw.proof.to_vec())?;
-        let prefix = CommitmentPrefix::from_bytes(raw.merkle_path.key_path.remove(0));
-        let path = PathBytes::flatten(raw.merkle_path.key_path);
+        let (prefix, path) = raw.merkle_path.into_prefix_and_path()?;
         let height = raw.height.try_into()?;
 
         Ok(Self {
@@ -266,7 +282,366 @@ impl TryFrom<CheckForMisbehaviourMsgRaw> for CheckForMisbehaviourMsg {
 
 #[cfg(test)]
 mod test {
-    use super::{InstantiateMsg, SudoMsg};
+    use std::collections::BTreeSet;
+    use std::fmt::Debug;
+    use std::fs;
+    use std::path::Path;
+
+    use cosmwasm_schema::serde::de::DeserializeOwned;
+    use cosmwasm_schema::serde::Serialize;
+    use cosmwasm_std::{from_json, to_json_string};
+    use ibc_core::client::types::Status;
+
+    use super::*;
+    use crate::types::{
+        CheckForMisbehaviourResponse, ContractResult, StatusResponse, TimestampAtHeightResponse,
+        VerifyClientMessageResponse,
+    };
+
+    /// The golden corpus of the JSON messages exchanged between the 08-wasm
+    /// proxy light client of ibc-go and the contracts. Every `<name>.json`
+    /// file holds a message as encoded by ibc-go, or a response as encoded by
+    /// the contracts, and `<name>.reencoded.json`, when present, holds its
+    /// re-encoding by the contracts where it differs from the one of ibc-go.
+    /// The binary payloads are shared under `payloads/`.
+    ///
+    /// Running the tests with `BLESS_WIRE_CORPUS=1` writes the re-encodings
+    /// that differ from the corpus instead of failing, to be reviewed before
+    /// being checked in.
+    const CORPUS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/wire");
+
+    const BLESS_VAR: &str = "BLESS_WIRE_CORPUS";
+
+    const REENCODED_SUFFIX: &str = ".reencoded.json";
+
+    fn read_corpus_file(name: &str) -> Option<String> {
+        fs::read_to_string(Path::new(CORPUS_DIR).join(name))
+            .ok()
+            .map(|content| content.trim_end().to_string())
+    }
+
+    fn payload(name: &str) -> Binary {
+        let base64 = read_corpus_file(&format!("payloads/{name}.b64"))
+            .unwrap_or_else(|| panic!("missing payload `{name}`"));
+
+        Binary::from_base64(&base64).unwrap()
+    }
+
+    /// Lists the messages of the corpus, relative to its directory.
+    fn list_corpus_messages(dir: &Path, messages: &mut BTreeSet<String>) {
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+
+            if path.is_dir() {
+                list_corpus_messages(&path, messages);
+                continue;
+            }
+
+            let name = path
+                .strip_prefix(CORPUS_DIR)
+                .unwrap()
+                .to_string_lossy()
+                .into_owned();
+
+            if name.ends_with(".json") && !name.ends_with(REENCODED_SUFFIX) {
+                messages.insert(name);
+            }
+        }
+    }
+
+    /// Runs the corpus, keeping track of the checked messages.
+    #[derive(Default)]
+    struct Corpus {
+        checked: BTreeSet<String>,
+    }
+
+    impl Corpus {
+        /// Decodes a message of the corpus into the expected value, and checks
+        /// that it is re-encoded as expected and decoded back to the same value.
+        fn check<T>(&mut self, name: &str, expected: T) -> T
+        where
+            T: Serialize + DeserializeOwned + PartialEq + Debug,
+        {
+            let wire = read_corpus_file(name).unwrap_or_else(|| panic!("missing message `{name}`"));
+
+            let decoded: T = from_json(&wire).unwrap_or_else(|e| panic!("`{name}`: {e}"));
+
+            assert_eq!(decoded, expected, "`{name}` decoded unexpectedly");
+
+            let reencoded_name = name.replace(".json", REENCODED_SUFFIX);
+
+            let expected_reencoded = read_corpus_file(&reencoded_name).unwrap_or(wire);
+
+            let reencoded = to_json_string(&decoded).unwrap();
+
+            if std::env::var_os(BLESS_VAR).is_some() && reencoded != expected_reencoded {
+                fs::write(
+                    Path::new(CORPUS_DIR).join(&reencoded_name),
+                    format!("{reencoded}\n"),
+                )
+                .unwrap();
+            } else {
+                assert_eq!(
+                    reencoded, expected_reencoded,
+                    "`{name}` re-encoded unexpectedly"
+                );
+            }
+
+            assert_eq!(
+                from_json::<T>(&reencoded).unwrap(),
+                decoded,
+                "`{name}` re-encoding does not round trip"
+            );
+
+            self.checked.insert(name.to_string());
+
+            decoded
+        }
+
+        /// Checks that every message of the corpus has been checked, so that
+        /// no captured message is left without an expected decode result.
+        fn assert_all_checked(&self) {
+            let mut messages = BTreeSet::new();
+
+            list_corpus_messages(Path::new(CORPUS_DIR), &mut messages);
+
+            assert_eq!(messages, self.checked, "unchecked messages in the corpus");
+        }
+    }
+
+    fn raw_height(revision_number: u64, revision_height: u64) -> RawHeight {
+        RawHeight {
+            revision_number,
+            revision_height,
+        }
+    }
+
+    fn key_path(keys: &[&str]) -> MerklePath {
+        MerklePath {
+            key_path: keys.iter().map(|key| key.as_bytes().into()).collect(),
+        }
+    }
+
+    const TM_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.tendermint.v1.ClientState";
+    const TM_CONSENSUS_STATE_TYPE_URL: &str = "/ibc.lightclients.tendermint.v1.ConsensusState";
+    const TM_HEADER_TYPE_URL: &str = "/ibc.lightclients.tendermint.v1.Header";
+
+    fn check_instantiate_msgs(corpus: &mut Corpus) {
+        corpus.check(
+            "instantiate/tendermint.json",
+            InstantiateMsg {
+                client_state: payload("tendermint_client_state"),
+                consensus_state: payload("tendermint_consensus_state"),
+                checksum: payload("checksum"),
+            },
+        );
+    }
+
+    fn check_sudo_msgs(corpus: &mut Corpus) {
+        let msg = corpus.check(
+            "sudo/update_state.json",
+            SudoMsg::UpdateState(UpdateStateMsgRaw {
+                client_message: payload("tendermint_header"),
+            }),
+        );
+
+        let SudoMsg::UpdateState(raw) = msg else {
+            unreachable!()
+        };
+
+        let msg = UpdateStateMsg::try_from(raw).unwrap();
+
+        assert_eq!(msg.client_message.type_url, TM_HEADER_TYPE_URL);
+
+        // The Tendermint client treats a header conflicting with its
+        // consensus states as misbehaviour.
+        let msg = corpus.check(
+            "sudo/update_state_on_misbehaviour.json",
+            SudoMsg::UpdateStateOnMisbehaviour(UpdateStateOnMisbehaviourMsgRaw {
+                client_message: payload("tendermint_misbehaviour"),
+            }),
+        );
+
+        let SudoMsg::UpdateStateOnMisbehaviour(raw) = msg else {
+            unreachable!()
+        };
+
+        let msg = UpdateStateOnMisbehaviourMsg::try_from(raw).unwrap();
+
+        assert_eq!(msg.client_message.type_url, TM_HEADER_TYPE_URL);
+
+        let msg = corpus.check(
+            "sudo/verify_upgrade_and_update_state.json",
+            SudoMsg::VerifyUpgradeAndUpdateState(VerifyUpgradeAndUpdateStateMsgRaw {
+                upgrade_client_state: payload("tendermint_client_state"),
+                upgrade_consensus_state: payload("tendermint_consensus_state"),
+                proof_upgrade_client: payload("connection_proof"),
+                proof_upgrade_consensus_state: payload("connection_proof"),
+            }),
+        );
+
+        let SudoMsg::VerifyUpgradeAndUpdateState(raw) = msg else {
+            unreachable!()
+        };
+
+        let msg = VerifyUpgradeAndUpdateStateMsg::try_from(raw).unwrap();
+
+        assert_eq!(msg.upgrade_client_state.type_url, TM_CLIENT_STATE_TYPE_URL);
+        assert_eq!(
+            msg.upgrade_consensus_state.type_url,
+            TM_CONSENSUS_STATE_TYPE_URL
+        );
+
+        // Captured from ibc-go, on the connection handshake.
+        let msg = corpus.check(
+            "sudo/verify_membership.json",
+            SudoMsg::VerifyMembership(VerifyMembershipMsgRaw {
+                proof: payload("connection_proof"),
+                merkle_path: key_path(&["ibc", "connections/connection-0"]),
+                value: payload("connection_end"),
+                height: raw_height(0, 57),
+                delay_block_period: 0,
+                delay_time_period: 0,
+            }),
+        );
+
+        let SudoMsg::VerifyMembership(raw) = msg else {
+            unreachable!()
+        };
+
+        let msg = VerifyMembershipMsg::try_from(raw).unwrap();
+
+        assert_eq!(msg.prefix.as_bytes(), b"ibc");
+        assert_eq!(msg.path.as_ref(), b"connections/connection-0");
+        assert_eq!(msg.height, Height::new(0, 57).unwrap());
+
+        let msg = corpus.check(
+            "sudo/verify_non_membership.json",
+            SudoMsg::VerifyNonMembership(VerifyNonMembershipMsgRaw {
+                proof: payload("connection_proof"),
+                merkle_path: key_path(&[
+                    "ibc",
+                    "receipts/ports/transfer/channels/channel-0/sequences/1",
+                ]),
+                height: raw_height(1, 120),
+                delay_block_period: 2,
+                delay_time_period: 600_000_000_000,
+            }),
+        );
+
+        let SudoMsg::VerifyNonMembership(raw) = msg else {
+            unreachable!()
+        };
+
+        let msg = VerifyNonMembershipMsg::try_from(raw).unwrap();
+
+        assert_eq!(msg.prefix.as_bytes(), b"ibc");
+        assert_eq!(
+            msg.path.as_ref(),
+            b"receipts/ports/transfer/channels/channel-0/sequences/1"
+        );
+        assert_eq!(msg.height, Height::new(1, 120).unwrap());
+
+        corpus.check(
+            "sudo/migrate_client_store.json",
+            SudoMsg::MigrateClientStore(MigrateClientStoreMsg {}),
+        );
+    }
+
+    fn check_query_msgs(corpus: &mut Corpus) {
+        corpus.check("query/status.json", QueryMsg::Status(StatusMsg {}));
+
+        // ibc-go leaves out the zero revision number.
+        corpus.check(
+            "query/timestamp_at_height.json",
+            QueryMsg::TimestampAtHeight(TimestampAtHeightMsg {
+                height: Height::new(0, 10).unwrap(),
+            }),
+        );
+
+        let msg = corpus.check(
+            "query/verify_client_message.json",
+            QueryMsg::VerifyClientMessage(VerifyClientMessageRaw {
+                client_message: payload("tendermint_header"),
+            }),
+        );
+
+        let QueryMsg::VerifyClientMessage(raw) = msg else {
+            unreachable!()
+        };
+
+        let msg = VerifyClientMessageMsg::try_from(raw).unwrap();
+
+        assert_eq!(msg.client_message.type_url, TM_HEADER_TYPE_URL);
+
+        let msg = corpus.check(
+            "query/check_for_misbehaviour.json",
+            QueryMsg::CheckForMisbehaviour(CheckForMisbehaviourMsgRaw {
+                client_message: payload("tendermint_misbehaviour"),
+            }),
+        );
+
+        let QueryMsg::CheckForMisbehaviour(raw) = msg else {
+            unreachable!()
+        };
+
+        let msg = CheckForMisbehaviourMsg::try_from(raw).unwrap();
+
+        assert_eq!(msg.client_message.type_url, TM_HEADER_TYPE_URL);
+    }
+
+    fn check_responses(corpus: &mut Corpus) {
+        for (name, status) in [
+            ("response/status_active.json", Status::Active),
+            ("response/status_frozen.json", Status::Frozen),
+            ("response/status_expired.json", Status::Expired),
+        ] {
+            corpus.check(name, StatusResponse { status });
+        }
+
+        corpus.check(
+            "response/timestamp_at_height.json",
+            TimestampAtHeightResponse {
+                timestamp: 1_571_797_444_879_305_533,
+            },
+        );
+
+        corpus.check(
+            "response/verify_client_message.json",
+            VerifyClientMessageResponse { is_valid: true },
+        );
+
+        corpus.check(
+            "response/check_for_misbehaviour.json",
+            CheckForMisbehaviourResponse {
+                found_misbehaviour: false,
+            },
+        );
+
+        corpus.check(
+            "response/contract_result_heights.json",
+            ContractResult::success().heights(vec![Height::new(0, 10).unwrap()]),
+        );
+
+        // ibc-go reads the heights of the update only, out of the results of
+        // all the sudo messages.
+        corpus.check(
+            "response/contract_result_empty.json",
+            ContractResult::success(),
+        );
+    }
+
+    #[test]
+    fn test_wire_corpus() {
+        let mut corpus = Corpus::default();
+
+        check_instantiate_msgs(&mut corpus);
+        check_sudo_msgs(&mut corpus);
+        check_query_msgs(&mut corpus);
+        check_responses(&mut corpus);
+
+        corpus.assert_all_checked();
+    }
 
     #[test]
     fn verify_membership_from_json() {
@@ -287,6 +662,36 @@ mod test {
         ));
     }
 
+    #[test]
+    fn verify_membership_with_empty_merkle_path() {
+        let msg = VerifyMembershipMsgRaw {
+            proof: vec![1].into(),
+            merkle_path: key_path(&[]),
+            value: b"value".to_vec().into(),
+            height: raw_height(0, 1),
+            delay_block_period: 0,
+            delay_time_period: 0,
+        };
+
+        assert!(matches!(
+            VerifyMembershipMsg::try_from(msg),
+            Err(ContractError::Decoding(_))
+        ));
+
+        let msg = VerifyNonMembershipMsgRaw {
+            proof: vec![1].into(),
+            merkle_path: key_path(&[]),
+            height: raw_height(0, 1),
+            delay_block_period: 0,
+            delay_time_period: 0,
+        };
+
+        assert!(matches!(
+            VerifyNonMembershipMsg::try_from(msg),
+            Err(ContractError::Decoding(_))
+        ));
+    }
+
     #[test]
     fn instantiate_msg_from_json() {
         let instantiate_msg = r#"{
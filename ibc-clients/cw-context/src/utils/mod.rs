@@ -1,3 +1,5 @@
 mod codec;
+mod storage;
 
 pub use codec::*;
+pub use storage::*;
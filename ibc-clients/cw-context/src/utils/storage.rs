@@ -0,0 +1,154 @@
+use cosmwasm_std::{Order, Record, Storage};
+
+/// Returns the first key after all the keys starting with the given prefix,
+/// or `None` if there is no such key.
+fn prefix_end(prefix: &[u8]) -> Option<Vec<u8>> {
+    let mut end = prefix.to_vec();
+
+    while let Some(last) = end.pop() {
+        if last < u8::MAX {
+            end.push(last + 1);
+            return Some(end);
+        }
+    }
+
+    None
+}
+
+fn prefixed_range<'a>(
+    storage: &'a dyn Storage,
+    prefix: &'a [u8],
+    start: Option<&[u8]>,
+    end: Option<&[u8]>,
+    order: Order,
+) -> Box<dyn Iterator<Item = Record> + 'a> {
+    let start = [prefix, start.unwrap_or_default()].concat();
+
+    let end = match end {
+        Some(end) => Some([prefix, end].concat()),
+        None => prefix_end(prefix),
+    };
+
+    Box::new(
+        storage
+            .range(Some(&start), end.as_deref(), order)
+            .map(move |(key, value)| (key[prefix.len()..].to_vec(), value)),
+    )
+}
+
+/// A read-only view over the keys of a storage starting with a prefix, which
+/// is stripped from the keys it returns. It does not implement [`Storage`],
+/// so that it cannot be handed to code writing to it.
+pub struct ReadonlyPrefixedStorage<'a> {
+    storage: &'a dyn Storage,
+    prefix: Vec<u8>,
+}
+
+impl<'a> ReadonlyPrefixedStorage<'a> {
+    pub fn new(storage: &'a dyn Storage, prefix: impl Into<Vec<u8>>) -> Self {
+        Self {
+            storage,
+            prefix: prefix.into(),
+        }
+    }
+
+    pub fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        self.storage.get(&[self.prefix.as_slice(), key].concat())
+    }
+
+    pub fn range<'b>(
+        &'b self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = Record> + 'b> {
+        prefixed_range(self.storage, &self.prefix, start, end, order)
+    }
+}
+
+/// A view over the keys of a storage starting with a prefix, which is
+/// stripped from the keys it returns and prepended to the keys it writes.
+pub struct PrefixedStorage<'a> {
+    storage: &'a mut dyn Storage,
+    prefix: Vec<u8>,
+}
+
+impl<'a> PrefixedStorage<'a> {
+    pub fn new(storage: &'a mut dyn Storage, prefix: impl Into<Vec<u8>>) -> Self {
+        Self {
+            storage,
+            prefix: prefix.into(),
+        }
+    }
+}
+
+impl Storage for PrefixedStorage<'_> {
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        self.storage.get(&[self.prefix.as_slice(), key].concat())
+    }
+
+    fn range<'b>(
+        &'b self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = Record> + 'b> {
+        prefixed_range(&*self.storage, &self.prefix, start, end, order)
+    }
+
+    fn set(&mut self, key: &[u8], value: &[u8]) {
+        self.storage
+            .set(&[self.prefix.as_slice(), key].concat(), value);
+    }
+
+    fn remove(&mut self, key: &[u8]) {
+        self.storage.remove(&[self.prefix.as_slice(), key].concat());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::testing::MockStorage;
+
+    use super::*;
+
+    #[test]
+    fn test_prefixed_storage() {
+        let mut storage = MockStorage::new();
+
+        storage.set(b"a/1", b"outside");
+
+        let mut prefixed = PrefixedStorage::new(&mut storage, b"b/".to_vec());
+
+        prefixed.set(b"1", b"one");
+        prefixed.set(b"2", b"two");
+
+        assert_eq!(prefixed.get(b"1"), Some(b"one".to_vec()));
+        assert_eq!(prefixed.get(b"3"), None);
+
+        let keys: Vec<_> = prefixed
+            .range(None, None, Order::Descending)
+            .map(|(key, _)| key)
+            .collect();
+
+        assert_eq!(keys, vec![b"2".to_vec(), b"1".to_vec()]);
+
+        prefixed.remove(b"2");
+
+        assert_eq!(storage.get(b"b/1"), Some(b"one".to_vec()));
+        assert_eq!(storage.get(b"b/2"), None);
+
+        let readonly = ReadonlyPrefixedStorage::new(&storage, b"a/".to_vec());
+
+        let records: Vec<_> = readonly.range(None, None, Order::Ascending).collect();
+
+        assert_eq!(records, vec![(b"1".to_vec(), b"outside".to_vec())]);
+    }
+
+    #[test]
+    fn test_prefix_end() {
+        assert_eq!(prefix_end(b"ab"), Some(b"ac".to_vec()));
+        assert_eq!(prefix_end(&[1, u8::MAX]), Some(vec![2]));
+        assert_eq!(prefix_end(&[u8::MAX]), None);
+    }
+}
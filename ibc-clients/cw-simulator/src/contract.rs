@@ -0,0 +1,18 @@
+use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response};
+use ibc_client_cw::types::{ContractError, InstantiateMsg, QueryMsg, SudoMsg};
+
+pub type InstantiateFn =
+    fn(DepsMut<'_>, Env, MessageInfo, InstantiateMsg) -> Result<Response, ContractError>;
+
+pub type SudoFn = fn(DepsMut<'_>, Env, SudoMsg) -> Result<Response, ContractError>;
+
+pub type QueryFn = fn(Deps<'_>, Env, QueryMsg) -> Result<Binary, ContractError>;
+
+/// The entry points of a light client contract, as exported by the
+/// `entrypoint` module of the contracts built with `ibc-client-cw`.
+#[derive(Clone, Copy, Debug)]
+pub struct WasmContract {
+    pub instantiate: InstantiateFn,
+    pub sudo: SudoFn,
+    pub query: QueryFn,
+}
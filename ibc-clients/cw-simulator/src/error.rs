@@ -0,0 +1,42 @@
+use cosmwasm_std::StdError;
+use derive_more::{Display, From};
+use ibc_client_cw::types::ContractError;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Status;
+use ibc_core::host::types::error::DecodingError;
+
+#[derive(From, Display, Debug)]
+pub enum SimulatorError {
+    #[from]
+    #[display("contract error: {_0}")]
+    Contract(ContractError),
+    #[from]
+    #[display("CosmWasm standard error: {_0}")]
+    Std(StdError),
+    #[from]
+    #[display("IBC client error: {_0}")]
+    Client(ClientError),
+    #[from]
+    #[display("IBC decoding error: {_0}")]
+    Decoding(DecodingError),
+    #[display("no contract stored with checksum `{checksum}`")]
+    UnknownChecksum { checksum: String },
+    #[display("no client state stored by the contract of client `{client_id}`")]
+    MissingClientState { client_id: String },
+    #[display(
+        "no consensus state stored by the contract of client `{client_id}` at height `{height}`"
+    )]
+    MissingConsensusState { client_id: String, height: String },
+    #[display("client `{client_id}` reported the unknown status `{status}`")]
+    UnknownStatus { client_id: String, status: String },
+    #[display("client `{client_id}` is not active but {status}")]
+    ClientNotActive { client_id: String, status: Status },
+    #[display("client `{client_id}` rejected the client message")]
+    InvalidClientMessage { client_id: String },
+    #[display("subject client `{client_id}` must not be active")]
+    SubjectActive { client_id: String },
+    #[display("subject client latest height `{subject}` must be lower than the substitute client latest height `{substitute}`")]
+    SubstituteHeightTooLow { subject: String, substitute: String },
+    #[display("subject and substitute clients must have the same checksum")]
+    ChecksumMismatch,
+}
@@ -0,0 +1,20 @@
+//! Contains an in-process simulator of the ibc-go 08-wasm proxy light client,
+//! to test light client contracts built with the `ibc-client-cw` crate end to
+//! end without a Go chain. The [`Simulator`](simulator::Simulator) drives the
+//! contracts through their entry points as 08-wasm does:
+//!
+//! - every client owns the `clients/{client_id}/` prefixed store of the IBC
+//!   module store;
+//! - the client and consensus states are wrapped in the 08-wasm
+//!   `ClientState` and `ConsensusState`, and read back from the client store
+//!   after the contract stored them;
+//! - client recovery runs over the `subject/` and `substitute/` views of the
+//!   two client stores;
+//! - the messages are encoded to JSON as ibc-go encodes them, and decoded by
+//!   the contract entry points.
+
+pub mod contract;
+pub mod error;
+pub mod msgs;
+pub mod simulator;
+pub mod store;
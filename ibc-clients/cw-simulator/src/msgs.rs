@@ -0,0 +1,231 @@
+//! Defines the messages sent by the 08-wasm proxy light client of ibc-go to
+//! the contracts, and the results it reads from them. They mirror the Go
+//! structs of the `08-wasm` module, so that their JSON encoding matches the
+//! one of `encoding/json`: byte slices are standard base64 strings, fields
+//! are ordered as they are declared in Go, and `omitempty` fields are left
+//! out when they are zero.
+use cosmwasm_schema::serde::{Deserialize, Serialize};
+use cosmwasm_std::Binary;
+use ibc_core::client::types::Height;
+
+fn is_zero(value: &u64) -> bool {
+    *value == 0
+}
+
+/// The `Height` of the `02-client` module of ibc-go, whose fields are both
+/// `omitempty`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(crate = "cosmwasm_schema::serde")]
+pub struct GoHeight {
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub revision_number: u64,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub revision_height: u64,
+}
+
+impl From<Height> for GoHeight {
+    fn from(height: Height) -> Self {
+        Self {
+            revision_number: height.revision_number(),
+            revision_height: height.revision_height(),
+        }
+    }
+}
+
+/// The `MerklePath` of the `23-commitment` module of ibc-go, whose
+/// `key_path` is `omitempty`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(crate = "cosmwasm_schema::serde")]
+pub struct GoMerklePath {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub key_path: Vec<Binary>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(crate = "cosmwasm_schema::serde")]
+pub struct InstantiateMessage {
+    pub client_state: Binary,
+    pub consensus_state: Binary,
+    pub checksum: Binary,
+}
+
+/// The sudo messages of 08-wasm. In Go, a struct of optional pointers of
+/// which a single one is set, encoded as a single-key object.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(crate = "cosmwasm_schema::serde", rename_all = "snake_case")]
+pub enum SudoMessage {
+    UpdateState {
+        client_message: Binary,
+    },
+    UpdateStateOnMisbehaviour {
+        client_message: Binary,
+    },
+    VerifyUpgradeAndUpdateState {
+        upgrade_client_state: Binary,
+        upgrade_consensus_state: Binary,
+        proof_upgrade_client: Binary,
+        proof_upgrade_consensus_state: Binary,
+    },
+    VerifyMembership {
+        height: GoHeight,
+        delay_time_period: u64,
+        delay_block_period: u64,
+        proof: Binary,
+        merkle_path: GoMerklePath,
+        value: Binary,
+    },
+    VerifyNonMembership {
+        height: GoHeight,
+        delay_time_period: u64,
+        delay_block_period: u64,
+        proof: Binary,
+        merkle_path: GoMerklePath,
+    },
+    MigrateClientStore {},
+}
+
+/// The query messages of 08-wasm, encoded as the sudo messages.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(crate = "cosmwasm_schema::serde", rename_all = "snake_case")]
+pub enum QueryMessage {
+    Status {},
+    TimestampAtHeight { height: GoHeight },
+    VerifyClientMessage { client_message: Binary },
+    CheckForMisbehaviour { client_message: Binary },
+}
+
+/// As `encoding/json`, the results ignore the fields they do not declare.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(crate = "cosmwasm_schema::serde")]
+pub struct StatusResult {
+    pub status: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(crate = "cosmwasm_schema::serde")]
+pub struct TimestampAtHeightResult {
+    pub timestamp: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(crate = "cosmwasm_schema::serde")]
+pub struct CheckForMisbehaviourResult {
+    pub found_misbehaviour: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(crate = "cosmwasm_schema::serde")]
+pub struct UpdateStateResult {
+    #[serde(default)]
+    pub heights: Vec<GoHeight>,
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{from_json, to_json_string};
+    use ibc_client_cw::types::{InstantiateMsg, QueryMsg, SudoMsg};
+
+    use super::*;
+
+    #[test]
+    fn test_sudo_message_to_json() {
+        let msg = SudoMessage::VerifyMembership {
+            height: GoHeight {
+                revision_number: 0,
+                revision_height: 57,
+            },
+            delay_time_period: 0,
+            delay_block_period: 0,
+            proof: b"proof".into(),
+            merkle_path: GoMerklePath {
+                key_path: vec![b"ibc".into(), b"connections/connection-0".into()],
+            },
+            value: b"value".into(),
+        };
+
+        let json = to_json_string(&msg).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"verify_membership":{"height":{"revision_height":57},"delay_time_period":0,"delay_block_period":0,"proof":"cHJvb2Y=","merkle_path":{"key_path":["aWJj","Y29ubmVjdGlvbnMvY29ubmVjdGlvbi0w"]},"value":"dmFsdWU="}}"#
+        );
+
+        assert!(matches!(
+            from_json::<SudoMsg>(&json).unwrap(),
+            SudoMsg::VerifyMembership(_)
+        ));
+
+        let json = to_json_string(&SudoMessage::MigrateClientStore {}).unwrap();
+
+        assert_eq!(json, r#"{"migrate_client_store":{}}"#);
+
+        assert!(matches!(
+            from_json::<SudoMsg>(&json).unwrap(),
+            SudoMsg::MigrateClientStore(_)
+        ));
+    }
+
+    #[test]
+    fn test_query_message_to_json() {
+        let json = to_json_string(&QueryMessage::Status {}).unwrap();
+
+        assert_eq!(json, r#"{"status":{}}"#);
+
+        assert!(matches!(
+            from_json::<QueryMsg>(&json).unwrap(),
+            QueryMsg::Status(_)
+        ));
+
+        let json = to_json_string(&QueryMessage::CheckForMisbehaviour {
+            client_message: b"header".into(),
+        })
+        .unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"check_for_misbehaviour":{"client_message":"aGVhZGVy"}}"#
+        );
+
+        assert!(matches!(
+            from_json::<QueryMsg>(&json).unwrap(),
+            QueryMsg::CheckForMisbehaviour(_)
+        ));
+    }
+
+    #[test]
+    fn test_instantiate_message_to_json() {
+        let msg = InstantiateMessage {
+            client_state: b"client_state".into(),
+            consensus_state: b"consensus_state".into(),
+            checksum: b"checksum".into(),
+        };
+
+        let json = to_json_string(&msg).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"client_state":"Y2xpZW50X3N0YXRl","consensus_state":"Y29uc2Vuc3VzX3N0YXRl","checksum":"Y2hlY2tzdW0="}"#
+        );
+
+        from_json::<InstantiateMsg>(&json).unwrap();
+    }
+
+    #[test]
+    fn test_update_state_result_from_json() {
+        let result: UpdateStateResult =
+            from_json(r#"{"heights":[{"revision_number":1,"revision_height":10}]}"#).unwrap();
+
+        assert_eq!(
+            result.heights,
+            vec![GoHeight {
+                revision_number: 1,
+                revision_height: 10,
+            }]
+        );
+
+        // the contracts leave out the heights of the other sudo messages
+        let result: UpdateStateResult = from_json("{}").unwrap();
+
+        assert!(result.heights.is_empty());
+    }
+}
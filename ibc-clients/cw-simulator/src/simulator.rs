@@ -0,0 +1,488 @@
+use std::collections::HashMap;
+
+use cosmwasm_schema::serde::de::DeserializeOwned;
+use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{
+    from_json, to_json_vec, Addr, Checksum, Deps, DepsMut, Env, MessageInfo, Order, QuerierWrapper,
+    Storage,
+};
+use ibc_client_cw::types::VerifyClientMessageResponse;
+use ibc_client_cw::utils::ReadonlyPrefixedStorage;
+use ibc_client_cw_testkit::clock::Clock;
+use ibc_client_wasm_types::client_state::ClientState as WasmClientState;
+use ibc_client_wasm_types::consensus_state::ConsensusState as WasmConsensusState;
+use ibc_core::client::types::{Height, Status};
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::host::types::identifiers::ClientId;
+use ibc_core::host::types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc_core::primitives::proto::{Any, Protobuf};
+
+use crate::contract::WasmContract;
+use crate::error::SimulatorError;
+use crate::msgs::{
+    CheckForMisbehaviourResult, GoMerklePath, InstantiateMessage, QueryMessage, StatusResult,
+    SudoMessage, TimestampAtHeightResult, UpdateStateResult,
+};
+use crate::store::{client_store, client_store_ref, client_store_snapshot, MigrationStore};
+
+/// The sender of the messages instantiating the contracts.
+pub const IBC_MODULE_ADDRESS: &str = "ibc";
+
+/// An in-process simulator of the 08-wasm proxy light client of ibc-go, along
+/// with the parts of the `02-client` module driving it.
+///
+/// The contracts are run over a single IBC module store, in which every
+/// client owns a prefixed store. A failing sudo message reverts the changes
+/// the contract made to the store, as the transaction running it would.
+pub struct Simulator {
+    storage: MockStorage,
+    api: MockApi,
+    querier: MockQuerier,
+    clock: Clock,
+    contracts: HashMap<Checksum, WasmContract>,
+    next_client_sequence: u64,
+}
+
+impl Default for Simulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Simulator {
+    /// Constructs a simulator with an empty store, at the block of the
+    /// default [`Clock`].
+    pub fn new() -> Self {
+        Self {
+            storage: MockStorage::new(),
+            api: MockApi::default(),
+            querier: MockQuerier::default(),
+            clock: Clock::default(),
+            contracts: HashMap::new(),
+            next_client_sequence: 0,
+        }
+    }
+
+    /// Returns the clock of the chain, giving the block time and height seen
+    /// by the contracts.
+    pub fn clock(&self) -> &Clock {
+        &self.clock
+    }
+
+    pub fn clock_mut(&mut self) -> &mut Clock {
+        &mut self.clock
+    }
+
+    /// Returns the IBC module store holding the stores of all the clients.
+    pub fn storage(&self) -> &dyn Storage {
+        &self.storage
+    }
+
+    /// Returns the store of a client, as seen by its contract.
+    pub fn client_store(&self, client_id: &ClientId) -> ReadonlyPrefixedStorage<'_> {
+        client_store_ref(&self.storage, client_id)
+    }
+
+    /// Stores the code of a contract, as `MsgStoreCode` does, and returns its
+    /// checksum.
+    pub fn store_code(&mut self, wasm_code: &[u8], contract: WasmContract) -> Checksum {
+        let checksum = Checksum::generate(wasm_code);
+
+        self.contracts.insert(checksum, contract);
+
+        checksum
+    }
+
+    fn env(&self, client_id: &ClientId) -> Env {
+        let mut env = self.clock.env();
+        env.contract.address = Addr::unchecked(client_id.as_str());
+        env
+    }
+
+    fn contract(&self, checksum: &[u8]) -> Result<WasmContract, SimulatorError> {
+        Checksum::try_from(checksum)
+            .ok()
+            .and_then(|checksum| self.contracts.get(&checksum).copied())
+            .ok_or_else(|| SimulatorError::UnknownChecksum {
+                checksum: Checksum::try_from(checksum)
+                    .map(|checksum| checksum.to_hex())
+                    .unwrap_or_default(),
+            })
+    }
+
+    /// Runs the given closure over the store, and reverts its changes if it
+    /// fails.
+    fn with_revert<T>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> Result<T, SimulatorError>,
+    ) -> Result<T, SimulatorError> {
+        let snapshot: Vec<_> = self.storage.range(None, None, Order::Ascending).collect();
+
+        let result = f(self);
+
+        if result.is_err() {
+            self.storage = MockStorage::new();
+
+            for (key, value) in snapshot {
+                self.storage.set(&key, &value);
+            }
+        }
+
+        result
+    }
+
+    /// Returns the client state stored by the contract of a client.
+    pub fn client_state(&self, client_id: &ClientId) -> Result<WasmClientState, SimulatorError> {
+        let value = self
+            .client_store(client_id)
+            .get(ClientStatePath::leaf().as_bytes())
+            .ok_or_else(|| SimulatorError::MissingClientState {
+                client_id: client_id.to_string(),
+            })?;
+
+        Protobuf::<Any>::decode(value.as_slice())
+            .map_err(|e| DecodingError::invalid_raw_data(e).into())
+    }
+
+    /// Returns the consensus state stored by the contract of a client at the
+    /// given height.
+    pub fn consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: Height,
+    ) -> Result<WasmConsensusState, SimulatorError> {
+        let path = ClientConsensusStatePath::new(
+            client_id.clone(),
+            height.revision_number(),
+            height.revision_height(),
+        );
+
+        let value = self
+            .client_store(client_id)
+            .get(path.leaf().as_bytes())
+            .ok_or_else(|| SimulatorError::MissingConsensusState {
+                client_id: client_id.to_string(),
+                height: height.to_string(),
+            })?;
+
+        Protobuf::<Any>::decode(value.as_slice())
+            .map_err(|e| DecodingError::invalid_raw_data(e).into())
+    }
+
+    fn sudo(&mut self, client_id: &ClientId, msg: SudoMessage) -> Result<Vec<u8>, SimulatorError> {
+        let contract = self.contract(&self.client_state(client_id)?.checksum)?;
+
+        let env = self.env(client_id);
+
+        let msg = from_json(to_json_vec(&msg)?)?;
+
+        let deps = DepsMut {
+            storage: &mut client_store(&mut self.storage, client_id),
+            api: &self.api,
+            querier: QuerierWrapper::new(&self.querier),
+        };
+
+        let resp = (contract.sudo)(deps, env, msg)?;
+
+        Ok(resp.data.map(Vec::from).unwrap_or_default())
+    }
+
+    fn query<T: DeserializeOwned>(
+        &self,
+        client_id: &ClientId,
+        msg: QueryMessage,
+    ) -> Result<T, SimulatorError> {
+        let contract = self.contract(&self.client_state(client_id)?.checksum)?;
+
+        let msg = from_json(to_json_vec(&msg)?)?;
+
+        let deps = Deps {
+            storage: &client_store_snapshot(&self.storage, client_id),
+            api: &self.api,
+            querier: QuerierWrapper::new(&self.querier),
+        };
+
+        let resp = (contract.query)(deps, self.env(client_id), msg)?;
+
+        Ok(from_json(resp)?)
+    }
+
+    /// Creates a client, as `MsgCreateClient` does, out of the client and
+    /// consensus states of the light client, which are wrapped in the
+    /// 08-wasm states. The new client must be active.
+    pub fn create_client(
+        &mut self,
+        checksum: &Checksum,
+        client_state: Vec<u8>,
+        consensus_state: Vec<u8>,
+        latest_height: Height,
+    ) -> Result<ClientId, SimulatorError> {
+        let client_state = WasmClientState {
+            data: client_state,
+            checksum: checksum.as_slice().to_vec(),
+            latest_height,
+        };
+
+        let consensus_state = WasmConsensusState {
+            data: consensus_state,
+        };
+
+        let client_id = ClientId::new("08-wasm", self.next_client_sequence)
+            .expect("never fails as the client type is valid");
+
+        self.with_revert(|sim| {
+            let contract = sim.contract(&client_state.checksum)?;
+
+            let msg = InstantiateMessage {
+                client_state: client_state.data.into(),
+                consensus_state: consensus_state.data.into(),
+                checksum: client_state.checksum.into(),
+            };
+
+            let msg = from_json(to_json_vec(&msg)?)?;
+
+            let env = sim.env(&client_id);
+
+            let deps = DepsMut {
+                storage: &mut client_store(&mut sim.storage, &client_id),
+                api: &sim.api,
+                querier: QuerierWrapper::new(&sim.querier),
+            };
+
+            let info = MessageInfo {
+                sender: Addr::unchecked(IBC_MODULE_ADDRESS),
+                funds: vec![],
+            };
+
+            (contract.instantiate)(deps, env, info, msg)?;
+
+            // 02-client reads the states back from the client store
+            let client_state = sim.client_state(&client_id)?;
+
+            sim.consensus_state(&client_id, client_state.latest_height)?;
+
+            let status = sim.status(&client_id)?;
+
+            if status != Status::Active {
+                return Err(SimulatorError::ClientNotActive {
+                    client_id: client_id.to_string(),
+                    status,
+                });
+            }
+
+            Ok(())
+        })?;
+
+        self.next_client_sequence += 1;
+
+        Ok(client_id)
+    }
+
+    /// Returns the status of a client. 08-wasm reports the clients whose
+    /// contract fails to return a known status as `Unknown`, which is an
+    /// error here.
+    pub fn status(&self, client_id: &ClientId) -> Result<Status, SimulatorError> {
+        let result: StatusResult = self.query(client_id, QueryMessage::Status {})?;
+
+        match result.status.as_str() {
+            "Active" => Ok(Status::Active),
+            "Frozen" => Ok(Status::Frozen),
+            "Expired" => Ok(Status::Expired),
+            "Unauthorized" => Ok(Status::Unauthorized),
+            _ => Err(SimulatorError::UnknownStatus {
+                client_id: client_id.to_string(),
+                status: result.status,
+            }),
+        }
+    }
+
+    pub fn timestamp_at_height(
+        &self,
+        client_id: &ClientId,
+        height: Height,
+    ) -> Result<u64, SimulatorError> {
+        let result: TimestampAtHeightResult = self.query(
+            client_id,
+            QueryMessage::TimestampAtHeight {
+                height: height.into(),
+            },
+        )?;
+
+        Ok(result.timestamp)
+    }
+
+    /// Updates a client with a client message, as `MsgUpdateClient` does:
+    /// the message is verified and checked for misbehaviour, which freezes
+    /// the client, before updating its state. Returns the heights of the
+    /// consensus states added by the update.
+    ///
+    /// 08-wasm relies on the `VerifyClientMessage` query failing for invalid
+    /// messages, while the contracts built with `ibc-client-cw` report them
+    /// through the `is_valid` field of their result, so both are rejected.
+    pub fn update_client(
+        &mut self,
+        client_id: &ClientId,
+        client_message: Vec<u8>,
+    ) -> Result<Vec<Height>, SimulatorError> {
+        let status = self.status(client_id)?;
+
+        if status != Status::Active {
+            return Err(SimulatorError::ClientNotActive {
+                client_id: client_id.to_string(),
+                status,
+            });
+        }
+
+        let client_message = client_message.into();
+
+        let is_valid = self
+            .query::<VerifyClientMessageResponse>(
+                client_id,
+                QueryMessage::VerifyClientMessage {
+                    client_message: Clone::clone(&client_message),
+                },
+            )
+            .is_ok_and(|resp| resp.is_valid);
+
+        if !is_valid {
+            return Err(SimulatorError::InvalidClientMessage {
+                client_id: client_id.to_string(),
+            });
+        }
+
+        let result: CheckForMisbehaviourResult = self.query(
+            client_id,
+            QueryMessage::CheckForMisbehaviour {
+                client_message: Clone::clone(&client_message),
+            },
+        )?;
+
+        self.with_revert(|sim| {
+            if result.found_misbehaviour {
+                sim.sudo(
+                    client_id,
+                    SudoMessage::UpdateStateOnMisbehaviour { client_message },
+                )?;
+
+                return Ok(Vec::new());
+            }
+
+            let data = sim.sudo(client_id, SudoMessage::UpdateState { client_message })?;
+
+            let result: UpdateStateResult = from_json(data)?;
+
+            result
+                .heights
+                .into_iter()
+                .map(|height| Ok(Height::new(height.revision_number, height.revision_height)?))
+                .collect()
+        })
+    }
+
+    /// Verifies a membership proof, as the connection and channel handshakes
+    /// and the packet handlers do. The key path starts with the commitment
+    /// prefix of the counterparty.
+    #[allow(clippy::too_many_arguments)]
+    pub fn verify_membership(
+        &mut self,
+        client_id: &ClientId,
+        height: Height,
+        delay_time_period: u64,
+        delay_block_period: u64,
+        proof: Vec<u8>,
+        key_path: Vec<Vec<u8>>,
+        value: Vec<u8>,
+    ) -> Result<(), SimulatorError> {
+        let msg = SudoMessage::VerifyMembership {
+            height: height.into(),
+            delay_time_period,
+            delay_block_period,
+            proof: proof.into(),
+            merkle_path: GoMerklePath {
+                key_path: key_path.into_iter().map(Into::into).collect(),
+            },
+            value: value.into(),
+        };
+
+        self.with_revert(|sim| sim.sudo(client_id, msg).map(|_| ()))
+    }
+
+    /// Verifies a non-membership proof. See [`Simulator::verify_membership`].
+    pub fn verify_non_membership(
+        &mut self,
+        client_id: &ClientId,
+        height: Height,
+        delay_time_period: u64,
+        delay_block_period: u64,
+        proof: Vec<u8>,
+        key_path: Vec<Vec<u8>>,
+    ) -> Result<(), SimulatorError> {
+        let msg = SudoMessage::VerifyNonMembership {
+            height: height.into(),
+            delay_time_period,
+            delay_block_period,
+            proof: proof.into(),
+            merkle_path: GoMerklePath {
+                key_path: key_path.into_iter().map(Into::into).collect(),
+            },
+        };
+
+        self.with_revert(|sim| sim.sudo(client_id, msg).map(|_| ()))
+    }
+
+    /// Recovers a frozen or expired subject client with an active substitute
+    /// client, as `MsgRecoverClient` does. The contract of the subject client
+    /// is run over the migration store of the two clients.
+    pub fn recover_client(
+        &mut self,
+        subject: &ClientId,
+        substitute: &ClientId,
+    ) -> Result<(), SimulatorError> {
+        if self.status(subject)? == Status::Active {
+            return Err(SimulatorError::SubjectActive {
+                client_id: subject.to_string(),
+            });
+        }
+
+        let status = self.status(substitute)?;
+
+        if status != Status::Active {
+            return Err(SimulatorError::ClientNotActive {
+                client_id: substitute.to_string(),
+                status,
+            });
+        }
+
+        let subject_state = self.client_state(subject)?;
+        let substitute_state = self.client_state(substitute)?;
+
+        if subject_state.latest_height >= substitute_state.latest_height {
+            return Err(SimulatorError::SubstituteHeightTooLow {
+                subject: subject_state.latest_height.to_string(),
+                substitute: substitute_state.latest_height.to_string(),
+            });
+        }
+
+        if subject_state.checksum != substitute_state.checksum {
+            return Err(SimulatorError::ChecksumMismatch);
+        }
+
+        let contract = self.contract(&subject_state.checksum)?;
+
+        self.with_revert(|sim| {
+            let msg = from_json(to_json_vec(&SudoMessage::MigrateClientStore {})?)?;
+
+            let env = sim.env(subject);
+
+            let deps = DepsMut {
+                storage: &mut MigrationStore::new(&mut sim.storage, subject, substitute),
+                api: &sim.api,
+                querier: QuerierWrapper::new(&sim.querier),
+            };
+
+            (contract.sudo)(deps, env, msg)?;
+
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,226 @@
+use cosmwasm_std::testing::MockStorage;
+use cosmwasm_std::{Order, Record, Storage};
+use ibc_client_cw::utils::{PrefixedStorage, ReadonlyPrefixedStorage};
+use ibc_client_wasm_types::{SUBJECT_PREFIX, SUBSTITUTE_PREFIX};
+use ibc_core::host::types::identifiers::ClientId;
+use ibc_core::host::types::path::CLIENT_PREFIX;
+
+/// Returns the prefix of the store of a client within the IBC module store.
+pub fn client_store_prefix(client_id: &ClientId) -> Vec<u8> {
+    format!("{CLIENT_PREFIX}/{client_id}/").into_bytes()
+}
+
+/// The client stores seen through the [`MigrationStore`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ClientStore {
+    Subject,
+    Substitute,
+}
+
+impl ClientStore {
+    fn prefix(self) -> &'static [u8] {
+        match self {
+            Self::Subject => SUBJECT_PREFIX,
+            Self::Substitute => SUBSTITUTE_PREFIX,
+        }
+    }
+}
+
+/// Splits a key into the client store its `subject/` or `substitute/` prefix
+/// refers to and the rest of the key, as the `splitPrefix` function of
+/// 08-wasm.
+fn split_prefix(key: Option<&[u8]>) -> (Option<ClientStore>, Option<&[u8]>) {
+    let Some(key) = key else {
+        return (None, None);
+    };
+
+    for store in [ClientStore::Subject, ClientStore::Substitute] {
+        if let Some(rest) = key.strip_prefix(store.prefix()) {
+            return (Some(store), Some(rest));
+        }
+    }
+
+    (None, Some(key))
+}
+
+/// The store given to the contracts on `MigrateClientStore`, mirroring the
+/// `migrateClientWrappedStore` of 08-wasm: the keys prefixed with `subject/`
+/// and `substitute/` are read from the stores of the subject and substitute
+/// clients, while the other keys are not found. Only the subject store can be
+/// written to, and the iterations must stay within a single store.
+pub struct MigrationStore<'a> {
+    storage: &'a mut dyn Storage,
+    subject_prefix: Vec<u8>,
+    substitute_prefix: Vec<u8>,
+}
+
+impl<'a> MigrationStore<'a> {
+    pub fn new(storage: &'a mut dyn Storage, subject: &ClientId, substitute: &ClientId) -> Self {
+        Self {
+            storage,
+            subject_prefix: client_store_prefix(subject),
+            substitute_prefix: client_store_prefix(substitute),
+        }
+    }
+
+    fn store_prefix(&self, store: ClientStore) -> &[u8] {
+        match store {
+            ClientStore::Subject => &self.subject_prefix,
+            ClientStore::Substitute => &self.substitute_prefix,
+        }
+    }
+
+    fn subject_key(&self, key: &[u8]) -> Vec<u8> {
+        match split_prefix(Some(key)) {
+            (Some(ClientStore::Subject), Some(key)) => {
+                [self.subject_prefix.as_slice(), key].concat()
+            }
+            _ => panic!("writes only allowed on subject store"),
+        }
+    }
+}
+
+impl Storage for MigrationStore<'_> {
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        let (store, key) = split_prefix(Some(key));
+
+        ReadonlyPrefixedStorage::new(&*self.storage, self.store_prefix(store?))
+            .get(key.unwrap_or_default())
+    }
+
+    fn range<'b>(
+        &'b self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = Record> + 'b> {
+        let (start_store, start) = split_prefix(start);
+        let (end_store, end) = split_prefix(end);
+
+        // An iteration across stores, or outside of them, is closed.
+        let Some(store) = start_store.filter(|_| start_store == end_store) else {
+            return Box::new(core::iter::empty());
+        };
+
+        let prefix = store.prefix();
+
+        let records: Vec<Record> =
+            ReadonlyPrefixedStorage::new(&*self.storage, self.store_prefix(store))
+                .range(start, end, order)
+                .map(|(key, value)| ([prefix, key.as_slice()].concat(), value))
+                .collect();
+
+        Box::new(records.into_iter())
+    }
+
+    fn set(&mut self, key: &[u8], value: &[u8]) {
+        let key = self.subject_key(key);
+
+        self.storage.set(&key, value);
+    }
+
+    fn remove(&mut self, key: &[u8]) {
+        let key = self.subject_key(key);
+
+        self.storage.remove(&key);
+    }
+}
+
+/// Returns the store of a client, as given to its contract.
+pub fn client_store<'a>(storage: &'a mut dyn Storage, client_id: &ClientId) -> PrefixedStorage<'a> {
+    PrefixedStorage::new(storage, client_store_prefix(client_id))
+}
+
+/// Returns the read-only store of a client.
+pub fn client_store_ref<'a>(
+    storage: &'a dyn Storage,
+    client_id: &ClientId,
+) -> ReadonlyPrefixedStorage<'a> {
+    ReadonlyPrefixedStorage::new(storage, client_store_prefix(client_id))
+}
+
+/// Returns a copy of the store of a client, as given to its contract on
+/// queries, so that the writes of a query never reach the IBC module store.
+pub fn client_store_snapshot(storage: &dyn Storage, client_id: &ClientId) -> MockStorage {
+    let mut snapshot = MockStorage::new();
+
+    client_store_ref(storage, client_id)
+        .range(None, None, Order::Ascending)
+        .for_each(|(key, value)| snapshot.set(&key, &value));
+
+    snapshot
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::testing::MockStorage;
+
+    use super::*;
+
+    #[test]
+    fn test_migration_store() {
+        let subject = ClientId::new("08-wasm", 0).unwrap();
+        let substitute = ClientId::new("08-wasm", 1).unwrap();
+
+        let mut storage = MockStorage::new();
+
+        client_store(&mut storage, &subject).set(b"clientState", b"subject");
+        client_store(&mut storage, &substitute).set(b"clientState", b"substitute");
+        client_store(&mut storage, &substitute).set(b"consensusStates/0-1", b"1");
+        client_store(&mut storage, &substitute).set(b"consensusStates/0-2", b"2");
+
+        let mut store = MigrationStore::new(&mut storage, &subject, &substitute);
+
+        assert_eq!(store.get(b"subject/clientState"), Some(b"subject".to_vec()));
+        assert_eq!(
+            store.get(b"substitute/clientState"),
+            Some(b"substitute".to_vec())
+        );
+        assert_eq!(store.get(b"clientState"), None);
+
+        let keys: Vec<_> = store
+            .range(
+                Some(b"substitute/consensusStates/"),
+                Some(b"substitute/consensusStates0"),
+                Order::Ascending,
+            )
+            .map(|(key, _)| key)
+            .collect();
+
+        assert_eq!(
+            keys,
+            vec![
+                b"substitute/consensusStates/0-1".to_vec(),
+                b"substitute/consensusStates/0-2".to_vec(),
+            ]
+        );
+
+        // iterations across the stores, or outside of them, are closed
+        assert_eq!(
+            store
+                .range(Some(b"subject/"), Some(b"substitute0"), Order::Ascending)
+                .count(),
+            0
+        );
+        assert_eq!(store.range(None, None, Order::Ascending).count(), 0);
+
+        store.set(b"subject/clientState", b"recovered");
+
+        assert_eq!(
+            client_store_ref(&storage, &subject).get(b"clientState"),
+            Some(b"recovered".to_vec())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "writes only allowed on subject store")]
+    fn test_migration_store_rejects_substitute_writes() {
+        let subject = ClientId::new("08-wasm", 0).unwrap();
+        let substitute = ClientId::new("08-wasm", 1).unwrap();
+
+        let mut storage = MockStorage::new();
+
+        MigrationStore::new(&mut storage, &subject, &substitute)
+            .set(b"substitute/clientState", b"value");
+    }
+}
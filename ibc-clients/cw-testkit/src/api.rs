@@ -0,0 +1,45 @@
+use core::fmt::Debug;
+use core::time::Duration;
+
+use ibc_client_cw::api::ClientType;
+use ibc_core::client::types::Height;
+
+use crate::fixture::Fixture;
+
+/// Enables users to run the testkit scenarios against their light client, by
+/// generating the states and client messages that drive it through the
+/// [`Fixture`].
+///
+/// The states and messages are returned encoded as `Any`, as they are sent
+/// to the contract by the 08-wasm proxy light client.
+pub trait TestClient: for<'a> ClientType<'a> {
+    /// The parameters of the generated states and messages that are specific
+    /// to the light client, such as its chain identifier or validator set.
+    type Config: Clone + Debug + Default;
+
+    /// Returns the trusting period of the generated client states.
+    fn trusting_period(config: &Self::Config) -> Duration;
+
+    /// Returns the height of the consensus state trusted by a default
+    /// fixture, for clients whose heights follow the chain identifier or
+    /// the blocks of the counterparty.
+    fn trusted_height(_config: &Self::Config) -> Height {
+        Height::new(0, 5).expect("never fails")
+    }
+
+    /// Returns the client state the client is created with, whose latest
+    /// height is the trusted height of the fixture.
+    fn client_state(fxt: &Fixture<Self>) -> Vec<u8>;
+
+    /// Returns the consensus state the client is created with, at the
+    /// trusted height and timestamp of the fixture. Clients verifying
+    /// ICS-23 proofs commit to the app hash of the fixture store.
+    fn consensus_state(fxt: &Fixture<Self>) -> Vec<u8>;
+
+    /// Returns a valid header at the target height, verifiable against the
+    /// consensus state at the trusted height of the fixture.
+    fn header(fxt: &Fixture<Self>, target_height: Height) -> Vec<u8>;
+
+    /// Returns a client message that the client detects as misbehaviour.
+    fn misbehaviour(fxt: &Fixture<Self>) -> Vec<u8>;
+}
@@ -0,0 +1,83 @@
+use core::time::Duration;
+
+use cosmwasm_std::testing::mock_env;
+use cosmwasm_std::{BlockInfo, Env};
+use ibc_core::primitives::Timestamp;
+
+/// A controllable clock for the block height and time of the environment
+/// given to the contracts. Tests advance it explicitly instead of waiting on
+/// the wall clock, so that the time-sensitive scenarios are instant and
+/// reproducible.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Clock {
+    block: BlockInfo,
+}
+
+impl Default for Clock {
+    /// Starts at the block of the CosmWasm mock environment.
+    fn default() -> Self {
+        Self::new(mock_env().block)
+    }
+}
+
+impl Clock {
+    pub fn new(block: BlockInfo) -> Self {
+        Self { block }
+    }
+
+    pub fn block(&self) -> &BlockInfo {
+        &self.block
+    }
+
+    pub fn height(&self) -> u64 {
+        self.block.height
+    }
+
+    pub fn now(&self) -> Timestamp {
+        Timestamp::from_nanoseconds(self.block.time.nanos())
+    }
+
+    /// Advances the clock by the given number of blocks and duration.
+    ///
+    /// Panics if the duration does not fit in the nanoseconds of a block
+    /// time.
+    pub fn advance(&mut self, blocks: u64, duration: Duration) {
+        let nanos = u64::try_from(duration.as_nanos()).unwrap_or_else(|_| {
+            panic!("cannot advance the clock by {duration:?}: overflows u64 nanoseconds")
+        });
+
+        self.block.height += blocks;
+        self.block.time = self.block.time.plus_nanos(nanos);
+    }
+
+    /// Returns a mock environment at the current block of the clock.
+    pub fn env(&self) -> Env {
+        let mut env = mock_env();
+        env.block = self.block.clone();
+        env
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_clock_advance() {
+        let mut clock = Clock::default();
+
+        let start = clock.now();
+
+        clock.advance(2, Duration::from_secs(10));
+
+        assert_eq!(clock.height(), mock_env().block.height + 2);
+        assert_eq!(clock.now(), (start + Duration::from_secs(10)).unwrap());
+        assert_eq!(clock.env().block, *clock.block());
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows u64 nanoseconds")]
+    fn test_clock_advance_overflow() {
+        Clock::default().advance(1, Duration::MAX);
+    }
+}
@@ -0,0 +1,328 @@
+use core::fmt::Debug;
+
+use cosmwasm_std::{from_json, Binary, Deps, DepsMut, Empty, Response, StdError, StdResult};
+use ibc_client_cw::context::Context;
+use ibc_client_cw::types::{
+    CheckForMisbehaviourMsgRaw, CheckForMisbehaviourResponse, ContractError, InstantiateMsg,
+    MerklePath, MigrateClientStoreMsg, MigrationPrefix, QueryMsg, StatusMsg, StatusResponse,
+    UpdateStateMsgRaw, UpdateStateOnMisbehaviourMsgRaw, VerifyClientMessageRaw,
+    VerifyClientMessageResponse, VerifyMembershipMsgRaw, VerifyNonMembershipMsgRaw,
+};
+use ibc_core::client::types::{Height, Status};
+use ibc_core::primitives::Timestamp;
+
+use crate::api::TestClient;
+use crate::clock::Clock;
+use crate::store::{MultiStore, IBC_STORE};
+use crate::utils::dummy_checksum;
+
+/// Test fixture of a light client, generic over the [`TestClient`] that
+/// generates its states and client messages. The environment given to the
+/// contract is read from the [`Clock`] of the fixture, and the membership
+/// proofs are produced from its [`MultiStore`].
+pub struct Fixture<C: TestClient> {
+    pub config: C::Config,
+    pub clock: Clock,
+    pub store: MultiStore,
+    pub trusted_timestamp: Timestamp,
+    pub trusted_height: Height,
+    pub migration_prefix: MigrationPrefix,
+}
+
+// Implemented by hand, as deriving would require `C` itself, usually a unit
+// struct, to be `Clone` and `Debug`.
+impl<C: TestClient> Clone for Fixture<C> {
+    fn clone(&self) -> Self {
+        Self {
+            config: self.config.clone(),
+            clock: self.clock.clone(),
+            store: self.store.clone(),
+            trusted_timestamp: self.trusted_timestamp,
+            trusted_height: self.trusted_height,
+            migration_prefix: self.migration_prefix.clone(),
+        }
+    }
+}
+
+impl<C: TestClient> Debug for Fixture<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Fixture")
+            .field("config", &self.config)
+            .field("clock", &self.clock)
+            .field("store", &self.store)
+            .field("trusted_timestamp", &self.trusted_timestamp)
+            .field("trusted_height", &self.trusted_height)
+            .field("migration_prefix", &self.migration_prefix)
+            .finish()
+    }
+}
+
+impl<C: TestClient> Default for Fixture<C> {
+    /// Trusts a consensus state at the current time of the clock.
+    fn default() -> Self {
+        Self::new(C::Config::default())
+    }
+}
+
+impl<C: TestClient> Fixture<C> {
+    pub fn new(config: C::Config) -> Self {
+        let clock = Clock::default();
+
+        Self {
+            trusted_timestamp: clock.now(),
+            clock,
+            store: MultiStore::default(),
+            trusted_height: C::trusted_height(&config),
+            config,
+            migration_prefix: MigrationPrefix::None,
+        }
+    }
+
+    pub fn set_migration_prefix(&mut self, migration_mode: MigrationPrefix) {
+        self.migration_prefix = migration_mode;
+    }
+
+    pub fn ctx_ref<'a>(&self, deps: Deps<'a, Empty>) -> Context<'a, C> {
+        let mut ctx = Context::new_ref(deps, self.clock.env()).expect("never fails");
+
+        match self.migration_prefix {
+            MigrationPrefix::None => {}
+            MigrationPrefix::Subject => {
+                ctx.set_subject_prefix();
+            }
+            MigrationPrefix::Substitute => {
+                ctx.set_substitute_prefix();
+            }
+        };
+
+        ctx
+    }
+
+    pub fn ctx_mut<'a>(&self, deps: DepsMut<'a, Empty>) -> Context<'a, C> {
+        let mut ctx = Context::new_mut(deps, self.clock.env()).expect("never fails");
+
+        match self.migration_prefix {
+            MigrationPrefix::None => {}
+            MigrationPrefix::Subject => {
+                ctx.set_subject_prefix();
+            }
+            MigrationPrefix::Substitute => {
+                ctx.set_substitute_prefix();
+            }
+        };
+
+        ctx
+    }
+
+    pub fn dummy_instantiate_msg(&self) -> InstantiateMsg {
+        InstantiateMsg {
+            client_state: C::client_state(self).into(),
+            consensus_state: C::consensus_state(self).into(),
+            checksum: dummy_checksum(),
+        }
+    }
+
+    pub fn dummy_client_message(&self, target_height: Height) -> Vec<u8> {
+        C::header(self, target_height)
+    }
+
+    pub fn dummy_misbehaviour_message(&self) -> Vec<u8> {
+        C::misbehaviour(self)
+    }
+
+    /// Returns a message verifying the value at the path of the IBC store,
+    /// with a proof of the last commit of the fixture store.
+    pub fn dummy_verify_membership_msg(
+        &self,
+        height: Height,
+        path: &[u8],
+        value: &[u8],
+    ) -> VerifyMembershipMsgRaw {
+        VerifyMembershipMsgRaw {
+            proof: self.store.membership_proof(IBC_STORE, path),
+            merkle_path: MerklePath {
+                key_path: vec![IBC_STORE.to_vec().into(), path.to_vec().into()],
+            },
+            value: value.to_vec().into(),
+            height: height.into(),
+            delay_block_period: 0,
+            delay_time_period: 0,
+        }
+    }
+
+    /// Returns a message verifying the absence of the path in the IBC store,
+    /// with a proof of the last commit of the fixture store.
+    pub fn dummy_verify_non_membership_msg(
+        &self,
+        height: Height,
+        path: &[u8],
+    ) -> VerifyNonMembershipMsgRaw {
+        VerifyNonMembershipMsgRaw {
+            proof: self.store.non_membership_proof(IBC_STORE, path),
+            merkle_path: MerklePath {
+                key_path: vec![IBC_STORE.to_vec().into(), path.to_vec().into()],
+            },
+            height: height.into(),
+            delay_block_period: 0,
+            delay_time_period: 0,
+        }
+    }
+
+    pub fn is_valid_client_message(&self, deps: Deps<'_>, client_message: Vec<u8>) -> bool {
+        let resp: VerifyClientMessageResponse = self
+            .query(
+                deps,
+                VerifyClientMessageRaw {
+                    client_message: client_message.into(),
+                },
+            )
+            .and_then(from_json)
+            .unwrap();
+
+        resp.is_valid
+    }
+
+    pub fn found_misbehaviour(&self, deps: Deps<'_>, client_message: Vec<u8>) -> bool {
+        let resp: CheckForMisbehaviourResponse = self
+            .query(
+                deps,
+                CheckForMisbehaviourMsgRaw {
+                    client_message: client_message.into(),
+                },
+            )
+            .and_then(from_json)
+            .unwrap();
+
+        resp.found_misbehaviour
+    }
+
+    pub fn verify_client_message(&self, deps: Deps<'_>, client_message: Vec<u8>) {
+        assert!(self.is_valid_client_message(deps, client_message));
+    }
+
+    pub fn check_for_misbehaviour(&self, deps: Deps<'_>, client_message: Vec<u8>) {
+        assert!(self.found_misbehaviour(deps, client_message));
+    }
+
+    pub fn check_client_status(&self, deps: Deps<'_>, expected: Status) {
+        let resp: StatusResponse = self.query(deps, StatusMsg {}).and_then(from_json).unwrap();
+
+        assert_eq!(resp.status, expected);
+    }
+
+    pub fn query(&self, deps: Deps<'_>, msg: impl Into<QueryMsg>) -> StdResult<Binary> {
+        let ctx = self.ctx_ref(deps);
+
+        ctx.query(msg.into())
+            .map_err(|e| StdError::generic_err(e.to_string()))
+    }
+
+    pub fn create_client(&self, deps_mut: DepsMut<'_>) -> Result<Response, ContractError> {
+        self.instantiate(deps_mut, self.dummy_instantiate_msg())
+    }
+
+    /// Creates the client from the given states, such as states recorded from
+    /// the counterparty chain rather than generated by the [`TestClient`].
+    pub fn instantiate(
+        &self,
+        deps_mut: DepsMut<'_>,
+        instantiate_msg: InstantiateMsg,
+    ) -> Result<Response, ContractError> {
+        let mut ctx = self.ctx_mut(deps_mut);
+
+        let data = ctx.instantiate(instantiate_msg)?;
+
+        Ok(Response::default().set_data(data))
+    }
+
+    pub fn update_client(
+        &self,
+        deps_mut: DepsMut<'_>,
+        target_height: Height,
+    ) -> Result<Response, ContractError> {
+        self.update_client_with(deps_mut, self.dummy_client_message(target_height))
+    }
+
+    /// Updates the client with the given client message, which must be
+    /// valid.
+    pub fn update_client_with(
+        &self,
+        deps_mut: DepsMut<'_>,
+        client_message: Vec<u8>,
+    ) -> Result<Response, ContractError> {
+        self.verify_client_message(deps_mut.as_ref(), client_message.clone());
+
+        let mut ctx = self.ctx_mut(deps_mut);
+
+        let data = ctx.sudo(
+            UpdateStateMsgRaw {
+                client_message: client_message.into(),
+            }
+            .into(),
+        )?;
+
+        Ok(Response::default().set_data(data))
+    }
+
+    pub fn update_client_on_misbehaviour(&self, deps_mut: DepsMut<'_>) -> Response {
+        self.update_client_on_misbehaviour_with(deps_mut, self.dummy_misbehaviour_message())
+    }
+
+    /// Freezes the client with the given client message, which must be
+    /// found to be misbehaviour.
+    pub fn update_client_on_misbehaviour_with(
+        &self,
+        deps_mut: DepsMut<'_>,
+        client_message: Vec<u8>,
+    ) -> Response {
+        self.check_for_misbehaviour(deps_mut.as_ref(), client_message.clone());
+
+        let mut ctx = self.ctx_mut(deps_mut);
+
+        let data = ctx
+            .sudo(
+                UpdateStateOnMisbehaviourMsgRaw {
+                    client_message: client_message.into(),
+                }
+                .into(),
+            )
+            .unwrap();
+
+        Response::default().set_data(data)
+    }
+
+    /// Recovers the subject client with the substitute client, as the
+    /// contract does on `MigrateClientStore`, regardless of the migration
+    /// prefix of the fixture.
+    pub fn recover_client(&self, deps_mut: DepsMut<'_>) -> Result<Response, ContractError> {
+        let mut ctx = Context::<C>::new_mut(deps_mut, self.clock.env()).expect("never fails");
+
+        let data = ctx.sudo(MigrateClientStoreMsg {}.into())?;
+
+        Ok(Response::default().set_data(data))
+    }
+
+    pub fn verify_membership(
+        &self,
+        deps_mut: DepsMut<'_>,
+        msg: VerifyMembershipMsgRaw,
+    ) -> Result<Response, ContractError> {
+        let mut ctx = self.ctx_mut(deps_mut);
+
+        let data = ctx.sudo(msg.into())?;
+
+        Ok(Response::default().set_data(data))
+    }
+
+    pub fn verify_non_membership(
+        &self,
+        deps_mut: DepsMut<'_>,
+        msg: VerifyNonMembershipMsgRaw,
+    ) -> Result<Response, ContractError> {
+        let mut ctx = self.ctx_mut(deps_mut);
+
+        let data = ctx.sudo(msg.into())?;
+
+        Ok(Response::default().set_data(data))
+    }
+}
@@ -0,0 +1,15 @@
+//! Contains a test fixture and a suite of scenarios for light clients
+//! integrated into CosmWasm contracts with the `ibc-client-cw` crate. Light
+//! clients implement the [`TestClient`](api::TestClient) trait to generate
+//! their states and client messages, and get the same scenarios run against
+//! their `Context` as the existing clients. The block time and height seen by
+//! the contracts follow the [`Clock`](clock::Clock) of the fixture, which the
+//! tests advance explicitly, and the membership proofs are produced from its
+//! in-memory [`MultiStore`](store::MultiStore).
+
+pub mod api;
+pub mod clock;
+pub mod fixture;
+pub mod scenarios;
+pub mod store;
+pub mod utils;
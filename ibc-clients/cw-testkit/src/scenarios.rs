@@ -0,0 +1,139 @@
+//! The scenarios run by the testkit against a light client. Each scenario
+//! starts from a fresh mock storage and panics on the first unexpected
+//! outcome, so that light clients can call them from their own tests. The
+//! scenarios advance their own copy of the fixture clock.
+
+use core::time::Duration;
+
+use cosmwasm_std::from_json;
+use cosmwasm_std::testing::mock_dependencies;
+use ibc_client_cw::types::{ContractResult, MigrationPrefix};
+use ibc_core::client::types::Status;
+
+use crate::api::TestClient;
+use crate::fixture::Fixture;
+
+/// The time between the blocks of the counterparty chain in the scenarios.
+pub const BLOCK_TIME: Duration = Duration::from_secs(5);
+
+/// Creates the client, which is then active without any update.
+pub fn create_client_ok<C: TestClient>(fxt: &Fixture<C>) {
+    let mut deps = mock_dependencies();
+
+    let resp = fxt.create_client(deps.as_mut()).unwrap();
+
+    assert_eq!(0, resp.messages.len());
+
+    let contract_result: ContractResult = from_json(resp.data.unwrap()).unwrap();
+
+    assert!(contract_result.heights.is_none());
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+}
+
+/// Updates the client with a header at a height above the trusted height,
+/// produced as many blocks later.
+pub fn update_client_ok<C: TestClient>(fxt: &Fixture<C>) {
+    let mut fxt = fxt.clone();
+
+    let mut deps = mock_dependencies();
+
+    // ------------------- Create client -------------------
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    // ------------------- Verify and Update client -------------------
+
+    fxt.clock.advance(5, 5 * BLOCK_TIME);
+
+    let target_height = fxt.trusted_height.add(5);
+
+    let resp = fxt.update_client(deps.as_mut(), target_height).unwrap();
+
+    // ------------------- Check response -------------------
+
+    assert_eq!(0, resp.messages.len());
+
+    let contract_result: ContractResult = from_json(resp.data.unwrap()).unwrap();
+
+    // Clients following every block of the counterparty, such as Bitcoin
+    // clients, also store the consensus states of the intermediate heights.
+    let heights = contract_result.heights.unwrap();
+
+    assert_eq!(heights.last(), Some(&target_height));
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+}
+
+/// Freezes the client on misbehaviour.
+pub fn freeze_on_misbehaviour<C: TestClient>(fxt: &Fixture<C>) {
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    fxt.update_client_on_misbehaviour(deps.as_mut());
+
+    fxt.check_client_status(deps.as_ref(), Status::Frozen);
+}
+
+/// Recovers a frozen subject client with an active substitute client.
+pub fn recovery_client_ok<C: TestClient>(fxt: &Fixture<C>) {
+    let mut fxt = fxt.clone();
+
+    let mut deps = mock_dependencies();
+
+    // ------------------- Create subject client -------------------
+
+    fxt.set_migration_prefix(MigrationPrefix::Subject);
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    // ------------------- Freeze subject client -------------------
+
+    fxt.update_client_on_misbehaviour(deps.as_mut());
+
+    fxt.check_client_status(deps.as_ref(), Status::Frozen);
+
+    // ------------------- Create substitute client -------------------
+
+    fxt.set_migration_prefix(MigrationPrefix::Substitute);
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    // ------------------- Recover subject client -------------------
+
+    let resp = fxt.recover_client(deps.as_mut()).unwrap();
+
+    assert_eq!(0, resp.messages.len());
+
+    fxt.set_migration_prefix(MigrationPrefix::Subject);
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+}
+
+/// Creates the client and lets its consensus state outlive the trusting
+/// period, after which the client is expired.
+pub fn client_expiry<C: TestClient>(fxt: &Fixture<C>) {
+    let mut fxt = fxt.clone();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+
+    let trusting_period = C::trusting_period(&fxt.config);
+
+    fxt.clock.advance(1, trusting_period + BLOCK_TIME);
+
+    fxt.check_client_status(deps.as_ref(), Status::Expired);
+}
+
+/// Runs all the scenarios of the testkit against the client.
+pub fn run_all<C: TestClient>(fxt: &Fixture<C>) {
+    create_client_ok(fxt);
+    update_client_ok(fxt);
+    freeze_on_misbehaviour(fxt);
+    recovery_client_ok(fxt);
+    client_expiry(fxt);
+}
@@ -0,0 +1,483 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_std::Binary;
+use ibc_core::commitment_types::merkle::MerkleProof;
+use ibc_core::commitment_types::proto::ics23::commitment_proof::Proof;
+use ibc_core::commitment_types::proto::ics23::{
+    iavl_spec, tendermint_spec, CommitmentProof, ExistenceProof, HashOp, InnerOp, LeafOp,
+    NonExistenceProof,
+};
+use ibc_core::commitment_types::proto::v1::MerkleProof as RawMerkleProof;
+use ibc_core::primitives::proto::Protobuf;
+use prost::encoding::encode_varint;
+use sha2::{Digest, Sha256};
+
+/// The name of the store of the IBC module, which is the prefix of the
+/// paths the counterparty proves.
+pub const IBC_STORE: &[u8] = b"ibc";
+
+pub fn sha256(data: impl AsRef<[u8]>) -> [u8; 32] {
+    Sha256::digest(data).into()
+}
+
+/// Encodes the given proofs, from the innermost store to the root, as a
+/// `MerkleProof`.
+pub fn encode_merkle_proof(proofs: Vec<Proof>) -> Binary {
+    let merkle_proof = MerkleProof {
+        proofs: proofs
+            .into_iter()
+            .map(|proof| CommitmentProof { proof: Some(proof) })
+            .collect(),
+    };
+
+    Protobuf::<RawMerkleProof>::encode_vec(merkle_proof).into()
+}
+
+fn length_prefixed(data: &[u8]) -> Vec<u8> {
+    let mut prefixed = vec![];
+    encode_varint(data.len() as u64, &mut prefixed);
+    prefixed.extend(data);
+    prefixed
+}
+
+/// Encodes the height, size and version of an IAVL node, as the signed
+/// varints prefixing its hash preimage.
+fn iavl_node_header(height: i64, size: i64, version: i64) -> Vec<u8> {
+    let mut header = vec![];
+
+    for value in [height, size, version] {
+        encode_varint(((value << 1) ^ (value >> 63)) as u64, &mut header);
+    }
+
+    header
+}
+
+/// The layout of a [`MerkleTree`], setting how its nodes are hashed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TreeLayout {
+    /// The IAVL tree of a Cosmos SDK store at the given version, whose
+    /// proofs match the ICS-23 `iavl_spec`.
+    Iavl { version: i64 },
+    /// The RFC 6962 layout used by Tendermint and by the Cosmos SDK
+    /// multistore, whose proofs match the ICS-23 `tendermint_spec`.
+    Simple,
+}
+
+/// The root hash of a subtree, along with the height and the number of
+/// leaves that IAVL inner nodes commit to.
+#[derive(Clone, Copy, Debug)]
+struct Subtree {
+    hash: [u8; 32],
+    height: i64,
+    size: i64,
+}
+
+/// An in-memory Merkle tree over key-values sorted by key, hashed following
+/// its [`TreeLayout`].
+#[derive(Clone, Debug)]
+pub struct MerkleTree {
+    layout: TreeLayout,
+    entries: BTreeMap<Vec<u8>, Vec<u8>>,
+}
+
+impl MerkleTree {
+    pub fn iavl(version: i64) -> Self {
+        Self {
+            layout: TreeLayout::Iavl { version },
+            entries: BTreeMap::new(),
+        }
+    }
+
+    pub fn simple() -> Self {
+        Self {
+            layout: TreeLayout::Simple,
+            entries: BTreeMap::new(),
+        }
+    }
+
+    pub fn layout(&self) -> TreeLayout {
+        self.layout
+    }
+
+    pub fn insert(&mut self, key: &[u8], value: &[u8]) {
+        self.entries.insert(key.to_vec(), value.to_vec());
+    }
+
+    pub fn remove(&mut self, key: &[u8]) {
+        self.entries.remove(key);
+    }
+
+    pub fn get(&self, key: &[u8]) -> Option<&[u8]> {
+        self.entries.get(key).map(Vec::as_slice)
+    }
+
+    fn leaf_op(&self) -> LeafOp {
+        match self.layout {
+            TreeLayout::Iavl { version } => LeafOp {
+                prefix: iavl_node_header(0, 1, version),
+                ..iavl_spec().leaf_spec.expect("never fails")
+            },
+            TreeLayout::Simple => tendermint_spec().leaf_spec.expect("never fails"),
+        }
+    }
+
+    fn leaf(&self, key: &[u8], value: &[u8]) -> Subtree {
+        let hash = sha256(
+            [
+                self.leaf_op().prefix.as_slice(),
+                &length_prefixed(key),
+                &length_prefixed(&sha256(value)),
+            ]
+            .concat(),
+        );
+
+        Subtree {
+            hash,
+            height: 0,
+            size: 1,
+        }
+    }
+
+    /// Returns the bytes hashed before the left child of an inner node.
+    fn inner_prefix(&self, left: &Subtree, right: &Subtree) -> Vec<u8> {
+        match self.layout {
+            TreeLayout::Iavl { version } => {
+                let height = left.height.max(right.height) + 1;
+
+                let mut prefix = iavl_node_header(height, left.size + right.size, version);
+                prefix.push(32);
+                prefix
+            }
+            TreeLayout::Simple => vec![1],
+        }
+    }
+
+    /// Returns the bytes hashed between the children of an inner node.
+    fn inner_separator(&self) -> &'static [u8] {
+        match self.layout {
+            TreeLayout::Iavl { .. } => &[32],
+            TreeLayout::Simple => &[],
+        }
+    }
+
+    /// Returns the size of the left subtree of a tree with `n > 1` leaves,
+    /// i.e. the largest power of two smaller than `n`.
+    fn split_point(n: usize) -> usize {
+        n.next_power_of_two() / 2
+    }
+
+    fn subtree(&self, entries: &[(&Vec<u8>, &Vec<u8>)]) -> Subtree {
+        match entries {
+            [] => Subtree {
+                hash: sha256([]),
+                height: 0,
+                size: 0,
+            },
+            [(key, value)] => self.leaf(key, value),
+            _ => {
+                let (left, right) = entries.split_at(Self::split_point(entries.len()));
+
+                let (left, right) = (self.subtree(left), self.subtree(right));
+
+                let hash = sha256(
+                    [
+                        self.inner_prefix(&left, &right).as_slice(),
+                        &left.hash,
+                        self.inner_separator(),
+                        &right.hash,
+                    ]
+                    .concat(),
+                );
+
+                Subtree {
+                    hash,
+                    height: left.height.max(right.height) + 1,
+                    size: left.size + right.size,
+                }
+            }
+        }
+    }
+
+    fn sorted_entries(&self) -> Vec<(&Vec<u8>, &Vec<u8>)> {
+        self.entries.iter().collect()
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.subtree(&self.sorted_entries()).hash
+    }
+
+    fn existence_proof(&self, key: &[u8]) -> ExistenceProof {
+        let mut entries = &self.sorted_entries()[..];
+        let mut index = entries
+            .iter()
+            .position(|(k, _)| k.as_slice() == key)
+            .expect("key is in the tree");
+        let mut path = vec![];
+
+        while entries.len() > 1 {
+            let split = Self::split_point(entries.len());
+            let (left, right) = entries.split_at(split);
+            let (left_subtree, right_subtree) = (self.subtree(left), self.subtree(right));
+            let prefix = self.inner_prefix(&left_subtree, &right_subtree);
+
+            if index < split {
+                path.push(InnerOp {
+                    hash: HashOp::Sha256.into(),
+                    prefix,
+                    suffix: [self.inner_separator(), &right_subtree.hash].concat(),
+                });
+                entries = left;
+            } else {
+                path.push(InnerOp {
+                    hash: HashOp::Sha256.into(),
+                    prefix: [
+                        prefix.as_slice(),
+                        &left_subtree.hash,
+                        self.inner_separator(),
+                    ]
+                    .concat(),
+                    suffix: vec![],
+                });
+                entries = right;
+                index -= split;
+            }
+        }
+
+        path.reverse();
+
+        ExistenceProof {
+            key: key.to_vec(),
+            value: self.entries[key].clone(),
+            leaf: Some(self.leaf_op()),
+            path,
+        }
+    }
+
+    /// Returns the proof of the entry stored under `key`.
+    pub fn membership_proof(&self, key: &[u8]) -> Proof {
+        Proof::Exist(self.existence_proof(key))
+    }
+
+    /// Returns the proof of the absence of `key`, made of the existence
+    /// proofs of its neighbours in key order.
+    pub fn non_membership_proof(&self, key: &[u8]) -> Proof {
+        let left = self
+            .entries
+            .range(..key.to_vec())
+            .next_back()
+            .map(|(k, _)| self.existence_proof(k));
+
+        let right = self
+            .entries
+            .range(key.to_vec()..)
+            .next()
+            .map(|(k, _)| self.existence_proof(k));
+
+        Proof::Nonexist(NonExistenceProof {
+            key: key.to_vec(),
+            left,
+            right,
+        })
+    }
+}
+
+/// An in-memory Cosmos SDK multistore: every store is an IAVL tree, and the
+/// app hash is the root of the simple Merkle tree committing to the root of
+/// each store under its name. The proofs are of the last commit, and verify
+/// against its app hash with the `ProofSpecs::cosmos()` specs.
+#[derive(Clone, Debug, Default)]
+pub struct MultiStore {
+    working: BTreeMap<Vec<u8>, BTreeMap<Vec<u8>, Vec<u8>>>,
+    committed: BTreeMap<Vec<u8>, MerkleTree>,
+    version: i64,
+}
+
+impl MultiStore {
+    /// Sets the value of a key in the given store, to be committed with the
+    /// next [`commit`](Self::commit).
+    pub fn set(&mut self, store: &[u8], key: &[u8], value: &[u8]) {
+        self.working
+            .entry(store.to_vec())
+            .or_default()
+            .insert(key.to_vec(), value.to_vec());
+    }
+
+    /// Removes a key from the given store, with the next commit.
+    pub fn remove(&mut self, store: &[u8], key: &[u8]) {
+        if let Some(entries) = self.working.get_mut(store) {
+            entries.remove(key);
+        }
+    }
+
+    /// Commits the changes as a new version of the stores, and returns the
+    /// resulting app hash.
+    pub fn commit(&mut self) -> Vec<u8> {
+        self.version += 1;
+
+        self.committed = self
+            .working
+            .iter()
+            .map(|(name, entries)| {
+                let mut tree = MerkleTree::iavl(self.version);
+
+                for (key, value) in entries {
+                    tree.insert(key, value);
+                }
+
+                (name.clone(), tree)
+            })
+            .collect();
+
+        self.app_hash()
+    }
+
+    /// Returns the version of the last commit, starting at 0 before any.
+    pub fn version(&self) -> i64 {
+        self.version
+    }
+
+    fn multistore_tree(&self) -> MerkleTree {
+        let mut multistore = MerkleTree::simple();
+
+        for (name, tree) in &self.committed {
+            multistore.insert(name, &tree.root());
+        }
+
+        multistore
+    }
+
+    /// Returns the app hash of the last commit.
+    pub fn app_hash(&self) -> Vec<u8> {
+        self.multistore_tree().root().to_vec()
+    }
+
+    fn committed_store(&self, store: &[u8]) -> &MerkleTree {
+        self.committed
+            .get(store)
+            .expect("store is committed in the multistore")
+    }
+
+    /// Returns the proof of the committed value of a key in the given store,
+    /// encoded as the `MerkleProof` sent to the contracts.
+    pub fn membership_proof(&self, store: &[u8], key: &[u8]) -> Binary {
+        encode_merkle_proof(vec![
+            self.committed_store(store).membership_proof(key),
+            self.multistore_tree().membership_proof(store),
+        ])
+    }
+
+    /// Returns the proof of the absence of a key in the given store, as of
+    /// the last commit.
+    pub fn non_membership_proof(&self, store: &[u8], key: &[u8]) -> Binary {
+        encode_merkle_proof(vec![
+            self.committed_store(store).non_membership_proof(key),
+            self.multistore_tree().membership_proof(store),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ibc_core::commitment_types::commitment::CommitmentRoot;
+    use ibc_core::commitment_types::merkle::MerklePath;
+    use ibc_core::commitment_types::proto::ics23::HostFunctionsManager;
+    use ibc_core::commitment_types::proto::v1::MerkleRoot;
+    use ibc_core::commitment_types::specs::ProofSpecs;
+    use ibc_core::host::types::path::PathBytes;
+
+    use super::*;
+
+    fn decode_proof(proof: Binary) -> MerkleProof {
+        Protobuf::<RawMerkleProof>::decode_vec(&proof).unwrap()
+    }
+
+    fn merkle_path(store: &[u8], key: &[u8]) -> MerklePath {
+        MerklePath::new(vec![
+            PathBytes::from_bytes(store),
+            PathBytes::from_bytes(key),
+        ])
+    }
+
+    fn dummy_multistore(keys: &[&[u8]]) -> MultiStore {
+        let mut store = MultiStore::default();
+
+        store.set(b"bank", b"balance", b"100");
+
+        for key in keys {
+            store.set(IBC_STORE, key, &[key, &b"-value"[..]].concat());
+        }
+
+        store.commit();
+
+        store
+    }
+
+    #[test]
+    fn test_multistore_membership_proofs() {
+        let keys: [&[u8]; 5] = [b"b", b"d", b"f", b"h", b"j"];
+
+        let store = dummy_multistore(&keys);
+
+        let root: MerkleRoot = CommitmentRoot::from_bytes(&store.app_hash()).into();
+
+        for key in keys {
+            decode_proof(store.membership_proof(IBC_STORE, key))
+                .verify_membership::<HostFunctionsManager>(
+                    &ProofSpecs::cosmos(),
+                    root.clone(),
+                    merkle_path(IBC_STORE, key),
+                    [key, &b"-value"[..]].concat(),
+                    0,
+                )
+                .unwrap();
+
+            assert!(decode_proof(store.membership_proof(IBC_STORE, key))
+                .verify_membership::<HostFunctionsManager>(
+                    &ProofSpecs::cosmos(),
+                    root.clone(),
+                    merkle_path(IBC_STORE, key),
+                    b"other-value".to_vec(),
+                    0,
+                )
+                .is_err());
+        }
+
+        // absent keys before, between and after the committed ones
+        for key in [&b"a"[..], b"c", b"e", b"g", b"i", b"k"] {
+            decode_proof(store.non_membership_proof(IBC_STORE, key))
+                .verify_non_membership::<HostFunctionsManager>(
+                    &ProofSpecs::cosmos(),
+                    root.clone(),
+                    merkle_path(IBC_STORE, key),
+                )
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn test_multistore_commit() {
+        let mut store = dummy_multistore(&[b"key"]);
+
+        let app_hash = store.app_hash();
+
+        store.set(IBC_STORE, b"other-key", b"value");
+
+        // uncommitted changes are not proven
+        assert_eq!(store.app_hash(), app_hash);
+
+        let new_app_hash = store.commit();
+
+        assert_ne!(new_app_hash, app_hash);
+        assert_eq!(store.version(), 2);
+
+        decode_proof(store.membership_proof(IBC_STORE, b"other-key"))
+            .verify_membership::<HostFunctionsManager>(
+                &ProofSpecs::cosmos(),
+                CommitmentRoot::from_bytes(&new_app_hash).into(),
+                merkle_path(IBC_STORE, b"other-key"),
+                b"value".to_vec(),
+                0,
+            )
+            .unwrap();
+    }
+}
@@ -0,0 +1,9 @@
+use cosmwasm_std::{Binary, Checksum};
+
+pub fn dummy_checksum() -> Binary {
+    let hex_bytes =
+        Checksum::from_hex("2469f43c3ca20d476442bd3d98cbd97a180776ab37332aa7b02cae5a620acfc6")
+            .expect("Never fails");
+
+    hex_bytes.as_slice().into()
+}
@@ -0,0 +1,63 @@
+#!/usr/bin/env bash
+# Records the latest finality update of a live beacon chain, along with a
+# bootstrap of the same sync committee period and the account proof of the
+# IBC contract, under `fixtures/recorded/<name>`. The layout is described in
+# `src/tests/recorded.rs`.
+#
+# Usage:
+#   BEACON_API=<url> EXECUTION_RPC=<url> CHAIN_ID=<id> \
+#   IBC_CONTRACT_ADDRESS=<0x...> [IBC_COMMITMENT_SLOT=<0x...>] \
+#   record.sh <name>
+set -euo pipefail
+
+: "${BEACON_API:?BEACON_API must be set to the URL of a beacon node}"
+: "${EXECUTION_RPC:?EXECUTION_RPC must be set to the URL of an execution node}"
+: "${CHAIN_ID:?CHAIN_ID must be set to the chain ID of the execution layer}"
+: "${IBC_CONTRACT_ADDRESS:?IBC_CONTRACT_ADDRESS must be set}"
+IBC_COMMITMENT_SLOT=${IBC_COMMITMENT_SLOT:-0x0000000000000000000000000000000000000000000000000000000000000000}
+
+name=${1:?usage: record.sh <name>}
+dir="$(cd "$(dirname "$0")" && pwd)/recorded/$name"
+mkdir -p "$dir"
+
+beacon() {
+  curl -fsS -H 'Accept: application/json' "$BEACON_API$1"
+}
+
+beacon /eth/v1/beacon/genesis | jq . > "$dir/genesis.json"
+beacon /eth/v1/config/spec | jq . > "$dir/spec.json"
+beacon /eth/v1/beacon/light_client/finality_update | jq . > "$dir/update.json"
+
+slots_per_epoch=$(jq -r .data.SLOTS_PER_EPOCH "$dir/spec.json")
+epochs_per_period=$(jq -r .data.EPOCHS_PER_SYNC_COMMITTEE_PERIOD "$dir/spec.json")
+slots_per_period=$((slots_per_epoch * epochs_per_period))
+
+finalized_slot=$(jq -r .data.finalized_header.beacon.slot "$dir/update.json")
+signature_slot=$(jq -r .data.signature_slot "$dir/update.json")
+block_number=$(jq -r .data.finalized_header.execution.block_number "$dir/update.json")
+
+# Bootstraps are only served for finalized checkpoint blocks. Take the one of
+# the epoch before the finalized checkpoint, i.e. the last block at or before
+# its first slot, which must be in the period of the signature slot.
+slot=$(((finalized_slot / slots_per_epoch - 1) * slots_per_epoch))
+
+if ((slot / slots_per_period != signature_slot / slots_per_period)); then
+  echo "the update is signed in another period than the bootstrap, retry in a few epochs" >&2
+  exit 1
+fi
+
+until block_root=$(beacon "/eth/v1/beacon/blocks/$slot/root" 2>/dev/null | jq -r .data.root); do
+  slot=$((slot - 1))
+done
+
+beacon "/eth/v1/beacon/light_client/bootstrap/$block_root" | jq . > "$dir/bootstrap.json"
+
+curl -fsS -H 'Content-Type: application/json' "$EXECUTION_RPC" \
+  -d "$(jq -nc --arg address "$IBC_CONTRACT_ADDRESS" --arg block "$(printf '0x%x' "$block_number")" \
+    '{jsonrpc: "2.0", id: 1, method: "eth_getProof", params: [$address, [], $block]}')" |
+  jq 'if .error then error(.error.message) else . end' > "$dir/account_proof.json"
+
+jq -n --arg chain_id "$CHAIN_ID" --arg address "$IBC_CONTRACT_ADDRESS" --arg slot "$IBC_COMMITMENT_SLOT" \
+  '{chain_id: $chain_id, ibc_contract_address: $address, ibc_commitment_slot: $slot}' > "$dir/ibc.json"
+
+echo "Recorded the update finalizing slot $finalized_slot, bootstrapped at slot $slot, in $dir"
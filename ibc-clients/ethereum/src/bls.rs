@@ -0,0 +1,89 @@
+//! BLS signature verification of sync committee aggregates, delegated to the
+//! BLS12-381 primitives exposed by the CosmWasm host.
+use cosmwasm_std::{HashFunction, BLS12_381_G1_GENERATOR};
+use ibc_client_cw::context::Context;
+
+use crate::client_type::EthereumClient;
+use crate::error::EthereumError;
+use crate::ssz::{hash_pair, Bytes32, ZERO_CHUNK};
+use crate::types::{Bytes4, Bytes48, Bytes96};
+
+/// The domain type of sync committee signatures.
+pub const DOMAIN_SYNC_COMMITTEE: Bytes4 = [7, 0, 0, 0];
+
+/// The ciphersuite used by Ethereum for BLS signatures (proof of possession).
+pub const BLS_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// Provides BLS aggregate signature verification to the light client.
+pub trait BlsVerifier {
+    /// Verifies that `signature` is a valid aggregate signature of all
+    /// `pubkeys` over `message`.
+    fn fast_aggregate_verify(
+        &self,
+        pubkeys: &[Bytes48],
+        message: &Bytes32,
+        signature: &Bytes96,
+    ) -> Result<(), EthereumError>;
+}
+
+impl BlsVerifier for Context<'_, EthereumClient> {
+    fn fast_aggregate_verify(
+        &self,
+        pubkeys: &[Bytes48],
+        message: &Bytes32,
+        signature: &Bytes96,
+    ) -> Result<(), EthereumError> {
+        let signature_error = |e: &dyn core::fmt::Display| EthereumError::InvalidSignature {
+            description: e.to_string(),
+        };
+
+        let api = self.api();
+
+        let aggregate_pubkey = api
+            .bls12_381_aggregate_g1(&pubkeys.concat())
+            .map_err(|e| signature_error(&e))?;
+
+        let message_point = api
+            .bls12_381_hash_to_g2(HashFunction::Sha256, message, BLS_DST)
+            .map_err(|e| signature_error(&e))?;
+
+        let is_valid = api
+            .bls12_381_pairing_equality(
+                &BLS12_381_G1_GENERATOR,
+                signature,
+                &aggregate_pubkey,
+                &message_point,
+            )
+            .map_err(|e| signature_error(&e))?;
+
+        if !is_valid {
+            return Err(signature_error(&"pairing check failed"));
+        }
+
+        Ok(())
+    }
+}
+
+/// Computes the signature domain for the given fork version, following
+/// `compute_domain` of the consensus specs.
+pub fn compute_domain(
+    domain_type: Bytes4,
+    fork_version: Bytes4,
+    genesis_validators_root: &Bytes32,
+) -> Bytes32 {
+    let mut version_chunk = ZERO_CHUNK;
+    version_chunk[..4].copy_from_slice(&fork_version);
+
+    let fork_data_root = hash_pair(&version_chunk, genesis_validators_root);
+
+    let mut domain = ZERO_CHUNK;
+    domain[..4].copy_from_slice(&domain_type);
+    domain[4..].copy_from_slice(&fork_data_root[..28]);
+    domain
+}
+
+/// Computes the signing root of an object, following `compute_signing_root`
+/// of the consensus specs.
+pub fn compute_signing_root(object_root: &Bytes32, domain: &Bytes32) -> Bytes32 {
+    hash_pair(object_root, domain)
+}
@@ -0,0 +1,164 @@
+use core::time::Duration;
+
+use ibc_core::client::context::client_state::ClientStateCommon;
+use ibc_core::client::context::consensus_state::ConsensusState as _;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::{Height, Status};
+use ibc_core::commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::host::types::identifiers::ClientType;
+use ibc_core::host::types::path::{Path, PathBytes};
+use ibc_core::primitives::proto::{Any, Protobuf};
+use ibc_core::primitives::Timestamp;
+
+use super::ClientState;
+use crate::consensus_state::ConsensusState;
+use crate::error::EthereumError;
+use crate::mpt::{keccak256, trim_leading_zeros, verify_proof, RlpItem};
+use crate::ssz::Bytes32;
+use crate::types::proto::StorageProof as RawStorageProof;
+use crate::types::{fixed_bytes, StorageProof};
+
+pub const ETHEREUM_CLIENT_TYPE: &str = "ethereum";
+
+impl ClientStateCommon for ClientState {
+    fn verify_consensus_state(
+        &self,
+        consensus_state: Any,
+        host_timestamp: &Timestamp,
+    ) -> Result<(), ClientError> {
+        let consensus_state = ConsensusState::try_from(consensus_state)?;
+
+        if consensus_status(&consensus_state, host_timestamp, self.trusting_period)?.is_expired() {
+            return Err(ClientError::InvalidStatus(Status::Expired));
+        }
+
+        Ok(())
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::new(ETHEREUM_CLIENT_TYPE).expect("valid client type")
+    }
+
+    fn latest_height(&self) -> Height {
+        self.latest_height
+    }
+
+    fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError> {
+        if self.latest_height < proof_height {
+            return Err(ClientError::InsufficientProofHeight {
+                actual: self.latest_height,
+                expected: proof_height,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn serialize_path(&self, path: Path) -> Result<PathBytes, ClientError> {
+        Ok(path.to_string().into_bytes().into())
+    }
+
+    /// Upgrades are driven by the Ethereum fork schedule rather than by
+    /// upgrade proofs, so they are not supported by this client.
+    fn verify_upgrade_client(
+        &self,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+        _proof_upgrade_client: CommitmentProofBytes,
+        _proof_upgrade_consensus_state: CommitmentProofBytes,
+        _root: &CommitmentRoot,
+    ) -> Result<(), ClientError> {
+        Err(ClientError::ClientSpecific {
+            description: "upgrades are not supported by the Ethereum client".to_string(),
+        })
+    }
+
+    fn verify_membership_raw(
+        &self,
+        _prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: PathBytes,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        let stored = self.verify_storage_proof(proof, root, &path)?;
+
+        let expected = keccak256(value);
+
+        let Some(stored) = stored else {
+            return Err(EthereumError::MismatchedStorageValue)?;
+        };
+
+        if RlpItem::decode(&stored)?.as_bytes()? != trim_leading_zeros(&expected) {
+            return Err(EthereumError::MismatchedStorageValue)?;
+        }
+
+        Ok(())
+    }
+
+    fn verify_non_membership_raw(
+        &self,
+        _prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: PathBytes,
+    ) -> Result<(), ClientError> {
+        if self.verify_storage_proof(proof, root, &path)?.is_some() {
+            return Err(EthereumError::MismatchedStorageValue)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ClientState {
+    /// Returns the storage key of the IBC contract's commitment for `path`,
+    /// i.e. the slot of `commitments[keccak256(path)]`, hashed once more as
+    /// keys of the secure storage trie are.
+    pub fn commitment_storage_key(&self, path: &[u8]) -> Bytes32 {
+        let mut preimage = keccak256(path).to_vec();
+        preimage.extend_from_slice(&self.ibc_commitment_slot);
+
+        keccak256(keccak256(preimage))
+    }
+
+    /// Verifies the storage proof of the commitment at `path` against the
+    /// storage root, returning the RLP-encoded value if present.
+    fn verify_storage_proof(
+        &self,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: &PathBytes,
+    ) -> Result<Option<Vec<u8>>, ClientError> {
+        let storage_proof: StorageProof = Protobuf::<RawStorageProof>::decode(proof.as_ref())
+            .map_err(|e| EthereumError::from(DecodingError::from(e)))?;
+
+        let storage_root: Bytes32 =
+            fixed_bytes("storage root", root.as_bytes().to_vec()).map_err(EthereumError::from)?;
+
+        Ok(verify_proof(
+            &storage_root,
+            &self.commitment_storage_key(path.as_ref()),
+            &storage_proof.proof,
+        )?)
+    }
+}
+
+/// Determines whether a consensus state is `Active` or `Expired`, given the
+/// host's timestamp and the trusting period.
+pub fn consensus_status(
+    consensus_state: &ConsensusState,
+    host_timestamp: &Timestamp,
+    trusting_period: Duration,
+) -> Result<Status, ClientError> {
+    if let Some(elapsed) = host_timestamp.duration_since(&consensus_state.timestamp()?) {
+        if elapsed >= trusting_period {
+            return Ok(Status::Expired);
+        }
+    }
+
+    Ok(Status::Active)
+}
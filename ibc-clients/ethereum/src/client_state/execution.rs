@@ -0,0 +1,172 @@
+use ibc_core::client::context::client_state::{ClientStateCommon, ClientStateExecution};
+use ibc_core::client::context::prelude::*;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Height;
+use ibc_core::host::types::identifiers::ClientId;
+use ibc_core::host::types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc_core::primitives::proto::Any;
+
+use super::{consensus_state_at, ClientState};
+use crate::bls::BlsVerifier;
+use crate::consensus_state::ConsensusState;
+use crate::types::Header;
+
+impl<E> ClientStateExecution<E> for ClientState
+where
+    E: ExtClientExecutionContext + BlsVerifier,
+    E::ClientStateRef: From<Self>,
+    ConsensusState: Convertible<E::ConsensusStateRef>,
+    <ConsensusState as TryFrom<E::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    fn initialise(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        let consensus_state: ConsensusState = consensus_state.try_into()?;
+
+        ctx.store_client_state(ClientStatePath::new(client_id.clone()), self.clone().into())?;
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                client_id.clone(),
+                self.latest_height.revision_number(),
+                self.latest_height.revision_height(),
+            ),
+            consensus_state.into(),
+        )?;
+        ctx.store_update_meta(
+            client_id.clone(),
+            self.latest_height,
+            ctx.host_timestamp()?,
+            ctx.host_height()?,
+        )?;
+
+        Ok(())
+    }
+
+    fn update_state(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        let header = Header::try_from(header)?;
+        let header_height = header.height()?;
+
+        let maybe_existing_consensus_state = consensus_state_at(ctx, client_id, header_height);
+
+        if maybe_existing_consensus_state.is_ok() {
+            // if we already had the header installed by a previous relayer
+            // then this is a no-op.
+            return Ok(vec![header_height]);
+        }
+
+        let trusted_consensus_state = consensus_state_at(ctx, client_id, header.trusted_height)?;
+        let new_consensus_state = self.next_consensus_state(&trusted_consensus_state, &header)?;
+
+        let new_client_state = if header_height > self.latest_height {
+            Self {
+                latest_slot: new_consensus_state.slot,
+                latest_height: header_height,
+                ..self.clone()
+            }
+        } else {
+            self.clone()
+        };
+
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                client_id.clone(),
+                header_height.revision_number(),
+                header_height.revision_height(),
+            ),
+            new_consensus_state.into(),
+        )?;
+        ctx.store_client_state(
+            ClientStatePath::new(client_id.clone()),
+            new_client_state.into(),
+        )?;
+        ctx.store_update_meta(
+            client_id.clone(),
+            header_height,
+            ctx.host_timestamp()?,
+            ctx.host_height()?,
+        )?;
+
+        Ok(vec![header_height])
+    }
+
+    fn update_state_on_misbehaviour(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        _client_message: Any,
+    ) -> Result<(), ClientError> {
+        let frozen_client_state = Self {
+            frozen_height: Some(Height::min(0)),
+            ..self.clone()
+        };
+
+        ctx.store_client_state(
+            ClientStatePath::new(client_id.clone()),
+            frozen_client_state.into(),
+        )?;
+
+        Ok(())
+    }
+
+    fn update_state_on_upgrade(
+        &self,
+        _ctx: &mut E,
+        _client_id: &ClientId,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        Err(ClientError::ClientSpecific {
+            description: "upgrades are not supported by the Ethereum client".to_string(),
+        })
+    }
+
+    fn update_on_recovery(
+        &self,
+        ctx: &mut E,
+        subject_client_id: &ClientId,
+        substitute_client_state: Any,
+        substitute_consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        let substitute_client_state = Self::try_from(substitute_client_state)?;
+        let new_consensus_state: ConsensusState = substitute_consensus_state.try_into()?;
+
+        let new_client_state = Self {
+            latest_slot: substitute_client_state.latest_slot,
+            latest_height: substitute_client_state.latest_height,
+            trusting_period: substitute_client_state.trusting_period,
+            frozen_height: None,
+            ..self.clone()
+        };
+
+        let latest_height = new_client_state.latest_height();
+
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                subject_client_id.clone(),
+                latest_height.revision_number(),
+                latest_height.revision_height(),
+            ),
+            new_consensus_state.into(),
+        )?;
+        ctx.store_client_state(
+            ClientStatePath::new(subject_client_id.clone()),
+            new_client_state.into(),
+        )?;
+        ctx.store_update_meta(
+            subject_client_id.clone(),
+            latest_height,
+            ctx.host_timestamp()?,
+            ctx.host_height()?,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,171 @@
+//! Implements the Ethereum `ClientState` along with the `ClientStateCommon`,
+//! `ClientStateValidation` and `ClientStateExecution` traits.
+use core::time::Duration;
+
+use ibc_core::client::types::Height;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::proto::{Any, Protobuf};
+
+use crate::ssz::Bytes32;
+use crate::types::proto::ClientState as RawClientState;
+use crate::types::{fixed_bytes, Bytes20, ForkParameters};
+
+mod common;
+mod execution;
+mod validation;
+
+pub use common::*;
+pub use validation::*;
+
+pub const ETHEREUM_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.ethereum.v1.ClientState";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientState {
+    /// The chain ID of the execution layer.
+    pub chain_id: u64,
+    pub genesis_validators_root: Bytes32,
+    /// The genesis time of the beacon chain, in seconds.
+    pub genesis_time: u64,
+    pub fork_parameters: ForkParameters,
+    pub seconds_per_slot: u64,
+    pub slots_per_epoch: u64,
+    pub epochs_per_sync_committee_period: u64,
+    /// The number of members in a sync committee, e.g. 512 on mainnet.
+    pub sync_committee_size: u64,
+    pub trusting_period: Duration,
+    /// The beacon slot of the latest finalized header.
+    pub latest_slot: u64,
+    /// The execution block number of the latest finalized header.
+    pub latest_height: Height,
+    pub frozen_height: Option<Height>,
+    /// The address of the IBC contract whose storage holds the commitments.
+    pub ibc_contract_address: Bytes20,
+    /// The storage slot of the IBC contract's commitments mapping.
+    pub ibc_commitment_slot: Bytes32,
+}
+
+impl ClientState {
+    pub fn is_frozen(&self) -> bool {
+        self.frozen_height.is_some()
+    }
+
+    pub fn compute_epoch(&self, slot: u64) -> u64 {
+        slot / self.slots_per_epoch
+    }
+
+    pub fn compute_sync_committee_period(&self, slot: u64) -> u64 {
+        self.compute_epoch(slot) / self.epochs_per_sync_committee_period
+    }
+
+    fn validate(&self) -> Result<(), DecodingError> {
+        if self.sync_committee_size == 0 || !self.sync_committee_size.is_power_of_two() {
+            return Err(DecodingError::invalid_raw_data(format!(
+                "sync committee size `{}` must be a non-zero power of two",
+                self.sync_committee_size
+            )));
+        }
+
+        if self.sync_committee_size % 8 != 0 {
+            return Err(DecodingError::invalid_raw_data(
+                "sync committee size must be a multiple of 8",
+            ));
+        }
+
+        if self.slots_per_epoch == 0 || self.epochs_per_sync_committee_period == 0 {
+            return Err(DecodingError::invalid_raw_data(
+                "slots per epoch and epochs per sync committee period must be non-zero",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        let client_state = Self {
+            chain_id: raw.chain_id,
+            genesis_validators_root: fixed_bytes(
+                "genesis validators root",
+                raw.genesis_validators_root,
+            )?,
+            genesis_time: raw.genesis_time,
+            fork_parameters: raw
+                .fork_parameters
+                .ok_or(DecodingError::missing_raw_data("fork parameters"))?
+                .try_into()?,
+            seconds_per_slot: raw.seconds_per_slot,
+            slots_per_epoch: raw.slots_per_epoch,
+            epochs_per_sync_committee_period: raw.epochs_per_sync_committee_period,
+            sync_committee_size: raw.sync_committee_size,
+            trusting_period: Duration::from_nanos(raw.trusting_period),
+            latest_slot: raw.latest_slot,
+            latest_height: raw
+                .latest_height
+                .ok_or(DecodingError::missing_raw_data("latest height"))?
+                .try_into()?,
+            frozen_height: raw.frozen_height.map(TryInto::try_into).transpose()?,
+            ibc_contract_address: fixed_bytes("IBC contract address", raw.ibc_contract_address)?,
+            ibc_commitment_slot: fixed_bytes("IBC commitment slot", raw.ibc_commitment_slot)?,
+        };
+
+        client_state.validate()?;
+
+        Ok(client_state)
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            chain_id: value.chain_id,
+            genesis_validators_root: value.genesis_validators_root.to_vec(),
+            genesis_time: value.genesis_time,
+            fork_parameters: Some(value.fork_parameters.into()),
+            seconds_per_slot: value.seconds_per_slot,
+            slots_per_epoch: value.slots_per_epoch,
+            epochs_per_sync_committee_period: value.epochs_per_sync_committee_period,
+            sync_committee_size: value.sync_committee_size,
+            trusting_period: value
+                .trusting_period
+                .as_nanos()
+                .try_into()
+                .expect("no overflow"),
+            latest_slot: value.latest_slot,
+            latest_height: Some(value.latest_height.into()),
+            frozen_height: value.frozen_height.map(Into::into),
+            ibc_contract_address: value.ibc_contract_address.to_vec(),
+            ibc_commitment_slot: value.ibc_commitment_slot.to_vec(),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let ETHEREUM_CLIENT_STATE_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawClientState>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: ETHEREUM_CLIENT_STATE_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Self {
+            type_url: ETHEREUM_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientState>::encode_vec(client_state),
+        }
+    }
+}
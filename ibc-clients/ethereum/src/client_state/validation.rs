@@ -0,0 +1,475 @@
+use ibc_core::client::context::client_state::ClientStateValidation;
+use ibc_core::client::context::prelude::*;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::{Height, Status};
+use ibc_core::host::types::identifiers::ClientId;
+use ibc_core::host::types::path::ClientConsensusStatePath;
+use ibc_core::primitives::proto::Any;
+use ibc_core::primitives::Timestamp;
+
+use super::{consensus_status, ClientState};
+use crate::bls::{compute_domain, compute_signing_root, BlsVerifier, DOMAIN_SYNC_COMMITTEE};
+use crate::consensus_state::ConsensusState;
+use crate::error::EthereumError;
+use crate::mpt::{account_storage_root, keccak256, verify_proof};
+use crate::ssz::is_valid_merkle_branch;
+use crate::types::{
+    Header, LightClientHeader, Misbehaviour, SyncCommittee, ETHEREUM_HEADER_TYPE_URL,
+    ETHEREUM_MISBEHAVIOUR_TYPE_URL, EXECUTION_PAYLOAD_GINDEX,
+};
+
+impl<V> ClientStateValidation<V> for ClientState
+where
+    V: ExtClientValidationContext + BlsVerifier,
+    ConsensusState: Convertible<V::ConsensusStateRef>,
+    <ConsensusState as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    fn verify_client_message(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        match client_message.type_url.as_str() {
+            ETHEREUM_HEADER_TYPE_URL => {
+                let header = Header::try_from(client_message)?;
+                self.verify_header(ctx, client_id, &header)
+            }
+            ETHEREUM_MISBEHAVIOUR_TYPE_URL => {
+                let misbehaviour = Misbehaviour::try_from(client_message)?;
+                self.verify_misbehaviour(ctx, client_id, &misbehaviour)
+            }
+            header_type => Err(ClientError::InvalidHeaderType(header_type.to_owned())),
+        }
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        match client_message.type_url.as_str() {
+            ETHEREUM_HEADER_TYPE_URL => {
+                let header = Header::try_from(client_message)?;
+                self.check_for_misbehaviour_on_header(ctx, client_id, &header)
+            }
+            ETHEREUM_MISBEHAVIOUR_TYPE_URL => {
+                let misbehaviour = Misbehaviour::try_from(client_message)?;
+
+                let finalized_1 = &misbehaviour.header_1.consensus_update.finalized_header;
+                let finalized_2 = &misbehaviour.header_2.consensus_update.finalized_header;
+
+                Ok(finalized_1.beacon.hash_tree_root() != finalized_2.beacon.hash_tree_root())
+            }
+            header_type => Err(ClientError::InvalidHeaderType(header_type.to_owned())),
+        }
+    }
+
+    fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError> {
+        if self.is_frozen() {
+            return Ok(Status::Frozen);
+        }
+
+        // if the client state does not have an associated consensus state for
+        // its latest height then it must be expired
+        let Ok(latest_consensus_state) = consensus_state_at(ctx, client_id, self.latest_height)
+        else {
+            return Ok(Status::Expired);
+        };
+
+        consensus_status(
+            &latest_consensus_state,
+            &ctx.host_timestamp()?,
+            self.trusting_period,
+        )
+    }
+
+    /// The subject and substitute client states match if all their chain
+    /// parameters match, regardless of the latest slot and height, frozen
+    /// height and trusting period.
+    fn check_substitute(&self, _ctx: &V, substitute_client_state: Any) -> Result<(), ClientError> {
+        let substitute_client_state = Self::try_from(substitute_client_state)?;
+
+        let normalized = Self {
+            trusting_period: self.trusting_period,
+            latest_slot: self.latest_slot,
+            latest_height: self.latest_height,
+            frozen_height: self.frozen_height,
+            ..substitute_client_state
+        };
+
+        (&normalized == self)
+            .then_some(())
+            .ok_or(ClientError::FailedToVerifyClientRecoveryStates)
+    }
+}
+
+impl ClientState {
+    /// Verifies a header against the trusted consensus state it refers to,
+    /// following the beacon chain light client sync protocol.
+    pub fn verify_header<V>(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        header: &Header,
+    ) -> Result<(), ClientError>
+    where
+        V: ExtClientValidationContext + BlsVerifier,
+        ConsensusState: Convertible<V::ConsensusStateRef>,
+        <ConsensusState as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+    {
+        let trusted_consensus_state = consensus_state_at(ctx, client_id, header.trusted_height)?;
+
+        if consensus_status(
+            &trusted_consensus_state,
+            &ctx.host_timestamp()?,
+            self.trusting_period,
+        )?
+        .is_expired()
+        {
+            return Err(ClientError::InvalidStatus(Status::Expired));
+        }
+
+        let update = &header.consensus_update;
+        let attested = &update.attested_header;
+        let finalized = &update.finalized_header;
+        let trusted_sync_committee = &header.trusted_sync_committee;
+
+        // The signing committee must be one of the committees trusted by the
+        // consensus state the header builds upon.
+        let expected_sync_committee = if trusted_sync_committee.is_next {
+            trusted_consensus_state
+                .next_sync_committee
+                .ok_or(EthereumError::UnknownNextSyncCommittee)?
+        } else {
+            trusted_consensus_state.current_sync_committee
+        };
+
+        if trusted_sync_committee.sync_committee.hash_tree_root() != expected_sync_committee {
+            return Err(EthereumError::MismatchedTrustedSyncCommittee)?;
+        }
+
+        self.check_sync_committee_size(&trusted_sync_committee.sync_committee)?;
+
+        if !(update.signature_slot > attested.beacon.slot
+            && attested.beacon.slot >= finalized.beacon.slot)
+        {
+            return Err(EthereumError::InvalidSlotOrdering {
+                signature_slot: update.signature_slot,
+                attested_slot: attested.beacon.slot,
+                finalized_slot: finalized.beacon.slot,
+            })?;
+        }
+
+        if finalized.beacon.slot <= trusted_consensus_state.slot {
+            return Err(EthereumError::StaleFinalizedSlot {
+                finalized_slot: finalized.beacon.slot,
+                trusted_slot: trusted_consensus_state.slot,
+            })?;
+        }
+
+        let attested_epoch = self.compute_epoch(attested.beacon.slot);
+
+        if !self
+            .fork_parameters
+            .is_deneb_or_later(self.compute_epoch(finalized.beacon.slot))
+        {
+            return Err(EthereumError::UnsupportedFork(finalized.beacon.slot))?;
+        }
+
+        let trusted_period = self.compute_sync_committee_period(trusted_consensus_state.slot);
+        let signature_period = self.compute_sync_committee_period(update.signature_slot);
+
+        let expected_signature_period = if trusted_sync_committee.is_next {
+            trusted_period + 1
+        } else {
+            trusted_period
+        };
+
+        if signature_period != expected_signature_period {
+            return Err(EthereumError::InvalidSignaturePeriod {
+                signature_period,
+                trusted_period,
+            })?;
+        }
+
+        if !is_valid_merkle_branch(
+            &finalized.beacon.hash_tree_root(),
+            &update.finality_branch,
+            self.fork_parameters.finalized_root_gindex(attested_epoch),
+            &attested.beacon.state_root,
+        ) {
+            return Err(EthereumError::InvalidMerkleBranch("finalized header"))?;
+        }
+
+        verify_execution_branch(attested)?;
+        verify_execution_branch(finalized)?;
+
+        if let Some(next_sync_committee) = &update.next_sync_committee {
+            self.check_sync_committee_size(next_sync_committee)?;
+
+            if !is_valid_merkle_branch(
+                &next_sync_committee.hash_tree_root(),
+                &update.next_sync_committee_branch,
+                self.fork_parameters
+                    .next_sync_committee_gindex(attested_epoch),
+                &attested.beacon.state_root,
+            ) {
+                return Err(EthereumError::InvalidMerkleBranch("next sync committee"))?;
+            }
+        }
+
+        self.verify_sync_aggregate(ctx, header)?;
+
+        self.verify_account_update(header)
+    }
+
+    /// Verifies both headers of a misbehaviour, which must finalize the same
+    /// slot.
+    pub fn verify_misbehaviour<V>(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        misbehaviour: &Misbehaviour,
+    ) -> Result<(), ClientError>
+    where
+        V: ExtClientValidationContext + BlsVerifier,
+        ConsensusState: Convertible<V::ConsensusStateRef>,
+        <ConsensusState as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+    {
+        let slot_1 = misbehaviour
+            .header_1
+            .consensus_update
+            .finalized_header
+            .beacon
+            .slot;
+        let slot_2 = misbehaviour
+            .header_2
+            .consensus_update
+            .finalized_header
+            .beacon
+            .slot;
+
+        if slot_1 != slot_2 {
+            return Err(EthereumError::MisbehaviourSlotMismatch)?;
+        }
+
+        self.verify_header(ctx, client_id, &misbehaviour.header_1)?;
+        self.verify_header(ctx, client_id, &misbehaviour.header_2)
+    }
+
+    /// A header is misbehaviour if a consensus state already stored at its
+    /// height commits to a different execution state.
+    fn check_for_misbehaviour_on_header<V>(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        header: &Header,
+    ) -> Result<bool, ClientError>
+    where
+        V: ExtClientValidationContext,
+        ConsensusState: Convertible<V::ConsensusStateRef>,
+        <ConsensusState as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+    {
+        let Ok(existing_consensus_state) = consensus_state_at(ctx, client_id, header.height()?)
+        else {
+            return Ok(false);
+        };
+
+        let trusted_consensus_state = consensus_state_at(ctx, client_id, header.trusted_height)?;
+        let header_consensus_state = self.next_consensus_state(&trusted_consensus_state, header)?;
+
+        Ok(existing_consensus_state.slot != header_consensus_state.slot
+            || existing_consensus_state.state_root != header_consensus_state.state_root
+            || existing_consensus_state.storage_root != header_consensus_state.storage_root)
+    }
+
+    /// Computes the consensus state resulting from applying a verified header
+    /// on top of the trusted consensus state, rotating the sync committees
+    /// when the finalized header enters the next period.
+    pub fn next_consensus_state(
+        &self,
+        trusted_consensus_state: &ConsensusState,
+        header: &Header,
+    ) -> Result<ConsensusState, ClientError> {
+        let update = &header.consensus_update;
+        let finalized = &update.finalized_header;
+
+        let trusted_period = self.compute_sync_committee_period(trusted_consensus_state.slot);
+        let finalized_period = self.compute_sync_committee_period(finalized.beacon.slot);
+        let attested_period =
+            self.compute_sync_committee_period(update.attested_header.beacon.slot);
+
+        // The next sync committee of the update belongs to the attested
+        // period, so it only extends the finalized period if both match.
+        let update_next_sync_committee = update
+            .next_sync_committee
+            .as_ref()
+            .filter(|_| attested_period == finalized_period)
+            .map(SyncCommittee::hash_tree_root);
+
+        let (current_sync_committee, next_sync_committee) = if finalized_period == trusted_period {
+            (
+                trusted_consensus_state.current_sync_committee,
+                update_next_sync_committee.or(trusted_consensus_state.next_sync_committee),
+            )
+        } else if finalized_period == trusted_period + 1 {
+            (
+                trusted_consensus_state
+                    .next_sync_committee
+                    .ok_or(EthereumError::UnknownNextSyncCommittee)?,
+                Some(update_next_sync_committee.ok_or(EthereumError::MissingNextSyncCommittee)?),
+            )
+        } else {
+            return Err(EthereumError::InvalidFinalizedPeriod {
+                finalized_period,
+                trusted_period,
+            })?;
+        };
+
+        let timestamp =
+            Timestamp::from_unix_timestamp(finalized.execution.timestamp, 0).map_err(|e| {
+                ClientError::ClientSpecific {
+                    description: e.to_string(),
+                }
+            })?;
+
+        Ok(ConsensusState {
+            slot: finalized.beacon.slot,
+            state_root: finalized.execution.state_root,
+            storage_root: header.account_update.storage_root.to_vec().into(),
+            timestamp,
+            current_sync_committee,
+            next_sync_committee,
+        })
+    }
+
+    fn check_sync_committee_size(
+        &self,
+        sync_committee: &SyncCommittee,
+    ) -> Result<(), EthereumError> {
+        let actual = sync_committee.pubkeys.len();
+
+        if actual as u64 != self.sync_committee_size {
+            return Err(EthereumError::InvalidLength {
+                name: "sync committee",
+                expected: self.sync_committee_size as usize,
+                actual,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Checks that a supermajority of the trusted sync committee signed the
+    /// attested header.
+    fn verify_sync_aggregate<V: BlsVerifier>(
+        &self,
+        ctx: &V,
+        header: &Header,
+    ) -> Result<(), EthereumError> {
+        let update = &header.consensus_update;
+        let sync_aggregate = &update.sync_aggregate;
+        let sync_committee = &header.trusted_sync_committee.sync_committee;
+
+        let expected_bits_len = (self.sync_committee_size / 8) as usize;
+
+        if sync_aggregate.sync_committee_bits.len() != expected_bits_len {
+            return Err(EthereumError::InvalidLength {
+                name: "sync committee bits",
+                expected: expected_bits_len,
+                actual: sync_aggregate.sync_committee_bits.len(),
+            });
+        }
+
+        let participants = sync_aggregate.num_participants();
+
+        if participants * 3 < self.sync_committee_size * 2 {
+            return Err(EthereumError::InsufficientParticipation {
+                participants,
+                size: self.sync_committee_size,
+            });
+        }
+
+        let pubkeys: Vec<_> = sync_committee
+            .pubkeys
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| sync_aggregate.participated(*i))
+            .map(|(_, pubkey)| *pubkey)
+            .collect();
+
+        let fork_version = self
+            .fork_parameters
+            .fork_version(self.compute_epoch(update.signature_slot.max(1) - 1));
+
+        let domain = compute_domain(
+            DOMAIN_SYNC_COMMITTEE,
+            fork_version,
+            &self.genesis_validators_root,
+        );
+
+        let signing_root =
+            compute_signing_root(&update.attested_header.beacon.hash_tree_root(), &domain);
+
+        ctx.fast_aggregate_verify(
+            &pubkeys,
+            &signing_root,
+            &sync_aggregate.sync_committee_signature,
+        )
+    }
+
+    /// Checks the IBC contract account proof against the finalized execution
+    /// state root, which commits the storage root used for membership proofs.
+    fn verify_account_update(&self, header: &Header) -> Result<(), ClientError> {
+        let execution = &header.consensus_update.finalized_header.execution;
+        let account_update = &header.account_update;
+
+        let account = verify_proof(
+            &execution.state_root,
+            &keccak256(self.ibc_contract_address),
+            &account_update.account_proof,
+        )?
+        .ok_or(EthereumError::MismatchedStorageRoot)?;
+
+        if account_storage_root(&account)? != account_update.storage_root {
+            return Err(EthereumError::MismatchedStorageRoot)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn verify_execution_branch(header: &LightClientHeader) -> Result<(), EthereumError> {
+    if !is_valid_merkle_branch(
+        &header.execution.hash_tree_root(),
+        &header.execution_branch,
+        EXECUTION_PAYLOAD_GINDEX,
+        &header.beacon.body_root,
+    ) {
+        return Err(EthereumError::InvalidMerkleBranch("execution payload"));
+    }
+
+    Ok(())
+}
+
+/// Fetches the consensus state stored for the client at the given height.
+pub fn consensus_state_at<V>(
+    ctx: &V,
+    client_id: &ClientId,
+    height: Height,
+) -> Result<ConsensusState, ClientError>
+where
+    V: ExtClientValidationContext,
+    ConsensusState: Convertible<V::ConsensusStateRef>,
+    <ConsensusState as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    ctx.consensus_state(&ClientConsensusStatePath::new(
+        client_id.clone(),
+        height.revision_number(),
+        height.revision_height(),
+    ))?
+    .try_into()
+    .map_err(Into::into)
+}
@@ -0,0 +1,13 @@
+use ibc_client_cw::api::ClientType;
+
+use crate::client_state::ClientState;
+use crate::consensus_state::ConsensusState;
+
+/// A unit struct that represents the Ethereum client type.
+#[derive(Clone, Debug)]
+pub struct EthereumClient;
+
+impl ClientType<'_> for EthereumClient {
+    type ClientState = ClientState;
+    type ConsensusState = ConsensusState;
+}
@@ -0,0 +1,113 @@
+//! Implements the Ethereum `ConsensusState`. Each consensus state records a
+//! finalized execution block, the storage root of the IBC contract at that
+//! block and the sync committees trusted at its slot.
+use ibc_core::client::context::consensus_state::ConsensusState as ConsensusStateTrait;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::commitment_types::commitment::CommitmentRoot;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::proto::{Any, Protobuf};
+use ibc_core::primitives::Timestamp;
+
+use crate::ssz::{Bytes32, ZERO_CHUNK};
+use crate::types::fixed_bytes;
+use crate::types::proto::ConsensusState as RawConsensusState;
+
+pub const ETHEREUM_CONSENSUS_STATE_TYPE_URL: &str = "/ibc.lightclients.ethereum.v1.ConsensusState";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusState {
+    /// The beacon slot of the finalized header.
+    pub slot: u64,
+    /// The execution state root of the finalized block.
+    pub state_root: Bytes32,
+    /// The storage root of the IBC contract, used as the commitment root.
+    pub storage_root: CommitmentRoot,
+    /// The execution timestamp of the finalized block.
+    pub timestamp: Timestamp,
+    /// The hash tree root of the sync committee of the slot's period.
+    pub current_sync_committee: Bytes32,
+    /// The hash tree root of the sync committee of the following period, if
+    /// already known.
+    pub next_sync_committee: Option<Bytes32>,
+}
+
+impl ConsensusState {
+    /// Returns the storage root of the IBC contract.
+    pub fn storage_root(&self) -> Result<Bytes32, DecodingError> {
+        fixed_bytes("storage root", self.storage_root.as_bytes().to_vec())
+    }
+}
+
+impl ConsensusStateTrait for ConsensusState {
+    fn root(&self) -> &CommitmentRoot {
+        &self.storage_root
+    }
+
+    fn timestamp(&self) -> Result<Timestamp, ClientError> {
+        Ok(self.timestamp)
+    }
+}
+
+impl Protobuf<RawConsensusState> for ConsensusState {}
+
+impl TryFrom<RawConsensusState> for ConsensusState {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawConsensusState) -> Result<Self, Self::Error> {
+        let storage_root: Bytes32 = fixed_bytes("storage root", raw.storage_root)?;
+
+        let next_sync_committee: Bytes32 =
+            fixed_bytes("next sync committee", raw.next_sync_committee)?;
+
+        Ok(Self {
+            slot: raw.slot,
+            state_root: fixed_bytes("state root", raw.state_root)?,
+            storage_root: storage_root.to_vec().into(),
+            timestamp: Timestamp::from_nanoseconds(raw.timestamp),
+            current_sync_committee: fixed_bytes(
+                "current sync committee",
+                raw.current_sync_committee,
+            )?,
+            next_sync_committee: (next_sync_committee != ZERO_CHUNK).then_some(next_sync_committee),
+        })
+    }
+}
+
+impl From<ConsensusState> for RawConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        Self {
+            slot: value.slot,
+            state_root: value.state_root.to_vec(),
+            storage_root: value.storage_root.into_vec(),
+            timestamp: value.timestamp.nanoseconds(),
+            current_sync_committee: value.current_sync_committee.to_vec(),
+            next_sync_committee: value.next_sync_committee.unwrap_or(ZERO_CHUNK).to_vec(),
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let ETHEREUM_CONSENSUS_STATE_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawConsensusState>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: ETHEREUM_CONSENSUS_STATE_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Self {
+            type_url: ETHEREUM_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawConsensusState>::encode_vec(consensus_state),
+        }
+    }
+}
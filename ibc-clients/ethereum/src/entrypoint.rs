@@ -0,0 +1,32 @@
+use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response};
+use ibc_client_cw::context::Context;
+use ibc_client_cw::types::{ContractError, InstantiateMsg, QueryMsg, SudoMsg};
+
+use crate::client_type::EthereumClient;
+
+pub type EthereumContext<'a> = Context<'a, EthereumClient>;
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+pub fn instantiate(
+    deps: DepsMut<'_>,
+    env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let mut ctx = EthereumContext::new_mut(deps, env)?;
+    let data = ctx.instantiate(msg)?;
+    Ok(Response::default().set_data(data))
+}
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+pub fn sudo(deps: DepsMut<'_>, env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+    let mut ctx = EthereumContext::new_mut(deps, env)?;
+    let data = ctx.sudo(msg)?;
+    Ok(Response::default().set_data(data))
+}
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+pub fn query(deps: Deps<'_>, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+    let ctx = EthereumContext::new_ref(deps, env)?;
+    ctx.query(msg)
+}
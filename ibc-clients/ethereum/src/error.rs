@@ -0,0 +1,78 @@
+//! Defines the error type of the Ethereum light client.
+use derive_more::{Display, From};
+use ibc_core::client::types::error::ClientError;
+use ibc_core::host::types::error::DecodingError;
+
+#[derive(From, Display, Debug)]
+pub enum EthereumError {
+    #[from]
+    #[display("decoding error: {_0}")]
+    Decoding(DecodingError),
+    #[display("invalid length for {name}: expected {expected}, actual {actual}")]
+    InvalidLength {
+        name: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+    #[display("sync committee size `{_0}` must be a non-zero power of two")]
+    InvalidSyncCommitteeSize(u64),
+    #[display("unsupported fork at slot `{_0}`; only Deneb and later forks are supported")]
+    UnsupportedFork(u64),
+    #[display("trusted sync committee does not match the trusted consensus state")]
+    MismatchedTrustedSyncCommittee,
+    #[display("next sync committee is unknown for the trusted consensus state")]
+    UnknownNextSyncCommittee,
+    #[display("missing next sync committee for a sync committee period transition")]
+    MissingNextSyncCommittee,
+    #[display(
+        "invalid signature period `{signature_period}` for the trusted period `{trusted_period}`"
+    )]
+    InvalidSignaturePeriod {
+        signature_period: u64,
+        trusted_period: u64,
+    },
+    #[display(
+        "invalid finalized period `{finalized_period}` for the trusted period `{trusted_period}`"
+    )]
+    InvalidFinalizedPeriod {
+        finalized_period: u64,
+        trusted_period: u64,
+    },
+    #[display("invalid slot ordering: signature `{signature_slot}` > attested `{attested_slot}` >= finalized `{finalized_slot}` is required")]
+    InvalidSlotOrdering {
+        signature_slot: u64,
+        attested_slot: u64,
+        finalized_slot: u64,
+    },
+    #[display(
+        "finalized slot `{finalized_slot}` is not newer than the trusted slot `{trusted_slot}`"
+    )]
+    StaleFinalizedSlot {
+        finalized_slot: u64,
+        trusted_slot: u64,
+    },
+    #[display("invalid merkle branch for {_0}")]
+    InvalidMerkleBranch(&'static str),
+    #[display("insufficient sync committee participation: {participants} of {size}")]
+    InsufficientParticipation { participants: u64, size: u64 },
+    #[display("invalid BLS signature: {description}")]
+    InvalidSignature { description: String },
+    #[display("invalid RLP encoding: {description}")]
+    InvalidRlp { description: String },
+    #[display("invalid Merkle-Patricia proof: {description}")]
+    InvalidMptProof { description: String },
+    #[display("account proof does not match the expected storage root")]
+    MismatchedStorageRoot,
+    #[display("storage proof value does not match the expected commitment")]
+    MismatchedStorageValue,
+    #[display("misbehaviour headers must finalize the same slot")]
+    MisbehaviourSlotMismatch,
+}
+
+impl From<EthereumError> for ClientError {
+    fn from(e: EthereumError) -> Self {
+        Self::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! The CosmWasm contract implementation of an Ethereum light client. It tracks
+//! the beacon chain through sync committee signed finality updates, as
+//! specified by the Altair light client sync protocol, and verifies IBC
+//! commitments stored by an IBC contract on the execution layer through
+//! Merkle-Patricia storage proofs.
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+
+pub mod bls;
+pub mod client_state;
+pub mod client_type;
+pub mod consensus_state;
+pub mod entrypoint;
+pub mod error;
+pub mod mpt;
+pub mod ssz;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
+pub mod types;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,275 @@
+//! Minimal RLP decoding and Merkle-Patricia trie proof verification, used to
+//! verify Ethereum account and storage proofs against execution state roots.
+use sha3::{Digest, Keccak256};
+
+use crate::error::EthereumError;
+use crate::ssz::Bytes32;
+
+/// The root of an empty Merkle-Patricia trie, i.e. `keccak256(rlp(""))`.
+pub const EMPTY_TRIE_ROOT: Bytes32 = [
+    0x56, 0xe8, 0x1f, 0x17, 0x1b, 0xcc, 0x55, 0xa6, 0xff, 0x83, 0x45, 0xe6, 0x92, 0xc0, 0xf8, 0x6e,
+    0x5b, 0x48, 0xe0, 0x1b, 0x99, 0x6c, 0xad, 0xc0, 0x01, 0x62, 0x2f, 0xb5, 0xe3, 0x63, 0xb4, 0x21,
+];
+
+/// Returns the Keccak-256 digest of the given bytes.
+pub fn keccak256(data: impl AsRef<[u8]>) -> Bytes32 {
+    Keccak256::digest(data.as_ref()).into()
+}
+
+/// A decoded RLP item that keeps a reference to its raw encoding.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RlpItem<'a> {
+    pub raw: &'a [u8],
+    pub kind: RlpKind<'a>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RlpKind<'a> {
+    Bytes(&'a [u8]),
+    List(Vec<RlpItem<'a>>),
+}
+
+impl<'a> RlpItem<'a> {
+    /// Decodes a single RLP item spanning the whole input.
+    pub fn decode(data: &'a [u8]) -> Result<Self, EthereumError> {
+        let (item, rest) = Self::decode_prefix(data)?;
+
+        if !rest.is_empty() {
+            return Err(rlp_error("trailing bytes after RLP item"));
+        }
+
+        Ok(item)
+    }
+
+    pub fn as_bytes(&self) -> Result<&'a [u8], EthereumError> {
+        match self.kind {
+            RlpKind::Bytes(bytes) => Ok(bytes),
+            RlpKind::List(_) => Err(rlp_error("expected bytes, found list")),
+        }
+    }
+
+    pub fn as_list(&self) -> Result<&[Self], EthereumError> {
+        match &self.kind {
+            RlpKind::List(items) => Ok(items),
+            RlpKind::Bytes(_) => Err(rlp_error("expected list, found bytes")),
+        }
+    }
+
+    fn decode_prefix(data: &'a [u8]) -> Result<(Self, &'a [u8]), EthereumError> {
+        let prefix = *data.first().ok_or(rlp_error("unexpected end of input"))?;
+
+        let (is_list, offset, length) = match prefix {
+            0x00..=0x7f => (false, 0, 1),
+            0x80..=0xb7 => (false, 1, usize::from(prefix - 0x80)),
+            0xb8..=0xbf => {
+                let len_of_len = usize::from(prefix - 0xb7);
+                (false, 1 + len_of_len, read_length(data, len_of_len)?)
+            }
+            0xc0..=0xf7 => (true, 1, usize::from(prefix - 0xc0)),
+            0xf8..=0xff => {
+                let len_of_len = usize::from(prefix - 0xf7);
+                (true, 1 + len_of_len, read_length(data, len_of_len)?)
+            }
+        };
+
+        let end = offset
+            .checked_add(length)
+            .filter(|end| *end <= data.len())
+            .ok_or(rlp_error("item length exceeds input"))?;
+
+        let payload = &data[offset..end];
+
+        let kind = if is_list {
+            let mut items = Vec::new();
+            let mut rest = payload;
+            while !rest.is_empty() {
+                let (item, remaining) = Self::decode_prefix(rest)?;
+                items.push(item);
+                rest = remaining;
+            }
+            RlpKind::List(items)
+        } else {
+            if prefix == 0x81 && payload[0] < 0x80 {
+                return Err(rlp_error("non-canonical single byte encoding"));
+            }
+            RlpKind::Bytes(payload)
+        };
+
+        Ok((
+            Self {
+                raw: &data[..end],
+                kind,
+            },
+            &data[end..],
+        ))
+    }
+}
+
+fn read_length(data: &[u8], len_of_len: usize) -> Result<usize, EthereumError> {
+    let bytes = data
+        .get(1..1 + len_of_len)
+        .ok_or(rlp_error("unexpected end of input"))?;
+
+    if bytes[0] == 0 || len_of_len > size_of::<usize>() {
+        return Err(rlp_error("non-canonical length encoding"));
+    }
+
+    Ok(bytes.iter().fold(0, |acc, b| (acc << 8) | usize::from(*b)))
+}
+
+fn rlp_error(description: &str) -> EthereumError {
+    EthereumError::InvalidRlp {
+        description: description.to_string(),
+    }
+}
+
+fn mpt_error(description: &str) -> EthereumError {
+    EthereumError::InvalidMptProof {
+        description: description.to_string(),
+    }
+}
+
+/// Splits the given bytes into nibbles.
+fn to_nibbles(bytes: &[u8]) -> Vec<u8> {
+    bytes.iter().flat_map(|b| [b >> 4, b & 0x0f]).collect()
+}
+
+/// Decodes a hex-prefix encoded path, returning its nibbles and whether the
+/// node is a leaf.
+fn decode_hex_prefix(encoded: &[u8]) -> Result<(Vec<u8>, bool), EthereumError> {
+    let first = *encoded.first().ok_or(mpt_error("empty node path"))?;
+
+    let flag = first >> 4;
+
+    if flag > 3 {
+        return Err(mpt_error("invalid hex-prefix flag"));
+    }
+
+    let is_leaf = flag >= 2;
+    let is_odd = flag % 2 == 1;
+
+    let mut nibbles = Vec::with_capacity(encoded.len() * 2);
+    if is_odd {
+        nibbles.push(first & 0x0f);
+    }
+    nibbles.extend(to_nibbles(&encoded[1..]));
+
+    Ok((nibbles, is_leaf))
+}
+
+enum NodeRef<'a> {
+    Hash(Bytes32),
+    Inline(&'a [u8]),
+}
+
+impl<'a> NodeRef<'a> {
+    fn from_child(child: &RlpItem<'a>) -> Result<Option<Self>, EthereumError> {
+        match child.kind {
+            RlpKind::Bytes([]) => Ok(None),
+            RlpKind::Bytes(hash) => {
+                Ok(Some(Self::Hash(hash.try_into().map_err(|_| {
+                    mpt_error("invalid child node hash length")
+                })?)))
+            }
+            RlpKind::List(_) => Ok(Some(Self::Inline(child.raw))),
+        }
+    }
+}
+
+/// Verifies a Merkle-Patricia proof for `key` against `root`. Returns the
+/// value stored at the key, or `None` if the proof shows the key is absent.
+pub fn verify_proof(
+    root: &Bytes32,
+    key: &[u8],
+    proof: &[Vec<u8>],
+) -> Result<Option<Vec<u8>>, EthereumError> {
+    if root == &EMPTY_TRIE_ROOT && proof.is_empty() {
+        return Ok(None);
+    }
+
+    let nibbles = to_nibbles(key);
+    let mut position = 0;
+    let mut proof_nodes = proof.iter();
+    let mut next = NodeRef::Hash(*root);
+
+    let value = loop {
+        let encoded = match next {
+            NodeRef::Hash(hash) => {
+                let node = proof_nodes.next().ok_or(mpt_error("missing proof node"))?;
+
+                if keccak256(node) != hash {
+                    return Err(mpt_error("proof node does not match its hash"));
+                }
+
+                node.as_slice()
+            }
+            NodeRef::Inline(node) => node,
+        };
+
+        let node = RlpItem::decode(encoded)?;
+        let items = node.as_list()?;
+
+        let child = match items.len() {
+            17 => {
+                let Some(nibble) = nibbles.get(position) else {
+                    let value = items[16].as_bytes()?;
+                    break (!value.is_empty()).then(|| value.to_vec());
+                };
+                position += 1;
+
+                &items[usize::from(*nibble)]
+            }
+            2 => {
+                let (path, is_leaf) = decode_hex_prefix(items[0].as_bytes()?)?;
+                let remaining = &nibbles[position..];
+
+                if is_leaf {
+                    break (remaining == path.as_slice())
+                        .then(|| items[1].as_bytes().map(<[u8]>::to_vec))
+                        .transpose()?;
+                }
+
+                if !remaining.starts_with(&path) {
+                    break None;
+                }
+                position += path.len();
+
+                &items[1]
+            }
+            _ => return Err(mpt_error("invalid node arity")),
+        };
+
+        match NodeRef::from_child(child)? {
+            Some(node_ref) => next = node_ref,
+            None => break None,
+        }
+    };
+
+    if proof_nodes.next().is_some() {
+        return Err(mpt_error("unused proof nodes"));
+    }
+
+    Ok(value)
+}
+
+/// Strips leading zero bytes, as done for RLP-encoded integers.
+pub fn trim_leading_zeros(bytes: &[u8]) -> &[u8] {
+    let start = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+    &bytes[start..]
+}
+
+/// Extracts the storage root from an RLP-encoded account
+/// `[nonce, balance, storage_root, code_hash]`.
+pub fn account_storage_root(account_rlp: &[u8]) -> Result<Bytes32, EthereumError> {
+    let account = RlpItem::decode(account_rlp)?;
+    let fields = account.as_list()?;
+
+    if fields.len() != 4 {
+        return Err(rlp_error("account must have four fields"));
+    }
+
+    fields[2]
+        .as_bytes()?
+        .try_into()
+        .map_err(|_| rlp_error("invalid storage root length"))
+}
@@ -0,0 +1,150 @@
+//! Minimal SSZ merkleization helpers needed to compute the hash tree roots of
+//! the beacon chain containers tracked by the light client and to verify
+//! Merkle branches against them.
+use sha2::{Digest, Sha256};
+
+pub type Bytes32 = [u8; 32];
+
+pub const ZERO_CHUNK: Bytes32 = [0; 32];
+
+/// Returns the SHA-256 digest of the given bytes.
+pub fn sha256(data: impl AsRef<[u8]>) -> Bytes32 {
+    Sha256::digest(data.as_ref()).into()
+}
+
+/// Hashes two chunks together.
+pub fn hash_pair(left: &Bytes32, right: &Bytes32) -> Bytes32 {
+    let mut hasher = Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Packs a `uint64` into a little-endian SSZ chunk.
+pub fn u64_chunk(value: u64) -> Bytes32 {
+    let mut chunk = ZERO_CHUNK;
+    chunk[..8].copy_from_slice(&value.to_le_bytes());
+    chunk
+}
+
+/// Packs the given bytes into right-padded 32-byte chunks.
+pub fn pack_bytes(bytes: &[u8]) -> Vec<Bytes32> {
+    bytes
+        .chunks(32)
+        .map(|c| {
+            let mut chunk = ZERO_CHUNK;
+            chunk[..c.len()].copy_from_slice(c);
+            chunk
+        })
+        .collect()
+}
+
+/// Merkleizes the given chunks, padding them with zero chunks up to the next
+/// power of two of `limit`, or of the number of chunks if no limit is given.
+pub fn merkleize(chunks: &[Bytes32], limit: Option<usize>) -> Bytes32 {
+    let width = limit.unwrap_or(chunks.len()).max(1).next_power_of_two();
+
+    debug_assert!(chunks.len() <= width);
+
+    let mut layer = chunks.to_vec();
+    let mut zero_hash = ZERO_CHUNK;
+    let mut size = width;
+
+    while size > 1 {
+        if layer.len() % 2 == 1 {
+            layer.push(zero_hash);
+        }
+
+        layer = layer
+            .chunks(2)
+            .map(|pair| hash_pair(&pair[0], &pair[1]))
+            .collect();
+
+        zero_hash = hash_pair(&zero_hash, &zero_hash);
+        size /= 2;
+    }
+
+    layer.first().copied().unwrap_or(zero_hash)
+}
+
+/// Mixes the length of a list into its root.
+pub fn mix_in_length(root: &Bytes32, length: usize) -> Bytes32 {
+    hash_pair(root, &u64_chunk(length as u64))
+}
+
+/// Returns the hash tree root of a fixed-size byte vector.
+pub fn bytes_vector_root(bytes: &[u8]) -> Bytes32 {
+    merkleize(&pack_bytes(bytes), None)
+}
+
+/// Returns the hash tree root of a byte list bounded by `max_length` bytes.
+pub fn bytes_list_root(bytes: &[u8], max_length: usize) -> Bytes32 {
+    let limit = max_length.div_ceil(32);
+
+    mix_in_length(&merkleize(&pack_bytes(bytes), Some(limit)), bytes.len())
+}
+
+/// Computes the root of a Merkle branch for the leaf at the given index of a
+/// subtree of the given depth.
+pub fn branch_root(leaf: &Bytes32, branch: &[Bytes32], index: u64) -> Bytes32 {
+    branch.iter().enumerate().fold(*leaf, |node, (i, sibling)| {
+        if (index >> i) & 1 == 1 {
+            hash_pair(sibling, &node)
+        } else {
+            hash_pair(&node, sibling)
+        }
+    })
+}
+
+/// Checks that `leaf` is included at the given generalized index in the tree
+/// rooted at `root`.
+pub fn is_valid_merkle_branch(
+    leaf: &Bytes32,
+    branch: &[Bytes32],
+    gindex: u64,
+    root: &Bytes32,
+) -> bool {
+    let depth = gindex.ilog2() as usize;
+
+    if branch.len() != depth {
+        return false;
+    }
+
+    let index = gindex - (1 << depth);
+
+    &branch_root(leaf, branch, index) == root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merkleize_pads_to_the_next_power_of_two() {
+        let a = [1; 32];
+        let b = [2; 32];
+        let c = [3; 32];
+
+        let expected = hash_pair(&hash_pair(&a, &b), &hash_pair(&c, &ZERO_CHUNK));
+
+        assert_eq!(merkleize(&[a, b, c], None), expected);
+        assert_eq!(merkleize(&[a], None), a);
+        assert_eq!(merkleize(&[], Some(2)), hash_pair(&ZERO_CHUNK, &ZERO_CHUNK));
+    }
+
+    #[test]
+    fn merkle_branch_roundtrip() {
+        let leaves: Vec<Bytes32> = (0u8..8).map(|i| [i; 32]).collect();
+        let root = merkleize(&leaves, None);
+
+        let l01 = hash_pair(&leaves[0], &leaves[1]);
+        let l45 = hash_pair(&leaves[4], &leaves[5]);
+        let l67 = hash_pair(&leaves[6], &leaves[7]);
+        let branch = [leaves[3], l01, hash_pair(&l45, &l67)];
+
+        // leaf index 2 at depth 3 has the generalized index 8 + 2
+        assert!(is_valid_merkle_branch(&leaves[2], &branch, 10, &root));
+        assert!(!is_valid_merkle_branch(&leaves[2], &branch, 11, &root));
+        assert!(!is_valid_merkle_branch(&leaves[2], &branch[..2], 10, &root));
+    }
+}
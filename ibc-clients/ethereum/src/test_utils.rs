@@ -0,0 +1,325 @@
+//! Builders of the Ethereum data structures signed and proven by the
+//! counterparty chain, for the tests of this client and of the clients
+//! embedding it: RLP encoding, secure Merkle-Patricia tries, sparse SSZ
+//! Merkle trees and sync committees with known secret keys.
+
+use std::collections::BTreeMap;
+
+use ark_bls12_381::{Fr, G1Affine, G1Projective, G2Affine};
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use cosmwasm_std::testing::MockApi;
+use cosmwasm_std::{Api, HashFunction};
+
+use crate::bls::BLS_DST;
+use crate::mpt::keccak256;
+use crate::ssz::{hash_pair, Bytes32, ZERO_CHUNK};
+use crate::types::{Bytes48, Bytes96, SyncCommittee};
+
+// ------------------- RLP -------------------
+
+fn rlp_length_prefix(len: usize, offset: u8) -> Vec<u8> {
+    if len < 56 {
+        return vec![offset + len as u8];
+    }
+
+    let len_bytes: Vec<u8> = len
+        .to_be_bytes()
+        .into_iter()
+        .skip_while(|b| *b == 0)
+        .collect();
+
+    let mut prefix = vec![offset + 55 + len_bytes.len() as u8];
+    prefix.extend(len_bytes);
+    prefix
+}
+
+pub fn rlp_bytes(bytes: &[u8]) -> Vec<u8> {
+    if bytes.len() == 1 && bytes[0] < 0x80 {
+        return bytes.to_vec();
+    }
+
+    let mut encoded = rlp_length_prefix(bytes.len(), 0x80);
+    encoded.extend_from_slice(bytes);
+    encoded
+}
+
+pub fn rlp_list(items: &[Vec<u8>]) -> Vec<u8> {
+    let payload = items.concat();
+
+    let mut encoded = rlp_length_prefix(payload.len(), 0xc0);
+    encoded.extend(payload);
+    encoded
+}
+
+// ------------------- Merkle-Patricia trie -------------------
+
+enum TrieNode {
+    Leaf(Vec<u8>, Vec<u8>),
+    Extension(Vec<u8>, Box<Self>),
+    Branch(Vec<Option<Self>>),
+}
+
+fn to_nibbles(key: &[u8]) -> Vec<u8> {
+    key.iter().flat_map(|b| [b >> 4, b & 0x0f]).collect()
+}
+
+fn hex_prefix(nibbles: &[u8], is_leaf: bool) -> Vec<u8> {
+    let flag = if is_leaf { 2 } else { 0 };
+
+    let mut prefixed = if nibbles.len() % 2 == 1 {
+        vec![flag + 1]
+    } else {
+        vec![flag, 0]
+    };
+    prefixed.extend_from_slice(nibbles);
+
+    prefixed.chunks(2).map(|c| (c[0] << 4) | c[1]).collect()
+}
+
+impl TrieNode {
+    /// Builds a trie out of entries whose keys all have the same length.
+    fn build(entries: &[(Vec<u8>, Vec<u8>)]) -> Self {
+        if let [(path, value)] = entries {
+            return Self::Leaf(path.clone(), value.clone());
+        }
+
+        let first = &entries[0].0;
+        let common = (0..first.len())
+            .take_while(|i| entries.iter().all(|(path, _)| path[*i] == first[*i]))
+            .count();
+
+        if common > 0 {
+            let stripped: Vec<_> = entries
+                .iter()
+                .map(|(path, value)| (path[common..].to_vec(), value.clone()))
+                .collect();
+
+            return Self::Extension(first[..common].to_vec(), Box::new(Self::build(&stripped)));
+        }
+
+        let children = (0..16u8)
+            .map(|nibble| {
+                let group: Vec<_> = entries
+                    .iter()
+                    .filter(|(path, _)| path[0] == nibble)
+                    .map(|(path, value)| (path[1..].to_vec(), value.clone()))
+                    .collect();
+
+                (!group.is_empty()).then(|| Self::build(&group))
+            })
+            .collect();
+
+        Self::Branch(children)
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        match self {
+            Self::Leaf(path, value) => {
+                rlp_list(&[rlp_bytes(&hex_prefix(path, true)), rlp_bytes(value)])
+            }
+            Self::Extension(path, child) => {
+                rlp_list(&[rlp_bytes(&hex_prefix(path, false)), child.reference()])
+            }
+            Self::Branch(children) => {
+                let mut items: Vec<_> = children
+                    .iter()
+                    .map(|child| child.as_ref().map_or(rlp_bytes(&[]), Self::reference))
+                    .collect();
+                items.push(rlp_bytes(&[]));
+
+                rlp_list(&items)
+            }
+        }
+    }
+
+    fn reference(&self) -> Vec<u8> {
+        let encoded = self.encode();
+
+        if encoded.len() < 32 {
+            encoded
+        } else {
+            rlp_bytes(&keccak256(encoded))
+        }
+    }
+
+    /// Collects the hashed nodes visited while looking up `path`.
+    fn prove(&self, path: &[u8], is_root: bool, proof: &mut Vec<Vec<u8>>) {
+        let encoded = self.encode();
+
+        if is_root || encoded.len() >= 32 {
+            proof.push(encoded);
+        }
+
+        match self {
+            Self::Leaf(..) => {}
+            Self::Extension(prefix, child) => {
+                if path.starts_with(prefix) {
+                    child.prove(&path[prefix.len()..], false, proof);
+                }
+            }
+            Self::Branch(children) => {
+                if let Some(child) = &children[usize::from(path[0])] {
+                    child.prove(&path[1..], false, proof);
+                }
+            }
+        }
+    }
+}
+
+/// An in-memory secure Merkle-Patricia trie, i.e. a trie keyed by the
+/// keccak256 hash of the keys, as used for Ethereum accounts and storage.
+#[derive(Clone, Debug, Default)]
+pub struct SecureTrie {
+    entries: BTreeMap<Bytes32, Vec<u8>>,
+}
+
+impl SecureTrie {
+    pub fn insert(&mut self, key: &[u8], value: Vec<u8>) {
+        self.entries.insert(keccak256(key), value);
+    }
+
+    fn node(&self) -> Option<TrieNode> {
+        let entries: Vec<_> = self
+            .entries
+            .iter()
+            .map(|(key, value)| (to_nibbles(key), value.clone()))
+            .collect();
+
+        (!entries.is_empty()).then(|| TrieNode::build(&entries))
+    }
+
+    pub fn root(&self) -> Bytes32 {
+        self.node()
+            .map_or(crate::mpt::EMPTY_TRIE_ROOT, |node| keccak256(node.encode()))
+    }
+
+    pub fn proof(&self, key: &[u8]) -> Vec<Vec<u8>> {
+        let mut proof = vec![];
+
+        if let Some(node) = self.node() {
+            node.prove(&to_nibbles(&keccak256(key)), true, &mut proof);
+        }
+
+        proof
+    }
+}
+
+// ------------------- SSZ -------------------
+
+/// A sparse SSZ Merkle tree of the given depth, holding a few known leaves
+/// at their generalized indices and zero chunks elsewhere.
+pub struct SparseTree {
+    depth: u32,
+    leaves: BTreeMap<u64, Bytes32>,
+}
+
+impl SparseTree {
+    pub fn new(depth: u32) -> Self {
+        Self {
+            depth,
+            leaves: BTreeMap::new(),
+        }
+    }
+
+    pub fn with_leaf(mut self, gindex: u64, leaf: Bytes32) -> Self {
+        self.leaves.insert(gindex, leaf);
+        self
+    }
+
+    fn node(&self, gindex: u64) -> Bytes32 {
+        if let Some(leaf) = self.leaves.get(&gindex) {
+            return *leaf;
+        }
+
+        if gindex.ilog2() >= self.depth {
+            return ZERO_CHUNK;
+        }
+
+        hash_pair(&self.node(2 * gindex), &self.node(2 * gindex + 1))
+    }
+
+    pub fn root(&self) -> Bytes32 {
+        self.node(1)
+    }
+
+    pub fn branch(&self, gindex: u64) -> Vec<Bytes32> {
+        let mut branch = vec![];
+        let mut index = gindex;
+
+        while index > 1 {
+            branch.push(self.node(index ^ 1));
+            index >>= 1;
+        }
+
+        branch
+    }
+}
+
+// ------------------- BLS -------------------
+
+fn serialize<T: CanonicalSerialize>(point: &T) -> Vec<u8> {
+    let mut bytes = vec![];
+    point.serialize_compressed(&mut bytes).expect("never fails");
+    bytes
+}
+
+/// A sync committee whose secret keys are known, so that it can sign
+/// attested headers.
+#[derive(Clone, Debug)]
+pub struct TestSyncCommittee {
+    secret_keys: Vec<Fr>,
+}
+
+impl TestSyncCommittee {
+    /// Derives a deterministic committee of the given size from a seed.
+    pub fn new(seed: u64, size: u64) -> Self {
+        Self {
+            secret_keys: (0..size).map(|i| Fr::from(seed * 10_000 + i + 1)).collect(),
+        }
+    }
+
+    fn pubkey(secret_key: &Fr) -> G1Affine {
+        (G1Affine::generator() * secret_key).into_affine()
+    }
+
+    pub fn sync_committee(&self) -> SyncCommittee {
+        let pubkeys: Vec<Bytes48> = self
+            .secret_keys
+            .iter()
+            .map(|sk| serialize(&Self::pubkey(sk)).try_into().expect("48 bytes"))
+            .collect();
+
+        let aggregate_pubkey: G1Projective = self.secret_keys.iter().map(Self::pubkey).sum();
+
+        SyncCommittee {
+            pubkeys,
+            aggregate_pubkey: serialize(&aggregate_pubkey.into_affine())
+                .try_into()
+                .expect("48 bytes"),
+        }
+    }
+
+    /// Returns the aggregate signature over `message` of the members whose
+    /// participation bit is set.
+    pub fn sign(&self, message: &Bytes32, participation_bits: &[u8]) -> Bytes96 {
+        let message_point = MockApi::default()
+            .bls12_381_hash_to_g2(HashFunction::Sha256, message, BLS_DST)
+            .expect("never fails");
+
+        let message_point =
+            G2Affine::deserialize_compressed(&message_point[..]).expect("valid G2 point");
+
+        let aggregate_secret_key: Fr = self
+            .secret_keys
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| (participation_bits[i / 8] >> (i % 8)) & 1 == 1)
+            .map(|(_, sk)| *sk)
+            .sum();
+
+        serialize(&(message_point * aggregate_secret_key).into_affine())
+            .try_into()
+            .expect("96 bytes")
+    }
+}
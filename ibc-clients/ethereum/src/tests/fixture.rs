@@ -0,0 +1,492 @@
+use std::time::Duration;
+
+use cosmwasm_std::Binary;
+use ibc_client_cw::types::{MerklePath, VerifyMembershipMsgRaw, VerifyNonMembershipMsgRaw};
+use ibc_client_cw::utils::AnyCodec;
+use ibc_client_cw_testkit::api::TestClient;
+use ibc_client_cw_testkit::clock::Clock;
+use ibc_core::client::types::Height;
+use ibc_core::primitives::proto::Protobuf;
+use ibc_core::primitives::Timestamp;
+
+use crate::bls::{compute_domain, compute_signing_root, DOMAIN_SYNC_COMMITTEE};
+use crate::client_state::ClientState;
+use crate::client_type::EthereumClient;
+use crate::consensus_state::ConsensusState;
+use crate::mpt::{keccak256, trim_leading_zeros};
+use crate::ssz::{sha256, u64_chunk, Bytes32};
+use crate::test_utils::{rlp_bytes, rlp_list, SecureTrie, SparseTree, TestSyncCommittee};
+use crate::tests::recorded::{Bootstrap, Recording};
+use crate::types::proto::StorageProof as RawStorageProof;
+use crate::types::{
+    AccountUpdate, BeaconBlockHeader, Bytes20, ExecutionPayloadHeader, Fork, ForkParameters,
+    Header, LightClientHeader, LightClientUpdate, Misbehaviour, StorageProof, SyncAggregate,
+    TrustedSyncCommittee, BYTES_PER_LOGS_BLOOM, CURRENT_SYNC_COMMITTEE_GINDEX_DENEB,
+    EXECUTION_PAYLOAD_GINDEX, FINALIZED_ROOT_GINDEX_DENEB, NEXT_SYNC_COMMITTEE_GINDEX_DENEB,
+};
+
+/// A minimal preset, so that sync committee periods span only 64 slots.
+pub const SYNC_COMMITTEE_SIZE: u64 = 32;
+pub const SLOTS_PER_EPOCH: u64 = 8;
+pub const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: u64 = 8;
+pub const SLOTS_PER_PERIOD: u64 = SLOTS_PER_EPOCH * EPOCHS_PER_SYNC_COMMITTEE_PERIOD;
+pub const SECONDS_PER_SLOT: u64 = 12;
+
+/// The offset between beacon slots and execution block numbers of the
+/// synthetic chain.
+const BLOCK_NUMBER_OFFSET: u64 = 1000;
+
+/// Test fixture of the Ethereum client, whose heights are the execution
+/// block numbers of the finalized slots.
+pub type Fixture = ibc_client_cw_testkit::fixture::Fixture<EthereumClient>;
+
+/// A beacon chain with deterministic sync committees and an IBC contract on
+/// its execution layer. All data is synthetic but follows the SSZ,
+/// Merkle-Patricia and BLS encodings of the real chain.
+#[derive(Clone, Debug)]
+pub struct EthereumConfig {
+    pub genesis_time: u64,
+    pub trusting_period: Duration,
+    pub ibc_contract_address: Bytes20,
+    pub ibc_commitment_slot: Bytes32,
+    /// The (path, value) commitments stored by the IBC contract.
+    pub commitments: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+impl Default for EthereumConfig {
+    fn default() -> Self {
+        let now = Clock::default().now().nanoseconds() / 1_000_000_000;
+
+        Self {
+            genesis_time: now - 3600,
+            trusting_period: Duration::from_secs(64000),
+            ibc_contract_address: [0xbc; 20],
+            ibc_commitment_slot: [0; 32],
+            commitments: vec![
+                (
+                    b"commitments/ports/transfer/channels/channel-0/sequences/1".to_vec(),
+                    b"packet-commitment-1".to_vec(),
+                ),
+                (
+                    b"commitments/ports/transfer/channels/channel-0/sequences/2".to_vec(),
+                    b"packet-commitment-2".to_vec(),
+                ),
+                (
+                    b"connections/connection-0".to_vec(),
+                    b"connection-end".to_vec(),
+                ),
+            ],
+        }
+    }
+}
+
+impl EthereumConfig {
+    pub fn storage_trie(&self) -> SecureTrie {
+        let mut trie = SecureTrie::default();
+
+        for (path, value) in &self.commitments {
+            trie.insert(
+                &self.storage_slot(path),
+                rlp_bytes(trim_leading_zeros(&keccak256(value))),
+            );
+        }
+
+        trie
+    }
+
+    /// Returns the storage slot of `commitments[keccak256(path)]`.
+    pub fn storage_slot(&self, path: &[u8]) -> Bytes32 {
+        keccak256([keccak256(path), self.ibc_commitment_slot].concat())
+    }
+
+    /// Returns the account trie at the given slot, holding the IBC contract
+    /// and a few other accounts whose nonces change every slot.
+    pub fn account_trie(&self, slot: u64) -> SecureTrie {
+        let account = |nonce: u64, storage_root: Bytes32| {
+            rlp_list(&[
+                rlp_bytes(trim_leading_zeros(&nonce.to_be_bytes())),
+                rlp_bytes(&[]),
+                rlp_bytes(&storage_root),
+                rlp_bytes(&keccak256([])),
+            ])
+        };
+
+        let mut trie = SecureTrie::default();
+
+        trie.insert(
+            &self.ibc_contract_address,
+            account(1, self.storage_trie().root()),
+        );
+
+        for i in 0..8u8 {
+            trie.insert(&[i; 20], account(slot, crate::mpt::EMPTY_TRIE_ROOT));
+        }
+
+        trie
+    }
+
+    pub fn execution_payload(&self, slot: u64) -> ExecutionPayloadHeader {
+        ExecutionPayloadHeader {
+            parent_hash: sha256(u64_chunk(slot - 1)),
+            fee_recipient: [0xfe; 20],
+            state_root: self.account_trie(slot).root(),
+            receipts_root: [0x01; 32],
+            logs_bloom: vec![0; BYTES_PER_LOGS_BLOOM],
+            prev_randao: [0x02; 32],
+            block_number: EthereumClient::height(slot).revision_height(),
+            gas_limit: 30_000_000,
+            gas_used: 21_000,
+            timestamp: self.genesis_time + slot * SECONDS_PER_SLOT,
+            extra_data: b"synthetic".to_vec(),
+            base_fee_per_gas: u64_chunk(7),
+            block_hash: sha256(u64_chunk(slot)),
+            transactions_root: [0x03; 32],
+            withdrawals_root: [0x04; 32],
+            blob_gas_used: 0,
+            excess_blob_gas: 0,
+        }
+    }
+
+    pub fn light_client_header(
+        &self,
+        slot: u64,
+        state: &SparseTree,
+        proposer_index: u64,
+    ) -> LightClientHeader {
+        let execution = self.execution_payload(slot);
+
+        let body = SparseTree::new(4)
+            .with_leaf(EXECUTION_PAYLOAD_GINDEX, execution.hash_tree_root())
+            .with_leaf(16, sha256(u64_chunk(slot)));
+
+        LightClientHeader {
+            beacon: BeaconBlockHeader {
+                slot,
+                proposer_index,
+                parent_root: sha256(u64_chunk(slot - 1)),
+                state_root: state.root(),
+                body_root: body.root(),
+            },
+            execution,
+            execution_branch: body.branch(EXECUTION_PAYLOAD_GINDEX),
+        }
+    }
+
+    /// Constructs a header finalizing `finalized_slot`, signed by all members
+    /// of the sync committee of the signature slot.
+    pub fn dummy_header(&self, trusted_slot: u64, finalized_slot: u64) -> Header {
+        self.dummy_header_with(trusted_slot, finalized_slot, SYNC_COMMITTEE_SIZE, 0)
+    }
+
+    /// Constructs a header finalizing `finalized_slot`, attested two epochs
+    /// later and signed by the first `participants` members of the sync
+    /// committee of the signature slot.
+    pub fn dummy_header_with(
+        &self,
+        trusted_slot: u64,
+        finalized_slot: u64,
+        participants: u64,
+        proposer_index: u64,
+    ) -> Header {
+        let attested_slot = finalized_slot + 2 * SLOTS_PER_EPOCH;
+        let signature_slot = attested_slot + 1;
+
+        let trusted_period = EthereumClient::period(trusted_slot);
+        let signature_period = EthereumClient::period(signature_slot);
+        let signing_committee = EthereumClient::sync_committee(signature_period);
+
+        let finalized_state = SparseTree::new(6).with_leaf(34, u64_chunk(finalized_slot));
+        let finalized_header =
+            self.light_client_header(finalized_slot, &finalized_state, proposer_index);
+
+        let next_sync_committee =
+            EthereumClient::sync_committee(EthereumClient::period(attested_slot) + 1)
+                .sync_committee();
+
+        let attested_state = SparseTree::new(6)
+            .with_leaf(34, u64_chunk(attested_slot))
+            .with_leaf(
+                FINALIZED_ROOT_GINDEX_DENEB,
+                finalized_header.beacon.hash_tree_root(),
+            )
+            .with_leaf(
+                NEXT_SYNC_COMMITTEE_GINDEX_DENEB,
+                next_sync_committee.hash_tree_root(),
+            );
+        let attested_header = self.light_client_header(attested_slot, &attested_state, 0);
+
+        let sync_committee_bits: Vec<u8> = (0..SYNC_COMMITTEE_SIZE / 8)
+            .map(|byte| {
+                (0..8)
+                    .filter(|bit| byte * 8 + bit < participants)
+                    .fold(0u8, |acc, bit| acc | (1 << bit))
+            })
+            .collect();
+
+        let fork_version =
+            EthereumClient::fork_parameters().fork_version((signature_slot - 1) / SLOTS_PER_EPOCH);
+        let domain = compute_domain(
+            DOMAIN_SYNC_COMMITTEE,
+            fork_version,
+            &EthereumClient::genesis_validators_root(),
+        );
+        let signing_root = compute_signing_root(&attested_header.beacon.hash_tree_root(), &domain);
+
+        let account_trie = self.account_trie(finalized_slot);
+
+        Header {
+            trusted_height: EthereumClient::height(trusted_slot),
+            trusted_sync_committee: TrustedSyncCommittee {
+                sync_committee: signing_committee.sync_committee(),
+                is_next: signature_period == trusted_period + 1,
+            },
+            consensus_update: LightClientUpdate {
+                next_sync_committee_branch: attested_state.branch(NEXT_SYNC_COMMITTEE_GINDEX_DENEB),
+                next_sync_committee: Some(next_sync_committee),
+                finality_branch: attested_state.branch(FINALIZED_ROOT_GINDEX_DENEB),
+                finalized_header,
+                attested_header,
+                sync_aggregate: SyncAggregate {
+                    sync_committee_signature: signing_committee
+                        .sign(&signing_root, &sync_committee_bits),
+                    sync_committee_bits,
+                },
+                signature_slot,
+            },
+            account_update: AccountUpdate {
+                account_proof: account_trie.proof(&self.ibc_contract_address),
+                storage_root: self.storage_trie().root(),
+            },
+        }
+    }
+
+    pub fn dummy_storage_proof(&self, path: &[u8]) -> Binary {
+        let storage_proof = StorageProof {
+            proof: self.storage_trie().proof(&self.storage_slot(path)),
+        };
+
+        Protobuf::<RawStorageProof>::encode_vec(storage_proof).into()
+    }
+
+    pub fn dummy_verify_membership_msg(
+        &self,
+        height: Height,
+        path: &[u8],
+        value: &[u8],
+    ) -> VerifyMembershipMsgRaw {
+        VerifyMembershipMsgRaw {
+            proof: self.dummy_storage_proof(path),
+            merkle_path: MerklePath {
+                key_path: vec![b"ibc".to_vec().into(), path.to_vec().into()],
+            },
+            value: value.to_vec().into(),
+            height: height.into(),
+            delay_block_period: 0,
+            delay_time_period: 0,
+        }
+    }
+
+    pub fn dummy_verify_non_membership_msg(
+        &self,
+        height: Height,
+        path: &[u8],
+    ) -> VerifyNonMembershipMsgRaw {
+        VerifyNonMembershipMsgRaw {
+            proof: self.dummy_storage_proof(path),
+            merkle_path: MerklePath {
+                key_path: vec![b"ibc".to_vec().into(), path.to_vec().into()],
+            },
+            height: height.into(),
+            delay_block_period: 0,
+            delay_time_period: 0,
+        }
+    }
+}
+
+impl EthereumClient {
+    pub fn period(slot: u64) -> u64 {
+        slot / SLOTS_PER_PERIOD
+    }
+
+    /// Returns the slot finalizing the block at the given height.
+    pub fn slot(height: Height) -> u64 {
+        height.revision_height() - BLOCK_NUMBER_OFFSET
+    }
+
+    pub fn height(slot: u64) -> Height {
+        Height::new(0, slot + BLOCK_NUMBER_OFFSET).expect("never fails")
+    }
+
+    pub fn fork_parameters() -> ForkParameters {
+        let fork = |version: u8, epoch: u64| Fork {
+            version: [version, 0, 0, 0],
+            epoch,
+        };
+
+        ForkParameters {
+            genesis_fork_version: [0; 4],
+            genesis_slot: 0,
+            altair: fork(1, 0),
+            bellatrix: fork(2, 0),
+            capella: fork(3, 0),
+            deneb: fork(4, 0),
+            electra: fork(5, u64::MAX),
+        }
+    }
+
+    pub fn genesis_validators_root() -> Bytes32 {
+        [0x42; 32]
+    }
+
+    pub fn sync_committee(period: u64) -> TestSyncCommittee {
+        TestSyncCommittee::new(period, SYNC_COMMITTEE_SIZE)
+    }
+
+    pub fn trusted_slot(fxt: &Fixture) -> u64 {
+        Self::slot(fxt.trusted_height)
+    }
+
+    pub fn dummy_client_state(fxt: &Fixture) -> ClientState {
+        let trusted_slot = Self::trusted_slot(fxt);
+
+        ClientState {
+            chain_id: 1337,
+            genesis_validators_root: Self::genesis_validators_root(),
+            genesis_time: fxt.config.genesis_time,
+            fork_parameters: Self::fork_parameters(),
+            seconds_per_slot: SECONDS_PER_SLOT,
+            slots_per_epoch: SLOTS_PER_EPOCH,
+            epochs_per_sync_committee_period: EPOCHS_PER_SYNC_COMMITTEE_PERIOD,
+            sync_committee_size: SYNC_COMMITTEE_SIZE,
+            trusting_period: fxt.config.trusting_period,
+            latest_slot: trusted_slot,
+            latest_height: Self::height(trusted_slot),
+            frozen_height: None,
+            ibc_contract_address: fxt.config.ibc_contract_address,
+            ibc_commitment_slot: fxt.config.ibc_commitment_slot,
+        }
+    }
+
+    pub fn dummy_consensus_state(fxt: &Fixture) -> ConsensusState {
+        let trusted_slot = Self::trusted_slot(fxt);
+
+        let period = Self::period(trusted_slot);
+
+        ConsensusState {
+            slot: trusted_slot,
+            state_root: fxt.config.account_trie(trusted_slot).root(),
+            storage_root: fxt.config.storage_trie().root().to_vec().into(),
+            timestamp: Timestamp::from_nanoseconds(
+                fxt.config.execution_payload(trusted_slot).timestamp * 1_000_000_000,
+            ),
+            current_sync_committee: Self::sync_committee(period)
+                .sync_committee()
+                .hash_tree_root(),
+            next_sync_committee: Some(
+                Self::sync_committee(period + 1)
+                    .sync_committee()
+                    .hash_tree_root(),
+            ),
+        }
+    }
+
+    /// Constructs a recording of the synthetic chain, bootstrapped at the
+    /// trusted slot and updated to `finalized_slot`.
+    pub fn dummy_recording(fxt: &Fixture, finalized_slot: u64) -> Recording {
+        let trusted_slot = Self::trusted_slot(fxt);
+
+        let current_sync_committee =
+            Self::sync_committee(Self::period(trusted_slot)).sync_committee();
+
+        let bootstrap_state = SparseTree::new(6)
+            .with_leaf(34, u64_chunk(trusted_slot))
+            .with_leaf(
+                CURRENT_SYNC_COMMITTEE_GINDEX_DENEB,
+                current_sync_committee.hash_tree_root(),
+            );
+
+        let header = fxt.config.dummy_header(trusted_slot, finalized_slot);
+        let client_state = Self::dummy_client_state(fxt);
+
+        Recording {
+            chain_id: client_state.chain_id,
+            genesis_validators_root: client_state.genesis_validators_root,
+            genesis_time: client_state.genesis_time,
+            fork_parameters: client_state.fork_parameters,
+            seconds_per_slot: client_state.seconds_per_slot,
+            slots_per_epoch: client_state.slots_per_epoch,
+            epochs_per_sync_committee_period: client_state.epochs_per_sync_committee_period,
+            sync_committee_size: client_state.sync_committee_size,
+            ibc_contract_address: client_state.ibc_contract_address,
+            ibc_commitment_slot: client_state.ibc_commitment_slot,
+            bootstrap: Bootstrap {
+                header: fxt
+                    .config
+                    .light_client_header(trusted_slot, &bootstrap_state, 0),
+                current_sync_committee,
+                current_sync_committee_branch: bootstrap_state
+                    .branch(CURRENT_SYNC_COMMITTEE_GINDEX_DENEB),
+            },
+            update: header.consensus_update,
+            account_update: header.account_update,
+        }
+    }
+
+    /// Constructs a misbehaviour out of two headers finalizing different
+    /// beacon blocks at the same slot.
+    pub fn dummy_misbehaviour(fxt: &Fixture, finalized_slot: u64) -> Misbehaviour {
+        let trusted_slot = Self::trusted_slot(fxt);
+
+        Misbehaviour {
+            header_1: fxt.config.dummy_header_with(
+                trusted_slot,
+                finalized_slot,
+                SYNC_COMMITTEE_SIZE,
+                1,
+            ),
+            header_2: fxt.config.dummy_header_with(
+                trusted_slot,
+                finalized_slot,
+                SYNC_COMMITTEE_SIZE,
+                2,
+            ),
+        }
+    }
+}
+
+impl TestClient for EthereumClient {
+    type Config = EthereumConfig;
+
+    fn trusting_period(config: &EthereumConfig) -> Duration {
+        config.trusting_period
+    }
+
+    fn trusted_height(_config: &EthereumConfig) -> Height {
+        Self::height(16)
+    }
+
+    fn client_state(fxt: &Fixture) -> Vec<u8> {
+        ClientState::encode_to_any_vec(Self::dummy_client_state(fxt))
+    }
+
+    fn consensus_state(fxt: &Fixture) -> Vec<u8> {
+        ConsensusState::encode_to_any_vec(Self::dummy_consensus_state(fxt))
+    }
+
+    /// Constructs the header finalizing the slot of the target height.
+    fn header(fxt: &Fixture, target_height: Height) -> Vec<u8> {
+        Header::encode_to_any_vec(
+            fxt.config
+                .dummy_header(Self::trusted_slot(fxt), Self::slot(target_height)),
+        )
+    }
+
+    /// Constructs two headers finalizing different beacon blocks at the
+    /// first slot of the next epoch.
+    fn misbehaviour(fxt: &Fixture) -> Vec<u8> {
+        Misbehaviour::encode_to_any_vec(Self::dummy_misbehaviour(
+            fxt,
+            Self::trusted_slot(fxt) + SLOTS_PER_EPOCH,
+        ))
+    }
+}
@@ -0,0 +1,327 @@
+use std::time::Duration;
+
+use cosmwasm_std::from_json;
+use cosmwasm_std::testing::mock_dependencies;
+use ibc_client_cw::types::{ContractResult, MigrationPrefix};
+use ibc_client_cw::utils::AnyCodec;
+use ibc_client_cw_testkit::scenarios;
+use ibc_core::client::types::Status;
+
+use crate::client_type::EthereumClient;
+use crate::types::{Header, Misbehaviour};
+
+pub mod fixture;
+pub mod recorded;
+
+use fixture::{EthereumConfig, Fixture, SLOTS_PER_PERIOD, SYNC_COMMITTEE_SIZE};
+use recorded::{recordings, Recording};
+
+#[test]
+fn test_cw_ethereum_testkit_scenarios() {
+    scenarios::run_all(&Fixture::default());
+}
+
+#[test]
+fn test_cw_ethereum_update_client_ok() {
+    let fxt = Fixture::default();
+
+    let trusted_slot = EthereumClient::trusted_slot(&fxt);
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let finalized_slot = trusted_slot + 8;
+
+    let resp = fxt
+        .update_client_with(
+            deps.as_mut(),
+            Header::encode_to_any_vec(fxt.config.dummy_header(trusted_slot, finalized_slot)),
+        )
+        .unwrap();
+
+    let contract_result: ContractResult = from_json(resp.data.unwrap()).unwrap();
+
+    assert_eq!(
+        contract_result.heights,
+        Some(vec![EthereumClient::height(finalized_slot)])
+    );
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+}
+
+#[test]
+fn test_cw_ethereum_sync_committee_rotation() {
+    let fxt = Fixture::default();
+
+    let trusted_slot = EthereumClient::trusted_slot(&fxt);
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    // ------------------- Enter the next period -------------------
+
+    // Signed by the next sync committee of the trusted consensus state, which
+    // becomes the current committee of the new consensus state.
+    let next_period_slot = SLOTS_PER_PERIOD + 6;
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(fxt.config.dummy_header(trusted_slot, next_period_slot)),
+    )
+    .unwrap();
+
+    // ------------------- Skipping a period is rejected -------------------
+
+    let skipped_period_header = fxt
+        .config
+        .dummy_header(trusted_slot, 2 * SLOTS_PER_PERIOD + 6);
+
+    assert!(!fxt.is_valid_client_message(
+        deps.as_ref(),
+        Header::encode_to_any_vec(skipped_period_header)
+    ));
+
+    // ------------------- Rotate once more from the new state -------------------
+
+    let resp = fxt
+        .update_client_with(
+            deps.as_mut(),
+            Header::encode_to_any_vec(
+                fxt.config
+                    .dummy_header(next_period_slot, 2 * SLOTS_PER_PERIOD + 6),
+            ),
+        )
+        .unwrap();
+
+    let contract_result: ContractResult = from_json(resp.data.unwrap()).unwrap();
+
+    assert_eq!(
+        contract_result.heights,
+        Some(vec![EthereumClient::height(2 * SLOTS_PER_PERIOD + 6)])
+    );
+}
+
+#[test]
+fn test_cw_ethereum_insufficient_participation() {
+    let fxt = Fixture::default();
+
+    let trusted_slot = EthereumClient::trusted_slot(&fxt);
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let supermajority = (SYNC_COMMITTEE_SIZE * 2).div_ceil(3);
+
+    let header = fxt
+        .config
+        .dummy_header_with(trusted_slot, trusted_slot + 8, supermajority, 0);
+
+    assert!(fxt.is_valid_client_message(deps.as_ref(), Header::encode_to_any_vec(header)));
+
+    let header = fxt
+        .config
+        .dummy_header_with(trusted_slot, trusted_slot + 8, supermajority - 1, 0);
+
+    assert!(!fxt.is_valid_client_message(deps.as_ref(), Header::encode_to_any_vec(header)));
+}
+
+#[test]
+fn test_cw_ethereum_invalid_header_rejected() {
+    let fxt = Fixture::default();
+
+    let trusted_slot = EthereumClient::trusted_slot(&fxt);
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let header = fxt.config.dummy_header(trusted_slot, trusted_slot + 8);
+
+    let mut tampered_signature = header.clone();
+    tampered_signature
+        .consensus_update
+        .attested_header
+        .beacon
+        .proposer_index += 1;
+
+    let mut tampered_finality = header.clone();
+    tampered_finality
+        .consensus_update
+        .finalized_header
+        .execution
+        .timestamp += 1;
+
+    let mut tampered_storage_root = header.clone();
+    tampered_storage_root.account_update.storage_root = [0xff; 32];
+
+    let mut unknown_committee = header;
+    unknown_committee.trusted_sync_committee.sync_committee =
+        EthereumClient::sync_committee(7).sync_committee();
+
+    for header in [
+        tampered_signature,
+        tampered_finality,
+        tampered_storage_root,
+        unknown_committee,
+    ] {
+        assert!(!fxt.is_valid_client_message(deps.as_ref(), Header::encode_to_any_vec(header)));
+    }
+}
+
+#[test]
+fn test_cw_ethereum_verify_membership() {
+    let fxt = Fixture::default();
+
+    let trusted_slot = EthereumClient::trusted_slot(&fxt);
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let height = EthereumClient::height(trusted_slot);
+    let (path, value) = fxt.config.commitments[0].clone();
+
+    fxt.verify_membership(
+        deps.as_mut(),
+        fxt.config
+            .dummy_verify_membership_msg(height, &path, &value),
+    )
+    .unwrap();
+
+    assert!(fxt
+        .verify_membership(
+            deps.as_mut(),
+            fxt.config
+                .dummy_verify_membership_msg(height, &path, b"other-value"),
+        )
+        .is_err());
+
+    let absent_path = b"commitments/ports/transfer/channels/channel-0/sequences/3";
+
+    fxt.verify_non_membership(
+        deps.as_mut(),
+        fxt.config
+            .dummy_verify_non_membership_msg(height, absent_path),
+    )
+    .unwrap();
+
+    assert!(fxt
+        .verify_non_membership(
+            deps.as_mut(),
+            fxt.config.dummy_verify_non_membership_msg(height, &path),
+        )
+        .is_err());
+}
+
+#[test]
+fn test_cw_ethereum_freeze_on_misbehaviour() {
+    let fxt = Fixture::default();
+
+    let trusted_slot = EthereumClient::trusted_slot(&fxt);
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    fxt.update_client_on_misbehaviour_with(
+        deps.as_mut(),
+        Misbehaviour::encode_to_any_vec(EthereumClient::dummy_misbehaviour(&fxt, trusted_slot + 8)),
+    );
+
+    fxt.check_client_status(deps.as_ref(), Status::Frozen);
+}
+
+#[test]
+fn test_cw_ethereum_recovery_client_ok() {
+    let mut fxt = Fixture::default();
+
+    let trusted_slot = EthereumClient::trusted_slot(&fxt);
+
+    let mut deps = mock_dependencies();
+
+    // ------------------- Create subject client -------------------
+
+    fxt.set_migration_prefix(MigrationPrefix::Subject);
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    // ------------------- Freeze subject client -------------------
+
+    fxt.update_client_on_misbehaviour_with(
+        deps.as_mut(),
+        Misbehaviour::encode_to_any_vec(EthereumClient::dummy_misbehaviour(&fxt, trusted_slot + 8)),
+    );
+
+    fxt.check_client_status(deps.as_ref(), Status::Frozen);
+
+    // ------------------- Create substitute client -------------------
+
+    fxt.set_migration_prefix(MigrationPrefix::Substitute);
+
+    fxt.trusted_height = fxt.trusted_height.add(24);
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    // ------------------- Recover subject client -------------------
+
+    let resp = fxt.recover_client(deps.as_mut()).unwrap();
+
+    assert_eq!(0, resp.messages.len());
+
+    fxt.set_migration_prefix(MigrationPrefix::Subject);
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+}
+
+#[test]
+fn test_cw_ethereum_client_expiry() {
+    let fxt = Fixture::new(EthereumConfig {
+        trusting_period: Duration::from_secs(60),
+        ..Default::default()
+    });
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    fxt.check_client_status(deps.as_ref(), Status::Expired);
+}
+
+#[test]
+fn test_cw_ethereum_recording_round_trip() {
+    let fxt = Fixture::default();
+
+    let trusted_slot = EthereumClient::trusted_slot(&fxt);
+
+    let recording = EthereumClient::dummy_recording(&fxt, trusted_slot + 8);
+
+    let parsed = Recording::from_json(&recording.to_json());
+
+    assert_eq!(parsed, recording);
+
+    parsed.verify();
+
+    let mut tampered_bootstrap = parsed;
+    tampered_bootstrap.bootstrap.current_sync_committee =
+        EthereumClient::sync_committee(7).sync_committee();
+
+    assert!(!tampered_bootstrap.verify_bootstrap());
+}
+
+#[test]
+#[ignore = "no recording is checked in yet, record one with `make record-ethereum-fixture`"]
+fn test_cw_ethereum_recorded_update() {
+    let recordings = recordings();
+
+    assert!(
+        !recordings.is_empty(),
+        "no recording under `fixtures/recorded`"
+    );
+
+    for dir in recordings {
+        Recording::load(&dir).verify();
+    }
+}
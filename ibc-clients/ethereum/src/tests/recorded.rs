@@ -0,0 +1,623 @@
+//! Recordings of a live beacon chain, made of the responses of its beacon
+//! and execution node APIs, verified end to end against the contract. A
+//! recording is a directory under `fixtures/recorded/` holding:
+//!
+//! - `genesis.json`, from `/eth/v1/beacon/genesis`;
+//! - `spec.json`, from `/eth/v1/config/spec`;
+//! - `bootstrap.json`, from `/eth/v1/beacon/light_client/bootstrap/{block_root}`;
+//! - `update.json`, from `/eth/v1/beacon/light_client/finality_update` or
+//!   `/eth/v1/beacon/light_client/updates`, signed by the sync committee of
+//!   the period of the bootstrap;
+//! - `account_proof.json`, the `eth_getProof` response for the IBC contract
+//!   at the finalized execution block of the update;
+//! - `ibc.json`, the chain ID of the execution layer along with the address
+//!   and commitment slot of the IBC contract.
+//!
+//! `fixtures/record.sh` records one from live nodes.
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use cosmwasm_std::testing::mock_dependencies;
+use cosmwasm_std::{from_json, BlockInfo, Timestamp as BlockTime};
+use ibc_client_cw::types::{ContractResult, InstantiateMsg};
+use ibc_client_cw::utils::AnyCodec;
+use ibc_client_cw_testkit::clock::Clock;
+use ibc_client_cw_testkit::utils::dummy_checksum;
+use ibc_core::client::types::{Height, Status};
+use ibc_core::primitives::Timestamp;
+use serde_json::{json, Value};
+
+use crate::client_state::ClientState;
+use crate::consensus_state::ConsensusState;
+use crate::ssz::{is_valid_merkle_branch, Bytes32, ZERO_CHUNK};
+use crate::tests::fixture::Fixture;
+use crate::types::{
+    AccountUpdate, BeaconBlockHeader, Bytes20, ExecutionPayloadHeader, Fork, ForkParameters,
+    Header, LightClientHeader, LightClientUpdate, SyncAggregate, SyncCommittee,
+    TrustedSyncCommittee,
+};
+
+/// The trusting period of the clients created from recordings, long enough
+/// for the update to come anywhere in the period of the bootstrap.
+const TRUSTING_PERIOD: Duration = Duration::from_secs(14 * 24 * 3600);
+
+/// The files of a recording, in the order they are recorded.
+const FILES: [&str; 6] = [
+    "genesis.json",
+    "spec.json",
+    "bootstrap.json",
+    "update.json",
+    "account_proof.json",
+    "ibc.json",
+];
+
+/// The bootstrap of the beacon chain light client protocol, carrying the
+/// sync committee of the period of its header.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bootstrap {
+    pub header: LightClientHeader,
+    pub current_sync_committee: SyncCommittee,
+    pub current_sync_committee_branch: Vec<Bytes32>,
+}
+
+/// A light client update of a beacon chain, along with the bootstrap and
+/// the chain parameters needed to verify it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Recording {
+    pub chain_id: u64,
+    pub genesis_validators_root: Bytes32,
+    pub genesis_time: u64,
+    pub fork_parameters: ForkParameters,
+    pub seconds_per_slot: u64,
+    pub slots_per_epoch: u64,
+    pub epochs_per_sync_committee_period: u64,
+    pub sync_committee_size: u64,
+    pub ibc_contract_address: Bytes20,
+    pub ibc_commitment_slot: Bytes32,
+    pub bootstrap: Bootstrap,
+    pub update: LightClientUpdate,
+    pub account_update: AccountUpdate,
+}
+
+/// Returns the directories of the recordings checked in under
+/// `fixtures/recorded/`.
+pub fn recordings() -> Vec<PathBuf> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/recorded");
+
+    let Ok(entries) = fs::read_dir(root) else {
+        return vec![];
+    };
+
+    let mut dirs: Vec<_> = entries
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+impl Recording {
+    /// Loads the recording in the given directory.
+    pub fn load(dir: &Path) -> Self {
+        let files = FILES
+            .into_iter()
+            .map(|name| {
+                let path = dir.join(name);
+                let content = fs::read_to_string(&path)
+                    .unwrap_or_else(|e| panic!("failed to read {}: {e}", path.display()));
+                let value = serde_json::from_str(&content)
+                    .unwrap_or_else(|e| panic!("failed to parse {}: {e}", path.display()));
+
+                (name, value)
+            })
+            .collect();
+
+        Self::from_json(&files)
+    }
+
+    /// Parses a recording out of the API responses, keyed by file name.
+    pub fn from_json(files: &BTreeMap<&str, Value>) -> Self {
+        let genesis = data(&files["genesis.json"]);
+        let spec = data(&files["spec.json"]);
+        let bootstrap = data(&files["bootstrap.json"]);
+        let update = data(&files["update.json"]);
+        let account_proof = files["account_proof.json"]
+            .get("result")
+            .unwrap_or(&files["account_proof.json"]);
+        let ibc = &files["ibc.json"];
+
+        let fork = |name: &str| Fork {
+            version: spec
+                .get(format!("{name}_FORK_VERSION"))
+                .map_or([0; 4], fixed),
+            epoch: spec
+                .get(format!("{name}_FORK_EPOCH"))
+                .map_or(u64::MAX, uint),
+        };
+
+        Self {
+            chain_id: uint(field(ibc, "chain_id")),
+            genesis_validators_root: fixed(field(genesis, "genesis_validators_root")),
+            genesis_time: uint(field(genesis, "genesis_time")),
+            fork_parameters: ForkParameters {
+                genesis_fork_version: fixed(field(spec, "GENESIS_FORK_VERSION")),
+                genesis_slot: 0,
+                altair: fork("ALTAIR"),
+                bellatrix: fork("BELLATRIX"),
+                capella: fork("CAPELLA"),
+                deneb: fork("DENEB"),
+                electra: fork("ELECTRA"),
+            },
+            seconds_per_slot: uint(field(spec, "SECONDS_PER_SLOT")),
+            slots_per_epoch: uint(field(spec, "SLOTS_PER_EPOCH")),
+            epochs_per_sync_committee_period: uint(field(spec, "EPOCHS_PER_SYNC_COMMITTEE_PERIOD")),
+            sync_committee_size: uint(field(spec, "SYNC_COMMITTEE_SIZE")),
+            ibc_contract_address: fixed(field(ibc, "ibc_contract_address")),
+            ibc_commitment_slot: fixed(field(ibc, "ibc_commitment_slot")),
+            bootstrap: Bootstrap {
+                header: light_client_header(field(bootstrap, "header")),
+                current_sync_committee: sync_committee(field(bootstrap, "current_sync_committee")),
+                current_sync_committee_branch: branch(field(
+                    bootstrap,
+                    "current_sync_committee_branch",
+                )),
+            },
+            update: LightClientUpdate {
+                attested_header: light_client_header(field(update, "attested_header")),
+                next_sync_committee: update.get("next_sync_committee").map(sync_committee),
+                next_sync_committee_branch: update
+                    .get("next_sync_committee_branch")
+                    .map_or(vec![], branch),
+                finalized_header: light_client_header(field(update, "finalized_header")),
+                finality_branch: branch(field(update, "finality_branch")),
+                sync_aggregate: {
+                    let aggregate = field(update, "sync_aggregate");
+
+                    SyncAggregate {
+                        sync_committee_bits: bytes(field(aggregate, "sync_committee_bits")),
+                        sync_committee_signature: fixed(field(
+                            aggregate,
+                            "sync_committee_signature",
+                        )),
+                    }
+                },
+                signature_slot: uint(field(update, "signature_slot")),
+            },
+            account_update: AccountUpdate {
+                account_proof: field(account_proof, "accountProof")
+                    .as_array()
+                    .expect("account proof must be an array")
+                    .iter()
+                    .map(bytes)
+                    .collect(),
+                storage_root: fixed(field(account_proof, "storageHash")),
+            },
+        }
+    }
+
+    /// Renders the recording as the API responses it is parsed from, keyed
+    /// by file name.
+    pub fn to_json(&self) -> BTreeMap<&'static str, Value> {
+        let forks = self.fork_parameters;
+
+        let mut spec = json!({
+            "GENESIS_FORK_VERSION": hex(&forks.genesis_fork_version),
+            "SECONDS_PER_SLOT": self.seconds_per_slot.to_string(),
+            "SLOTS_PER_EPOCH": self.slots_per_epoch.to_string(),
+            "EPOCHS_PER_SYNC_COMMITTEE_PERIOD": self.epochs_per_sync_committee_period.to_string(),
+            "SYNC_COMMITTEE_SIZE": self.sync_committee_size.to_string(),
+        });
+
+        for (name, fork) in [
+            ("ALTAIR", forks.altair),
+            ("BELLATRIX", forks.bellatrix),
+            ("CAPELLA", forks.capella),
+            ("DENEB", forks.deneb),
+            ("ELECTRA", forks.electra),
+        ] {
+            spec[format!("{name}_FORK_VERSION")] = hex(&fork.version);
+            spec[format!("{name}_FORK_EPOCH")] = fork.epoch.to_string().into();
+        }
+
+        let update = &self.update;
+
+        let mut update_data = json!({
+            "attested_header": light_client_header_json(&update.attested_header),
+            "finalized_header": light_client_header_json(&update.finalized_header),
+            "finality_branch": branch_json(&update.finality_branch),
+            "sync_aggregate": {
+                "sync_committee_bits": hex(&update.sync_aggregate.sync_committee_bits),
+                "sync_committee_signature": hex(&update.sync_aggregate.sync_committee_signature),
+            },
+            "signature_slot": update.signature_slot.to_string(),
+        });
+
+        if let Some(next_sync_committee) = &update.next_sync_committee {
+            update_data["next_sync_committee"] = sync_committee_json(next_sync_committee);
+            update_data["next_sync_committee_branch"] =
+                branch_json(&update.next_sync_committee_branch);
+        }
+
+        BTreeMap::from([
+            (
+                "genesis.json",
+                json!({
+                    "data": {
+                        "genesis_time": self.genesis_time.to_string(),
+                        "genesis_validators_root": hex(&self.genesis_validators_root),
+                        "genesis_fork_version": hex(&forks.genesis_fork_version),
+                    }
+                }),
+            ),
+            ("spec.json", json!({ "data": spec })),
+            (
+                "bootstrap.json",
+                json!({
+                    "version": "deneb",
+                    "data": {
+                        "header": light_client_header_json(&self.bootstrap.header),
+                        "current_sync_committee":
+                            sync_committee_json(&self.bootstrap.current_sync_committee),
+                        "current_sync_committee_branch":
+                            branch_json(&self.bootstrap.current_sync_committee_branch),
+                    }
+                }),
+            ),
+            (
+                "update.json",
+                json!({ "version": "deneb", "data": update_data }),
+            ),
+            (
+                "account_proof.json",
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": 1,
+                    "result": {
+                        "address": hex(&self.ibc_contract_address),
+                        "accountProof": self
+                            .account_update
+                            .account_proof
+                            .iter()
+                            .map(|node| hex(node))
+                            .collect::<Vec<_>>(),
+                        "storageHash": hex(&self.account_update.storage_root),
+                        "storageProof": [],
+                    }
+                }),
+            ),
+            (
+                "ibc.json",
+                json!({
+                    "chain_id": self.chain_id.to_string(),
+                    "ibc_contract_address": hex(&self.ibc_contract_address),
+                    "ibc_commitment_slot": hex(&self.ibc_commitment_slot),
+                }),
+            ),
+        ])
+    }
+
+    fn epoch(&self, slot: u64) -> u64 {
+        slot / self.slots_per_epoch
+    }
+
+    fn period(&self, slot: u64) -> u64 {
+        self.epoch(slot) / self.epochs_per_sync_committee_period
+    }
+
+    fn bootstrap_height(&self) -> Height {
+        Height::new(0, self.bootstrap.header.execution.block_number).expect("never fails")
+    }
+
+    /// Returns whether the sync committee of the bootstrap is the one of the
+    /// beacon state of its header.
+    pub fn verify_bootstrap(&self) -> bool {
+        let beacon = &self.bootstrap.header.beacon;
+
+        is_valid_merkle_branch(
+            &self.bootstrap.current_sync_committee.hash_tree_root(),
+            &self.bootstrap.current_sync_committee_branch,
+            self.fork_parameters
+                .current_sync_committee_gindex(self.epoch(beacon.slot)),
+            &beacon.state_root,
+        )
+    }
+
+    pub fn client_state(&self) -> ClientState {
+        ClientState {
+            chain_id: self.chain_id,
+            genesis_validators_root: self.genesis_validators_root,
+            genesis_time: self.genesis_time,
+            fork_parameters: self.fork_parameters,
+            seconds_per_slot: self.seconds_per_slot,
+            slots_per_epoch: self.slots_per_epoch,
+            epochs_per_sync_committee_period: self.epochs_per_sync_committee_period,
+            sync_committee_size: self.sync_committee_size,
+            trusting_period: TRUSTING_PERIOD,
+            latest_slot: self.bootstrap.header.beacon.slot,
+            latest_height: self.bootstrap_height(),
+            frozen_height: None,
+            ibc_contract_address: self.ibc_contract_address,
+            ibc_commitment_slot: self.ibc_commitment_slot,
+        }
+    }
+
+    /// Returns the consensus state trusting the bootstrap. The bootstrap
+    /// carries neither the storage root of the IBC contract nor the next
+    /// sync committee, which the update does not need.
+    pub fn consensus_state(&self) -> ConsensusState {
+        let header = &self.bootstrap.header;
+
+        ConsensusState {
+            slot: header.beacon.slot,
+            state_root: header.execution.state_root,
+            storage_root: ZERO_CHUNK.to_vec().into(),
+            timestamp: Timestamp::from_nanoseconds(header.execution.timestamp * 1_000_000_000),
+            current_sync_committee: self.bootstrap.current_sync_committee.hash_tree_root(),
+            next_sync_committee: None,
+        }
+    }
+
+    pub fn instantiate_msg(&self) -> InstantiateMsg {
+        InstantiateMsg {
+            client_state: ClientState::encode_to_any_vec(self.client_state()).into(),
+            consensus_state: ConsensusState::encode_to_any_vec(self.consensus_state()).into(),
+            checksum: dummy_checksum(),
+        }
+    }
+
+    /// Returns the header submitting the update on top of the bootstrap.
+    pub fn header(&self) -> Header {
+        Header {
+            trusted_height: self.bootstrap_height(),
+            trusted_sync_committee: TrustedSyncCommittee {
+                sync_committee: self.bootstrap.current_sync_committee.clone(),
+                is_next: false,
+            },
+            consensus_update: self.update.clone(),
+            account_update: self.account_update.clone(),
+        }
+    }
+
+    /// Creates a client trusting the bootstrap, and updates it with the
+    /// update a minute after its finalized block.
+    pub fn verify(&self) {
+        assert!(
+            self.verify_bootstrap(),
+            "the sync committee of the bootstrap is not the one of its beacon state"
+        );
+
+        assert_eq!(
+            self.period(self.bootstrap.header.beacon.slot),
+            self.period(self.update.signature_slot),
+            "the update must be signed by the sync committee of the bootstrap"
+        );
+
+        let finalized = &self.update.finalized_header.execution;
+
+        let fxt = Fixture {
+            clock: Clock::new(BlockInfo {
+                time: BlockTime::from_seconds(finalized.timestamp + 60),
+                ..Clock::default().block().clone()
+            }),
+            ..Default::default()
+        };
+
+        let mut deps = mock_dependencies();
+
+        fxt.instantiate(deps.as_mut(), self.instantiate_msg())
+            .unwrap();
+
+        let resp = fxt
+            .update_client_with(deps.as_mut(), Header::encode_to_any_vec(self.header()))
+            .unwrap();
+
+        let contract_result: ContractResult = from_json(resp.data.unwrap()).unwrap();
+
+        assert_eq!(
+            contract_result.heights,
+            Some(vec![Height::new(0, finalized.block_number).unwrap()])
+        );
+
+        fxt.check_client_status(deps.as_ref(), Status::Active);
+    }
+}
+
+// ------------------- Parsing -------------------
+
+/// Unwraps the `data` of a beacon API response, taking the first element of
+/// the list returned by the `updates` endpoint.
+fn data(response: &Value) -> &Value {
+    let response = match response {
+        Value::Array(responses) => responses.first().expect("empty response list"),
+        response => response,
+    };
+
+    field(response, "data")
+}
+
+fn field<'a>(value: &'a Value, name: &str) -> &'a Value {
+    value
+        .get(name)
+        .unwrap_or_else(|| panic!("missing field `{name}`"))
+}
+
+/// Parses an unsigned integer, quoted as the beacon API does or not.
+fn uint(value: &Value) -> u64 {
+    match value {
+        Value::String(s) => s.parse().expect("invalid integer"),
+        value => value.as_u64().expect("invalid integer"),
+    }
+}
+
+fn bytes(value: &Value) -> Vec<u8> {
+    let s = value.as_str().expect("bytes must be a hex string");
+    let s = s.strip_prefix("0x").unwrap_or(s);
+
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).expect("invalid hex"))
+        .collect()
+}
+
+fn fixed<const N: usize>(value: &Value) -> [u8; N] {
+    bytes(value).try_into().expect("unexpected length")
+}
+
+fn branch(value: &Value) -> Vec<Bytes32> {
+    value
+        .as_array()
+        .expect("branch must be an array")
+        .iter()
+        .map(fixed)
+        .collect()
+}
+
+/// Parses a decimal `uint256` into its little-endian SSZ encoding.
+fn uint256(value: &Value) -> Bytes32 {
+    let mut le = ZERO_CHUNK;
+
+    for digit in value.as_str().expect("uint256 must be a string").bytes() {
+        let mut carry = u16::from(digit - b'0');
+
+        for byte in le.iter_mut() {
+            let product = u16::from(*byte) * 10 + carry;
+            *byte = product as u8;
+            carry = product >> 8;
+        }
+
+        assert_eq!(carry, 0, "uint256 overflow");
+    }
+
+    le
+}
+
+fn light_client_header(value: &Value) -> LightClientHeader {
+    let beacon = field(value, "beacon");
+    let execution = field(value, "execution");
+
+    LightClientHeader {
+        beacon: BeaconBlockHeader {
+            slot: uint(field(beacon, "slot")),
+            proposer_index: uint(field(beacon, "proposer_index")),
+            parent_root: fixed(field(beacon, "parent_root")),
+            state_root: fixed(field(beacon, "state_root")),
+            body_root: fixed(field(beacon, "body_root")),
+        },
+        execution: ExecutionPayloadHeader {
+            parent_hash: fixed(field(execution, "parent_hash")),
+            fee_recipient: fixed(field(execution, "fee_recipient")),
+            state_root: fixed(field(execution, "state_root")),
+            receipts_root: fixed(field(execution, "receipts_root")),
+            logs_bloom: bytes(field(execution, "logs_bloom")),
+            prev_randao: fixed(field(execution, "prev_randao")),
+            block_number: uint(field(execution, "block_number")),
+            gas_limit: uint(field(execution, "gas_limit")),
+            gas_used: uint(field(execution, "gas_used")),
+            timestamp: uint(field(execution, "timestamp")),
+            extra_data: bytes(field(execution, "extra_data")),
+            base_fee_per_gas: uint256(field(execution, "base_fee_per_gas")),
+            block_hash: fixed(field(execution, "block_hash")),
+            transactions_root: fixed(field(execution, "transactions_root")),
+            withdrawals_root: fixed(field(execution, "withdrawals_root")),
+            blob_gas_used: uint(field(execution, "blob_gas_used")),
+            excess_blob_gas: uint(field(execution, "excess_blob_gas")),
+        },
+        execution_branch: branch(field(value, "execution_branch")),
+    }
+}
+
+fn sync_committee(value: &Value) -> SyncCommittee {
+    SyncCommittee {
+        pubkeys: field(value, "pubkeys")
+            .as_array()
+            .expect("pubkeys must be an array")
+            .iter()
+            .map(fixed)
+            .collect(),
+        aggregate_pubkey: fixed(field(value, "aggregate_pubkey")),
+    }
+}
+
+// ------------------- Rendering -------------------
+
+fn hex(bytes: &[u8]) -> Value {
+    let digits: String = bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+
+    format!("0x{digits}").into()
+}
+
+fn branch_json(branch: &[Bytes32]) -> Value {
+    branch.iter().map(|node| hex(node)).collect()
+}
+
+/// Renders the little-endian SSZ encoding of a `uint256` in decimal.
+fn uint256_json(le: &Bytes32) -> Value {
+    let mut le = *le;
+    let mut digits = vec![];
+
+    loop {
+        let mut remainder = 0u16;
+
+        for byte in le.iter_mut().rev() {
+            let dividend = (remainder << 8) | u16::from(*byte);
+            *byte = (dividend / 10) as u8;
+            remainder = dividend % 10;
+        }
+
+        digits.push(char::from(b'0' + remainder as u8));
+
+        if le == ZERO_CHUNK {
+            break;
+        }
+    }
+
+    digits.iter().rev().collect::<String>().into()
+}
+
+fn light_client_header_json(header: &LightClientHeader) -> Value {
+    let beacon = &header.beacon;
+    let execution = &header.execution;
+
+    json!({
+        "beacon": {
+            "slot": beacon.slot.to_string(),
+            "proposer_index": beacon.proposer_index.to_string(),
+            "parent_root": hex(&beacon.parent_root),
+            "state_root": hex(&beacon.state_root),
+            "body_root": hex(&beacon.body_root),
+        },
+        "execution": {
+            "parent_hash": hex(&execution.parent_hash),
+            "fee_recipient": hex(&execution.fee_recipient),
+            "state_root": hex(&execution.state_root),
+            "receipts_root": hex(&execution.receipts_root),
+            "logs_bloom": hex(&execution.logs_bloom),
+            "prev_randao": hex(&execution.prev_randao),
+            "block_number": execution.block_number.to_string(),
+            "gas_limit": execution.gas_limit.to_string(),
+            "gas_used": execution.gas_used.to_string(),
+            "timestamp": execution.timestamp.to_string(),
+            "extra_data": hex(&execution.extra_data),
+            "base_fee_per_gas": uint256_json(&execution.base_fee_per_gas),
+            "block_hash": hex(&execution.block_hash),
+            "transactions_root": hex(&execution.transactions_root),
+            "withdrawals_root": hex(&execution.withdrawals_root),
+            "blob_gas_used": execution.blob_gas_used.to_string(),
+            "excess_blob_gas": execution.excess_blob_gas.to_string(),
+        },
+        "execution_branch": branch_json(&header.execution_branch),
+    })
+}
+
+fn sync_committee_json(sync_committee: &SyncCommittee) -> Value {
+    json!({
+        "pubkeys": sync_committee
+            .pubkeys
+            .iter()
+            .map(|pubkey| hex(pubkey))
+            .collect::<Vec<_>>(),
+        "aggregate_pubkey": hex(&sync_committee.aggregate_pubkey),
+    })
+}
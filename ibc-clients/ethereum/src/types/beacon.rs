@@ -0,0 +1,365 @@
+//! Beacon chain containers tracked by the light client, along with their SSZ
+//! hash tree roots.
+use ibc_core::host::types::error::DecodingError;
+
+use super::proto::{
+    BeaconBlockHeader as RawBeaconBlockHeader, ExecutionPayloadHeader as RawExecutionPayloadHeader,
+    LightClientHeader as RawLightClientHeader, LightClientUpdate as RawLightClientUpdate,
+    SyncAggregate as RawSyncAggregate, SyncCommittee as RawSyncCommittee,
+};
+use super::{branch, branch_to_raw, fixed_bytes, Bytes20, Bytes48, Bytes96};
+use crate::ssz::{
+    bytes_list_root, bytes_vector_root, hash_pair, merkleize, u64_chunk, Bytes32, ZERO_CHUNK,
+};
+
+/// The maximum length of the `extra_data` field of an execution payload.
+pub const MAX_EXTRA_DATA_BYTES: usize = 32;
+/// The length of the `logs_bloom` field of an execution payload.
+pub const BYTES_PER_LOGS_BLOOM: usize = 256;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BeaconBlockHeader {
+    pub slot: u64,
+    pub proposer_index: u64,
+    pub parent_root: Bytes32,
+    pub state_root: Bytes32,
+    pub body_root: Bytes32,
+}
+
+impl BeaconBlockHeader {
+    pub fn hash_tree_root(&self) -> Bytes32 {
+        merkleize(
+            &[
+                u64_chunk(self.slot),
+                u64_chunk(self.proposer_index),
+                self.parent_root,
+                self.state_root,
+                self.body_root,
+            ],
+            None,
+        )
+    }
+}
+
+impl TryFrom<RawBeaconBlockHeader> for BeaconBlockHeader {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawBeaconBlockHeader) -> Result<Self, Self::Error> {
+        Ok(Self {
+            slot: raw.slot,
+            proposer_index: raw.proposer_index,
+            parent_root: fixed_bytes("parent root", raw.parent_root)?,
+            state_root: fixed_bytes("state root", raw.state_root)?,
+            body_root: fixed_bytes("body root", raw.body_root)?,
+        })
+    }
+}
+
+impl From<BeaconBlockHeader> for RawBeaconBlockHeader {
+    fn from(value: BeaconBlockHeader) -> Self {
+        Self {
+            slot: value.slot,
+            proposer_index: value.proposer_index,
+            parent_root: value.parent_root.to_vec(),
+            state_root: value.state_root.to_vec(),
+            body_root: value.body_root.to_vec(),
+        }
+    }
+}
+
+/// The Deneb (and Electra) execution payload header.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionPayloadHeader {
+    pub parent_hash: Bytes32,
+    pub fee_recipient: Bytes20,
+    pub state_root: Bytes32,
+    pub receipts_root: Bytes32,
+    pub logs_bloom: Vec<u8>,
+    pub prev_randao: Bytes32,
+    pub block_number: u64,
+    pub gas_limit: u64,
+    pub gas_used: u64,
+    pub timestamp: u64,
+    pub extra_data: Vec<u8>,
+    /// Little-endian `uint256`.
+    pub base_fee_per_gas: Bytes32,
+    pub block_hash: Bytes32,
+    pub transactions_root: Bytes32,
+    pub withdrawals_root: Bytes32,
+    pub blob_gas_used: u64,
+    pub excess_blob_gas: u64,
+}
+
+impl ExecutionPayloadHeader {
+    pub fn hash_tree_root(&self) -> Bytes32 {
+        let mut fee_recipient = ZERO_CHUNK;
+        fee_recipient[..20].copy_from_slice(&self.fee_recipient);
+
+        merkleize(
+            &[
+                self.parent_hash,
+                fee_recipient,
+                self.state_root,
+                self.receipts_root,
+                bytes_vector_root(&self.logs_bloom),
+                self.prev_randao,
+                u64_chunk(self.block_number),
+                u64_chunk(self.gas_limit),
+                u64_chunk(self.gas_used),
+                u64_chunk(self.timestamp),
+                bytes_list_root(&self.extra_data, MAX_EXTRA_DATA_BYTES),
+                self.base_fee_per_gas,
+                self.block_hash,
+                self.transactions_root,
+                self.withdrawals_root,
+                u64_chunk(self.blob_gas_used),
+                u64_chunk(self.excess_blob_gas),
+            ],
+            None,
+        )
+    }
+}
+
+impl TryFrom<RawExecutionPayloadHeader> for ExecutionPayloadHeader {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawExecutionPayloadHeader) -> Result<Self, Self::Error> {
+        if raw.logs_bloom.len() != BYTES_PER_LOGS_BLOOM {
+            return Err(DecodingError::invalid_raw_data(format!(
+                "logs bloom must be {BYTES_PER_LOGS_BLOOM} bytes long"
+            )));
+        }
+
+        if raw.extra_data.len() > MAX_EXTRA_DATA_BYTES {
+            return Err(DecodingError::invalid_raw_data(format!(
+                "extra data must be at most {MAX_EXTRA_DATA_BYTES} bytes long"
+            )));
+        }
+
+        Ok(Self {
+            parent_hash: fixed_bytes("parent hash", raw.parent_hash)?,
+            fee_recipient: fixed_bytes("fee recipient", raw.fee_recipient)?,
+            state_root: fixed_bytes("execution state root", raw.state_root)?,
+            receipts_root: fixed_bytes("receipts root", raw.receipts_root)?,
+            logs_bloom: raw.logs_bloom,
+            prev_randao: fixed_bytes("prev randao", raw.prev_randao)?,
+            block_number: raw.block_number,
+            gas_limit: raw.gas_limit,
+            gas_used: raw.gas_used,
+            timestamp: raw.timestamp,
+            extra_data: raw.extra_data,
+            base_fee_per_gas: fixed_bytes("base fee per gas", raw.base_fee_per_gas)?,
+            block_hash: fixed_bytes("block hash", raw.block_hash)?,
+            transactions_root: fixed_bytes("transactions root", raw.transactions_root)?,
+            withdrawals_root: fixed_bytes("withdrawals root", raw.withdrawals_root)?,
+            blob_gas_used: raw.blob_gas_used,
+            excess_blob_gas: raw.excess_blob_gas,
+        })
+    }
+}
+
+impl From<ExecutionPayloadHeader> for RawExecutionPayloadHeader {
+    fn from(value: ExecutionPayloadHeader) -> Self {
+        Self {
+            parent_hash: value.parent_hash.to_vec(),
+            fee_recipient: value.fee_recipient.to_vec(),
+            state_root: value.state_root.to_vec(),
+            receipts_root: value.receipts_root.to_vec(),
+            logs_bloom: value.logs_bloom,
+            prev_randao: value.prev_randao.to_vec(),
+            block_number: value.block_number,
+            gas_limit: value.gas_limit,
+            gas_used: value.gas_used,
+            timestamp: value.timestamp,
+            extra_data: value.extra_data,
+            base_fee_per_gas: value.base_fee_per_gas.to_vec(),
+            block_hash: value.block_hash.to_vec(),
+            transactions_root: value.transactions_root.to_vec(),
+            withdrawals_root: value.withdrawals_root.to_vec(),
+            blob_gas_used: value.blob_gas_used,
+            excess_blob_gas: value.excess_blob_gas,
+        }
+    }
+}
+
+/// A beacon block header together with the execution payload header it
+/// commits to through `execution_branch`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LightClientHeader {
+    pub beacon: BeaconBlockHeader,
+    pub execution: ExecutionPayloadHeader,
+    pub execution_branch: Vec<Bytes32>,
+}
+
+impl TryFrom<RawLightClientHeader> for LightClientHeader {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawLightClientHeader) -> Result<Self, Self::Error> {
+        Ok(Self {
+            beacon: raw
+                .beacon
+                .ok_or(DecodingError::missing_raw_data("beacon header"))?
+                .try_into()?,
+            execution: raw
+                .execution
+                .ok_or(DecodingError::missing_raw_data("execution payload header"))?
+                .try_into()?,
+            execution_branch: branch("execution branch", raw.execution_branch)?,
+        })
+    }
+}
+
+impl From<LightClientHeader> for RawLightClientHeader {
+    fn from(value: LightClientHeader) -> Self {
+        Self {
+            beacon: Some(value.beacon.into()),
+            execution: Some(value.execution.into()),
+            execution_branch: branch_to_raw(value.execution_branch),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SyncCommittee {
+    pub pubkeys: Vec<Bytes48>,
+    pub aggregate_pubkey: Bytes48,
+}
+
+impl SyncCommittee {
+    pub fn hash_tree_root(&self) -> Bytes32 {
+        let pubkeys: Vec<Bytes32> = self.pubkeys.iter().map(pubkey_root).collect();
+
+        hash_pair(
+            &merkleize(&pubkeys, None),
+            &pubkey_root(&self.aggregate_pubkey),
+        )
+    }
+}
+
+fn pubkey_root(pubkey: &Bytes48) -> Bytes32 {
+    bytes_vector_root(pubkey)
+}
+
+impl TryFrom<RawSyncCommittee> for SyncCommittee {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawSyncCommittee) -> Result<Self, Self::Error> {
+        Ok(Self {
+            pubkeys: raw
+                .pubkeys
+                .into_iter()
+                .map(|pk| fixed_bytes("sync committee pubkey", pk))
+                .collect::<Result<_, _>>()?,
+            aggregate_pubkey: fixed_bytes("aggregate pubkey", raw.aggregate_pubkey)?,
+        })
+    }
+}
+
+impl From<SyncCommittee> for RawSyncCommittee {
+    fn from(value: SyncCommittee) -> Self {
+        Self {
+            pubkeys: value.pubkeys.into_iter().map(Vec::from).collect(),
+            aggregate_pubkey: value.aggregate_pubkey.to_vec(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SyncAggregate {
+    pub sync_committee_bits: Vec<u8>,
+    pub sync_committee_signature: Bytes96,
+}
+
+impl SyncAggregate {
+    /// Returns whether the committee member at the given index participated.
+    pub fn participated(&self, index: usize) -> bool {
+        self.sync_committee_bits
+            .get(index / 8)
+            .is_some_and(|byte| (byte >> (index % 8)) & 1 == 1)
+    }
+
+    pub fn num_participants(&self) -> u64 {
+        self.sync_committee_bits
+            .iter()
+            .map(|byte| u64::from(byte.count_ones()))
+            .sum()
+    }
+}
+
+impl TryFrom<RawSyncAggregate> for SyncAggregate {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawSyncAggregate) -> Result<Self, Self::Error> {
+        Ok(Self {
+            sync_committee_bits: raw.sync_committee_bits,
+            sync_committee_signature: fixed_bytes(
+                "sync committee signature",
+                raw.sync_committee_signature,
+            )?,
+        })
+    }
+}
+
+impl From<SyncAggregate> for RawSyncAggregate {
+    fn from(value: SyncAggregate) -> Self {
+        Self {
+            sync_committee_bits: value.sync_committee_bits,
+            sync_committee_signature: value.sync_committee_signature.to_vec(),
+        }
+    }
+}
+
+/// A finality update of the beacon chain light client protocol, optionally
+/// carrying the next sync committee of the attested period.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LightClientUpdate {
+    pub attested_header: LightClientHeader,
+    pub next_sync_committee: Option<SyncCommittee>,
+    pub next_sync_committee_branch: Vec<Bytes32>,
+    pub finalized_header: LightClientHeader,
+    pub finality_branch: Vec<Bytes32>,
+    pub sync_aggregate: SyncAggregate,
+    pub signature_slot: u64,
+}
+
+impl TryFrom<RawLightClientUpdate> for LightClientUpdate {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawLightClientUpdate) -> Result<Self, Self::Error> {
+        Ok(Self {
+            attested_header: raw
+                .attested_header
+                .ok_or(DecodingError::missing_raw_data("attested header"))?
+                .try_into()?,
+            next_sync_committee: raw.next_sync_committee.map(TryInto::try_into).transpose()?,
+            next_sync_committee_branch: branch(
+                "next sync committee branch",
+                raw.next_sync_committee_branch,
+            )?,
+            finalized_header: raw
+                .finalized_header
+                .ok_or(DecodingError::missing_raw_data("finalized header"))?
+                .try_into()?,
+            finality_branch: branch("finality branch", raw.finality_branch)?,
+            sync_aggregate: raw
+                .sync_aggregate
+                .ok_or(DecodingError::missing_raw_data("sync aggregate"))?
+                .try_into()?,
+            signature_slot: raw.signature_slot,
+        })
+    }
+}
+
+impl From<LightClientUpdate> for RawLightClientUpdate {
+    fn from(value: LightClientUpdate) -> Self {
+        Self {
+            attested_header: Some(value.attested_header.into()),
+            next_sync_committee: value.next_sync_committee.map(Into::into),
+            next_sync_committee_branch: branch_to_raw(value.next_sync_committee_branch),
+            finalized_header: Some(value.finalized_header.into()),
+            finality_branch: branch_to_raw(value.finality_branch),
+            sync_aggregate: Some(value.sync_aggregate.into()),
+            signature_slot: value.signature_slot,
+        }
+    }
+}
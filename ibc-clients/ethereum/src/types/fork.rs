@@ -0,0 +1,142 @@
+use ibc_core::host::types::error::DecodingError;
+
+use super::proto::{Fork as RawFork, ForkParameters as RawForkParameters};
+use super::{fixed_bytes, Bytes4};
+
+/// Generalized index of `finalized_checkpoint.root` in the Deneb `BeaconState`.
+pub const FINALIZED_ROOT_GINDEX_DENEB: u64 = 105;
+/// Generalized index of `finalized_checkpoint.root` in the Electra `BeaconState`.
+pub const FINALIZED_ROOT_GINDEX_ELECTRA: u64 = 169;
+/// Generalized index of `current_sync_committee` in the Deneb `BeaconState`.
+pub const CURRENT_SYNC_COMMITTEE_GINDEX_DENEB: u64 = 54;
+/// Generalized index of `current_sync_committee` in the Electra `BeaconState`.
+pub const CURRENT_SYNC_COMMITTEE_GINDEX_ELECTRA: u64 = 86;
+/// Generalized index of `next_sync_committee` in the Deneb `BeaconState`.
+pub const NEXT_SYNC_COMMITTEE_GINDEX_DENEB: u64 = 55;
+/// Generalized index of `next_sync_committee` in the Electra `BeaconState`.
+pub const NEXT_SYNC_COMMITTEE_GINDEX_ELECTRA: u64 = 87;
+/// Generalized index of `execution_payload` in the `BeaconBlockBody`.
+pub const EXECUTION_PAYLOAD_GINDEX: u64 = 25;
+
+/// A scheduled fork of the beacon chain.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Fork {
+    pub version: Bytes4,
+    pub epoch: u64,
+}
+
+impl TryFrom<RawFork> for Fork {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawFork) -> Result<Self, Self::Error> {
+        Ok(Self {
+            version: fixed_bytes("fork version", raw.version)?,
+            epoch: raw.epoch,
+        })
+    }
+}
+
+impl From<Fork> for RawFork {
+    fn from(value: Fork) -> Self {
+        Self {
+            version: value.version.to_vec(),
+            epoch: value.epoch,
+        }
+    }
+}
+
+/// The fork schedule of the tracked beacon chain. Forks that are not yet
+/// scheduled are expected to carry `u64::MAX` as their epoch.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ForkParameters {
+    pub genesis_fork_version: Bytes4,
+    pub genesis_slot: u64,
+    pub altair: Fork,
+    pub bellatrix: Fork,
+    pub capella: Fork,
+    pub deneb: Fork,
+    pub electra: Fork,
+}
+
+impl ForkParameters {
+    /// Returns the fork version active at the given epoch.
+    pub fn fork_version(&self, epoch: u64) -> Bytes4 {
+        [
+            self.electra,
+            self.deneb,
+            self.capella,
+            self.bellatrix,
+            self.altair,
+        ]
+        .into_iter()
+        .find(|fork| epoch >= fork.epoch)
+        .map_or(self.genesis_fork_version, |fork| fork.version)
+    }
+
+    pub fn is_deneb_or_later(&self, epoch: u64) -> bool {
+        epoch >= self.deneb.epoch
+    }
+
+    pub fn is_electra_or_later(&self, epoch: u64) -> bool {
+        epoch >= self.electra.epoch
+    }
+
+    pub fn finalized_root_gindex(&self, epoch: u64) -> u64 {
+        if self.is_electra_or_later(epoch) {
+            FINALIZED_ROOT_GINDEX_ELECTRA
+        } else {
+            FINALIZED_ROOT_GINDEX_DENEB
+        }
+    }
+
+    pub fn current_sync_committee_gindex(&self, epoch: u64) -> u64 {
+        if self.is_electra_or_later(epoch) {
+            CURRENT_SYNC_COMMITTEE_GINDEX_ELECTRA
+        } else {
+            CURRENT_SYNC_COMMITTEE_GINDEX_DENEB
+        }
+    }
+
+    pub fn next_sync_committee_gindex(&self, epoch: u64) -> u64 {
+        if self.is_electra_or_later(epoch) {
+            NEXT_SYNC_COMMITTEE_GINDEX_ELECTRA
+        } else {
+            NEXT_SYNC_COMMITTEE_GINDEX_DENEB
+        }
+    }
+}
+
+impl TryFrom<RawForkParameters> for ForkParameters {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawForkParameters) -> Result<Self, Self::Error> {
+        let fork = |name: &str, fork: Option<RawFork>| -> Result<Fork, DecodingError> {
+            fork.ok_or(DecodingError::missing_raw_data(format!("{name} fork")))?
+                .try_into()
+        };
+
+        Ok(Self {
+            genesis_fork_version: fixed_bytes("genesis fork version", raw.genesis_fork_version)?,
+            genesis_slot: raw.genesis_slot,
+            altair: fork("altair", raw.altair)?,
+            bellatrix: fork("bellatrix", raw.bellatrix)?,
+            capella: fork("capella", raw.capella)?,
+            deneb: fork("deneb", raw.deneb)?,
+            electra: fork("electra", raw.electra)?,
+        })
+    }
+}
+
+impl From<ForkParameters> for RawForkParameters {
+    fn from(value: ForkParameters) -> Self {
+        Self {
+            genesis_fork_version: value.genesis_fork_version.to_vec(),
+            genesis_slot: value.genesis_slot,
+            altair: Some(value.altair.into()),
+            bellatrix: Some(value.bellatrix.into()),
+            capella: Some(value.capella.into()),
+            deneb: Some(value.deneb.into()),
+            electra: Some(value.electra.into()),
+        }
+    }
+}
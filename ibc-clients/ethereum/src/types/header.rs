@@ -0,0 +1,246 @@
+//! Client messages and proofs accepted by the Ethereum light client.
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Height;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::proto::{Any, Protobuf};
+
+use super::proto::{
+    AccountUpdate as RawAccountUpdate, Header as RawHeader, Misbehaviour as RawMisbehaviour,
+    StorageProof as RawStorageProof, TrustedSyncCommittee as RawTrustedSyncCommittee,
+};
+use super::{fixed_bytes, LightClientUpdate, SyncCommittee};
+use crate::ssz::Bytes32;
+
+pub const ETHEREUM_HEADER_TYPE_URL: &str = "/ibc.lightclients.ethereum.v1.Header";
+pub const ETHEREUM_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.lightclients.ethereum.v1.Misbehaviour";
+
+/// The sync committee that signed an update, along with whether it is the
+/// current or the next committee of the trusted consensus state.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TrustedSyncCommittee {
+    pub sync_committee: SyncCommittee,
+    pub is_next: bool,
+}
+
+impl TryFrom<RawTrustedSyncCommittee> for TrustedSyncCommittee {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawTrustedSyncCommittee) -> Result<Self, Self::Error> {
+        Ok(Self {
+            sync_committee: raw
+                .sync_committee
+                .ok_or(DecodingError::missing_raw_data("trusted sync committee"))?
+                .try_into()?,
+            is_next: raw.is_next,
+        })
+    }
+}
+
+impl From<TrustedSyncCommittee> for RawTrustedSyncCommittee {
+    fn from(value: TrustedSyncCommittee) -> Self {
+        Self {
+            sync_committee: Some(value.sync_committee.into()),
+            is_next: value.is_next,
+        }
+    }
+}
+
+/// The proof of the IBC contract account against the finalized execution
+/// state root, yielding the storage root used for membership proofs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountUpdate {
+    pub account_proof: Vec<Vec<u8>>,
+    pub storage_root: Bytes32,
+}
+
+impl TryFrom<RawAccountUpdate> for AccountUpdate {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawAccountUpdate) -> Result<Self, Self::Error> {
+        Ok(Self {
+            account_proof: raw.account_proof,
+            storage_root: fixed_bytes("storage root", raw.storage_root)?,
+        })
+    }
+}
+
+impl From<AccountUpdate> for RawAccountUpdate {
+    fn from(value: AccountUpdate) -> Self {
+        Self {
+            account_proof: value.account_proof,
+            storage_root: value.storage_root.to_vec(),
+        }
+    }
+}
+
+/// The header submitted to update the client.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Header {
+    pub trusted_height: Height,
+    pub trusted_sync_committee: TrustedSyncCommittee,
+    pub consensus_update: LightClientUpdate,
+    pub account_update: AccountUpdate,
+}
+
+impl Header {
+    /// Returns the height of the header, i.e. the block number of the
+    /// finalized execution payload.
+    pub fn height(&self) -> Result<Height, ClientError> {
+        Height::new(
+            0,
+            self.consensus_update
+                .finalized_header
+                .execution
+                .block_number,
+        )
+    }
+}
+
+impl Protobuf<RawHeader> for Header {}
+
+impl TryFrom<RawHeader> for Header {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawHeader) -> Result<Self, Self::Error> {
+        Ok(Self {
+            trusted_height: raw
+                .trusted_height
+                .ok_or(DecodingError::missing_raw_data("trusted height"))?
+                .try_into()?,
+            trusted_sync_committee: raw
+                .trusted_sync_committee
+                .ok_or(DecodingError::missing_raw_data("trusted sync committee"))?
+                .try_into()?,
+            consensus_update: raw
+                .consensus_update
+                .ok_or(DecodingError::missing_raw_data("consensus update"))?
+                .try_into()?,
+            account_update: raw
+                .account_update
+                .ok_or(DecodingError::missing_raw_data("account update"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<Header> for RawHeader {
+    fn from(value: Header) -> Self {
+        Self {
+            trusted_height: Some(value.trusted_height.into()),
+            trusted_sync_committee: Some(value.trusted_sync_committee.into()),
+            consensus_update: Some(value.consensus_update.into()),
+            account_update: Some(value.account_update.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let ETHEREUM_HEADER_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawHeader>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: ETHEREUM_HEADER_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        Self {
+            type_url: ETHEREUM_HEADER_TYPE_URL.to_string(),
+            value: Protobuf::<RawHeader>::encode_vec(header),
+        }
+    }
+}
+
+/// Two headers, each signed by a trusted sync committee, that finalize
+/// conflicting beacon blocks at the same slot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Misbehaviour {
+    pub header_1: Header,
+    pub header_2: Header,
+}
+
+impl Protobuf<RawMisbehaviour> for Misbehaviour {}
+
+impl TryFrom<RawMisbehaviour> for Misbehaviour {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawMisbehaviour) -> Result<Self, Self::Error> {
+        Ok(Self {
+            header_1: raw
+                .header_1
+                .ok_or(DecodingError::missing_raw_data("misbehaviour header 1"))?
+                .try_into()?,
+            header_2: raw
+                .header_2
+                .ok_or(DecodingError::missing_raw_data("misbehaviour header 2"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<Misbehaviour> for RawMisbehaviour {
+    fn from(value: Misbehaviour) -> Self {
+        Self {
+            header_1: Some(value.header_1.into()),
+            header_2: Some(value.header_2.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for Misbehaviour {}
+
+impl TryFrom<Any> for Misbehaviour {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let ETHEREUM_MISBEHAVIOUR_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawMisbehaviour>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: ETHEREUM_MISBEHAVIOUR_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<Misbehaviour> for Any {
+    fn from(misbehaviour: Misbehaviour) -> Self {
+        Self {
+            type_url: ETHEREUM_MISBEHAVIOUR_TYPE_URL.to_string(),
+            value: Protobuf::<RawMisbehaviour>::encode_vec(misbehaviour),
+        }
+    }
+}
+
+/// A Merkle-Patricia proof of a storage slot of the IBC contract, carried as
+/// the commitment proof bytes of membership and non-membership checks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageProof {
+    pub proof: Vec<Vec<u8>>,
+}
+
+impl Protobuf<RawStorageProof> for StorageProof {}
+
+impl TryFrom<RawStorageProof> for StorageProof {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawStorageProof) -> Result<Self, Self::Error> {
+        Ok(Self { proof: raw.proof })
+    }
+}
+
+impl From<StorageProof> for RawStorageProof {
+    fn from(value: StorageProof) -> Self {
+        Self { proof: value.proof }
+    }
+}
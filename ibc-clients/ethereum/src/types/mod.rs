@@ -0,0 +1,40 @@
+mod beacon;
+mod fork;
+mod header;
+pub mod proto;
+
+pub use beacon::*;
+pub use fork::*;
+pub use header::*;
+use ibc_core::host::types::error::DecodingError;
+
+use crate::ssz::Bytes32;
+
+pub type Bytes4 = [u8; 4];
+pub type Bytes20 = [u8; 20];
+pub type Bytes48 = [u8; 48];
+pub type Bytes96 = [u8; 96];
+
+/// Converts raw bytes into a fixed-size array, failing if the length does not
+/// match.
+pub(crate) fn fixed_bytes<const N: usize>(
+    name: &str,
+    bytes: Vec<u8>,
+) -> Result<[u8; N], DecodingError> {
+    let actual = bytes.len();
+
+    bytes.try_into().map_err(|_| {
+        DecodingError::invalid_raw_data(format!(
+            "{name} must be {N} bytes long, found {actual} bytes"
+        ))
+    })
+}
+
+/// Converts a list of raw Merkle branch nodes into 32-byte chunks.
+pub(crate) fn branch(name: &str, nodes: Vec<Vec<u8>>) -> Result<Vec<Bytes32>, DecodingError> {
+    nodes.into_iter().map(|n| fixed_bytes(name, n)).collect()
+}
+
+pub(crate) fn branch_to_raw(branch: Vec<Bytes32>) -> Vec<Vec<u8>> {
+    branch.into_iter().map(Vec::from).collect()
+}
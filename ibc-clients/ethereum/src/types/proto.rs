@@ -0,0 +1,215 @@
+//! Protobuf definitions of the Ethereum light client types, under the
+//! `ibc.lightclients.ethereum.v1` package.
+use ibc_core::client::types::proto::v1::Height as RawHeight;
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Fork {
+    #[prost(bytes = "vec", tag = "1")]
+    pub version: Vec<u8>,
+    #[prost(uint64, tag = "2")]
+    pub epoch: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ForkParameters {
+    #[prost(bytes = "vec", tag = "1")]
+    pub genesis_fork_version: Vec<u8>,
+    #[prost(uint64, tag = "2")]
+    pub genesis_slot: u64,
+    #[prost(message, optional, tag = "3")]
+    pub altair: Option<Fork>,
+    #[prost(message, optional, tag = "4")]
+    pub bellatrix: Option<Fork>,
+    #[prost(message, optional, tag = "5")]
+    pub capella: Option<Fork>,
+    #[prost(message, optional, tag = "6")]
+    pub deneb: Option<Fork>,
+    #[prost(message, optional, tag = "7")]
+    pub electra: Option<Fork>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ClientState {
+    #[prost(uint64, tag = "1")]
+    pub chain_id: u64,
+    #[prost(bytes = "vec", tag = "2")]
+    pub genesis_validators_root: Vec<u8>,
+    #[prost(uint64, tag = "3")]
+    pub genesis_time: u64,
+    #[prost(message, optional, tag = "4")]
+    pub fork_parameters: Option<ForkParameters>,
+    #[prost(uint64, tag = "5")]
+    pub seconds_per_slot: u64,
+    #[prost(uint64, tag = "6")]
+    pub slots_per_epoch: u64,
+    #[prost(uint64, tag = "7")]
+    pub epochs_per_sync_committee_period: u64,
+    #[prost(uint64, tag = "8")]
+    pub sync_committee_size: u64,
+    #[prost(uint64, tag = "9")]
+    pub trusting_period: u64,
+    #[prost(uint64, tag = "10")]
+    pub latest_slot: u64,
+    #[prost(message, optional, tag = "11")]
+    pub latest_height: Option<RawHeight>,
+    #[prost(message, optional, tag = "12")]
+    pub frozen_height: Option<RawHeight>,
+    #[prost(bytes = "vec", tag = "13")]
+    pub ibc_contract_address: Vec<u8>,
+    #[prost(bytes = "vec", tag = "14")]
+    pub ibc_commitment_slot: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ConsensusState {
+    #[prost(uint64, tag = "1")]
+    pub slot: u64,
+    #[prost(bytes = "vec", tag = "2")]
+    pub state_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub storage_root: Vec<u8>,
+    #[prost(uint64, tag = "4")]
+    pub timestamp: u64,
+    #[prost(bytes = "vec", tag = "5")]
+    pub current_sync_committee: Vec<u8>,
+    #[prost(bytes = "vec", tag = "6")]
+    pub next_sync_committee: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct BeaconBlockHeader {
+    #[prost(uint64, tag = "1")]
+    pub slot: u64,
+    #[prost(uint64, tag = "2")]
+    pub proposer_index: u64,
+    #[prost(bytes = "vec", tag = "3")]
+    pub parent_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub state_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "5")]
+    pub body_root: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ExecutionPayloadHeader {
+    #[prost(bytes = "vec", tag = "1")]
+    pub parent_hash: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub fee_recipient: Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub state_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub receipts_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "5")]
+    pub logs_bloom: Vec<u8>,
+    #[prost(bytes = "vec", tag = "6")]
+    pub prev_randao: Vec<u8>,
+    #[prost(uint64, tag = "7")]
+    pub block_number: u64,
+    #[prost(uint64, tag = "8")]
+    pub gas_limit: u64,
+    #[prost(uint64, tag = "9")]
+    pub gas_used: u64,
+    #[prost(uint64, tag = "10")]
+    pub timestamp: u64,
+    #[prost(bytes = "vec", tag = "11")]
+    pub extra_data: Vec<u8>,
+    #[prost(bytes = "vec", tag = "12")]
+    pub base_fee_per_gas: Vec<u8>,
+    #[prost(bytes = "vec", tag = "13")]
+    pub block_hash: Vec<u8>,
+    #[prost(bytes = "vec", tag = "14")]
+    pub transactions_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "15")]
+    pub withdrawals_root: Vec<u8>,
+    #[prost(uint64, tag = "16")]
+    pub blob_gas_used: u64,
+    #[prost(uint64, tag = "17")]
+    pub excess_blob_gas: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct LightClientHeader {
+    #[prost(message, optional, tag = "1")]
+    pub beacon: Option<BeaconBlockHeader>,
+    #[prost(message, optional, tag = "2")]
+    pub execution: Option<ExecutionPayloadHeader>,
+    #[prost(bytes = "vec", repeated, tag = "3")]
+    pub execution_branch: Vec<Vec<u8>>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SyncCommittee {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub pubkeys: Vec<Vec<u8>>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub aggregate_pubkey: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SyncAggregate {
+    #[prost(bytes = "vec", tag = "1")]
+    pub sync_committee_bits: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub sync_committee_signature: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct LightClientUpdate {
+    #[prost(message, optional, tag = "1")]
+    pub attested_header: Option<LightClientHeader>,
+    #[prost(message, optional, tag = "2")]
+    pub next_sync_committee: Option<SyncCommittee>,
+    #[prost(bytes = "vec", repeated, tag = "3")]
+    pub next_sync_committee_branch: Vec<Vec<u8>>,
+    #[prost(message, optional, tag = "4")]
+    pub finalized_header: Option<LightClientHeader>,
+    #[prost(bytes = "vec", repeated, tag = "5")]
+    pub finality_branch: Vec<Vec<u8>>,
+    #[prost(message, optional, tag = "6")]
+    pub sync_aggregate: Option<SyncAggregate>,
+    #[prost(uint64, tag = "7")]
+    pub signature_slot: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct TrustedSyncCommittee {
+    #[prost(message, optional, tag = "1")]
+    pub sync_committee: Option<SyncCommittee>,
+    #[prost(bool, tag = "2")]
+    pub is_next: bool,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct AccountUpdate {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub account_proof: Vec<Vec<u8>>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub storage_root: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Header {
+    #[prost(message, optional, tag = "1")]
+    pub trusted_height: Option<RawHeight>,
+    #[prost(message, optional, tag = "2")]
+    pub trusted_sync_committee: Option<TrustedSyncCommittee>,
+    #[prost(message, optional, tag = "3")]
+    pub consensus_update: Option<LightClientUpdate>,
+    #[prost(message, optional, tag = "4")]
+    pub account_update: Option<AccountUpdate>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Misbehaviour {
+    #[prost(message, optional, tag = "1")]
+    pub header_1: Option<Header>,
+    #[prost(message, optional, tag = "2")]
+    pub header_2: Option<Header>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct StorageProof {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub proof: Vec<Vec<u8>>,
+}
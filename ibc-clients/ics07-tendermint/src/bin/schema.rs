@@ -0,0 +1,29 @@
+//! Writes the JSON schema of the messages and responses of the contract to
+//! the `schema` directory of the working directory. Run it from the root of
+//! the crate to update the checked-in copy.
+use std::env;
+use std::fs::{create_dir_all, write};
+
+use cosmwasm_schema::{export_schema, remove_schemas};
+use ibc_client_tendermint_cw::schema;
+
+fn main() {
+    let out_dir = env::current_dir().unwrap().join("schema");
+    let raw_dir = out_dir.join("raw");
+    create_dir_all(&raw_dir).unwrap();
+    remove_schemas(&out_dir).unwrap();
+
+    let api = schema::api().render();
+
+    let path = out_dir.join(format!("{}.json", env!("CARGO_PKG_NAME")));
+    write(&path, api.to_string().unwrap() + "\n").unwrap();
+    println!("Exported the full API as {}", path.display());
+
+    for (name, json) in api.to_schema_files().unwrap() {
+        let path = raw_dir.join(name);
+        write(&path, json + "\n").unwrap();
+        println!("Exported {}", path.display());
+    }
+
+    export_schema(&schema::contract_result(), &raw_dir);
+}
@@ -1,4 +1,4 @@
-use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response};
 use ibc_client_cw::context::Context;
 use ibc_client_cw::types::{ContractError, InstantiateMsg, QueryMsg, SudoMsg};
 
@@ -6,7 +6,7 @@ use crate::client_type::TendermintClient;
 
 pub type TendermintContext<'a> = Context<'a, TendermintClient>;
 
-#[entry_point]
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
 pub fn instantiate(
     deps: DepsMut<'_>,
     env: Env,
@@ -18,14 +18,14 @@ pub fn instantiate(
     Ok(Response::default().set_data(data))
 }
 
-#[entry_point]
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
 pub fn sudo(deps: DepsMut<'_>, env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
     let mut ctx = TendermintContext::new_mut(deps, env)?;
     let data = ctx.sudo(msg)?;
     Ok(Response::default().set_data(data))
 }
 
-#[entry_point]
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
 pub fn query(deps: Deps<'_>, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
     let ctx = TendermintContext::new_ref(deps, env)?;
     ctx.query(msg)
@@ -4,6 +4,7 @@
 
 pub mod client_type;
 pub mod entrypoint;
+pub mod schema;
 
 #[cfg(test)]
 mod tests;
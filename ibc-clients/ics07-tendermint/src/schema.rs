@@ -0,0 +1,20 @@
+//! The JSON schema of the messages and responses of the contract, shared by
+//! the `schema` binary writing it and the test checking the written copy.
+use cosmwasm_schema::schemars::schema::RootSchema;
+use cosmwasm_schema::{generate_api, schema_for, Api};
+use ibc_client_cw::types::{ContractResult, InstantiateMsg, QueryMsg, SudoMsg};
+
+/// The API of the contract, as rendered by `cosmwasm-schema`.
+pub fn api() -> Api {
+    generate_api! {
+        instantiate: InstantiateMsg,
+        sudo: SudoMsg,
+        query: QueryMsg,
+    }
+}
+
+/// The schema of the [`ContractResult`] that the instantiate and sudo entry
+/// points answer with in the response data, which [`api`] has no place for.
+pub fn contract_result() -> RootSchema {
+    schema_for!(ContractResult)
+}
@@ -1,232 +1,184 @@
+use std::collections::BTreeMap;
 use std::time::Duration;
 
-use cosmwasm_std::{from_json, Binary, Deps, DepsMut, Empty, Response, StdError, StdResult};
-use ibc_client_cw::types::{
-    CheckForMisbehaviourMsgRaw, CheckForMisbehaviourResponse, ContractError, InstantiateMsg,
-    MigrationPrefix, QueryMsg, StatusMsg, StatusResponse, UpdateStateMsgRaw,
-    UpdateStateOnMisbehaviourMsgRaw, VerifyClientMessageRaw, VerifyClientMessageResponse,
-};
 use ibc_client_cw::utils::AnyCodec;
+use ibc_client_cw_testkit::api::TestClient;
 use ibc_client_tendermint::client_state::ClientState as TmClientState;
 use ibc_client_tendermint::consensus_state::ConsensusState as TmConsensusState;
-use ibc_client_tendermint::types::Header;
-use ibc_core::client::types::{Height, Status};
-use ibc_core::host::types::identifiers::ChainId;
-use ibc_core::primitives::Timestamp;
+use ibc_client_tendermint::types::{Header, Misbehaviour};
+use ibc_core::client::types::Height;
+use ibc_core::commitment_types::specs::ProofSpecs;
+use ibc_core::host::types::identifiers::{ChainId, ClientId};
+use ibc_core::primitives::{IntoHostTime, Timestamp};
 use ibc_testkit::fixtures::clients::tendermint::ClientStateConfig;
 use tendermint::Time;
-use tendermint_testgen::{Generator, Validator};
+use tendermint_testgen::{Commit, Generator, LightBlock, Validator, Vote};
 
-use crate::entrypoint::TendermintContext;
-use crate::tests::helper::{
-    dummy_checksum, dummy_sov_consensus_state, mock_env_with_timestamp_now,
-};
+use crate::client_type::TendermintClient;
+use crate::tests::helper::{dummy_sov_consensus_state, validator_set};
 
-/// Test fixture
+/// Test fixture of the Tendermint client.
+pub type Fixture = ibc_client_cw_testkit::fixture::Fixture<TendermintClient>;
+
+/// The chain and validators of the generated Tendermint states and headers.
 #[derive(Clone, Debug)]
-pub struct Fixture {
+pub struct TendermintConfig {
     pub chain_id: ChainId,
-    pub trusted_timestamp: Timestamp,
-    pub trusted_height: Height,
+    /// The validator set of the chain from its first block.
     pub validators: Vec<Validator>,
-    pub migration_prefix: MigrationPrefix,
+    /// The validator sets taking over from the given revision heights.
+    pub validator_changes: BTreeMap<u64, Vec<Validator>>,
+    pub trusting_period: Duration,
 }
 
-impl Default for Fixture {
+impl Default for TendermintConfig {
     fn default() -> Self {
         Self {
             chain_id: ChainId::new("test-chain").unwrap(),
-            trusted_timestamp: Timestamp::now(),
-            trusted_height: Height::new(0, 5).unwrap(),
             validators: vec![
                 Validator::new("1").voting_power(40),
                 Validator::new("2").voting_power(30),
                 Validator::new("3").voting_power(30),
             ],
-            migration_prefix: MigrationPrefix::None,
+            validator_changes: BTreeMap::new(),
+            trusting_period: Duration::from_secs(64000),
         }
     }
 }
 
-impl Fixture {
-    pub fn set_migration_prefix(&mut self, migration_mode: MigrationPrefix) {
-        self.migration_prefix = migration_mode;
+impl TendermintConfig {
+    /// Returns the validator set of the chain at the given revision height.
+    pub fn validators_at(&self, height: u64) -> &[Validator] {
+        self.validator_changes
+            .range(..=height)
+            .next_back()
+            .map_or(&self.validators, |(_, validators)| validators)
     }
+}
 
-    pub fn ctx_ref<'a>(&self, deps: Deps<'a, Empty>) -> TendermintContext<'a> {
-        let mut ctx =
-            TendermintContext::new_ref(deps, mock_env_with_timestamp_now()).expect("never fails");
-
-        match self.migration_prefix {
-            MigrationPrefix::None => {}
-            MigrationPrefix::Subject => {
-                ctx.set_subject_prefix();
-            }
-            MigrationPrefix::Substitute => {
-                ctx.set_substitute_prefix();
-            }
-        };
-
-        ctx
-    }
-
-    pub fn ctx_mut<'a>(&self, deps: DepsMut<'a, Empty>) -> TendermintContext<'a> {
-        let mut ctx =
-            TendermintContext::new_mut(deps, mock_env_with_timestamp_now()).expect("never fails");
-
-        match self.migration_prefix {
-            MigrationPrefix::None => {}
-            MigrationPrefix::Subject => {
-                ctx.set_subject_prefix();
-            }
-            MigrationPrefix::Substitute => {
-                ctx.set_substitute_prefix();
+impl TendermintClient {
+    /// Constructs a header at the given height, committed by the given
+    /// signers out of the validator set of the chain at that height, or by
+    /// all of them. It is verified against the consensus state at the trusted
+    /// height of the fixture.
+    pub fn dummy_signed_header(
+        fxt: &Fixture,
+        header_height: Height,
+        header_timestamp: Timestamp,
+        signers: Option<&[Validator]>,
+    ) -> Header {
+        let config = &fxt.config;
+
+        let height = header_height.revision_height();
+
+        let header_time: Time = header_timestamp.into_host_time().expect("never fails");
+
+        let header = tendermint_testgen::Header::new(config.validators_at(height))
+            .chain_id(config.chain_id.as_str())
+            .height(height)
+            .time(header_time)
+            .next_validators(config.validators_at(height + 1))
+            .app_hash(fxt.store.app_hash().try_into().expect("never fails"));
+
+        let commit = match signers {
+            Some(signers) => {
+                let votes = signers
+                    .iter()
+                    .map(|signer| Vote::new(signer.clone(), header.clone()).round(1))
+                    .collect();
+
+                Commit::new_with_votes(header.clone(), 1, votes)
             }
+            None => Commit::new(header.clone(), 1),
         };
 
-        ctx
-    }
-
-    pub fn dummy_instantiate_msg(&self) -> InstantiateMsg {
-        // Setting the `trusting_period` to 1 second allows the quick
-        // client expiry for the tests.
-        let tm_client_state: TmClientState = ClientStateConfig::builder()
-            .trusting_period(Duration::from_secs(1))
-            .build()
-            .into_client_state(self.chain_id.clone(), self.trusted_height)
-            .expect("never fails");
-
-        let tm_consensus_state = dummy_sov_consensus_state(self.trusted_timestamp);
-
-        InstantiateMsg {
-            client_state: TmClientState::encode_to_any_vec(tm_client_state).into(),
-            consensus_state: TmConsensusState::encode_to_any_vec(tm_consensus_state).into(),
-            checksum: dummy_checksum(),
-        }
-    }
-
-    fn dummy_header(&self, header_height: Height) -> Vec<u8> {
-        let header = tendermint_testgen::Header::new(&self.validators)
-            .chain_id(self.chain_id.as_str())
-            .height(header_height.revision_height())
-            .time(Time::now())
-            .next_validators(&self.validators)
-            .app_hash(vec![0; 32].try_into().expect("never fails"));
-
-        let light_block = tendermint_testgen::LightBlock::new_default_with_header(header)
+        let light_block = LightBlock::new(header, commit)
             .generate()
             .expect("failed to generate light block");
 
-        let tm_header = Header {
+        let trusted_height = fxt.trusted_height.revision_height();
+
+        Header {
             signed_header: light_block.signed_header,
             validator_set: light_block.validators,
-            trusted_height: self.trusted_height,
-            trusted_next_validator_set: light_block.next_validators,
-        };
-
-        Header::encode_to_any_vec(tm_header)
+            trusted_height: fxt.trusted_height,
+            trusted_next_validator_set: validator_set(config.validators_at(trusted_height + 1)),
+        }
     }
 
-    pub fn dummy_client_message(&self, target_height: Height) -> Vec<u8> {
-        self.dummy_header(target_height)
+    fn dummy_header(fxt: &Fixture, header_height: Height, header_timestamp: Timestamp) -> Vec<u8> {
+        Header::encode_to_any_vec(Self::dummy_signed_header(
+            fxt,
+            header_height,
+            header_timestamp,
+            None,
+        ))
     }
 
-    /// Constructs a dummy misbehaviour message that is one block behind the
-    /// trusted height, but with a future timestamp.
-    pub fn dummy_misbehaviour_message(&self) -> Vec<u8> {
-        let prev_height = self.trusted_height.decrement().expect("never fails");
+    /// Constructs a misbehaviour made of two headers at the given height,
+    /// both committed by the validators of the chain but produced one second
+    /// apart, as a double signing of conflicting blocks.
+    pub fn dummy_double_signing(fxt: &Fixture, height: Height) -> Vec<u8> {
+        let header_1 = Self::dummy_signed_header(fxt, height, fxt.clock.now(), None);
 
-        self.dummy_header(prev_height)
-    }
+        let later_timestamp = (fxt.clock.now() + Duration::from_secs(1)).expect("never fails");
 
-    pub fn verify_client_message(&self, deps: Deps<'_>, client_message: Vec<u8>) {
-        let resp: VerifyClientMessageResponse = self
-            .query(
-                deps,
-                VerifyClientMessageRaw {
-                    client_message: client_message.into(),
-                },
-            )
-            .and_then(from_json)
-            .unwrap();
-
-        assert!(resp.is_valid);
-    }
+        let header_2 = Self::dummy_signed_header(fxt, height, later_timestamp, None);
 
-    pub fn check_for_misbehaviour(&self, deps: Deps<'_>, client_message: Vec<u8>) {
-        let resp: CheckForMisbehaviourResponse = self
-            .query(
-                deps,
-                CheckForMisbehaviourMsgRaw {
-                    client_message: client_message.into(),
-                },
-            )
-            .and_then(from_json)
-            .unwrap();
-
-        assert!(resp.found_misbehaviour);
+        Misbehaviour::encode_to_any_vec(Misbehaviour::new(
+            ClientId::new("08-wasm", 0).expect("never fails"),
+            header_1,
+            header_2,
+        ))
     }
+}
 
-    pub fn check_client_status(&self, deps: Deps<'_>, expected: Status) {
-        let resp: StatusResponse = self.query(deps, StatusMsg {}).and_then(from_json).unwrap();
+impl TestClient for TendermintClient {
+    type Config = TendermintConfig;
 
-        assert_eq!(resp.status, expected);
+    fn trusting_period(config: &TendermintConfig) -> Duration {
+        config.trusting_period
     }
 
-    pub fn query(&self, deps: Deps<'_>, msg: impl Into<QueryMsg>) -> StdResult<Binary> {
-        let ctx = self.ctx_ref(deps);
+    fn client_state(fxt: &Fixture) -> Vec<u8> {
+        let tm_client_state: TmClientState = ClientStateConfig::builder()
+            .trusting_period(fxt.config.trusting_period)
+            .proof_specs(ProofSpecs::cosmos())
+            .build()
+            .into_client_state(fxt.config.chain_id.clone(), fxt.trusted_height)
+            .expect("never fails");
 
-        ctx.query(msg.into())
-            .map_err(|e| StdError::generic_err(e.to_string()))
+        TmClientState::encode_to_any_vec(tm_client_state)
     }
 
-    pub fn create_client(&self, deps_mut: DepsMut<'_>) -> Result<Response, ContractError> {
-        let mut ctx = self.ctx_mut(deps_mut);
-
-        let instantiate_msg = self.dummy_instantiate_msg();
+    fn consensus_state(fxt: &Fixture) -> Vec<u8> {
+        let next_validators = fxt
+            .config
+            .validators_at(fxt.trusted_height.revision_height() + 1);
 
-        let data = ctx.instantiate(instantiate_msg)?;
+        let tm_consensus_state = dummy_sov_consensus_state(
+            fxt.trusted_timestamp,
+            fxt.store.app_hash(),
+            validator_set(next_validators).hash(),
+        );
 
-        Ok(Response::default().set_data(data))
+        TmConsensusState::encode_to_any_vec(tm_consensus_state)
     }
 
-    pub fn update_client(
-        &self,
-        deps_mut: DepsMut<'_>,
-        target_height: Height,
-    ) -> Result<Response, ContractError> {
-        let client_message = self.dummy_client_message(target_height);
-
-        self.verify_client_message(deps_mut.as_ref(), client_message.clone());
-
-        let mut ctx = self.ctx_mut(deps_mut);
-
-        let data = ctx.sudo(
-            UpdateStateMsgRaw {
-                client_message: client_message.into(),
-            }
-            .into(),
-        )?;
-
-        Ok(Response::default().set_data(data))
+    /// Constructs a header produced at the current time of the fixture clock,
+    /// committing to the app hash of the fixture store.
+    fn header(fxt: &Fixture, target_height: Height) -> Vec<u8> {
+        Self::dummy_header(fxt, target_height, fxt.clock.now())
     }
 
-    pub fn update_client_on_misbehaviour(&self, deps_mut: DepsMut<'_>) -> Response {
-        let client_message = self.dummy_misbehaviour_message();
-
-        self.check_for_misbehaviour(deps_mut.as_ref(), client_message.clone());
-
-        let mut ctx = self.ctx_mut(deps_mut);
+    /// Constructs a dummy misbehaviour message that is one block behind the
+    /// trusted height, but with a future timestamp.
+    fn misbehaviour(fxt: &Fixture) -> Vec<u8> {
+        let prev_height = fxt.trusted_height.decrement().expect("never fails");
 
-        let data = ctx
-            .sudo(
-                UpdateStateOnMisbehaviourMsgRaw {
-                    client_message: client_message.into(),
-                }
-                .into(),
-            )
-            .unwrap();
+        let future_timestamp =
+            (fxt.trusted_timestamp + Duration::from_secs(1)).expect("never fails");
 
-        Response::default().set_data(data)
+        Self::dummy_header(fxt, prev_height, future_timestamp)
     }
 }
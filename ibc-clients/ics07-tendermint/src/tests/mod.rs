@@ -1,17 +1,27 @@
-use std::time::Duration;
-
 use cosmwasm_std::from_json;
-use cosmwasm_std::testing::{mock_dependencies, mock_env};
+use cosmwasm_std::testing::mock_dependencies;
 use ibc_client_cw::types::{
-    ContractResult, MigrateClientStoreMsg, MigrationPrefix, VerifyClientMessageRaw,
-    VerifyClientMessageResponse,
+    ContractResult, MigrateClientStoreMsg, MigrationPrefix, UpdateStateMsgRaw,
+    UpdateStateOnMisbehaviourMsgRaw,
 };
+use ibc_client_cw::utils::AnyCodec;
+use ibc_client_cw_simulator::contract::WasmContract;
+use ibc_client_cw_simulator::error::SimulatorError;
+use ibc_client_cw_simulator::simulator::Simulator;
+use ibc_client_cw_testkit::api::TestClient;
+use ibc_client_cw_testkit::scenarios::{self, BLOCK_TIME};
+use ibc_client_cw_testkit::store::IBC_STORE;
+use ibc_client_tendermint::types::{Header, Misbehaviour};
 use ibc_core::client::types::{Height, Status};
+use ibc_core::host::types::identifiers::ClientId;
+use tendermint_testgen::Validator;
 
-use crate::entrypoint::sudo;
+use crate::client_type::TendermintClient;
+use crate::entrypoint::{instantiate, query, sudo};
 
 pub mod fixture;
 pub mod helper;
+pub mod schema;
 
 use fixture::Fixture;
 
@@ -34,7 +44,7 @@ fn test_cw_create_client_ok() {
 
 #[test]
 fn test_cw_update_client_ok() {
-    let fxt = Fixture::default();
+    let mut fxt = Fixture::default();
 
     let mut deps = mock_dependencies();
 
@@ -44,6 +54,8 @@ fn test_cw_update_client_ok() {
 
     // ------------------- Verify and Update client -------------------
 
+    fxt.clock.advance(5, 5 * BLOCK_TIME);
+
     let target_height = Height::new(0, 10).unwrap();
 
     let resp = fxt.update_client(deps.as_mut(), target_height).unwrap();
@@ -83,7 +95,12 @@ fn test_cw_recovery_client_ok() {
 
     // ------------------- Recover subject client -------------------
 
-    let resp = sudo(deps.as_mut(), mock_env(), MigrateClientStoreMsg {}.into()).unwrap();
+    let resp = sudo(
+        deps.as_mut(),
+        fxt.clock.env(),
+        MigrateClientStoreMsg {}.into(),
+    )
+    .unwrap();
 
     assert_eq!(0, resp.messages.len());
 
@@ -92,7 +109,7 @@ fn test_cw_recovery_client_ok() {
 
 #[test]
 fn test_cw_client_expiry() {
-    let fxt = Fixture::default();
+    let mut fxt = Fixture::default();
 
     let mut deps = mock_dependencies();
 
@@ -102,7 +119,9 @@ fn test_cw_client_expiry() {
 
     // ------------------- Expire client -------------------
 
-    std::thread::sleep(Duration::from_millis(1200));
+    let trusting_period = TendermintClient::trusting_period(&fxt.config);
+
+    fxt.clock.advance(1, trusting_period + BLOCK_TIME);
 
     // ------------------- Try update client -------------------
 
@@ -110,19 +129,385 @@ fn test_cw_client_expiry() {
 
     let client_message = fxt.dummy_client_message(target_height);
 
-    let resp: VerifyClientMessageResponse = fxt
-        .query(
-            deps.as_ref(),
-            VerifyClientMessageRaw {
-                client_message: client_message.into(),
-            },
+    assert!(!fxt.is_valid_client_message(deps.as_ref(), client_message));
+
+    // ------------------- Check client status -------------------
+
+    fxt.check_client_status(deps.as_ref(), Status::Expired);
+}
+
+#[test]
+fn test_cw_verify_membership() {
+    let mut fxt = Fixture::default();
+
+    let path = b"connections/connection-0";
+    let absent_path = b"connections/connection-1";
+
+    fxt.store.set(IBC_STORE, path, b"connection-end");
+    fxt.store
+        .set(IBC_STORE, b"clients/07-tendermint-0", b"client");
+    fxt.store.commit();
+
+    let mut deps = mock_dependencies();
+
+    // ------------------- Create client -------------------
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    // ------------------- Verify at the trusted height -------------------
+
+    let height = fxt.trusted_height;
+
+    fxt.verify_membership(
+        deps.as_mut(),
+        fxt.dummy_verify_membership_msg(height, path, b"connection-end"),
+    )
+    .unwrap();
+
+    assert!(fxt
+        .verify_membership(
+            deps.as_mut(),
+            fxt.dummy_verify_membership_msg(height, path, b"other-connection-end"),
+        )
+        .is_err());
+
+    fxt.verify_non_membership(
+        deps.as_mut(),
+        fxt.dummy_verify_non_membership_msg(height, absent_path),
+    )
+    .unwrap();
+
+    // ------------------- Verify at an updated height -------------------
+
+    fxt.store.set(IBC_STORE, absent_path, b"connection-end");
+    fxt.store.commit();
+
+    fxt.clock.advance(5, 5 * BLOCK_TIME);
+
+    let target_height = height.add(5);
+
+    fxt.update_client(deps.as_mut(), target_height).unwrap();
+
+    fxt.verify_membership(
+        deps.as_mut(),
+        fxt.dummy_verify_membership_msg(target_height, absent_path, b"connection-end"),
+    )
+    .unwrap();
+
+    // the proof of the latest commit does not verify at the trusted height
+    assert!(fxt
+        .verify_membership(
+            deps.as_mut(),
+            fxt.dummy_verify_membership_msg(height, absent_path, b"connection-end"),
         )
-        .and_then(from_json)
+        .is_err());
+}
+
+fn signed_header(fxt: &Fixture, height: Height, signers: &[Validator]) -> Vec<u8> {
+    Header::encode_to_any_vec(TendermintClient::dummy_signed_header(
+        fxt,
+        height,
+        fxt.clock.now(),
+        Some(signers),
+    ))
+}
+
+#[test]
+fn test_cw_validator_set_rotation() {
+    let mut fxt = Fixture::default();
+
+    let trusted_height = fxt.trusted_height;
+
+    // the whole validator set is replaced two blocks after the trusted height
+    fxt.config.validator_changes.insert(
+        trusted_height.revision_height() + 2,
+        vec![
+            Validator::new("4").voting_power(50),
+            Validator::new("5").voting_power(50),
+        ],
+    );
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    // ------------------- Update to the handover block -------------------
+
+    fxt.clock.advance(1, BLOCK_TIME);
+
+    fxt.update_client(deps.as_mut(), trusted_height.increment())
         .unwrap();
 
-    assert!(!resp.is_valid);
+    // ------------------- Update to the rotated set -------------------
 
-    // ------------------- Check client status -------------------
+    fxt.clock.advance(1, BLOCK_TIME);
 
-    fxt.check_client_status(deps.as_ref(), Status::Expired);
+    let target_height = trusted_height.add(2);
+
+    // the rotated set shares no voting power with the set trusted at the
+    // initial height
+    assert!(!fxt.is_valid_client_message(deps.as_ref(), fxt.dummy_client_message(target_height)));
+
+    // but the handover block commits to it as the next validator set
+    fxt.trusted_height = trusted_height.increment();
+
+    let resp = fxt.update_client(deps.as_mut(), target_height).unwrap();
+
+    let contract_result: ContractResult = from_json(resp.data.unwrap()).unwrap();
+
+    assert_eq!(contract_result.heights, Some(vec![target_height]));
+}
+
+#[test]
+fn test_cw_non_adjacent_update_trust_threshold() {
+    let mut fxt = Fixture::default();
+
+    let trusted_height = fxt.trusted_height;
+
+    let rotated_validators = vec![
+        Validator::new("1").voting_power(40),
+        Validator::new("4").voting_power(30),
+        Validator::new("5").voting_power(30),
+    ];
+
+    fxt.config
+        .validator_changes
+        .insert(trusted_height.revision_height() + 3, rotated_validators);
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    fxt.clock.advance(5, 5 * BLOCK_TIME);
+
+    // ------------------- Overlap above the trust threshold -------------------
+
+    // the validator kept in the set holds 40% of the trusted voting power,
+    // above the default 1/3 trust level
+    let target_height = trusted_height.add(5);
+
+    fxt.update_client(deps.as_mut(), target_height).unwrap();
+
+    // ------------------- Overlap below the trust threshold -------------------
+
+    let mut below_threshold_fxt = fxt.clone();
+
+    below_threshold_fxt.config.validator_changes.insert(
+        trusted_height.revision_height() + 8,
+        vec![
+            Validator::new("2").voting_power(30),
+            Validator::new("6").voting_power(35),
+            Validator::new("7").voting_power(35),
+        ],
+    );
+
+    below_threshold_fxt.clock.advance(5, 5 * BLOCK_TIME);
+
+    // only validator 2 signs out of the set trusted at the initial height,
+    // with 30% of its voting power
+    assert!(!below_threshold_fxt.is_valid_client_message(
+        deps.as_ref(),
+        below_threshold_fxt.dummy_client_message(trusted_height.add(10))
+    ));
+
+    // and none of the set trusted at the updated height signs
+    below_threshold_fxt.trusted_height = target_height;
+
+    assert!(!below_threshold_fxt.is_valid_client_message(
+        deps.as_ref(),
+        below_threshold_fxt.dummy_client_message(trusted_height.add(10))
+    ));
+
+    // whereas a set keeping 60% of the voting power trusted at the updated
+    // height is accepted
+    below_threshold_fxt.config.validator_changes.insert(
+        trusted_height.revision_height() + 8,
+        vec![
+            Validator::new("4").voting_power(30),
+            Validator::new("5").voting_power(30),
+            Validator::new("6").voting_power(40),
+        ],
+    );
+
+    assert!(below_threshold_fxt.is_valid_client_message(
+        deps.as_ref(),
+        below_threshold_fxt.dummy_client_message(trusted_height.add(10))
+    ));
+}
+
+#[test]
+fn test_cw_header_missing_signatures() {
+    let mut fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    fxt.clock.advance(1, BLOCK_TIME);
+
+    let target_height = fxt.trusted_height.increment();
+
+    let validators = fxt.config.validators.clone();
+
+    // 40% of the voting power is short of the 2/3 commit quorum
+    assert!(!fxt.is_valid_client_message(
+        deps.as_ref(),
+        signed_header(&fxt, target_height, &validators[..1])
+    ));
+
+    // while 70% reaches it
+    let client_message = signed_header(&fxt, target_height, &validators[..2]);
+
+    assert!(fxt.is_valid_client_message(deps.as_ref(), client_message.clone()));
+
+    let resp = sudo(
+        deps.as_mut(),
+        fxt.clock.env(),
+        UpdateStateMsgRaw {
+            client_message: client_message.into(),
+        }
+        .into(),
+    )
+    .unwrap();
+
+    let contract_result: ContractResult = from_json(resp.data.unwrap()).unwrap();
+
+    assert_eq!(contract_result.heights, Some(vec![target_height]));
+}
+
+#[test]
+fn test_cw_double_signing_misbehaviour() {
+    let mut fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    fxt.clock.advance(1, BLOCK_TIME);
+
+    let height = fxt.trusted_height.increment();
+
+    // ------------------- Identical headers -------------------
+
+    let header = TendermintClient::dummy_signed_header(&fxt, height, fxt.clock.now(), None);
+
+    assert!(!fxt.found_misbehaviour(
+        deps.as_ref(),
+        Misbehaviour::encode_to_any_vec(Misbehaviour::new(
+            ClientId::new("08-wasm", 0).unwrap(),
+            header.clone(),
+            header,
+        )),
+    ));
+
+    // ------------------- Conflicting headers -------------------
+
+    let misbehaviour = TendermintClient::dummy_double_signing(&fxt, height);
+
+    fxt.verify_client_message(deps.as_ref(), misbehaviour.clone());
+
+    fxt.check_for_misbehaviour(deps.as_ref(), misbehaviour.clone());
+
+    sudo(
+        deps.as_mut(),
+        fxt.clock.env(),
+        UpdateStateOnMisbehaviourMsgRaw {
+            client_message: misbehaviour.into(),
+        }
+        .into(),
+    )
+    .unwrap();
+
+    fxt.check_client_status(deps.as_ref(), Status::Frozen);
+}
+
+#[test]
+fn test_cw_testkit_scenarios() {
+    scenarios::run_all(&Fixture::default());
+}
+
+#[test]
+fn test_cw_simulator_end_to_end() {
+    let mut fxt = Fixture::default();
+
+    let mut sim = Simulator::new();
+
+    let checksum = sim.store_code(
+        b"tendermint",
+        WasmContract {
+            instantiate,
+            sudo,
+            query,
+        },
+    );
+
+    let create_client = |sim: &mut Simulator, fxt: &Fixture| {
+        sim.create_client(
+            &checksum,
+            TendermintClient::client_state(fxt),
+            TendermintClient::consensus_state(fxt),
+            fxt.trusted_height,
+        )
+        .unwrap()
+    };
+
+    // ------------------- Create and update the subject client -------------------
+
+    let subject = create_client(&mut sim, &fxt);
+
+    sim.clock_mut().advance(5, 5 * BLOCK_TIME);
+
+    // the headers are produced at the time of the simulated chain
+    fxt.clock = sim.clock().clone();
+
+    let target_height = fxt.trusted_height.add(5);
+
+    let heights = sim
+        .update_client(&subject, fxt.dummy_client_message(target_height))
+        .unwrap();
+
+    assert_eq!(heights, vec![target_height]);
+
+    // ------------------- Expire the subject client -------------------
+
+    let trusting_period = TendermintClient::trusting_period(&fxt.config);
+
+    sim.clock_mut().advance(1, trusting_period + BLOCK_TIME);
+
+    fxt.clock = sim.clock().clone();
+
+    assert_eq!(sim.status(&subject).unwrap(), Status::Expired);
+
+    assert!(matches!(
+        sim.update_client(
+            &subject,
+            fxt.dummy_client_message(target_height.increment())
+        ),
+        Err(SimulatorError::ClientNotActive { .. })
+    ));
+
+    // ------------------- Recover it with a substitute client -------------------
+
+    let substitute_fxt = Fixture {
+        trusted_timestamp: sim.clock().now(),
+        trusted_height: target_height.add(5),
+        ..fxt.clone()
+    };
+
+    let substitute = create_client(&mut sim, &substitute_fxt);
+
+    // an active client cannot be recovered
+    assert!(matches!(
+        sim.recover_client(&substitute, &subject),
+        Err(SimulatorError::SubjectActive { .. })
+    ));
+
+    sim.recover_client(&subject, &substitute).unwrap();
+
+    assert_eq!(sim.status(&subject).unwrap(), Status::Active);
+
+    assert_eq!(
+        sim.client_state(&subject).unwrap().latest_height,
+        substitute_fxt.trusted_height
+    );
 }
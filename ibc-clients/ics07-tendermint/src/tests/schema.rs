@@ -0,0 +1,36 @@
+use std::fs;
+use std::path::Path;
+
+use crate::schema;
+
+fn assert_up_to_date(path: &Path, generated: String) {
+    let checked_in = fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("failed to read {}: {e}", path.display()));
+
+    assert!(
+        checked_in == generated + "\n",
+        "{} is out of date, run `cargo run --bin schema` from `ibc-clients/ics07-tendermint`",
+        path.display()
+    );
+}
+
+#[test]
+fn test_schema_up_to_date() {
+    let schema_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("schema");
+
+    let api = schema::api().render();
+
+    assert_up_to_date(
+        &schema_dir.join(format!("{}.json", env!("CARGO_PKG_NAME"))),
+        api.to_string().unwrap(),
+    );
+
+    for (name, generated) in api.to_schema_files().unwrap() {
+        assert_up_to_date(&schema_dir.join("raw").join(name), generated);
+    }
+
+    assert_up_to_date(
+        &schema_dir.join("raw").join("contract_result.json"),
+        serde_json::to_string_pretty(&schema::contract_result()).unwrap(),
+    );
+}
@@ -0,0 +1,492 @@
+//! Implements the mock `ClientState` used by the contract. It extends the
+//! `ibc-testkit` [`MockClientState`] with a flag that decides whether
+//! membership and upgrade proofs are accepted unconditionally or rejected.
+use core::time::Duration;
+
+use ibc_core::client::context::client_state::{
+    ClientStateCommon, ClientStateExecution, ClientStateValidation,
+};
+use ibc_core::client::context::prelude::*;
+use ibc_core::client::types::error::{ClientError, UpgradeClientError};
+use ibc_core::client::types::{Height, Status};
+use ibc_core::commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::host::types::identifiers::{ClientId, ClientType};
+use ibc_core::host::types::path::{ClientConsensusStatePath, ClientStatePath, Path, PathBytes};
+use ibc_core::primitives::proto::{Any, Protobuf};
+use ibc_core::primitives::Timestamp;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::{
+    client_type as mock_client_type, MockClientState,
+};
+use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use ibc_testkit::testapp::ibc::clients::mock::header::{MockHeader, MOCK_HEADER_TYPE_URL};
+use ibc_testkit::testapp::ibc::clients::mock::misbehaviour::{
+    Misbehaviour, MOCK_MISBEHAVIOUR_TYPE_URL,
+};
+use ibc_testkit::testapp::ibc::clients::mock::proto::Header as RawMockHeader;
+
+pub const MOCK_CW_CLIENT_STATE_TYPE_URL: &str = "/ibc.mock.cw.ClientState";
+
+/// The raw representation of the mock [`ClientState`]. The first three fields
+/// are wire-compatible with the `ibc.mock.ClientState` message.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct RawClientState {
+    #[prost(message, optional, tag = "1")]
+    pub header: Option<RawMockHeader>,
+    #[prost(uint64, tag = "2")]
+    pub trusting_period: u64,
+    #[prost(bool, tag = "3")]
+    pub frozen: bool,
+    #[prost(bool, tag = "4")]
+    pub accept_all_proofs: bool,
+}
+
+/// The mock client state stored by the contract.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ClientState {
+    inner: MockClientState,
+    accept_all_proofs: bool,
+}
+
+impl ClientState {
+    /// Initializes a new mock client state at the given header, which accepts
+    /// all proofs by default.
+    pub fn new(header: MockHeader) -> Self {
+        Self {
+            inner: MockClientState::new(header),
+            accept_all_proofs: true,
+        }
+    }
+
+    pub fn inner(&self) -> &MockClientState {
+        &self.inner
+    }
+
+    pub fn with_trusting_period(self, trusting_period: Duration) -> Self {
+        Self {
+            inner: self.inner.with_trusting_period(trusting_period),
+            ..self
+        }
+    }
+
+    /// Sets whether the client accepts all membership, non-membership and
+    /// upgrade proofs. When disabled, every proof is rejected.
+    pub fn with_accept_all_proofs(self, accept_all_proofs: bool) -> Self {
+        Self {
+            accept_all_proofs,
+            ..self
+        }
+    }
+
+    pub fn accept_all_proofs(&self) -> bool {
+        self.accept_all_proofs
+    }
+
+    pub fn frozen(self) -> Self {
+        Self {
+            inner: self.inner.frozen(),
+            ..self
+        }
+    }
+
+    pub fn unfrozen(self) -> Self {
+        Self {
+            inner: self.inner.unfrozen(),
+            ..self
+        }
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.inner.is_frozen()
+    }
+
+    /// Returns a copy of the client state advanced to the given header, if the
+    /// header is higher than the latest height.
+    fn with_header(self, header: MockHeader) -> Self {
+        if header.height() <= self.latest_height() {
+            return self;
+        }
+
+        Self {
+            inner: MockClientState {
+                header,
+                ..self.inner
+            },
+            ..self
+        }
+    }
+
+    fn check_proofs_accepted(&self) -> Result<(), ClientError> {
+        if self.accept_all_proofs {
+            return Ok(());
+        }
+
+        Err(ClientError::ClientSpecific {
+            description: "mock client is configured to reject all proofs".to_string(),
+        })
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        Ok(Self {
+            inner: MockClientState {
+                header: raw
+                    .header
+                    .ok_or(DecodingError::missing_raw_data("mock client state header"))?
+                    .try_into()?,
+                trusting_period: Duration::from_nanos(raw.trusting_period),
+                frozen: raw.frozen,
+            },
+            accept_all_proofs: raw.accept_all_proofs,
+        })
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            header: Some(value.inner.header.into()),
+            trusting_period: value
+                .inner
+                .trusting_period
+                .as_nanos()
+                .try_into()
+                .expect("no overflow"),
+            frozen: value.inner.frozen,
+            accept_all_proofs: value.accept_all_proofs,
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let MOCK_CW_CLIENT_STATE_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawClientState>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: MOCK_CW_CLIENT_STATE_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Self {
+            type_url: MOCK_CW_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientState>::encode_vec(client_state),
+        }
+    }
+}
+
+impl ClientStateCommon for ClientState {
+    fn verify_consensus_state(
+        &self,
+        consensus_state: Any,
+        host_timestamp: &Timestamp,
+    ) -> Result<(), ClientError> {
+        self.inner
+            .verify_consensus_state(consensus_state, host_timestamp)
+    }
+
+    fn client_type(&self) -> ClientType {
+        mock_client_type()
+    }
+
+    fn latest_height(&self) -> Height {
+        self.inner.latest_height()
+    }
+
+    fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError> {
+        self.inner.validate_proof_height(proof_height)
+    }
+
+    fn serialize_path(&self, path: Path) -> Result<PathBytes, ClientError> {
+        Ok(path.to_string().into_bytes().into())
+    }
+
+    fn verify_upgrade_client(
+        &self,
+        upgraded_client_state: Any,
+        upgraded_consensus_state: Any,
+        _proof_upgrade_client: CommitmentProofBytes,
+        _proof_upgrade_consensus_state: CommitmentProofBytes,
+        _root: &CommitmentRoot,
+    ) -> Result<(), ClientError> {
+        let upgraded_client_state = Self::try_from(upgraded_client_state)?;
+        MockConsensusState::try_from(upgraded_consensus_state)?;
+
+        if self.latest_height() >= upgraded_client_state.latest_height() {
+            return Err(UpgradeClientError::InsufficientUpgradeHeight {
+                upgraded_height: upgraded_client_state.latest_height(),
+                client_height: self.latest_height(),
+            })?;
+        }
+
+        self.check_proofs_accepted()
+    }
+
+    fn verify_membership_raw(
+        &self,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        _path: PathBytes,
+        _value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        self.check_proofs_accepted()
+    }
+
+    fn verify_non_membership_raw(
+        &self,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProofBytes,
+        _root: &CommitmentRoot,
+        _path: PathBytes,
+    ) -> Result<(), ClientError> {
+        self.check_proofs_accepted()
+    }
+}
+
+impl<V> ClientStateValidation<V> for ClientState
+where
+    V: ExtClientValidationContext,
+    MockConsensusState: Convertible<V::ConsensusStateRef>,
+    <MockConsensusState as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    /// Accepts any well-formed mock header or misbehaviour.
+    fn verify_client_message(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        match client_message.type_url.as_str() {
+            MOCK_HEADER_TYPE_URL => {
+                MockHeader::try_from(client_message)?;
+            }
+            MOCK_MISBEHAVIOUR_TYPE_URL => {
+                Misbehaviour::try_from(client_message)?;
+            }
+            header_type => return Err(ClientError::InvalidHeaderType(header_type.to_owned())),
+        }
+
+        Ok(())
+    }
+
+    /// Reports misbehaviour for any mock misbehaviour whose two headers share
+    /// the same height.
+    fn check_for_misbehaviour(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        match client_message.type_url.as_str() {
+            MOCK_HEADER_TYPE_URL => Ok(false),
+            MOCK_MISBEHAVIOUR_TYPE_URL => {
+                let misbehaviour = Misbehaviour::try_from(client_message)?;
+
+                Ok(misbehaviour.header1.height() == misbehaviour.header2.height())
+            }
+            header_type => Err(ClientError::InvalidHeaderType(header_type.to_owned())),
+        }
+    }
+
+    fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError> {
+        if self.is_frozen() {
+            return Ok(Status::Frozen);
+        }
+
+        let latest_consensus_state: MockConsensusState = {
+            match ctx.consensus_state(&ClientConsensusStatePath::new(
+                client_id.clone(),
+                self.latest_height().revision_number(),
+                self.latest_height().revision_height(),
+            )) {
+                Ok(cs) => cs.try_into().map_err(Into::into)?,
+                // if the client state does not have an associated consensus
+                // state for its latest height then it must be expired
+                Err(_) => return Ok(Status::Expired),
+            }
+        };
+
+        let now = ctx.host_timestamp()?;
+
+        if let Some(elapsed) = now.duration_since(&latest_consensus_state.timestamp()) {
+            if elapsed >= self.inner.trusting_period {
+                return Ok(Status::Expired);
+            }
+        }
+
+        Ok(Status::Active)
+    }
+
+    fn check_substitute(&self, _ctx: &V, substitute_client_state: Any) -> Result<(), ClientError> {
+        Self::try_from(substitute_client_state)?;
+
+        Ok(())
+    }
+}
+
+impl<E> ClientStateExecution<E> for ClientState
+where
+    E: ExtClientExecutionContext,
+    E::ClientStateRef: From<Self>,
+    MockConsensusState: Convertible<E::ConsensusStateRef>,
+    <MockConsensusState as TryFrom<E::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    fn initialise(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        let mock_consensus_state: MockConsensusState = consensus_state.try_into()?;
+
+        ctx.store_client_state(ClientStatePath::new(client_id.clone()), (*self).into())?;
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                client_id.clone(),
+                self.latest_height().revision_number(),
+                self.latest_height().revision_height(),
+            ),
+            mock_consensus_state.into(),
+        )?;
+        ctx.store_update_meta(
+            client_id.clone(),
+            self.latest_height(),
+            ctx.host_timestamp()?,
+            ctx.host_height()?,
+        )?;
+
+        Ok(())
+    }
+
+    fn update_state(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        let header = MockHeader::try_from(header)?;
+        let header_height = header.height();
+
+        let new_client_state = self.with_header(header);
+
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                client_id.clone(),
+                header_height.revision_number(),
+                header_height.revision_height(),
+            ),
+            MockConsensusState::new(header).into(),
+        )?;
+        ctx.store_client_state(
+            ClientStatePath::new(client_id.clone()),
+            new_client_state.into(),
+        )?;
+        ctx.store_update_meta(
+            client_id.clone(),
+            header_height,
+            ctx.host_timestamp()?,
+            ctx.host_height()?,
+        )?;
+
+        Ok(vec![header_height])
+    }
+
+    fn update_state_on_misbehaviour(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        _client_message: Any,
+    ) -> Result<(), ClientError> {
+        ctx.store_client_state(
+            ClientStatePath::new(client_id.clone()),
+            self.frozen().into(),
+        )?;
+
+        Ok(())
+    }
+
+    fn update_state_on_upgrade(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        upgraded_client_state: Any,
+        upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        let new_client_state = Self::try_from(upgraded_client_state)?;
+        let new_consensus_state: MockConsensusState = upgraded_consensus_state.try_into()?;
+
+        let latest_height = new_client_state.latest_height();
+
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                client_id.clone(),
+                latest_height.revision_number(),
+                latest_height.revision_height(),
+            ),
+            new_consensus_state.into(),
+        )?;
+        ctx.store_client_state(
+            ClientStatePath::new(client_id.clone()),
+            new_client_state.into(),
+        )?;
+        ctx.store_update_meta(
+            client_id.clone(),
+            latest_height,
+            ctx.host_timestamp()?,
+            ctx.host_height()?,
+        )?;
+
+        Ok(latest_height)
+    }
+
+    fn update_on_recovery(
+        &self,
+        ctx: &mut E,
+        subject_client_id: &ClientId,
+        substitute_client_state: Any,
+        substitute_consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        let new_client_state = Self::try_from(substitute_client_state)?.unfrozen();
+        let new_consensus_state: MockConsensusState = substitute_consensus_state.try_into()?;
+
+        let latest_height = new_client_state.latest_height();
+
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                subject_client_id.clone(),
+                latest_height.revision_number(),
+                latest_height.revision_height(),
+            ),
+            new_consensus_state.into(),
+        )?;
+        ctx.store_client_state(
+            ClientStatePath::new(subject_client_id.clone()),
+            new_client_state.into(),
+        )?;
+        ctx.store_update_meta(
+            subject_client_id.clone(),
+            latest_height,
+            ctx.host_timestamp()?,
+            ctx.host_height()?,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,13 @@
+use ibc_client_cw::api::ClientType;
+use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+
+use crate::client_state::ClientState;
+
+/// A unit struct that represents the mock client type.
+#[derive(Clone, Debug)]
+pub struct MockClient;
+
+impl ClientType<'_> for MockClient {
+    type ClientState = ClientState;
+    type ConsensusState = MockConsensusState;
+}
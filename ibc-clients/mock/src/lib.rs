@@ -0,0 +1,12 @@
+//! The CosmWasm contract implementation of a mock light client built on top
+//! of the `ibc-testkit` mock client types. It accepts any header, can be
+//! frozen through a misbehaviour message and can optionally accept all proofs,
+//! which allows exercising 08-wasm flows without real consensus data.
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+
+pub mod client_state;
+pub mod client_type;
+pub mod entrypoint;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,79 @@
+use std::time::Duration;
+
+use ibc_client_cw::utils::AnyCodec;
+use ibc_client_cw_testkit::api::TestClient;
+use ibc_core::client::types::Height;
+use ibc_core::host::types::identifiers::ClientId;
+use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::clients::mock::misbehaviour::Misbehaviour;
+
+use crate::client_state::ClientState;
+use crate::client_type::MockClient;
+
+/// Test fixture of the mock client.
+pub type Fixture = ibc_client_cw_testkit::fixture::Fixture<MockClient>;
+
+/// The parameters of the generated mock client states.
+#[derive(Clone, Debug)]
+pub struct MockConfig {
+    pub trusting_period: Duration,
+    pub accept_all_proofs: bool,
+}
+
+impl Default for MockConfig {
+    fn default() -> Self {
+        Self {
+            trusting_period: Duration::from_secs(64000),
+            accept_all_proofs: true,
+        }
+    }
+}
+
+impl MockClient {
+    fn dummy_mock_header(fxt: &Fixture, height: Height) -> MockHeader {
+        MockHeader::new(height).with_timestamp(fxt.trusted_timestamp)
+    }
+}
+
+impl TestClient for MockClient {
+    type Config = MockConfig;
+
+    fn trusting_period(config: &MockConfig) -> Duration {
+        config.trusting_period
+    }
+
+    fn client_state(fxt: &Fixture) -> Vec<u8> {
+        let header = Self::dummy_mock_header(fxt, fxt.trusted_height);
+
+        let client_state = ClientState::new(header)
+            .with_trusting_period(fxt.config.trusting_period)
+            .with_accept_all_proofs(fxt.config.accept_all_proofs);
+
+        ClientState::encode_to_any_vec(client_state)
+    }
+
+    fn consensus_state(fxt: &Fixture) -> Vec<u8> {
+        let header = Self::dummy_mock_header(fxt, fxt.trusted_height);
+
+        MockConsensusState::encode_to_any_vec(MockConsensusState::new(header))
+    }
+
+    fn header(fxt: &Fixture, target_height: Height) -> Vec<u8> {
+        MockHeader::encode_to_any_vec(Self::dummy_mock_header(fxt, target_height))
+    }
+
+    /// Constructs a dummy misbehaviour message out of two headers at the
+    /// height right after the trusted height.
+    fn misbehaviour(fxt: &Fixture) -> Vec<u8> {
+        let header = Self::dummy_mock_header(fxt, fxt.trusted_height.increment());
+
+        let misbehaviour = Misbehaviour {
+            client_id: ClientId::new("08-wasm", 0).expect("never fails"),
+            header1: header,
+            header2: header,
+        };
+
+        Misbehaviour::encode_to_any_vec(misbehaviour)
+    }
+}
@@ -0,0 +1,28 @@
+use ibc_client_cw::types::{MerklePath, VerifyMembershipMsgRaw, VerifyNonMembershipMsgRaw};
+
+use crate::tests::fixture::Fixture;
+
+pub fn dummy_verify_membership_msg(fxt: &Fixture) -> VerifyMembershipMsgRaw {
+    VerifyMembershipMsgRaw {
+        proof: vec![1].into(),
+        merkle_path: MerklePath {
+            key_path: vec![b"ibc".to_vec().into(), b"key".to_vec().into()],
+        },
+        value: b"value".to_vec().into(),
+        height: fxt.trusted_height.into(),
+        delay_block_period: 0,
+        delay_time_period: 0,
+    }
+}
+
+pub fn dummy_verify_non_membership_msg(fxt: &Fixture) -> VerifyNonMembershipMsgRaw {
+    VerifyNonMembershipMsgRaw {
+        proof: vec![1].into(),
+        merkle_path: MerklePath {
+            key_path: vec![b"ibc".to_vec().into(), b"key".to_vec().into()],
+        },
+        height: fxt.trusted_height.into(),
+        delay_block_period: 0,
+        delay_time_period: 0,
+    }
+}
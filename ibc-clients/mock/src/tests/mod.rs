@@ -0,0 +1,274 @@
+use std::time::Duration;
+
+use cosmwasm_std::from_json;
+use cosmwasm_std::testing::mock_dependencies;
+use ibc_client_cw::types::{ContractResult, MigrateClientStoreMsg, MigrationPrefix};
+use ibc_client_cw_simulator::contract::WasmContract;
+use ibc_client_cw_simulator::simulator::Simulator;
+use ibc_client_cw_testkit::api::TestClient;
+use ibc_client_cw_testkit::scenarios;
+use ibc_core::client::types::{Height, Status};
+
+use crate::client_type::MockClient;
+use crate::entrypoint::{instantiate, query, sudo};
+
+pub mod fixture;
+pub mod helper;
+
+use fixture::{Fixture, MockConfig};
+use helper::{dummy_verify_membership_msg, dummy_verify_non_membership_msg};
+
+#[test]
+fn test_cw_mock_create_client_ok() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    let resp = fxt.create_client(deps.as_mut()).unwrap();
+
+    assert_eq!(0, resp.messages.len());
+
+    let contract_result: ContractResult = from_json(resp.data.unwrap()).unwrap();
+
+    assert!(contract_result.heights.is_none());
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+}
+
+#[test]
+fn test_cw_mock_update_client_ok() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    // ------------------- Create client -------------------
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    // ------------------- Update client with any header -------------------
+
+    for target_height in [Height::new(0, 10).unwrap(), Height::new(0, 3).unwrap()] {
+        let resp = fxt.update_client(deps.as_mut(), target_height).unwrap();
+
+        let contract_result: ContractResult = from_json(resp.data.unwrap()).unwrap();
+
+        assert_eq!(contract_result.heights, Some(vec![target_height]));
+    }
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+}
+
+#[test]
+fn test_cw_mock_freeze_on_misbehaviour() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    fxt.update_client_on_misbehaviour(deps.as_mut());
+
+    fxt.check_client_status(deps.as_ref(), Status::Frozen);
+}
+
+#[test]
+fn test_cw_mock_accept_all_proofs() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    fxt.verify_membership(deps.as_mut(), dummy_verify_membership_msg(&fxt))
+        .unwrap();
+
+    fxt.verify_non_membership(deps.as_mut(), dummy_verify_non_membership_msg(&fxt))
+        .unwrap();
+}
+
+#[test]
+fn test_cw_mock_reject_all_proofs() {
+    let fxt = Fixture::new(MockConfig {
+        accept_all_proofs: false,
+        ..Default::default()
+    });
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    assert!(fxt
+        .verify_membership(deps.as_mut(), dummy_verify_membership_msg(&fxt))
+        .is_err());
+
+    assert!(fxt
+        .verify_non_membership(deps.as_mut(), dummy_verify_non_membership_msg(&fxt))
+        .is_err());
+}
+
+#[test]
+fn test_cw_mock_recovery_client_ok() {
+    let mut fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    // ------------------- Create subject client -------------------
+
+    fxt.set_migration_prefix(MigrationPrefix::Subject);
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    // ------------------- Freeze subject client -------------------
+
+    fxt.update_client_on_misbehaviour(deps.as_mut());
+
+    fxt.check_client_status(deps.as_ref(), Status::Frozen);
+
+    // ------------------- Create substitute client -------------------
+
+    fxt.set_migration_prefix(MigrationPrefix::Substitute);
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    // ------------------- Recover subject client -------------------
+
+    let resp = sudo(
+        deps.as_mut(),
+        fxt.clock.env(),
+        MigrateClientStoreMsg {}.into(),
+    )
+    .unwrap();
+
+    assert_eq!(0, resp.messages.len());
+
+    fxt.set_migration_prefix(MigrationPrefix::Subject);
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+}
+
+#[test]
+fn test_cw_mock_client_expiry() {
+    let trusting_period = Duration::from_secs(60);
+
+    let mut fxt = Fixture::new(MockConfig {
+        trusting_period,
+        ..Default::default()
+    });
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    fxt.clock.advance(1, 2 * trusting_period);
+
+    fxt.check_client_status(deps.as_ref(), Status::Expired);
+}
+
+#[test]
+fn test_cw_mock_testkit_scenarios() {
+    scenarios::run_all(&Fixture::default());
+}
+
+#[test]
+fn test_cw_mock_simulator_end_to_end() {
+    let fxt = Fixture::default();
+
+    let mut sim = Simulator::new();
+
+    let checksum = sim.store_code(
+        b"mock",
+        WasmContract {
+            instantiate,
+            sudo,
+            query,
+        },
+    );
+
+    let create_client = |sim: &mut Simulator, fxt: &Fixture| {
+        sim.create_client(
+            &checksum,
+            MockClient::client_state(fxt),
+            MockClient::consensus_state(fxt),
+            fxt.trusted_height,
+        )
+        .unwrap()
+    };
+
+    // ------------------- Create and update the subject client -------------------
+
+    let subject = create_client(&mut sim, &fxt);
+
+    let target_height = fxt.trusted_height.add(5);
+
+    let heights = sim
+        .update_client(&subject, fxt.dummy_client_message(target_height))
+        .unwrap();
+
+    assert_eq!(heights, vec![target_height]);
+
+    assert_eq!(
+        sim.client_state(&subject).unwrap().latest_height,
+        target_height
+    );
+
+    assert_eq!(
+        sim.timestamp_at_height(&subject, target_height).unwrap(),
+        fxt.trusted_timestamp.nanoseconds()
+    );
+
+    // ------------------- Verify proofs -------------------
+
+    let key_path = vec![b"ibc".to_vec(), b"key".to_vec()];
+
+    sim.verify_membership(
+        &subject,
+        target_height,
+        0,
+        0,
+        vec![1],
+        key_path.clone(),
+        b"value".to_vec(),
+    )
+    .unwrap();
+
+    sim.verify_non_membership(&subject, target_height, 0, 0, vec![1], key_path)
+        .unwrap();
+
+    // ------------------- Freeze the subject client -------------------
+
+    assert!(sim
+        .update_client(&subject, fxt.dummy_misbehaviour_message())
+        .unwrap()
+        .is_empty());
+
+    assert_eq!(sim.status(&subject).unwrap(), Status::Frozen);
+
+    assert!(sim
+        .update_client(
+            &subject,
+            fxt.dummy_client_message(target_height.increment())
+        )
+        .is_err());
+
+    // ------------------- Recover it with a substitute client -------------------
+
+    let substitute = create_client(
+        &mut sim,
+        &Fixture {
+            trusted_height: target_height.add(5),
+            ..Fixture::default()
+        },
+    );
+
+    sim.recover_client(&subject, &substitute).unwrap();
+
+    assert_eq!(sim.status(&subject).unwrap(), Status::Active);
+
+    assert_eq!(
+        sim.client_state(&subject).unwrap().latest_height,
+        target_height.add(5)
+    );
+
+    sim.update_client(&subject, fxt.dummy_client_message(target_height.add(10)))
+        .unwrap();
+}
@@ -0,0 +1,146 @@
+use ibc_client_ethereum_cw::mpt::{keccak256, trim_leading_zeros, verify_proof, RlpItem};
+use ibc_client_ethereum_cw::ssz::Bytes32;
+use ibc_client_ethereum_cw::types::proto::StorageProof as RawStorageProof;
+use ibc_client_ethereum_cw::types::StorageProof;
+use ibc_core::client::context::client_state::ClientStateCommon;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Height;
+use ibc_core::commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::host::types::identifiers::ClientType;
+use ibc_core::host::types::path::{Path, PathBytes};
+use ibc_core::primitives::proto::{Any, Protobuf};
+use ibc_core::primitives::Timestamp;
+
+use super::ClientState;
+use crate::consensus_state::ConsensusState;
+use crate::error::OpStackError;
+use crate::types::fixed_bytes;
+
+pub const OP_STACK_CLIENT_TYPE: &str = "op-stack";
+
+impl ClientStateCommon for ClientState {
+    /// Only the L1 consensus state the client is created with is verified,
+    /// by the L1 client: the L2 consensus states are as recent as the outputs
+    /// finalized on the L1 allow.
+    fn verify_consensus_state(
+        &self,
+        consensus_state: Any,
+        host_timestamp: &Timestamp,
+    ) -> Result<(), ClientError> {
+        let consensus_state = ConsensusState::try_from(consensus_state)?;
+
+        if let Some(l1_consensus_state) = consensus_state.l1_consensus_state {
+            self.l1_client
+                .verify_consensus_state(l1_consensus_state.into(), host_timestamp)?;
+        }
+
+        Ok(())
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::new(OP_STACK_CLIENT_TYPE).expect("valid client type")
+    }
+
+    fn latest_height(&self) -> Height {
+        self.latest_height
+    }
+
+    fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError> {
+        if self.latest_height < proof_height {
+            return Err(ClientError::InsufficientProofHeight {
+                actual: self.latest_height,
+                expected: proof_height,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn serialize_path(&self, path: Path) -> Result<PathBytes, ClientError> {
+        Ok(path.to_string().into_bytes().into())
+    }
+
+    /// Upgrades are not supported by this client: the L1 client follows the
+    /// Ethereum fork schedule, and changes to the L2 go through a client
+    /// recovery.
+    fn verify_upgrade_client(
+        &self,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+        _proof_upgrade_client: CommitmentProofBytes,
+        _proof_upgrade_consensus_state: CommitmentProofBytes,
+        _root: &CommitmentRoot,
+    ) -> Result<(), ClientError> {
+        Err(ClientError::ClientSpecific {
+            description: "upgrades are not supported by the OP-stack client".to_string(),
+        })
+    }
+
+    fn verify_membership_raw(
+        &self,
+        _prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: PathBytes,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        let Some(stored) = self.verify_storage_proof(proof, root, &path)? else {
+            return Err(OpStackError::MismatchedStorageValue)?;
+        };
+
+        if RlpItem::decode(&stored)?.as_bytes()? != trim_leading_zeros(&keccak256(value)) {
+            return Err(OpStackError::MismatchedStorageValue)?;
+        }
+
+        Ok(())
+    }
+
+    fn verify_non_membership_raw(
+        &self,
+        _prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: PathBytes,
+    ) -> Result<(), ClientError> {
+        if self.verify_storage_proof(proof, root, &path)?.is_some() {
+            return Err(OpStackError::MismatchedStorageValue)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ClientState {
+    /// Returns the storage key of the IBC contract's commitment for `path`,
+    /// laid out as in the Ethereum client.
+    pub fn commitment_storage_key(&self, path: &[u8]) -> Bytes32 {
+        let mut preimage = keccak256(path).to_vec();
+        preimage.extend_from_slice(&self.ibc_commitment_slot);
+
+        keccak256(keccak256(preimage))
+    }
+
+    /// Verifies the L2 storage proof of the commitment at `path` against the
+    /// storage root, returning the RLP-encoded value if present.
+    fn verify_storage_proof(
+        &self,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: &PathBytes,
+    ) -> Result<Option<Vec<u8>>, ClientError> {
+        let storage_proof: StorageProof = Protobuf::<RawStorageProof>::decode(proof.as_ref())
+            .map_err(|e| OpStackError::from(DecodingError::from(e)))?;
+
+        let storage_root: Bytes32 =
+            fixed_bytes("storage root", root.as_bytes().to_vec()).map_err(OpStackError::from)?;
+
+        Ok(verify_proof(
+            &storage_root,
+            &self.commitment_storage_key(path.as_ref()),
+            &storage_proof.proof,
+        )?)
+    }
+}
@@ -0,0 +1,241 @@
+use ibc_client_ethereum_cw::client_state::ClientState as L1ClientState;
+use ibc_client_ethereum_cw::client_type::EthereumClient;
+use ibc_core::client::context::client_state::ClientStateExecution;
+use ibc_core::client::context::prelude::*;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Height;
+use ibc_core::host::types::identifiers::ClientId;
+use ibc_core::host::types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc_core::primitives::proto::Any;
+
+use super::{consensus_state_at, ClientState};
+use crate::consensus_state::ConsensusState;
+use crate::entrypoint::OpStackContext;
+use crate::error::OpStackError;
+use crate::l1::{is_l1_client_message, L1_NAMESPACE};
+use crate::types::Header;
+
+impl<'a> ClientStateExecution<OpStackContext<'a>> for ClientState {
+    /// Initialises the L1 client with the L1 consensus state carried by the
+    /// consensus state, which must be installed at the latest height of the
+    /// L1 client.
+    fn initialise(
+        &self,
+        ctx: &mut OpStackContext<'a>,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        let consensus_state = ConsensusState::try_from(consensus_state)?;
+
+        let l1_consensus_state = consensus_state
+            .l1_consensus_state
+            .clone()
+            .ok_or(OpStackError::MissingL1ConsensusState)?;
+
+        if consensus_state.l1_height != self.l1_client.latest_height {
+            return Err(OpStackError::MismatchedL1Height {
+                height: consensus_state.l1_height,
+                expected: self.l1_client.latest_height,
+            })?;
+        }
+
+        self.l1_client.initialise(
+            &mut ctx.namespaced_mut::<EthereumClient>(L1_NAMESPACE),
+            client_id,
+            l1_consensus_state.into(),
+        )?;
+
+        ctx.store_client_state(ClientStatePath::new(client_id.clone()), self.clone())?;
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                client_id.clone(),
+                self.latest_height.revision_number(),
+                self.latest_height.revision_height(),
+            ),
+            consensus_state.without_l1_consensus_state(),
+        )?;
+        ctx.store_update_meta(
+            client_id.clone(),
+            self.latest_height,
+            ctx.host_timestamp()?,
+            ctx.host_height()?,
+        )?;
+
+        Ok(())
+    }
+
+    /// Updates the L1 client with L1 client messages, and installs the L2
+    /// block of an OP-stack header otherwise. As L1 updates do not install
+    /// any L2 consensus state, they report the latest height of the client.
+    fn update_state(
+        &self,
+        ctx: &mut OpStackContext<'a>,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        if is_l1_client_message(&client_message) {
+            self.l1_client.update_state(
+                &mut ctx.namespaced_mut::<EthereumClient>(L1_NAMESPACE),
+                client_id,
+                client_message,
+            )?;
+
+            let new_client_state = Self {
+                l1_client: stored_l1_client(ctx, client_id)?,
+                ..self.clone()
+            };
+
+            ctx.store_client_state(ClientStatePath::new(client_id.clone()), new_client_state)?;
+
+            return Ok(vec![self.latest_height]);
+        }
+
+        let header = Header::try_from(client_message)?;
+        let height = header.height()?;
+
+        // the same output may be proven again against a later L1 height,
+        // which leaves the installed consensus state untouched
+        if consensus_state_at(ctx, client_id, height).is_ok() {
+            return Ok(vec![height]);
+        }
+
+        let new_consensus_state = self.header_consensus_state(&header)?;
+
+        let new_client_state = Self {
+            latest_height: self.latest_height.max(height),
+            ..self.clone()
+        };
+
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                client_id.clone(),
+                height.revision_number(),
+                height.revision_height(),
+            ),
+            new_consensus_state,
+        )?;
+        ctx.store_client_state(ClientStatePath::new(client_id.clone()), new_client_state)?;
+        ctx.store_update_meta(
+            client_id.clone(),
+            height,
+            ctx.host_timestamp()?,
+            ctx.host_height()?,
+        )?;
+
+        Ok(vec![height])
+    }
+
+    /// Freezes the client, along with the L1 client on L1 misbehaviour, as
+    /// no output can be trusted once the view of the L1 is compromised.
+    fn update_state_on_misbehaviour(
+        &self,
+        ctx: &mut OpStackContext<'a>,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        if is_l1_client_message(&client_message) {
+            self.l1_client.update_state_on_misbehaviour(
+                &mut ctx.namespaced_mut::<EthereumClient>(L1_NAMESPACE),
+                client_id,
+                client_message,
+            )?;
+        }
+
+        let new_client_state = Self {
+            l1_client: stored_l1_client(ctx, client_id)?,
+            frozen_height: Some(Height::min(0)),
+            ..self.clone()
+        };
+
+        ctx.store_client_state(ClientStatePath::new(client_id.clone()), new_client_state)?;
+
+        Ok(())
+    }
+
+    fn update_state_on_upgrade(
+        &self,
+        _ctx: &mut OpStackContext<'a>,
+        _client_id: &ClientId,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        Err(ClientError::ClientSpecific {
+            description: "upgrades are not supported by the OP-stack client".to_string(),
+        })
+    }
+
+    /// Recovers the L1 client with the substitute L1 client, whose latest
+    /// consensus state is read from the store of the substitute L1 client,
+    /// then installs the latest consensus state of the substitute.
+    fn update_on_recovery(
+        &self,
+        ctx: &mut OpStackContext<'a>,
+        subject_client_id: &ClientId,
+        substitute_client_state: Any,
+        substitute_consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        let substitute_client_state = Self::try_from(substitute_client_state)?;
+        let new_consensus_state = ConsensusState::try_from(substitute_consensus_state)?;
+
+        let substitute_l1_client = substitute_client_state.l1_client;
+        let substitute_l1_height = substitute_l1_client.latest_height;
+
+        let substitute_l1_consensus_state = {
+            let mut substitute_ctx = ctx.namespaced_ref::<EthereumClient>(L1_NAMESPACE);
+            substitute_ctx.set_substitute_prefix();
+            substitute_ctx.consensus_state(&ClientConsensusStatePath::new(
+                subject_client_id.clone(),
+                substitute_l1_height.revision_number(),
+                substitute_l1_height.revision_height(),
+            ))?
+        };
+
+        self.l1_client.update_on_recovery(
+            &mut ctx.namespaced_mut::<EthereumClient>(L1_NAMESPACE),
+            subject_client_id,
+            substitute_l1_client.into(),
+            substitute_l1_consensus_state.into(),
+        )?;
+
+        let new_client_state = Self {
+            l1_client: stored_l1_client(ctx, subject_client_id)?,
+            latest_height: substitute_client_state.latest_height,
+            frozen_height: None,
+            ..self.clone()
+        };
+
+        let latest_height = new_client_state.latest_height;
+
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                subject_client_id.clone(),
+                latest_height.revision_number(),
+                latest_height.revision_height(),
+            ),
+            new_consensus_state.without_l1_consensus_state(),
+        )?;
+        ctx.store_client_state(
+            ClientStatePath::new(subject_client_id.clone()),
+            new_client_state,
+        )?;
+        ctx.store_update_meta(
+            subject_client_id.clone(),
+            latest_height,
+            ctx.host_timestamp()?,
+            ctx.host_height()?,
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Fetches the client state of the L1 client from its store, where the L1
+/// client keeps it up to date.
+fn stored_l1_client(
+    ctx: &OpStackContext<'_>,
+    client_id: &ClientId,
+) -> Result<L1ClientState, ClientError> {
+    Ok(ctx
+        .namespaced_ref::<EthereumClient>(L1_NAMESPACE)
+        .client_state(client_id)?)
+}
@@ -0,0 +1,127 @@
+//! Implements the OP-stack `ClientState` along with the `ClientStateCommon`,
+//! `ClientStateValidation` and `ClientStateExecution` traits.
+use core::time::Duration;
+
+use ibc_client_ethereum_cw::client_state::ClientState as L1ClientState;
+use ibc_client_ethereum_cw::ssz::Bytes32;
+use ibc_client_ethereum_cw::types::Bytes20;
+use ibc_core::client::types::Height;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::proto::{Any, Protobuf};
+
+use crate::types::fixed_bytes;
+use crate::types::proto::ClientState as RawClientState;
+
+mod common;
+mod execution;
+mod validation;
+
+pub use common::*;
+pub use validation::*;
+
+pub const OP_STACK_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.opstack.v1.ClientState";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientState {
+    /// The client state of the L1 client, as kept up to date by the L1 client
+    /// in its own store.
+    pub l1_client: L1ClientState,
+    /// The chain ID of the L2.
+    pub l2_chain_id: u64,
+    /// The address of the L2 output oracle contract on the L1.
+    pub l2_output_oracle_address: Bytes20,
+    /// The storage slot of the `l2Outputs` array of the oracle.
+    pub l2_outputs_slot: Bytes32,
+    /// The duration after which an output proposal can no longer be deleted
+    /// from the oracle, and is accepted by the client.
+    pub finalization_period: Duration,
+    /// The L2 block number of the latest output.
+    pub latest_height: Height,
+    pub frozen_height: Option<Height>,
+    /// The address of the IBC contract on the L2, whose storage holds the
+    /// commitments.
+    pub ibc_contract_address: Bytes20,
+    /// The storage slot of the IBC contract's commitments mapping.
+    pub ibc_commitment_slot: Bytes32,
+}
+
+impl ClientState {
+    pub fn is_frozen(&self) -> bool {
+        self.frozen_height.is_some()
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        Ok(Self {
+            l1_client: raw
+                .l1_client_state
+                .ok_or(DecodingError::missing_raw_data("L1 client state"))?
+                .try_into()?,
+            l2_chain_id: raw.l2_chain_id,
+            l2_output_oracle_address: fixed_bytes(
+                "L2 output oracle address",
+                raw.l2_output_oracle_address,
+            )?,
+            l2_outputs_slot: fixed_bytes("L2 outputs slot", raw.l2_outputs_slot)?,
+            finalization_period: Duration::from_nanos(raw.finalization_period),
+            latest_height: raw
+                .latest_height
+                .ok_or(DecodingError::missing_raw_data("latest height"))?
+                .try_into()?,
+            frozen_height: raw.frozen_height.map(TryInto::try_into).transpose()?,
+            ibc_contract_address: fixed_bytes("IBC contract address", raw.ibc_contract_address)?,
+            ibc_commitment_slot: fixed_bytes("IBC commitment slot", raw.ibc_commitment_slot)?,
+        })
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            l1_client_state: Some(value.l1_client.into()),
+            l2_chain_id: value.l2_chain_id,
+            l2_output_oracle_address: value.l2_output_oracle_address.to_vec(),
+            l2_outputs_slot: value.l2_outputs_slot.to_vec(),
+            finalization_period: value
+                .finalization_period
+                .as_nanos()
+                .try_into()
+                .expect("no overflow"),
+            latest_height: Some(value.latest_height.into()),
+            frozen_height: value.frozen_height.map(Into::into),
+            ibc_contract_address: value.ibc_contract_address.to_vec(),
+            ibc_commitment_slot: value.ibc_commitment_slot.to_vec(),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let OP_STACK_CLIENT_STATE_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawClientState>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: OP_STACK_CLIENT_STATE_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Self {
+            type_url: OP_STACK_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientState>::encode_vec(client_state),
+        }
+    }
+}
@@ -0,0 +1,324 @@
+use ibc_client_ethereum_cw::client_state::consensus_state_at as l1_consensus_state_at;
+use ibc_client_ethereum_cw::client_type::EthereumClient;
+use ibc_client_ethereum_cw::consensus_state::ConsensusState as L1ConsensusState;
+use ibc_client_ethereum_cw::mpt::{
+    account_storage_root, keccak256, trim_leading_zeros, verify_proof, RlpItem,
+};
+use ibc_client_ethereum_cw::ssz::Bytes32;
+use ibc_client_ethereum_cw::types::{AccountUpdate, Bytes20};
+use ibc_core::client::context::client_state::ClientStateValidation;
+use ibc_core::client::context::prelude::*;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::{Height, Status};
+use ibc_core::host::types::identifiers::ClientId;
+use ibc_core::host::types::path::ClientConsensusStatePath;
+use ibc_core::primitives::proto::Any;
+use ibc_core::primitives::Timestamp;
+
+use super::ClientState;
+use crate::consensus_state::ConsensusState;
+use crate::entrypoint::OpStackContext;
+use crate::error::OpStackError;
+use crate::l1::{
+    is_l1_client_message, output_proposal_metadata, output_proposal_slots, L1_NAMESPACE,
+};
+use crate::types::{Header, OP_STACK_HEADER_TYPE_URL, OUTPUT_VERSION_V0};
+
+impl<'a> ClientStateValidation<OpStackContext<'a>> for ClientState {
+    fn verify_client_message(
+        &self,
+        ctx: &OpStackContext<'a>,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        if is_l1_client_message(&client_message) {
+            return self.l1_client.verify_client_message(
+                &ctx.namespaced_ref::<EthereumClient>(L1_NAMESPACE),
+                client_id,
+                client_message,
+            );
+        }
+
+        match client_message.type_url.as_str() {
+            OP_STACK_HEADER_TYPE_URL => {
+                let header = Header::try_from(client_message)?;
+                self.verify_header(ctx, client_id, &header)
+            }
+            header_type => Err(ClientError::InvalidHeaderType(header_type.to_owned())),
+        }
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        ctx: &OpStackContext<'a>,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        if is_l1_client_message(&client_message) {
+            return self.l1_client.check_for_misbehaviour(
+                &ctx.namespaced_ref::<EthereumClient>(L1_NAMESPACE),
+                client_id,
+                client_message,
+            );
+        }
+
+        match client_message.type_url.as_str() {
+            OP_STACK_HEADER_TYPE_URL => {
+                let header = Header::try_from(client_message)?;
+                self.check_for_misbehaviour_on_header(ctx, client_id, &header)
+            }
+            header_type => Err(ClientError::InvalidHeaderType(header_type.to_owned())),
+        }
+    }
+
+    /// The L2 consensus states do not expire on their own: the client is as
+    /// alive as its L1 client, provided it holds a consensus state at its
+    /// latest height.
+    fn status(
+        &self,
+        ctx: &OpStackContext<'a>,
+        client_id: &ClientId,
+    ) -> Result<Status, ClientError> {
+        if self.is_frozen() {
+            return Ok(Status::Frozen);
+        }
+
+        let l1_status = self.l1_client.status(
+            &ctx.namespaced_ref::<EthereumClient>(L1_NAMESPACE),
+            client_id,
+        )?;
+
+        if !l1_status.is_active() {
+            return Ok(l1_status);
+        }
+
+        // if the client state does not have an associated consensus state for
+        // its latest height then it must be expired
+        if consensus_state_at(ctx, client_id, self.latest_height).is_err() {
+            return Ok(Status::Expired);
+        }
+
+        Ok(Status::Active)
+    }
+
+    /// The subject and substitute client states match if their L1 clients
+    /// match and they track the same L2 through the same oracle.
+    fn check_substitute(
+        &self,
+        ctx: &OpStackContext<'a>,
+        substitute_client_state: Any,
+    ) -> Result<(), ClientError> {
+        let substitute_client_state = Self::try_from(substitute_client_state)?;
+
+        self.l1_client.check_substitute(
+            &ctx.namespaced_ref::<EthereumClient>(L1_NAMESPACE),
+            substitute_client_state.l1_client.clone().into(),
+        )?;
+
+        let normalized = Self {
+            l1_client: self.l1_client.clone(),
+            latest_height: self.latest_height,
+            frozen_height: self.frozen_height,
+            ..substitute_client_state
+        };
+
+        (&normalized == self)
+            .then_some(())
+            .ok_or(ClientError::FailedToVerifyClientRecoveryStates)
+    }
+}
+
+impl ClientState {
+    /// Verifies that the output of the header was proposed to the L2 output
+    /// oracle, as of the L1 consensus state at the header's L1 height, and
+    /// is finalized, then that it commits to the L2 block header and the IBC
+    /// contract account.
+    pub fn verify_header(
+        &self,
+        ctx: &OpStackContext<'_>,
+        client_id: &ClientId,
+        header: &Header,
+    ) -> Result<(), ClientError> {
+        let l1_ctx = ctx.namespaced_ref::<EthereumClient>(L1_NAMESPACE);
+
+        let l1_status = self.l1_client.status(&l1_ctx, client_id)?;
+
+        if !l1_status.is_active() {
+            return Err(OpStackError::InactiveL1Client(l1_status))?;
+        }
+
+        let l1_consensus_state = l1_consensus_state_at(&l1_ctx, client_id, header.l1_height)?;
+
+        self.verify_output_proposal(&l1_consensus_state, header)?;
+
+        let output_root_proof = &header.output_root_proof;
+        let l2_block_header = &header.l2_block_header;
+
+        if output_root_proof.version != OUTPUT_VERSION_V0 {
+            return Err(OpStackError::UnsupportedOutputVersion)?;
+        }
+
+        if output_root_proof.latest_block_hash != l2_block_header.hash()
+            || output_root_proof.state_root != l2_block_header.state_root
+        {
+            return Err(OpStackError::MismatchedOutputRootProof)?;
+        }
+
+        verify_account(
+            &l2_block_header.state_root,
+            &self.ibc_contract_address,
+            &header.ibc_account,
+        )
+    }
+
+    /// Verifies the storage proofs of the output proposal against the L1
+    /// execution state root, and checks that its finalization period has
+    /// elapsed by the time of the L1 consensus state.
+    fn verify_output_proposal(
+        &self,
+        l1_consensus_state: &L1ConsensusState,
+        header: &Header,
+    ) -> Result<(), ClientError> {
+        let proposal = &header.output_proposal;
+        let index = proposal.output_index;
+
+        let proposed_at = Timestamp::from_unix_timestamp(proposal.timestamp, 0).map_err(|e| {
+            ClientError::ClientSpecific {
+                description: e.to_string(),
+            }
+        })?;
+
+        let elapsed = l1_consensus_state
+            .timestamp
+            .duration_since(&proposed_at)
+            .unwrap_or_default();
+
+        if let Some(remaining) = self
+            .finalization_period
+            .checked_sub(elapsed)
+            .filter(|remaining| !remaining.is_zero())
+        {
+            return Err(OpStackError::OutputNotFinalized { index, remaining })?;
+        }
+
+        let oracle_account = &proposal.oracle_account;
+
+        verify_account(
+            &l1_consensus_state.state_root,
+            &self.l2_output_oracle_address,
+            oracle_account,
+        )?;
+
+        let (output_root_slot, metadata_slot) = output_proposal_slots(&self.l2_outputs_slot, index);
+
+        for (slot, proof, expected) in [
+            (
+                output_root_slot,
+                &proposal.output_root_proof,
+                header.output_root_proof.output_root(),
+            ),
+            (
+                metadata_slot,
+                &proposal.metadata_proof,
+                output_proposal_metadata(proposal.timestamp, header.l2_block_header.number),
+            ),
+        ] {
+            let stored = verify_proof(&oracle_account.storage_root, &keccak256(slot), proof)?
+                .ok_or(OpStackError::MissingOutputProposal { index })?;
+
+            if RlpItem::decode(&stored)?.as_bytes()? != trim_leading_zeros(&expected) {
+                return Err(OpStackError::MismatchedOutputProposal { index })?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Computes the consensus state installed by a verified header.
+    pub fn header_consensus_state(&self, header: &Header) -> Result<ConsensusState, ClientError> {
+        let timestamp = Timestamp::from_unix_timestamp(header.l2_block_header.timestamp, 0)
+            .map_err(|e| ClientError::ClientSpecific {
+                description: e.to_string(),
+            })?;
+
+        Ok(ConsensusState {
+            l1_height: header.l1_height,
+            state_root: header.l2_block_header.state_root,
+            storage_root: header.ibc_account.storage_root.to_vec().into(),
+            timestamp,
+            l1_consensus_state: None,
+        })
+    }
+
+    /// Checks whether a header conflicts with the L2 block already installed
+    /// at its height, or breaks the monotonicity of the consensus state
+    /// timestamps. The same output proven against another L1 height does not
+    /// conflict.
+    pub fn check_for_misbehaviour_on_header(
+        &self,
+        ctx: &OpStackContext<'_>,
+        client_id: &ClientId,
+        header: &Header,
+    ) -> Result<bool, ClientError> {
+        let height = header.height()?;
+        let header_consensus_state = self.header_consensus_state(header)?;
+
+        if let Ok(existing_consensus_state) = consensus_state_at(ctx, client_id, height) {
+            return Ok(
+                existing_consensus_state.state_root != header_consensus_state.state_root
+                    || existing_consensus_state.storage_root != header_consensus_state.storage_root
+                    || existing_consensus_state.timestamp != header_consensus_state.timestamp,
+            );
+        }
+
+        if let Some(prev_consensus_state) = ctx.prev_consensus_state(client_id, &height)? {
+            if header_consensus_state.timestamp <= prev_consensus_state.timestamp {
+                return Ok(true);
+            }
+        }
+
+        if height < self.latest_height {
+            if let Some(next_consensus_state) = ctx.next_consensus_state(client_id, &height)? {
+                if header_consensus_state.timestamp >= next_consensus_state.timestamp {
+                    return Ok(true);
+                }
+            }
+        }
+
+        Ok(false)
+    }
+}
+
+/// Verifies the account proof of `address` against the execution state root,
+/// and checks that it commits to the storage root of the account update.
+fn verify_account(
+    state_root: &Bytes32,
+    address: &Bytes20,
+    account_update: &AccountUpdate,
+) -> Result<(), ClientError> {
+    let account = verify_proof(
+        state_root,
+        &keccak256(address),
+        &account_update.account_proof,
+    )?
+    .ok_or(OpStackError::MismatchedStorageRoot)?;
+
+    if account_storage_root(&account)? != account_update.storage_root {
+        return Err(OpStackError::MismatchedStorageRoot.into());
+    }
+
+    Ok(())
+}
+
+/// Fetches the L2 consensus state stored for the client at the given height.
+pub fn consensus_state_at(
+    ctx: &OpStackContext<'_>,
+    client_id: &ClientId,
+    height: Height,
+) -> Result<ConsensusState, ClientError> {
+    Ok(ctx.consensus_state(&ClientConsensusStatePath::new(
+        client_id.clone(),
+        height.revision_number(),
+        height.revision_height(),
+    ))?)
+}
@@ -0,0 +1,117 @@
+//! Implements the OP-stack `ConsensusState`. Each consensus state records an
+//! L2 block committed by a finalized output root, along with the storage root
+//! of the IBC contract at that block.
+use ibc_client_ethereum_cw::consensus_state::ConsensusState as L1ConsensusState;
+use ibc_client_ethereum_cw::ssz::Bytes32;
+use ibc_core::client::context::consensus_state::ConsensusState as ConsensusStateTrait;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Height;
+use ibc_core::commitment_types::commitment::CommitmentRoot;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::proto::{Any, Protobuf};
+use ibc_core::primitives::Timestamp;
+
+use crate::types::fixed_bytes;
+use crate::types::proto::ConsensusState as RawConsensusState;
+
+pub const OP_STACK_CONSENSUS_STATE_TYPE_URL: &str = "/ibc.lightclients.opstack.v1.ConsensusState";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusState {
+    /// The height of the L1 consensus state the output was proven against.
+    pub l1_height: Height,
+    /// The state root of the L2 block.
+    pub state_root: Bytes32,
+    /// The storage root of the IBC contract, used as the commitment root.
+    pub storage_root: CommitmentRoot,
+    /// The timestamp of the L2 block.
+    pub timestamp: Timestamp,
+    /// The consensus state of the L1 client at `l1_height`, only set in the
+    /// consensus state the client is created with to initialise its L1
+    /// client. It is never stored.
+    pub l1_consensus_state: Option<L1ConsensusState>,
+}
+
+impl ConsensusState {
+    /// Returns the storage root of the IBC contract.
+    pub fn storage_root(&self) -> Result<Bytes32, DecodingError> {
+        fixed_bytes("storage root", self.storage_root.as_bytes().to_vec())
+    }
+
+    /// Returns the consensus state to store, without the L1 consensus state.
+    pub fn without_l1_consensus_state(self) -> Self {
+        Self {
+            l1_consensus_state: None,
+            ..self
+        }
+    }
+}
+
+impl ConsensusStateTrait for ConsensusState {
+    fn root(&self) -> &CommitmentRoot {
+        &self.storage_root
+    }
+
+    fn timestamp(&self) -> Result<Timestamp, ClientError> {
+        Ok(self.timestamp)
+    }
+}
+
+impl Protobuf<RawConsensusState> for ConsensusState {}
+
+impl TryFrom<RawConsensusState> for ConsensusState {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawConsensusState) -> Result<Self, Self::Error> {
+        let storage_root: Bytes32 = fixed_bytes("storage root", raw.storage_root)?;
+
+        Ok(Self {
+            l1_height: raw
+                .l1_height
+                .ok_or(DecodingError::missing_raw_data("L1 height"))?
+                .try_into()?,
+            state_root: fixed_bytes("state root", raw.state_root)?,
+            storage_root: storage_root.to_vec().into(),
+            timestamp: Timestamp::from_nanoseconds(raw.timestamp),
+            l1_consensus_state: raw.l1_consensus_state.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+impl From<ConsensusState> for RawConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        Self {
+            l1_height: Some(value.l1_height.into()),
+            state_root: value.state_root.to_vec(),
+            storage_root: value.storage_root.into_vec(),
+            timestamp: value.timestamp.nanoseconds(),
+            l1_consensus_state: value.l1_consensus_state.map(Into::into),
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let OP_STACK_CONSENSUS_STATE_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawConsensusState>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: OP_STACK_CONSENSUS_STATE_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Self {
+            type_url: OP_STACK_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawConsensusState>::encode_vec(consensus_state),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+//! Defines the error type of the OP-stack light client.
+use core::time::Duration;
+
+use derive_more::{Display, From};
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::{Height, Status};
+use ibc_core::host::types::error::DecodingError;
+
+#[derive(From, Display, Debug)]
+pub enum OpStackError {
+    #[from]
+    #[display("decoding error: {_0}")]
+    Decoding(DecodingError),
+    #[display("L1 client is not active: {_0}")]
+    InactiveL1Client(Status),
+    #[display("missing L1 consensus state to initialise the L1 client with")]
+    MissingL1ConsensusState,
+    #[display("L1 consensus state at `{height}` does not match the L1 client height `{expected}`")]
+    MismatchedL1Height { height: Height, expected: Height },
+    #[display("unsupported output root version")]
+    UnsupportedOutputVersion,
+    #[display("output root does not match the L2 block header")]
+    MismatchedOutputRootProof,
+    #[display("output proposal `{index}` is not stored by the L2 output oracle")]
+    MissingOutputProposal { index: u64 },
+    #[display("output proposal `{index}` does not match the output")]
+    MismatchedOutputProposal { index: u64 },
+    #[display("output proposal `{index}` is not finalized yet, {remaining:?} remaining")]
+    OutputNotFinalized { index: u64, remaining: Duration },
+    #[display("account proof does not match the expected storage root")]
+    MismatchedStorageRoot,
+    #[display("storage proof value does not match the expected commitment")]
+    MismatchedStorageValue,
+}
+
+impl From<OpStackError> for ClientError {
+    fn from(e: OpStackError) -> Self {
+        Self::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
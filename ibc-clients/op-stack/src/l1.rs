@@ -0,0 +1,58 @@
+//! The L1 client embedded in the OP-stack client, and the storage layout of
+//! the L2 output oracle on the L1. The L1 client is an Ethereum light client
+//! running against a context over its own namespace of the contract store,
+//! so that its consensus states never mix with the L2 ones.
+use ibc_client_ethereum_cw::mpt::keccak256;
+use ibc_client_ethereum_cw::ssz::Bytes32;
+use ibc_client_ethereum_cw::types::{ETHEREUM_HEADER_TYPE_URL, ETHEREUM_MISBEHAVIOUR_TYPE_URL};
+use ibc_core::primitives::proto::Any;
+
+/// The namespace of the store of the L1 client.
+pub const L1_NAMESPACE: &[u8] = b"l1/";
+
+/// Returns whether the client message is addressed to the L1 client.
+pub fn is_l1_client_message(client_message: &Any) -> bool {
+    matches!(
+        client_message.type_url.as_str(),
+        ETHEREUM_HEADER_TYPE_URL | ETHEREUM_MISBEHAVIOUR_TYPE_URL
+    )
+}
+
+/// Returns the storage slots of the output proposal at `output_index` in the
+/// `l2Outputs` array of the oracle, stored at `l2_outputs_slot`. Each
+/// proposal spans two slots: the output root, followed by the timestamp and
+/// the L2 block number packed as two `uint128`.
+pub fn output_proposal_slots(l2_outputs_slot: &Bytes32, output_index: u64) -> (Bytes32, Bytes32) {
+    let first_slot = add_to_slot(&keccak256(l2_outputs_slot), u128::from(output_index) * 2);
+
+    (first_slot, add_to_slot(&first_slot, 1))
+}
+
+/// Returns the value of the slot packing the timestamp and the L2 block
+/// number of an output proposal, the timestamp taking the low-order bytes.
+pub fn output_proposal_metadata(timestamp: u64, l2_block_number: u64) -> Bytes32 {
+    let mut metadata = [0; 32];
+
+    metadata[..16].copy_from_slice(&u128::from(l2_block_number).to_be_bytes());
+    metadata[16..].copy_from_slice(&u128::from(timestamp).to_be_bytes());
+
+    metadata
+}
+
+/// Adds `offset` to a storage slot, read as a big-endian 256-bit integer.
+fn add_to_slot(slot: &Bytes32, offset: u128) -> Bytes32 {
+    let mut result = *slot;
+    let mut carry = offset;
+
+    for byte in result.iter_mut().rev() {
+        if carry == 0 {
+            break;
+        }
+
+        let sum = u128::from(*byte) + (carry & 0xff);
+        *byte = sum as u8;
+        carry = (carry >> 8) + (sum >> 8);
+    }
+
+    result
+}
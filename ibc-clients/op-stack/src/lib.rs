@@ -0,0 +1,18 @@
+//! The CosmWasm contract implementation of an OP-stack rollup light client. It
+//! follows the L1 through an embedded Ethereum light client, running over its
+//! own namespace of the contract store, and accepts the L2 output roots stored
+//! by the L2 output oracle on the L1 once their finalization period has
+//! elapsed. IBC commitments stored by an IBC contract on the L2 are verified
+//! through Merkle-Patricia storage proofs against the L2 state roots.
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+
+pub mod client_state;
+pub mod client_type;
+pub mod consensus_state;
+pub mod entrypoint;
+pub mod error;
+pub mod l1;
+pub mod types;
+
+#[cfg(test)]
+mod tests;
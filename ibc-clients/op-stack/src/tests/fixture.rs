@@ -0,0 +1,646 @@
+use std::time::Duration;
+
+use cosmwasm_std::Binary;
+use ibc_client_cw::types::{MerklePath, VerifyMembershipMsgRaw, VerifyNonMembershipMsgRaw};
+use ibc_client_cw::utils::AnyCodec;
+use ibc_client_cw_testkit::api::TestClient;
+use ibc_client_cw_testkit::clock::Clock;
+use ibc_client_ethereum_cw::bls::{compute_domain, compute_signing_root, DOMAIN_SYNC_COMMITTEE};
+use ibc_client_ethereum_cw::client_state::ClientState as L1ClientState;
+use ibc_client_ethereum_cw::consensus_state::ConsensusState as L1ConsensusState;
+use ibc_client_ethereum_cw::mpt::{keccak256, trim_leading_zeros, EMPTY_TRIE_ROOT};
+use ibc_client_ethereum_cw::ssz::{sha256, u64_chunk, Bytes32};
+use ibc_client_ethereum_cw::test_utils::{
+    rlp_bytes, rlp_list, SecureTrie, SparseTree, TestSyncCommittee,
+};
+use ibc_client_ethereum_cw::types::proto::StorageProof as RawStorageProof;
+use ibc_client_ethereum_cw::types::{
+    AccountUpdate, BeaconBlockHeader, Bytes20, ExecutionPayloadHeader, Fork, ForkParameters,
+    Header as L1Header, LightClientHeader, LightClientUpdate, Misbehaviour as L1Misbehaviour,
+    StorageProof, SyncAggregate, TrustedSyncCommittee, BYTES_PER_LOGS_BLOOM,
+    EXECUTION_PAYLOAD_GINDEX, FINALIZED_ROOT_GINDEX_DENEB, NEXT_SYNC_COMMITTEE_GINDEX_DENEB,
+};
+use ibc_core::client::types::Height;
+use ibc_core::primitives::proto::Protobuf;
+use ibc_core::primitives::Timestamp;
+
+use crate::client_state::ClientState;
+use crate::client_type::OpStackClient;
+use crate::consensus_state::ConsensusState;
+use crate::l1::{output_proposal_metadata, output_proposal_slots};
+use crate::types::{
+    Header, L2BlockHeader, OutputProposalProof, OutputRootProof, OUTPUT_VERSION_V0,
+};
+
+/// A minimal preset of the L1, so that sync committee periods span only 64
+/// slots.
+pub const SYNC_COMMITTEE_SIZE: u64 = 32;
+pub const SLOTS_PER_EPOCH: u64 = 8;
+pub const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: u64 = 8;
+pub const SLOTS_PER_PERIOD: u64 = SLOTS_PER_EPOCH * EPOCHS_PER_SYNC_COMMITTEE_PERIOD;
+pub const SECONDS_PER_SLOT: u64 = 12;
+
+/// The offset between beacon slots and execution block numbers of the
+/// synthetic L1.
+const BLOCK_NUMBER_OFFSET: u64 = 1000;
+
+/// The L2 produces a block every two seconds, and an output is proposed for
+/// every L1 slot worth of L2 blocks.
+pub const L2_BLOCK_TIME: u64 = 2;
+pub const L2_BLOCKS_PER_OUTPUT: u64 = SECONDS_PER_SLOT / L2_BLOCK_TIME;
+
+/// Test fixture of the OP Stack client, whose heights are the L2 block
+/// numbers of the outputs.
+pub type Fixture = ibc_client_cw_testkit::fixture::Fixture<OpStackClient>;
+
+/// An L1 beacon chain with deterministic sync committees, whose execution
+/// layer hosts the L2 output oracle of an L2 with an IBC contract. The
+/// output of index `i` commits to the L2 block `6 * (i + 1)` and is proposed
+/// at the L1 slot `i + 2`. All data is synthetic but follows the encodings
+/// of the real chains.
+#[derive(Clone, Debug)]
+pub struct OpStackConfig {
+    /// The genesis time of both the L1 and the L2.
+    pub genesis_time: u64,
+    /// The L1 slot the L1 client is created at.
+    pub trusted_slot: u64,
+    pub l1_trusting_period: Duration,
+    pub finalization_period: Duration,
+    pub l2_output_oracle_address: Bytes20,
+    pub l2_outputs_slot: Bytes32,
+    pub ibc_contract_address: Bytes20,
+    pub ibc_commitment_slot: Bytes32,
+    /// The (path, value) commitments stored by the IBC contract.
+    pub commitments: Vec<(Vec<u8>, Vec<u8>)>,
+    /// The L1 slot from which the oracle stores the outputs of a fork of the
+    /// L2, if any.
+    pub fork_slot: Option<u64>,
+}
+
+impl Default for OpStackConfig {
+    fn default() -> Self {
+        let now = Clock::default().now().nanoseconds() / 1_000_000_000;
+
+        Self {
+            genesis_time: now - 3600,
+            trusted_slot: 16,
+            l1_trusting_period: Duration::from_secs(64000),
+            finalization_period: Duration::from_secs(60),
+            l2_output_oracle_address: [0x0a; 20],
+            l2_outputs_slot: u64_chunk(3),
+            ibc_contract_address: [0xbc; 20],
+            ibc_commitment_slot: [0; 32],
+            commitments: vec![
+                (
+                    b"commitments/ports/transfer/channels/channel-0/sequences/1".to_vec(),
+                    b"packet-commitment-1".to_vec(),
+                ),
+                (
+                    b"commitments/ports/transfer/channels/channel-0/sequences/2".to_vec(),
+                    b"packet-commitment-2".to_vec(),
+                ),
+                (
+                    b"connections/connection-0".to_vec(),
+                    b"connection-end".to_vec(),
+                ),
+            ],
+            fork_slot: None,
+        }
+    }
+}
+
+impl OpStackConfig {
+    // ------------------- Synthetic L2 -------------------
+
+    pub fn l2_storage_trie(&self) -> SecureTrie {
+        let mut trie = SecureTrie::default();
+
+        for (path, value) in &self.commitments {
+            trie.insert(
+                &self.storage_slot(path),
+                rlp_bytes(trim_leading_zeros(&keccak256(value))),
+            );
+        }
+
+        trie
+    }
+
+    /// Returns the storage slot of `commitments[keccak256(path)]`.
+    pub fn storage_slot(&self, path: &[u8]) -> Bytes32 {
+        keccak256([keccak256(path), self.ibc_commitment_slot].concat())
+    }
+
+    /// Returns the L2 account trie at the given block, holding the IBC
+    /// contract and a few other accounts whose nonces change every block,
+    /// and differ on the fork of the L2.
+    pub fn l2_account_trie(&self, number: u64, forked: bool) -> SecureTrie {
+        let nonce = if forked { number + 1_000_000 } else { number };
+
+        let mut trie = SecureTrie::default();
+
+        trie.insert(
+            &self.ibc_contract_address,
+            account(1, self.l2_storage_trie().root()),
+        );
+
+        for i in 0..8u8 {
+            trie.insert(&[i; 20], account(nonce, EMPTY_TRIE_ROOT));
+        }
+
+        trie
+    }
+
+    pub fn l2_block_header(&self, number: u64, forked: bool) -> L2BlockHeader {
+        let integer = |value: u64| rlp_bytes(trim_leading_zeros(&value.to_be_bytes()));
+
+        let rlp = rlp_list(&[
+            rlp_bytes(&sha256(u64_chunk(number - 1))),
+            rlp_bytes(&keccak256(rlp_list(&[]))),
+            rlp_bytes(&[0x42; 20]),
+            rlp_bytes(&self.l2_account_trie(number, forked).root()),
+            rlp_bytes(&[0x01; 32]),
+            rlp_bytes(&[0x02; 32]),
+            rlp_bytes(&[0; BYTES_PER_LOGS_BLOOM]),
+            integer(0),
+            integer(number),
+            integer(30_000_000),
+            integer(21_000),
+            integer(self.genesis_time + number * L2_BLOCK_TIME),
+            rlp_bytes(b"synthetic"),
+            rlp_bytes(&[0x03; 32]),
+            rlp_bytes(&[0; 8]),
+            integer(7),
+        ]);
+
+        rlp.try_into().expect("valid L2 block header")
+    }
+
+    pub fn output_root_proof(&self, output_index: u64, forked: bool) -> OutputRootProof {
+        let l2_block_header =
+            self.l2_block_header(OpStackClient::output_block_number(output_index), forked);
+
+        OutputRootProof {
+            version: OUTPUT_VERSION_V0,
+            state_root: l2_block_header.state_root,
+            message_passer_storage_root: [0x16; 32],
+            latest_block_hash: l2_block_header.hash(),
+        }
+    }
+
+    pub fn is_forked(&self, l1_slot: u64) -> bool {
+        self.fork_slot.is_some_and(|fork_slot| l1_slot >= fork_slot)
+    }
+
+    /// Returns the storage trie of the oracle at the given L1 slot, holding
+    /// the outputs proposed up to that slot.
+    pub fn oracle_storage_trie(&self, l1_slot: u64) -> SecureTrie {
+        let forked = self.is_forked(l1_slot);
+
+        let mut trie = SecureTrie::default();
+
+        for output_index in 0..l1_slot.saturating_sub(1) {
+            let (output_root_slot, metadata_slot) =
+                output_proposal_slots(&self.l2_outputs_slot, output_index);
+
+            let output_root = self.output_root_proof(output_index, forked).output_root();
+            let metadata = output_proposal_metadata(
+                self.execution_timestamp(OpStackClient::proposal_slot(output_index)),
+                OpStackClient::output_block_number(output_index),
+            );
+
+            trie.insert(
+                &output_root_slot,
+                rlp_bytes(trim_leading_zeros(&output_root)),
+            );
+            trie.insert(&metadata_slot, rlp_bytes(trim_leading_zeros(&metadata)));
+        }
+
+        trie
+    }
+
+    /// Constructs a header for the output of the given index, proven
+    /// against the L1 consensus state at `l1_slot`.
+    pub fn dummy_header(&self, l1_slot: u64, output_index: u64) -> Header {
+        let forked = self.is_forked(l1_slot);
+        let (output_root_slot, metadata_slot) =
+            output_proposal_slots(&self.l2_outputs_slot, output_index);
+
+        let oracle_storage_trie = self.oracle_storage_trie(l1_slot);
+
+        let l2_block_number = OpStackClient::output_block_number(output_index);
+
+        Header {
+            l1_height: OpStackClient::l1_height(l1_slot),
+            output_proposal: OutputProposalProof {
+                output_index,
+                timestamp: self.execution_timestamp(OpStackClient::proposal_slot(output_index)),
+                oracle_account: AccountUpdate {
+                    account_proof: self
+                        .l1_account_trie(l1_slot)
+                        .proof(&self.l2_output_oracle_address),
+                    storage_root: oracle_storage_trie.root(),
+                },
+                output_root_proof: oracle_storage_trie.proof(&output_root_slot),
+                metadata_proof: oracle_storage_trie.proof(&metadata_slot),
+            },
+            output_root_proof: self.output_root_proof(output_index, forked),
+            l2_block_header: self.l2_block_header(l2_block_number, forked),
+            ibc_account: AccountUpdate {
+                account_proof: self
+                    .l2_account_trie(l2_block_number, forked)
+                    .proof(&self.ibc_contract_address),
+                storage_root: self.l2_storage_trie().root(),
+            },
+        }
+    }
+
+    // ------------------- Synthetic L1 -------------------
+
+    pub fn execution_timestamp(&self, slot: u64) -> u64 {
+        self.genesis_time + slot * SECONDS_PER_SLOT
+    }
+
+    /// Returns the L1 account trie at the given slot, holding the oracle and
+    /// a few other accounts whose nonces change every slot.
+    pub fn l1_account_trie(&self, slot: u64) -> SecureTrie {
+        let mut trie = SecureTrie::default();
+
+        trie.insert(
+            &self.l2_output_oracle_address,
+            account(1, self.oracle_storage_trie(slot).root()),
+        );
+
+        for i in 0..8u8 {
+            trie.insert(&[i; 20], account(slot, EMPTY_TRIE_ROOT));
+        }
+
+        trie
+    }
+
+    pub fn execution_payload(&self, slot: u64) -> ExecutionPayloadHeader {
+        ExecutionPayloadHeader {
+            parent_hash: sha256(u64_chunk(slot - 1)),
+            fee_recipient: [0xfe; 20],
+            state_root: self.l1_account_trie(slot).root(),
+            receipts_root: [0x01; 32],
+            logs_bloom: vec![0; BYTES_PER_LOGS_BLOOM],
+            prev_randao: [0x02; 32],
+            block_number: OpStackClient::l1_height(slot).revision_height(),
+            gas_limit: 30_000_000,
+            gas_used: 21_000,
+            timestamp: self.execution_timestamp(slot),
+            extra_data: b"synthetic".to_vec(),
+            base_fee_per_gas: u64_chunk(7),
+            block_hash: sha256(u64_chunk(slot)),
+            transactions_root: [0x03; 32],
+            withdrawals_root: [0x04; 32],
+            blob_gas_used: 0,
+            excess_blob_gas: 0,
+        }
+    }
+
+    pub fn light_client_header(
+        &self,
+        slot: u64,
+        state: &SparseTree,
+        proposer_index: u64,
+    ) -> LightClientHeader {
+        let execution = self.execution_payload(slot);
+
+        let body = SparseTree::new(4)
+            .with_leaf(EXECUTION_PAYLOAD_GINDEX, execution.hash_tree_root())
+            .with_leaf(16, sha256(u64_chunk(slot)));
+
+        LightClientHeader {
+            beacon: BeaconBlockHeader {
+                slot,
+                proposer_index,
+                parent_root: sha256(u64_chunk(slot - 1)),
+                state_root: state.root(),
+                body_root: body.root(),
+            },
+            execution,
+            execution_branch: body.branch(EXECUTION_PAYLOAD_GINDEX),
+        }
+    }
+
+    /// Returns the L1 client state, whose IBC contract is the oracle so that
+    /// the L1 client tracks the oracle storage root.
+    pub fn dummy_l1_client_state(&self) -> L1ClientState {
+        L1ClientState {
+            chain_id: 1337,
+            genesis_validators_root: OpStackClient::genesis_validators_root(),
+            genesis_time: self.genesis_time,
+            fork_parameters: OpStackClient::fork_parameters(),
+            seconds_per_slot: SECONDS_PER_SLOT,
+            slots_per_epoch: SLOTS_PER_EPOCH,
+            epochs_per_sync_committee_period: EPOCHS_PER_SYNC_COMMITTEE_PERIOD,
+            sync_committee_size: SYNC_COMMITTEE_SIZE,
+            trusting_period: self.l1_trusting_period,
+            latest_slot: self.trusted_slot,
+            latest_height: OpStackClient::l1_height(self.trusted_slot),
+            frozen_height: None,
+            ibc_contract_address: self.l2_output_oracle_address,
+            ibc_commitment_slot: self.l2_outputs_slot,
+        }
+    }
+
+    pub fn dummy_l1_consensus_state(&self) -> L1ConsensusState {
+        let period = OpStackClient::period(self.trusted_slot);
+
+        L1ConsensusState {
+            slot: self.trusted_slot,
+            state_root: self.l1_account_trie(self.trusted_slot).root(),
+            storage_root: self
+                .oracle_storage_trie(self.trusted_slot)
+                .root()
+                .to_vec()
+                .into(),
+            timestamp: Timestamp::from_nanoseconds(
+                self.execution_timestamp(self.trusted_slot) * 1_000_000_000,
+            ),
+            current_sync_committee: OpStackClient::sync_committee(period)
+                .sync_committee()
+                .hash_tree_root(),
+            next_sync_committee: Some(
+                OpStackClient::sync_committee(period + 1)
+                    .sync_committee()
+                    .hash_tree_root(),
+            ),
+        }
+    }
+
+    /// Constructs an L1 header finalizing `finalized_slot`, signed by all
+    /// members of the sync committee of the signature slot.
+    pub fn dummy_l1_header(&self, trusted_slot: u64, finalized_slot: u64) -> L1Header {
+        self.dummy_l1_header_with(trusted_slot, finalized_slot, 0)
+    }
+
+    /// Constructs an L1 header finalizing `finalized_slot`, attested two
+    /// epochs later.
+    pub fn dummy_l1_header_with(
+        &self,
+        trusted_slot: u64,
+        finalized_slot: u64,
+        proposer_index: u64,
+    ) -> L1Header {
+        let attested_slot = finalized_slot + 2 * SLOTS_PER_EPOCH;
+        let signature_slot = attested_slot + 1;
+
+        let trusted_period = OpStackClient::period(trusted_slot);
+        let signature_period = OpStackClient::period(signature_slot);
+        let signing_committee = OpStackClient::sync_committee(signature_period);
+
+        let finalized_state = SparseTree::new(6).with_leaf(34, u64_chunk(finalized_slot));
+        let finalized_header =
+            self.light_client_header(finalized_slot, &finalized_state, proposer_index);
+
+        let next_sync_committee =
+            OpStackClient::sync_committee(OpStackClient::period(attested_slot) + 1)
+                .sync_committee();
+
+        let attested_state = SparseTree::new(6)
+            .with_leaf(34, u64_chunk(attested_slot))
+            .with_leaf(
+                FINALIZED_ROOT_GINDEX_DENEB,
+                finalized_header.beacon.hash_tree_root(),
+            )
+            .with_leaf(
+                NEXT_SYNC_COMMITTEE_GINDEX_DENEB,
+                next_sync_committee.hash_tree_root(),
+            );
+        let attested_header = self.light_client_header(attested_slot, &attested_state, 0);
+
+        let sync_committee_bits = vec![0xff; (SYNC_COMMITTEE_SIZE / 8) as usize];
+
+        let fork_version =
+            OpStackClient::fork_parameters().fork_version((signature_slot - 1) / SLOTS_PER_EPOCH);
+        let domain = compute_domain(
+            DOMAIN_SYNC_COMMITTEE,
+            fork_version,
+            &OpStackClient::genesis_validators_root(),
+        );
+        let signing_root = compute_signing_root(&attested_header.beacon.hash_tree_root(), &domain);
+
+        L1Header {
+            trusted_height: OpStackClient::l1_height(trusted_slot),
+            trusted_sync_committee: TrustedSyncCommittee {
+                sync_committee: signing_committee.sync_committee(),
+                is_next: signature_period == trusted_period + 1,
+            },
+            consensus_update: LightClientUpdate {
+                next_sync_committee_branch: attested_state.branch(NEXT_SYNC_COMMITTEE_GINDEX_DENEB),
+                next_sync_committee: Some(next_sync_committee),
+                finality_branch: attested_state.branch(FINALIZED_ROOT_GINDEX_DENEB),
+                finalized_header,
+                attested_header,
+                sync_aggregate: SyncAggregate {
+                    sync_committee_signature: signing_committee
+                        .sign(&signing_root, &sync_committee_bits),
+                    sync_committee_bits,
+                },
+                signature_slot,
+            },
+            account_update: AccountUpdate {
+                account_proof: self
+                    .l1_account_trie(finalized_slot)
+                    .proof(&self.l2_output_oracle_address),
+                storage_root: self.oracle_storage_trie(finalized_slot).root(),
+            },
+        }
+    }
+
+    /// Constructs an L1 misbehaviour out of two headers finalizing different
+    /// beacon blocks at the same slot.
+    pub fn dummy_l1_misbehaviour(&self, finalized_slot: u64) -> L1Misbehaviour {
+        L1Misbehaviour {
+            header_1: self.dummy_l1_header_with(self.trusted_slot, finalized_slot, 1),
+            header_2: self.dummy_l1_header_with(self.trusted_slot, finalized_slot, 2),
+        }
+    }
+
+    // ------------------- Client messages -------------------
+
+    pub fn dummy_storage_proof(&self, path: &[u8]) -> Binary {
+        let storage_proof = StorageProof {
+            proof: self.l2_storage_trie().proof(&self.storage_slot(path)),
+        };
+
+        Protobuf::<RawStorageProof>::encode_vec(storage_proof).into()
+    }
+
+    pub fn dummy_verify_membership_msg(
+        &self,
+        height: Height,
+        path: &[u8],
+        value: &[u8],
+    ) -> VerifyMembershipMsgRaw {
+        VerifyMembershipMsgRaw {
+            proof: self.dummy_storage_proof(path),
+            merkle_path: MerklePath {
+                key_path: vec![b"ibc".to_vec().into(), path.to_vec().into()],
+            },
+            value: value.to_vec().into(),
+            height: height.into(),
+            delay_block_period: 0,
+            delay_time_period: 0,
+        }
+    }
+
+    pub fn dummy_verify_non_membership_msg(
+        &self,
+        height: Height,
+        path: &[u8],
+    ) -> VerifyNonMembershipMsgRaw {
+        VerifyNonMembershipMsgRaw {
+            proof: self.dummy_storage_proof(path),
+            merkle_path: MerklePath {
+                key_path: vec![b"ibc".to_vec().into(), path.to_vec().into()],
+            },
+            height: height.into(),
+            delay_block_period: 0,
+            delay_time_period: 0,
+        }
+    }
+}
+
+impl OpStackClient {
+    /// Returns the L2 height of the output of the given index.
+    pub fn height(output_index: u64) -> Height {
+        Height::new(0, Self::output_block_number(output_index)).expect("never fails")
+    }
+
+    pub fn output_block_number(output_index: u64) -> u64 {
+        L2_BLOCKS_PER_OUTPUT * (output_index + 1)
+    }
+
+    /// Returns the L1 slot at which the output of the given index is
+    /// proposed.
+    pub fn proposal_slot(output_index: u64) -> u64 {
+        output_index + 2
+    }
+
+    /// Returns the index of the output committing to the L2 block at the
+    /// given height.
+    pub fn output_index(height: Height) -> u64 {
+        height.revision_height() / L2_BLOCKS_PER_OUTPUT - 1
+    }
+
+    /// Returns the index of the output the client is created at.
+    pub fn trusted_output(fxt: &Fixture) -> u64 {
+        Self::output_index(fxt.trusted_height)
+    }
+
+    pub fn period(slot: u64) -> u64 {
+        slot / SLOTS_PER_PERIOD
+    }
+
+    pub fn l1_height(slot: u64) -> Height {
+        Height::new(0, slot + BLOCK_NUMBER_OFFSET).expect("never fails")
+    }
+
+    pub fn fork_parameters() -> ForkParameters {
+        let fork = |version: u8, epoch: u64| Fork {
+            version: [version, 0, 0, 0],
+            epoch,
+        };
+
+        ForkParameters {
+            genesis_fork_version: [0; 4],
+            genesis_slot: 0,
+            altair: fork(1, 0),
+            bellatrix: fork(2, 0),
+            capella: fork(3, 0),
+            deneb: fork(4, 0),
+            electra: fork(5, u64::MAX),
+        }
+    }
+
+    pub fn genesis_validators_root() -> Bytes32 {
+        [0x42; 32]
+    }
+
+    pub fn sync_committee(period: u64) -> TestSyncCommittee {
+        TestSyncCommittee::new(period, SYNC_COMMITTEE_SIZE)
+    }
+
+    pub fn dummy_client_state(fxt: &Fixture) -> ClientState {
+        ClientState {
+            l1_client: fxt.config.dummy_l1_client_state(),
+            l2_chain_id: 10,
+            l2_output_oracle_address: fxt.config.l2_output_oracle_address,
+            l2_outputs_slot: fxt.config.l2_outputs_slot,
+            finalization_period: fxt.config.finalization_period,
+            latest_height: fxt.trusted_height,
+            frozen_height: None,
+            ibc_contract_address: fxt.config.ibc_contract_address,
+            ibc_commitment_slot: fxt.config.ibc_commitment_slot,
+        }
+    }
+
+    pub fn dummy_consensus_state(fxt: &Fixture) -> ConsensusState {
+        let trusted_output = Self::trusted_output(fxt);
+
+        let l2_block_header = fxt
+            .config
+            .l2_block_header(Self::output_block_number(trusted_output), false);
+
+        ConsensusState {
+            l1_height: Self::l1_height(fxt.config.trusted_slot),
+            state_root: l2_block_header.state_root,
+            storage_root: fxt.config.l2_storage_trie().root().to_vec().into(),
+            timestamp: Timestamp::from_nanoseconds(l2_block_header.timestamp * 1_000_000_000),
+            l1_consensus_state: Some(fxt.config.dummy_l1_consensus_state()),
+        }
+    }
+}
+
+impl TestClient for OpStackClient {
+    type Config = OpStackConfig;
+
+    fn trusting_period(config: &OpStackConfig) -> Duration {
+        config.l1_trusting_period
+    }
+
+    fn trusted_height(_config: &OpStackConfig) -> Height {
+        Self::height(0)
+    }
+
+    fn client_state(fxt: &Fixture) -> Vec<u8> {
+        ClientState::encode_to_any_vec(Self::dummy_client_state(fxt))
+    }
+
+    fn consensus_state(fxt: &Fixture) -> Vec<u8> {
+        ConsensusState::encode_to_any_vec(Self::dummy_consensus_state(fxt))
+    }
+
+    /// Constructs the header of the output committing to the target height,
+    /// which must be the L2 block of an output, proven against the trusted
+    /// L1 consensus state.
+    fn header(fxt: &Fixture, target_height: Height) -> Vec<u8> {
+        Header::encode_to_any_vec(
+            fxt.config
+                .dummy_header(fxt.config.trusted_slot, Self::output_index(target_height)),
+        )
+    }
+
+    /// Constructs an L1 misbehaviour of two headers finalizing different
+    /// beacon blocks at the first slot of the next epoch.
+    fn misbehaviour(fxt: &Fixture) -> Vec<u8> {
+        L1Misbehaviour::encode_to_any_vec(
+            fxt.config
+                .dummy_l1_misbehaviour(fxt.config.trusted_slot + SLOTS_PER_EPOCH),
+        )
+    }
+}
+
+/// Encodes an account with the given nonce and storage root, and no balance
+/// nor code.
+fn account(nonce: u64, storage_root: Bytes32) -> Vec<u8> {
+    rlp_list(&[
+        rlp_bytes(trim_leading_zeros(&nonce.to_be_bytes())),
+        rlp_bytes(&[]),
+        rlp_bytes(&storage_root),
+        rlp_bytes(&keccak256([])),
+    ])
+}
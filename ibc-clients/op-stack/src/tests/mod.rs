@@ -0,0 +1,358 @@
+use std::time::Duration;
+
+use cosmwasm_std::from_json;
+use cosmwasm_std::testing::mock_dependencies;
+use ibc_client_cw::types::{ContractResult, MigrationPrefix};
+use ibc_client_cw::utils::AnyCodec;
+use ibc_client_cw_testkit::scenarios;
+use ibc_client_ethereum_cw::types::{Header as L1Header, Misbehaviour as L1Misbehaviour};
+use ibc_core::client::types::Status;
+
+use crate::client_type::OpStackClient;
+use crate::types::Header;
+
+pub mod fixture;
+
+use fixture::{Fixture, OpStackConfig};
+
+#[test]
+fn test_cw_op_stack_testkit_scenarios() {
+    // The heights of the client are the L2 blocks of the outputs, one every
+    // six blocks, which the update scenario does not target.
+    let fxt = Fixture::default();
+
+    scenarios::create_client_ok(&fxt);
+    scenarios::freeze_on_misbehaviour(&fxt);
+    scenarios::recovery_client_ok(&fxt);
+    scenarios::client_expiry(&fxt);
+}
+
+#[test]
+fn test_cw_op_stack_update_client_ok() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    for output_index in [3, 9] {
+        let resp = fxt
+            .update_client_with(
+                deps.as_mut(),
+                Header::encode_to_any_vec(
+                    fxt.config
+                        .dummy_header(fxt.config.trusted_slot, output_index),
+                ),
+            )
+            .unwrap();
+
+        let contract_result: ContractResult = from_json(resp.data.unwrap()).unwrap();
+
+        assert_eq!(
+            contract_result.heights,
+            Some(vec![OpStackClient::height(output_index)])
+        );
+    }
+
+    // An output installed already can be proven again.
+    fxt.update_client_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(fxt.config.dummy_header(fxt.config.trusted_slot, 3)),
+    )
+    .unwrap();
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+}
+
+#[test]
+fn test_cw_op_stack_pending_output_rejected() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    // The output 10 is proposed 48 seconds before the L1 consensus state,
+    // within the finalization period.
+    assert!(!fxt.is_valid_client_message(
+        deps.as_ref(),
+        Header::encode_to_any_vec(fxt.config.dummy_header(fxt.config.trusted_slot, 10))
+    ));
+
+    // The output 20 is not proposed yet.
+    assert!(!fxt.is_valid_client_message(
+        deps.as_ref(),
+        Header::encode_to_any_vec(fxt.config.dummy_header(fxt.config.trusted_slot, 20))
+    ));
+
+    // The proposal time is the one stored by the oracle, so that the
+    // finalization period cannot be shortened by the header.
+    let mut header = fxt.config.dummy_header(fxt.config.trusted_slot, 10);
+    header.output_proposal.timestamp -= 60;
+
+    assert!(!fxt.is_valid_client_message(deps.as_ref(), Header::encode_to_any_vec(header)));
+}
+
+#[test]
+fn test_cw_op_stack_invalid_header_rejected() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let slot = fxt.config.trusted_slot;
+
+    // The output proposal must be stored at its index.
+    let mut header = fxt.config.dummy_header(slot, 5);
+    header.output_proposal.output_index = 4;
+
+    assert!(!fxt.is_valid_client_message(deps.as_ref(), Header::encode_to_any_vec(header)));
+
+    // The L1 consensus state must exist.
+    let mut header = fxt.config.dummy_header(slot, 5);
+    header.l1_height = OpStackClient::l1_height(slot + 1);
+
+    assert!(!fxt.is_valid_client_message(deps.as_ref(), Header::encode_to_any_vec(header)));
+
+    // The output root must be the one of the proposal.
+    let mut header = fxt.config.dummy_header(slot, 5);
+    header.output_root_proof.message_passer_storage_root = [0xff; 32];
+
+    assert!(!fxt.is_valid_client_message(deps.as_ref(), Header::encode_to_any_vec(header)));
+
+    // The output root must commit to the L2 block header.
+    let mut header = fxt.config.dummy_header(slot, 5);
+    header.l2_block_header = fxt
+        .config
+        .l2_block_header(OpStackClient::output_block_number(5) + 1, false);
+
+    assert!(!fxt.is_valid_client_message(deps.as_ref(), Header::encode_to_any_vec(header)));
+
+    // The IBC contract account must commit to the storage root.
+    let mut header = fxt.config.dummy_header(slot, 5);
+    header.ibc_account.storage_root = [0xff; 32];
+
+    assert!(!fxt.is_valid_client_message(deps.as_ref(), Header::encode_to_any_vec(header)));
+}
+
+#[test]
+fn test_cw_op_stack_verify_membership() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(fxt.config.dummy_header(fxt.config.trusted_slot, 5)),
+    )
+    .unwrap();
+
+    let height = OpStackClient::height(5);
+
+    for (path, value) in &fxt.config.commitments {
+        fxt.verify_membership(
+            deps.as_mut(),
+            fxt.config.dummy_verify_membership_msg(height, path, value),
+        )
+        .unwrap();
+
+        assert!(fxt
+            .verify_membership(
+                deps.as_mut(),
+                fxt.config
+                    .dummy_verify_membership_msg(height, path, b"other-value"),
+            )
+            .is_err());
+    }
+
+    let absent_path = b"receipts/ports/transfer/channels/channel-0/sequences/1";
+
+    fxt.verify_non_membership(
+        deps.as_mut(),
+        fxt.config
+            .dummy_verify_non_membership_msg(height, absent_path),
+    )
+    .unwrap();
+
+    let (path, _) = &fxt.config.commitments[0];
+
+    assert!(fxt
+        .verify_non_membership(
+            deps.as_mut(),
+            fxt.config.dummy_verify_non_membership_msg(height, path),
+        )
+        .is_err());
+}
+
+#[test]
+fn test_cw_op_stack_update_l1_client_ok() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let l1_slot = fxt.config.trusted_slot + 8;
+
+    // The output 13 is not finalized as of the trusted L1 slot.
+    assert!(!fxt.is_valid_client_message(
+        deps.as_ref(),
+        Header::encode_to_any_vec(fxt.config.dummy_header(fxt.config.trusted_slot, 13))
+    ));
+
+    let resp = fxt
+        .update_client_with(
+            deps.as_mut(),
+            L1Header::encode_to_any_vec(
+                fxt.config.dummy_l1_header(fxt.config.trusted_slot, l1_slot),
+            ),
+        )
+        .unwrap();
+
+    // An L1 update installs no L2 consensus state.
+    let contract_result: ContractResult = from_json(resp.data.unwrap()).unwrap();
+
+    assert_eq!(contract_result.heights, Some(vec![fxt.trusted_height]));
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(fxt.config.dummy_header(l1_slot, 13)),
+    )
+    .unwrap();
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+}
+
+#[test]
+fn test_cw_op_stack_freeze_on_conflicting_output() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(fxt.config.dummy_header(fxt.config.trusted_slot, 5)),
+    )
+    .unwrap();
+
+    // The oracle replaces its outputs with those of a fork of the L2.
+    let l1_slot = fxt.config.trusted_slot + 8;
+
+    let mut forked = fxt.clone();
+    forked.config.fork_slot = Some(l1_slot);
+
+    forked
+        .update_client_with(
+            deps.as_mut(),
+            L1Header::encode_to_any_vec(
+                forked
+                    .config
+                    .dummy_l1_header(fxt.config.trusted_slot, l1_slot),
+            ),
+        )
+        .unwrap();
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+
+    forked.update_client_on_misbehaviour_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(forked.config.dummy_header(l1_slot, 5)),
+    );
+
+    fxt.check_client_status(deps.as_ref(), Status::Frozen);
+}
+
+#[test]
+fn test_cw_op_stack_freeze_on_l1_misbehaviour() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    fxt.update_client_on_misbehaviour_with(
+        deps.as_mut(),
+        L1Misbehaviour::encode_to_any_vec(
+            fxt.config
+                .dummy_l1_misbehaviour(fxt.config.trusted_slot + 8),
+        ),
+    );
+
+    fxt.check_client_status(deps.as_ref(), Status::Frozen);
+
+    assert!(!fxt.is_valid_client_message(
+        deps.as_ref(),
+        Header::encode_to_any_vec(fxt.config.dummy_header(fxt.config.trusted_slot, 5))
+    ));
+}
+
+#[test]
+fn test_cw_op_stack_recovery_client_ok() {
+    let mut fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    // ------------------- Create subject client -------------------
+
+    fxt.set_migration_prefix(MigrationPrefix::Subject);
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    // ------------------- Freeze subject client -------------------
+
+    fxt.update_client_on_misbehaviour_with(
+        deps.as_mut(),
+        L1Misbehaviour::encode_to_any_vec(
+            fxt.config
+                .dummy_l1_misbehaviour(fxt.config.trusted_slot + 8),
+        ),
+    );
+
+    fxt.check_client_status(deps.as_ref(), Status::Frozen);
+
+    // ------------------- Create substitute client -------------------
+
+    fxt.set_migration_prefix(MigrationPrefix::Substitute);
+
+    fxt.config.trusted_slot += 24;
+    fxt.trusted_height = OpStackClient::height(15);
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    // ------------------- Recover subject client -------------------
+
+    let resp = fxt.recover_client(deps.as_mut()).unwrap();
+
+    assert_eq!(0, resp.messages.len());
+
+    fxt.set_migration_prefix(MigrationPrefix::Subject);
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+
+    // ------------------- Update from the substitute L1 state -------------------
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(fxt.config.dummy_header(fxt.config.trusted_slot, 25)),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_cw_op_stack_client_expiry() {
+    let fxt = Fixture::new(OpStackConfig {
+        l1_trusting_period: Duration::from_secs(60),
+        ..Default::default()
+    });
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    fxt.check_client_status(deps.as_ref(), Status::Expired);
+}
@@ -0,0 +1,279 @@
+//! Client messages accepted by the OP-stack light client, besides the client
+//! messages of its L1 client.
+use ibc_client_ethereum_cw::error::EthereumError;
+use ibc_client_ethereum_cw::mpt::{keccak256, RlpItem};
+use ibc_client_ethereum_cw::ssz::Bytes32;
+use ibc_client_ethereum_cw::types::AccountUpdate;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Height;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::proto::{Any, Protobuf};
+
+use super::fixed_bytes;
+use super::proto::{
+    Header as RawHeader, OutputProposalProof as RawOutputProposalProof,
+    OutputRootProof as RawOutputRootProof,
+};
+
+pub const OP_STACK_HEADER_TYPE_URL: &str = "/ibc.lightclients.opstack.v1.Header";
+
+/// The only output root version defined by the OP-stack so far.
+pub const OUTPUT_VERSION_V0: Bytes32 = [0; 32];
+
+/// The preimage of an L2 output root.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OutputRootProof {
+    pub version: Bytes32,
+    pub state_root: Bytes32,
+    pub message_passer_storage_root: Bytes32,
+    pub latest_block_hash: Bytes32,
+}
+
+impl OutputRootProof {
+    /// Computes the output root, i.e. the keccak256 hash of the concatenated
+    /// fields.
+    pub fn output_root(&self) -> Bytes32 {
+        keccak256(
+            [
+                self.version,
+                self.state_root,
+                self.message_passer_storage_root,
+                self.latest_block_hash,
+            ]
+            .concat(),
+        )
+    }
+}
+
+impl TryFrom<RawOutputRootProof> for OutputRootProof {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawOutputRootProof) -> Result<Self, Self::Error> {
+        Ok(Self {
+            version: fixed_bytes("output version", raw.version)?,
+            state_root: fixed_bytes("L2 state root", raw.state_root)?,
+            message_passer_storage_root: fixed_bytes(
+                "message passer storage root",
+                raw.message_passer_storage_root,
+            )?,
+            latest_block_hash: fixed_bytes("L2 block hash", raw.latest_block_hash)?,
+        })
+    }
+}
+
+impl From<OutputRootProof> for RawOutputRootProof {
+    fn from(value: OutputRootProof) -> Self {
+        Self {
+            version: value.version.to_vec(),
+            state_root: value.state_root.to_vec(),
+            message_passer_storage_root: value.message_passer_storage_root.to_vec(),
+            latest_block_hash: value.latest_block_hash.to_vec(),
+        }
+    }
+}
+
+/// The proof of an output proposal stored by the L2 output oracle, against
+/// the L1 execution state root.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OutputProposalProof {
+    /// The index of the proposal in the `l2Outputs` array of the oracle.
+    pub output_index: u64,
+    /// The L1 time at which the output was proposed, in seconds.
+    pub timestamp: u64,
+    /// The proof of the oracle account, committing to its storage root.
+    pub oracle_account: AccountUpdate,
+    /// The storage proof of the output root slot of the proposal.
+    pub output_root_proof: Vec<Vec<u8>>,
+    /// The storage proof of the slot packing the timestamp and the L2 block
+    /// number of the proposal.
+    pub metadata_proof: Vec<Vec<u8>>,
+}
+
+impl TryFrom<RawOutputProposalProof> for OutputProposalProof {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawOutputProposalProof) -> Result<Self, Self::Error> {
+        Ok(Self {
+            output_index: raw.output_index,
+            timestamp: raw.timestamp,
+            oracle_account: raw
+                .oracle_account
+                .ok_or(DecodingError::missing_raw_data("oracle account"))?
+                .try_into()?,
+            output_root_proof: raw.output_root_proof,
+            metadata_proof: raw.metadata_proof,
+        })
+    }
+}
+
+impl From<OutputProposalProof> for RawOutputProposalProof {
+    fn from(value: OutputProposalProof) -> Self {
+        Self {
+            output_index: value.output_index,
+            timestamp: value.timestamp,
+            oracle_account: Some(value.oracle_account.into()),
+            output_root_proof: value.output_root_proof,
+            metadata_proof: value.metadata_proof,
+        }
+    }
+}
+
+/// An RLP-encoded L2 block header, along with the fields the client uses.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct L2BlockHeader {
+    pub rlp: Vec<u8>,
+    pub number: u64,
+    /// The block time, in seconds.
+    pub timestamp: u64,
+    pub state_root: Bytes32,
+}
+
+impl L2BlockHeader {
+    /// Returns the block hash, i.e. the keccak256 hash of the encoded header.
+    pub fn hash(&self) -> Bytes32 {
+        keccak256(&self.rlp)
+    }
+}
+
+impl TryFrom<Vec<u8>> for L2BlockHeader {
+    type Error = DecodingError;
+
+    fn try_from(rlp: Vec<u8>) -> Result<Self, Self::Error> {
+        let invalid =
+            |e: EthereumError| DecodingError::invalid_raw_data(format!("L2 block header: {e}"));
+
+        let (number, timestamp, state_root) = {
+            let header = RlpItem::decode(&rlp).map_err(invalid)?;
+            let fields = header.as_list().map_err(invalid)?;
+
+            // [parent_hash, ommers_hash, coinbase, state_root, transactions_root,
+            //  receipts_root, logs_bloom, difficulty, number, gas_limit, gas_used,
+            //  timestamp, ...]
+            if fields.len() < 12 {
+                return Err(DecodingError::invalid_raw_data(
+                    "L2 block header must have at least 12 fields",
+                ));
+            }
+
+            (
+                rlp_u64("L2 block number", fields[8].as_bytes().map_err(invalid)?)?,
+                rlp_u64(
+                    "L2 block timestamp",
+                    fields[11].as_bytes().map_err(invalid)?,
+                )?,
+                fixed_bytes::<32>(
+                    "L2 state root",
+                    fields[3].as_bytes().map_err(invalid)?.to_vec(),
+                )?,
+            )
+        };
+
+        Ok(Self {
+            rlp,
+            number,
+            timestamp,
+            state_root,
+        })
+    }
+}
+
+/// Decodes an RLP-encoded integer of at most eight bytes.
+fn rlp_u64(name: &str, bytes: &[u8]) -> Result<u64, DecodingError> {
+    if bytes.len() > 8 || bytes.first() == Some(&0) {
+        return Err(DecodingError::invalid_raw_data(format!(
+            "{name} is not a canonical 64-bit integer"
+        )));
+    }
+
+    Ok(bytes.iter().fold(0, |acc, b| (acc << 8) | u64::from(*b)))
+}
+
+/// The header submitted to update the client to an L2 output finalized on
+/// the L1 at `l1_height`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Header {
+    /// The height of the L1 consensus state whose execution state root the
+    /// output proposal is proven against.
+    pub l1_height: Height,
+    pub output_proposal: OutputProposalProof,
+    pub output_root_proof: OutputRootProof,
+    /// The L2 block committed by the output root.
+    pub l2_block_header: L2BlockHeader,
+    /// The proof of the IBC contract account against the L2 state root,
+    /// yielding the storage root used for membership proofs.
+    pub ibc_account: AccountUpdate,
+}
+
+impl Header {
+    /// Returns the height of the header, i.e. the L2 block number of the
+    /// output.
+    pub fn height(&self) -> Result<Height, ClientError> {
+        Height::new(0, self.l2_block_header.number)
+    }
+}
+
+impl Protobuf<RawHeader> for Header {}
+
+impl TryFrom<RawHeader> for Header {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawHeader) -> Result<Self, Self::Error> {
+        Ok(Self {
+            l1_height: raw
+                .l1_height
+                .ok_or(DecodingError::missing_raw_data("L1 height"))?
+                .try_into()?,
+            output_proposal: raw
+                .output_proposal
+                .ok_or(DecodingError::missing_raw_data("output proposal proof"))?
+                .try_into()?,
+            output_root_proof: raw
+                .output_root_proof
+                .ok_or(DecodingError::missing_raw_data("output root proof"))?
+                .try_into()?,
+            l2_block_header: raw.l2_block_header.try_into()?,
+            ibc_account: raw
+                .ibc_account
+                .ok_or(DecodingError::missing_raw_data("IBC contract account"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<Header> for RawHeader {
+    fn from(value: Header) -> Self {
+        Self {
+            l1_height: Some(value.l1_height.into()),
+            output_proposal: Some(value.output_proposal.into()),
+            output_root_proof: Some(value.output_root_proof.into()),
+            l2_block_header: value.l2_block_header.rlp,
+            ibc_account: Some(value.ibc_account.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let OP_STACK_HEADER_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawHeader>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: OP_STACK_HEADER_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        Self {
+            type_url: OP_STACK_HEADER_TYPE_URL.to_string(),
+            value: Protobuf::<RawHeader>::encode_vec(header),
+        }
+    }
+}
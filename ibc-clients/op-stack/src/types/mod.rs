@@ -0,0 +1,20 @@
+mod header;
+pub mod proto;
+
+pub use header::*;
+use ibc_core::host::types::error::DecodingError;
+
+/// Converts raw bytes into a fixed-size array, failing if the length does not
+/// match.
+pub(crate) fn fixed_bytes<const N: usize>(
+    name: &str,
+    bytes: Vec<u8>,
+) -> Result<[u8; N], DecodingError> {
+    let actual = bytes.len();
+
+    bytes.try_into().map_err(|_| {
+        DecodingError::invalid_raw_data(format!(
+            "{name} must be {N} bytes long, found {actual} bytes"
+        ))
+    })
+}
@@ -0,0 +1,84 @@
+//! Protobuf definitions of the OP-stack light client types, under the
+//! `ibc.lightclients.opstack.v1` package. The L1 client state and consensus
+//! state, as well as the account proofs, are those of the Ethereum light
+//! client.
+use ibc_client_ethereum_cw::types::proto::{
+    AccountUpdate, ClientState as L1ClientState, ConsensusState as L1ConsensusState,
+};
+use ibc_core::client::types::proto::v1::Height as RawHeight;
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ClientState {
+    #[prost(message, optional, tag = "1")]
+    pub l1_client_state: Option<L1ClientState>,
+    #[prost(uint64, tag = "2")]
+    pub l2_chain_id: u64,
+    #[prost(bytes = "vec", tag = "3")]
+    pub l2_output_oracle_address: Vec<u8>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub l2_outputs_slot: Vec<u8>,
+    #[prost(uint64, tag = "5")]
+    pub finalization_period: u64,
+    #[prost(message, optional, tag = "6")]
+    pub latest_height: Option<RawHeight>,
+    #[prost(message, optional, tag = "7")]
+    pub frozen_height: Option<RawHeight>,
+    #[prost(bytes = "vec", tag = "8")]
+    pub ibc_contract_address: Vec<u8>,
+    #[prost(bytes = "vec", tag = "9")]
+    pub ibc_commitment_slot: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ConsensusState {
+    #[prost(message, optional, tag = "1")]
+    pub l1_height: Option<RawHeight>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub state_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub storage_root: Vec<u8>,
+    #[prost(uint64, tag = "4")]
+    pub timestamp: u64,
+    #[prost(message, optional, tag = "5")]
+    pub l1_consensus_state: Option<L1ConsensusState>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct OutputRootProof {
+    #[prost(bytes = "vec", tag = "1")]
+    pub version: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub state_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub message_passer_storage_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub latest_block_hash: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct OutputProposalProof {
+    #[prost(uint64, tag = "1")]
+    pub output_index: u64,
+    #[prost(uint64, tag = "2")]
+    pub timestamp: u64,
+    #[prost(message, optional, tag = "3")]
+    pub oracle_account: Option<AccountUpdate>,
+    #[prost(bytes = "vec", repeated, tag = "4")]
+    pub output_root_proof: Vec<Vec<u8>>,
+    #[prost(bytes = "vec", repeated, tag = "5")]
+    pub metadata_proof: Vec<Vec<u8>>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Header {
+    #[prost(message, optional, tag = "1")]
+    pub l1_height: Option<RawHeight>,
+    #[prost(message, optional, tag = "2")]
+    pub output_proposal: Option<OutputProposalProof>,
+    #[prost(message, optional, tag = "3")]
+    pub output_root_proof: Option<OutputRootProof>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub l2_block_header: Vec<u8>,
+    #[prost(message, optional, tag = "5")]
+    pub ibc_account: Option<AccountUpdate>,
+}
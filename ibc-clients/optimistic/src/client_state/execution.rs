@@ -0,0 +1,180 @@
+use ibc_core::client::context::client_state::{ClientStateCommon, ClientStateExecution};
+use ibc_core::client::context::prelude::*;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Height;
+use ibc_core::host::types::identifiers::ClientId;
+use ibc_core::host::types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc_core::primitives::proto::Any;
+
+use super::{consensus_state_at, ClientState};
+use crate::consensus_state::ConsensusState;
+use crate::secp256k1::Secp256k1Verifier;
+use crate::types::{StateProposal, OPTIMISTIC_STATE_PROPOSAL_TYPE_URL};
+
+impl<E> ClientStateExecution<E> for ClientState
+where
+    E: ExtClientExecutionContext + Secp256k1Verifier,
+    E::ClientStateRef: From<Self>,
+    ConsensusState: Convertible<E::ConsensusStateRef>,
+    <ConsensusState as TryFrom<E::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    fn initialise(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        let consensus_state: ConsensusState = consensus_state.try_into()?;
+
+        ctx.store_client_state(ClientStatePath::new(client_id.clone()), self.clone().into())?;
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                client_id.clone(),
+                self.latest_height.revision_number(),
+                self.latest_height.revision_height(),
+            ),
+            consensus_state.into(),
+        )?;
+        ctx.store_update_meta(
+            client_id.clone(),
+            self.latest_height,
+            ctx.host_timestamp()?,
+            ctx.host_height()?,
+        )?;
+
+        Ok(())
+    }
+
+    /// Installs the proposed consensus state, pending until the end of its
+    /// challenge period, which starts with the processed time recorded in
+    /// the update metadata.
+    fn update_state(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        let proposal = match client_message.type_url.as_str() {
+            OPTIMISTIC_STATE_PROPOSAL_TYPE_URL => StateProposal::try_from(client_message)?,
+            header_type => return Err(ClientError::InvalidHeaderType(header_type.to_owned())),
+        };
+
+        let height = proposal.height;
+
+        if consensus_state_at(ctx, client_id, height).is_ok() {
+            // if we already had the proposal installed by a previous
+            // relayer then this is a no-op, which must not restart the
+            // challenge period.
+            return Ok(vec![height]);
+        }
+
+        let new_consensus_state: ConsensusState = (&proposal).into();
+
+        let new_client_state = if height > self.latest_height {
+            Self {
+                latest_height: height,
+                ..self.clone()
+            }
+        } else {
+            self.clone()
+        };
+
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                client_id.clone(),
+                height.revision_number(),
+                height.revision_height(),
+            ),
+            new_consensus_state.into(),
+        )?;
+        ctx.store_client_state(
+            ClientStatePath::new(client_id.clone()),
+            new_client_state.into(),
+        )?;
+        ctx.store_update_meta(
+            client_id.clone(),
+            height,
+            ctx.host_timestamp()?,
+            ctx.host_height()?,
+        )?;
+
+        Ok(vec![height])
+    }
+
+    fn update_state_on_misbehaviour(
+        &self,
+        ctx: &mut E,
+        client_id: &ClientId,
+        _client_message: Any,
+    ) -> Result<(), ClientError> {
+        let frozen_client_state = Self {
+            frozen_height: Some(Height::min(0)),
+            ..self.clone()
+        };
+
+        ctx.store_client_state(
+            ClientStatePath::new(client_id.clone()),
+            frozen_client_state.into(),
+        )?;
+
+        Ok(())
+    }
+
+    fn update_state_on_upgrade(
+        &self,
+        _ctx: &mut E,
+        _client_id: &ClientId,
+        _upgraded_client_state: Any,
+        _upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        Err(ClientError::ClientSpecific {
+            description: "upgrades are not supported by the optimistic client".to_string(),
+        })
+    }
+
+    /// Recovers the client with the proposer and challengers of the
+    /// substitute. The consensus state of the substitute goes through a new
+    /// challenge period.
+    fn update_on_recovery(
+        &self,
+        ctx: &mut E,
+        subject_client_id: &ClientId,
+        substitute_client_state: Any,
+        substitute_consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        let substitute_client_state = Self::try_from(substitute_client_state)?;
+        let new_consensus_state: ConsensusState = substitute_consensus_state.try_into()?;
+
+        let new_client_state = Self {
+            proposer: substitute_client_state.proposer,
+            challengers: substitute_client_state.challengers,
+            latest_height: substitute_client_state.latest_height,
+            frozen_height: None,
+            trusting_period: substitute_client_state.trusting_period,
+            ..self.clone()
+        };
+
+        let latest_height = new_client_state.latest_height();
+
+        ctx.store_consensus_state(
+            ClientConsensusStatePath::new(
+                subject_client_id.clone(),
+                latest_height.revision_number(),
+                latest_height.revision_height(),
+            ),
+            new_consensus_state.into(),
+        )?;
+        ctx.store_client_state(
+            ClientStatePath::new(subject_client_id.clone()),
+            new_client_state.into(),
+        )?;
+        ctx.store_update_meta(
+            subject_client_id.clone(),
+            latest_height,
+            ctx.host_timestamp()?,
+            ctx.host_height()?,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,169 @@
+//! Implements the optimistic `ClientState` along with the `ClientStateCommon`,
+//! `ClientStateValidation` and `ClientStateExecution` traits.
+use core::time::Duration;
+use std::collections::BTreeSet;
+
+use ibc_core::client::types::Height;
+use ibc_core::commitment_types::specs::ProofSpecs;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::proto::{Any, Protobuf};
+
+use crate::types::proto::ClientState as RawClientState;
+use crate::types::{fixed_bytes, Bytes33};
+
+mod common;
+mod execution;
+mod validation;
+
+pub use common::*;
+pub use validation::*;
+
+pub const OPTIMISTIC_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.optimistic.v1.ClientState";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClientState {
+    /// The identifier of the counterparty chain, part of every document
+    /// signed by the proposer and the challengers.
+    pub chain_id: String,
+    /// The key of the proposer, trusted to propose state roots that are
+    /// accepted without verification.
+    pub proposer: Bytes33,
+    /// The keys of the challengers, any of which can dispute a pending state
+    /// root with a fraud proof.
+    pub challengers: Vec<Bytes33>,
+    /// The duration after the processing of a consensus state during which
+    /// it can be disputed, and cannot verify proofs.
+    pub challenge_period: Duration,
+    pub latest_height: Height,
+    pub frozen_height: Option<Height>,
+    /// The duration after which a proposed state is no longer trusted.
+    pub trusting_period: Duration,
+    /// The ICS-23 proof specs of the counterparty state commitments.
+    pub proof_specs: ProofSpecs,
+}
+
+impl ClientState {
+    pub fn is_frozen(&self) -> bool {
+        self.frozen_height.is_some()
+    }
+
+    fn validate(&self) -> Result<(), DecodingError> {
+        if self.chain_id.is_empty() {
+            return Err(DecodingError::missing_raw_data("chain ID"));
+        }
+
+        if self.challengers.is_empty() {
+            return Err(DecodingError::missing_raw_data("challengers"));
+        }
+
+        let distinct_challengers: BTreeSet<_> = self.challengers.iter().collect();
+
+        if distinct_challengers.len() != self.challengers.len() {
+            return Err(DecodingError::invalid_raw_data(
+                "challengers must be distinct",
+            ));
+        }
+
+        if self.challenge_period.is_zero() {
+            return Err(DecodingError::invalid_raw_data(
+                "challenge period must be non-zero",
+            ));
+        }
+
+        // a consensus state must remain trusted for some time once usable
+        if self.challenge_period >= self.trusting_period {
+            return Err(DecodingError::invalid_raw_data(format!(
+                "challenge period `{:?}` must be shorter than the trusting period `{:?}`",
+                self.challenge_period, self.trusting_period
+            )));
+        }
+
+        if self.proof_specs.is_empty() {
+            return Err(DecodingError::missing_raw_data("proof specs"));
+        }
+
+        Ok(())
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        let client_state = Self {
+            chain_id: raw.chain_id,
+            proposer: fixed_bytes("proposer public key", raw.proposer)?,
+            challengers: raw
+                .challengers
+                .into_iter()
+                .map(|challenger| fixed_bytes("challenger public key", challenger))
+                .collect::<Result<_, _>>()?,
+            challenge_period: Duration::from_nanos(raw.challenge_period),
+            latest_height: raw
+                .latest_height
+                .ok_or(DecodingError::missing_raw_data("latest height"))?
+                .try_into()?,
+            frozen_height: raw.frozen_height.map(TryInto::try_into).transpose()?,
+            trusting_period: Duration::from_nanos(raw.trusting_period),
+            proof_specs: raw
+                .proof_specs
+                .try_into()
+                .map_err(|e| DecodingError::invalid_raw_data(format!("proof specs: {e}")))?,
+        };
+
+        client_state.validate()?;
+
+        Ok(client_state)
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            chain_id: value.chain_id,
+            proposer: value.proposer.to_vec(),
+            challengers: value.challengers.into_iter().map(Vec::from).collect(),
+            challenge_period: value
+                .challenge_period
+                .as_nanos()
+                .try_into()
+                .expect("no overflow"),
+            latest_height: Some(value.latest_height.into()),
+            frozen_height: value.frozen_height.map(Into::into),
+            trusting_period: value
+                .trusting_period
+                .as_nanos()
+                .try_into()
+                .expect("no overflow"),
+            proof_specs: value.proof_specs.into(),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let OPTIMISTIC_CLIENT_STATE_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawClientState>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: OPTIMISTIC_CLIENT_STATE_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Self {
+            type_url: OPTIMISTIC_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientState>::encode_vec(client_state),
+        }
+    }
+}
@@ -0,0 +1,273 @@
+use core::time::Duration;
+
+use ibc_core::client::context::client_state::ClientStateValidation;
+use ibc_core::client::context::prelude::*;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::{Height, Status};
+use ibc_core::host::types::identifiers::ClientId;
+use ibc_core::host::types::path::ClientConsensusStatePath;
+use ibc_core::primitives::proto::Any;
+use sha2::{Digest, Sha256};
+
+use super::{consensus_status, ClientState};
+use crate::consensus_state::ConsensusState;
+use crate::error::OptimisticError;
+use crate::secp256k1::Secp256k1Verifier;
+use crate::types::{
+    FraudProof, StateProposal, OPTIMISTIC_FRAUD_PROOF_TYPE_URL, OPTIMISTIC_STATE_PROPOSAL_TYPE_URL,
+};
+
+impl<V> ClientStateValidation<V> for ClientState
+where
+    V: ExtClientValidationContext + Secp256k1Verifier,
+    ConsensusState: Convertible<V::ConsensusStateRef>,
+    <ConsensusState as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    fn verify_client_message(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        match client_message.type_url.as_str() {
+            OPTIMISTIC_STATE_PROPOSAL_TYPE_URL => {
+                let proposal = StateProposal::try_from(client_message)?;
+                self.verify_proposal(ctx, &proposal)
+            }
+            OPTIMISTIC_FRAUD_PROOF_TYPE_URL => {
+                let fraud_proof = FraudProof::try_from(client_message)?;
+                self.verify_fraud_proof(ctx, client_id, &fraud_proof)
+            }
+            header_type => Err(ClientError::InvalidHeaderType(header_type.to_owned())),
+        }
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        match client_message.type_url.as_str() {
+            OPTIMISTIC_STATE_PROPOSAL_TYPE_URL => {
+                let proposal = StateProposal::try_from(client_message)?;
+                self.check_for_misbehaviour_on_proposal(ctx, client_id, &proposal)
+            }
+            OPTIMISTIC_FRAUD_PROOF_TYPE_URL => {
+                let fraud_proof = FraudProof::try_from(client_message)?;
+
+                let consensus_state = consensus_state_at(ctx, client_id, fraud_proof.height)?;
+
+                Ok(consensus_state.root.as_bytes() != fraud_proof.root.as_slice())
+            }
+            header_type => Err(ClientError::InvalidHeaderType(header_type.to_owned())),
+        }
+    }
+
+    fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError> {
+        if self.is_frozen() {
+            return Ok(Status::Frozen);
+        }
+
+        // if the client state does not have an associated consensus state for
+        // its latest height then it must be expired
+        let Ok(latest_consensus_state) = consensus_state_at(ctx, client_id, self.latest_height)
+        else {
+            return Ok(Status::Expired);
+        };
+
+        consensus_status(
+            &latest_consensus_state,
+            &ctx.host_timestamp()?,
+            self.trusting_period,
+        )
+    }
+
+    /// The subject and substitute client states match if they track the same
+    /// chain with the same challenge period and proof specs. The proposer and
+    /// the challengers may differ, as a frozen client is typically recovered
+    /// because its proposer misbehaved.
+    fn check_substitute(&self, _ctx: &V, substitute_client_state: Any) -> Result<(), ClientError> {
+        let substitute_client_state = Self::try_from(substitute_client_state)?;
+
+        let normalized = Self {
+            proposer: self.proposer,
+            challengers: self.challengers.clone(),
+            latest_height: self.latest_height,
+            frozen_height: self.frozen_height,
+            trusting_period: self.trusting_period,
+            ..substitute_client_state
+        };
+
+        (&normalized == self)
+            .then_some(())
+            .ok_or(ClientError::FailedToVerifyClientRecoveryStates)
+    }
+}
+
+impl ClientState {
+    /// Verifies that the proposal is signed by the proposer. The proposed
+    /// state itself is not verified, but can be disputed during the
+    /// challenge period.
+    pub fn verify_proposal<V>(&self, ctx: &V, proposal: &StateProposal) -> Result<(), ClientError>
+    where
+        V: Secp256k1Verifier,
+    {
+        let message_hash: [u8; 32] = Sha256::digest(proposal.sign_doc(&self.chain_id)).into();
+
+        if !ctx.secp256k1_verify(&message_hash, &proposal.signature, &self.proposer) {
+            return Err(OptimisticError::InvalidProposerSignature.into());
+        }
+
+        Ok(())
+    }
+
+    /// Verifies that the fraud proof is signed by a challenger and disputes
+    /// a consensus state whose challenge period is still running.
+    pub fn verify_fraud_proof<V>(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        fraud_proof: &FraudProof,
+    ) -> Result<(), ClientError>
+    where
+        V: ExtClientValidationContext + Secp256k1Verifier,
+    {
+        let index = fraud_proof.challenger_index;
+
+        let challenger =
+            self.challengers
+                .get(index as usize)
+                .ok_or(OptimisticError::UnknownChallenger {
+                    index,
+                    size: self.challengers.len(),
+                })?;
+
+        let message_hash: [u8; 32] = Sha256::digest(fraud_proof.sign_doc(&self.chain_id)).into();
+
+        if !ctx.secp256k1_verify(&message_hash, &fraud_proof.signature, challenger) {
+            return Err(OptimisticError::InvalidChallengerSignature { index }.into());
+        }
+
+        if self
+            .pending_period(ctx, client_id, fraud_proof.height)?
+            .is_none()
+        {
+            return Err(OptimisticError::ChallengePeriodElapsed {
+                height: fraud_proof.height,
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the challenge period of the consensus state at `height`
+    /// has elapsed, so that it can be used to verify proofs.
+    pub fn verify_challenge_period_elapsed<V>(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        height: Height,
+    ) -> Result<(), ClientError>
+    where
+        V: ExtClientValidationContext,
+    {
+        if let Some(remaining) = self.pending_period(ctx, client_id, height)? {
+            return Err(OptimisticError::PendingConsensusState { height, remaining }.into());
+        }
+
+        Ok(())
+    }
+
+    /// Returns the remaining challenge period of the consensus state at
+    /// `height`, measured from the time it was processed, or `None` once it
+    /// has elapsed.
+    fn pending_period<V>(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        height: Height,
+    ) -> Result<Option<Duration>, ClientError>
+    where
+        V: ExtClientValidationContext,
+    {
+        let (processed_time, _) = ctx
+            .client_update_meta(client_id, &height)
+            .map_err(|_| OptimisticError::MissingConsensusState { height })?;
+
+        let elapsed = ctx
+            .host_timestamp()?
+            .duration_since(&processed_time)
+            .unwrap_or_default();
+
+        Ok(self
+            .challenge_period
+            .checked_sub(elapsed)
+            .filter(|remaining| !remaining.is_zero()))
+    }
+
+    /// Checks whether a proposal conflicts with the consensus state already
+    /// installed at its height, or breaks the monotonicity of the consensus
+    /// state timestamps.
+    pub fn check_for_misbehaviour_on_proposal<V>(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        proposal: &StateProposal,
+    ) -> Result<bool, ClientError>
+    where
+        V: ExtClientValidationContext,
+        ConsensusState: Convertible<V::ConsensusStateRef>,
+        <ConsensusState as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+    {
+        let height = proposal.height;
+        let proposed_consensus_state: ConsensusState = proposal.into();
+
+        if let Ok(existing_consensus_state) = consensus_state_at(ctx, client_id, height) {
+            return Ok(existing_consensus_state != proposed_consensus_state);
+        }
+
+        if let Some(prev_consensus_state) = ctx.prev_consensus_state(client_id, &height)? {
+            let prev_consensus_state: ConsensusState =
+                prev_consensus_state.try_into().map_err(Into::into)?;
+
+            if proposed_consensus_state.timestamp <= prev_consensus_state.timestamp {
+                return Ok(true);
+            }
+        }
+
+        if height < self.latest_height {
+            if let Some(next_consensus_state) = ctx.next_consensus_state(client_id, &height)? {
+                let next_consensus_state: ConsensusState =
+                    next_consensus_state.try_into().map_err(Into::into)?;
+
+                if proposed_consensus_state.timestamp >= next_consensus_state.timestamp {
+                    return Ok(true);
+                }
+            }
+        }
+
+        Ok(false)
+    }
+}
+
+/// Fetches the consensus state stored for the client at the given height.
+pub fn consensus_state_at<V>(
+    ctx: &V,
+    client_id: &ClientId,
+    height: Height,
+) -> Result<ConsensusState, ClientError>
+where
+    V: ExtClientValidationContext,
+    ConsensusState: Convertible<V::ConsensusStateRef>,
+    <ConsensusState as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    ctx.consensus_state(&ClientConsensusStatePath::new(
+        client_id.clone(),
+        height.revision_number(),
+        height.revision_height(),
+    ))?
+    .try_into()
+    .map_err(Into::into)
+}
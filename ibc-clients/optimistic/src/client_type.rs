@@ -0,0 +1,25 @@
+use ibc_client_cw::api::ClientType;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Height;
+
+use crate::client_state::ClientState;
+use crate::consensus_state::ConsensusState;
+use crate::entrypoint::OptimisticContext;
+
+/// A unit struct that represents the optimistic client type.
+#[derive(Clone, Debug)]
+pub struct OptimisticClient;
+
+impl<'a> ClientType<'a> for OptimisticClient {
+    type ClientState = ClientState;
+    type ConsensusState = ConsensusState;
+
+    /// Rejects the consensus states whose challenge period is still running.
+    fn verify_proof_height(
+        ctx: &OptimisticContext<'a>,
+        client_state: &ClientState,
+        height: &Height,
+    ) -> Result<(), ClientError> {
+        client_state.verify_challenge_period_elapsed(ctx, &ctx.client_id(), *height)
+    }
+}
@@ -0,0 +1,94 @@
+//! Implements the optimistic `ConsensusState`. Each consensus state is keyed by
+//! a proposed height and records the proposed state root and block time.
+use ibc_core::client::context::consensus_state::ConsensusState as ConsensusStateTrait;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::commitment_types::commitment::CommitmentRoot;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::proto::{Any, Protobuf};
+use ibc_core::primitives::Timestamp;
+
+use crate::types::proto::ConsensusState as RawConsensusState;
+use crate::types::StateProposal;
+
+pub const OPTIMISTIC_CONSENSUS_STATE_TYPE_URL: &str =
+    "/ibc.lightclients.optimistic.v1.ConsensusState";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusState {
+    /// The proposed state root, used as the commitment root.
+    pub root: CommitmentRoot,
+    /// The proposed block time.
+    pub timestamp: Timestamp,
+}
+
+impl ConsensusStateTrait for ConsensusState {
+    fn root(&self) -> &CommitmentRoot {
+        &self.root
+    }
+
+    fn timestamp(&self) -> Result<Timestamp, ClientError> {
+        Ok(self.timestamp)
+    }
+}
+
+/// Derives the consensus state installed by a state proposal.
+impl From<&StateProposal> for ConsensusState {
+    fn from(proposal: &StateProposal) -> Self {
+        Self {
+            root: proposal.root.clone().into(),
+            timestamp: proposal.timestamp,
+        }
+    }
+}
+
+impl Protobuf<RawConsensusState> for ConsensusState {}
+
+impl TryFrom<RawConsensusState> for ConsensusState {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawConsensusState) -> Result<Self, Self::Error> {
+        if raw.root.is_empty() {
+            return Err(DecodingError::missing_raw_data("consensus state root"));
+        }
+
+        Ok(Self {
+            root: raw.root.into(),
+            timestamp: Timestamp::from_nanoseconds(raw.timestamp),
+        })
+    }
+}
+
+impl From<ConsensusState> for RawConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        Self {
+            root: value.root.into_vec(),
+            timestamp: value.timestamp.nanoseconds(),
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let OPTIMISTIC_CONSENSUS_STATE_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawConsensusState>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: OPTIMISTIC_CONSENSUS_STATE_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Self {
+            type_url: OPTIMISTIC_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawConsensusState>::encode_vec(consensus_state),
+        }
+    }
+}
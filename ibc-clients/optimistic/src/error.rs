@@ -0,0 +1,34 @@
+//! Defines the error type of the optimistic light client.
+use core::time::Duration;
+
+use derive_more::{Display, From};
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Height;
+use ibc_core::host::types::error::DecodingError;
+
+#[derive(From, Display, Debug)]
+pub enum OptimisticError {
+    #[from]
+    #[display("decoding error: {_0}")]
+    Decoding(DecodingError),
+    #[display("invalid signature of the proposer")]
+    InvalidProposerSignature,
+    #[display("unknown challenger index `{index}` among {size} challengers")]
+    UnknownChallenger { index: u32, size: usize },
+    #[display("invalid signature of challenger `{index}`")]
+    InvalidChallengerSignature { index: u32 },
+    #[display("no consensus state at height `{height}`")]
+    MissingConsensusState { height: Height },
+    #[display("consensus state at height `{height}` is pending for another {remaining:?}")]
+    PendingConsensusState { height: Height, remaining: Duration },
+    #[display("challenge period of the consensus state at height `{height}` has elapsed")]
+    ChallengePeriodElapsed { height: Height },
+}
+
+impl From<OptimisticError> for ClientError {
+    fn from(e: OptimisticError) -> Self {
+        Self::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+//! The CosmWasm contract implementation of an optimistic light client, meant
+//! for rollups and app-chains too expensive to follow with a full light
+//! client. The state roots proposed by a trusted proposer are accepted
+//! without verification, but are only used to verify IBC commitments once a
+//! challenge period has elapsed since they were processed. Until then, any
+//! of the challengers of the client can freeze it with a fraud proof
+//! disputing a pending state root.
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+
+pub mod client_state;
+pub mod client_type;
+pub mod consensus_state;
+pub mod entrypoint;
+pub mod error;
+pub mod secp256k1;
+pub mod types;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,282 @@
+use std::time::Duration;
+
+use ibc_client_cw::types::{MerklePath, VerifyMembershipMsgRaw, VerifyNonMembershipMsgRaw};
+use ibc_client_cw::utils::AnyCodec;
+use ibc_client_cw_testkit::api::TestClient;
+use ibc_client_cw_testkit::clock::Clock;
+use ibc_client_cw_testkit::store::{MultiStore, IBC_STORE};
+use ibc_core::client::types::Height;
+use ibc_core::commitment_types::specs::ProofSpecs;
+use ibc_core::primitives::Timestamp;
+use k256::ecdsa::signature::Signer;
+use k256::ecdsa::{Signature, SigningKey};
+
+use crate::client_state::ClientState;
+use crate::client_type::OptimisticClient;
+use crate::consensus_state::ConsensusState;
+use crate::types::{Bytes33, Bytes64, FraudProof, StateProposal};
+
+/// The block time of the synthetic chain.
+const BLOCK_TIME: u64 = 5;
+
+/// Test fixture of the optimistic client.
+pub type Fixture = ibc_client_cw_testkit::fixture::Fixture<OptimisticClient>;
+
+/// A rollup whose state is a Cosmos SDK multistore, proposed by a secp256k1
+/// key and watched by a set of challenger keys.
+#[derive(Clone, Debug)]
+pub struct OptimisticConfig {
+    pub chain_id: String,
+    /// The time of the block at height zero, in seconds.
+    pub genesis_time: u64,
+    pub trusting_period: Duration,
+    pub challenge_period: Duration,
+    pub proposer_key: SigningKey,
+    pub challenger_keys: Vec<SigningKey>,
+    /// The (path, value) commitments stored in the IBC store.
+    pub commitments: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+impl Default for OptimisticConfig {
+    fn default() -> Self {
+        let now = Clock::default().now().nanoseconds() / 1_000_000_000;
+
+        Self {
+            chain_id: "optimistic-rollup-1".to_string(),
+            genesis_time: now - 3600,
+            trusting_period: Duration::from_secs(64000),
+            challenge_period: Duration::from_secs(600),
+            proposer_key: OptimisticClient::signing_key(1),
+            challenger_keys: vec![
+                OptimisticClient::signing_key(2),
+                OptimisticClient::signing_key(3),
+            ],
+            commitments: vec![
+                (
+                    b"commitments/ports/transfer/channels/channel-0/sequences/1".to_vec(),
+                    b"packet-commitment-1".to_vec(),
+                ),
+                (
+                    b"connections/connection-0".to_vec(),
+                    b"connection-end".to_vec(),
+                ),
+            ],
+        }
+    }
+}
+
+impl OptimisticConfig {
+    pub fn block_time(&self, height: u64) -> Timestamp {
+        Timestamp::from_nanoseconds((self.genesis_time + height * BLOCK_TIME) * 1_000_000_000)
+    }
+
+    /// Returns the multistore committed at the given height. Besides the IBC
+    /// commitments, the IBC store records the height so that the app hash
+    /// changes on every block.
+    pub fn store(&self, height: u64) -> MultiStore {
+        let mut store = MultiStore::default();
+
+        store.set(b"bank", b"supply", b"bank-store");
+
+        for (path, value) in &self.commitments {
+            store.set(IBC_STORE, path, value);
+        }
+
+        store.set(IBC_STORE, b"height", &height.to_be_bytes());
+
+        store.commit();
+
+        store
+    }
+
+    pub fn state_root(&self, height: u64) -> Vec<u8> {
+        self.store(height).app_hash()
+    }
+
+    /// Signs the proposal with the given key, replacing its signature.
+    pub fn signed_proposal(&self, mut proposal: StateProposal, key: &SigningKey) -> StateProposal {
+        proposal.signature = OptimisticClient::sign(key, &proposal.sign_doc(&self.chain_id));
+
+        proposal
+    }
+
+    /// Builds the proposal of the block at `height`, signed by the proposer.
+    pub fn dummy_proposal(&self, height: u64) -> StateProposal {
+        self.signed_proposal(
+            StateProposal {
+                height: OptimisticClient::height(height),
+                timestamp: self.block_time(height),
+                root: self.state_root(height),
+                signature: [0; 64],
+            },
+            &self.proposer_key,
+        )
+    }
+
+    /// Builds a proposal at `height` committing to the state root of another
+    /// block, signed by the proposer.
+    pub fn conflicting_proposal(&self, height: u64) -> StateProposal {
+        self.signed_proposal(
+            StateProposal {
+                root: self.state_root(height + 1),
+                ..self.dummy_proposal(height)
+            },
+            &self.proposer_key,
+        )
+    }
+
+    /// Builds the fraud proof by the challenger at `challenger_index`,
+    /// claiming that the state root at `height` is `root`.
+    pub fn fraud_proof(&self, challenger_index: u32, height: u64, root: Vec<u8>) -> FraudProof {
+        let mut fraud_proof = FraudProof {
+            height: OptimisticClient::height(height),
+            root,
+            challenger_index,
+            signature: [0; 64],
+        };
+
+        fraud_proof.signature = OptimisticClient::sign(
+            &self.challenger_keys[challenger_index as usize],
+            &fraud_proof.sign_doc(&self.chain_id),
+        );
+
+        fraud_proof
+    }
+
+    /// Builds the fraud proof by the first challenger disputing the state
+    /// root at `height`.
+    pub fn dummy_fraud_proof(&self, height: u64) -> FraudProof {
+        self.fraud_proof(0, height, self.state_root(height + 1))
+    }
+
+    pub fn dummy_verify_membership_msg(
+        &self,
+        height: Height,
+        path: &[u8],
+        value: &[u8],
+    ) -> VerifyMembershipMsgRaw {
+        VerifyMembershipMsgRaw {
+            proof: self
+                .store(height.revision_height())
+                .membership_proof(IBC_STORE, path),
+            merkle_path: MerklePath {
+                key_path: vec![IBC_STORE.to_vec().into(), path.to_vec().into()],
+            },
+            value: value.to_vec().into(),
+            height: height.into(),
+            delay_block_period: 0,
+            delay_time_period: 0,
+        }
+    }
+
+    pub fn dummy_verify_non_membership_msg(
+        &self,
+        height: Height,
+        path: &[u8],
+    ) -> VerifyNonMembershipMsgRaw {
+        VerifyNonMembershipMsgRaw {
+            proof: self
+                .store(height.revision_height())
+                .non_membership_proof(IBC_STORE, path),
+            merkle_path: MerklePath {
+                key_path: vec![IBC_STORE.to_vec().into(), path.to_vec().into()],
+            },
+            height: height.into(),
+            delay_block_period: 0,
+            delay_time_period: 0,
+        }
+    }
+}
+
+impl OptimisticClient {
+    /// Derives a deterministic signing key from the given seed.
+    pub fn signing_key(seed: u8) -> SigningKey {
+        SigningKey::from_bytes(&[seed; 32].into()).expect("never fails")
+    }
+
+    pub fn public_key(key: &SigningKey) -> Bytes33 {
+        key.verifying_key()
+            .to_encoded_point(true)
+            .as_bytes()
+            .try_into()
+            .expect("never fails")
+    }
+
+    pub fn sign(key: &SigningKey, sign_doc: &[u8]) -> Bytes64 {
+        let signature: Signature = key.sign(sign_doc);
+
+        signature.to_bytes().into()
+    }
+
+    pub fn height(height: u64) -> Height {
+        Height::new(1, height).expect("never fails")
+    }
+
+    /// Moves the host time to the end of the challenge period of the
+    /// consensus states processed so far.
+    pub fn elapse_challenge_period(fxt: &mut Fixture) {
+        fxt.clock.advance(1, fxt.config.challenge_period);
+    }
+
+    pub fn dummy_client_state(fxt: &Fixture) -> ClientState {
+        let config = &fxt.config;
+
+        ClientState {
+            chain_id: config.chain_id.clone(),
+            proposer: Self::public_key(&config.proposer_key),
+            challengers: config
+                .challenger_keys
+                .iter()
+                .map(Self::public_key)
+                .collect(),
+            challenge_period: config.challenge_period,
+            latest_height: fxt.trusted_height,
+            frozen_height: None,
+            trusting_period: config.trusting_period,
+            proof_specs: ProofSpecs::cosmos(),
+        }
+    }
+
+    pub fn dummy_consensus_state(fxt: &Fixture) -> ConsensusState {
+        let trusted_height = fxt.trusted_height.revision_height();
+
+        ConsensusState {
+            root: fxt.config.state_root(trusted_height).into(),
+            timestamp: fxt.config.block_time(trusted_height),
+        }
+    }
+}
+
+impl TestClient for OptimisticClient {
+    type Config = OptimisticConfig;
+
+    fn trusting_period(config: &OptimisticConfig) -> Duration {
+        config.trusting_period
+    }
+
+    fn trusted_height(_config: &OptimisticConfig) -> Height {
+        Self::height(10)
+    }
+
+    fn client_state(fxt: &Fixture) -> Vec<u8> {
+        ClientState::encode_to_any_vec(Self::dummy_client_state(fxt))
+    }
+
+    fn consensus_state(fxt: &Fixture) -> Vec<u8> {
+        ConsensusState::encode_to_any_vec(Self::dummy_consensus_state(fxt))
+    }
+
+    /// Constructs the proposal of the block at the target height.
+    fn header(fxt: &Fixture, target_height: Height) -> Vec<u8> {
+        StateProposal::encode_to_any_vec(fxt.config.dummy_proposal(target_height.revision_height()))
+    }
+
+    /// Constructs the fraud proof disputing the state root at the trusted
+    /// height, within its challenge period.
+    fn misbehaviour(fxt: &Fixture) -> Vec<u8> {
+        FraudProof::encode_to_any_vec(
+            fxt.config
+                .dummy_fraud_proof(fxt.trusted_height.revision_height()),
+        )
+    }
+}
@@ -0,0 +1,399 @@
+use std::time::Duration;
+
+use cosmwasm_std::from_json;
+use cosmwasm_std::testing::mock_dependencies;
+use ibc_client_cw::types::{ContractResult, MigrationPrefix};
+use ibc_client_cw::utils::AnyCodec;
+use ibc_client_cw_testkit::scenarios;
+use ibc_core::client::types::Status;
+
+use crate::client_type::OptimisticClient;
+use crate::types::{FraudProof, StateProposal};
+
+pub mod fixture;
+
+use fixture::{Fixture, OptimisticConfig};
+
+#[test]
+fn test_cw_optimistic_testkit_scenarios() {
+    scenarios::run_all(&Fixture::default());
+}
+
+#[test]
+fn test_cw_optimistic_update_client_ok() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let trusted_height = fxt.trusted_height.revision_height();
+
+    for height in [trusted_height + 1, trusted_height + 20] {
+        let resp = fxt
+            .update_client_with(
+                deps.as_mut(),
+                StateProposal::encode_to_any_vec(fxt.config.dummy_proposal(height)),
+            )
+            .unwrap();
+
+        let contract_result: ContractResult = from_json(resp.data.unwrap()).unwrap();
+
+        assert_eq!(
+            contract_result.heights,
+            Some(vec![OptimisticClient::height(height)])
+        );
+    }
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+}
+
+#[test]
+fn test_cw_optimistic_invalid_proposal_rejected() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let proposal = fxt
+        .config
+        .dummy_proposal(fxt.trusted_height.revision_height() + 5);
+
+    let challenger_signature = fxt
+        .config
+        .signed_proposal(proposal.clone(), &fxt.config.challenger_keys[0]);
+
+    let mut tampered_root = proposal.clone();
+    tampered_root.root = vec![0xff; 32];
+
+    let mut tampered_height = proposal.clone();
+    tampered_height.height = tampered_height.height.increment();
+
+    let other_chain = OptimisticConfig {
+        chain_id: "other-rollup-1".to_string(),
+        ..fxt.config.clone()
+    }
+    .signed_proposal(proposal, &fxt.config.proposer_key);
+
+    for proposal in [
+        challenger_signature,
+        tampered_root,
+        tampered_height,
+        other_chain,
+    ] {
+        assert!(
+            !fxt.is_valid_client_message(deps.as_ref(), StateProposal::encode_to_any_vec(proposal))
+        );
+    }
+}
+
+#[test]
+fn test_cw_optimistic_verify_membership_after_challenge_period() {
+    let mut fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let trusted_height = OptimisticClient::height(fxt.trusted_height.revision_height());
+    let (path, value) = fxt.config.commitments[0].clone();
+    let absent_path = b"receipts/ports/transfer/channels/channel-0/sequences/1";
+
+    // Even the initial consensus state waits for the challenge period.
+    assert!(fxt
+        .verify_membership(
+            deps.as_mut(),
+            fxt.config
+                .dummy_verify_membership_msg(trusted_height, &path, &value),
+        )
+        .is_err());
+
+    assert!(fxt
+        .verify_non_membership(
+            deps.as_mut(),
+            fxt.config
+                .dummy_verify_non_membership_msg(trusted_height, absent_path),
+        )
+        .is_err());
+
+    OptimisticClient::elapse_challenge_period(&mut fxt);
+
+    fxt.verify_membership(
+        deps.as_mut(),
+        fxt.config
+            .dummy_verify_membership_msg(trusted_height, &path, &value),
+    )
+    .unwrap();
+
+    fxt.verify_non_membership(
+        deps.as_mut(),
+        fxt.config
+            .dummy_verify_non_membership_msg(trusted_height, absent_path),
+    )
+    .unwrap();
+
+    assert!(fxt
+        .verify_membership(
+            deps.as_mut(),
+            fxt.config
+                .dummy_verify_membership_msg(trusted_height, &path, b"other-value"),
+        )
+        .is_err());
+
+    // A newly proposed state is pending, while the earlier one stays usable.
+    let proposal = fxt
+        .config
+        .dummy_proposal(trusted_height.revision_height() + 5);
+    let height = proposal.height;
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        StateProposal::encode_to_any_vec(proposal.clone()),
+    )
+    .unwrap();
+
+    assert!(fxt
+        .verify_membership(
+            deps.as_mut(),
+            fxt.config
+                .dummy_verify_membership_msg(height, &path, &value),
+        )
+        .is_err());
+
+    fxt.verify_membership(
+        deps.as_mut(),
+        fxt.config
+            .dummy_verify_membership_msg(trusted_height, &path, &value),
+    )
+    .unwrap();
+
+    // Submitting the proposal again does not restart its challenge period.
+    OptimisticClient::elapse_challenge_period(&mut fxt);
+
+    fxt.update_client_with(deps.as_mut(), StateProposal::encode_to_any_vec(proposal))
+        .unwrap();
+
+    fxt.verify_membership(
+        deps.as_mut(),
+        fxt.config
+            .dummy_verify_membership_msg(height, &path, &value),
+    )
+    .unwrap();
+
+    fxt.verify_non_membership(
+        deps.as_mut(),
+        fxt.config
+            .dummy_verify_non_membership_msg(height, absent_path),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_cw_optimistic_freeze_on_fraud_proof() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let height = fxt.trusted_height.revision_height() + 5;
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        StateProposal::encode_to_any_vec(fxt.config.dummy_proposal(height)),
+    )
+    .unwrap();
+
+    // A challenger agreeing with the proposed root is no evidence of
+    // misbehaviour.
+    let same_root = fxt
+        .config
+        .fraud_proof(1, height, fxt.config.state_root(height));
+
+    assert!(fxt.is_valid_client_message(
+        deps.as_ref(),
+        FraudProof::encode_to_any_vec(same_root.clone())
+    ));
+
+    assert!(!fxt.found_misbehaviour(deps.as_ref(), FraudProof::encode_to_any_vec(same_root)));
+
+    fxt.update_client_on_misbehaviour_with(
+        deps.as_mut(),
+        FraudProof::encode_to_any_vec(fxt.config.dummy_fraud_proof(height)),
+    );
+
+    fxt.check_client_status(deps.as_ref(), Status::Frozen);
+}
+
+#[test]
+fn test_cw_optimistic_invalid_fraud_proof_rejected() {
+    let mut fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let height = fxt.trusted_height.revision_height() + 5;
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        StateProposal::encode_to_any_vec(fxt.config.dummy_proposal(height)),
+    )
+    .unwrap();
+
+    let fraud_proof = fxt.config.dummy_fraud_proof(height);
+
+    let mut unknown_challenger = fraud_proof.clone();
+    unknown_challenger.challenger_index = fxt.config.challenger_keys.len() as u32;
+
+    let mut wrong_challenger = fraud_proof.clone();
+    wrong_challenger.challenger_index = 1;
+
+    let mut tampered_root = fraud_proof.clone();
+    tampered_root.root = vec![0xff; 32];
+
+    let outsider_signature = OptimisticConfig {
+        challenger_keys: vec![OptimisticClient::signing_key(9)],
+        ..fxt.config.clone()
+    }
+    .dummy_fraud_proof(height);
+
+    // A fraud proof disputing a height without consensus state is rejected.
+    let unknown_height = fxt.config.dummy_fraud_proof(height + 1);
+
+    for fraud_proof in [
+        unknown_challenger,
+        wrong_challenger,
+        tampered_root,
+        outsider_signature,
+        unknown_height,
+    ] {
+        assert!(
+            !fxt.is_valid_client_message(deps.as_ref(), FraudProof::encode_to_any_vec(fraud_proof))
+        );
+    }
+
+    // A fraud proof is rejected once the challenge period has elapsed.
+    OptimisticClient::elapse_challenge_period(&mut fxt);
+
+    assert!(!fxt.is_valid_client_message(deps.as_ref(), FraudProof::encode_to_any_vec(fraud_proof)));
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+}
+
+#[test]
+fn test_cw_optimistic_freeze_on_conflicting_proposal() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let height = fxt.trusted_height.revision_height() + 5;
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        StateProposal::encode_to_any_vec(fxt.config.dummy_proposal(height)),
+    )
+    .unwrap();
+
+    fxt.update_client_on_misbehaviour_with(
+        deps.as_mut(),
+        StateProposal::encode_to_any_vec(fxt.config.conflicting_proposal(height)),
+    );
+
+    fxt.check_client_status(deps.as_ref(), Status::Frozen);
+}
+
+#[test]
+fn test_cw_optimistic_recovery_client_ok() {
+    let mut fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    // ------------------- Create subject client -------------------
+
+    fxt.set_migration_prefix(MigrationPrefix::Subject);
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    // ------------------- Freeze subject client -------------------
+
+    fxt.update_client_on_misbehaviour_with(
+        deps.as_mut(),
+        FraudProof::encode_to_any_vec(
+            fxt.config
+                .dummy_fraud_proof(fxt.trusted_height.revision_height()),
+        ),
+    );
+
+    fxt.check_client_status(deps.as_ref(), Status::Frozen);
+
+    // ------------------- Create substitute client -------------------
+
+    // The substitute client is trusting a new proposer.
+    fxt.set_migration_prefix(MigrationPrefix::Substitute);
+
+    fxt.trusted_height = OptimisticClient::height(fxt.trusted_height.revision_height() + 20);
+    fxt.config.proposer_key = OptimisticClient::signing_key(4);
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    // ------------------- Recover subject client -------------------
+
+    let resp = fxt.recover_client(deps.as_mut()).unwrap();
+
+    assert_eq!(0, resp.messages.len());
+
+    fxt.set_migration_prefix(MigrationPrefix::Subject);
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+
+    // ------------------- Wait for the recovered state -------------------
+
+    let (path, value) = fxt.config.commitments[0].clone();
+
+    assert!(fxt
+        .verify_membership(
+            deps.as_mut(),
+            fxt.config
+                .dummy_verify_membership_msg(fxt.trusted_height, &path, &value),
+        )
+        .is_err());
+
+    OptimisticClient::elapse_challenge_period(&mut fxt);
+
+    fxt.verify_membership(
+        deps.as_mut(),
+        fxt.config
+            .dummy_verify_membership_msg(fxt.trusted_height, &path, &value),
+    )
+    .unwrap();
+
+    // ------------------- Update with the new proposer -------------------
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        StateProposal::encode_to_any_vec(
+            fxt.config
+                .dummy_proposal(fxt.trusted_height.revision_height() + 1),
+        ),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_cw_optimistic_client_expiry() {
+    let fxt = Fixture::new(OptimisticConfig {
+        trusting_period: Duration::from_secs(60),
+        challenge_period: Duration::from_secs(30),
+        ..Default::default()
+    });
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    fxt.check_client_status(deps.as_ref(), Status::Expired);
+}
@@ -0,0 +1,97 @@
+//! Fraud proofs, the client messages by which a challenger disputes a
+//! pending state root, freezing the client.
+use ibc_core::client::types::Height;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::proto::{Any, Protobuf};
+use prost::Message;
+
+use super::proto::{FraudProof as RawFraudProof, FraudProofSignDoc};
+use super::{fixed_bytes, Bytes64};
+
+pub const OPTIMISTIC_FRAUD_PROOF_TYPE_URL: &str = "/ibc.lightclients.optimistic.v1.FraudProof";
+
+/// The claim by a challenger that the counterparty state at `height` is
+/// committed by `root`. It is evidence of misbehaviour if the client holds
+/// another root at that height.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FraudProof {
+    pub height: Height,
+    /// The root of the counterparty state at `height`, according to the
+    /// challenger.
+    pub root: Vec<u8>,
+    pub challenger_index: u32,
+    pub signature: Bytes64,
+}
+
+impl FraudProof {
+    /// Returns the encoded document signed by the challenger, binding the
+    /// disputed state to the counterparty chain.
+    pub fn sign_doc(&self, chain_id: &str) -> Vec<u8> {
+        FraudProofSignDoc {
+            type_url: OPTIMISTIC_FRAUD_PROOF_TYPE_URL.to_string(),
+            chain_id: chain_id.to_string(),
+            height: Some(self.height.into()),
+            root: self.root.clone(),
+        }
+        .encode_to_vec()
+    }
+}
+
+impl Protobuf<RawFraudProof> for FraudProof {}
+
+impl TryFrom<RawFraudProof> for FraudProof {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawFraudProof) -> Result<Self, Self::Error> {
+        if raw.root.is_empty() {
+            return Err(DecodingError::missing_raw_data("challenged state root"));
+        }
+
+        Ok(Self {
+            height: raw
+                .height
+                .ok_or(DecodingError::missing_raw_data("challenged height"))?
+                .try_into()?,
+            root: raw.root,
+            challenger_index: raw.challenger_index,
+            signature: fixed_bytes("challenger signature", raw.signature)?,
+        })
+    }
+}
+
+impl From<FraudProof> for RawFraudProof {
+    fn from(value: FraudProof) -> Self {
+        Self {
+            height: Some(value.height.into()),
+            root: value.root,
+            challenger_index: value.challenger_index,
+            signature: value.signature.to_vec(),
+        }
+    }
+}
+
+impl Protobuf<Any> for FraudProof {}
+
+impl TryFrom<Any> for FraudProof {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let OPTIMISTIC_FRAUD_PROOF_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawFraudProof>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: OPTIMISTIC_FRAUD_PROOF_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<FraudProof> for Any {
+    fn from(fraud_proof: FraudProof) -> Self {
+        Self {
+            type_url: OPTIMISTIC_FRAUD_PROOF_TYPE_URL.to_string(),
+            value: Protobuf::<RawFraudProof>::encode_vec(fraud_proof),
+        }
+    }
+}
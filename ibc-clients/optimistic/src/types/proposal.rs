@@ -0,0 +1,104 @@
+//! State proposals, the client messages updating the client to a new
+//! counterparty state root, pending until the end of its challenge period.
+use ibc_core::client::types::Height;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::proto::{Any, Protobuf};
+use ibc_core::primitives::Timestamp;
+use prost::Message;
+
+use super::proto::{StateProposal as RawStateProposal, StateProposalSignDoc};
+use super::{fixed_bytes, Bytes64};
+
+pub const OPTIMISTIC_STATE_PROPOSAL_TYPE_URL: &str =
+    "/ibc.lightclients.optimistic.v1.StateProposal";
+
+/// The claim by the proposer that the counterparty state at `height` is
+/// committed by `root`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StateProposal {
+    pub height: Height,
+    /// The time of the counterparty block at `height`.
+    pub timestamp: Timestamp,
+    /// The root of the counterparty state, against which ICS-23 proofs are
+    /// verified once the challenge period has elapsed.
+    pub root: Vec<u8>,
+    pub signature: Bytes64,
+}
+
+impl StateProposal {
+    /// Returns the encoded document signed by the proposer, binding the
+    /// proposed state to the counterparty chain.
+    pub fn sign_doc(&self, chain_id: &str) -> Vec<u8> {
+        StateProposalSignDoc {
+            type_url: OPTIMISTIC_STATE_PROPOSAL_TYPE_URL.to_string(),
+            chain_id: chain_id.to_string(),
+            height: Some(self.height.into()),
+            timestamp: self.timestamp.nanoseconds(),
+            root: self.root.clone(),
+        }
+        .encode_to_vec()
+    }
+}
+
+impl Protobuf<RawStateProposal> for StateProposal {}
+
+impl TryFrom<RawStateProposal> for StateProposal {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawStateProposal) -> Result<Self, Self::Error> {
+        if raw.root.is_empty() {
+            return Err(DecodingError::missing_raw_data("proposed state root"));
+        }
+
+        if raw.timestamp == 0 {
+            return Err(DecodingError::missing_raw_data("proposed timestamp"));
+        }
+
+        Ok(Self {
+            height: raw
+                .height
+                .ok_or(DecodingError::missing_raw_data("proposed height"))?
+                .try_into()?,
+            timestamp: Timestamp::from_nanoseconds(raw.timestamp),
+            root: raw.root,
+            signature: fixed_bytes("proposer signature", raw.signature)?,
+        })
+    }
+}
+
+impl From<StateProposal> for RawStateProposal {
+    fn from(value: StateProposal) -> Self {
+        Self {
+            height: Some(value.height.into()),
+            timestamp: value.timestamp.nanoseconds(),
+            root: value.root,
+            signature: value.signature.to_vec(),
+        }
+    }
+}
+
+impl Protobuf<Any> for StateProposal {}
+
+impl TryFrom<Any> for StateProposal {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let OPTIMISTIC_STATE_PROPOSAL_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawStateProposal>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: OPTIMISTIC_STATE_PROPOSAL_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<StateProposal> for Any {
+    fn from(proposal: StateProposal) -> Self {
+        Self {
+            type_url: OPTIMISTIC_STATE_PROPOSAL_TYPE_URL.to_string(),
+            value: Protobuf::<RawStateProposal>::encode_vec(proposal),
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//! Protobuf definitions of the optimistic light client types, under the
+//! `ibc.lightclients.optimistic.v1` package.
+use ibc_core::client::types::proto::v1::Height as RawHeight;
+use ibc_core::commitment_types::proto::ics23::ProofSpec;
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ClientState {
+    #[prost(string, tag = "1")]
+    pub chain_id: String,
+    /// The compressed secp256k1 public key of the proposer.
+    #[prost(bytes = "vec", tag = "2")]
+    pub proposer: Vec<u8>,
+    /// The compressed secp256k1 public keys of the challengers.
+    #[prost(bytes = "vec", repeated, tag = "3")]
+    pub challengers: Vec<Vec<u8>>,
+    #[prost(uint64, tag = "4")]
+    pub challenge_period: u64,
+    #[prost(message, optional, tag = "5")]
+    pub latest_height: Option<RawHeight>,
+    #[prost(message, optional, tag = "6")]
+    pub frozen_height: Option<RawHeight>,
+    #[prost(uint64, tag = "7")]
+    pub trusting_period: u64,
+    #[prost(message, repeated, tag = "8")]
+    pub proof_specs: Vec<ProofSpec>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ConsensusState {
+    #[prost(bytes = "vec", tag = "1")]
+    pub root: Vec<u8>,
+    #[prost(uint64, tag = "2")]
+    pub timestamp: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct StateProposal {
+    #[prost(message, optional, tag = "1")]
+    pub height: Option<RawHeight>,
+    #[prost(uint64, tag = "2")]
+    pub timestamp: u64,
+    #[prost(bytes = "vec", tag = "3")]
+    pub root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub signature: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct FraudProof {
+    #[prost(message, optional, tag = "1")]
+    pub height: Option<RawHeight>,
+    /// The state root at `height` according to the challenger.
+    #[prost(bytes = "vec", tag = "2")]
+    pub root: Vec<u8>,
+    /// The index of the challenger in the client state.
+    #[prost(uint32, tag = "3")]
+    pub challenger_index: u32,
+    #[prost(bytes = "vec", tag = "4")]
+    pub signature: Vec<u8>,
+}
+
+/// The document signed by the proposer of a state proposal.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct StateProposalSignDoc {
+    /// The type URL of the signed message, separating the domains of the
+    /// signed documents.
+    #[prost(string, tag = "1")]
+    pub type_url: String,
+    #[prost(string, tag = "2")]
+    pub chain_id: String,
+    #[prost(message, optional, tag = "3")]
+    pub height: Option<RawHeight>,
+    #[prost(uint64, tag = "4")]
+    pub timestamp: u64,
+    #[prost(bytes = "vec", tag = "5")]
+    pub root: Vec<u8>,
+}
+
+/// The document signed by the challenger of a fraud proof.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct FraudProofSignDoc {
+    /// The type URL of the signed message, separating the domains of the
+    /// signed documents.
+    #[prost(string, tag = "1")]
+    pub type_url: String,
+    #[prost(string, tag = "2")]
+    pub chain_id: String,
+    #[prost(message, optional, tag = "3")]
+    pub height: Option<RawHeight>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub root: Vec<u8>,
+}
@@ -0,0 +1,121 @@
+//! Implements the Sovereign rollup `ClientState` along with the
+//! `ClientStateCommon`, `ClientStateValidation` and `ClientStateExecution`
+//! traits.
+use ibc_core::client::types::Height;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::proto::{Any, Protobuf};
+
+use crate::types::proto::ClientState as RawClientState;
+use crate::types::{fixed_bytes, Bytes32, TendermintParams};
+
+mod common;
+mod execution;
+mod validation;
+
+pub use common::*;
+pub use validation::*;
+
+pub const SOVEREIGN_CLIENT_STATE_TYPE_URL: &str =
+    "/ibc.lightclients.sovereign.tendermint.v1.ClientState";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientState {
+    /// A human-readable identifier of the rollup.
+    pub rollup_id: String,
+    /// The state root of the rollup at genesis.
+    pub genesis_state_root: Bytes32,
+    /// The commitment to the rollup's state transition function code the
+    /// aggregated proofs must be produced for.
+    pub code_commitment: Vec<u8>,
+    /// The latest DA height the client was updated to.
+    pub latest_height: Height,
+    pub frozen_height: Option<Height>,
+    pub da_params: TendermintParams,
+}
+
+impl ClientState {
+    pub fn is_frozen(&self) -> bool {
+        self.frozen_height.is_some()
+    }
+
+    fn validate(&self) -> Result<(), DecodingError> {
+        if self.code_commitment.is_empty() {
+            return Err(DecodingError::missing_raw_data("code commitment"));
+        }
+
+        if self.latest_height.revision_number() != self.da_params.chain_id.revision_number() {
+            return Err(DecodingError::invalid_raw_data(format!(
+                "latest height `{}` does not match the revision of the DA chain `{}`",
+                self.latest_height, self.da_params.chain_id
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        let client_state = Self {
+            rollup_id: raw.rollup_id,
+            genesis_state_root: fixed_bytes("genesis state root", raw.genesis_state_root)?,
+            code_commitment: raw.code_commitment,
+            latest_height: raw
+                .latest_height
+                .ok_or(DecodingError::missing_raw_data("latest height"))?
+                .try_into()?,
+            frozen_height: raw.frozen_height.map(TryInto::try_into).transpose()?,
+            da_params: raw
+                .da_params
+                .ok_or(DecodingError::missing_raw_data("DA parameters"))?
+                .try_into()?,
+        };
+
+        client_state.validate()?;
+
+        Ok(client_state)
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            rollup_id: value.rollup_id,
+            genesis_state_root: value.genesis_state_root.to_vec(),
+            code_commitment: value.code_commitment,
+            latest_height: Some(value.latest_height.into()),
+            frozen_height: value.frozen_height.map(Into::into),
+            da_params: Some(value.da_params.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let SOVEREIGN_CLIENT_STATE_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawClientState>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: SOVEREIGN_CLIENT_STATE_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Self {
+            type_url: SOVEREIGN_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientState>::encode_vec(client_state),
+        }
+    }
+}
@@ -0,0 +1,291 @@
+use ibc_client_tendermint::client_state::{
+    check_for_misbehaviour_on_misbehavior, verify_header as verify_da_header,
+    verify_misbehaviour as verify_da_misbehaviour,
+};
+use ibc_client_tendermint::types::{
+    ConsensusState as TmConsensusStateType, Misbehaviour as TmMisbehaviour,
+};
+use ibc_core::client::context::client_state::ClientStateValidation;
+use ibc_core::client::context::prelude::*;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::{Height, Status};
+use ibc_core::host::types::identifiers::ClientId;
+use ibc_core::host::types::path::ClientConsensusStatePath;
+use ibc_core::primitives::proto::Any;
+use tendermint::crypto::default::Sha256;
+use tendermint_light_client_verifier::ProdVerifier;
+
+use super::{consensus_status, ClientState};
+use crate::consensus_state::ConsensusState;
+use crate::error::SovereignError;
+use crate::types::{
+    Header, Misbehaviour, SOVEREIGN_HEADER_TYPE_URL, SOVEREIGN_MISBEHAVIOUR_TYPE_URL,
+};
+use crate::zk::AggregatedProofVerifier;
+
+impl<V> ClientStateValidation<V> for ClientState
+where
+    V: ExtClientValidationContext + AggregatedProofVerifier,
+    ConsensusState: Convertible<V::ConsensusStateRef>,
+    <ConsensusState as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+    TmConsensusStateType: Convertible<V::ConsensusStateRef>,
+    <TmConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    fn verify_client_message(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        match client_message.type_url.as_str() {
+            SOVEREIGN_HEADER_TYPE_URL => {
+                let header = Header::try_from(client_message)?;
+                self.verify_header(ctx, client_id, &header)
+            }
+            SOVEREIGN_MISBEHAVIOUR_TYPE_URL => {
+                let misbehaviour = Misbehaviour::try_from(client_message)?;
+                self.verify_misbehaviour(ctx, client_id, &misbehaviour)
+            }
+            header_type => Err(ClientError::InvalidHeaderType(header_type.to_owned())),
+        }
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        match client_message.type_url.as_str() {
+            SOVEREIGN_HEADER_TYPE_URL => {
+                let header = Header::try_from(client_message)?;
+                self.check_for_misbehaviour_on_header(ctx, client_id, &header)
+            }
+            SOVEREIGN_MISBEHAVIOUR_TYPE_URL => {
+                let misbehaviour = Misbehaviour::try_from(client_message)?;
+
+                check_for_misbehaviour_on_misbehavior(
+                    &misbehaviour.header_1.da_header,
+                    &misbehaviour.header_2.da_header,
+                )
+            }
+            header_type => Err(ClientError::InvalidHeaderType(header_type.to_owned())),
+        }
+    }
+
+    fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError> {
+        if self.is_frozen() {
+            return Ok(Status::Frozen);
+        }
+
+        // if the client state does not have an associated consensus state for
+        // its latest height then it must be expired
+        let Ok(latest_consensus_state) = consensus_state_at(ctx, client_id, self.latest_height)
+        else {
+            return Ok(Status::Expired);
+        };
+
+        consensus_status(
+            &latest_consensus_state,
+            &ctx.host_timestamp()?,
+            self.da_params.trusting_period,
+        )
+    }
+
+    /// The subject and substitute client states match if they track the same
+    /// rollup on the same DA chain, regardless of the latest height, frozen
+    /// height and trusting period.
+    fn check_substitute(&self, _ctx: &V, substitute_client_state: Any) -> Result<(), ClientError> {
+        let substitute_client_state = Self::try_from(substitute_client_state)?;
+
+        let mut normalized = Self {
+            latest_height: self.latest_height,
+            frozen_height: self.frozen_height,
+            ..substitute_client_state
+        };
+        normalized.da_params.trusting_period = self.da_params.trusting_period;
+
+        (&normalized == self)
+            .then_some(())
+            .ok_or(ClientError::FailedToVerifyClientRecoveryStates)
+    }
+}
+
+impl ClientState {
+    /// Verifies the DA header with the Tendermint light client rules, then
+    /// the aggregated proof of the rollup slots between the trusted height
+    /// and the DA header.
+    pub fn verify_header<V>(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        header: &Header,
+    ) -> Result<(), ClientError>
+    where
+        V: ExtClientValidationContext + AggregatedProofVerifier,
+        ConsensusState: Convertible<V::ConsensusStateRef>,
+        <ConsensusState as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+        TmConsensusStateType: Convertible<V::ConsensusStateRef>,
+        <TmConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+    {
+        verify_da_header::<V, Sha256>(
+            ctx,
+            &header.da_header,
+            client_id,
+            &self.da_params.chain_id,
+            &self.da_params.as_light_client_options()?,
+            &ProdVerifier::default(),
+        )?;
+
+        let trusted_consensus_state = consensus_state_at(ctx, client_id, header.trusted_height())?;
+
+        self.verify_public_data(header, &trusted_consensus_state)?;
+
+        ctx.verify_aggregated_proof(&self.code_commitment, &header.aggregated_proof)?;
+
+        Ok(())
+    }
+
+    /// Checks that the public data of the aggregated proof binds the proven
+    /// state transition to this rollup, to the trusted consensus state and
+    /// to the verified DA header.
+    fn verify_public_data(
+        &self,
+        header: &Header,
+        trusted_consensus_state: &ConsensusState,
+    ) -> Result<(), SovereignError> {
+        let public_data = &header.aggregated_proof.public_data;
+
+        if public_data.code_commitment != self.code_commitment {
+            return Err(SovereignError::MismatchedPublicData("code commitment"));
+        }
+
+        if public_data.genesis_state_root != self.genesis_state_root {
+            return Err(SovereignError::MismatchedPublicData("genesis state root"));
+        }
+
+        if public_data.initial_state_root != trusted_consensus_state.root.as_bytes() {
+            return Err(SovereignError::MismatchedPublicData("initial state root"));
+        }
+
+        let expected_slot = header.trusted_height().revision_height() + 1;
+
+        if public_data.initial_slot_number != expected_slot {
+            return Err(SovereignError::InvalidInitialSlot {
+                initial_slot: public_data.initial_slot_number,
+                expected_slot,
+            });
+        }
+
+        let expected_slot = header.height().revision_height();
+
+        if public_data.final_slot_number != expected_slot {
+            return Err(SovereignError::InvalidFinalSlot {
+                final_slot: public_data.final_slot_number,
+                expected_slot,
+            });
+        }
+
+        if public_data.final_slot_hash != header.da_header.signed_header.header.hash().as_bytes() {
+            return Err(SovereignError::MismatchedPublicData("final slot hash"));
+        }
+
+        Ok(())
+    }
+
+    /// Verifies the conflicting DA headers of a misbehaviour. A fork of the
+    /// DA layer compromises the rollup whatever its state transitions are,
+    /// so the aggregated proofs of the headers are not considered.
+    pub fn verify_misbehaviour<V>(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        misbehaviour: &Misbehaviour,
+    ) -> Result<(), ClientError>
+    where
+        V: ExtClientValidationContext,
+        TmConsensusStateType: Convertible<V::ConsensusStateRef>,
+        <TmConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+    {
+        let da_misbehaviour = TmMisbehaviour::new(
+            client_id.clone(),
+            misbehaviour.header_1.da_header.clone(),
+            misbehaviour.header_2.da_header.clone(),
+        );
+
+        verify_da_misbehaviour::<V, Sha256>(
+            ctx,
+            &da_misbehaviour,
+            client_id,
+            &self.da_params.chain_id,
+            &self.da_params.as_light_client_options()?,
+            &ProdVerifier::default(),
+        )
+    }
+
+    /// Checks whether a header conflicts with the consensus state already
+    /// installed at its height, or breaks the monotonicity of the consensus
+    /// state timestamps.
+    pub fn check_for_misbehaviour_on_header<V>(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        header: &Header,
+    ) -> Result<bool, ClientError>
+    where
+        V: ExtClientValidationContext,
+        ConsensusState: Convertible<V::ConsensusStateRef>,
+        <ConsensusState as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+    {
+        let header_height = header.height();
+        let header_consensus_state: ConsensusState = header.try_into()?;
+
+        if let Ok(existing_consensus_state) = consensus_state_at(ctx, client_id, header_height) {
+            return Ok(existing_consensus_state != header_consensus_state);
+        }
+
+        if let Some(prev_consensus_state) = ctx.prev_consensus_state(client_id, &header_height)? {
+            let prev_consensus_state: ConsensusState =
+                prev_consensus_state.try_into().map_err(Into::into)?;
+
+            if header_consensus_state.timestamp <= prev_consensus_state.timestamp {
+                return Ok(true);
+            }
+        }
+
+        if header_height < self.latest_height {
+            if let Some(next_consensus_state) =
+                ctx.next_consensus_state(client_id, &header_height)?
+            {
+                let next_consensus_state: ConsensusState =
+                    next_consensus_state.try_into().map_err(Into::into)?;
+
+                if header_consensus_state.timestamp >= next_consensus_state.timestamp {
+                    return Ok(true);
+                }
+            }
+        }
+
+        Ok(false)
+    }
+}
+
+/// Fetches the consensus state stored for the client at the given height.
+pub fn consensus_state_at<V>(
+    ctx: &V,
+    client_id: &ClientId,
+    height: Height,
+) -> Result<ConsensusState, ClientError>
+where
+    V: ExtClientValidationContext,
+    ConsensusState: Convertible<V::ConsensusStateRef>,
+    <ConsensusState as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    ctx.consensus_state(&ClientConsensusStatePath::new(
+        client_id.clone(),
+        height.revision_number(),
+        height.revision_height(),
+    ))?
+    .try_into()
+    .map_err(Into::into)
+}
@@ -0,0 +1,144 @@
+//! Implements the Sovereign rollup `ConsensusState`. Each consensus state is
+//! keyed by a DA height and records the rollup state root after the slot of
+//! that DA block, along with the DA block time and next validators hash the
+//! following DA headers are verified against.
+use ibc_client_tendermint::types::ConsensusState as TmConsensusStateType;
+use ibc_core::client::context::consensus_state::ConsensusState as ConsensusStateTrait;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::commitment_types::commitment::CommitmentRoot;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::proto::{Any, Protobuf};
+use ibc_core::primitives::{IntoHostTime, IntoTimestamp, Timestamp};
+use tendermint::hash::Algorithm;
+use tendermint::Hash;
+
+use crate::types::proto::ConsensusState as RawConsensusState;
+use crate::types::Header;
+
+pub const SOVEREIGN_CONSENSUS_STATE_TYPE_URL: &str =
+    "/ibc.lightclients.sovereign.tendermint.v1.ConsensusState";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusState {
+    /// The rollup state root, used as the commitment root.
+    pub root: CommitmentRoot,
+    /// The time of the DA block.
+    pub timestamp: Timestamp,
+    /// The hash of the DA validator set expected to sign the next DA block.
+    pub next_validators_hash: Hash,
+}
+
+impl ConsensusStateTrait for ConsensusState {
+    fn root(&self) -> &CommitmentRoot {
+        &self.root
+    }
+
+    fn timestamp(&self) -> Result<Timestamp, ClientError> {
+        Ok(self.timestamp)
+    }
+}
+
+/// Derives the consensus state installed by a header, committing to the
+/// rollup state root proven by its aggregated proof.
+impl TryFrom<&Header> for ConsensusState {
+    type Error = ClientError;
+
+    fn try_from(header: &Header) -> Result<Self, Self::Error> {
+        let da_header = header.da_header.signed_header.header();
+
+        Ok(Self {
+            root: header
+                .aggregated_proof
+                .public_data
+                .final_state_root
+                .to_vec()
+                .into(),
+            timestamp: da_header.time.into_timestamp()?,
+            next_validators_hash: da_header.next_validators_hash,
+        })
+    }
+}
+
+impl Protobuf<RawConsensusState> for ConsensusState {}
+
+impl TryFrom<RawConsensusState> for ConsensusState {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawConsensusState) -> Result<Self, Self::Error> {
+        if raw.root.is_empty() {
+            return Err(DecodingError::missing_raw_data("consensus state root"));
+        }
+
+        let next_validators_hash = Hash::from_bytes(Algorithm::Sha256, &raw.next_validators_hash)
+            .map_err(|e| {
+            DecodingError::invalid_raw_data(format!("next validators hash: {e}"))
+        })?;
+
+        Ok(Self {
+            root: raw.root.into(),
+            timestamp: Timestamp::from_nanoseconds(raw.timestamp),
+            next_validators_hash,
+        })
+    }
+}
+
+impl From<ConsensusState> for RawConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        Self {
+            root: value.root.into_vec(),
+            timestamp: value.timestamp.nanoseconds(),
+            next_validators_hash: value.next_validators_hash.as_bytes().to_vec(),
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let SOVEREIGN_CONSENSUS_STATE_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawConsensusState>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: SOVEREIGN_CONSENSUS_STATE_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Self {
+            type_url: SOVEREIGN_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawConsensusState>::encode_vec(consensus_state),
+        }
+    }
+}
+
+/// Views a consensus state as a Tendermint one, so that DA headers can be
+/// verified with the Tendermint light client functions. Only the timestamp
+/// and the next validators hash are relevant to that verification.
+impl TryFrom<ConsensusState> for TmConsensusStateType {
+    type Error = ClientError;
+
+    fn try_from(value: ConsensusState) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            value.root,
+            value.timestamp.into_host_time()?,
+            value.next_validators_hash,
+        ))
+    }
+}
+
+impl From<TmConsensusStateType> for ConsensusState {
+    fn from(value: TmConsensusStateType) -> Self {
+        Self {
+            root: value.root,
+            timestamp: value.timestamp.into_timestamp().expect("no overflow"),
+            next_validators_hash: value.next_validators_hash,
+        }
+    }
+}
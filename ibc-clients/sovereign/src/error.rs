@@ -0,0 +1,34 @@
+//! Defines the error type of the Sovereign rollup light client.
+use derive_more::{Display, From};
+use ibc_core::client::types::error::ClientError;
+use ibc_core::host::types::error::DecodingError;
+
+#[derive(From, Display, Debug)]
+pub enum SovereignError {
+    #[from]
+    #[display("decoding error: {_0}")]
+    Decoding(DecodingError),
+    #[display("aggregated proof commits to a different {_0} than expected")]
+    MismatchedPublicData(&'static str),
+    #[display(
+        "aggregated proof starts at slot `{initial_slot}`, expected the slot `{expected_slot}` following the trusted height"
+    )]
+    InvalidInitialSlot {
+        initial_slot: u64,
+        expected_slot: u64,
+    },
+    #[display(
+        "aggregated proof ends at slot `{final_slot}`, expected the DA header height `{expected_slot}`"
+    )]
+    InvalidFinalSlot { final_slot: u64, expected_slot: u64 },
+    #[display("invalid aggregated proof: {description}")]
+    InvalidAggregatedProof { description: String },
+}
+
+impl From<SovereignError> for ClientError {
+    fn from(e: SovereignError) -> Self {
+        Self::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! Verification of Jellyfish Merkle Tree (JMT) proofs, as produced by the
+//! Sovereign SDK storage, through their ICS-23 representation.
+use ibc_core::client::types::error::ClientError;
+use ibc_core::commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core::commitment_types::merkle::{MerklePath, MerkleProof};
+use ibc_core::commitment_types::proto::ics23::{
+    HashOp, HostFunctionsManager, InnerSpec, LeafOp, LengthOp, ProofSpec,
+};
+use ibc_core::commitment_types::specs::ProofSpecs;
+use ibc_core::host::types::path::PathBytes;
+
+/// The domain separator of JMT leaf nodes.
+pub const LEAF_DOMAIN_SEPARATOR: &[u8] = b"JMT::LeafNode";
+
+/// The domain separator of JMT internal nodes.
+pub const INTERNAL_DOMAIN_SEPARATOR: &[u8] = b"JMT::IntrnalNode";
+
+/// The hash standing for an empty subtree.
+pub const SPARSE_MERKLE_PLACEHOLDER_HASH: &[u8; 32] = b"SPARSE_MERKLE_PLACEHOLDER_HASH__";
+
+/// Returns the ICS-23 proof spec of the JMT with SHA-256 hashing, viewed as a
+/// binary sparse Merkle tree. Leaves commit to the hashes of their key and
+/// value, and empty subtrees are represented by the placeholder hash.
+pub fn jmt_spec() -> ProofSpec {
+    ProofSpec {
+        leaf_spec: Some(LeafOp {
+            hash: HashOp::Sha256.into(),
+            prehash_key: HashOp::Sha256.into(),
+            prehash_value: HashOp::Sha256.into(),
+            length: LengthOp::NoPrefix.into(),
+            prefix: LEAF_DOMAIN_SEPARATOR.to_vec(),
+        }),
+        inner_spec: Some(InnerSpec {
+            child_order: vec![0, 1],
+            child_size: 32,
+            min_prefix_length: INTERNAL_DOMAIN_SEPARATOR.len() as i32,
+            max_prefix_length: INTERNAL_DOMAIN_SEPARATOR.len() as i32,
+            empty_child: SPARSE_MERKLE_PLACEHOLDER_HASH.to_vec(),
+            hash: HashOp::Sha256.into(),
+        }),
+        max_depth: 256,
+        min_depth: 0,
+        prehash_key_before_comparison: true,
+    }
+}
+
+fn jmt_specs() -> ProofSpecs {
+    vec![jmt_spec()]
+        .try_into()
+        .expect("the JMT proof spec is valid")
+}
+
+/// Returns the JMT key of an IBC path, i.e. the path prefixed by the key
+/// prefix of the rollup's IBC module state.
+fn jmt_key(prefix: &CommitmentPrefix, path: PathBytes) -> Result<PathBytes, ClientError> {
+    if prefix.is_empty() {
+        return Err(ClientError::ClientSpecific {
+            description: "missing JMT key prefix".to_string(),
+        });
+    }
+
+    Ok([prefix.as_bytes(), path.as_ref()].concat().into())
+}
+
+/// Verifies that `value` is stored at `path` in the rollup state committed
+/// by `root`.
+pub fn verify_membership(
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    path: PathBytes,
+    value: Vec<u8>,
+) -> Result<(), ClientError> {
+    let merkle_path = MerklePath::new(vec![jmt_key(prefix, path)?]);
+
+    let merkle_proof = MerkleProof::try_from(proof)?;
+
+    merkle_proof.verify_membership::<HostFunctionsManager>(
+        &jmt_specs(),
+        root.clone().into(),
+        merkle_path,
+        value,
+        0,
+    )?;
+
+    Ok(())
+}
+
+/// Verifies that nothing is stored at `path` in the rollup state committed
+/// by `root`.
+pub fn verify_non_membership(
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    path: PathBytes,
+) -> Result<(), ClientError> {
+    let merkle_path = MerklePath::new(vec![jmt_key(prefix, path)?]);
+
+    let merkle_proof = MerkleProof::try_from(proof)?;
+
+    merkle_proof.verify_non_membership::<HostFunctionsManager>(
+        &jmt_specs(),
+        root.clone().into(),
+        merkle_path,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,19 @@
+//! The CosmWasm contract implementation of a Sovereign SDK rollup light
+//! client. It follows the rollup's DA layer, a Tendermint-based chain such as
+//! Celestia, with the Tendermint light client verification, and the rollup
+//! state roots through aggregated proofs covering the DA blocks processed by
+//! the rollup. IBC commitments are verified against the rollup state root
+//! with Jellyfish Merkle Tree (JMT) proofs.
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+
+pub mod client_state;
+pub mod client_type;
+pub mod consensus_state;
+pub mod entrypoint;
+pub mod error;
+pub mod jmt;
+pub mod types;
+pub mod zk;
+
+#[cfg(test)]
+mod tests;
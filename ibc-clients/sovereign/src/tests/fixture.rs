@@ -0,0 +1,298 @@
+use std::time::Duration;
+
+use ibc_client_cw::types::{MerklePath, VerifyMembershipMsgRaw, VerifyNonMembershipMsgRaw};
+use ibc_client_cw::utils::AnyCodec;
+use ibc_client_cw_testkit::api::TestClient;
+use ibc_client_cw_testkit::clock::Clock;
+use ibc_client_tendermint::types::{Header as TmHeader, TrustThreshold};
+use ibc_core::client::types::Height;
+use ibc_core::host::types::identifiers::ChainId;
+use ibc_core::primitives::IntoTimestamp;
+use tendermint::Time;
+use tendermint_testgen::light_block::TmLightBlock;
+use tendermint_testgen::{Generator, Validator};
+
+use crate::client_state::ClientState;
+use crate::client_type::SovereignClient;
+use crate::consensus_state::ConsensusState;
+use crate::tests::helper::TestJmt;
+use crate::types::{
+    AggregatedProof, AggregatedProofPublicData, Bytes32, Header, Misbehaviour, TendermintParams,
+};
+
+/// The key prefix of the rollup's IBC module state.
+pub const IBC_PREFIX: &[u8] = b"ibc";
+
+/// The DA block time of the synthetic chain.
+const DA_BLOCK_TIME: u64 = 6;
+
+/// Test fixture of the Sovereign rollup client.
+pub type Fixture = ibc_client_cw_testkit::fixture::Fixture<SovereignClient>;
+
+/// A Tendermint DA chain and a rollup publishing a block on every DA block.
+/// DA blocks are generated and signed with `tendermint-testgen`, and the
+/// rollup state is a JMT holding IBC commitments. Aggregated proofs carry
+/// consistent public data, but their serialized proofs are placeholders.
+#[derive(Clone, Debug)]
+pub struct SovereignConfig {
+    pub chain_id: ChainId,
+    /// The time of the DA block at height zero, in seconds.
+    pub genesis_time: u64,
+    pub trusting_period: Duration,
+    pub validators: Vec<Validator>,
+    pub code_commitment: Vec<u8>,
+    /// The (path, value) commitments stored by the rollup.
+    pub commitments: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+impl Default for SovereignConfig {
+    fn default() -> Self {
+        let now = Clock::default().now().nanoseconds() / 1_000_000_000;
+
+        Self {
+            chain_id: ChainId::new("mock-celestia").expect("never fails"),
+            genesis_time: now - 3600,
+            trusting_period: Duration::from_secs(64000),
+            validators: vec![
+                Validator::new("1").voting_power(40),
+                Validator::new("2").voting_power(30),
+                Validator::new("3").voting_power(30),
+            ],
+            code_commitment: b"rollup-code-commitment".to_vec(),
+            commitments: vec![
+                (
+                    b"commitments/ports/transfer/channels/channel-0/sequences/1".to_vec(),
+                    b"packet-commitment-1".to_vec(),
+                ),
+                (
+                    b"commitments/ports/transfer/channels/channel-0/sequences/2".to_vec(),
+                    b"packet-commitment-2".to_vec(),
+                ),
+                (
+                    b"connections/connection-0".to_vec(),
+                    b"connection-end".to_vec(),
+                ),
+            ],
+        }
+    }
+}
+
+impl SovereignConfig {
+    pub fn da_time(&self, height: u64) -> Time {
+        Time::from_unix_timestamp((self.genesis_time + height * DA_BLOCK_TIME) as i64, 0)
+            .expect("never fails")
+    }
+
+    /// Generates the DA block at the given height, with the given time.
+    pub fn da_block_at(&self, height: u64, time: Time) -> TmLightBlock {
+        let header = tendermint_testgen::Header::new(&self.validators)
+            .chain_id(self.chain_id.as_str())
+            .height(height)
+            .time(time)
+            .next_validators(&self.validators)
+            .app_hash(vec![0; 32].try_into().expect("never fails"));
+
+        tendermint_testgen::LightBlock::new_default_with_header(header)
+            .generate()
+            .expect("failed to generate light block")
+    }
+
+    pub fn da_block(&self, height: u64) -> TmLightBlock {
+        self.da_block_at(height, self.da_time(height))
+    }
+
+    pub fn da_block_hash(&self, height: u64) -> Bytes32 {
+        self.da_block(height)
+            .signed_header
+            .header
+            .hash()
+            .as_bytes()
+            .try_into()
+            .expect("never fails")
+    }
+
+    /// Returns the rollup state after the slot of the given DA height. Besides
+    /// the IBC commitments, the state records the latest slot number so that
+    /// the state root changes on every slot.
+    pub fn rollup_state(&self, height: u64) -> TestJmt {
+        let mut jmt = TestJmt::default();
+
+        for (path, value) in &self.commitments {
+            jmt.insert(&[IBC_PREFIX, path].concat(), value);
+        }
+
+        jmt.insert(b"rollup/slot", &height.to_be_bytes());
+
+        jmt
+    }
+
+    pub fn state_root(&self, height: u64) -> Bytes32 {
+        self.rollup_state(height).root()
+    }
+
+    pub fn header_with_da_block(&self, trusted_height: u64, da_block: TmLightBlock) -> Header {
+        let height = da_block.signed_header.header.height.value();
+
+        let public_data = AggregatedProofPublicData {
+            initial_slot_number: trusted_height + 1,
+            final_slot_number: height,
+            genesis_state_root: self.state_root(0),
+            initial_state_root: self.state_root(trusted_height),
+            final_state_root: self.state_root(height),
+            initial_slot_hash: self.da_block_hash(trusted_height + 1),
+            final_slot_hash: da_block
+                .signed_header
+                .header
+                .hash()
+                .as_bytes()
+                .try_into()
+                .expect("never fails"),
+            code_commitment: self.code_commitment.clone(),
+        };
+
+        Header {
+            da_header: TmHeader {
+                signed_header: da_block.signed_header,
+                validator_set: da_block.validators,
+                trusted_height: SovereignClient::height(trusted_height),
+                trusted_next_validator_set: da_block.next_validators,
+            },
+            aggregated_proof: AggregatedProof {
+                public_data,
+                serialized_proof: b"aggregated-proof".to_vec(),
+            },
+        }
+    }
+
+    /// Builds a header for the DA block at `height`, with an aggregated proof
+    /// of the rollup slots following `trusted_height`.
+    pub fn dummy_header(&self, trusted_height: u64, height: u64) -> Header {
+        self.header_with_da_block(trusted_height, self.da_block(height))
+    }
+
+    pub fn dummy_verify_membership_msg(
+        &self,
+        height: Height,
+        path: &[u8],
+        value: &[u8],
+    ) -> VerifyMembershipMsgRaw {
+        let rollup_state = self.rollup_state(height.revision_height());
+
+        VerifyMembershipMsgRaw {
+            proof: rollup_state.membership_proof(&[IBC_PREFIX, path].concat()),
+            merkle_path: MerklePath {
+                key_path: vec![IBC_PREFIX.to_vec().into(), path.to_vec().into()],
+            },
+            value: value.to_vec().into(),
+            height: height.into(),
+            delay_block_period: 0,
+            delay_time_period: 0,
+        }
+    }
+
+    pub fn dummy_verify_non_membership_msg(
+        &self,
+        height: Height,
+        path: &[u8],
+    ) -> VerifyNonMembershipMsgRaw {
+        let rollup_state = self.rollup_state(height.revision_height());
+
+        VerifyNonMembershipMsgRaw {
+            proof: rollup_state.non_membership_proof(&[IBC_PREFIX, path].concat()),
+            merkle_path: MerklePath {
+                key_path: vec![IBC_PREFIX.to_vec().into(), path.to_vec().into()],
+            },
+            height: height.into(),
+            delay_block_period: 0,
+            delay_time_period: 0,
+        }
+    }
+}
+
+impl SovereignClient {
+    pub fn height(height: u64) -> Height {
+        Height::new(0, height).expect("never fails")
+    }
+
+    pub fn dummy_client_state(fxt: &Fixture) -> ClientState {
+        ClientState {
+            rollup_id: "mock-rollup".to_string(),
+            genesis_state_root: fxt.config.state_root(0),
+            code_commitment: fxt.config.code_commitment.clone(),
+            latest_height: fxt.trusted_height,
+            frozen_height: None,
+            da_params: TendermintParams {
+                chain_id: fxt.config.chain_id.clone(),
+                trust_level: TrustThreshold::ONE_THIRD,
+                trusting_period: fxt.config.trusting_period,
+                unbonding_period: Duration::from_secs(128000),
+                max_clock_drift: Duration::from_secs(10),
+            },
+        }
+    }
+
+    pub fn dummy_consensus_state(fxt: &Fixture) -> ConsensusState {
+        let trusted_height = fxt.trusted_height.revision_height();
+        let da_header = fxt.config.da_block(trusted_height).signed_header.header;
+
+        ConsensusState {
+            root: fxt.config.state_root(trusted_height).to_vec().into(),
+            timestamp: da_header.time.into_timestamp().expect("never fails"),
+            next_validators_hash: da_header.next_validators_hash,
+        }
+    }
+
+    /// Builds a misbehaviour made of two DA blocks at the same height but
+    /// with different times, both signed by the trusted validators.
+    pub fn dummy_misbehaviour(fxt: &Fixture, height: u64) -> Misbehaviour {
+        let trusted_height = fxt.trusted_height.revision_height();
+
+        let conflicting_time =
+            (fxt.config.da_time(height) + Duration::from_secs(1)).expect("never fails");
+
+        Misbehaviour {
+            header_1: fxt.config.dummy_header(trusted_height, height),
+            header_2: fxt.config.header_with_da_block(
+                trusted_height,
+                fxt.config.da_block_at(height, conflicting_time),
+            ),
+        }
+    }
+}
+
+impl TestClient for SovereignClient {
+    type Config = SovereignConfig;
+
+    fn trusting_period(config: &SovereignConfig) -> Duration {
+        config.trusting_period
+    }
+
+    fn trusted_height(_config: &SovereignConfig) -> Height {
+        Self::height(10)
+    }
+
+    fn client_state(fxt: &Fixture) -> Vec<u8> {
+        ClientState::encode_to_any_vec(Self::dummy_client_state(fxt))
+    }
+
+    fn consensus_state(fxt: &Fixture) -> Vec<u8> {
+        ConsensusState::encode_to_any_vec(Self::dummy_consensus_state(fxt))
+    }
+
+    /// Constructs the header of the DA block at the target height, with an
+    /// aggregated proof of the rollup slots following the trusted height.
+    fn header(fxt: &Fixture, target_height: Height) -> Vec<u8> {
+        Header::encode_to_any_vec(fxt.config.dummy_header(
+            fxt.trusted_height.revision_height(),
+            target_height.revision_height(),
+        ))
+    }
+
+    /// Constructs two conflicting DA blocks right after the trusted height.
+    fn misbehaviour(fxt: &Fixture) -> Vec<u8> {
+        Misbehaviour::encode_to_any_vec(Self::dummy_misbehaviour(
+            fxt,
+            fxt.trusted_height.revision_height() + 1,
+        ))
+    }
+}
@@ -0,0 +1,158 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_std::Binary;
+use ibc_client_cw_testkit::store::sha256;
+use ibc_core::commitment_types::merkle::MerkleProof;
+use ibc_core::commitment_types::proto::ics23::commitment_proof::Proof;
+use ibc_core::commitment_types::proto::ics23::{
+    CommitmentProof, ExistenceProof, HashOp, InnerOp, NonExistenceProof,
+};
+use ibc_core::commitment_types::proto::v1::MerkleProof as RawMerkleProof;
+use ibc_core::primitives::proto::Protobuf;
+
+use crate::jmt::{
+    jmt_spec, INTERNAL_DOMAIN_SEPARATOR, LEAF_DOMAIN_SEPARATOR, SPARSE_MERKLE_PLACEHOLDER_HASH,
+};
+use crate::types::Bytes32;
+
+// ------------------- Jellyfish Merkle Tree -------------------
+
+fn bit(key_hash: &Bytes32, depth: usize) -> bool {
+    (key_hash[depth / 8] >> (7 - depth % 8)) & 1 == 1
+}
+
+fn internal_op(left: Option<&Bytes32>, right: Option<&Bytes32>) -> InnerOp {
+    let mut prefix = INTERNAL_DOMAIN_SEPARATOR.to_vec();
+    prefix.extend(left.into_iter().flatten());
+
+    InnerOp {
+        hash: HashOp::Sha256.into(),
+        prefix,
+        suffix: right.map(|r| r.to_vec()).unwrap_or_default(),
+    }
+}
+
+/// An in-memory Jellyfish Merkle Tree, viewed as the binary sparse Merkle
+/// tree it commits to: single leaves are not pushed down the tree, and empty
+/// subtrees hash to the placeholder hash.
+#[derive(Clone, Debug, Default)]
+pub struct TestJmt {
+    /// The entries of the tree, keyed by the hash of their key.
+    entries: BTreeMap<Bytes32, (Vec<u8>, Vec<u8>)>,
+}
+
+impl TestJmt {
+    pub fn insert(&mut self, key: &[u8], value: &[u8]) {
+        self.entries
+            .insert(sha256(key), (key.to_vec(), value.to_vec()));
+    }
+
+    fn leaf_hash(key_hash: &Bytes32, value: &[u8]) -> Bytes32 {
+        sha256([LEAF_DOMAIN_SEPARATOR, key_hash, &sha256(value)].concat())
+    }
+
+    fn subtree_hash(entries: &[(&Bytes32, &Vec<u8>)], depth: usize) -> Bytes32 {
+        match entries {
+            [] => *SPARSE_MERKLE_PLACEHOLDER_HASH,
+            [(key_hash, value)] => Self::leaf_hash(key_hash, value),
+            _ => {
+                let split = entries.partition_point(|(key_hash, _)| !bit(key_hash, depth));
+
+                sha256(
+                    [
+                        INTERNAL_DOMAIN_SEPARATOR,
+                        &Self::subtree_hash(&entries[..split], depth + 1),
+                        &Self::subtree_hash(&entries[split..], depth + 1),
+                    ]
+                    .concat(),
+                )
+            }
+        }
+    }
+
+    fn sorted_entries(&self) -> Vec<(&Bytes32, &Vec<u8>)> {
+        self.entries
+            .iter()
+            .map(|(key_hash, (_, value))| (key_hash, value))
+            .collect()
+    }
+
+    pub fn root(&self) -> Bytes32 {
+        Self::subtree_hash(&self.sorted_entries(), 0)
+    }
+
+    fn existence_proof(&self, key_hash: &Bytes32) -> ExistenceProof {
+        let (key, value) = self.entries[key_hash].clone();
+
+        let mut entries = &self.sorted_entries()[..];
+        let mut path = vec![];
+        let mut depth = 0;
+
+        while entries.len() > 1 {
+            let split = entries.partition_point(|(k, _)| !bit(k, depth));
+            let (left, right) = entries.split_at(split);
+
+            if bit(key_hash, depth) {
+                path.push(internal_op(
+                    Some(&Self::subtree_hash(left, depth + 1)),
+                    None,
+                ));
+                entries = right;
+            } else {
+                path.push(internal_op(
+                    None,
+                    Some(&Self::subtree_hash(right, depth + 1)),
+                ));
+                entries = left;
+            }
+
+            depth += 1;
+        }
+
+        path.reverse();
+
+        ExistenceProof {
+            key,
+            value,
+            leaf: jmt_spec().leaf_spec,
+            path,
+        }
+    }
+
+    fn encode(proof: Proof) -> Binary {
+        let merkle_proof = MerkleProof {
+            proofs: vec![CommitmentProof { proof: Some(proof) }],
+        };
+
+        Protobuf::<RawMerkleProof>::encode_vec(merkle_proof).into()
+    }
+
+    /// Returns the encoded `MerkleProof` of the entry stored under `key`.
+    pub fn membership_proof(&self, key: &[u8]) -> Binary {
+        Self::encode(Proof::Exist(self.existence_proof(&sha256(key))))
+    }
+
+    /// Returns the encoded `MerkleProof` of the absence of `key`, made of the
+    /// existence proofs of its neighbours in key hash order.
+    pub fn non_membership_proof(&self, key: &[u8]) -> Binary {
+        let key_hash = sha256(key);
+
+        let left = self
+            .entries
+            .range(..key_hash)
+            .next_back()
+            .map(|(k, _)| self.existence_proof(k));
+
+        let right = self
+            .entries
+            .range(key_hash..)
+            .next()
+            .map(|(k, _)| self.existence_proof(k));
+
+        Self::encode(Proof::Nonexist(NonExistenceProof {
+            key: key.to_vec(),
+            left,
+            right,
+        }))
+    }
+}
@@ -0,0 +1,338 @@
+use std::time::Duration;
+
+use cosmwasm_std::from_json;
+use cosmwasm_std::testing::mock_dependencies;
+use ibc_client_cw::types::{ContractResult, MigrationPrefix};
+use ibc_client_cw::utils::AnyCodec;
+use ibc_client_cw_testkit::scenarios;
+use ibc_core::client::types::Status;
+use tendermint_testgen::Validator;
+
+use crate::client_type::SovereignClient;
+use crate::types::{Header, Misbehaviour};
+
+pub mod fixture;
+pub mod helper;
+
+use fixture::{Fixture, SovereignConfig};
+
+#[test]
+fn test_cw_sovereign_testkit_scenarios() {
+    scenarios::run_all(&Fixture::default());
+}
+
+#[test]
+fn test_cw_sovereign_update_client_ok() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let trusted_height = fxt.trusted_height.revision_height();
+
+    // ------------------- Adjacent update -------------------
+
+    let resp = fxt
+        .update_client_with(
+            deps.as_mut(),
+            Header::encode_to_any_vec(fxt.config.dummy_header(trusted_height, trusted_height + 1)),
+        )
+        .unwrap();
+
+    let contract_result: ContractResult = from_json(resp.data.unwrap()).unwrap();
+
+    assert_eq!(
+        contract_result.heights,
+        Some(vec![SovereignClient::height(trusted_height + 1)])
+    );
+
+    // ------------------- Skipping update from the new state -------------------
+
+    let resp = fxt
+        .update_client_with(
+            deps.as_mut(),
+            Header::encode_to_any_vec(
+                fxt.config
+                    .dummy_header(trusted_height + 1, trusted_height + 20),
+            ),
+        )
+        .unwrap();
+
+    let contract_result: ContractResult = from_json(resp.data.unwrap()).unwrap();
+
+    assert_eq!(
+        contract_result.heights,
+        Some(vec![SovereignClient::height(trusted_height + 20)])
+    );
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+}
+
+#[test]
+fn test_cw_sovereign_invalid_aggregated_proof_rejected() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let trusted_height = fxt.trusted_height.revision_height();
+
+    let header = fxt.config.dummy_header(trusted_height, trusted_height + 5);
+
+    assert!(fxt.is_valid_client_message(deps.as_ref(), Header::encode_to_any_vec(header.clone())));
+
+    let mut other_code = header.clone();
+    other_code.aggregated_proof.public_data.code_commitment = b"other-code".to_vec();
+
+    let mut other_genesis = header.clone();
+    other_genesis
+        .aggregated_proof
+        .public_data
+        .genesis_state_root = [0xff; 32];
+
+    let mut untrusted_initial_root = header.clone();
+    untrusted_initial_root
+        .aggregated_proof
+        .public_data
+        .initial_state_root = fxt.config.state_root(trusted_height + 1);
+
+    let mut skipped_slots = header.clone();
+    skipped_slots
+        .aggregated_proof
+        .public_data
+        .initial_slot_number += 1;
+
+    let mut other_final_slot = header.clone();
+    other_final_slot
+        .aggregated_proof
+        .public_data
+        .final_slot_number -= 1;
+
+    let mut other_da_block = header.clone();
+    other_da_block.aggregated_proof.public_data.final_slot_hash = [0xff; 32];
+
+    let mut empty_proof = header;
+    empty_proof.aggregated_proof.serialized_proof = vec![];
+
+    for header in [
+        other_code,
+        other_genesis,
+        untrusted_initial_root,
+        skipped_slots,
+        other_final_slot,
+        other_da_block,
+        empty_proof,
+    ] {
+        assert!(!fxt.is_valid_client_message(deps.as_ref(), Header::encode_to_any_vec(header)));
+    }
+}
+
+#[test]
+fn test_cw_sovereign_invalid_da_header_rejected() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let trusted_height = fxt.trusted_height.revision_height();
+
+    let header = fxt.config.dummy_header(trusted_height, trusted_height + 5);
+
+    let mut unknown_validators = header.clone();
+    unknown_validators.da_header.trusted_next_validator_set = SovereignConfig {
+        validators: vec![Validator::new("4").voting_power(100)],
+        ..SovereignConfig::default()
+    }
+    .da_block(trusted_height)
+    .validators;
+
+    let mut tampered_commit = header;
+    tampered_commit
+        .da_header
+        .signed_header
+        .commit
+        .signatures
+        .clear();
+
+    for header in [unknown_validators, tampered_commit] {
+        assert!(!fxt.is_valid_client_message(deps.as_ref(), Header::encode_to_any_vec(header)));
+    }
+}
+
+#[test]
+fn test_cw_sovereign_verify_membership() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let trusted_height = fxt.trusted_height.revision_height();
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(fxt.config.dummy_header(trusted_height, trusted_height + 5)),
+    )
+    .unwrap();
+
+    for height in [trusted_height, trusted_height + 5] {
+        let height = SovereignClient::height(height);
+
+        for (path, value) in &fxt.config.commitments {
+            fxt.verify_membership(
+                deps.as_mut(),
+                fxt.config.dummy_verify_membership_msg(height, path, value),
+            )
+            .unwrap();
+
+            assert!(fxt
+                .verify_membership(
+                    deps.as_mut(),
+                    fxt.config
+                        .dummy_verify_membership_msg(height, path, b"other-value"),
+                )
+                .is_err());
+
+            assert!(fxt
+                .verify_non_membership(
+                    deps.as_mut(),
+                    fxt.config.dummy_verify_non_membership_msg(height, path),
+                )
+                .is_err());
+        }
+
+        for absent_path in [
+            b"commitments/ports/transfer/channels/channel-0/sequences/3".as_slice(),
+            b"acks/ports/transfer/channels/channel-0/sequences/1",
+            b"receipts/ports/transfer/channels/channel-0/sequences/1",
+        ] {
+            fxt.verify_non_membership(
+                deps.as_mut(),
+                fxt.config
+                    .dummy_verify_non_membership_msg(height, absent_path),
+            )
+            .unwrap();
+        }
+    }
+
+    // A proof against the state root of another height is rejected.
+    let (path, value) = &fxt.config.commitments[0];
+
+    let mut msg = fxt.config.dummy_verify_membership_msg(
+        SovereignClient::height(trusted_height),
+        path,
+        value,
+    );
+    msg.height = SovereignClient::height(trusted_height + 5).into();
+
+    assert!(fxt.verify_membership(deps.as_mut(), msg).is_err());
+}
+
+#[test]
+fn test_cw_sovereign_freeze_on_da_misbehaviour() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let misbehaviour =
+        SovereignClient::dummy_misbehaviour(&fxt, fxt.trusted_height.revision_height() + 5);
+
+    fxt.update_client_on_misbehaviour_with(
+        deps.as_mut(),
+        Misbehaviour::encode_to_any_vec(misbehaviour),
+    );
+
+    fxt.check_client_status(deps.as_ref(), Status::Frozen);
+}
+
+#[test]
+fn test_cw_sovereign_freeze_on_conflicting_state_root() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let trusted_height = fxt.trusted_height.revision_height();
+
+    let header = fxt.config.dummy_header(trusted_height, trusted_height + 5);
+
+    fxt.update_client_with(deps.as_mut(), Header::encode_to_any_vec(header.clone()))
+        .unwrap();
+
+    // The same DA block proven to lead to another rollup state.
+    let mut conflicting_header = header;
+    conflicting_header
+        .aggregated_proof
+        .public_data
+        .final_state_root = [0xff; 32];
+
+    fxt.update_client_on_misbehaviour_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(conflicting_header),
+    );
+
+    fxt.check_client_status(deps.as_ref(), Status::Frozen);
+}
+
+#[test]
+fn test_cw_sovereign_recovery_client_ok() {
+    let mut fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    // ------------------- Create subject client -------------------
+
+    fxt.set_migration_prefix(MigrationPrefix::Subject);
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    // ------------------- Freeze subject client -------------------
+
+    let misbehaviour =
+        SovereignClient::dummy_misbehaviour(&fxt, fxt.trusted_height.revision_height() + 5);
+
+    fxt.update_client_on_misbehaviour_with(
+        deps.as_mut(),
+        Misbehaviour::encode_to_any_vec(misbehaviour),
+    );
+
+    fxt.check_client_status(deps.as_ref(), Status::Frozen);
+
+    // ------------------- Create substitute client -------------------
+
+    fxt.set_migration_prefix(MigrationPrefix::Substitute);
+
+    fxt.trusted_height = SovereignClient::height(fxt.trusted_height.revision_height() + 20);
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    // ------------------- Recover subject client -------------------
+
+    let resp = fxt.recover_client(deps.as_mut()).unwrap();
+
+    assert_eq!(0, resp.messages.len());
+
+    fxt.set_migration_prefix(MigrationPrefix::Subject);
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+}
+
+#[test]
+fn test_cw_sovereign_client_expiry() {
+    let fxt = Fixture::new(SovereignConfig {
+        trusting_period: Duration::from_secs(60),
+        ..Default::default()
+    });
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    fxt.check_client_status(deps.as_ref(), Status::Expired);
+}
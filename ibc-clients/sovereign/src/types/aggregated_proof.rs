@@ -0,0 +1,102 @@
+//! The aggregated proof of a range of rollup slots, along with the public
+//! data it commits to.
+use ibc_core::host::types::error::DecodingError;
+
+use super::proto::{
+    AggregatedProof as RawAggregatedProof,
+    AggregatedProofPublicData as RawAggregatedProofPublicData,
+};
+use super::{fixed_bytes, Bytes32};
+
+/// The public inputs of an aggregated proof, i.e. the claims it proves about
+/// the execution of the rollup over a range of DA blocks (slots).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AggregatedProofPublicData {
+    /// The DA height of the first slot covered by the proof.
+    pub initial_slot_number: u64,
+    /// The DA height of the last slot covered by the proof.
+    pub final_slot_number: u64,
+    pub genesis_state_root: Bytes32,
+    /// The rollup state root before the first slot is applied.
+    pub initial_state_root: Bytes32,
+    /// The rollup state root after the last slot is applied.
+    pub final_state_root: Bytes32,
+    /// The DA block hash of the first slot.
+    pub initial_slot_hash: Bytes32,
+    /// The DA block hash of the last slot.
+    pub final_slot_hash: Bytes32,
+    /// The commitment to the rollup's state transition function code, e.g.
+    /// the image ID of the zkVM guest program.
+    pub code_commitment: Vec<u8>,
+}
+
+impl TryFrom<RawAggregatedProofPublicData> for AggregatedProofPublicData {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawAggregatedProofPublicData) -> Result<Self, Self::Error> {
+        if raw.initial_slot_number > raw.final_slot_number {
+            return Err(DecodingError::invalid_raw_data(format!(
+                "initial slot number `{}` is greater than the final slot number `{}`",
+                raw.initial_slot_number, raw.final_slot_number
+            )));
+        }
+
+        Ok(Self {
+            initial_slot_number: raw.initial_slot_number,
+            final_slot_number: raw.final_slot_number,
+            genesis_state_root: fixed_bytes("genesis state root", raw.genesis_state_root)?,
+            initial_state_root: fixed_bytes("initial state root", raw.initial_state_root)?,
+            final_state_root: fixed_bytes("final state root", raw.final_state_root)?,
+            initial_slot_hash: fixed_bytes("initial slot hash", raw.initial_slot_hash)?,
+            final_slot_hash: fixed_bytes("final slot hash", raw.final_slot_hash)?,
+            code_commitment: raw.code_commitment,
+        })
+    }
+}
+
+impl From<AggregatedProofPublicData> for RawAggregatedProofPublicData {
+    fn from(value: AggregatedProofPublicData) -> Self {
+        Self {
+            initial_slot_number: value.initial_slot_number,
+            final_slot_number: value.final_slot_number,
+            genesis_state_root: value.genesis_state_root.to_vec(),
+            initial_state_root: value.initial_state_root.to_vec(),
+            final_state_root: value.final_state_root.to_vec(),
+            initial_slot_hash: value.initial_slot_hash.to_vec(),
+            final_slot_hash: value.final_slot_hash.to_vec(),
+            code_commitment: value.code_commitment,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AggregatedProof {
+    pub public_data: AggregatedProofPublicData,
+    /// The proof itself, serialized by the rollup's zkVM.
+    pub serialized_proof: Vec<u8>,
+}
+
+impl TryFrom<RawAggregatedProof> for AggregatedProof {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawAggregatedProof) -> Result<Self, Self::Error> {
+        Ok(Self {
+            public_data: raw
+                .public_data
+                .ok_or(DecodingError::missing_raw_data(
+                    "aggregated proof public data",
+                ))?
+                .try_into()?,
+            serialized_proof: raw.serialized_proof,
+        })
+    }
+}
+
+impl From<AggregatedProof> for RawAggregatedProof {
+    fn from(value: AggregatedProof) -> Self {
+        Self {
+            public_data: Some(value.public_data.into()),
+            serialized_proof: value.serialized_proof,
+        }
+    }
+}
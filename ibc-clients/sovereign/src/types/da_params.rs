@@ -0,0 +1,87 @@
+//! Parameters of the Tendermint-based DA layer the rollup publishes its
+//! blocks to.
+use core::time::Duration;
+
+use ibc_client_tendermint::types::TrustThreshold;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::host::types::identifiers::ChainId;
+use tendermint_light_client_verifier::options::Options;
+
+use super::proto::TendermintParams as RawTendermintParams;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TendermintParams {
+    /// The chain ID of the DA layer, whose revision number is the revision
+    /// number of the client heights.
+    pub chain_id: ChainId,
+    pub trust_level: TrustThreshold,
+    pub trusting_period: Duration,
+    pub unbonding_period: Duration,
+    pub max_clock_drift: Duration,
+}
+
+impl TendermintParams {
+    /// Returns the options of the Tendermint light client verification of
+    /// DA headers.
+    pub fn as_light_client_options(&self) -> Result<Options, ClientError> {
+        Ok(Options {
+            trust_threshold: self.trust_level.try_into()?,
+            trusting_period: self.trusting_period,
+            clock_drift: self.max_clock_drift,
+        })
+    }
+
+    fn validate(&self) -> Result<(), DecodingError> {
+        if self.trust_level == TrustThreshold::ZERO {
+            return Err(DecodingError::invalid_raw_data(
+                "trust level must be non-zero",
+            ));
+        }
+
+        if self.trusting_period.is_zero() || self.trusting_period >= self.unbonding_period {
+            return Err(DecodingError::invalid_raw_data(format!(
+                "trusting period `{:?}` must be non-zero and shorter than the unbonding period `{:?}`",
+                self.trusting_period, self.unbonding_period
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<RawTendermintParams> for TendermintParams {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawTendermintParams) -> Result<Self, Self::Error> {
+        let params = Self {
+            chain_id: raw.chain_id.parse()?,
+            trust_level: raw
+                .trust_level
+                .ok_or(DecodingError::missing_raw_data("trust level"))?
+                .try_into()?,
+            trusting_period: Duration::from_nanos(raw.trusting_period),
+            unbonding_period: Duration::from_nanos(raw.unbonding_period),
+            max_clock_drift: Duration::from_nanos(raw.max_clock_drift),
+        };
+
+        params.validate()?;
+
+        Ok(params)
+    }
+}
+
+impl From<TendermintParams> for RawTendermintParams {
+    fn from(value: TendermintParams) -> Self {
+        let nanos =
+            |duration: Duration| -> u64 { duration.as_nanos().try_into().expect("no overflow") };
+
+        Self {
+            chain_id: value.chain_id.to_string(),
+            trust_level: Some(value.trust_level.into()),
+            trusting_period: nanos(value.trusting_period),
+            unbonding_period: nanos(value.unbonding_period),
+            max_clock_drift: nanos(value.max_clock_drift),
+        }
+    }
+}
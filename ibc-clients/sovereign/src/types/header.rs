@@ -0,0 +1,151 @@
+//! Client messages accepted by the Sovereign rollup light client.
+use ibc_client_tendermint::types::Header as TmHeader;
+use ibc_core::client::types::Height;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::proto::{Any, Protobuf};
+
+use super::proto::{Header as RawHeader, Misbehaviour as RawMisbehaviour};
+use super::AggregatedProof;
+
+pub const SOVEREIGN_HEADER_TYPE_URL: &str = "/ibc.lightclients.sovereign.tendermint.v1.Header";
+pub const SOVEREIGN_MISBEHAVIOUR_TYPE_URL: &str =
+    "/ibc.lightclients.sovereign.tendermint.v1.Misbehaviour";
+
+/// The header submitted to update the client: a DA header, verified with the
+/// Tendermint light client rules, and an aggregated proof of the rollup
+/// slots up to that DA block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Header {
+    pub da_header: TmHeader,
+    pub aggregated_proof: AggregatedProof,
+}
+
+impl Header {
+    /// Returns the height of the header, i.e. the height of the DA block.
+    pub fn height(&self) -> Height {
+        self.da_header.height()
+    }
+
+    /// Returns the height of the trusted consensus state the header builds
+    /// upon.
+    pub fn trusted_height(&self) -> Height {
+        self.da_header.trusted_height
+    }
+}
+
+impl Protobuf<RawHeader> for Header {}
+
+impl TryFrom<RawHeader> for Header {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawHeader) -> Result<Self, Self::Error> {
+        Ok(Self {
+            da_header: raw
+                .da_header
+                .ok_or(DecodingError::missing_raw_data("DA header"))?
+                .try_into()?,
+            aggregated_proof: raw
+                .aggregated_proof
+                .ok_or(DecodingError::missing_raw_data("aggregated proof"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<Header> for RawHeader {
+    fn from(value: Header) -> Self {
+        Self {
+            da_header: Some(value.da_header.into()),
+            aggregated_proof: Some(value.aggregated_proof.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let SOVEREIGN_HEADER_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawHeader>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: SOVEREIGN_HEADER_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        Self {
+            type_url: SOVEREIGN_HEADER_TYPE_URL.to_string(),
+            value: Protobuf::<RawHeader>::encode_vec(header),
+        }
+    }
+}
+
+/// Two headers whose DA headers are evidence of misbehaviour of the DA
+/// layer, or which commit to different rollup state roots at the same
+/// height.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Misbehaviour {
+    pub header_1: Header,
+    pub header_2: Header,
+}
+
+impl Protobuf<RawMisbehaviour> for Misbehaviour {}
+
+impl TryFrom<RawMisbehaviour> for Misbehaviour {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawMisbehaviour) -> Result<Self, Self::Error> {
+        Ok(Self {
+            header_1: raw
+                .header_1
+                .ok_or(DecodingError::missing_raw_data("misbehaviour header 1"))?
+                .try_into()?,
+            header_2: raw
+                .header_2
+                .ok_or(DecodingError::missing_raw_data("misbehaviour header 2"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<Misbehaviour> for RawMisbehaviour {
+    fn from(value: Misbehaviour) -> Self {
+        Self {
+            header_1: Some(value.header_1.into()),
+            header_2: Some(value.header_2.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for Misbehaviour {}
+
+impl TryFrom<Any> for Misbehaviour {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let SOVEREIGN_MISBEHAVIOUR_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawMisbehaviour>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: SOVEREIGN_MISBEHAVIOUR_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<Misbehaviour> for Any {
+    fn from(misbehaviour: Misbehaviour) -> Self {
+        Self {
+            type_url: SOVEREIGN_MISBEHAVIOUR_TYPE_URL.to_string(),
+            value: Protobuf::<RawMisbehaviour>::encode_vec(misbehaviour),
+        }
+    }
+}
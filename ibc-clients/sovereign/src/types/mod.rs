@@ -0,0 +1,27 @@
+mod aggregated_proof;
+mod da_params;
+mod header;
+pub mod proto;
+
+pub use aggregated_proof::*;
+pub use da_params::*;
+pub use header::*;
+use ibc_core::host::types::error::DecodingError;
+
+/// The size of the rollup state roots and of the DA block hashes.
+pub type Bytes32 = [u8; 32];
+
+/// Converts raw bytes into a fixed-size array, failing if the length does not
+/// match.
+pub(crate) fn fixed_bytes<const N: usize>(
+    name: &str,
+    bytes: Vec<u8>,
+) -> Result<[u8; N], DecodingError> {
+    let actual = bytes.len();
+
+    bytes.try_into().map_err(|_| {
+        DecodingError::invalid_raw_data(format!(
+            "{name} must be {N} bytes long, found {actual} bytes"
+        ))
+    })
+}
@@ -0,0 +1,88 @@
+//! Protobuf definitions of the Sovereign rollup light client types, under the
+//! `ibc.lightclients.sovereign.tendermint.v1` package.
+use ibc_client_tendermint::types::proto::v1::{Fraction, Header as RawTmHeader};
+use ibc_core::client::types::proto::v1::Height as RawHeight;
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct TendermintParams {
+    #[prost(string, tag = "1")]
+    pub chain_id: String,
+    #[prost(message, optional, tag = "2")]
+    pub trust_level: Option<Fraction>,
+    #[prost(uint64, tag = "3")]
+    pub trusting_period: u64,
+    #[prost(uint64, tag = "4")]
+    pub unbonding_period: u64,
+    #[prost(uint64, tag = "5")]
+    pub max_clock_drift: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ClientState {
+    #[prost(string, tag = "1")]
+    pub rollup_id: String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub genesis_state_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub code_commitment: Vec<u8>,
+    #[prost(message, optional, tag = "4")]
+    pub latest_height: Option<RawHeight>,
+    #[prost(message, optional, tag = "5")]
+    pub frozen_height: Option<RawHeight>,
+    #[prost(message, optional, tag = "6")]
+    pub da_params: Option<TendermintParams>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ConsensusState {
+    #[prost(bytes = "vec", tag = "1")]
+    pub root: Vec<u8>,
+    #[prost(uint64, tag = "2")]
+    pub timestamp: u64,
+    #[prost(bytes = "vec", tag = "3")]
+    pub next_validators_hash: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct AggregatedProofPublicData {
+    #[prost(uint64, tag = "1")]
+    pub initial_slot_number: u64,
+    #[prost(uint64, tag = "2")]
+    pub final_slot_number: u64,
+    #[prost(bytes = "vec", tag = "3")]
+    pub genesis_state_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub initial_state_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "5")]
+    pub final_state_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "6")]
+    pub initial_slot_hash: Vec<u8>,
+    #[prost(bytes = "vec", tag = "7")]
+    pub final_slot_hash: Vec<u8>,
+    #[prost(bytes = "vec", tag = "8")]
+    pub code_commitment: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct AggregatedProof {
+    #[prost(message, optional, tag = "1")]
+    pub public_data: Option<AggregatedProofPublicData>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub serialized_proof: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Header {
+    #[prost(message, optional, tag = "1")]
+    pub da_header: Option<RawTmHeader>,
+    #[prost(message, optional, tag = "2")]
+    pub aggregated_proof: Option<AggregatedProof>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Misbehaviour {
+    #[prost(message, optional, tag = "1")]
+    pub header_1: Option<Header>,
+    #[prost(message, optional, tag = "2")]
+    pub header_2: Option<Header>,
+}
@@ -0,0 +1,49 @@
+//! Verification of the aggregated proofs of rollup state transitions.
+use ibc_client_cw::context::Context;
+
+use crate::client_type::SovereignClient;
+use crate::error::SovereignError;
+use crate::types::AggregatedProof;
+
+/// Provides aggregated proof verification to the light client.
+pub trait AggregatedProofVerifier {
+    /// Verifies that the serialized proof of `proof` attests its public data
+    /// for the state transition function identified by `code_commitment`.
+    fn verify_aggregated_proof(
+        &self,
+        code_commitment: &[u8],
+        proof: &AggregatedProof,
+    ) -> Result<(), SovereignError>;
+}
+
+/// CosmWasm hosts expose no verifier for the zkVM proofs aggregated by
+/// Sovereign rollups yet, so the contract cannot check the serialized proof
+/// against `code_commitment`. Without a verifier, every aggregated proof is
+/// rejected, and the client cannot track the rollup state roots.
+///
+/// The `insecure-unverified-proofs` feature, also enabled for the tests,
+/// accepts any non-empty proof instead. The public data is still checked
+/// against the client and consensus states, which binds the proven state root
+/// to the verified DA header, but the rollup state transition itself is then
+/// trusted without proof.
+impl AggregatedProofVerifier for Context<'_, SovereignClient> {
+    fn verify_aggregated_proof(
+        &self,
+        _code_commitment: &[u8],
+        proof: &AggregatedProof,
+    ) -> Result<(), SovereignError> {
+        if proof.serialized_proof.is_empty() {
+            return Err(SovereignError::InvalidAggregatedProof {
+                description: "empty serialized proof".to_string(),
+            });
+        }
+
+        if cfg!(any(test, feature = "insecure-unverified-proofs")) {
+            Ok(())
+        } else {
+            Err(SovereignError::InvalidAggregatedProof {
+                description: "no verifier available for the serialized proof".to_string(),
+            })
+        }
+    }
+}
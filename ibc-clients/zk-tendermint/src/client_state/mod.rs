@@ -0,0 +1,159 @@
+//! Implements the ZK-verified Tendermint `ClientState` along with the
+//! `ClientStateCommon`, `ClientStateValidation` and `ClientStateExecution`
+//! traits.
+use core::time::Duration;
+
+use ibc_client_tendermint::types::TrustThreshold;
+use ibc_core::client::types::Height;
+use ibc_core::commitment_types::specs::ProofSpecs;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::host::types::identifiers::ChainId;
+use ibc_core::primitives::proto::{Any, Protobuf};
+
+use crate::groth16::VerifyingKey;
+use crate::types::proto::ClientState as RawClientState;
+use crate::types::NUM_PUBLIC_INPUTS;
+
+mod common;
+mod execution;
+mod validation;
+
+pub use common::*;
+pub use validation::*;
+
+pub const ZK_TENDERMINT_CLIENT_STATE_TYPE_URL: &str =
+    "/ibc.lightclients.zktendermint.v1.ClientState";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClientState {
+    pub chain_id: ChainId,
+    /// The trust level the circuit verifies skipping commit transitions
+    /// with.
+    pub trust_level: TrustThreshold,
+    pub trusting_period: Duration,
+    pub max_clock_drift: Duration,
+    pub latest_height: Height,
+    pub frozen_height: Option<Height>,
+    pub proof_specs: ProofSpecs,
+    /// The verifying key of the commit transition circuit.
+    pub verifying_key: VerifyingKey,
+}
+
+impl ClientState {
+    pub fn is_frozen(&self) -> bool {
+        self.frozen_height.is_some()
+    }
+
+    fn validate(&self) -> Result<(), DecodingError> {
+        if self.trust_level == TrustThreshold::ZERO {
+            return Err(DecodingError::invalid_raw_data(
+                "trust level must be non-zero",
+            ));
+        }
+
+        if self.trusting_period.is_zero() {
+            return Err(DecodingError::invalid_raw_data(
+                "trusting period must be non-zero",
+            ));
+        }
+
+        if self.latest_height.revision_number() != self.chain_id.revision_number() {
+            return Err(DecodingError::invalid_raw_data(format!(
+                "latest height `{}` does not match the revision of the chain `{}`",
+                self.latest_height, self.chain_id
+            )));
+        }
+
+        if self.proof_specs.is_empty() {
+            return Err(DecodingError::missing_raw_data("proof specs"));
+        }
+
+        if self.verifying_key.num_public_inputs() != NUM_PUBLIC_INPUTS {
+            return Err(DecodingError::invalid_raw_data(format!(
+                "verifying key must have `{NUM_PUBLIC_INPUTS}` public inputs, found `{}`",
+                self.verifying_key.num_public_inputs()
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        let client_state = Self {
+            chain_id: raw.chain_id.parse()?,
+            trust_level: raw
+                .trust_level
+                .ok_or(DecodingError::missing_raw_data("trust level"))?
+                .try_into()?,
+            trusting_period: Duration::from_nanos(raw.trusting_period),
+            max_clock_drift: Duration::from_nanos(raw.max_clock_drift),
+            latest_height: raw
+                .latest_height
+                .ok_or(DecodingError::missing_raw_data("latest height"))?
+                .try_into()?,
+            frozen_height: raw.frozen_height.map(TryInto::try_into).transpose()?,
+            proof_specs: raw
+                .proof_specs
+                .try_into()
+                .map_err(|e| DecodingError::invalid_raw_data(format!("proof specs: {e}")))?,
+            verifying_key: raw
+                .verifying_key
+                .ok_or(DecodingError::missing_raw_data("verifying key"))?
+                .try_into()?,
+        };
+
+        client_state.validate()?;
+
+        Ok(client_state)
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        let nanos =
+            |duration: Duration| -> u64 { duration.as_nanos().try_into().expect("no overflow") };
+
+        Self {
+            chain_id: value.chain_id.to_string(),
+            trust_level: Some(value.trust_level.into()),
+            trusting_period: nanos(value.trusting_period),
+            max_clock_drift: nanos(value.max_clock_drift),
+            latest_height: Some(value.latest_height.into()),
+            frozen_height: value.frozen_height.map(Into::into),
+            proof_specs: value.proof_specs.into(),
+            verifying_key: Some(value.verifying_key.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let ZK_TENDERMINT_CLIENT_STATE_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawClientState>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: ZK_TENDERMINT_CLIENT_STATE_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Self {
+            type_url: ZK_TENDERMINT_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientState>::encode_vec(client_state),
+        }
+    }
+}
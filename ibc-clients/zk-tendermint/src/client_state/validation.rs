@@ -0,0 +1,272 @@
+use ibc_client_tendermint::client_state::consensus_state_status;
+use ibc_client_tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc_client_tendermint::types::ConsensusState as TmConsensusStateType;
+use ibc_core::client::context::client_state::ClientStateValidation;
+use ibc_core::client::context::prelude::*;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::{Height, Status};
+use ibc_core::host::types::identifiers::ClientId;
+use ibc_core::host::types::path::ClientConsensusStatePath;
+use ibc_core::primitives::proto::Any;
+use ibc_core::primitives::IntoTimestamp;
+
+use super::ClientState;
+use crate::error::ZkTendermintError;
+use crate::types::{
+    public_inputs, Header, Misbehaviour, ZK_TENDERMINT_HEADER_TYPE_URL,
+    ZK_TENDERMINT_MISBEHAVIOUR_TYPE_URL,
+};
+
+impl<V> ClientStateValidation<V> for ClientState
+where
+    V: ExtClientValidationContext,
+    TmConsensusStateType: Convertible<V::ConsensusStateRef>,
+    <TmConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    fn verify_client_message(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        match client_message.type_url.as_str() {
+            ZK_TENDERMINT_HEADER_TYPE_URL => {
+                let header = Header::try_from(client_message)?;
+                self.verify_header(ctx, client_id, &header)
+            }
+            ZK_TENDERMINT_MISBEHAVIOUR_TYPE_URL => {
+                let misbehaviour = Misbehaviour::try_from(client_message)?;
+                self.verify_misbehaviour(ctx, client_id, &misbehaviour)
+            }
+            header_type => Err(ClientError::InvalidHeaderType(header_type.to_owned())),
+        }
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        match client_message.type_url.as_str() {
+            ZK_TENDERMINT_HEADER_TYPE_URL => {
+                let header = Header::try_from(client_message)?;
+                self.check_for_misbehaviour_on_header(ctx, client_id, &header)
+            }
+            ZK_TENDERMINT_MISBEHAVIOUR_TYPE_URL => {
+                let misbehaviour = Misbehaviour::try_from(client_message)?;
+
+                Ok(misbehaviour.header_1.consensus_state()?
+                    != misbehaviour.header_2.consensus_state()?)
+            }
+            header_type => Err(ClientError::InvalidHeaderType(header_type.to_owned())),
+        }
+    }
+
+    fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError> {
+        if self.is_frozen() {
+            return Ok(Status::Frozen);
+        }
+
+        // if the client state does not have an associated consensus state for
+        // its latest height then it must be expired
+        let Ok(latest_consensus_state) = consensus_state_at(ctx, client_id, self.latest_height)
+        else {
+            return Ok(Status::Expired);
+        };
+
+        consensus_state_status(
+            &TmConsensusState::from(latest_consensus_state),
+            &ctx.host_timestamp()?,
+            self.trusting_period,
+        )
+    }
+
+    /// The subject and substitute client states match if they track the same
+    /// chain with the same circuit, regardless of the latest height, frozen
+    /// height and trusting period.
+    fn check_substitute(&self, _ctx: &V, substitute_client_state: Any) -> Result<(), ClientError> {
+        let substitute_client_state = Self::try_from(substitute_client_state)?;
+
+        let normalized = Self {
+            latest_height: self.latest_height,
+            frozen_height: self.frozen_height,
+            trusting_period: self.trusting_period,
+            ..substitute_client_state
+        };
+
+        (&normalized == self)
+            .then_some(())
+            .ok_or(ClientError::FailedToVerifyClientRecoveryStates)
+    }
+}
+
+impl ClientState {
+    /// Verifies that the header follows a consensus state within the
+    /// trusting period, then verifies the proof of the commit transition
+    /// from that consensus state.
+    pub fn verify_header<V>(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        header: &Header,
+    ) -> Result<(), ClientError>
+    where
+        V: ExtClientValidationContext,
+        TmConsensusStateType: Convertible<V::ConsensusStateRef>,
+        <TmConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+    {
+        if header.height.revision_number() != self.chain_id.revision_number() {
+            return Err(ZkTendermintError::MismatchedRevision {
+                height: header.height,
+                chain_id: self.chain_id.to_string(),
+            }
+            .into());
+        }
+
+        if header.height <= header.trusted_height {
+            return Err(ZkTendermintError::NonIncreasingHeight {
+                height: header.height,
+                trusted_height: header.trusted_height,
+            }
+            .into());
+        }
+
+        let trusted_consensus_state = consensus_state_at(ctx, client_id, header.trusted_height)?;
+        let trusted_timestamp = trusted_consensus_state.timestamp.into_timestamp()?;
+
+        let host_timestamp = ctx.host_timestamp()?;
+
+        if consensus_state_status(
+            &TmConsensusState::from(trusted_consensus_state.clone()),
+            &host_timestamp,
+            self.trusting_period,
+        )?
+        .is_expired()
+        {
+            return Err(ZkTendermintError::TrustedStateExpired {
+                trusted_height: header.trusted_height,
+            }
+            .into());
+        }
+
+        if header.timestamp <= trusted_timestamp {
+            return Err(ZkTendermintError::NonIncreasingTimestamp {
+                timestamp: header.timestamp,
+                trusted_timestamp,
+            }
+            .into());
+        }
+
+        let drifted_host_timestamp = (host_timestamp + self.max_clock_drift)?;
+
+        if header.timestamp > drifted_host_timestamp {
+            return Err(ZkTendermintError::HeaderFromFuture {
+                timestamp: header.timestamp,
+                host_timestamp,
+            }
+            .into());
+        }
+
+        let public_values = header.public_values(
+            &self.chain_id,
+            self.trust_level,
+            &trusted_consensus_state.next_validators_hash,
+        );
+
+        self.verifying_key
+            .verify(&public_inputs(&public_values), &header.proof)?;
+
+        Ok(())
+    }
+
+    /// Verifies both headers of a misbehaviour, which must be at the same
+    /// height.
+    pub fn verify_misbehaviour<V>(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        misbehaviour: &Misbehaviour,
+    ) -> Result<(), ClientError>
+    where
+        V: ExtClientValidationContext,
+        TmConsensusStateType: Convertible<V::ConsensusStateRef>,
+        <TmConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+    {
+        let height_1 = misbehaviour.header_1.height;
+        let height_2 = misbehaviour.header_2.height;
+
+        if height_1 != height_2 {
+            return Err(ZkTendermintError::MismatchedHeights { height_1, height_2 }.into());
+        }
+
+        self.verify_header(ctx, client_id, &misbehaviour.header_1)?;
+        self.verify_header(ctx, client_id, &misbehaviour.header_2)
+    }
+
+    /// Checks whether a header conflicts with the consensus state already
+    /// installed at its height, or breaks the monotonicity of the consensus
+    /// state timestamps.
+    pub fn check_for_misbehaviour_on_header<V>(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        header: &Header,
+    ) -> Result<bool, ClientError>
+    where
+        V: ExtClientValidationContext,
+        TmConsensusStateType: Convertible<V::ConsensusStateRef>,
+        <TmConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+    {
+        let header_height = header.height;
+        let header_consensus_state = header.consensus_state()?;
+
+        if let Ok(existing_consensus_state) = consensus_state_at(ctx, client_id, header_height) {
+            return Ok(existing_consensus_state != header_consensus_state);
+        }
+
+        if let Some(prev_consensus_state) = ctx.prev_consensus_state(client_id, &header_height)? {
+            let prev_consensus_state: TmConsensusStateType =
+                prev_consensus_state.try_into().map_err(Into::into)?;
+
+            if header_consensus_state.timestamp <= prev_consensus_state.timestamp {
+                return Ok(true);
+            }
+        }
+
+        if header_height < self.latest_height {
+            if let Some(next_consensus_state) =
+                ctx.next_consensus_state(client_id, &header_height)?
+            {
+                let next_consensus_state: TmConsensusStateType =
+                    next_consensus_state.try_into().map_err(Into::into)?;
+
+                if header_consensus_state.timestamp >= next_consensus_state.timestamp {
+                    return Ok(true);
+                }
+            }
+        }
+
+        Ok(false)
+    }
+}
+
+/// Fetches the consensus state stored for the client at the given height.
+pub fn consensus_state_at<V>(
+    ctx: &V,
+    client_id: &ClientId,
+    height: Height,
+) -> Result<TmConsensusStateType, ClientError>
+where
+    V: ExtClientValidationContext,
+    TmConsensusStateType: Convertible<V::ConsensusStateRef>,
+    <TmConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    ctx.consensus_state(&ClientConsensusStatePath::new(
+        client_id.clone(),
+        height.revision_number(),
+        height.revision_height(),
+    ))?
+    .try_into()
+    .map_err(Into::into)
+}
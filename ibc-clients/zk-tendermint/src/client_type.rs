@@ -0,0 +1,14 @@
+use ibc_client_cw::api::ClientType;
+use ibc_client_tendermint::consensus_state::ConsensusState;
+
+use crate::client_state::ClientState;
+
+/// A unit struct that represents the ZK-verified Tendermint client type. Its
+/// consensus states are those of the ICS-07 Tendermint client.
+#[derive(Clone, Debug)]
+pub struct ZkTendermintClient;
+
+impl ClientType<'_> for ZkTendermintClient {
+    type ClientState = ClientState;
+    type ConsensusState = ConsensusState;
+}
@@ -0,0 +1,52 @@
+//! Defines the error type of the ZK-verified Tendermint light client.
+use derive_more::{Display, From};
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Height;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::primitives::Timestamp;
+
+#[derive(From, Display, Debug)]
+pub enum ZkTendermintError {
+    #[from]
+    #[display("decoding error: {_0}")]
+    Decoding(DecodingError),
+    #[display("invalid Groth16 proof: {description}")]
+    InvalidProof { description: String },
+    #[display("verifying key expects `{expected}` public inputs, got `{actual}`")]
+    MismatchedPublicInputs { expected: usize, actual: usize },
+    #[display(
+        "header height `{height}` must be greater than the trusted height `{trusted_height}`"
+    )]
+    NonIncreasingHeight {
+        height: Height,
+        trusted_height: Height,
+    },
+    #[display("header height `{height}` does not match the revision of the chain `{chain_id}`")]
+    MismatchedRevision { height: Height, chain_id: String },
+    #[display(
+        "header timestamp `{timestamp}` must be later than the trusted timestamp `{trusted_timestamp}`"
+    )]
+    NonIncreasingTimestamp {
+        timestamp: Timestamp,
+        trusted_timestamp: Timestamp,
+    },
+    #[display("header timestamp `{timestamp}` is too far in the future of the host timestamp `{host_timestamp}`")]
+    HeaderFromFuture {
+        timestamp: Timestamp,
+        host_timestamp: Timestamp,
+    },
+    #[display(
+        "consensus state at the trusted height `{trusted_height}` is outside the trusting period"
+    )]
+    TrustedStateExpired { trusted_height: Height },
+    #[display("misbehaviour headers at different heights `{height_1}` and `{height_2}`")]
+    MismatchedHeights { height_1: Height, height_2: Height },
+}
+
+impl From<ZkTendermintError> for ClientError {
+    fn from(e: ZkTendermintError) -> Self {
+        Self::ClientSpecific {
+            description: e.to_string(),
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! Groth16 proof verification over BLS12-381, in pure Rust.
+use ark_bls12_381::{Bls12_381, Fr, G1Affine, G2Affine};
+use ark_ec::pairing::Pairing;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::Zero;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ibc_core::host::types::error::DecodingError;
+
+use crate::error::ZkTendermintError;
+use crate::types::proto::{Groth16Proof as RawProof, Groth16VerifyingKey as RawVerifyingKey};
+
+/// Decodes a curve point from its compressed encoding, checking that it is on
+/// the curve and in the prime-order subgroup.
+fn decode_point<P: CanonicalDeserialize>(name: &str, bytes: &[u8]) -> Result<P, DecodingError> {
+    P::deserialize_compressed(bytes)
+        .map_err(|e| DecodingError::invalid_raw_data(format!("{name}: {e}")))
+}
+
+fn encode_point<P: CanonicalSerialize>(point: &P) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(point.compressed_size());
+    point
+        .serialize_compressed(&mut bytes)
+        .expect("serializing into a vector never fails");
+    bytes
+}
+
+/// The verifying key of a Groth16 circuit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifyingKey {
+    pub alpha_g1: G1Affine,
+    pub beta_g2: G2Affine,
+    pub gamma_g2: G2Affine,
+    pub delta_g2: G2Affine,
+    /// The commitments to the public inputs, the first one standing for the
+    /// constant input `1`.
+    pub ic: Vec<G1Affine>,
+}
+
+impl VerifyingKey {
+    /// Returns the number of public inputs of the circuit.
+    pub fn num_public_inputs(&self) -> usize {
+        self.ic.len() - 1
+    }
+
+    /// Verifies `proof` for the given public inputs, checking that
+    /// `e(A, B) = e(alpha, beta) * e(vk_x, gamma) * e(C, delta)`, where
+    /// `vk_x` is the linear combination of `ic` by the public inputs.
+    pub fn verify(&self, public_inputs: &[Fr], proof: &Proof) -> Result<(), ZkTendermintError> {
+        if public_inputs.len() != self.num_public_inputs() {
+            return Err(ZkTendermintError::MismatchedPublicInputs {
+                expected: self.num_public_inputs(),
+                actual: public_inputs.len(),
+            });
+        }
+
+        let vk_x = public_inputs
+            .iter()
+            .zip(&self.ic[1..])
+            .fold(self.ic[0].into_group(), |acc, (input, ic)| {
+                acc + *ic * input
+            })
+            .into_affine();
+
+        let pairing = Bls12_381::multi_pairing(
+            [-proof.a, self.alpha_g1, vk_x, proof.c],
+            [proof.b, self.beta_g2, self.gamma_g2, self.delta_g2],
+        );
+
+        if !pairing.is_zero() {
+            return Err(ZkTendermintError::InvalidProof {
+                description: "pairing check failed".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<RawVerifyingKey> for VerifyingKey {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawVerifyingKey) -> Result<Self, Self::Error> {
+        if raw.ic.is_empty() {
+            return Err(DecodingError::missing_raw_data("verifying key IC"));
+        }
+
+        Ok(Self {
+            alpha_g1: decode_point("verifying key alpha", &raw.alpha_g1)?,
+            beta_g2: decode_point("verifying key beta", &raw.beta_g2)?,
+            gamma_g2: decode_point("verifying key gamma", &raw.gamma_g2)?,
+            delta_g2: decode_point("verifying key delta", &raw.delta_g2)?,
+            ic: raw
+                .ic
+                .iter()
+                .map(|ic| decode_point("verifying key IC", ic))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<VerifyingKey> for RawVerifyingKey {
+    fn from(value: VerifyingKey) -> Self {
+        Self {
+            alpha_g1: encode_point(&value.alpha_g1),
+            beta_g2: encode_point(&value.beta_g2),
+            gamma_g2: encode_point(&value.gamma_g2),
+            delta_g2: encode_point(&value.delta_g2),
+            ic: value.ic.iter().map(encode_point).collect(),
+        }
+    }
+}
+
+/// A Groth16 proof.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Proof {
+    pub a: G1Affine,
+    pub b: G2Affine,
+    pub c: G1Affine,
+}
+
+impl TryFrom<RawProof> for Proof {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawProof) -> Result<Self, Self::Error> {
+        Ok(Self {
+            a: decode_point("proof A", &raw.a)?,
+            b: decode_point("proof B", &raw.b)?,
+            c: decode_point("proof C", &raw.c)?,
+        })
+    }
+}
+
+impl From<Proof> for RawProof {
+    fn from(value: Proof) -> Self {
+        Self {
+            a: encode_point(&value.a),
+            b: encode_point(&value.b),
+            c: encode_point(&value.c),
+        }
+    }
+}
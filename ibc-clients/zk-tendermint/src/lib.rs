@@ -0,0 +1,18 @@
+//! The CosmWasm contract implementation of a ZK-verified Tendermint light
+//! client. Verifying a Tendermint commit in a contract is expensive, so the
+//! client instead verifies a Groth16 proof, over BLS12-381, that a valid
+//! commit transition happened from a trusted height to a new header. The
+//! consensus states installed by the proven headers are ICS-07 Tendermint
+//! consensus states, and IBC commitments are verified with ICS-23 proofs as
+//! in the Tendermint client.
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+
+pub mod client_state;
+pub mod client_type;
+pub mod entrypoint;
+pub mod error;
+pub mod groth16;
+pub mod types;
+
+#[cfg(test)]
+mod tests;
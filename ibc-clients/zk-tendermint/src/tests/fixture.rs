@@ -0,0 +1,255 @@
+use std::time::Duration;
+
+use ibc_client_cw::types::{MerklePath, VerifyMembershipMsgRaw, VerifyNonMembershipMsgRaw};
+use ibc_client_cw::utils::AnyCodec;
+use ibc_client_cw_testkit::api::TestClient;
+use ibc_client_cw_testkit::clock::Clock;
+use ibc_client_cw_testkit::store::{sha256, MultiStore, IBC_STORE};
+use ibc_client_tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc_client_tendermint::types::{ConsensusState as TmConsensusStateType, TrustThreshold};
+use ibc_core::client::types::Height;
+use ibc_core::commitment_types::specs::ProofSpecs;
+use ibc_core::host::types::identifiers::ChainId;
+use ibc_core::primitives::{IntoHostTime, Timestamp};
+use tendermint::Hash;
+
+use crate::client_state::ClientState;
+use crate::client_type::ZkTendermintClient;
+use crate::tests::helper::Trapdoor;
+use crate::types::{public_inputs, Header, Misbehaviour};
+
+/// The block time of the synthetic chain.
+const BLOCK_TIME: u64 = 6;
+
+/// Test fixture of the zk-Tendermint client.
+pub type Fixture = ibc_client_cw_testkit::fixture::Fixture<ZkTendermintClient>;
+
+/// A Tendermint chain whose application state is a Cosmos SDK multistore.
+/// Commit transition proofs are simulated with the trapdoor of the Groth16
+/// setup.
+#[derive(Clone, Debug)]
+pub struct ZkTendermintConfig {
+    pub chain_id: ChainId,
+    /// The time of the block at height zero, in seconds.
+    pub genesis_time: u64,
+    pub trusting_period: Duration,
+    pub trapdoor: Trapdoor,
+    /// The (path, value) commitments stored in the IBC store.
+    pub commitments: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+impl Default for ZkTendermintConfig {
+    fn default() -> Self {
+        let now = Clock::default().now().nanoseconds() / 1_000_000_000;
+
+        Self {
+            chain_id: ChainId::new("zk-chain-1").expect("never fails"),
+            genesis_time: now - 3600,
+            trusting_period: Duration::from_secs(64000),
+            trapdoor: Trapdoor::new("commit-transition-circuit"),
+            commitments: vec![
+                (
+                    b"commitments/ports/transfer/channels/channel-0/sequences/1".to_vec(),
+                    b"packet-commitment-1".to_vec(),
+                ),
+                (
+                    b"commitments/ports/transfer/channels/channel-0/sequences/2".to_vec(),
+                    b"packet-commitment-2".to_vec(),
+                ),
+                (
+                    b"connections/connection-0".to_vec(),
+                    b"connection-end".to_vec(),
+                ),
+            ],
+        }
+    }
+}
+
+impl ZkTendermintConfig {
+    pub fn block_time(&self, height: u64) -> Timestamp {
+        Timestamp::from_nanoseconds((self.genesis_time + height * BLOCK_TIME) * 1_000_000_000)
+    }
+
+    /// Returns the multistore committed at the given height. Besides the IBC
+    /// commitments, the IBC store records the height so that the app hash
+    /// changes on every block.
+    pub fn store(&self, height: u64) -> MultiStore {
+        let mut store = MultiStore::default();
+
+        store.set(b"bank", b"supply", b"bank-store");
+        store.set(b"staking", b"validators", b"staking-store");
+
+        for (path, value) in &self.commitments {
+            store.set(IBC_STORE, path, value);
+        }
+
+        store.set(IBC_STORE, b"height", &height.to_be_bytes());
+
+        store.commit();
+
+        store
+    }
+
+    pub fn app_hash(&self, height: u64) -> Vec<u8> {
+        self.store(height).app_hash()
+    }
+
+    /// Proves the header for its current fields, replacing its proof.
+    pub fn proven_header(&self, mut header: Header) -> Header {
+        let public_values = header.public_values(
+            &self.chain_id,
+            TrustThreshold::ONE_THIRD,
+            &ZkTendermintClient::validators_hash(),
+        );
+
+        header.proof = self.trapdoor.prove(&public_inputs(&public_values));
+
+        header
+    }
+
+    /// Builds the header of the block at `height`, with a proof of the
+    /// commit transition from `trusted_height`.
+    pub fn dummy_header(&self, trusted_height: u64, height: u64) -> Header {
+        let unproven_header = Header {
+            trusted_height: ZkTendermintClient::height(trusted_height),
+            height: ZkTendermintClient::height(height),
+            timestamp: self.block_time(height),
+            app_hash: self.app_hash(height),
+            next_validators_hash: ZkTendermintClient::validators_hash(),
+            proof: Trapdoor::placeholder_proof(),
+        };
+
+        self.proven_header(unproven_header)
+    }
+
+    pub fn dummy_verify_membership_msg(
+        &self,
+        height: Height,
+        path: &[u8],
+        value: &[u8],
+    ) -> VerifyMembershipMsgRaw {
+        VerifyMembershipMsgRaw {
+            proof: self
+                .store(height.revision_height())
+                .membership_proof(IBC_STORE, path),
+            merkle_path: MerklePath {
+                key_path: vec![IBC_STORE.to_vec().into(), path.to_vec().into()],
+            },
+            value: value.to_vec().into(),
+            height: height.into(),
+            delay_block_period: 0,
+            delay_time_period: 0,
+        }
+    }
+
+    pub fn dummy_verify_non_membership_msg(
+        &self,
+        height: Height,
+        path: &[u8],
+    ) -> VerifyNonMembershipMsgRaw {
+        VerifyNonMembershipMsgRaw {
+            proof: self
+                .store(height.revision_height())
+                .non_membership_proof(IBC_STORE, path),
+            merkle_path: MerklePath {
+                key_path: vec![IBC_STORE.to_vec().into(), path.to_vec().into()],
+            },
+            height: height.into(),
+            delay_block_period: 0,
+            delay_time_period: 0,
+        }
+    }
+}
+
+impl ZkTendermintClient {
+    pub fn height(height: u64) -> Height {
+        Height::new(1, height).expect("never fails")
+    }
+
+    /// Returns the hash of the validator set, which is the same at every
+    /// height.
+    pub fn validators_hash() -> Hash {
+        Hash::Sha256(sha256(b"validator-set"))
+    }
+
+    pub fn dummy_client_state(fxt: &Fixture) -> ClientState {
+        ClientState {
+            chain_id: fxt.config.chain_id.clone(),
+            trust_level: TrustThreshold::ONE_THIRD,
+            trusting_period: fxt.config.trusting_period,
+            max_clock_drift: Duration::from_secs(10),
+            latest_height: fxt.trusted_height,
+            frozen_height: None,
+            proof_specs: ProofSpecs::cosmos(),
+            verifying_key: fxt.config.trapdoor.verifying_key(),
+        }
+    }
+
+    pub fn dummy_consensus_state(fxt: &Fixture) -> TmConsensusStateType {
+        let trusted_height = fxt.trusted_height.revision_height();
+
+        TmConsensusStateType::new(
+            fxt.config.app_hash(trusted_height).into(),
+            fxt.config
+                .block_time(trusted_height)
+                .into_host_time()
+                .expect("never fails"),
+            Self::validators_hash(),
+        )
+    }
+
+    /// Builds a misbehaviour made of two proven headers at the same height
+    /// but with different app hashes.
+    pub fn dummy_misbehaviour(fxt: &Fixture, height: u64) -> Misbehaviour {
+        let trusted_height = fxt.trusted_height.revision_height();
+
+        let header_1 = fxt.config.dummy_header(trusted_height, height);
+
+        let header_2 = fxt.config.proven_header(Header {
+            app_hash: fxt.config.app_hash(height + 1),
+            ..header_1.clone()
+        });
+
+        Misbehaviour { header_1, header_2 }
+    }
+}
+
+impl TestClient for ZkTendermintClient {
+    type Config = ZkTendermintConfig;
+
+    fn trusting_period(config: &ZkTendermintConfig) -> Duration {
+        config.trusting_period
+    }
+
+    fn trusted_height(_config: &ZkTendermintConfig) -> Height {
+        Self::height(10)
+    }
+
+    fn client_state(fxt: &Fixture) -> Vec<u8> {
+        ClientState::encode_to_any_vec(Self::dummy_client_state(fxt))
+    }
+
+    fn consensus_state(fxt: &Fixture) -> Vec<u8> {
+        TmConsensusState::encode_to_any_vec(TmConsensusState::from(Self::dummy_consensus_state(
+            fxt,
+        )))
+    }
+
+    /// Constructs the header of the block at the target height, proving the
+    /// commit transition from the trusted height.
+    fn header(fxt: &Fixture, target_height: Height) -> Vec<u8> {
+        Header::encode_to_any_vec(fxt.config.dummy_header(
+            fxt.trusted_height.revision_height(),
+            target_height.revision_height(),
+        ))
+    }
+
+    /// Constructs two proven headers of the block right after the trusted
+    /// height, committing to different app hashes.
+    fn misbehaviour(fxt: &Fixture) -> Vec<u8> {
+        Misbehaviour::encode_to_any_vec(Self::dummy_misbehaviour(
+            fxt,
+            fxt.trusted_height.revision_height() + 1,
+        ))
+    }
+}
@@ -0,0 +1,90 @@
+use ark_bls12_381::{Fr, G1Affine, G2Affine};
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{Field, PrimeField};
+use ibc_client_cw_testkit::store::sha256;
+
+use crate::groth16::{Proof as Groth16Proof, VerifyingKey};
+use crate::types::NUM_PUBLIC_INPUTS;
+
+fn scalar(seed: impl AsRef<[u8]>) -> Fr {
+    Fr::from_be_bytes_mod_order(&sha256(seed))
+}
+
+/// The secret scalars of a Groth16 setup. Knowing them allows simulating
+/// valid proofs for any public inputs without a circuit, which is how proofs
+/// of commit transitions are produced in the tests: the verifier cannot tell
+/// them apart from proofs of a real circuit sharing the verifying key.
+#[derive(Clone, Debug)]
+pub struct Trapdoor {
+    alpha: Fr,
+    beta: Fr,
+    gamma: Fr,
+    delta: Fr,
+    ic: Vec<Fr>,
+}
+
+impl Trapdoor {
+    /// Derives a trapdoor deterministically from `seed`.
+    pub fn new(seed: &str) -> Self {
+        let scalar = |name: &str| scalar(format!("{seed}/{name}"));
+
+        Self {
+            alpha: scalar("alpha"),
+            beta: scalar("beta"),
+            gamma: scalar("gamma"),
+            delta: scalar("delta"),
+            ic: (0..=NUM_PUBLIC_INPUTS)
+                .map(|i| scalar(&format!("ic/{i}")))
+                .collect(),
+        }
+    }
+
+    pub fn verifying_key(&self) -> VerifyingKey {
+        let g1 = |s: &Fr| (G1Affine::generator() * s).into_affine();
+        let g2 = |s: &Fr| (G2Affine::generator() * s).into_affine();
+
+        VerifyingKey {
+            alpha_g1: g1(&self.alpha),
+            beta_g2: g2(&self.beta),
+            gamma_g2: g2(&self.gamma),
+            delta_g2: g2(&self.delta),
+            ic: self.ic.iter().map(g1).collect(),
+        }
+    }
+
+    /// Returns a proof made of the points at infinity, to be replaced by a
+    /// simulated proof.
+    pub fn placeholder_proof() -> Groth16Proof {
+        Groth16Proof {
+            a: G1Affine::zero(),
+            b: G2Affine::zero(),
+            c: G1Affine::zero(),
+        }
+    }
+
+    /// Simulates a proof for the given public inputs, picking `A = a * G1`
+    /// and `B = b * G2` and solving the verification equation for `C`.
+    pub fn prove(&self, public_inputs: &[Fr]) -> Groth16Proof {
+        let seed: Vec<u8> = public_inputs
+            .iter()
+            .flat_map(|input| input.into_bigint().to_string().into_bytes())
+            .collect();
+
+        let a = scalar([&seed[..], b"a"].concat());
+        let b = scalar([&seed[..], b"b"].concat());
+
+        let x = public_inputs
+            .iter()
+            .zip(&self.ic[1..])
+            .fold(self.ic[0], |acc, (input, ic)| acc + *input * ic);
+
+        let c = (a * b - self.alpha * self.beta - x * self.gamma)
+            * self.delta.inverse().expect("non-zero delta");
+
+        Groth16Proof {
+            a: (G1Affine::generator() * a).into_affine(),
+            b: (G2Affine::generator() * b).into_affine(),
+            c: (G1Affine::generator() * c).into_affine(),
+        }
+    }
+}
@@ -0,0 +1,407 @@
+use std::time::Duration;
+
+use cosmwasm_std::testing::mock_dependencies;
+use cosmwasm_std::{from_json, HexBinary};
+use ibc_client_cw::types::{ContractResult, MigrationPrefix};
+use ibc_client_cw::utils::AnyCodec;
+use ibc_client_cw_testkit::scenarios;
+use ibc_client_tendermint::types::{
+    ConsensusState as TmConsensusStateType, TrustThreshold, TENDERMINT_CONSENSUS_STATE_TYPE_URL,
+};
+use ibc_core::client::context::ClientValidationContext;
+use ibc_core::client::types::Status;
+use ibc_core::host::types::path::ClientConsensusStatePath;
+use ibc_core::primitives::proto::{Any, Protobuf};
+use prost::Message;
+
+use crate::client_type::ZkTendermintClient;
+use crate::groth16::VerifyingKey;
+use crate::types::proto::{Groth16VerifyingKey as RawVerifyingKey, Header as RawHeader};
+use crate::types::{public_inputs, Header, Misbehaviour};
+
+pub mod fixture;
+pub mod helper;
+
+use fixture::{Fixture, ZkTendermintConfig};
+use helper::Trapdoor;
+
+#[test]
+fn test_cw_zk_tendermint_testkit_scenarios() {
+    scenarios::run_all(&Fixture::default());
+}
+
+#[test]
+fn test_cw_zk_tendermint_update_client_ok() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let trusted_height = fxt.trusted_height.revision_height();
+
+    // ------------------- Adjacent update -------------------
+
+    let resp = fxt
+        .update_client_with(
+            deps.as_mut(),
+            Header::encode_to_any_vec(fxt.config.dummy_header(trusted_height, trusted_height + 1)),
+        )
+        .unwrap();
+
+    let contract_result: ContractResult = from_json(resp.data.unwrap()).unwrap();
+
+    assert_eq!(
+        contract_result.heights,
+        Some(vec![ZkTendermintClient::height(trusted_height + 1)])
+    );
+
+    // ------------------- Skipping update from the new state -------------------
+
+    let resp = fxt
+        .update_client_with(
+            deps.as_mut(),
+            Header::encode_to_any_vec(
+                fxt.config
+                    .dummy_header(trusted_height + 1, trusted_height + 20),
+            ),
+        )
+        .unwrap();
+
+    let contract_result: ContractResult = from_json(resp.data.unwrap()).unwrap();
+
+    assert_eq!(
+        contract_result.heights,
+        Some(vec![ZkTendermintClient::height(trusted_height + 20)])
+    );
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+}
+
+#[test]
+fn test_cw_zk_tendermint_consensus_state_is_tendermint() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let trusted_height = fxt.trusted_height.revision_height();
+
+    let header = fxt.config.dummy_header(trusted_height, trusted_height + 5);
+
+    fxt.update_client_with(deps.as_mut(), Header::encode_to_any_vec(header.clone()))
+        .unwrap();
+
+    let ctx = fxt.ctx_ref(deps.as_ref());
+
+    let consensus_state = ctx
+        .consensus_state(&ClientConsensusStatePath::new(
+            ctx.client_id(),
+            header.height.revision_number(),
+            header.height.revision_height(),
+        ))
+        .unwrap();
+
+    let consensus_state_any = Any::from(consensus_state);
+
+    assert_eq!(
+        consensus_state_any.type_url,
+        TENDERMINT_CONSENSUS_STATE_TYPE_URL
+    );
+
+    assert_eq!(
+        TmConsensusStateType::try_from(consensus_state_any).unwrap(),
+        header.consensus_state().unwrap()
+    );
+}
+
+#[test]
+fn test_cw_zk_tendermint_invalid_header_rejected() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let trusted_height = fxt.trusted_height.revision_height();
+
+    let header = fxt.config.dummy_header(trusted_height, trusted_height + 5);
+
+    assert!(fxt.is_valid_client_message(deps.as_ref(), Header::encode_to_any_vec(header.clone())));
+
+    // ------------------- Public values not matching the proof -------------------
+
+    let mut other_app_hash = header.clone();
+    other_app_hash.app_hash = fxt.config.app_hash(trusted_height + 6);
+
+    let mut other_timestamp = header.clone();
+    other_timestamp.timestamp = fxt.config.block_time(trusted_height + 6);
+
+    let mut other_validators = header.clone();
+    other_validators.next_validators_hash = tendermint::Hash::Sha256([0xff; 32]);
+
+    let mut other_trusted_height = header.clone();
+    other_trusted_height.trusted_height = ZkTendermintClient::height(trusted_height - 1);
+
+    let other_circuit = ZkTendermintConfig {
+        trapdoor: Trapdoor::new("other-circuit"),
+        ..fxt.config.clone()
+    }
+    .dummy_header(trusted_height, trusted_height + 5);
+
+    let mut swapped_points = header.clone();
+    swapped_points.proof.a = header.proof.c;
+    swapped_points.proof.c = header.proof.a;
+
+    // ------------------- Proven headers failing the client checks -------------------
+
+    let not_increasing_height = fxt.config.proven_header(Header {
+        height: ZkTendermintClient::height(trusted_height),
+        ..header.clone()
+    });
+
+    let not_increasing_timestamp = fxt.config.proven_header(Header {
+        timestamp: fxt.config.block_time(trusted_height),
+        ..header.clone()
+    });
+
+    let from_the_future = fxt.config.proven_header(Header {
+        timestamp: (fxt.config.block_time(trusted_height) + Duration::from_secs(7200)).unwrap(),
+        ..header.clone()
+    });
+
+    let untrusted_height = fxt
+        .config
+        .dummy_header(trusted_height + 1, trusted_height + 5);
+
+    for header in [
+        other_app_hash,
+        other_timestamp,
+        other_validators,
+        other_trusted_height,
+        other_circuit,
+        swapped_points,
+        not_increasing_height,
+        not_increasing_timestamp,
+        from_the_future,
+        untrusted_height,
+    ] {
+        assert!(!fxt.is_valid_client_message(deps.as_ref(), Header::encode_to_any_vec(header)));
+    }
+}
+
+#[test]
+fn test_cw_zk_tendermint_verify_membership() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let trusted_height = fxt.trusted_height.revision_height();
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(fxt.config.dummy_header(trusted_height, trusted_height + 5)),
+    )
+    .unwrap();
+
+    for height in [trusted_height, trusted_height + 5] {
+        let height = ZkTendermintClient::height(height);
+
+        for (path, value) in &fxt.config.commitments {
+            fxt.verify_membership(
+                deps.as_mut(),
+                fxt.config.dummy_verify_membership_msg(height, path, value),
+            )
+            .unwrap();
+
+            assert!(fxt
+                .verify_membership(
+                    deps.as_mut(),
+                    fxt.config
+                        .dummy_verify_membership_msg(height, path, b"other-value"),
+                )
+                .is_err());
+
+            assert!(fxt
+                .verify_non_membership(
+                    deps.as_mut(),
+                    fxt.config.dummy_verify_non_membership_msg(height, path),
+                )
+                .is_err());
+        }
+
+        for absent_path in [
+            b"commitments/ports/transfer/channels/channel-0/sequences/3".as_slice(),
+            b"acks/ports/transfer/channels/channel-0/sequences/1",
+            b"receipts/ports/transfer/channels/channel-0/sequences/1",
+        ] {
+            fxt.verify_non_membership(
+                deps.as_mut(),
+                fxt.config
+                    .dummy_verify_non_membership_msg(height, absent_path),
+            )
+            .unwrap();
+        }
+    }
+
+    // A proof against the app hash of another height is rejected.
+    let (path, value) = &fxt.config.commitments[0];
+
+    let mut msg = fxt.config.dummy_verify_membership_msg(
+        ZkTendermintClient::height(trusted_height),
+        path,
+        value,
+    );
+    msg.height = ZkTendermintClient::height(trusted_height + 5).into();
+
+    assert!(fxt.verify_membership(deps.as_mut(), msg).is_err());
+}
+
+#[test]
+fn test_cw_zk_tendermint_freeze_on_misbehaviour() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let misbehaviour =
+        ZkTendermintClient::dummy_misbehaviour(&fxt, fxt.trusted_height.revision_height() + 5);
+
+    fxt.update_client_on_misbehaviour_with(
+        deps.as_mut(),
+        Misbehaviour::encode_to_any_vec(misbehaviour),
+    );
+
+    fxt.check_client_status(deps.as_ref(), Status::Frozen);
+}
+
+#[test]
+fn test_cw_zk_tendermint_freeze_on_conflicting_header() {
+    let fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    let trusted_height = fxt.trusted_height.revision_height();
+
+    fxt.update_client_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(fxt.config.dummy_header(trusted_height, trusted_height + 5)),
+    )
+    .unwrap();
+
+    let conflicting_header =
+        ZkTendermintClient::dummy_misbehaviour(&fxt, trusted_height + 5).header_2;
+
+    fxt.update_client_on_misbehaviour_with(
+        deps.as_mut(),
+        Header::encode_to_any_vec(conflicting_header),
+    );
+
+    fxt.check_client_status(deps.as_ref(), Status::Frozen);
+}
+
+#[test]
+fn test_cw_zk_tendermint_recovery_client_ok() {
+    let mut fxt = Fixture::default();
+
+    let mut deps = mock_dependencies();
+
+    // ------------------- Create subject client -------------------
+
+    fxt.set_migration_prefix(MigrationPrefix::Subject);
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    // ------------------- Freeze subject client -------------------
+
+    let misbehaviour =
+        ZkTendermintClient::dummy_misbehaviour(&fxt, fxt.trusted_height.revision_height() + 5);
+
+    fxt.update_client_on_misbehaviour_with(
+        deps.as_mut(),
+        Misbehaviour::encode_to_any_vec(misbehaviour),
+    );
+
+    fxt.check_client_status(deps.as_ref(), Status::Frozen);
+
+    // ------------------- Create substitute client -------------------
+
+    fxt.set_migration_prefix(MigrationPrefix::Substitute);
+
+    fxt.trusted_height = ZkTendermintClient::height(fxt.trusted_height.revision_height() + 20);
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    // ------------------- Recover subject client -------------------
+
+    let resp = fxt.recover_client(deps.as_mut()).unwrap();
+
+    assert_eq!(0, resp.messages.len());
+
+    fxt.set_migration_prefix(MigrationPrefix::Subject);
+
+    fxt.check_client_status(deps.as_ref(), Status::Active);
+}
+
+#[test]
+fn test_cw_zk_tendermint_client_expiry() {
+    let fxt = Fixture::new(ZkTendermintConfig {
+        trusting_period: Duration::from_secs(60),
+        ..Default::default()
+    });
+
+    let mut deps = mock_dependencies();
+
+    fxt.create_client(deps.as_mut()).unwrap();
+
+    fxt.check_client_status(deps.as_ref(), Status::Expired);
+}
+
+/// A verifying key and a header recorded from the default fixture trapdoor,
+/// pinning the encoding of points and the derivation of the public inputs.
+mod groth16_fixture {
+    pub const VERIFYING_KEY: &str = "0a3093806b1c2d925de60cd281a3eed416e1373a0e24570cf1e3e6fb7967e43f60b2767799e0b487b7b0389f1850afc6cf001260890d24c7e434f7a7df1788c018ee89a03b593171bb8251e790ba88647db4530d6de73c8a5d7756eb9f001d7f94a9a2b81011e9568e4ebe570d66f774302dba2628ea855484614e9a891d1c811c6e2eacfcfb2fec1f1ede49d021b9cd822e510f1a6097f82d172a08f791e9eb0008e93231a2782a642abe9107413e4c21aa09e4171d36729633b9da4ed15240e294d14215930f9d7237d8fa2c124edf2071f48e70c9328dce1e32006dc4e10949bd4d9867756ef7111f791bd1625c3475dcaa40ee452260aa2c0e88d639d8c82b4cbbe35487a25b0c400430b44214bb96a991eb3d0ae801d7239b0b5dc86054695c2b34db23d9680b429d557cb7bd7cc40ad73ddb049fb07618119cbc0e7c67ee86254ede756a6fe53911fc523686d342615011943d54882a308b0cb2937594d1c58dddf0d46cc9f97cd45beee0ac074d32e23748075b3a596425015ba6d52eb9b0e88e814dd07c56532a30977389d250991af7b1a2b031ff196aeca88f4137c357b171702f6deedce1f1600715797aa8536edc8077038238ce61782a30b24ece2438b78b3f9c5a774195b0eefa5ef898b12407ec4c52ea09bc8901923338432cf6629669ccb922ab816dacf1ef";
+
+    pub const HEADER: &str = "0a040801100a12040801100b1880fc9db5cac9ecef1822202f4f3357d1c96695a56e67c9457d3a49035209ad00ed77bfe05951fb4b683e7b2a20df772cb57d0dfafb14f45df86e575a3d5e506ead160f271351bb14b2a5c9d09832c6010a30b81431463878c4011fa9523a8ac132f442148c325be7d742863a3927a65abbe8f891c039bb1a0594c19fabb2773ff5ea12608c13b2e4e574f60ccd039a73ed2f590fa95cc4f287fabead59c51fc2fe26c44289ca2ae866f06af6eacf165a012c02ea063bfbc835a21296ee37ee4432ca873791a50c7ffc15d7192289ca8e9b826f19aa76193a32d29d3e026536814b7327ef1a30ac95f02a1f1357f1ab0841df8bcb8d3ea2fde99117184646db6dc857843c458c5f7d0091133e9469c97bd74703a96cb5";
+}
+
+#[test]
+fn test_cw_zk_tendermint_fixed_proof_fixture() {
+    let fxt = Fixture::default();
+
+    let raw_verifying_key = RawVerifyingKey::decode(
+        HexBinary::from_hex(groth16_fixture::VERIFYING_KEY)
+            .unwrap()
+            .as_slice(),
+    )
+    .unwrap();
+
+    let verifying_key = VerifyingKey::try_from(raw_verifying_key).unwrap();
+
+    let header = <Header as Protobuf<RawHeader>>::decode_vec(
+        &HexBinary::from_hex(groth16_fixture::HEADER).unwrap(),
+    )
+    .unwrap();
+
+    let inputs = public_inputs(&header.public_values(
+        &fxt.config.chain_id,
+        TrustThreshold::ONE_THIRD,
+        &ZkTendermintClient::validators_hash(),
+    ));
+
+    verifying_key.verify(&inputs, &header.proof).unwrap();
+
+    let mut swapped_inputs = inputs.clone();
+    swapped_inputs.swap(0, 1);
+
+    assert!(verifying_key
+        .verify(&swapped_inputs, &header.proof)
+        .is_err());
+    assert!(verifying_key.verify(&inputs[..1], &header.proof).is_err());
+}
@@ -0,0 +1,222 @@
+//! Client messages accepted by the ZK-verified Tendermint light client.
+use ark_bls12_381::Fr;
+use ark_ff::PrimeField;
+use ibc_client_tendermint::types::{ConsensusState as TmConsensusStateType, TrustThreshold};
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Height;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::host::types::identifiers::ChainId;
+use ibc_core::primitives::proto::{Any, Protobuf};
+use ibc_core::primitives::{IntoHostTime, Timestamp};
+use prost::Message;
+use sha2::{Digest, Sha256};
+use tendermint::hash::Algorithm;
+use tendermint::Hash;
+
+use super::proto::{Header as RawHeader, Misbehaviour as RawMisbehaviour, PublicValues};
+use crate::groth16::Proof;
+
+pub const ZK_TENDERMINT_HEADER_TYPE_URL: &str = "/ibc.lightclients.zktendermint.v1.Header";
+pub const ZK_TENDERMINT_MISBEHAVIOUR_TYPE_URL: &str =
+    "/ibc.lightclients.zktendermint.v1.Misbehaviour";
+
+/// The number of public inputs of the commit transition circuit: the two
+/// 128-bit halves of the digest of the public values.
+pub const NUM_PUBLIC_INPUTS: usize = 2;
+
+/// The header submitted to update the client: the fields of the new
+/// consensus state, along with a proof that a valid Tendermint commit
+/// transition happened from the trusted height to a block with these fields.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Header {
+    pub trusted_height: Height,
+    pub height: Height,
+    /// The time of the new block.
+    pub timestamp: Timestamp,
+    /// The app hash of the new block, used as the commitment root.
+    pub app_hash: Vec<u8>,
+    /// The hash of the validator set expected to sign the block following the
+    /// new block.
+    pub next_validators_hash: Hash,
+    pub proof: Proof,
+}
+
+impl Header {
+    /// Returns the public values the proof must be produced for, binding
+    /// the proven transition to the tracked chain and to the trusted
+    /// consensus state.
+    pub fn public_values(
+        &self,
+        chain_id: &ChainId,
+        trust_level: TrustThreshold,
+        trusted_next_validators_hash: &Hash,
+    ) -> PublicValues {
+        PublicValues {
+            chain_id: chain_id.to_string(),
+            trust_level: Some(trust_level.into()),
+            trusted_height: Some(self.trusted_height.into()),
+            trusted_next_validators_hash: trusted_next_validators_hash.as_bytes().to_vec(),
+            height: Some(self.height.into()),
+            timestamp: self.timestamp.nanoseconds(),
+            app_hash: self.app_hash.clone(),
+            next_validators_hash: self.next_validators_hash.as_bytes().to_vec(),
+        }
+    }
+
+    /// Returns the consensus state installed by the header.
+    pub fn consensus_state(&self) -> Result<TmConsensusStateType, ClientError> {
+        Ok(TmConsensusStateType::new(
+            self.app_hash.clone().into(),
+            self.timestamp.into_host_time()?,
+            self.next_validators_hash,
+        ))
+    }
+}
+
+/// Computes the public inputs of the circuit from the public values: the
+/// big-endian halves of their SHA-256 digest, each fitting in the scalar
+/// field.
+pub fn public_inputs(public_values: &PublicValues) -> Vec<Fr> {
+    let digest = Sha256::digest(public_values.encode_to_vec());
+
+    digest
+        .chunks(32 / NUM_PUBLIC_INPUTS)
+        .map(Fr::from_be_bytes_mod_order)
+        .collect()
+}
+
+impl Protobuf<RawHeader> for Header {}
+
+impl TryFrom<RawHeader> for Header {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawHeader) -> Result<Self, Self::Error> {
+        if raw.app_hash.is_empty() {
+            return Err(DecodingError::missing_raw_data("app hash"));
+        }
+
+        let next_validators_hash = Hash::from_bytes(Algorithm::Sha256, &raw.next_validators_hash)
+            .map_err(|e| {
+            DecodingError::invalid_raw_data(format!("next validators hash: {e}"))
+        })?;
+
+        Ok(Self {
+            trusted_height: raw
+                .trusted_height
+                .ok_or(DecodingError::missing_raw_data("trusted height"))?
+                .try_into()?,
+            height: raw
+                .height
+                .ok_or(DecodingError::missing_raw_data("header height"))?
+                .try_into()?,
+            timestamp: Timestamp::from_nanoseconds(raw.timestamp),
+            app_hash: raw.app_hash,
+            next_validators_hash,
+            proof: raw
+                .proof
+                .ok_or(DecodingError::missing_raw_data("Groth16 proof"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<Header> for RawHeader {
+    fn from(value: Header) -> Self {
+        Self {
+            trusted_height: Some(value.trusted_height.into()),
+            height: Some(value.height.into()),
+            timestamp: value.timestamp.nanoseconds(),
+            app_hash: value.app_hash,
+            next_validators_hash: value.next_validators_hash.as_bytes().to_vec(),
+            proof: Some(value.proof.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let ZK_TENDERMINT_HEADER_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawHeader>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: ZK_TENDERMINT_HEADER_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        Self {
+            type_url: ZK_TENDERMINT_HEADER_TYPE_URL.to_string(),
+            value: Protobuf::<RawHeader>::encode_vec(header),
+        }
+    }
+}
+
+/// Two proven headers at the same height installing different consensus
+/// states.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Misbehaviour {
+    pub header_1: Header,
+    pub header_2: Header,
+}
+
+impl Protobuf<RawMisbehaviour> for Misbehaviour {}
+
+impl TryFrom<RawMisbehaviour> for Misbehaviour {
+    type Error = DecodingError;
+
+    fn try_from(raw: RawMisbehaviour) -> Result<Self, Self::Error> {
+        Ok(Self {
+            header_1: raw
+                .header_1
+                .ok_or(DecodingError::missing_raw_data("misbehaviour header 1"))?
+                .try_into()?,
+            header_2: raw
+                .header_2
+                .ok_or(DecodingError::missing_raw_data("misbehaviour header 2"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<Misbehaviour> for RawMisbehaviour {
+    fn from(value: Misbehaviour) -> Self {
+        Self {
+            header_1: Some(value.header_1.into()),
+            header_2: Some(value.header_2.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for Misbehaviour {}
+
+impl TryFrom<Any> for Misbehaviour {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        if let ZK_TENDERMINT_MISBEHAVIOUR_TYPE_URL = raw.type_url.as_str() {
+            Protobuf::<RawMisbehaviour>::decode(raw.value.as_ref()).map_err(Into::into)
+        } else {
+            Err(DecodingError::MismatchedResourceName {
+                expected: ZK_TENDERMINT_MISBEHAVIOUR_TYPE_URL.to_string(),
+                actual: raw.type_url,
+            })
+        }
+    }
+}
+
+impl From<Misbehaviour> for Any {
+    fn from(misbehaviour: Misbehaviour) -> Self {
+        Self {
+            type_url: ZK_TENDERMINT_MISBEHAVIOUR_TYPE_URL.to_string(),
+            value: Protobuf::<RawMisbehaviour>::encode_vec(misbehaviour),
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! Protobuf definitions of the ZK-verified Tendermint light client types,
+//! under the `ibc.lightclients.zktendermint.v1` package. Consensus states are
+//! those of the `ibc.lightclients.tendermint.v1` package.
+use ibc_client_tendermint::types::proto::v1::Fraction;
+use ibc_core::client::types::proto::v1::Height as RawHeight;
+use ibc_core::commitment_types::proto::ics23::ProofSpec;
+
+/// A Groth16 verifying key, made of compressed BLS12-381 points.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Groth16VerifyingKey {
+    #[prost(bytes = "vec", tag = "1")]
+    pub alpha_g1: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub beta_g2: Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub gamma_g2: Vec<u8>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub delta_g2: Vec<u8>,
+    #[prost(bytes = "vec", repeated, tag = "5")]
+    pub ic: Vec<Vec<u8>>,
+}
+
+/// A Groth16 proof, made of compressed BLS12-381 points.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Groth16Proof {
+    #[prost(bytes = "vec", tag = "1")]
+    pub a: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub b: Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub c: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ClientState {
+    #[prost(string, tag = "1")]
+    pub chain_id: String,
+    #[prost(message, optional, tag = "2")]
+    pub trust_level: Option<Fraction>,
+    #[prost(uint64, tag = "3")]
+    pub trusting_period: u64,
+    #[prost(uint64, tag = "4")]
+    pub max_clock_drift: u64,
+    #[prost(message, optional, tag = "5")]
+    pub latest_height: Option<RawHeight>,
+    #[prost(message, optional, tag = "6")]
+    pub frozen_height: Option<RawHeight>,
+    #[prost(message, repeated, tag = "7")]
+    pub proof_specs: Vec<ProofSpec>,
+    #[prost(message, optional, tag = "8")]
+    pub verifying_key: Option<Groth16VerifyingKey>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Header {
+    #[prost(message, optional, tag = "1")]
+    pub trusted_height: Option<RawHeight>,
+    #[prost(message, optional, tag = "2")]
+    pub height: Option<RawHeight>,
+    #[prost(uint64, tag = "3")]
+    pub timestamp: u64,
+    #[prost(bytes = "vec", tag = "4")]
+    pub app_hash: Vec<u8>,
+    #[prost(bytes = "vec", tag = "5")]
+    pub next_validators_hash: Vec<u8>,
+    #[prost(message, optional, tag = "6")]
+    pub proof: Option<Groth16Proof>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Misbehaviour {
+    #[prost(message, optional, tag = "1")]
+    pub header_1: Option<Header>,
+    #[prost(message, optional, tag = "2")]
+    pub header_2: Option<Header>,
+}
+
+/// The public values of a commit transition proof. The circuit commits to
+/// their SHA-256 digest, split into two 128-bit public inputs.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct PublicValues {
+    #[prost(string, tag = "1")]
+    pub chain_id: String,
+    #[prost(message, optional, tag = "2")]
+    pub trust_level: Option<Fraction>,
+    #[prost(message, optional, tag = "3")]
+    pub trusted_height: Option<RawHeight>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub trusted_next_validators_hash: Vec<u8>,
+    #[prost(message, optional, tag = "5")]
+    pub height: Option<RawHeight>,
+    #[prost(uint64, tag = "6")]
+    pub timestamp: u64,
+    #[prost(bytes = "vec", tag = "7")]
+    pub app_hash: Vec<u8>,
+    #[prost(bytes = "vec", tag = "8")]
+    pub next_validators_hash: Vec<u8>,
+}
@@ -0,0 +1,602 @@
+//! The light clients hosted by the contract. The client calls of the IBC core
+//! handlers are routed to the light client of each client state, which runs
+//! against the `ibc-client-cw` context over the store of its client, so that
+//! the stores of the hosted clients keep the layout of 08-wasm clients.
+use cosmwasm_std::Binary;
+#[cfg(any(test, feature = "mock"))]
+use ibc_client_mock_cw::client_state::{
+    ClientState as MockClientState, MOCK_CW_CLIENT_STATE_TYPE_URL,
+};
+#[cfg(any(test, feature = "mock"))]
+use ibc_client_mock_cw::client_type::MockClient;
+use ibc_client_tendermint::client_state::{
+    check_substitute as check_tm_substitute, ClientState as TmClientState,
+};
+use ibc_client_tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc_client_tendermint::types::{
+    ConsensusState as TmConsensusStateType, TENDERMINT_CLIENT_STATE_TYPE_URL,
+    TENDERMINT_CONSENSUS_STATE_TYPE_URL,
+};
+use ibc_client_tendermint_cw::client_type::TendermintClient;
+use ibc_core::client::context::client_state::{
+    ClientStateCommon, ClientStateExecution, ClientStateValidation,
+};
+use ibc_core::client::context::consensus_state::ConsensusState as ConsensusStateTrait;
+use ibc_core::client::context::prelude::*;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::{Height, Status};
+use ibc_core::commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core::host::types::error::{DecodingError, HostError};
+use ibc_core::host::types::identifiers::{ClientId, ClientType};
+use ibc_core::host::types::path::{ClientConsensusStatePath, ClientStatePath, Path, PathBytes};
+use ibc_core::primitives::proto::Any;
+use ibc_core::primitives::Timestamp;
+#[cfg(any(test, feature = "mock"))]
+use ibc_testkit::testapp::ibc::clients::mock::consensus_state::{
+    MockConsensusState, MOCK_CONSENSUS_STATE_TYPE_URL,
+};
+use sha2::{Digest, Sha256};
+
+use crate::context::IbcContext;
+
+/// Returns the checksum recorded in the `WasmClientState` of the hosted
+/// clients of the given type. Hosted clients are not instantiated from a
+/// code, so it is the SHA-256 hash of their client type instead.
+pub fn client_checksum(client_type: &ClientType) -> Binary {
+    Sha256::digest(client_type.as_str()).to_vec().into()
+}
+
+#[derive(Clone, Debug, derive_more::From)]
+pub enum AnyClientState {
+    Tendermint(TmClientState),
+    #[cfg(any(test, feature = "mock"))]
+    Mock(MockClientState),
+}
+
+impl AnyClientState {
+    pub fn checksum(&self) -> Binary {
+        client_checksum(&self.client_type())
+    }
+
+    /// Stores this client state in the store of its client.
+    pub fn store(&self, ctx: &mut IbcContext<'_>, client_id: &ClientId) -> Result<(), HostError> {
+        let path = ClientStatePath::new(client_id.clone());
+
+        match self {
+            Self::Tendermint(cs) => {
+                let mut client_ctx = ctx.client_mut::<TendermintClient>(client_id);
+                client_ctx.set_checksum(self.checksum());
+                client_ctx.store_client_state(path, cs.clone())
+            }
+            #[cfg(any(test, feature = "mock"))]
+            Self::Mock(cs) => {
+                let mut client_ctx = ctx.client_mut::<MockClient>(client_id);
+                client_ctx.set_checksum(self.checksum());
+                client_ctx.store_client_state(path, *cs)
+            }
+        }
+    }
+
+    pub fn update_meta(
+        &self,
+        ctx: &IbcContext<'_>,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<(Timestamp, Height), HostError> {
+        match self {
+            Self::Tendermint(_) => ctx
+                .client_ref::<TendermintClient>(client_id)
+                .client_update_meta(client_id, height),
+            #[cfg(any(test, feature = "mock"))]
+            Self::Mock(_) => ctx
+                .client_ref::<MockClient>(client_id)
+                .client_update_meta(client_id, height),
+        }
+    }
+
+    pub fn store_update_meta(
+        &self,
+        ctx: &mut IbcContext<'_>,
+        client_id: ClientId,
+        height: Height,
+        host_timestamp: Timestamp,
+        host_height: Height,
+    ) -> Result<(), HostError> {
+        match self {
+            Self::Tendermint(_) => ctx
+                .client_mut::<TendermintClient>(&client_id)
+                .store_update_meta(client_id, height, host_timestamp, host_height),
+            #[cfg(any(test, feature = "mock"))]
+            Self::Mock(_) => ctx.client_mut::<MockClient>(&client_id).store_update_meta(
+                client_id,
+                height,
+                host_timestamp,
+                host_height,
+            ),
+        }
+    }
+
+    pub fn delete_update_meta(
+        &self,
+        ctx: &mut IbcContext<'_>,
+        client_id: ClientId,
+        height: Height,
+    ) -> Result<(), HostError> {
+        match self {
+            Self::Tendermint(_) => ctx
+                .client_mut::<TendermintClient>(&client_id)
+                .delete_update_meta(client_id, height),
+            #[cfg(any(test, feature = "mock"))]
+            Self::Mock(_) => ctx
+                .client_mut::<MockClient>(&client_id)
+                .delete_update_meta(client_id, height),
+        }
+    }
+
+    pub fn consensus_state_heights(
+        &self,
+        ctx: &IbcContext<'_>,
+        client_id: &ClientId,
+    ) -> Result<Vec<Height>, HostError> {
+        match self {
+            Self::Tendermint(_) => ctx
+                .client_ref::<TendermintClient>(client_id)
+                .consensus_state_heights(client_id),
+            #[cfg(any(test, feature = "mock"))]
+            Self::Mock(_) => ctx
+                .client_ref::<MockClient>(client_id)
+                .consensus_state_heights(client_id),
+        }
+    }
+
+    pub fn next_consensus_state(
+        &self,
+        ctx: &IbcContext<'_>,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<AnyConsensusState>, HostError> {
+        let consensus_state = match self {
+            Self::Tendermint(_) => ctx
+                .client_ref::<TendermintClient>(client_id)
+                .next_consensus_state(client_id, height)?
+                .map(Into::into),
+            #[cfg(any(test, feature = "mock"))]
+            Self::Mock(_) => ctx
+                .client_ref::<MockClient>(client_id)
+                .next_consensus_state(client_id, height)?
+                .map(Into::into),
+        };
+
+        Ok(consensus_state)
+    }
+
+    pub fn prev_consensus_state(
+        &self,
+        ctx: &IbcContext<'_>,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<AnyConsensusState>, HostError> {
+        let consensus_state = match self {
+            Self::Tendermint(_) => ctx
+                .client_ref::<TendermintClient>(client_id)
+                .prev_consensus_state(client_id, height)?
+                .map(Into::into),
+            #[cfg(any(test, feature = "mock"))]
+            Self::Mock(_) => ctx
+                .client_ref::<MockClient>(client_id)
+                .prev_consensus_state(client_id, height)?
+                .map(Into::into),
+        };
+
+        Ok(consensus_state)
+    }
+}
+
+impl TryFrom<Any> for AnyClientState {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            TENDERMINT_CLIENT_STATE_TYPE_URL => Ok(Self::Tendermint(raw.try_into()?)),
+            #[cfg(any(test, feature = "mock"))]
+            MOCK_CW_CLIENT_STATE_TYPE_URL => Ok(Self::Mock(raw.try_into()?)),
+            _ => Err(DecodingError::UnknownTypeUrl(raw.type_url)),
+        }
+    }
+}
+
+impl From<AnyClientState> for Any {
+    fn from(client_state: AnyClientState) -> Self {
+        match client_state {
+            AnyClientState::Tendermint(cs) => cs.into(),
+            #[cfg(any(test, feature = "mock"))]
+            AnyClientState::Mock(cs) => cs.into(),
+        }
+    }
+}
+
+impl ClientStateCommon for AnyClientState {
+    fn verify_consensus_state(
+        &self,
+        consensus_state: Any,
+        host_timestamp: &Timestamp,
+    ) -> Result<(), ClientError> {
+        match self {
+            Self::Tendermint(cs) => cs.verify_consensus_state(consensus_state, host_timestamp),
+            #[cfg(any(test, feature = "mock"))]
+            Self::Mock(cs) => cs.verify_consensus_state(consensus_state, host_timestamp),
+        }
+    }
+
+    fn client_type(&self) -> ClientType {
+        match self {
+            Self::Tendermint(cs) => cs.client_type(),
+            #[cfg(any(test, feature = "mock"))]
+            Self::Mock(cs) => cs.client_type(),
+        }
+    }
+
+    fn latest_height(&self) -> Height {
+        match self {
+            Self::Tendermint(cs) => cs.latest_height(),
+            #[cfg(any(test, feature = "mock"))]
+            Self::Mock(cs) => cs.latest_height(),
+        }
+    }
+
+    /// The only check of the proof heights of the hosted clients: unlike the
+    /// client contracts, the `ibc-rs` handlers do not call
+    /// `ClientType::verify_proof_height`.
+    fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError> {
+        match self {
+            Self::Tendermint(cs) => cs.validate_proof_height(proof_height),
+            #[cfg(any(test, feature = "mock"))]
+            Self::Mock(cs) => cs.validate_proof_height(proof_height),
+        }
+    }
+
+    fn serialize_path(&self, path: Path) -> Result<PathBytes, ClientError> {
+        match self {
+            Self::Tendermint(cs) => cs.serialize_path(path),
+            #[cfg(any(test, feature = "mock"))]
+            Self::Mock(cs) => cs.serialize_path(path),
+        }
+    }
+
+    fn verify_upgrade_client(
+        &self,
+        upgraded_client_state: Any,
+        upgraded_consensus_state: Any,
+        proof_upgrade_client: CommitmentProofBytes,
+        proof_upgrade_consensus_state: CommitmentProofBytes,
+        root: &CommitmentRoot,
+    ) -> Result<(), ClientError> {
+        match self {
+            Self::Tendermint(cs) => cs.verify_upgrade_client(
+                upgraded_client_state,
+                upgraded_consensus_state,
+                proof_upgrade_client,
+                proof_upgrade_consensus_state,
+                root,
+            ),
+            #[cfg(any(test, feature = "mock"))]
+            Self::Mock(cs) => cs.verify_upgrade_client(
+                upgraded_client_state,
+                upgraded_consensus_state,
+                proof_upgrade_client,
+                proof_upgrade_consensus_state,
+                root,
+            ),
+        }
+    }
+
+    fn verify_membership_raw(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: PathBytes,
+        value: Vec<u8>,
+    ) -> Result<(), ClientError> {
+        match self {
+            Self::Tendermint(cs) => cs.verify_membership_raw(prefix, proof, root, path, value),
+            #[cfg(any(test, feature = "mock"))]
+            Self::Mock(cs) => cs.verify_membership_raw(prefix, proof, root, path, value),
+        }
+    }
+
+    fn verify_non_membership_raw(
+        &self,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProofBytes,
+        root: &CommitmentRoot,
+        path: PathBytes,
+    ) -> Result<(), ClientError> {
+        match self {
+            Self::Tendermint(cs) => cs.verify_non_membership_raw(prefix, proof, root, path),
+            #[cfg(any(test, feature = "mock"))]
+            Self::Mock(cs) => cs.verify_non_membership_raw(prefix, proof, root, path),
+        }
+    }
+}
+
+impl<'a> ClientStateValidation<IbcContext<'a>> for AnyClientState {
+    fn verify_client_message(
+        &self,
+        ctx: &IbcContext<'a>,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        match self {
+            Self::Tendermint(cs) => cs.verify_client_message(
+                &ctx.client_ref::<TendermintClient>(client_id),
+                client_id,
+                client_message,
+            ),
+            #[cfg(any(test, feature = "mock"))]
+            Self::Mock(cs) => cs.verify_client_message(
+                &ctx.client_ref::<MockClient>(client_id),
+                client_id,
+                client_message,
+            ),
+        }
+    }
+
+    fn check_for_misbehaviour(
+        &self,
+        ctx: &IbcContext<'a>,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<bool, ClientError> {
+        match self {
+            Self::Tendermint(cs) => cs.check_for_misbehaviour(
+                &ctx.client_ref::<TendermintClient>(client_id),
+                client_id,
+                client_message,
+            ),
+            #[cfg(any(test, feature = "mock"))]
+            Self::Mock(cs) => cs.check_for_misbehaviour(
+                &ctx.client_ref::<MockClient>(client_id),
+                client_id,
+                client_message,
+            ),
+        }
+    }
+
+    fn status(&self, ctx: &IbcContext<'a>, client_id: &ClientId) -> Result<Status, ClientError> {
+        match self {
+            Self::Tendermint(cs) => {
+                cs.status(&ctx.client_ref::<TendermintClient>(client_id), client_id)
+            }
+            #[cfg(any(test, feature = "mock"))]
+            Self::Mock(cs) => cs.status(&ctx.client_ref::<MockClient>(client_id), client_id),
+        }
+    }
+
+    /// Checks the substitute, which must be a client of the same type,
+    /// without the store of any client.
+    fn check_substitute(
+        &self,
+        _ctx: &IbcContext<'a>,
+        substitute_client_state: Any,
+    ) -> Result<(), ClientError> {
+        match self {
+            Self::Tendermint(cs) => check_tm_substitute::<
+                ibc_client_cw::context::Context<'_, TendermintClient>,
+            >(cs.inner(), substitute_client_state),
+            #[cfg(any(test, feature = "mock"))]
+            Self::Mock(_) => {
+                MockClientState::try_from(substitute_client_state)?;
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<'a> ClientStateExecution<IbcContext<'a>> for AnyClientState {
+    fn initialise(
+        &self,
+        ctx: &mut IbcContext<'a>,
+        client_id: &ClientId,
+        consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        match self {
+            Self::Tendermint(cs) => {
+                let mut client_ctx = ctx.client_mut::<TendermintClient>(client_id);
+                client_ctx.set_checksum(self.checksum());
+                cs.initialise(&mut client_ctx, client_id, consensus_state)
+            }
+            #[cfg(any(test, feature = "mock"))]
+            Self::Mock(cs) => {
+                let mut client_ctx = ctx.client_mut::<MockClient>(client_id);
+                client_ctx.set_checksum(self.checksum());
+                cs.initialise(&mut client_ctx, client_id, consensus_state)
+            }
+        }
+    }
+
+    fn update_state(
+        &self,
+        ctx: &mut IbcContext<'a>,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<Vec<Height>, ClientError> {
+        match self {
+            Self::Tendermint(cs) => cs.update_state(
+                &mut ctx.client_mut::<TendermintClient>(client_id),
+                client_id,
+                header,
+            ),
+            #[cfg(any(test, feature = "mock"))]
+            Self::Mock(cs) => cs.update_state(
+                &mut ctx.client_mut::<MockClient>(client_id),
+                client_id,
+                header,
+            ),
+        }
+    }
+
+    fn update_state_on_misbehaviour(
+        &self,
+        ctx: &mut IbcContext<'a>,
+        client_id: &ClientId,
+        client_message: Any,
+    ) -> Result<(), ClientError> {
+        match self {
+            Self::Tendermint(cs) => cs.update_state_on_misbehaviour(
+                &mut ctx.client_mut::<TendermintClient>(client_id),
+                client_id,
+                client_message,
+            ),
+            #[cfg(any(test, feature = "mock"))]
+            Self::Mock(cs) => cs.update_state_on_misbehaviour(
+                &mut ctx.client_mut::<MockClient>(client_id),
+                client_id,
+                client_message,
+            ),
+        }
+    }
+
+    fn update_state_on_upgrade(
+        &self,
+        ctx: &mut IbcContext<'a>,
+        client_id: &ClientId,
+        upgraded_client_state: Any,
+        upgraded_consensus_state: Any,
+    ) -> Result<Height, ClientError> {
+        match self {
+            Self::Tendermint(cs) => cs.update_state_on_upgrade(
+                &mut ctx.client_mut::<TendermintClient>(client_id),
+                client_id,
+                upgraded_client_state,
+                upgraded_consensus_state,
+            ),
+            #[cfg(any(test, feature = "mock"))]
+            Self::Mock(cs) => cs.update_state_on_upgrade(
+                &mut ctx.client_mut::<MockClient>(client_id),
+                client_id,
+                upgraded_client_state,
+                upgraded_consensus_state,
+            ),
+        }
+    }
+
+    fn update_on_recovery(
+        &self,
+        ctx: &mut IbcContext<'a>,
+        subject_client_id: &ClientId,
+        substitute_client_state: Any,
+        substitute_consensus_state: Any,
+    ) -> Result<(), ClientError> {
+        match self {
+            Self::Tendermint(cs) => cs.update_on_recovery(
+                &mut ctx.client_mut::<TendermintClient>(subject_client_id),
+                subject_client_id,
+                substitute_client_state,
+                substitute_consensus_state,
+            ),
+            #[cfg(any(test, feature = "mock"))]
+            Self::Mock(cs) => cs.update_on_recovery(
+                &mut ctx.client_mut::<MockClient>(subject_client_id),
+                subject_client_id,
+                substitute_client_state,
+                substitute_consensus_state,
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Debug, derive_more::From)]
+pub enum AnyConsensusState {
+    Tendermint(TmConsensusState),
+    #[cfg(any(test, feature = "mock"))]
+    Mock(MockConsensusState),
+}
+
+impl AnyConsensusState {
+    /// Stores this consensus state in the store of its client.
+    pub fn store(
+        self,
+        ctx: &mut IbcContext<'_>,
+        path: ClientConsensusStatePath,
+    ) -> Result<(), HostError> {
+        let client_id = path.client_id.clone();
+
+        match self {
+            Self::Tendermint(cs) => ctx
+                .client_mut::<TendermintClient>(&client_id)
+                .store_consensus_state(path, cs),
+            #[cfg(any(test, feature = "mock"))]
+            Self::Mock(cs) => ctx
+                .client_mut::<MockClient>(&client_id)
+                .store_consensus_state(path, cs),
+        }
+    }
+}
+
+impl ConsensusStateTrait for AnyConsensusState {
+    fn root(&self) -> &CommitmentRoot {
+        match self {
+            Self::Tendermint(cs) => cs.root(),
+            #[cfg(any(test, feature = "mock"))]
+            Self::Mock(cs) => cs.root(),
+        }
+    }
+
+    fn timestamp(&self) -> Result<Timestamp, ClientError> {
+        match self {
+            // the inherent method returns the time of the Tendermint header
+            Self::Tendermint(cs) => ConsensusStateTrait::timestamp(cs),
+            #[cfg(any(test, feature = "mock"))]
+            Self::Mock(cs) => ConsensusStateTrait::timestamp(cs),
+        }
+    }
+}
+
+impl TryFrom<Any> for AnyConsensusState {
+    type Error = DecodingError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            TENDERMINT_CONSENSUS_STATE_TYPE_URL => Ok(Self::Tendermint(raw.try_into()?)),
+            #[cfg(any(test, feature = "mock"))]
+            MOCK_CONSENSUS_STATE_TYPE_URL => Ok(Self::Mock(raw.try_into()?)),
+            _ => Err(DecodingError::UnknownTypeUrl(raw.type_url)),
+        }
+    }
+}
+
+impl From<AnyConsensusState> for Any {
+    fn from(consensus_state: AnyConsensusState) -> Self {
+        match consensus_state {
+            AnyConsensusState::Tendermint(cs) => cs.into(),
+            #[cfg(any(test, feature = "mock"))]
+            AnyConsensusState::Mock(cs) => cs.into(),
+        }
+    }
+}
+
+/// Lets the Tendermint client state of the hosting chain, as stored by its
+/// counterparties, be validated against the core context.
+impl From<TmConsensusStateType> for AnyConsensusState {
+    fn from(consensus_state: TmConsensusStateType) -> Self {
+        Self::Tendermint(consensus_state.into())
+    }
+}
+
+impl TryFrom<AnyConsensusState> for TmConsensusStateType {
+    type Error = ClientError;
+
+    fn try_from(consensus_state: AnyConsensusState) -> Result<Self, Self::Error> {
+        match consensus_state {
+            AnyConsensusState::Tendermint(cs) => Ok(cs.into_inner()),
+            #[cfg(any(test, feature = "mock"))]
+            AnyConsensusState::Mock(_) => Err(ClientError::ClientSpecific {
+                description: "expected a Tendermint consensus state".to_string(),
+            }),
+        }
+    }
+}
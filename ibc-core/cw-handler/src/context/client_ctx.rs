@@ -0,0 +1,156 @@
+//! Implementation of the client contexts for the `IbcContext` type, which
+//! routes the reads and writes of the core handlers to the stores of the
+//! hosted clients.
+use ibc_client_cw::utils::AnyCodec;
+use ibc_client_wasm_types::client_state::ClientState as WasmClientState;
+use ibc_client_wasm_types::consensus_state::ConsensusState as WasmConsensusState;
+use ibc_core::client::context::prelude::*;
+use ibc_core::client::types::Height;
+use ibc_core::host::types::error::HostError;
+use ibc_core::host::types::identifiers::ClientId;
+use ibc_core::host::types::path::{ClientConsensusStatePath, ClientStatePath};
+use ibc_core::host::ValidationContext;
+use ibc_core::primitives::proto::{Any, Protobuf};
+use ibc_core::primitives::Timestamp;
+
+use super::IbcContext;
+use crate::client::{AnyClientState, AnyConsensusState};
+
+impl ClientValidationContext for IbcContext<'_> {
+    type ClientStateRef = AnyClientState;
+    type ConsensusStateRef = AnyConsensusState;
+
+    fn client_state(&self, client_id: &ClientId) -> Result<Self::ClientStateRef, HostError> {
+        let client_state_value = self
+            .retrieve(ClientStatePath::new(client_id.clone()).to_string())
+            .ok_or(HostError::missing_state(format!(
+                "client state of client `{client_id}`"
+            )))?;
+
+        let any_wasm: WasmClientState = Protobuf::<Any>::decode(client_state_value.as_slice())
+            .map_err(HostError::invalid_state)?;
+
+        AnyClientState::decode_any_vec(any_wasm.data).map_err(HostError::invalid_state)
+    }
+
+    fn consensus_state(
+        &self,
+        client_cons_state_path: &ClientConsensusStatePath,
+    ) -> Result<Self::ConsensusStateRef, HostError> {
+        let consensus_state_value =
+            self.retrieve(client_cons_state_path.to_string())
+                .ok_or(HostError::missing_state(format!(
+                    "consensus state at `{client_cons_state_path}`"
+                )))?;
+
+        let any_wasm: WasmConsensusState = AnyConsensusState::decode_any_vec(consensus_state_value)
+            .map_err(HostError::invalid_state)?;
+
+        AnyConsensusState::decode_any_vec(any_wasm.data).map_err(HostError::invalid_state)
+    }
+
+    /// Returns the update metadata stored by the client. The hosted clients
+    /// record the heights of the hosting chain in revision zero, as 08-wasm
+    /// clients do, while the core handlers compare them to the host height
+    /// of the current revision.
+    fn client_update_meta(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<(Timestamp, Height), HostError> {
+        let (timestamp, processed_height) = self
+            .client_state(client_id)?
+            .update_meta(self, client_id, height)?;
+
+        let processed_height = Height::new(
+            ValidationContext::host_height(self)?.revision_number(),
+            processed_height.revision_height(),
+        )
+        .map_err(HostError::invalid_state)?;
+
+        Ok((timestamp, processed_height))
+    }
+}
+
+impl ClientExecutionContext for IbcContext<'_> {
+    type ClientStateMut = AnyClientState;
+
+    fn store_client_state(
+        &mut self,
+        client_state_path: ClientStatePath,
+        client_state: Self::ClientStateMut,
+    ) -> Result<(), HostError> {
+        client_state.store(self, &client_state_path.0)
+    }
+
+    fn store_consensus_state(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+        consensus_state: Self::ConsensusStateRef,
+    ) -> Result<(), HostError> {
+        consensus_state.store(self, consensus_state_path)
+    }
+
+    fn delete_consensus_state(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+    ) -> Result<(), HostError> {
+        self.remove(consensus_state_path.to_string());
+
+        Ok(())
+    }
+
+    fn store_update_meta(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+        host_timestamp: Timestamp,
+        host_height: Height,
+    ) -> Result<(), HostError> {
+        self.client_state(&client_id)?.store_update_meta(
+            self,
+            client_id,
+            height,
+            host_timestamp,
+            host_height,
+        )
+    }
+
+    fn delete_update_meta(&mut self, client_id: ClientId, height: Height) -> Result<(), HostError> {
+        self.client_state(&client_id)?
+            .delete_update_meta(self, client_id, height)
+    }
+}
+
+impl ExtClientValidationContext for IbcContext<'_> {
+    fn host_timestamp(&self) -> Result<Timestamp, HostError> {
+        ValidationContext::host_timestamp(self)
+    }
+
+    fn host_height(&self) -> Result<Height, HostError> {
+        ValidationContext::host_height(self)
+    }
+
+    fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, HostError> {
+        self.client_state(client_id)?
+            .consensus_state_heights(self, client_id)
+    }
+
+    fn next_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<Self::ConsensusStateRef>, HostError> {
+        self.client_state(client_id)?
+            .next_consensus_state(self, client_id, height)
+    }
+
+    fn prev_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<Self::ConsensusStateRef>, HostError> {
+        self.client_state(client_id)?
+            .prev_consensus_state(self, client_id, height)
+    }
+}
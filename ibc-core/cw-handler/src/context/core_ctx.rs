@@ -0,0 +1,417 @@
+//! Implementation of the `ValidationContext` and `ExecutionContext` traits
+//! for the `IbcContext` type. The IBC states are stored under their ICS-24
+//! paths, encoded as `ibc-go` commits them.
+use core::time::Duration;
+
+use cosmwasm_std::Event;
+use ibc_client_tendermint::client_state::ClientState as TmClientState;
+use ibc_client_tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc_core::channel::types::channel::ChannelEnd;
+use ibc_core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc_core::channel::types::packet::Receipt;
+use ibc_core::client::context::client_state::ClientStateCommon;
+use ibc_core::client::types::error::ClientError;
+use ibc_core::client::types::Height;
+use ibc_core::commitment_types::commitment::CommitmentPrefix;
+use ibc_core::connection::types::proto::v1::ClientPaths;
+use ibc_core::connection::types::ConnectionEnd;
+use ibc_core::handler::types::events::IbcEvent;
+use ibc_core::host::types::error::HostError;
+use ibc_core::host::types::identifiers::{ChainId, ConnectionId, Sequence};
+use ibc_core::host::types::path::{
+    AckPath, ChannelEndPath, ClientConnectionPath, CommitmentPath, ConnectionPath,
+    NextChannelSequencePath, NextClientSequencePath, NextConnectionSequencePath, ReceiptPath,
+    SeqAckPath, SeqRecvPath, SeqSendPath,
+};
+use ibc_core::host::{ExecutionContext, ValidationContext};
+use ibc_core::primitives::proto::{Any, Protobuf};
+use ibc_core::primitives::{Signer, Timestamp};
+use prost::Message;
+use tendermint::abci;
+
+use super::{IbcContext, HOST_CONSENSUS_STATES};
+
+/// The value `ibc-go` stores as the receipt of a received packet.
+const RECEIPT_OK: &[u8] = &[1];
+
+impl IbcContext<'_> {
+    /// Returns the chain identifier of the hosting chain, whose revision is
+    /// the revision of the host heights.
+    pub fn chain_id(&self) -> Result<ChainId, HostError> {
+        ChainId::new(&self.env().block.chain_id).map_err(HostError::invalid_state)
+    }
+
+    fn retrieve_sequence(&self, key: impl AsRef<[u8]>) -> Result<Sequence, HostError> {
+        self.retrieve_u64(key)?
+            .map(Sequence::from)
+            .ok_or(HostError::failed_to_retrieve("packet sequence"))
+    }
+
+    fn increase_counter(&mut self, key: impl AsRef<[u8]>) -> Result<(), HostError> {
+        let counter = self.retrieve_u64(key.as_ref())?.unwrap_or_default();
+
+        let counter = counter
+            .checked_add(1)
+            .ok_or(HostError::invalid_state("counter overflow"))?;
+
+        self.insert_u64(key, counter);
+
+        Ok(())
+    }
+}
+
+/// Converts an IBC event to a CosmWasm event, with the attributes `ibc-go`
+/// emits.
+fn event_from_ibc(event: IbcEvent) -> Result<Event, HostError> {
+    let event = abci::Event::try_from(event).map_err(HostError::invalid_state)?;
+
+    let attributes = event
+        .attributes
+        .iter()
+        .map(|attribute| {
+            let key = attribute.key_str().map_err(HostError::invalid_state)?;
+            let value = attribute.value_str().map_err(HostError::invalid_state)?;
+
+            Ok((key, value))
+        })
+        .collect::<Result<Vec<_>, HostError>>()?;
+
+    Ok(Event::new(event.kind).add_attributes(attributes))
+}
+
+impl ValidationContext for IbcContext<'_> {
+    type V = Self;
+    type HostClientState = TmClientState;
+    type HostConsensusState = TmConsensusState;
+
+    fn get_client_validation_context(&self) -> &Self::V {
+        self
+    }
+
+    fn host_height(&self) -> Result<Height, HostError> {
+        Height::new(self.chain_id()?.revision_number(), self.env().block.height)
+            .map_err(HostError::invalid_state)
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, HostError> {
+        Ok(Timestamp::from_nanoseconds(self.env().block.time.nanos()))
+    }
+
+    fn host_consensus_state(&self, height: &Height) -> Result<Self::HostConsensusState, HostError> {
+        let host_height = self.host_height()?;
+
+        let consensus_state = match height.revision_number() == host_height.revision_number() {
+            true => HOST_CONSENSUS_STATES
+                .may_load(self.storage_ref(), height.revision_height())
+                .map_err(HostError::failed_to_retrieve)?,
+            false => None,
+        }
+        .ok_or(HostError::missing_state(
+            ClientError::MissingLocalConsensusState(*height),
+        ))?;
+
+        Protobuf::<Any>::decode(consensus_state.as_slice()).map_err(HostError::invalid_state)
+    }
+
+    fn client_counter(&self) -> Result<u64, HostError> {
+        Ok(self
+            .retrieve_u64(NextClientSequencePath.to_string())?
+            .unwrap_or_default())
+    }
+
+    fn connection_end(&self, conn_id: &ConnectionId) -> Result<ConnectionEnd, HostError> {
+        let value = self
+            .retrieve(ConnectionPath::new(conn_id).to_string())
+            .ok_or(HostError::missing_state(format!(
+                "connection end for connection `{conn_id}`"
+            )))?;
+
+        Protobuf::decode_vec(&value).map_err(HostError::invalid_state)
+    }
+
+    /// Checks the client state of the hosting chain stored by a counterparty,
+    /// as the ibc-rs mock host does.
+    fn validate_self_client(
+        &self,
+        client_state_of_host_on_counterparty: Self::HostClientState,
+    ) -> Result<(), HostError> {
+        let client_state = client_state_of_host_on_counterparty.inner();
+
+        if client_state.is_frozen() {
+            return Err(HostError::invalid_state("client unexpectedly frozen"));
+        }
+
+        let chain_id = self.chain_id()?;
+
+        if client_state.chain_id() != &chain_id {
+            return Err(HostError::invalid_state(format!(
+                "client is not of the hosting chain; expected `{chain_id}`, actual `{}`",
+                client_state.chain_id()
+            )));
+        }
+
+        let latest_height = client_state_of_host_on_counterparty.latest_height();
+        let host_height = self.host_height()?;
+
+        if latest_height.revision_number() != host_height.revision_number() {
+            return Err(HostError::invalid_state(format!(
+                "client is not in the same revision as the chain; expected `{}`, actual `{}`",
+                host_height.revision_number(),
+                latest_height.revision_number()
+            )));
+        }
+
+        if latest_height >= host_height.increment() {
+            return Err(HostError::invalid_state(format!(
+                "client latest height `{latest_height}` should be less than chain height `{}`",
+                host_height.increment()
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn commitment_prefix(&self) -> CommitmentPrefix {
+        // the configuration is saved upon instantiation
+        let prefix = self
+            .config()
+            .map(|config| config.commitment_prefix)
+            .unwrap_or_default();
+
+        CommitmentPrefix::from_bytes(prefix)
+    }
+
+    fn connection_counter(&self) -> Result<u64, HostError> {
+        Ok(self
+            .retrieve_u64(NextConnectionSequencePath.to_string())?
+            .unwrap_or_default())
+    }
+
+    fn channel_end(&self, channel_end_path: &ChannelEndPath) -> Result<ChannelEnd, HostError> {
+        let value = self
+            .retrieve(channel_end_path.to_string())
+            .ok_or(HostError::missing_state(format!(
+                "channel `{}` in port `{}`",
+                channel_end_path.1, channel_end_path.0
+            )))?;
+
+        Protobuf::decode_vec(&value).map_err(HostError::invalid_state)
+    }
+
+    fn get_next_sequence_send(&self, seq_send_path: &SeqSendPath) -> Result<Sequence, HostError> {
+        self.retrieve_sequence(seq_send_path.to_string())
+    }
+
+    fn get_next_sequence_recv(&self, seq_recv_path: &SeqRecvPath) -> Result<Sequence, HostError> {
+        self.retrieve_sequence(seq_recv_path.to_string())
+    }
+
+    fn get_next_sequence_ack(&self, seq_ack_path: &SeqAckPath) -> Result<Sequence, HostError> {
+        self.retrieve_sequence(seq_ack_path.to_string())
+    }
+
+    fn get_packet_commitment(
+        &self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<PacketCommitment, HostError> {
+        self.retrieve(commitment_path.to_string())
+            .map(PacketCommitment::from)
+            .ok_or(HostError::failed_to_retrieve("packet commitment"))
+    }
+
+    fn get_packet_receipt(&self, receipt_path: &ReceiptPath) -> Result<Receipt, HostError> {
+        match self.retrieve(receipt_path.to_string()) {
+            Some(_) => Ok(Receipt::Ok),
+            None => Ok(Receipt::None),
+        }
+    }
+
+    fn get_packet_acknowledgement(
+        &self,
+        ack_path: &AckPath,
+    ) -> Result<AcknowledgementCommitment, HostError> {
+        self.retrieve(ack_path.to_string())
+            .map(AcknowledgementCommitment::from)
+            .ok_or(HostError::failed_to_retrieve(format!(
+                "packet acknowledgment `{}`",
+                ack_path.sequence
+            )))
+    }
+
+    fn channel_counter(&self) -> Result<u64, HostError> {
+        Ok(self
+            .retrieve_u64(NextChannelSequencePath.to_string())?
+            .unwrap_or_default())
+    }
+
+    fn max_expected_time_per_block(&self) -> Duration {
+        // the configuration is saved upon instantiation
+        let secs = self
+            .config()
+            .map(|config| config.max_expected_time_per_block)
+            .unwrap_or_default();
+
+        Duration::from_secs(secs)
+    }
+
+    /// Only accepts the messages signed by the sender of the contract call,
+    /// so that relayers cannot submit messages on behalf of others.
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), HostError> {
+        match self.sender {
+            Some(ref sender) if sender.as_str() == signer.as_ref() => Ok(()),
+            _ => Err(HostError::invalid_state(format!(
+                "signer `{signer}` is not the sender of the message"
+            ))),
+        }
+    }
+}
+
+impl ExecutionContext for IbcContext<'_> {
+    type E = Self;
+
+    fn get_client_execution_context(&mut self) -> &mut Self::E {
+        self
+    }
+
+    fn increase_client_counter(&mut self) -> Result<(), HostError> {
+        self.increase_counter(NextClientSequencePath.to_string())
+    }
+
+    fn store_connection(
+        &mut self,
+        connection_path: &ConnectionPath,
+        connection_end: ConnectionEnd,
+    ) -> Result<(), HostError> {
+        self.insert(connection_path.to_string(), connection_end.encode_vec());
+
+        Ok(())
+    }
+
+    fn store_connection_to_client(
+        &mut self,
+        client_connection_path: &ClientConnectionPath,
+        conn_id: ConnectionId,
+    ) -> Result<(), HostError> {
+        let key = client_connection_path.to_string();
+
+        let mut client_paths = match self.retrieve(&key) {
+            Some(value) => {
+                ClientPaths::decode(value.as_slice()).map_err(HostError::invalid_state)?
+            }
+            None => ClientPaths::default(),
+        };
+
+        client_paths.paths.push(conn_id.to_string());
+
+        self.insert(key, client_paths.encode_to_vec());
+
+        Ok(())
+    }
+
+    fn increase_connection_counter(&mut self) -> Result<(), HostError> {
+        self.increase_counter(NextConnectionSequencePath.to_string())
+    }
+
+    fn store_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+        commitment: PacketCommitment,
+    ) -> Result<(), HostError> {
+        self.insert(commitment_path.to_string(), commitment.into_vec());
+
+        Ok(())
+    }
+
+    fn delete_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<(), HostError> {
+        self.remove(commitment_path.to_string());
+
+        Ok(())
+    }
+
+    fn store_packet_receipt(
+        &mut self,
+        receipt_path: &ReceiptPath,
+        _receipt: Receipt,
+    ) -> Result<(), HostError> {
+        self.insert(receipt_path.to_string(), RECEIPT_OK);
+
+        Ok(())
+    }
+
+    fn store_packet_acknowledgement(
+        &mut self,
+        ack_path: &AckPath,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), HostError> {
+        self.insert(ack_path.to_string(), ack_commitment.into_vec());
+
+        Ok(())
+    }
+
+    fn delete_packet_acknowledgement(&mut self, ack_path: &AckPath) -> Result<(), HostError> {
+        self.remove(ack_path.to_string());
+
+        Ok(())
+    }
+
+    fn store_channel(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        channel_end: ChannelEnd,
+    ) -> Result<(), HostError> {
+        self.insert(channel_end_path.to_string(), channel_end.encode_vec());
+
+        Ok(())
+    }
+
+    fn store_next_sequence_send(
+        &mut self,
+        seq_send_path: &SeqSendPath,
+        seq: Sequence,
+    ) -> Result<(), HostError> {
+        self.insert_u64(seq_send_path.to_string(), seq.value());
+
+        Ok(())
+    }
+
+    fn store_next_sequence_recv(
+        &mut self,
+        seq_recv_path: &SeqRecvPath,
+        seq: Sequence,
+    ) -> Result<(), HostError> {
+        self.insert_u64(seq_recv_path.to_string(), seq.value());
+
+        Ok(())
+    }
+
+    fn store_next_sequence_ack(
+        &mut self,
+        seq_ack_path: &SeqAckPath,
+        seq: Sequence,
+    ) -> Result<(), HostError> {
+        self.insert_u64(seq_ack_path.to_string(), seq.value());
+
+        Ok(())
+    }
+
+    fn increase_channel_counter(&mut self) -> Result<(), HostError> {
+        self.increase_counter(NextChannelSequencePath.to_string())
+    }
+
+    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), HostError> {
+        let event = event_from_ibc(event)?;
+
+        self.events.push(event);
+
+        Ok(())
+    }
+
+    fn log_message(&mut self, message: String) -> Result<(), HostError> {
+        self.api().debug(&message);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,174 @@
+pub mod client_ctx;
+pub mod core_ctx;
+
+use core::fmt::Display;
+
+use cosmwasm_std::{Addr, Api, Binary, Deps, DepsMut, Env, Event, Storage};
+use cw_storage_plus::{Item, Map};
+use ibc_client_cw::api::ClientType;
+use ibc_client_cw::context::Context;
+use ibc_core::host::types::error::HostError;
+use ibc_core::host::types::identifiers::ClientId;
+use ibc_core::primitives::proto::Any;
+
+use crate::types::Config;
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// The consensus states of the hosting chain provided by the chain itself,
+/// by revision height.
+pub const HOST_CONSENSUS_STATES: Map<u64, Binary> = Map::new("host_consensus_states");
+
+/// IbcContext is a wrapper around the deps and env that provides access to
+/// the methods under the ibc-rs core Validation and Execution traits, and
+/// collects the events they emit.
+///
+/// It is the client context of the hosted clients as well: the client calls
+/// of the core handlers are routed to the light client of each client state,
+/// running over a [`Context`] of the store of the client.
+pub struct IbcContext<'a> {
+    deps: Option<Deps<'a>>,
+    deps_mut: Option<DepsMut<'a>>,
+    env: Env,
+    sender: Option<Addr>,
+    events: Vec<Event>,
+}
+
+impl<'a> IbcContext<'a> {
+    /// Constructs a new IbcContext object with the given deps and env.
+    pub fn new_ref(deps: Deps<'a>, env: Env) -> Self {
+        Self {
+            deps: Some(deps),
+            deps_mut: None,
+            env,
+            sender: None,
+            events: Vec::new(),
+        }
+    }
+
+    /// Constructs a new IbcContext object with the given deps_mut and env.
+    pub fn new_mut(deps_mut: DepsMut<'a>, env: Env) -> Self {
+        Self {
+            deps: None,
+            deps_mut: Some(deps_mut),
+            env,
+            sender: None,
+            events: Vec::new(),
+        }
+    }
+
+    /// Returns the env of the context.
+    pub fn env(&self) -> &Env {
+        &self.env
+    }
+
+    pub fn api(&self) -> &dyn Api {
+        match self.deps {
+            Some(ref deps) => deps.api,
+            None => match self.deps_mut {
+                Some(ref deps) => deps.api,
+                None => panic!("Either deps or deps_mut should be available"),
+            },
+        }
+    }
+
+    pub fn storage_ref(&self) -> &dyn Storage {
+        match self.deps {
+            Some(ref deps) => deps.storage,
+            None => match self.deps_mut {
+                Some(ref deps) => deps.storage,
+                None => panic!("Either deps or deps_mut should be available"),
+            },
+        }
+    }
+
+    pub fn storage_mut(&mut self) -> &mut dyn Storage {
+        match self.deps_mut {
+            Some(ref mut deps) => deps.storage,
+            None => panic!("deps_mut should be available"),
+        }
+    }
+
+    /// Sets the sender of the IBC messages, which must be their signer.
+    pub fn set_sender(&mut self, sender: Addr) {
+        self.sender = Some(sender);
+    }
+
+    /// Takes the events emitted so far.
+    pub fn take_events(&mut self) -> Vec<Event> {
+        core::mem::take(&mut self.events)
+    }
+
+    pub fn config(&self) -> Result<Config, HostError> {
+        CONFIG
+            .load(self.storage_ref())
+            .map_err(HostError::failed_to_retrieve)
+    }
+
+    /// Retrieves the value of the given key.
+    pub fn retrieve(&self, key: impl AsRef<[u8]>) -> Option<Vec<u8>> {
+        self.storage_ref().get(key.as_ref())
+    }
+
+    /// Inserts the given key-value pair.
+    pub fn insert(&mut self, key: impl AsRef<[u8]>, value: impl AsRef<[u8]>) {
+        self.storage_mut().set(key.as_ref(), value.as_ref());
+    }
+
+    /// Removes the value of the given key.
+    pub fn remove(&mut self, key: impl AsRef<[u8]>) {
+        self.storage_mut().remove(key.as_ref());
+    }
+
+    /// Retrieves the big-endian integer stored under the given key, such as
+    /// a counter or a sequence.
+    pub fn retrieve_u64(&self, key: impl AsRef<[u8]>) -> Result<Option<u64>, HostError> {
+        self.retrieve(key)
+            .map(|value| {
+                let bytes = value
+                    .try_into()
+                    .map_err(|_| HostError::invalid_state("stored integer must be 8 bytes"))?;
+
+                Ok(u64::from_be_bytes(bytes))
+            })
+            .transpose()
+    }
+
+    /// Inserts the given integer in big-endian, as `ibc-go` stores them.
+    pub fn insert_u64(&mut self, key: impl AsRef<[u8]>, value: u64) {
+        self.insert(key, value.to_be_bytes());
+    }
+
+    /// Returns a context over the store of the given hosted client.
+    pub fn client_ref<'b, C>(&'b self, client_id: &ClientId) -> Context<'b, C>
+    where
+        C: ClientType<'b>,
+        <C::ClientState as TryFrom<Any>>::Error: Display,
+        <C::ConsensusState as TryFrom<Any>>::Error: Display,
+    {
+        let deps = match self.deps {
+            Some(deps) => deps,
+            None => match self.deps_mut {
+                Some(ref deps) => deps.as_ref(),
+                None => panic!("Either deps or deps_mut should be available"),
+            },
+        };
+
+        Context::new_hosted_ref(deps, self.env.clone(), client_id.clone())
+    }
+
+    /// Returns a mutable context over the store of the given hosted client.
+    pub fn client_mut<'b, C>(&'b mut self, client_id: &ClientId) -> Context<'b, C>
+    where
+        C: ClientType<'b>,
+        <C::ClientState as TryFrom<Any>>::Error: Display,
+        <C::ConsensusState as TryFrom<Any>>::Error: Display,
+    {
+        let deps_mut = match self.deps_mut {
+            Some(ref mut deps) => deps.branch(),
+            None => panic!("deps_mut should be available"),
+        };
+
+        Context::new_hosted_mut(deps_mut, self.env.clone(), client_id.clone())
+    }
+}
@@ -0,0 +1,57 @@
+use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response};
+use ibc_core::router::router::Router;
+
+use crate::context::IbcContext;
+use crate::router::ModuleRouter;
+use crate::types::{ContractError, ExecuteMsg, InstantiateMsg, QueryMsg, SudoMsg};
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+pub fn instantiate(
+    deps: DepsMut<'_>,
+    env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let mut ctx = IbcContext::new_mut(deps, env);
+    ctx.instantiate(msg)
+}
+
+/// Binds no application: the channel handshakes and packets of the built
+/// contract fail on every port. Contracts embedding the handlers bind their
+/// applications through [`execute_with_router`] instead.
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+pub fn execute(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    execute_with_router(deps, env, info, msg, &mut ModuleRouter::default())
+}
+
+/// Executes the given [`ExecuteMsg`], routing the channel and packet
+/// callbacks to the applications of the given router. It is called by the
+/// `execute` entry point of the contracts embedding the handlers, with the
+/// router of the applications they compile in.
+pub fn execute_with_router(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+    router: &mut impl Router,
+) -> Result<Response, ContractError> {
+    let mut ctx = IbcContext::new_mut(deps, env);
+    ctx.execute(router, info, msg)
+}
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+pub fn sudo(deps: DepsMut<'_>, env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+    let mut ctx = IbcContext::new_mut(deps, env);
+    ctx.sudo(msg)
+}
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+pub fn query(deps: Deps<'_>, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+    let ctx = IbcContext::new_ref(deps, env);
+    ctx.query(msg)
+}
@@ -0,0 +1,95 @@
+use std::str::FromStr;
+
+use cosmwasm_std::{to_json_binary, Binary, MessageInfo, Response};
+use ibc_client_cw::types::StatusResponse;
+use ibc_client_tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc_core::client::context::client_state::ClientStateValidation;
+use ibc_core::client::context::ClientValidationContext;
+use ibc_core::entrypoint::dispatch;
+use ibc_core::handler::types::msgs::MsgEnvelope;
+use ibc_core::host::types::error::DecodingError;
+use ibc_core::host::types::identifiers::ClientId;
+use ibc_core::primitives::proto::{Any, Protobuf};
+use ibc_core::router::router::Router;
+use prost::Message;
+
+use crate::context::{IbcContext, CONFIG, HOST_CONSENSUS_STATES};
+use crate::types::{Config, ContractError, ExecuteMsg, InstantiateMsg, QueryMsg, SudoMsg};
+
+impl IbcContext<'_> {
+    /// Instantiates the contract with the given [`InstantiateMsg`] message.
+    pub fn instantiate(&mut self, msg: InstantiateMsg) -> Result<Response, ContractError> {
+        if msg.commitment_prefix.is_empty() {
+            return Err(ContractError::EmptyCommitmentPrefix);
+        }
+
+        let config = Config {
+            commitment_prefix: msg.commitment_prefix,
+            max_expected_time_per_block: msg.max_expected_time_per_block,
+        };
+
+        CONFIG.save(self.storage_mut(), &config)?;
+
+        Ok(Response::default())
+    }
+
+    /// Executes the given [`ExecuteMsg`], routing the channel and packet
+    /// callbacks to the modules of the given router.
+    pub fn execute(
+        &mut self,
+        router: &mut impl Router,
+        info: MessageInfo,
+        msg: ExecuteMsg,
+    ) -> Result<Response, ContractError> {
+        match msg {
+            ExecuteMsg::Dispatch { messages } => {
+                self.set_sender(info.sender);
+
+                for message in messages {
+                    let any = Any::decode(message.as_slice()).map_err(DecodingError::from)?;
+
+                    dispatch(self, router, MsgEnvelope::try_from(any)?)?;
+                }
+
+                Ok(Response::default().add_events(self.take_events()))
+            }
+        }
+    }
+
+    /// Executes the given [`SudoMsg`], sent by the hosting chain.
+    pub fn sudo(&mut self, msg: SudoMsg) -> Result<Response, ContractError> {
+        match msg {
+            SudoMsg::StoreHostConsensusState {
+                height,
+                consensus_state,
+            } => {
+                // only well-formed consensus states are stored
+                <TmConsensusState as Protobuf<Any>>::decode(consensus_state.as_slice())
+                    .map_err(DecodingError::invalid_raw_data)?;
+
+                HOST_CONSENSUS_STATES.save(self.storage_mut(), height, &consensus_state)?;
+
+                Ok(Response::default())
+            }
+        }
+    }
+
+    /// Queries the contract with the given [`QueryMsg`].
+    pub fn query(&self, msg: QueryMsg) -> Result<Binary, ContractError> {
+        match msg {
+            QueryMsg::Config {} => Ok(to_json_binary(&CONFIG.load(self.storage_ref())?)?),
+            QueryMsg::Value { path } => {
+                let value = self.retrieve(path).map(Binary::from);
+
+                Ok(to_json_binary(&value)?)
+            }
+            QueryMsg::ClientStatus { client_id } => {
+                let client_id = ClientId::from_str(&client_id)?;
+
+                let status = self.client_state(&client_id)?.status(self, &client_id)?;
+
+                Ok(to_json_binary(&StatusResponse { status })?)
+            }
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! The CosmWasm contract implementation of the IBC core handlers: the ICS-02
+//! clients, ICS-03 connections and ICS-04 channels and packets, for hosting
+//! chains that do not run `ibc-go`. It implements the `ibc-rs` validation and
+//! execution contexts over the contract storage, and dispatches the IBC core
+//! messages submitted by relayers to the `ibc-rs` handlers.
+//!
+//! The light clients are hosted by the contract rather than by a proxy
+//! client: each client runs over the `ibc-client-cw` [`Context`] of the
+//! `clients/{client_id}/` namespace, so that its store keeps the layout of an
+//! 08-wasm client. The other IBC states are stored under their ICS-24 paths,
+//! encoded as `ibc-go` commits them.
+//!
+//! The applications are bound to their ports by the [`ModuleRouter`], and
+//! must be compiled into the contract: the contract built from this crate
+//! binds none, and is meant to be extended by using it as a library, with an
+//! `execute` entry point calling [`execute_with_router`] with the router of
+//! its applications.
+//!
+//! The hosted clients check the height of their proofs only through
+//! `ClientStateCommon::validate_proof_height`, as the `ibc-rs` handlers do:
+//! [`ClientType::verify_proof_height`] is never called, so that the clients
+//! relying on it, such as optimistic clients rejecting their pending
+//! consensus states, must not be hosted.
+//!
+//! [`Context`]: ibc_client_cw::context::Context
+//! [`ModuleRouter`]: crate::router::ModuleRouter
+//! [`execute_with_router`]: crate::entrypoint::execute_with_router
+//! [`ClientType::verify_proof_height`]: ibc_client_cw::api::ClientType::verify_proof_height
+#![cfg_attr(not(test), deny(clippy::unwrap_used))]
+
+pub mod client;
+pub mod context;
+pub mod entrypoint;
+pub mod handlers;
+pub mod router;
+pub mod types;
+
+#[cfg(test)]
+mod tests;
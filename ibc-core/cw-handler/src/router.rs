@@ -0,0 +1,57 @@
+//! The ICS-26 router binding the applications compiled into the contract to
+//! their ports.
+use std::collections::BTreeMap;
+
+use ibc_core::host::types::identifiers::PortId;
+use ibc_core::router::module::Module;
+use ibc_core::router::router::Router;
+use ibc_core::router::types::module::ModuleId;
+
+use crate::types::ContractError;
+
+#[derive(Debug, Default)]
+pub struct ModuleRouter {
+    modules: BTreeMap<ModuleId, Box<dyn Module>>,
+    /// Maps the ports to the module that owns them.
+    ports: BTreeMap<PortId, ModuleId>,
+}
+
+impl ModuleRouter {
+    /// Registers the given module under `module_id`.
+    pub fn add_route(
+        &mut self,
+        module_id: ModuleId,
+        module: impl Module + 'static,
+    ) -> Result<(), ContractError> {
+        if self.modules.contains_key(&module_id) {
+            return Err(ContractError::DuplicateModule {
+                module_id: module_id.to_string(),
+            });
+        }
+
+        self.modules.insert(module_id, Box::new(module));
+
+        Ok(())
+    }
+
+    /// Binds the given port to the module registered under `module_id`.
+    pub fn bind_port(&mut self, port_id: PortId, module_id: ModuleId) {
+        self.ports.insert(port_id, module_id);
+    }
+}
+
+impl Router for ModuleRouter {
+    fn get_route(&self, module_id: &ModuleId) -> Option<&dyn Module> {
+        self.modules.get(module_id).map(AsRef::as_ref)
+    }
+
+    fn get_route_mut(&mut self, module_id: &ModuleId) -> Option<&mut dyn Module> {
+        let module: &mut dyn Module = self.modules.get_mut(module_id)?.as_mut();
+
+        Some(module)
+    }
+
+    fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId> {
+        self.ports.get(port_id).cloned()
+    }
+}
@@ -0,0 +1,289 @@
+use core::time::Duration;
+
+use cosmwasm_schema::serde::de::DeserializeOwned;
+use cosmwasm_std::testing::{message_info, mock_env, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{from_json, Addr, Binary, Deps, DepsMut, OwnedDeps, Response};
+use ibc_client_mock_cw::client_state::ClientState as MockClientState;
+use ibc_client_tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc_client_tendermint::types::ConsensusState as TmConsensusStateType;
+use ibc_core::channel::types::channel::Order;
+use ibc_core::channel::types::msgs::{MsgChannelOpenAck, MsgChannelOpenInit, MsgRecvPacket};
+use ibc_core::channel::types::packet::Packet;
+use ibc_core::channel::types::timeout::{TimeoutHeight, TimeoutTimestamp};
+use ibc_core::channel::types::Version as ChannelVersion;
+use ibc_core::client::types::msgs::{MsgCreateClient, MsgUpdateClient};
+use ibc_core::client::types::Height;
+use ibc_core::commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core::connection::types::msgs::{MsgConnectionOpenAck, MsgConnectionOpenInit};
+use ibc_core::connection::types::version::Version as ConnectionVersion;
+use ibc_core::connection::types::Counterparty;
+use ibc_core::host::types::identifiers::{ChainId, ChannelId, ClientId, ConnectionId, PortId};
+use ibc_core::primitives::proto::{Any, Protobuf};
+use ibc_core::primitives::{Signer, Timestamp, ToProto};
+use ibc_core::router::types::module::ModuleId;
+use ibc_testkit::fixtures::clients::tendermint::ClientStateConfig;
+use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use prost::Message;
+
+use crate::entrypoint::{execute_with_router, instantiate, query, sudo};
+use crate::router::ModuleRouter;
+use crate::types::{ContractError, ExecuteMsg, InstantiateMsg, QueryMsg, SudoMsg};
+
+pub type MockDeps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+/// Test fixture of a core handler contract with the dummy transfer module
+/// bound to the `transfer` port.
+pub struct Fixture {
+    pub commitment_prefix: String,
+    pub max_expected_time_per_block: u64,
+    pub router: ModuleRouter,
+}
+
+impl Default for Fixture {
+    fn default() -> Self {
+        let mut router = ModuleRouter::default();
+
+        let module_id = ModuleId::new("transfer".to_string());
+
+        router
+            .add_route(module_id.clone(), DummyTransferModule::new())
+            .unwrap();
+
+        router.bind_port(PortId::transfer(), module_id);
+
+        Self {
+            commitment_prefix: "ibc".to_string(),
+            max_expected_time_per_block: 30,
+            router,
+        }
+    }
+}
+
+impl Fixture {
+    pub fn relayer(deps: &MockDeps) -> Addr {
+        deps.api.addr_make("relayer")
+    }
+
+    /// Returns the identifier of the first mock client created.
+    pub fn client_id() -> ClientId {
+        ClientId::new("9999-mock", 0).unwrap()
+    }
+
+    pub fn instantiate(&self, deps: DepsMut<'_>) -> Result<Response, ContractError> {
+        let admin = MockApi::default().addr_make("admin");
+
+        let msg = InstantiateMsg {
+            commitment_prefix: self.commitment_prefix.clone(),
+            max_expected_time_per_block: self.max_expected_time_per_block,
+        };
+
+        instantiate(deps, mock_env(), message_info(&admin, &[]), msg)
+    }
+
+    /// Dispatches the given messages through the router of the fixture, as
+    /// a contract embedding the handlers does.
+    pub fn dispatch(
+        &mut self,
+        deps: DepsMut<'_>,
+        sender: &Addr,
+        messages: Vec<Any>,
+    ) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::Dispatch {
+            messages: messages
+                .into_iter()
+                .map(|any| any.encode_to_vec().into())
+                .collect(),
+        };
+
+        execute_with_router(
+            deps,
+            mock_env(),
+            message_info(sender, &[]),
+            msg,
+            &mut self.router,
+        )
+    }
+
+    pub fn sudo(&self, deps: DepsMut<'_>, msg: SudoMsg) -> Result<Response, ContractError> {
+        sudo(deps, mock_env(), msg)
+    }
+
+    pub fn query<T: DeserializeOwned>(&self, deps: Deps<'_>, msg: QueryMsg) -> T {
+        from_json(query(deps, mock_env(), msg).unwrap()).unwrap()
+    }
+
+    /// Returns the value stored under the given ICS-24 path.
+    pub fn value(&self, deps: Deps<'_>, path: impl ToString) -> Option<Binary> {
+        self.query(
+            deps,
+            QueryMsg::Value {
+                path: path.to_string(),
+            },
+        )
+    }
+
+    /// Returns a mock header at the given height, timestamped at the block
+    /// time of the mock environment.
+    pub fn header(revision_height: u64) -> MockHeader {
+        let timestamp = Timestamp::from_nanoseconds(mock_env().block.time.nanos());
+
+        MockHeader::new(Height::new(0, revision_height).unwrap()).with_timestamp(timestamp)
+    }
+
+    pub fn create_client_msg(signer: &Addr) -> Any {
+        let header = Self::header(1);
+
+        MsgCreateClient::new(
+            MockClientState::new(header).into(),
+            MockConsensusState::new(header).into(),
+            Signer::from(signer.to_string()),
+        )
+        .to_any()
+    }
+
+    pub fn update_client_msg(revision_height: u64, signer: &Addr) -> Any {
+        MsgUpdateClient {
+            client_id: Self::client_id(),
+            client_message: Self::header(revision_height).into(),
+            signer: Signer::from(signer.to_string()),
+        }
+        .to_any()
+    }
+
+    pub fn conn_open_init_msg(signer: &Addr) -> Any {
+        MsgConnectionOpenInit {
+            client_id_on_a: Self::client_id(),
+            counterparty: Counterparty::new(
+                ClientId::new("07-tendermint", 3).unwrap(),
+                None,
+                CommitmentPrefix::from_bytes(b"ibc"),
+            ),
+            version: None,
+            delay_period: Duration::ZERO,
+            signer: Signer::from(signer.to_string()),
+        }
+        .to_any()
+    }
+
+    pub fn chan_open_init_msg(signer: &Addr) -> Any {
+        MsgChannelOpenInit {
+            port_id_on_a: PortId::transfer(),
+            connection_hops_on_a: vec![ConnectionId::new(0)],
+            port_id_on_b: PortId::transfer(),
+            ordering: Order::Unordered,
+            signer: Signer::from(signer.to_string()),
+            version_proposal: ChannelVersion::new("ics20-1".to_string()),
+        }
+        .to_any()
+    }
+
+    /// Returns a height of the hosting chain, in the revision of the chain
+    /// identifier of the mock environment.
+    pub fn host_height(revision_height: u64) -> Height {
+        let chain_id = ChainId::new(&mock_env().block.chain_id).unwrap();
+
+        Height::new(chain_id.revision_number(), revision_height).unwrap()
+    }
+
+    pub fn host_consensus_state() -> Binary {
+        let consensus_state = TmConsensusState::from(TmConsensusStateType::new(
+            CommitmentRoot::from_bytes(&[1; 32]),
+            tendermint::Time::from_unix_timestamp(1_571_797_419, 0).unwrap(),
+            tendermint::Hash::Sha256([2; 32]),
+        ));
+
+        Protobuf::<Any>::encode_vec(consensus_state).into()
+    }
+
+    /// Returns a proof, which the mock client accepts whatever it is.
+    pub fn proof() -> CommitmentProofBytes {
+        CommitmentProofBytes::try_from(b"proof".to_vec()).unwrap()
+    }
+
+    /// Returns the acknowledgement of the counterparty to the connection
+    /// opened by [`Self::conn_open_init_msg`], proven at the height of the
+    /// mock client, whose client of the hosting chain trusts the host
+    /// consensus state at `host_height`.
+    pub fn conn_open_ack_msg(host_height: Height, signer: &Addr) -> Any {
+        let chain_id = ChainId::new(&mock_env().block.chain_id).unwrap();
+
+        let client_state_of_a_on_b = ClientStateConfig::default()
+            .into_client_state(chain_id, host_height)
+            .unwrap();
+
+        MsgConnectionOpenAck {
+            conn_id_on_a: ConnectionId::new(0),
+            conn_id_on_b: ConnectionId::new(5),
+            client_state_of_a_on_b: client_state_of_a_on_b.into(),
+            proof_conn_end_on_b: Self::proof(),
+            proof_client_state_of_a_on_b: Self::proof(),
+            proof_consensus_state_of_a_on_b: Self::proof(),
+            proofs_height_on_b: Height::new(0, 1).unwrap(),
+            consensus_height_of_a_on_b: host_height,
+            version: ConnectionVersion::compatibles()[0].clone(),
+            signer: Signer::from(signer.to_string()),
+            proof_consensus_state_of_a: None,
+        }
+        .to_any()
+    }
+
+    /// Returns the acknowledgement of the counterparty to the channel opened
+    /// by [`Self::chan_open_init_msg`].
+    pub fn chan_open_ack_msg(signer: &Addr) -> Any {
+        MsgChannelOpenAck {
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::new(0),
+            chan_id_on_b: ChannelId::new(3),
+            version_on_b: ChannelVersion::new("ics20-1".to_string()),
+            proof_chan_end_on_b: Self::proof(),
+            proof_height_on_b: Height::new(0, 1).unwrap(),
+            signer: Signer::from(signer.to_string()),
+        }
+        .to_any()
+    }
+
+    /// Returns the first packet sent by the counterparty over the channel
+    /// opened by [`Self::chan_open_init_msg`].
+    pub fn packet() -> Packet {
+        Packet {
+            seq_on_a: 1.into(),
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::new(3),
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: ChannelId::new(0),
+            data: b"packet".to_vec(),
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: TimeoutTimestamp::At(Timestamp::from_nanoseconds(
+                mock_env().block.time.plus_hours(1).nanos(),
+            )),
+        }
+    }
+
+    pub fn recv_packet_msg(signer: &Addr) -> Any {
+        MsgRecvPacket {
+            packet: Self::packet(),
+            proof_commitment_on_a: Self::proof(),
+            proof_height_on_a: Height::new(0, 1).unwrap(),
+            signer: Signer::from(signer.to_string()),
+        }
+        .to_any()
+    }
+
+    /// Instantiates the contract and creates the mock client.
+    pub fn setup(&mut self, deps: &mut MockDeps) {
+        self.instantiate(deps.as_mut()).unwrap();
+
+        let relayer = Self::relayer(deps);
+
+        self.dispatch(
+            deps.as_mut(),
+            &relayer,
+            vec![Self::create_client_msg(&relayer)],
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,330 @@
+pub mod fixture;
+
+use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+use cosmwasm_std::Binary;
+use ibc_client_cw::types::StatusResponse;
+use ibc_client_tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc_client_tendermint::types::ConsensusState as TmConsensusStateType;
+use ibc_core::channel::types::channel::{ChannelEnd, State as ChannelState};
+use ibc_core::client::types::Status;
+use ibc_core::commitment_types::commitment::CommitmentRoot;
+use ibc_core::connection::types::{ConnectionEnd, State as ConnectionState};
+use ibc_core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core::host::types::path::{
+    AckPath, ChannelEndPath, ClientConsensusStatePath, ClientStatePath, ConnectionPath,
+    ReceiptPath, SeqSendPath,
+};
+use ibc_core::primitives::proto::{Any, Protobuf};
+use prost::Message;
+use tendermint::Hash;
+
+use crate::context::HOST_CONSENSUS_STATES;
+use crate::entrypoint::{execute, instantiate};
+use crate::tests::fixture::Fixture;
+use crate::types::{Config, ContractError, ExecuteMsg, InstantiateMsg, QueryMsg, SudoMsg};
+
+#[test]
+fn test_cw_core_instantiate() {
+    let mut deps = mock_dependencies();
+
+    let fxt = Fixture::default();
+
+    fxt.instantiate(deps.as_mut()).unwrap();
+
+    let config: Config = fxt.query(deps.as_ref(), QueryMsg::Config {});
+
+    assert_eq!(config.commitment_prefix, "ibc");
+    assert_eq!(config.max_expected_time_per_block, 30);
+
+    let msg = InstantiateMsg {
+        commitment_prefix: String::new(),
+        max_expected_time_per_block: 30,
+    };
+
+    let relayer = Fixture::relayer(&deps);
+
+    let err = instantiate(deps.as_mut(), mock_env(), message_info(&relayer, &[]), msg).unwrap_err();
+
+    assert!(matches!(err, ContractError::EmptyCommitmentPrefix));
+}
+
+#[test]
+fn test_cw_core_create_and_update_client() {
+    let mut deps = mock_dependencies();
+
+    let mut fxt = Fixture::default();
+
+    fxt.instantiate(deps.as_mut()).unwrap();
+
+    let relayer = Fixture::relayer(&deps);
+
+    let resp = fxt
+        .dispatch(
+            deps.as_mut(),
+            &relayer,
+            vec![Fixture::create_client_msg(&relayer)],
+        )
+        .unwrap();
+
+    assert!(resp.events.iter().any(|e| e.ty == "create_client"));
+
+    let client_id = Fixture::client_id();
+
+    assert!(fxt
+        .value(deps.as_ref(), ClientStatePath::new(client_id.clone()))
+        .is_some());
+
+    let status: StatusResponse = fxt.query(
+        deps.as_ref(),
+        QueryMsg::ClientStatus {
+            client_id: client_id.to_string(),
+        },
+    );
+
+    assert_eq!(status.status, Status::Active);
+
+    let resp = fxt
+        .dispatch(
+            deps.as_mut(),
+            &relayer,
+            vec![Fixture::update_client_msg(2, &relayer)],
+        )
+        .unwrap();
+
+    assert!(resp.events.iter().any(|e| e.ty == "update_client"));
+
+    assert!(fxt
+        .value(
+            deps.as_ref(),
+            ClientConsensusStatePath::new(client_id, 0, 2)
+        )
+        .is_some());
+}
+
+#[test]
+fn test_cw_core_connection_and_channel_open_init() {
+    let mut deps = mock_dependencies();
+
+    let mut fxt = Fixture::default();
+
+    fxt.setup(&mut deps);
+
+    let relayer = Fixture::relayer(&deps);
+
+    fxt.dispatch(
+        deps.as_mut(),
+        &relayer,
+        vec![
+            Fixture::conn_open_init_msg(&relayer),
+            Fixture::chan_open_init_msg(&relayer),
+        ],
+    )
+    .unwrap();
+
+    let connection = fxt
+        .value(deps.as_ref(), ConnectionPath::new(&ConnectionId::new(0)))
+        .unwrap();
+
+    let connection = <ConnectionEnd as Protobuf<_>>::decode_vec(connection.as_slice()).unwrap();
+
+    assert_eq!(connection.state(), &ConnectionState::Init);
+    assert_eq!(connection.client_id(), &Fixture::client_id());
+
+    let channel_end_path = ChannelEndPath::new(&PortId::transfer(), &ChannelId::new(0));
+
+    let channel = fxt.value(deps.as_ref(), channel_end_path).unwrap();
+
+    let channel = <ChannelEnd as Protobuf<_>>::decode_vec(channel.as_slice()).unwrap();
+
+    assert_eq!(channel.state(), &ChannelState::Init);
+
+    let next_sequence_send = fxt
+        .value(
+            deps.as_ref(),
+            SeqSendPath::new(&PortId::transfer(), &ChannelId::new(0)),
+        )
+        .unwrap();
+
+    assert_eq!(next_sequence_send.as_slice(), 1u64.to_be_bytes());
+}
+
+#[test]
+fn test_cw_core_channel_open_and_packet_round_trip() {
+    let mut deps = mock_dependencies();
+
+    let mut fxt = Fixture::default();
+
+    fxt.setup(&mut deps);
+
+    let relayer = Fixture::relayer(&deps);
+
+    // The counterparty trusts a consensus state of the hosting chain.
+    let host_height = Fixture::host_height(mock_env().block.height - 1);
+
+    let msg = SudoMsg::StoreHostConsensusState {
+        height: host_height.revision_height(),
+        consensus_state: Fixture::host_consensus_state(),
+    };
+
+    fxt.sudo(deps.as_mut(), msg).unwrap();
+
+    fxt.dispatch(
+        deps.as_mut(),
+        &relayer,
+        vec![
+            Fixture::conn_open_init_msg(&relayer),
+            Fixture::conn_open_ack_msg(host_height, &relayer),
+            Fixture::chan_open_init_msg(&relayer),
+        ],
+    )
+    .unwrap();
+
+    let resp = fxt
+        .dispatch(
+            deps.as_mut(),
+            &relayer,
+            vec![Fixture::chan_open_ack_msg(&relayer)],
+        )
+        .unwrap();
+
+    assert!(resp.events.iter().any(|e| e.ty == "channel_open_ack"));
+
+    let channel_end_path = ChannelEndPath::new(&PortId::transfer(), &ChannelId::new(0));
+
+    let channel = fxt.value(deps.as_ref(), channel_end_path).unwrap();
+
+    let channel = <ChannelEnd as Protobuf<_>>::decode_vec(channel.as_slice()).unwrap();
+
+    assert_eq!(channel.state(), &ChannelState::Open);
+
+    // The packets of the counterparty are received by the bound module, whose
+    // acknowledgement is committed.
+    let resp = fxt
+        .dispatch(
+            deps.as_mut(),
+            &relayer,
+            vec![Fixture::recv_packet_msg(&relayer)],
+        )
+        .unwrap();
+
+    assert!(resp.events.iter().any(|e| e.ty == "recv_packet"));
+    assert!(resp.events.iter().any(|e| e.ty == "write_acknowledgement"));
+
+    let packet = Fixture::packet();
+
+    assert!(fxt
+        .value(
+            deps.as_ref(),
+            ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a)
+        )
+        .is_some());
+
+    assert!(fxt
+        .value(
+            deps.as_ref(),
+            AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a)
+        )
+        .is_some());
+
+    // A packet is only received once.
+    assert!(fxt
+        .dispatch(
+            deps.as_mut(),
+            &relayer,
+            vec![Fixture::recv_packet_msg(&relayer)],
+        )
+        .is_err());
+}
+
+#[test]
+fn test_cw_core_channel_on_unbound_port_rejected() {
+    let mut deps = mock_dependencies();
+
+    let mut fxt = Fixture::default();
+
+    fxt.setup(&mut deps);
+
+    let relayer = Fixture::relayer(&deps);
+
+    fxt.dispatch(
+        deps.as_mut(),
+        &relayer,
+        vec![Fixture::conn_open_init_msg(&relayer)],
+    )
+    .unwrap();
+
+    // the contract entry point binds no application
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&relayer, &[]),
+        ExecuteMsg::Dispatch {
+            messages: vec![Fixture::chan_open_init_msg(&relayer).encode_to_vec().into()],
+        },
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, ContractError::Handler(_)));
+}
+
+#[test]
+fn test_cw_core_signer_mismatch_rejected() {
+    let mut deps = mock_dependencies();
+
+    let mut fxt = Fixture::default();
+
+    fxt.instantiate(deps.as_mut()).unwrap();
+
+    let relayer = Fixture::relayer(&deps);
+    let other = deps.api.addr_make("other");
+
+    let err = fxt
+        .dispatch(
+            deps.as_mut(),
+            &other,
+            vec![Fixture::create_client_msg(&relayer)],
+        )
+        .unwrap_err();
+
+    assert!(matches!(err, ContractError::Handler(_)));
+
+    assert!(fxt
+        .value(deps.as_ref(), ClientStatePath::new(Fixture::client_id()))
+        .is_none());
+}
+
+#[test]
+fn test_cw_core_store_host_consensus_state() {
+    let mut deps = mock_dependencies();
+
+    let fxt = Fixture::default();
+
+    fxt.instantiate(deps.as_mut()).unwrap();
+
+    let msg = SudoMsg::StoreHostConsensusState {
+        height: 10,
+        consensus_state: Binary::from(b"not a consensus state".to_vec()),
+    };
+
+    fxt.sudo(deps.as_mut(), msg).unwrap_err();
+
+    let consensus_state = TmConsensusState::from(TmConsensusStateType::new(
+        CommitmentRoot::from_bytes(&[1; 32]),
+        tendermint::Time::from_unix_timestamp(1_571_797_419, 0).unwrap(),
+        Hash::Sha256([2; 32]),
+    ));
+
+    let consensus_state = Binary::from(Protobuf::<Any>::encode_vec(consensus_state));
+
+    let msg = SudoMsg::StoreHostConsensusState {
+        height: 10,
+        consensus_state: consensus_state.clone(),
+    };
+
+    fxt.sudo(deps.as_mut(), msg).unwrap();
+
+    assert_eq!(
+        HOST_CONSENSUS_STATES.load(&deps.storage, 10).unwrap(),
+        consensus_state
+    );
+}
@@ -0,0 +1,46 @@
+use cosmwasm_std::StdError;
+use derive_more::{Display, From};
+use ibc_core::client::types::error::ClientError;
+use ibc_core::handler::types::error::HandlerError;
+use ibc_core::host::types::error::{DecodingError, HostError, IdentifierError};
+
+#[derive(From, Display, Debug)]
+pub enum ContractError {
+    #[from]
+    #[display("CosmWasm standard error: {_0}")]
+    Std(StdError),
+    #[from]
+    #[display("CosmWasm hosting error: {_0}")]
+    Host(HostError),
+    #[from]
+    #[display("IBC handler error: {_0}")]
+    Handler(HandlerError),
+    #[from]
+    #[display("IBC client error: {_0}")]
+    Client(ClientError),
+    #[from]
+    #[display("IBC identifier error: {_0}")]
+    Identifier(IdentifierError),
+    #[from]
+    #[display("IBC decoding error: {_0}")]
+    Decoding(DecodingError),
+    #[display("commitment prefix must not be empty")]
+    EmptyCommitmentPrefix,
+    #[display("module `{module_id}` is already registered")]
+    DuplicateModule { module_id: String },
+}
+
+impl From<ContractError> for StdError {
+    fn from(err: ContractError) -> Self {
+        Self::generic_err(err.to_string())
+    }
+}
+
+impl From<ContractError> for HostError {
+    fn from(err: ContractError) -> Self {
+        match err {
+            ContractError::Host(err) => err,
+            err => Self::invalid_state(err),
+        }
+    }
+}
@@ -0,0 +1,7 @@
+mod error;
+mod msgs;
+mod state;
+
+pub use error::*;
+pub use msgs::*;
+pub use state::*;
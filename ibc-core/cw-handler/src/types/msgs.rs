@@ -0,0 +1,66 @@
+//! Defines the messages sent to the CosmWasm contract by its users and by the
+//! hosting chain.
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Binary;
+use ibc_client_cw::types::StatusResponse;
+
+use super::state::Config;
+
+// ------------------------------------------------------------
+// Implementation of the InstantiateMsg struct
+// ------------------------------------------------------------
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub commitment_prefix: String,
+    /// The maximum expected time per block of the hosting chain, in seconds.
+    pub max_expected_time_per_block: u64,
+}
+
+// ------------------------------------------------------------
+// Implementation of the ExecuteMsg enum and its variants
+// ------------------------------------------------------------
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Dispatches the given IBC core messages, in order, to the client,
+    /// connection and channel handlers. Each message is a protobuf encoded
+    /// `Any`, such as a `MsgCreateClient` or a `MsgRecvPacket`, whose signer
+    /// must be the sender of this message.
+    Dispatch { messages: Vec<Binary> },
+}
+
+// ------------------------------------------------------------
+// Implementation of the SudoMsg enum and its variants
+// ------------------------------------------------------------
+
+#[cw_serde]
+pub enum SudoMsg {
+    /// Stores the Tendermint consensus state of the hosting chain at the given
+    /// height of its current revision, as a protobuf encoded `Any`. The
+    /// connection handshakes check the consensus states the counterparties
+    /// store of the hosting chain against them, and contracts have no access
+    /// to the block headers, so only the hosting chain can provide them.
+    StoreHostConsensusState {
+        height: u64,
+        consensus_state: Binary,
+    },
+}
+
+// ------------------------------------------------------------
+// Implementation of the QueryMsg enum and its variants
+// ------------------------------------------------------------
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(Config)]
+    Config {},
+    /// Returns the value stored under the given ICS-24 path, such as
+    /// `connections/connection-0` or `commitments/ports/{port_id}/channels/
+    /// {channel_id}/sequences/{sequence}`.
+    #[returns(Option<Binary>)]
+    Value { path: String },
+    #[returns(StatusResponse)]
+    ClientStatus { client_id: String },
+}
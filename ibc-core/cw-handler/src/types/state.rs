@@ -0,0 +1,11 @@
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+pub struct Config {
+    /// The prefix of the store under which the counterparty chains verify
+    /// the commitments of the contract.
+    pub commitment_prefix: String,
+    /// The maximum expected time per block of the hosting chain, in seconds,
+    /// from which the block delays of the connections are derived.
+    pub max_expected_time_per_block: u64,
+}